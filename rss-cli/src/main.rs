@@ -0,0 +1,544 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand};
+use rss_core::{
+    build_http_client, feeds_to_opml, format_bytes, poll_once_in, shared_feed_list, write_atomic,
+    AddFeedError, AllowHttp, AppConfig, CycleSummary, DataApi, Event, FeedDescriptor, FeedEntry,
+    PollConfig, PollerMetrics,
+};
+use serde::Serialize;
+use tracing_subscriber::EnvFilter;
+use url::Url;
+
+// ===
+//
+//
+// Compagnon en ligne de commande de ReadRSS: gère les abonnements et lit les titres sans
+// interface graphique. Il travaille sur le même dossier de configuration et les mêmes fichiers
+// JSON que la GUI (écritures atomiques), l’état est donc partagé entre les deux.
+//
+//
+// ===
+
+type CliResult = Result<(), Box<dyn std::error::Error>>;
+
+#[derive(Parser)]
+#[command(name = "rss-cli", version, about = "ReadRSS en ligne de commande")]
+struct Cli {
+    /// Sortie JSON (pour les scripts) au lieu du texte lisible
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Ajoute un abonnement (HTTPS, ou HTTP selon network.allow_http)
+    Add {
+        url: String,
+        #[arg(long)]
+        title: Option<String>,
+        /// Autorise le HTTP non chiffré pour ce flux seulement
+        #[arg(long)]
+        allow_insecure: bool,
+    },
+    /// Supprime un abonnement (et ses marques de lecture)
+    Remove { id: String },
+    /// Liste les abonnements
+    List,
+    /// Récupère tous les flux une fois et affiche les nouveaux articles
+    Poll,
+    /// Récupère les flux en continu, à l’intervalle de la configuration (instance sans interface)
+    Watch(WatchArgs),
+    /// Liste les articles non lus
+    Unread,
+    /// Marque un article comme lu (identité affichée par `unread`)
+    MarkRead { identity: String },
+    /// Exporte les abonnements au format OPML
+    ExportOpml { path: PathBuf },
+    /// Nettoie le cache d’articles selon la conservation de chaque flux
+    Cleanup,
+    /// Compacte les données: flux supprimés, articles en double, marques anciennes sans article
+    Compact,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Expose les métriques du poller (format Prometheus) sur http://ADRESSE/metrics
+    #[cfg(feature = "metrics-http")]
+    #[arg(long, value_name = "ADRESSE")]
+    metrics_addr: Option<String>,
+}
+
+// Flux tel qu’affiché: URL masquée, jamais d’identifiants.
+#[derive(Serialize)]
+struct FeedRow {
+    id: String,
+    title: String,
+    url: String,
+    disabled: bool,
+}
+
+impl From<&FeedDescriptor> for FeedRow {
+    fn from(feed: &FeedDescriptor) -> Self {
+        Self {
+            id: feed.id.clone(),
+            title: feed.title.clone(),
+            url: feed.redacted_url(),
+            disabled: feed.disabled,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ArticleRow {
+    identity: String,
+    feed_id: String,
+    feed_title: String,
+    title: String,
+    url: String,
+    published_at: Option<DateTime<Utc>>,
+}
+
+impl ArticleRow {
+    fn new(entry: &FeedEntry, titles: &HashMap<String, String>) -> Self {
+        Self {
+            identity: entry.identity(),
+            feed_id: entry.feed_id.clone(),
+            feed_title: titles
+                .get(&entry.feed_id)
+                .cloned()
+                .unwrap_or_else(|| entry.feed_id.clone()),
+            title: entry.title.clone(),
+            url: entry.url.clone(),
+            published_at: entry.published_at,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    init_tracing();
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Erreur: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// ===
+//
+//
+// Logs sur stderr (avertissements par défaut, filtrables par RUST_LOG) pour garder stdout propre.
+//
+//
+// ===
+fn init_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+// ===
+//
+//
+// Dossier de configuration partagé avec la GUI.
+//
+//
+// ===
+fn config_dir() -> PathBuf {
+    let mut dir = dirs::config_dir().unwrap_or_else(|| std::env::current_dir().unwrap());
+    dir.push("readrss");
+    dir
+}
+
+// ===
+//
+//
+// Charge les magasins (flux, lus, cache d’articles, “vus”) et leurs limites comme la GUI au
+// démarrage.
+//
+//
+// ===
+async fn load_stores(config: &AppConfig) -> DataApi {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), config_dir()).await;
+    api.set_max_articles_per_feed(config.feeds.max_articles_per_feed);
+    api.set_max_title_chars(config.feeds.max_title_chars);
+    api.set_max_seen_per_feed(config.feeds.max_seen_per_feed);
+    api
+}
+
+async fn run(cli: Cli) -> CliResult {
+    let config = AppConfig::load();
+    let api = load_stores(&config).await;
+    match cli.command {
+        Command::Add {
+            url,
+            title,
+            allow_insecure,
+        } => {
+            let policy = if allow_insecure {
+                AllowHttp::Always
+            } else {
+                config.network.allow_http
+            };
+            add(&api, &url, title, policy, allow_insecure, cli.json).await
+        }
+        Command::Remove { id } => remove(&api, &id, cli.json).await,
+        Command::List => list(&api, cli.json).await,
+        Command::Poll => poll(&api, &config, cli.json).await,
+        Command::Watch(args) => watch(&config, args, cli.json).await,
+        Command::Unread => unread(&api, cli.json).await,
+        Command::MarkRead { identity } => mark_read(&api, &identity, cli.json).await,
+        Command::ExportOpml { path } => export_opml(&api, &path, cli.json).await,
+        Command::Cleanup => cleanup(&api, cli.json).await,
+        Command::Compact => compact(&api, cli.json).await,
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> CliResult {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+async fn feed_titles(api: &DataApi) -> HashMap<String, String> {
+    api.list_feeds()
+        .await
+        .into_iter()
+        .map(|f| (f.id, f.title))
+        .collect()
+}
+
+// ===
+//
+//
+// Ajoute un flux avec les mêmes règles que la GUI (HTTPS, ou HTTP si `allow_http` l’autorise;
+// identifiant titre:horodatage).
+//
+//
+// ===
+async fn add(
+    api: &DataApi,
+    url: &str,
+    title: Option<String>,
+    allow_http: AllowHttp,
+    allow_insecure: bool,
+    json: bool,
+) -> CliResult {
+    let url = url.trim();
+    let parsed = Url::parse(url).map_err(|_| "URL invalide")?;
+    if !allow_http.allows(&parsed) {
+        return Err(match parsed.scheme() {
+            "http" => "HTTP non autorisé: utilisez --allow-insecure ou network.allow_http".into(),
+            _ => "Seules les URLs HTTP(S) sont acceptées".into(),
+        });
+    }
+    let title = title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty());
+    let descriptor = FeedDescriptor {
+        id: format!(
+            "{}:{}",
+            title.as_deref().unwrap_or_default(),
+            Utc::now().timestamp_millis()
+        ),
+        title: title.unwrap_or_else(|| url.to_string()),
+        url: url.to_string(),
+        allow_insecure,
+        ..Default::default()
+    };
+    match api.try_add_feed(descriptor.clone()).await {
+        Ok(()) => {}
+        Err(AddFeedError::Duplicate { id, title }) => {
+            return Err(format!("Déjà abonné à {}: {} ({})", url, title, id).into());
+        }
+        Err(AddFeedError::InvalidUrl(_)) => return Err("URL invalide".into()),
+        Err(AddFeedError::Data(e)) => return Err(e.into()),
+    }
+    if json {
+        return print_json(&FeedRow::from(&descriptor));
+    }
+    println!("Ajouté: {} ({})", descriptor.title, descriptor.id);
+    Ok(())
+}
+
+async fn remove(api: &DataApi, id: &str, json: bool) -> CliResult {
+    let Some(feed) = api.list_feeds().await.into_iter().find(|f| f.id == id) else {
+        return Err(format!("Flux inconnu: {}", id).into());
+    };
+    api.remove_feed(id).await?;
+    if json {
+        return print_json(&FeedRow::from(&feed));
+    }
+    println!("Supprimé: {}", feed.title);
+    Ok(())
+}
+
+async fn list(api: &DataApi, json: bool) -> CliResult {
+    let feeds = api.list_feeds().await;
+    if json {
+        return print_json(&feeds.iter().map(FeedRow::from).collect::<Vec<_>>());
+    }
+    if feeds.is_empty() {
+        println!("Aucun abonnement.");
+    }
+    for feed in &feeds {
+        let state = if feed.disabled { " [désactivé]" } else { "" };
+        println!(
+            "{}  {}{}\n    {}",
+            feed.id,
+            feed.title,
+            state,
+            feed.redacted_url()
+        );
+    }
+    Ok(())
+}
+
+// ===
+//
+//
+// Un tour de polling (poll_once_in): articles nouveaux et santé des flux persistés comme dans la GUI.
+//
+//
+// ===
+async fn poll(api: &DataApi, config: &AppConfig, json: bool) -> CliResult {
+    let client = build_http_client(&config.network)?;
+    let poll_config = PollConfig::from_app_config(config);
+    let round = poll_round(api, config, &poll_config, &client).await?;
+    print_round(&round, json)
+}
+
+// ===
+//
+//
+// Boucle de `poll` à l’intervalle configuré, magasins relus à chaque tour pour suivre les
+// changements faits par la GUI. Avec la feature `metrics-http`, `--metrics-addr` expose les
+// compteurs du poller sur /metrics.
+//
+//
+// ===
+async fn watch(config: &AppConfig, args: WatchArgs, json: bool) -> CliResult {
+    let client = build_http_client(&config.network)?;
+    let metrics = PollerMetrics::shared();
+    let poll_config = PollConfig {
+        metrics: Some(metrics.clone()),
+        ..PollConfig::from_app_config(config)
+    };
+    #[cfg(feature = "metrics-http")]
+    let _server = match &args.metrics_addr {
+        Some(addr) => {
+            let server = rss_core::serve_metrics(addr, metrics.clone()).await?;
+            eprintln!("Métriques: http://{}/metrics", server.local_addr());
+            Some(server)
+        }
+        None => None,
+    };
+    #[cfg(not(feature = "metrics-http"))]
+    let _ = args;
+    loop {
+        let api = load_stores(config).await;
+        metrics.set_feeds_configured(api.list_feeds().await.len());
+        match poll_round(&api, config, &poll_config, &client).await {
+            Ok(round) => print_round(&round, json)?,
+            Err(e) => eprintln!("Erreur: {}", e),
+        }
+        tokio::time::sleep(poll_config.interval).await;
+    }
+}
+
+// Résultat d’un tour: nouveaux articles, flux en échec et bilan.
+struct Round {
+    new_articles: Vec<ArticleRow>,
+    failures: usize,
+    summary: CycleSummary,
+}
+
+async fn poll_round(
+    api: &DataApi,
+    config: &AppConfig,
+    poll_config: &PollConfig,
+    client: &reqwest::Client,
+) -> Result<Round, Box<dyn std::error::Error>> {
+    let feeds = api.list_feeds().await;
+    let titles = feed_titles(api).await;
+
+    let mut new_articles = Vec::new();
+    let mut failures = 0;
+    // Cookies des flux: ceux de secrets.json, relu avec les autres magasins.
+    let poll_config = PollConfig {
+        secrets: api.feed_secrets().clone(),
+        ..poll_config.clone()
+    };
+    // Échecs consécutifs comptés dans la liste de DataApi, conservée par flush_pending.
+    let (events, summary) = poll_once_in(
+        api.feed_list(),
+        &feeds,
+        &poll_config,
+        client,
+        api.seen_store(),
+    )
+    .await;
+    for event in events {
+        match event {
+            Event::NewArticles(feed_id, entries) => {
+                new_articles.extend(entries.iter().map(|e| ArticleRow::new(e, &titles)));
+                api.upsert_articles(&feed_id, entries).await?;
+            }
+            Event::ArticlesUpdated(feed_id, entries) => {
+                api.upsert_articles(&feed_id, entries).await?;
+            }
+            Event::FetchCompleted(feed_id, outcome) => {
+                if let Some(error) = outcome.error.as_deref().filter(|_| !outcome.success) {
+                    failures += 1;
+                    let title = titles.get(&feed_id).unwrap_or(&feed_id);
+                    eprintln!("Échec pour {}: {}", title, error);
+                }
+                api.record_fetch(&feed_id, outcome).await;
+            }
+            Event::FeedDisabled(feed_id) => {
+                let title = titles.get(&feed_id).unwrap_or(&feed_id);
+                eprintln!("Flux désactivé après des échecs répétés: {}", title);
+            }
+            Event::CycleCompleted(_) | Event::ConnectivityChanged(_) => {}
+            Event::FeedMoved(feed_id, url) => {
+                if config.feeds.auto_update_moved_feeds
+                    && api.update_feed_url(&feed_id, &url).await?
+                {
+                    let title = titles.get(&feed_id).unwrap_or(&feed_id);
+                    eprintln!("Flux déplacé, URL mise à jour: {}", title);
+                }
+            }
+            Event::FeedMetadata(feed_id, meta) => {
+                api.update_feed_metadata(&feed_id, meta).await?;
+            }
+            Event::FeedTitle(feed_id, title) => {
+                api.update_feed_title(&feed_id, &title).await?;
+            }
+        }
+    }
+    // Statistiques du tour écrites en une fois.
+    api.flush_pending().await?;
+
+    Ok(Round {
+        new_articles,
+        failures,
+        summary,
+    })
+}
+
+fn print_round(round: &Round, json: bool) -> CliResult {
+    if json {
+        return print_json(&round.new_articles);
+    }
+    for article in &round.new_articles {
+        println!("[{}] {}", article.feed_title, article.title);
+    }
+    println!(
+        "{} nouvel(s) article(s), {} flux en échec sur {}, {} téléchargé(s) en {:.1} s.",
+        round.new_articles.len(),
+        round.failures,
+        round.summary.feeds_polled,
+        format_bytes(round.summary.bytes_downloaded),
+        round.summary.duration.as_secs_f64()
+    );
+    Ok(())
+}
+
+async fn unread(api: &DataApi, json: bool) -> CliResult {
+    let titles = feed_titles(api).await;
+    let mut rows = Vec::new();
+    for entry in api.list_all_articles().await {
+        if !api.is_read(&entry).await {
+            rows.push(ArticleRow::new(&entry, &titles));
+        }
+    }
+    if json {
+        return print_json(&rows);
+    }
+    if rows.is_empty() {
+        println!("Aucun article non lu.");
+    }
+    for row in &rows {
+        println!("{}\n    [{}] {}", row.identity, row.feed_title, row.title);
+    }
+    Ok(())
+}
+
+async fn mark_read(api: &DataApi, identity: &str, json: bool) -> CliResult {
+    let Some(entry) = api
+        .list_all_articles()
+        .await
+        .into_iter()
+        .find(|e| e.identity() == identity)
+    else {
+        return Err(format!("Article inconnu: {}", identity).into());
+    };
+    api.mark_read(&entry).await?;
+    // Commande ponctuelle: la marque rejoint read_store.json plutôt que le journal.
+    api.compact_read_journal().await?;
+    if json {
+        return print_json(&ArticleRow::new(&entry, &feed_titles(api).await));
+    }
+    println!("Marqué comme lu: {}", entry.title);
+    Ok(())
+}
+
+async fn export_opml(api: &DataApi, path: &std::path::Path, json: bool) -> CliResult {
+    let feeds = api.list_feeds().await;
+    write_atomic(
+        path,
+        feeds_to_opml(&feeds, "Abonnements ReadRSS").as_bytes(),
+    )
+    .await?;
+    if json {
+        return print_json(&serde_json::json!({
+            "path": path,
+            "feeds": feeds.len(),
+        }));
+    }
+    println!("{} flux exportés vers {}", feeds.len(), path.display());
+    Ok(())
+}
+
+async fn cleanup(api: &DataApi, json: bool) -> CliResult {
+    let report = api.cleanup().await?;
+    if json {
+        return print_json(&serde_json::json!({
+            "removed": report.removed,
+            "bytes_saved": report.bytes_saved,
+        }));
+    }
+    println!(
+        "{} article(s) supprimé(s), {} octets libérés",
+        report.removed, report.bytes_saved
+    );
+    Ok(())
+}
+
+async fn compact(api: &DataApi, json: bool) -> CliResult {
+    let report = api.compact().await?;
+    if json {
+        return print_json(&serde_json::json!({
+            "removed_articles": report.removed_articles,
+            "removed_read_marks": report.removed_read_marks,
+            "bytes_before": report.bytes_before,
+            "bytes_after": report.bytes_after,
+        }));
+    }
+    println!(
+        "{} article(s) et {} marque(s) de lecture retirés, {} → {} octets",
+        report.removed_articles, report.removed_read_marks, report.bytes_before, report.bytes_after
+    );
+    Ok(())
+}
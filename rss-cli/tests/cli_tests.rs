@@ -0,0 +1,123 @@
+// The CLI resolves its data directory through XDG_CONFIG_HOME, which only applies on Linux.
+#![cfg(target_os = "linux")]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+fn temp_home() -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_cli_{}", nanos))
+}
+
+fn cli(home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rss-cli"))
+        .args(args)
+        .env("XDG_CONFIG_HOME", home)
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run rss-cli")
+}
+
+fn json(home: &Path, args: &[&str]) -> Value {
+    let out = cli(home, args);
+    assert!(
+        out.status.success(),
+        "{:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).expect("stdout is not JSON")
+}
+
+#[test]
+fn manages_subscriptions_in_the_shared_config_dir() {
+    let home = temp_home();
+    let added = json(
+        &home,
+        &[
+            "add",
+            "https://example.org/feed.xml",
+            "--title",
+            "Exemple",
+            "--json",
+        ],
+    );
+    let id = added["id"].as_str().unwrap().to_string();
+
+    // Same stores as the GUI: feeds.json in the readrss config directory.
+    let feeds_json = std::fs::read_to_string(home.join("readrss/feeds.json")).unwrap();
+    assert!(feeds_json.contains("https://example.org/feed.xml"));
+
+    let listed = json(&home, &["list", "--json"]);
+    assert_eq!(listed.as_array().unwrap().len(), 1);
+    assert_eq!(listed[0]["title"], "Exemple");
+
+    // Duplicates (even with a trailing slash) and plain HTTP are refused with a failing exit code.
+    let duplicate = cli(&home, &["add", "https://example.org/feed.xml/"]);
+    assert!(!duplicate.status.success());
+    assert!(String::from_utf8_lossy(&duplicate.stderr).contains("Exemple"));
+    assert!(!cli(&home, &["add", "http://example.org/other.xml"])
+        .status
+        .success());
+
+    let opml = home.join("export/feeds.opml");
+    let out = cli(&home, &["export-opml", opml.to_str().unwrap()]);
+    assert!(out.status.success());
+    assert!(std::fs::read_to_string(&opml)
+        .unwrap()
+        .contains(r#"xmlUrl="https://example.org/feed.xml""#));
+
+    let text = String::from_utf8(cli(&home, &["list"]).stdout).unwrap();
+    assert!(text.contains("Exemple"));
+
+    json(&home, &["remove", &id, "--json"]);
+    assert!(json(&home, &["list", "--json"])
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(!cli(&home, &["remove", &id]).status.success());
+}
+
+#[test]
+fn unread_and_mark_read_share_read_state() {
+    let home = temp_home();
+    let dir = home.join("readrss");
+    std::fs::create_dir_all(&dir).unwrap();
+    let articles = serde_json::json!({
+        "news": [
+            {"feed_id": "news", "title": "Premier", "url": "https://example.org/1",
+             "guid": "g1", "summary": null, "published_at": null},
+            {"feed_id": "news", "title": "Second", "url": "https://example.org/2",
+             "guid": "g2", "summary": null, "published_at": null}
+        ]
+    });
+    std::fs::write(dir.join("articles_store.json"), articles.to_string()).unwrap();
+
+    let unread = json(&home, &["unread", "--json"]);
+    assert_eq!(unread.as_array().unwrap().len(), 2);
+    let identity = unread
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|a| a["title"] == "Premier")
+        .unwrap()["identity"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    json(&home, &["mark-read", &identity, "--json"]);
+    let unread = json(&home, &["unread", "--json"]);
+    assert_eq!(unread.as_array().unwrap().len(), 1);
+    assert_eq!(unread[0]["title"], "Second");
+    assert!(std::fs::read_to_string(dir.join("read_store.json"))
+        .unwrap()
+        .contains(&identity));
+
+    assert!(!cli(&home, &["mark-read", "inconnu"]).status.success());
+}
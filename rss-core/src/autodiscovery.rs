@@ -0,0 +1,62 @@
+// ===
+//
+//
+// Autodécouverte des flux: une page HTML annonce ses flux par
+// `<link rel="alternate" type="application/rss+xml" href="…">` (ou `application/atom+xml`).
+//
+//
+// ===
+
+use url::Url;
+
+use crate::content_extractor::attr_value;
+use crate::sanitize::{find_tag_end, tag_name};
+
+// Types MIME d’un flux annoncé par une page.
+const FEED_LINK_TYPES: [&str; 3] = [
+    "application/rss+xml",
+    "application/atom+xml",
+    "application/rdf+xml",
+];
+
+// ===
+//
+//
+// Flux annoncés par une page, dans l’ordre du document, résolus par rapport à `base`
+// (sans doublons).
+//
+//
+// ===
+pub fn find_feed_links(html: &str, base: &Url) -> Vec<Url> {
+    let mut links: Vec<Url> = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        let Some(end) = find_tag_end(rest) else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        if tag_name(tag) != "link" || tag.starts_with('/') {
+            continue;
+        }
+        let is_alternate = attr_value(tag, "rel").is_some_and(|rel| {
+            rel.split_whitespace()
+                .any(|t| t.eq_ignore_ascii_case("alternate"))
+        });
+        let is_feed = attr_value(tag, "type").is_some_and(|t| {
+            let t = t.trim();
+            FEED_LINK_TYPES.iter().any(|f| t.eq_ignore_ascii_case(f))
+        });
+        if !is_alternate || !is_feed {
+            continue;
+        }
+        let Some(url) = attr_value(tag, "href").and_then(|href| base.join(href.trim()).ok()) else {
+            continue;
+        };
+        if matches!(url.scheme(), "http" | "https") && !links.contains(&url) {
+            links.push(url);
+        }
+    }
+    links
+}
@@ -1,15 +1,49 @@
+use chrono::NaiveTime;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+use url::{Host, Url};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+use crate::dedup::DEFAULT_TITLE_SIMILARITY;
+use crate::feed::Secret;
+use crate::poller::FeedFormat;
+use crate::preview::DEFAULT_READING_WPM;
+
+// ===
+//
+//
+// Version du format de config.json. Un fichier plus ancien (sans `version`: 0) passe par
+// `AppConfig::migrate` au chargement; toute évolution incompatible du format ajoute une étape
+// dans MIGRATIONS et incrémente ce numéro.
+//
+//
+// ===
+pub const CONFIG_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
+    pub version: u32,
     pub theme: ThemeConfig,
     pub feeds: FeedConfig,
     pub ui: UiConfig,
+    pub network: NetworkConfig,
+    pub sync: SyncMode,
+    pub integrations: IntegrationsConfig,
+    pub websub: WebSubConfig,
+    // Clés inconnues de cette version (écrites par une version plus récente), réécrites telles
+    // quelles.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ThemeConfig {
+    // Palette d’origine; passe à Custom dès qu’une couleur est modifiée à la main.
+    #[serde(default)]
+    pub preset: ThemePreset,
     pub background_color: [u8; 3],
     pub panel_color: [u8; 3],
     pub accent_color: [u8; 3],
@@ -19,34 +53,461 @@ pub struct ThemeConfig {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FeedConfig {
     pub update_interval_minutes: u64,
     pub max_articles_per_feed: usize,
     pub request_timeout_seconds: u64,
     pub retry_attempts: u8,
+    #[serde(default = "default_max_seen_per_feed")]
+    pub max_seen_per_feed: usize,
+    // Taille maximale du HTML gardé par article (contenu et résumé, chacun), au-delà tronqué.
+    #[serde(
+        default = "default_max_content_bytes",
+        alias = "max_entry_content_bytes"
+    )]
+    pub max_content_bytes: usize,
+    #[serde(default = "default_max_feed_bytes")]
+    pub max_feed_bytes: usize,
+    #[serde(default = "default_disable_after_failures")]
+    pub disable_after_failures: u32,
+    // Reporte dans feeds.json l’URL d’un flux redirigé de façon permanente (301/308).
+    #[serde(default = "default_true")]
+    pub auto_update_moved_feeds: bool,
+    // Requêtes au plus par minute et par hôte (PollConfig::per_host_rate_limit); None: sans limite.
+    #[serde(default)]
+    pub host_requests_per_minute: Option<u32>,
+    // Heures calmes [début, fin[ en heure locale (peut passer minuit): aucun polling planifié.
+    #[serde(default)]
+    pub quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    // Un rafraîchissement dès la fin des heures calmes si des cycles ont été sautés.
+    #[serde(default = "default_true")]
+    pub quiet_hours_catch_up: bool,
+    // Espace les relevés des flux silencieux (PollConfig::adaptive_polling), jusqu’à
+    // `max_adaptive_interval_minutes`.
+    #[serde(default)]
+    pub adaptive_polling: bool,
+    #[serde(default = "default_max_adaptive_interval_minutes")]
+    pub max_adaptive_interval_minutes: u64,
+    // Titres de flux coupés à cette longueur (graphèmes) à l’ajout (DataApi::set_max_title_chars).
+    #[serde(default = "default_max_title_chars")]
+    pub max_title_chars: usize,
+}
+
+fn default_max_seen_per_feed() -> usize {
+    crate::storage::DEFAULT_MAX_SEEN_PER_FEED
+}
+
+fn default_max_content_bytes() -> usize {
+    crate::poller::DEFAULT_MAX_CONTENT_BYTES
+}
+
+fn default_max_feed_bytes() -> usize {
+    crate::poller::DEFAULT_MAX_FEED_BYTES
+}
+
+fn default_disable_after_failures() -> u32 {
+    crate::poller::DEFAULT_DISABLE_AFTER_FAILURES
+}
+
+fn default_max_adaptive_interval_minutes() -> u64 {
+    crate::poller::DEFAULT_MAX_ADAPTIVE_INTERVAL.as_secs() / 60
+}
+
+fn default_max_title_chars() -> usize {
+    crate::data::DEFAULT_MAX_TITLE_CHARS
+}
+
+fn default_title_similarity() -> f32 {
+    DEFAULT_TITLE_SIMILARITY
+}
+
+fn default_reading_wpm() -> u32 {
+    DEFAULT_READING_WPM
+}
+
+fn default_true() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UiConfig {
     pub font_size: f32,
     pub left_panel_width: f32,
     pub show_article_preview: bool,
     pub articles_per_page: usize,
+    #[serde(default)]
+    pub sort_mode: SortMode,
+    #[serde(default)]
+    pub date_format: DateFormat,
+    // « Tout marquer comme lu » ne touche que les articles plus vieux que N jours (0 = tous).
+    #[serde(default)]
+    pub mark_read_older_than_days: u32,
+    // Fermer la fenêtre la masque dans la zone de notification (le polling continue).
+    #[serde(default)]
+    pub minimize_to_tray: bool,
+    // Taille de la fenêtre (points) à la fermeture, restaurée au démarrage.
+    #[serde(default)]
+    pub window_size: Option<[f32; 2]>,
+    // Position (coin haut gauche du cadre, points) et taille de l’écran qui affichait la
+    // fenêtre à la fermeture; la position est ramenée dans l’écran s’il a disparu.
+    #[serde(default)]
+    pub window_position: Option<[f32; 2]>,
+    #[serde(default)]
+    pub monitor_size: Option<[f32; 2]>,
+    // Langues retenues dans Discover (codes ISO 639-1); vide: toutes les langues.
+    #[serde(default)]
+    pub preferred_languages: Vec<String>,
+    #[serde(default)]
+    pub layout: Layout,
+    // Largeur (points) de la colonne liste en mode côte à côte, enregistrée à la fermeture.
+    pub split_list_width: f32,
+    // Images des articles téléchargées d’office dans la vue détaillée (non par défaut: chaque
+    // image est une requête vers le site de l’article).
+    pub load_images: bool,
+    // Langue de l’interface (code d’une table de rss-gui/locales, « fr », « en »...); None:
+    // langue du système, à défaut l’anglais.
+    #[serde(default)]
+    pub language: Option<String>,
+    // Vue agrégée: articles de flux différents aux titres presque identiques (moins de 48 h
+    // d’écart) repliés sous une seule carte, à partir de `title_similarity` (0 à 1).
+    #[serde(default)]
+    pub group_similar_titles: bool,
+    #[serde(default = "default_title_similarity")]
+    pub title_similarity: f32,
+    // Vitesse de lecture (mots par minute) pour le temps de lecture affiché des articles.
+    #[serde(default = "default_reading_wpm")]
+    pub reading_wpm: u32,
 }
 
-impl Default for ThemeConfig {
+// ===
+//
+//
+// Palettes de thème prêtes à l’emploi. Custom désigne des couleurs choisies une à une.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    #[default]
+    DarkVsCode,
+    Light,
+    Solarized,
+    HighContrast,
+    Custom,
+}
+
+impl ThemePreset {
+    pub const ALL: [ThemePreset; 5] = [
+        ThemePreset::DarkVsCode,
+        ThemePreset::Light,
+        ThemePreset::Solarized,
+        ThemePreset::HighContrast,
+        ThemePreset::Custom,
+    ];
+
+    // ===
+    //
+    //
+    // Couleurs complètes de la palette (None pour Custom, qui garde les couleurs courantes).
+    //
+    //
+    // ===
+    pub fn palette(self) -> Option<ThemeConfig> {
+        let [background, panel, accent, text, secondary, border] = match self {
+            ThemePreset::DarkVsCode => [
+                [30, 30, 30],
+                [37, 37, 38],
+                [0, 122, 204],
+                [204, 204, 204],
+                [150, 150, 150],
+                [60, 60, 60],
+            ],
+            ThemePreset::Light => [
+                [250, 250, 250],
+                [240, 240, 240],
+                [0, 95, 184],
+                [32, 32, 32],
+                [110, 110, 110],
+                [205, 205, 205],
+            ],
+            ThemePreset::Solarized => [
+                [0, 43, 54],
+                [7, 54, 66],
+                [38, 139, 210],
+                [147, 161, 161],
+                [101, 123, 131],
+                [28, 78, 90],
+            ],
+            ThemePreset::HighContrast => [
+                [0, 0, 0],
+                [12, 12, 12],
+                [255, 214, 0],
+                [255, 255, 255],
+                [210, 210, 210],
+                [255, 255, 255],
+            ],
+            ThemePreset::Custom => return None,
+        };
+        Some(ThemeConfig {
+            preset: self,
+            background_color: background,
+            panel_color: panel,
+            accent_color: accent,
+            text_color: text,
+            secondary_text_color: secondary,
+            border_color: border,
+        })
+    }
+}
+
+// ===
+//
+//
+// Ordre d’affichage de la liste d’articles.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+    ByFeed,
+    UnreadFirst,
+}
+
+// ===
+//
+//
+// Disposition de la vue articles: liste seule (la lecture remplace la liste) ou liste à
+// gauche et article sélectionné à droite.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Layout {
+    #[default]
+    SinglePane,
+    SplitHorizontal,
+}
+
+// ===
+//
+//
+// Affichage des dates dans la liste d’articles (la vue détaillée garde la date complète).
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DateFormat {
+    #[default]
+    Relative,
+    Absolute,
+    Both,
+}
+
+// ===
+//
+//
+// Réglages réseau du client HTTP (proxy, certificats, User-Agent).
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NetworkConfig {
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub allow_http: AllowHttp,
+}
+
+// ===
+//
+//
+// Flux autorisés en HTTP non chiffré (HTTPS toujours accepté). PrivateNetworks n’examine que
+// les hôtes donnés par leur adresse IP (RFC 1918, ULA fc00::/7) ou localhost, sans résolution
+// DNS. Un flux peut y déroger individuellement (FeedDescriptor::allow_insecure).
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowHttp {
+    Never,
+    #[default]
+    LoopbackOnly,
+    PrivateNetworks,
+    Always,
+}
+
+impl AllowHttp {
+    pub fn allows(self, url: &Url) -> bool {
+        if url.scheme() == "https" {
+            return true;
+        }
+        if url.scheme() != "http" {
+            return false;
+        }
+        let loopback = match url.host() {
+            Some(Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+            Some(Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        };
+        let private = match url.host() {
+            Some(Host::Ipv4(ip)) => ip.is_private(),
+            Some(Host::Ipv6(ip)) => ip.segments()[0] & 0xfe00 == 0xfc00,
+            _ => false,
+        };
+        match self {
+            AllowHttp::Never => false,
+            AllowHttp::LoopbackOnly => loopback,
+            AllowHttp::PrivateNetworks => loopback || private,
+            AllowHttp::Always => true,
+        }
+    }
+}
+
+// ===
+//
+//
+// Source des articles: polling local des flux, ou synchronisation avec un serveur Fever ou
+// compatible Google Reader (FreshRSS, Miniflux...) qui se charge lui-même de récupérer les flux.
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum SyncMode {
+    #[default]
+    Local,
+    Fever {
+        endpoint: String,
+        api_key: Secret,
+    },
+    #[serde(rename = "greader")]
+    GReader {
+        endpoint: String,
+        username: String,
+        password: Secret,
+    },
+}
+
+// ===
+//
+//
+// Services externes optionnels (lire plus tard...). Absents = fonctionnalité masquée.
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IntegrationsConfig {
+    #[serde(default)]
+    pub wallabag: Option<WallabagConfig>,
+    #[serde(default)]
+    pub starred_feed: StarredFeedConfig,
+}
+
+// ===
+//
+//
+// Republication des favoris dans un fichier RSS ou Atom (chemin vide: choisi par l’interface).
+// `auto_export` réécrit le fichier à chaque changement des favoris.
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct StarredFeedConfig {
+    pub path: String,
+    pub format: FeedFormat,
+    pub auto_export: bool,
+}
+
+// ===
+//
+//
+// Abonnements WebSub (push) aux flux qui annoncent un hub: un petit serveur HTTP reçoit les
+// vérifications et les nouveautés des hubs. Désactivé par défaut; l’adresse d’écoute doit être
+// joignable par les hubs, directement ou via `callback_base_url` (redirection de port, proxy).
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct WebSubConfig {
+    pub enabled: bool,
+    pub bind_address: String,
+    // Adresse publique du serveur vue par les hubs (None: http://<bind_address>).
+    pub callback_base_url: Option<String>,
+    // Durée d’abonnement demandée aux hubs (renouvelée avant l’échéance).
+    pub lease_seconds: u64,
+    // Un flux abonné n’est plus relevé qu’un cycle de polling sur `poll_interval_factor`.
+    pub poll_interval_factor: u32,
+}
+
+impl Default for WebSubConfig {
     fn default() -> Self {
         Self {
-            background_color: [30, 30, 30],
-            panel_color: [37, 37, 38],
-            accent_color: [0, 122, 204],
-            text_color: [204, 204, 204],
-            secondary_text_color: [150, 150, 150],
-            border_color: [60, 60, 60],
+            enabled: false,
+            bind_address: "127.0.0.1:8765".into(),
+            callback_base_url: None,
+            lease_seconds: 7 * 24 * 3600,
+            poll_interval_factor: 6,
         }
     }
 }
 
+// ===
+//
+//
+// Accès à une instance Wallabag: client OAuth2 créé dans « Gestion des clients API ».
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WallabagConfig {
+    pub url: String,
+    pub client_id: String,
+    pub client_secret: Secret,
+    pub username: String,
+    pub password: Secret,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            theme: ThemeConfig::default(),
+            feeds: FeedConfig::default(),
+            ui: UiConfig::default(),
+            network: NetworkConfig::default(),
+            sync: SyncMode::default(),
+            integrations: IntegrationsConfig::default(),
+            websub: WebSubConfig::default(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        ThemePreset::DarkVsCode
+            .palette()
+            .expect("built-in preset has a palette")
+    }
+}
+
 impl Default for FeedConfig {
     fn default() -> Self {
         Self {
@@ -54,6 +515,17 @@ impl Default for FeedConfig {
             max_articles_per_feed: 100,
             request_timeout_seconds: 10,
             retry_attempts: 3,
+            max_seen_per_feed: default_max_seen_per_feed(),
+            max_content_bytes: default_max_content_bytes(),
+            max_feed_bytes: default_max_feed_bytes(),
+            disable_after_failures: default_disable_after_failures(),
+            auto_update_moved_feeds: true,
+            host_requests_per_minute: None,
+            quiet_hours: None,
+            quiet_hours_catch_up: true,
+            adaptive_polling: false,
+            max_adaptive_interval_minutes: default_max_adaptive_interval_minutes(),
+            max_title_chars: default_max_title_chars(),
         }
     }
 }
@@ -65,6 +537,21 @@ impl Default for UiConfig {
             left_panel_width: 300.0,
             show_article_preview: true,
             articles_per_page: 20,
+            sort_mode: SortMode::default(),
+            date_format: DateFormat::default(),
+            mark_read_older_than_days: 0,
+            minimize_to_tray: false,
+            window_size: None,
+            window_position: None,
+            monitor_size: None,
+            preferred_languages: Vec::new(),
+            layout: Layout::default(),
+            split_list_width: 420.0,
+            load_images: false,
+            language: None,
+            group_similar_titles: false,
+            title_similarity: DEFAULT_TITLE_SIMILARITY,
+            reading_wpm: DEFAULT_READING_WPM,
         }
     }
 }
@@ -90,42 +577,139 @@ impl AppConfig {
     // ===
     //
     //
-    // Charge la configuration depuis le fichier, sinon crée et sauvegarde une configuration par défaut.
+    // Charge la configuration (voir `load_from_path`); un incident est écrit sur la sortie
+    // d’erreur. La GUI passe par `load_checked` pour l’afficher.
     //
     //
     // ===
     pub fn load() -> Self {
-        match Self::load_from_file() {
-            Ok(config) => config,
-            Err(e) => {
-                eprintln!(
-                    "Impossible de charger la configuration: {}. Utilisation des valeurs par défaut.",
-                    e
-                );
-                let default_config = Self::default();
-                if let Err(save_err) = default_config.save() {
+        let (config, issue) = Self::load_checked();
+        if let Some(issue) = issue {
+            eprintln!("Configuration: {}", issue);
+        }
+        config
+    }
+
+    pub fn load_checked() -> (Self, Option<ConfigIssue>) {
+        match Self::config_file_path() {
+            Ok(path) => Self::load_from_path(&path),
+            Err(e) => (
+                Self::default(),
+                Some(ConfigIssue::Unreadable {
+                    error: e.to_string(),
+                    backup: None,
+                }),
+            ),
+        }
+    }
+
+    // ===
+    //
+    //
+    // Charge `path` sans jamais perdre tout le fichier pour un réglage invalide: les sections
+    // et champs illisibles prennent leur valeur par défaut (et sont signalés), un ancien format
+    // est migré. Avant toute réécriture d’un fichier existant, sa copie est gardée dans
+    // `config.json.bak`. Fichier absent: valeurs par défaut enregistrées.
+    //
+    //
+    // ===
+    pub fn load_from_path(path: &Path) -> (Self, Option<ConfigIssue>) {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let config = Self::default();
+                if let Err(e) = config.save_to(path) {
                     eprintln!(
                         "Impossible de sauvegarder la configuration par défaut: {}",
-                        save_err
+                        e
                     );
                 }
-                default_config
+                return (config, None);
+            }
+            Err(e) => {
+                let issue = ConfigIssue::Unreadable {
+                    error: e.to_string(),
+                    backup: None,
+                };
+                return (Self::default(), Some(issue));
+            }
+        };
+
+        let raw: Value = match serde_json::from_str(&content) {
+            Ok(raw) => raw,
+            Err(e) => {
+                let backup = backup_config(path);
+                let config = Self::default();
+                if backup.is_some() {
+                    let _ = config.save_to(path);
+                }
+                return (
+                    config,
+                    Some(ConfigIssue::Unreadable {
+                        error: e.to_string(),
+                        backup,
+                    }),
+                );
+            }
+        };
+
+        let from_version = raw_version(&raw);
+        let (config, ignored) = Self::migrate_checked(raw);
+        if from_version >= CONFIG_VERSION && ignored.is_empty() {
+            return (config, None);
+        }
+        let backup = backup_config(path);
+        // Sans copie de l’original, on ne réécrit pas: le fichier reste tel quel.
+        if backup.is_some() {
+            if let Err(e) = config.save_to(path) {
+                eprintln!("Impossible d'enregistrer la configuration migrée: {}", e);
             }
         }
+        let issue = (!ignored.is_empty()).then_some(ConfigIssue::Ignored {
+            fields: ignored,
+            backup,
+        });
+        (config, issue)
     }
 
     // ===
     //
     //
-    // Lecture et désérialisation de la configuration à partir du fichier.
+    // Convertit un config.json brut, quelle que soit sa version, dans le format courant:
+    // étapes de MIGRATIONS depuis sa version, puis lecture section par section et champ par
+    // champ (un champ invalide garde sa valeur par défaut). Les clés de premier niveau
+    // inconnues sont conservées dans `extra`.
     //
     //
     // ===
-    fn load_from_file() -> Result<Self, Box<dyn std::error::Error>> {
-        let config_path = Self::config_file_path()?;
-        let config_content = std::fs::read_to_string(config_path)?;
-        let config: AppConfig = serde_json::from_str(&config_content)?;
-        Ok(config)
+    pub fn migrate(raw: Value) -> AppConfig {
+        Self::migrate_checked(raw).0
+    }
+
+    // Comme `migrate`, avec la liste des champs ignorés (« section.champ »).
+    fn migrate_checked(mut raw: Value) -> (AppConfig, Vec<String>) {
+        let from_version = raw_version(&raw);
+        for step in MIGRATIONS.iter().skip(from_version as usize) {
+            step(&mut raw);
+        }
+        let mut map = match raw {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        let mut ignored = Vec::new();
+        map.remove("version");
+        let config = AppConfig {
+            version: CONFIG_VERSION.max(from_version),
+            theme: lenient_section("theme", map.remove("theme"), &mut ignored),
+            feeds: lenient_section("feeds", map.remove("feeds"), &mut ignored),
+            ui: lenient_section("ui", map.remove("ui"), &mut ignored),
+            network: lenient_section("network", map.remove("network"), &mut ignored),
+            sync: lenient_section("sync", map.remove("sync"), &mut ignored),
+            integrations: lenient_section("integrations", map.remove("integrations"), &mut ignored),
+            websub: lenient_section("websub", map.remove("websub"), &mut ignored),
+            extra: map,
+        };
+        (config, ignored)
     }
 
     // ===
@@ -136,9 +720,12 @@ impl AppConfig {
     //
     // ===
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config_path = Self::config_file_path()?;
+        self.save_to(&Self::config_file_path()?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
         let config_json = serde_json::to_string_pretty(self)?;
-        std::fs::write(config_path, config_json)?;
+        std::fs::write(path, config_json)?;
         Ok(())
     }
 
@@ -166,6 +753,21 @@ impl AppConfig {
         self.save()
     }
 
+    // ===
+    //
+    //
+    // Met à jour la section réseau et persiste la modification.
+    //
+    //
+    // ===
+    pub fn update_network(
+        &mut self,
+        network: NetworkConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.network = network;
+        self.save()
+    }
+
     // ===
     //
     //
@@ -179,7 +781,81 @@ impl AppConfig {
     }
 }
 
+// Contraste minimal du texte courant selon les WCAG 2.1, niveau AA.
+pub const WCAG_AA_CONTRAST: f64 = 4.5;
+
+// ===
+//
+//
+// Rapport de contraste WCAG entre deux couleurs sRGB: de 1 (identiques) à 21 (noir et blanc).
+//
+//
+// ===
+pub fn contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+fn relative_luminance(color: [u8; 3]) -> f64 {
+    let [r, g, b] = color.map(|c| {
+        let c = f64::from(c) / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
 impl ThemeConfig {
+    // ===
+    //
+    //
+    // Applique une palette prédéfinie; Custom ne change que le préréglage affiché.
+    //
+    //
+    // ===
+    pub fn apply_preset(&mut self, preset: ThemePreset) {
+        match preset.palette() {
+            Some(palette) => *self = palette,
+            None => self.preset = preset,
+        }
+    }
+
+    // ===
+    //
+    //
+    // Thème sombre si l’arrière-plan est peu lumineux (vaut aussi pour les couleurs Custom).
+    //
+    //
+    // ===
+    pub fn is_dark(&self) -> bool {
+        let [r, g, b] = self.background_color.map(u32::from);
+        (299 * r + 587 * g + 114 * b) / 1000 < 128
+    }
+
+    // ===
+    //
+    //
+    // Plus faible contraste entre une couleur de texte (courant, secondaire, accent) et un
+    // fond (arrière-plan, panneau); à comparer à WCAG_AA_CONTRAST.
+    //
+    //
+    // ===
+    pub fn min_text_contrast(&self) -> f64 {
+        let texts = [
+            self.text_color,
+            self.secondary_text_color,
+            self.accent_color,
+        ];
+        let backgrounds = [self.background_color, self.panel_color];
+        texts
+            .iter()
+            .flat_map(|text| backgrounds.iter().map(|bg| contrast_ratio(*text, *bg)))
+            .fold(f64::INFINITY, f64::min)
+    }
+
     // ===
     //
     //
@@ -255,3 +931,127 @@ impl ThemeConfig {
     }
 }
 
+// ===
+//
+//
+// Incident au chargement de la configuration, à signaler à l’utilisateur. `backup` est la
+// copie du fichier d’origine quand il a été réécrit.
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigIssue {
+    // Fichier illisible (JSON invalide, droits...): toute la configuration par défaut.
+    Unreadable {
+        error: String,
+        backup: Option<PathBuf>,
+    },
+    // Réglages invalides remplacés par leur valeur par défaut, le reste est gardé.
+    Ignored {
+        fields: Vec<String>,
+        backup: Option<PathBuf>,
+    },
+}
+
+impl ConfigIssue {
+    pub fn backup(&self) -> Option<&Path> {
+        match self {
+            ConfigIssue::Unreadable { backup, .. } | ConfigIssue::Ignored { backup, .. } => {
+                backup.as_deref()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigIssue::Unreadable { error, .. } => {
+                write!(f, "unreadable file ({}), defaults used", error)?
+            }
+            ConfigIssue::Ignored { fields, .. } => write!(
+                f,
+                "invalid settings reset to default: {}",
+                fields.join(", ")
+            )?,
+        }
+        if let Some(backup) = self.backup() {
+            write!(f, "; previous file saved as {}", backup.display())?;
+        }
+        Ok(())
+    }
+}
+
+// ===
+//
+//
+// Étapes de migration: MIGRATIONS[n] fait passer un fichier de la version n à n + 1.
+//
+//
+// ===
+const MIGRATIONS: [fn(&mut Value); CONFIG_VERSION as usize] = [migrate_v0];
+
+// v0 (avant `version`): `ui.last_selected_feed` a été remplacé par la session (session.json).
+fn migrate_v0(raw: &mut Value) {
+    if let Some(ui) = raw.get_mut("ui").and_then(Value::as_object_mut) {
+        ui.remove("last_selected_feed");
+    }
+}
+
+fn raw_version(raw: &Value) -> u32 {
+    raw.get("version")
+        .and_then(Value::as_u64)
+        .map_or(0, |v| v.min(u64::from(u32::MAX)) as u32)
+}
+
+// ===
+//
+//
+// Lit une section en gardant tout ce qui est valide: si la section entière ne se lit pas,
+// ses champs sont repris un à un sur les valeurs par défaut et ceux qui la rendent
+// illisible sont ignorés (ajoutés à `ignored`).
+//
+//
+// ===
+fn lenient_section<T>(name: &str, raw: Option<Value>, ignored: &mut Vec<String>) -> T
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let Some(raw) = raw else {
+        return T::default();
+    };
+    if let Ok(section) = serde_json::from_value(raw.clone()) {
+        return section;
+    }
+    let (Value::Object(fields), Ok(Value::Object(mut merged))) =
+        (raw, serde_json::to_value(T::default()))
+    else {
+        ignored.push(name.to_string());
+        return T::default();
+    };
+    for (key, value) in fields {
+        let mut candidate = merged.clone();
+        candidate.insert(key.clone(), value);
+        if serde_json::from_value::<T>(Value::Object(candidate.clone())).is_ok() {
+            merged = candidate;
+        } else {
+            ignored.push(format!("{}.{}", name, key));
+        }
+    }
+    serde_json::from_value(Value::Object(merged)).unwrap_or_default()
+}
+
+// Copie le fichier actuel en `<nom>.bak` avant réécriture; None si la copie échoue.
+fn backup_config(path: &Path) -> Option<PathBuf> {
+    let backup = match path.extension() {
+        Some(ext) => path.with_extension(format!("{}.bak", ext.to_string_lossy())),
+        None => path.with_extension("bak"),
+    };
+    match std::fs::copy(path, &backup) {
+        Ok(_) => Some(backup),
+        Err(e) => {
+            eprintln!("Impossible de sauvegarder {}: {}", backup.display(), e);
+            None
+        }
+    }
+}
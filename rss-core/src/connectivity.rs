@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Client;
+use tracing::debug;
+use url::Url;
+
+use crate::feed::FeedDescriptor;
+use crate::poller::PollConfig;
+use crate::stats::{FetchErrorKind, FetchOutcome};
+
+// ===
+//
+//
+// Détection du mode hors ligne. Un tour de récupération où aucun serveur n’a répondu et où
+// des erreurs réseau ont touché plusieurs serveurs distincts (`PollConfig::offline_after_hosts`,
+// ou tous les serveurs relevés s’il y en a moins) fait passer hors ligne; une réponse, même
+// une erreur HTTP, fait repasser en ligne. L’état est partagé par le poller et les
+// rafraîchissements manuels (`PollConfig::connectivity`), et chaque changement est annoncé
+// par Event::ConnectivityChanged. Hors ligne, le poller ne lance plus ses cycles planifiés:
+// une sonde légère (probe) interroge quelques serveurs à `offline_probe_interval`.
+//
+//
+// ===
+
+// Serveurs distincts injoignables avant de passer hors ligne.
+pub const DEFAULT_OFFLINE_AFTER_HOSTS: usize = 3;
+
+// Intervalle de la sonde de connectivité hors ligne.
+pub const DEFAULT_OFFLINE_PROBE_INTERVAL: Duration = Duration::from_secs(60);
+
+// État en ligne / hors ligne partagé (clones liés), en ligne au départ.
+#[derive(Debug, Clone, Default)]
+pub struct Connectivity {
+    offline: Arc<AtomicBool>,
+}
+
+impl Connectivity {
+    pub fn is_online(&self) -> bool {
+        !self.offline.load(Ordering::SeqCst)
+    }
+
+    // Enregistre l’état constaté; Some(en ligne) s’il a changé (à annoncer), None sinon.
+    pub fn set_online(&self, online: bool) -> Option<bool> {
+        let was_offline = self.offline.swap(!online, Ordering::SeqCst);
+        (was_offline == online).then_some(online)
+    }
+}
+
+// Serveur d’un flux (hôte et port): clé des serveurs injoignables.
+fn server_key(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let host = url.host_str()?.to_ascii_lowercase();
+    Some(match url.port_or_known_default() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host,
+    })
+}
+
+// ===
+//
+//
+// Bilan de connectivité d’un tour: serveurs ayant répondu ou non, au fil des récupérations.
+// Le seuil est plafonné au nombre de serveurs distincts des flux du tour.
+//
+//
+// ===
+#[derive(Debug, Default)]
+pub struct ConnectivityCheck {
+    unreachable: HashSet<String>,
+    answered: bool,
+    threshold: usize,
+}
+
+impl ConnectivityCheck {
+    pub fn new<'a>(feeds: impl IntoIterator<Item = &'a FeedDescriptor>, cfg: &PollConfig) -> Self {
+        let servers: HashSet<String> = feeds
+            .into_iter()
+            .filter_map(|f| server_key(&f.url))
+            .collect();
+        Self {
+            threshold: cfg.offline_after_hosts.min(servers.len()).max(1),
+            ..Self::default()
+        }
+    }
+
+    pub fn record(&mut self, feed: &FeedDescriptor, outcome: &FetchOutcome) {
+        if outcome.error_kind == Some(FetchErrorKind::Network) && outcome.http_status.is_none() {
+            if let Some(server) = server_key(&feed.url) {
+                self.unreachable.insert(server);
+            }
+        } else {
+            self.answered = true;
+        }
+    }
+
+    // Some(false) hors ligne, Some(true) en ligne, None si le tour ne permet pas de trancher.
+    pub fn verdict(&self) -> Option<bool> {
+        if self.answered {
+            Some(true)
+        } else if self.unreachable.len() >= self.threshold {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+// ===
+//
+//
+// Sonde hors ligne: une requête HEAD vers le flux de quelques serveurs distincts (au plus
+// `offline_after_hosts`, ceux que la politique HTTP autorise). Une réponse quelconque
+// suffit pour repasser en ligne; aucun évènement de flux, aucun échec compté.
+//
+//
+// ===
+pub(crate) async fn probe(client: &Client, feeds: &[FeedDescriptor], cfg: &PollConfig) -> bool {
+    let mut servers = HashSet::new();
+    let targets = feeds.iter().filter(|f| !f.disabled).filter_map(|f| {
+        let url = Url::parse(&f.url).ok()?;
+        let allowed = f.allow_insecure || cfg.allow_http.allows(&url);
+        (allowed && servers.insert(server_key(&f.url)?)).then_some(url)
+    });
+    for url in targets.take(cfg.offline_after_hosts.max(1)) {
+        match client.head(url).timeout(cfg.request_timeout).send().await {
+            Ok(_) => return true,
+            Err(e) => debug!(error = %e.without_url(), "connectivity probe failed"),
+        }
+    }
+    false
+}
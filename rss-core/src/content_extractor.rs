@@ -0,0 +1,526 @@
+// ===
+//
+//
+// Extraction du contenu lisible d’une page d’article (« récupérer l’article complet »), pour les
+// flux qui ne publient qu’un résumé. Heuristique inspirée de Readability: on retire le bruit
+// (scripts, navigation, barres latérales, commentaires...), on note chaque paragraphe selon sa
+// longueur, on crédite son conteneur et le conteneur au-dessus, puis on garde le conteneur le
+// mieux noté, pénalisé par sa densité de liens.
+//
+//
+// ===
+
+use scraper::{Html, Selector};
+use url::Url;
+
+use crate::error::PollError;
+use crate::sanitize::{find_tag_end, sanitize_html, tag_name};
+
+// Balises retirées avec tout leur contenu avant l’analyse.
+const STRIPPED_TAGS: &[&str] = &[
+    "head", "script", "style", "noscript", "template", "nav", "aside", "header", "footer", "form",
+    "iframe", "svg", "button", "select",
+];
+
+// Balises sans fermeture (elles n’ouvrent pas de niveau).
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+// Conteneurs pouvant porter l’article.
+const CANDIDATE_TAGS: &[&str] = &["article", "main", "section", "div", "td", "body"];
+
+// Blocs de texte notés.
+const TEXT_BLOCK_TAGS: &[&str] = &["p", "pre"];
+
+// Blocs qui ferment implicitement un <p> resté ouvert.
+const CLOSES_PARAGRAPH: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "ul",
+    "ol",
+    "pre",
+    "blockquote",
+    "table",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "figure",
+];
+
+// Indices de class/id signalant un bloc annexe, sauf s’ils contiennent aussi un indice positif.
+const UNLIKELY_HINTS: &[&str] = &[
+    "comment",
+    "sidebar",
+    "footer",
+    "share",
+    "related",
+    "promo",
+    "sponsor",
+    "newsletter",
+    "social",
+    "menu",
+    "breadcrumb",
+    "cookie",
+    "banner",
+    "popup",
+    "nav",
+];
+const LIKELY_HINTS: &[&str] = &[
+    "article", "content", "main", "post", "entry", "story", "text",
+];
+
+// Paragraphe ignoré en dessous de cette longueur (légendes, boutons, mentions).
+const MIN_BLOCK_CHARS: usize = 25;
+
+// Score minimal du meilleur conteneur; en dessous, la page n’est pas un article exploitable.
+const MIN_ARTICLE_SCORE: f64 = 200.0;
+
+// ===
+//
+//
+// Extrait le HTML lisible d’une page; les liens et images relatifs sont résolus contre
+// `base_url` et le résultat est nettoyé comme le contenu des flux. None si aucun bloc de texte
+// suffisant n’est trouvé.
+//
+//
+// ===
+pub fn extract_readable(html: &str, base_url: &Url) -> Option<String> {
+    let cleaned = strip_noise(html);
+    let (start, end) = best_candidate(&cleaned)?;
+    let content = sanitize_html(&absolutize_urls(&cleaned[start..end], base_url));
+    let content = content.trim();
+    (!content.is_empty()).then(|| content.to_string())
+}
+
+// ===
+//
+//
+// Sélecteur CSS choisi pour un flux (FeedDescriptor::content_selector), quand l’heuristique
+// ne trouve pas le bon conteneur. Syntaxe des sélecteurs CSS de scraper (types, `#id`,
+// `.classe`, attributs, combinateurs, listes séparées par des virgules); un sélecteur qui ne
+// s’analyse pas est refusé à l’enregistrement.
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentSelector {
+    source: String,
+    selector: Selector,
+}
+
+impl ContentSelector {
+    pub fn parse(selector: &str) -> Result<Self, PollError> {
+        let source = selector.trim();
+        let invalid = |reason: String| PollError::InvalidSelector {
+            selector: selector.to_string(),
+            reason,
+        };
+        if source.is_empty() {
+            return Err(invalid("empty selector".to_string()));
+        }
+        Selector::parse(source)
+            .map(|parsed| Self {
+                source: source.to_string(),
+                selector: parsed,
+            })
+            .map_err(|e| invalid(e.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+// ===
+//
+//
+// Remplace l’heuristique quand le flux a un sélecteur: contenu (innerHTML) du premier élément
+// correspondant dans l’ordre du document, liens résolus et nettoyé comme extract_readable.
+// None si rien ne correspond ou si l’élément est vide.
+//
+//
+// ===
+pub fn extract_selected(html: &str, selector: &ContentSelector, base_url: &Url) -> Option<String> {
+    let inner = Html::parse_document(html)
+        .select(&selector.selector)
+        .next()?
+        .inner_html();
+    let content = sanitize_html(&absolutize_urls(&inner, base_url));
+    let content = content.trim();
+    (!content.is_empty()).then(|| content.to_string())
+}
+
+// Niveau ouvert pendant l’analyse: totaux du sous-arbre et score propre au conteneur.
+struct Frame {
+    name: String,
+    inner_start: usize,
+    text: usize,
+    link_text: usize,
+    score: f64,
+}
+
+// ===
+//
+//
+// Retire commentaires et éléments bruyants (balises de STRIPPED_TAGS, class/id annexes).
+//
+//
+// ===
+fn strip_noise(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        out.push_str(&rest[..lt]);
+        rest = &rest[lt..];
+        if rest.starts_with("<!--") {
+            rest = match rest.find("-->") {
+                Some(end) => &rest[end + 3..],
+                None => "",
+            };
+            continue;
+        }
+        let Some(tag_end) = find_tag_end(rest) else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[1..tag_end];
+        let after = &rest[tag_end + 1..];
+        let opening = !tag.starts_with(['/', '!', '?']);
+        let name = tag_name(tag);
+        if opening && (STRIPPED_TAGS.contains(&name.as_str()) || is_unlikely(&name, tag)) {
+            rest = if is_self_closing(&name, tag) {
+                after
+            } else {
+                skip_element(after, &name)
+            };
+            continue;
+        }
+        out.push_str(&rest[..tag_end + 1]);
+        rest = after;
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_self_closing(name: &str, tag: &str) -> bool {
+    VOID_TAGS.contains(&name) || tag.trim_end().ends_with('/')
+}
+
+fn is_unlikely(name: &str, tag: &str) -> bool {
+    if matches!(name, "html" | "body" | "article" | "main") {
+        return false;
+    }
+    let hints = format!(
+        "{} {}",
+        attr_value(tag, "class").unwrap_or_default(),
+        attr_value(tag, "id").unwrap_or_default()
+    )
+    .to_ascii_lowercase();
+    UNLIKELY_HINTS.iter().any(|h| hints.contains(h))
+        && !LIKELY_HINTS.iter().any(|h| hints.contains(h))
+}
+
+// ===
+//
+//
+// Avance après la fermeture de l’élément `name` ouvert juste avant `s`, en tenant compte des
+// éléments homonymes imbriqués. Sans fermeture, seule la balise ouvrante est retirée.
+//
+//
+// ===
+fn skip_element<'a>(s: &'a str, name: &str) -> &'a str {
+    match closing_tag(s, name) {
+        Some((_, end)) => &s[end..],
+        // Contenu brut non fermé: tout le reste est retiré.
+        None if matches!(name, "script" | "style" | "template") => "",
+        None => s,
+    }
+}
+
+// ===
+//
+//
+// Début et fin de la balise fermant l’élément `name` ouvert juste avant `s`, en tenant compte
+// des éléments homonymes imbriqués (sauf contenu brut: script, style, template).
+//
+//
+// ===
+fn closing_tag(s: &str, name: &str) -> Option<(usize, usize)> {
+    // Contenu brut: pas de balises imbriquées à suivre.
+    if matches!(name, "script" | "style" | "template") {
+        let needle = format!("</{}", name);
+        let start = s
+            .as_bytes()
+            .windows(needle.len())
+            .position(|w| w.eq_ignore_ascii_case(needle.as_bytes()))?;
+        return s[start..].find('>').map(|end| (start, start + end + 1));
+    }
+    let mut depth = 1usize;
+    let mut pos = 0;
+    while let Some(lt) = s[pos..].find('<') {
+        let at = pos + lt;
+        // Balises commentées: ignorées comme dans ContentSelector::find.
+        if s[at..].starts_with("<!--") {
+            pos = s[at..].find("-->").map_or(s.len(), |end| at + end + 3);
+            continue;
+        }
+        let tag_end = find_tag_end(&s[at..])?;
+        let tag = &s[at + 1..at + tag_end];
+        pos = at + tag_end + 1;
+        if tag_name(tag) != name {
+            continue;
+        }
+        if tag.starts_with('/') {
+            depth -= 1;
+            if depth == 0 {
+                return Some((at, pos));
+            }
+        } else if !tag.trim_end().ends_with('/') {
+            depth += 1;
+        }
+    }
+    None
+}
+
+// ===
+//
+//
+// Parcourt le HTML nettoyé et renvoie les bornes du contenu du conteneur le mieux noté.
+//
+//
+// ===
+fn best_candidate(html: &str) -> Option<(usize, usize)> {
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut best: Option<(f64, usize, usize)> = None;
+    let mut pos = 0;
+    while let Some(lt) = html[pos..].find('<') {
+        let at = pos + lt;
+        add_text(&mut stack, &html[pos..at]);
+        let Some(tag_end) = find_tag_end(&html[at..]) else {
+            pos = html.len();
+            break;
+        };
+        let tag = &html[at + 1..at + tag_end];
+        pos = at + tag_end + 1;
+        if tag.starts_with(['!', '?']) {
+            continue;
+        }
+        let name = tag_name(tag);
+        if let Some(closing) = tag.strip_prefix('/').map(|_| name.as_str()) {
+            if let Some(idx) = stack.iter().rposition(|f| f.name == closing) {
+                while stack.len() > idx {
+                    close_frame(&mut stack, at, &mut best);
+                }
+            }
+            continue;
+        }
+        if CLOSES_PARAGRAPH.contains(&name.as_str()) && stack.last().is_some_and(|f| f.name == "p")
+        {
+            close_frame(&mut stack, at, &mut best);
+        }
+        if is_self_closing(&name, tag) {
+            continue;
+        }
+        stack.push(Frame {
+            name,
+            inner_start: pos,
+            text: 0,
+            link_text: 0,
+            score: 0.0,
+        });
+    }
+    add_text(&mut stack, &html[pos..]);
+    while !stack.is_empty() {
+        close_frame(&mut stack, html.len(), &mut best);
+    }
+    best.filter(|(score, _, _)| *score >= MIN_ARTICLE_SCORE)
+        .map(|(_, start, end)| (start, end))
+}
+
+fn add_text(stack: &mut [Frame], text: &str) {
+    let len: usize = text.split_whitespace().map(|w| w.chars().count() + 1).sum();
+    if let Some(frame) = stack.last_mut() {
+        frame.text += len;
+    }
+}
+
+// ===
+//
+//
+// Ferme le niveau au sommet: ses totaux remontent au parent, un paragraphe crédite les deux
+// conteneurs au-dessus de lui, et un conteneur est comparé au meilleur candidat.
+//
+//
+// ===
+fn close_frame(stack: &mut Vec<Frame>, inner_end: usize, best: &mut Option<(f64, usize, usize)>) {
+    let Some(frame) = stack.pop() else {
+        return;
+    };
+    if let Some(parent) = stack.last_mut() {
+        parent.text += frame.text;
+        parent.link_text += if frame.name == "a" {
+            frame.text
+        } else {
+            frame.link_text
+        };
+    }
+    if TEXT_BLOCK_TAGS.contains(&frame.name.as_str()) && frame.text >= MIN_BLOCK_CHARS {
+        let mut weight = 1.0;
+        for container in stack
+            .iter_mut()
+            .rev()
+            .filter(|f| CANDIDATE_TAGS.contains(&f.name.as_str()))
+            .take(2)
+        {
+            container.score += frame.text as f64 * weight;
+            weight = 0.5;
+        }
+    }
+    if CANDIDATE_TAGS.contains(&frame.name.as_str()) && frame.score > 0.0 {
+        let link_density = frame.link_text as f64 / frame.text.max(1) as f64;
+        let score = frame.score * (1.0 - link_density.min(1.0));
+        if best.is_none_or(|(s, _, _)| score > s) {
+            *best = Some((score, frame.inner_start, inner_end));
+        }
+    }
+}
+
+// ===
+//
+//
+// Résout les attributs href/src relatifs contre l’URL de la page; les images chargées en
+// différé (data-src) reçoivent leur vraie source.
+//
+//
+// ===
+fn absolutize_urls(html: &str, base: &Url) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        out.push_str(&rest[..lt]);
+        rest = &rest[lt..];
+        let Some(tag_end) = find_tag_end(rest) else {
+            break;
+        };
+        let tag = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+        if tag.starts_with(['/', '!', '?']) {
+            out.push('<');
+            out.push_str(tag);
+            out.push('>');
+            continue;
+        }
+        out.push('<');
+        out.push_str(&rewrite_tag_urls(tag, base));
+        out.push('>');
+    }
+    out.push_str(rest);
+    out
+}
+
+fn rewrite_tag_urls(tag: &str, base: &Url) -> String {
+    let name_len = tag
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or(tag.len());
+    let attrs = parse_attributes(&tag[name_len..]);
+    let lazy_src = attrs
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case("data-src"))
+        .and_then(|(_, v)| v.clone());
+
+    let mut out = tag[..name_len].to_string();
+    for (name, value) in &attrs {
+        let lname = name.to_ascii_lowercase();
+        let value = match (lname.as_str(), value) {
+            ("src", _) if lazy_src.is_some() => lazy_src.clone(),
+            _ => value.clone(),
+        };
+        out.push(' ');
+        out.push_str(name);
+        if let Some(value) = value {
+            let value = if lname == "href" || lname == "src" {
+                resolve(base, &value)
+            } else {
+                value
+            };
+            out.push_str("=\"");
+            out.push_str(&value.replace('"', "&quot;"));
+            out.push('"');
+        }
+    }
+    if lazy_src.is_some() && !attrs.iter().any(|(n, _)| n.eq_ignore_ascii_case("src")) {
+        out.push_str(" src=\"");
+        out.push_str(
+            &resolve(base, lazy_src.as_deref().unwrap_or_default()).replace('"', "&quot;"),
+        );
+        out.push('"');
+    }
+    if tag.trim_end().ends_with('/') {
+        out.push_str(" /");
+    }
+    out
+}
+
+fn resolve(base: &Url, value: &str) -> String {
+    let value = value.trim();
+    if Url::parse(value).is_ok() {
+        return value.to_string();
+    }
+    base.join(value)
+        .map(String::from)
+        .unwrap_or_else(|_| value.to_string())
+}
+
+// Attributs d’une balise (nom, valeur éventuelle), guillemets retirés.
+fn parse_attributes(mut rest: &str) -> Vec<(String, Option<String>)> {
+    let mut attrs = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            break;
+        }
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+        let mut value = None;
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let (v, remaining) = match after_eq.chars().next() {
+                Some(q @ ('"' | '\'')) => match after_eq[1..].find(q) {
+                    Some(end) => (&after_eq[1..end + 1], &after_eq[end + 2..]),
+                    None => (&after_eq[1..], ""),
+                },
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..end], &after_eq[end..])
+                }
+            };
+            value = Some(v.to_string());
+            rest = remaining;
+        }
+        if !name.is_empty() {
+            attrs.push((name.to_string(), value));
+        }
+    }
+    attrs
+}
+
+// Valeur d’un attribut d’une balise (nom insensible à la casse), guillemets retirés.
+pub fn attr_value(tag: &str, name: &str) -> Option<String> {
+    let name_len = tag
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or(tag.len());
+    parse_attributes(&tag[name_len..])
+        .into_iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .and_then(|(_, v)| v)
+}
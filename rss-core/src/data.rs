@@ -1,16 +1,232 @@
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
+use chrono::{DateTime, Utc};
+use reqwest::Client;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tokio::sync::RwLock;
 use tracing::{debug, warn};
 
-use crate::feed::{add_feed, list_feeds, remove_feed, FeedDescriptor, FeedEntry, SharedFeedList};
+use crate::dedup::{dedup_entries, DedupedEntry};
+use crate::error::{AddFeedError, DataError, RuleError, StateError};
+use crate::export::{unique_export_path, ExportFormat};
+use crate::favicon::{fetch_favicon, icon_file_name, site_root, ICON_REFRESH_INTERVAL};
+use crate::feed::{
+    add_feed, list_feeds, mute_feed, record_new_articles, reenable_feed, remove_feeds,
+    reorder_feeds, try_add_feed, update_feed_metadata, update_feed_title, update_feed_url,
+    update_feeds, FeedDescriptor, FeedEntry, FeedMeta, FeedUpdate, RetentionPolicy, SharedFeedList,
+};
+use crate::feed_writer::{render_atom, render_rss, ChannelMeta};
+use crate::poller::FeedFormat;
+use crate::rules::{FilterRule, RuleSet};
+use crate::secrets::{FeedSecret, FeedSecrets};
+use crate::session::SessionState;
+use crate::stats::{compute_stats, FeedStats, FetchOutcome, Stats, StatsRange};
+use crate::storage::{write_atomic, write_atomic_private, SeenData, SeenStatus, SeenStore};
+
+// Limite par défaut du cache d’articles par flux (surchargée via FeedConfig).
+pub const DEFAULT_MAX_ARTICLES_PER_FEED: usize = 300;
+
+// Longueur maximale (en graphèmes) des titres de flux enregistrés (surchargée via FeedConfig).
+pub const DEFAULT_MAX_TITLE_CHARS: usize = 200;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct ReadData {
     read: HashMap<String, HashSet<String>>,
+    #[serde(default)]
+    starred: HashMap<String, HashSet<String>>,
+    // Favoris communs au serveur de synchronisation et au lecteur à la fin du dernier cycle,
+    // par service (préfixe des identifiants): base de la réconciliation des favoris.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    synced_starred: HashMap<String, HashSet<String>>,
+}
+
+// ===
+//
+//
+// Ligne du journal des marques de lecture (read_store.journal): une action unitaire, ajoutée
+// et synchronisée sur disque à chaque clic au lieu de réécrire read_store.json, puis rejouée
+// sur ce fichier au chargement.
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ReadMark {
+    Read { feed_id: String, key: String },
+    Star { feed_id: String, key: String },
+    Unstar { feed_id: String, key: String },
+}
+
+impl ReadData {
+    // Applique une marque; true si l’état a changé.
+    fn apply(&mut self, mark: &ReadMark) -> bool {
+        match mark {
+            ReadMark::Read { feed_id, key } => self
+                .read
+                .entry(feed_id.clone())
+                .or_default()
+                .insert(key.clone()),
+            ReadMark::Star { feed_id, key } => self
+                .starred
+                .entry(feed_id.clone())
+                .or_default()
+                .insert(key.clone()),
+            ReadMark::Unstar { feed_id, key } => self
+                .starred
+                .get_mut(feed_id)
+                .is_some_and(|set| set.remove(key)),
+        }
+    }
+}
+
+// Lignes de journal au-delà desquelles read_store.json est réécrit et le journal vidé.
+pub const READ_JOURNAL_COMPACT_AFTER: usize = 500;
+
+// Nombre maximal de positions de lecture conservées (les plus anciennes partent d’abord).
+pub const MAX_READING_POSITIONS: usize = 200;
+
+// Position de défilement d’un article dans la vue détaillée (du plus ancien au plus récent).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct ReadingPosition {
+    feed_id: String,
+    key: String,
+    offset: f32,
+}
+
+// Fichiers réécrits par DataApi; ceux dont la dernière écriture a échoué sont retentés à la
+// prochaine écriture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Store {
+    Feeds,
+    Read,
+    Articles,
+    Stats,
+    Positions,
+    Rules,
+}
+
+impl Store {
+    const ALL: [Store; 6] = [
+        Store::Feeds,
+        Store::Read,
+        Store::Articles,
+        Store::Stats,
+        Store::Positions,
+        Store::Rules,
+    ];
+}
+
+// Version du format d’export de l’état; un fichier plus récent est refusé à l’import.
+pub const STATE_SCHEMA_VERSION: u32 = 1;
+
+// ===
+//
+//
+// État complet exporté pour migrer vers une autre machine: flux, articles lus, cache
+// d’articles et identités vues, dans un unique JSON versionné.
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateBundle {
+    pub schema_version: u32,
+    pub exported_at: DateTime<Utc>,
+    #[serde(default)]
+    pub feeds: Vec<FeedDescriptor>,
+    #[serde(default)]
+    pub read: HashMap<String, HashSet<String>>,
+    #[serde(default)]
+    pub articles: HashMap<String, Vec<FeedEntry>>,
+    #[serde(default)]
+    pub seen: SeenData,
+    #[serde(default)]
+    pub starred: HashMap<String, HashSet<String>>,
+}
+
+// Bilan d’un import: ce qui a été ajouté ou remplacé localement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub feeds_added: usize,
+    pub read_added: usize,
+    pub articles_added: usize,
+    pub articles_updated: usize,
+}
+
+// ===
+//
+//
+// Copie en mémoire de tout ce que la suppression d’un flux efface (descripteur et rang dans la
+// liste, articles en cache, marques lu/favori, positions de lecture, santé, “vus”, icône,
+// cookie), pour l’annuler avec DataApi::restore_feed.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct FeedBackup {
+    pub feed: FeedDescriptor,
+    index: usize,
+    articles: Option<Vec<FeedEntry>>,
+    read: Option<HashSet<String>>,
+    starred: Option<HashSet<String>>,
+    positions: Vec<ReadingPosition>,
+    stats: Option<FeedStats>,
+    seen: SeenData,
+    icon: Option<Vec<u8>>,
+    secret: Option<FeedSecret>,
+}
+
+// ===
+//
+//
+// Marques « lu » ajoutées par un marquage groupé (identités par flux), sans celles qui
+// existaient déjà: DataApi::unmark_read les retire pour annuler l’opération.
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadMarks {
+    marks: HashMap<String, HashSet<String>>,
+}
+
+impl ReadMarks {
+    pub fn len(&self) -> usize {
+        self.marks.values().map(HashSet::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Bilan d’un nettoyage du cache: articles supprimés et octets gagnés sur les fichiers JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CleanupReport {
+    pub removed: usize,
+    pub bytes_saved: u64,
+}
+
+// Âge au-delà duquel DataApi::compact oublie les marques « lu » et “vus” sans article en cache.
+pub const COMPACT_MARKS_AFTER: std::time::Duration = std::time::Duration::from_secs(90 * 24 * 3600);
+
+// ===
+//
+//
+// Bilan d’un compactage des données: articles retirés (flux supprimés, doublons), marques
+// « lu » oubliées, et taille sérialisée du cache d’articles, de l’état de lecture et des
+// “vus” avant et après.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    pub removed_articles: usize,
+    pub removed_read_marks: usize,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -21,13 +237,40 @@ pub struct DataApi {
     read_path: PathBuf,
     articles_inner: Arc<RwLock<HashMap<String, Vec<FeedEntry>>>>, // feed_id -> entries cache
     articles_path: PathBuf,
+    stats_inner: Arc<RwLock<HashMap<String, FeedStats>>>, // feed_id -> santé du flux
+    stats_path: PathBuf,
+    positions_inner: Arc<RwLock<Vec<ReadingPosition>>>,
+    positions_path: PathBuf,
+    rules_inner: Arc<RwLock<RuleSet>>, // règles de filtrage compilées
+    rules_path: PathBuf,
+    session_path: PathBuf,
+    secrets: FeedSecrets, // cookies des flux, hors de feeds.json
+    secrets_path: PathBuf,
+    icons_dir: PathBuf, // icônes des flux, un fichier par feed_id
+    max_articles_per_feed: Arc<AtomicUsize>,
+    max_title_chars: Arc<AtomicUsize>,
+    read_writes: Arc<AtomicUsize>,
+    // Incrémenté à chaque changement des favoris (réexport automatique du flux des favoris).
+    stars_revision: Arc<AtomicUsize>,
+    // Incrémenté à chaque changement de l’état de lecture (vue de la liste gardée par la GUI).
+    read_revision: Arc<AtomicUsize>,
+    // Journal des marques unitaires; le verrou sérialise ajouts et réécritures de read_store.json.
+    journal_path: PathBuf,
+    journal_lock: Arc<tokio::sync::Mutex<()>>,
+    journal_len: Arc<AtomicUsize>,
+    seen: SeenStore, // identités vues par le poller (seen_store.json)
+    // false pour une API en mémoire (DataApi::in_memory): aucun fichier lu ni écrit.
+    persistent: bool,
+    // Fichiers à réécrire: modifiés depuis la dernière écriture réussie.
+    unsaved: Arc<Mutex<BTreeSet<Store>>>,
 }
 
 impl DataApi {
     // ===
     //
     //
-    // Initialise l’API (chargement des feeds, états lus, cache d’articles) depuis un dossier.
+    // Initialise l’API (chargement des feeds, états lus, cache d’articles, “vus”) depuis un
+    // dossier. Les “vus” de flux qui ne sont plus suivis sont oubliés au chargement.
     //
     //
     // ===
@@ -35,7 +278,15 @@ impl DataApi {
         let dir = dir.as_ref();
         let feeds_path = dir.join("feeds.json");
         let read_path = dir.join("read_store.json");
+        let journal_path = dir.join("read_store.journal");
         let articles_path = dir.join("articles_store.json");
+        let stats_path = dir.join("feed_stats.json");
+        let positions_path = dir.join("reading_positions.json");
+        let rules_path = dir.join("rules.json");
+        let session_path = dir.join("session.json");
+        let secrets_path = dir.join("secrets.json");
+        let seen_path = dir.join("seen_store.json");
+        let icons_dir = dir.join("icons");
 
         if let Err(e) = tokio::fs::create_dir_all(dir).await {
             warn!(error = %e, "failed to create config dir");
@@ -61,16 +312,53 @@ impl DataApi {
         }
 
         let initial_feeds: Vec<FeedDescriptor> = read_json_with_tmp_fallback(&feeds_path).await;
+        let seen = SeenStore::load_from(&seen_path).await;
         if !initial_feeds.is_empty() {
+            // Migration: les “vus” des flux supprimés n’étaient pas toujours nettoyés.
+            let ids: HashSet<&str> = initial_feeds.iter().map(|f| f.id.as_str()).collect();
+            seen.retain_feeds(|feed_id| ids.contains(feed_id)).await;
             let mut store = feeds.write().await;
             *store = initial_feeds;
         }
 
-        let read_inner: ReadData = read_json_with_tmp_fallback(&read_path).await;
+        let mut read_inner: ReadData = read_json_with_tmp_fallback(&read_path).await;
+        // Marques journalisées depuis la dernière réécriture, repliées aussitôt dans le fichier.
+        if replay_read_journal(&journal_path, &mut read_inner).await {
+            let compacted = match to_json(&read_inner) {
+                Ok(bytes) => write_atomic(&read_path, &bytes).await,
+                Err(e) => Err(std::io::Error::other(e)),
+            };
+            match compacted {
+                Ok(()) => remove_journal(&journal_path).await,
+                Err(e) => warn!(error = %e, "failed to compact the read journal"),
+            }
+        }
 
         let articles_inner: HashMap<String, Vec<FeedEntry>> =
             read_json_with_tmp_fallback(&articles_path).await;
 
+        let stats_inner: HashMap<String, FeedStats> =
+            read_json_with_tmp_fallback(&stats_path).await;
+
+        let positions_inner: Vec<ReadingPosition> =
+            read_json_with_tmp_fallback(&positions_path).await;
+
+        // Une règle devenue invalide (fichier modifié à la main) est ignorée, pas les autres.
+        let rules: Vec<FilterRule> = read_json_with_tmp_fallback(&rules_path).await;
+        let rules: Vec<FilterRule> = rules
+            .into_iter()
+            .filter(|rule| match rule.compile() {
+                Ok(_) => true,
+                Err(e) => {
+                    warn!(error = %e, pattern = %rule.pattern, "invalid filter rule ignored");
+                    false
+                }
+            })
+            .collect();
+        let rules_inner = RuleSet::compile(&rules).unwrap_or_default();
+
+        let secrets: HashMap<String, FeedSecret> = read_json_with_tmp_fallback(&secrets_path).await;
+
         Self {
             feeds,
             read_inner: Arc::new(RwLock::new(read_inner)),
@@ -78,119 +366,623 @@ impl DataApi {
             read_path,
             articles_inner: Arc::new(RwLock::new(articles_inner)),
             articles_path,
+            stats_inner: Arc::new(RwLock::new(stats_inner)),
+            stats_path,
+            positions_inner: Arc::new(RwLock::new(positions_inner)),
+            positions_path,
+            rules_inner: Arc::new(RwLock::new(rules_inner)),
+            rules_path,
+            session_path,
+            secrets: FeedSecrets::from_map(secrets),
+            secrets_path,
+            icons_dir,
+            max_articles_per_feed: Arc::new(AtomicUsize::new(DEFAULT_MAX_ARTICLES_PER_FEED)),
+            max_title_chars: Arc::new(AtomicUsize::new(DEFAULT_MAX_TITLE_CHARS)),
+            read_writes: Arc::new(AtomicUsize::new(0)),
+            stars_revision: Arc::new(AtomicUsize::new(0)),
+            read_revision: Arc::new(AtomicUsize::new(0)),
+            journal_path,
+            journal_lock: Arc::new(tokio::sync::Mutex::new(())),
+            journal_len: Arc::new(AtomicUsize::new(0)),
+            seen,
+            persistent: true,
+            unsaved: Arc::new(Mutex::new(BTreeSet::new())),
         }
     }
 
     // ===
     //
     //
-    // Persiste la liste des flux sur disque (écriture atomique via .tmp).
+    // API en mémoire: rien n’est lu ni écrit sur disque (intégration dans une autre appli,
+    // tests). Les icônes de flux ne sont pas mises en cache.
     //
     //
     // ===
-    async fn persist_feeds(&self) {
-        let feeds = list_feeds(&self.feeds).await;
-        match serde_json::to_vec_pretty(&feeds) {
-            Ok(bytes) => {
-                if let Some(parent) = self.feeds_path.parent() {
-                    let _ = tokio::fs::create_dir_all(parent).await;
-                }
-                let tmp = self.feeds_path.with_extension("json.tmp");
-                if let Err(e) = tokio::fs::write(&tmp, &bytes).await {
-                    warn!(error = %e, path = %tmp.display(), "failed to write temp feeds.json");
-                }
-                if let Err(e) = tokio::fs::rename(&tmp, &self.feeds_path).await {
-                    warn!(error = %e, path = %self.feeds_path.display(), "failed to persist feeds.json");
+    pub fn in_memory(feeds: SharedFeedList) -> Self {
+        Self {
+            feeds,
+            read_inner: Arc::new(RwLock::new(ReadData::default())),
+            feeds_path: PathBuf::new(),
+            read_path: PathBuf::new(),
+            articles_inner: Arc::new(RwLock::new(HashMap::new())),
+            articles_path: PathBuf::new(),
+            stats_inner: Arc::new(RwLock::new(HashMap::new())),
+            stats_path: PathBuf::new(),
+            positions_inner: Arc::new(RwLock::new(Vec::new())),
+            positions_path: PathBuf::new(),
+            rules_inner: Arc::new(RwLock::new(RuleSet::default())),
+            rules_path: PathBuf::new(),
+            session_path: PathBuf::new(),
+            secrets: FeedSecrets::default(),
+            secrets_path: PathBuf::new(),
+            icons_dir: PathBuf::new(),
+            max_articles_per_feed: Arc::new(AtomicUsize::new(DEFAULT_MAX_ARTICLES_PER_FEED)),
+            max_title_chars: Arc::new(AtomicUsize::new(DEFAULT_MAX_TITLE_CHARS)),
+            read_writes: Arc::new(AtomicUsize::new(0)),
+            stars_revision: Arc::new(AtomicUsize::new(0)),
+            read_revision: Arc::new(AtomicUsize::new(0)),
+            journal_path: PathBuf::new(),
+            journal_lock: Arc::new(tokio::sync::Mutex::new(())),
+            journal_len: Arc::new(AtomicUsize::new(0)),
+            seen: SeenStore::in_memory(),
+            persistent: false,
+            unsaved: Arc::new(Mutex::new(BTreeSet::new())),
+        }
+    }
+
+    // Écriture atomique d’un fichier géré; sans effet pour une API en mémoire.
+    async fn write_store(&self, path: &Path, bytes: &[u8]) -> Result<(), DataError> {
+        if !self.persistent {
+            return Ok(());
+        }
+        write_atomic(path, bytes)
+            .await
+            .map_err(|source| DataError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    // ===
+    //
+    //
+    // Remplace le magasin de “vus” chargé avec le dossier (magasin partagé avec un autre
+    // composant).
+    //
+    //
+    // ===
+    pub fn with_seen_store(mut self, seen: SeenStore) -> Self {
+        self.seen = seen;
+        self
+    }
+
+    // ===
+    //
+    //
+    // Magasin des identités vues, à passer au poller (voir aussi `From<&DataApi>`).
+    //
+    //
+    // ===
+    pub fn seen_store(&self) -> &SeenStore {
+        &self.seen
+    }
+
+    // Liste partagée des flux (compteurs d’échecs d’un tour poll_once_in, par exemple).
+    pub fn feed_list(&self) -> &SharedFeedList {
+        &self.feeds
+    }
+
+    // Nombre maximal d’identités vues conservées par flux.
+    pub fn set_max_seen_per_feed(&self, max: usize) {
+        self.seen.set_max_per_feed(max);
+    }
+
+    // ===
+    //
+    //
+    // Retourne true si l’article est nouveau et le marque comme vu (voir
+    // SeenStore::is_new_and_mark; persisté au prochain flush).
+    //
+    //
+    // ===
+    pub async fn is_new_and_mark(&self, entry: &FeedEntry) -> bool {
+        self.seen.is_new_and_mark(entry).await
+    }
+
+    // Verdict nouveau / modifié / inchangé d’une entrée (voir SeenStore::check_and_mark).
+    pub async fn check_and_mark(&self, entry: &FeedEntry) -> SeenStatus {
+        self.seen.check_and_mark(entry).await
+    }
+
+    // ===
+    //
+    //
+    // Sérialise et écrit un fichier géré (écriture atomique via .tmp). read_store.json est
+    // écrit sous le verrou du journal, vidé ensuite: le fichier contient alors chaque marque.
+    //
+    //
+    // ===
+    async fn write(&self, store: Store) -> Result<(), DataError> {
+        let _journal = match store {
+            Store::Read => Some(self.journal_lock.lock().await),
+            _ => None,
+        };
+        let (path, bytes) = match store {
+            Store::Feeds => (&self.feeds_path, to_json(&list_feeds(&self.feeds).await)),
+            Store::Read => (&self.read_path, to_json(&*self.read_inner.read().await)),
+            Store::Articles => (
+                &self.articles_path,
+                to_json(&*self.articles_inner.read().await),
+            ),
+            Store::Stats => (&self.stats_path, to_json(&*self.stats_inner.read().await)),
+            Store::Positions => (
+                &self.positions_path,
+                to_json(&*self.positions_inner.read().await),
+            ),
+            Store::Rules => (&self.rules_path, to_json(&self.rules().await)),
+        };
+        let bytes = bytes.map_err(|source| DataError::Serde {
+            path: path.clone(),
+            source,
+        })?;
+        if store == Store::Read {
+            self.read_writes.fetch_add(1, Ordering::Relaxed);
+        }
+        self.write_store(path, &bytes).await?;
+        if store == Store::Read && self.journal_len.swap(0, Ordering::Relaxed) > 0 {
+            remove_journal(&self.journal_path).await;
+        }
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Applique une marque unitaire et l’ajoute au journal (une ligne, synchronisée sur disque).
+    // Au-delà de READ_JOURNAL_COMPACT_AFTER lignes, read_store.json est réécrit; si l’ajout
+    // échoue, aussi. Les fichiers en échec sont retentés. Retourne true si l’état a changé.
+    //
+    //
+    // ===
+    async fn record_mark(&self, mark: ReadMark) -> Result<bool, DataError> {
+        let journal = self.journal_lock.lock().await;
+        if !self.read_inner.write().await.apply(&mark) {
+            return Ok(false);
+        }
+        self.read_revision.fetch_add(1, Ordering::Relaxed);
+        if !matches!(mark, ReadMark::Read { .. }) {
+            self.stars_revision.fetch_add(1, Ordering::Relaxed);
+        }
+        if !self.persistent {
+            return Ok(true);
+        }
+        let appended = append_journal(&self.journal_path, &mark).await;
+        drop(journal);
+        match appended {
+            Ok(()) => {
+                let lines = self.journal_len.fetch_add(1, Ordering::Relaxed) + 1;
+                if lines >= READ_JOURNAL_COMPACT_AFTER {
+                    self.persist(Store::Read).await?;
+                } else {
+                    // Fichiers dont l’écriture précédente a échoué.
+                    self.save().await?;
                 }
             }
-            Err(e) => warn!(error = %e, "failed to serialize feeds for persistence"),
+            Err(e) => {
+                warn!(error = %e, "read journal append failed, rewriting the read store");
+                self.persist(Store::Read).await?;
+            }
         }
+        Ok(true)
     }
 
     // ===
     //
     //
-    // Persiste l’état de lecture sur disque (écriture atomique via .tmp).
+    // Replie le journal des marques dans read_store.json (fin d’une commande ponctuelle, par
+    // exemple); sans effet si le journal est vide.
     //
     //
     // ===
-    async fn persist_read(&self) {
-        let inner = self.read_inner.read().await;
-        match serde_json::to_vec_pretty(&*inner) {
-            Ok(bytes) => {
-                if let Some(parent) = self.read_path.parent() {
-                    let _ = tokio::fs::create_dir_all(parent).await;
-                }
-                let tmp = self.read_path.with_extension("json.tmp");
-                if let Err(e) = tokio::fs::write(&tmp, &bytes).await {
-                    warn!(error = %e, path = %tmp.display(), "failed to write temp read_store.json");
+    pub async fn compact_read_journal(&self) -> Result<(), DataError> {
+        if self.journal_len.load(Ordering::Relaxed) == 0 {
+            return Ok(());
+        }
+        self.persist(Store::Read).await
+    }
+
+    // Marque un fichier à réécrire à la prochaine sauvegarde.
+    fn touch(&self, store: Store) {
+        if store == Store::Read {
+            self.read_revision.fetch_add(1, Ordering::Relaxed);
+        }
+        self.unsaved.lock().unwrap().insert(store);
+    }
+
+    // ===
+    //
+    //
+    // Réécrit les fichiers marqués. Un échec est loggé et renvoyé (le premier s’il y en a
+    // plusieurs); le fichier reste marqué et sera retenté à la prochaine sauvegarde, l’état en
+    // mémoire étant conservé.
+    //
+    //
+    // ===
+    async fn save(&self) -> Result<(), DataError> {
+        let stores: Vec<Store> = self.unsaved.lock().unwrap().iter().copied().collect();
+        let mut result = Ok(());
+        for store in stores {
+            match self.write(store).await {
+                Ok(()) => {
+                    self.unsaved.lock().unwrap().remove(&store);
                 }
-                if let Err(e) = tokio::fs::rename(&tmp, &self.read_path).await {
-                    warn!(error = %e, path = %self.read_path.display(), "failed to persist read_store.json");
+                Err(e) => {
+                    warn!(error = %e, "failed to persist data");
+                    result = result.and(Err(e));
                 }
             }
-            Err(e) => warn!(error = %e, "failed to serialize read map"),
         }
+        result
+    }
+
+    // Marque puis réécrit un fichier (et ceux dont l’écriture précédente a échoué).
+    async fn persist(&self, store: Store) -> Result<(), DataError> {
+        self.touch(store);
+        self.save().await
     }
 
     // ===
     //
     //
-    // Persiste le cache d’articles sur disque (écriture atomique via .tmp).
+    // Session de la GUI enregistrée à la dernière fermeture (défaut si absente ou illisible).
     //
     //
     // ===
-    async fn persist_articles(&self) {
-        let inner = self.articles_inner.read().await;
-        match serde_json::to_vec_pretty(&*inner) {
-            Ok(bytes) => {
-                if let Some(parent) = self.articles_path.parent() {
-                    let _ = tokio::fs::create_dir_all(parent).await;
-                }
-                let tmp = self.articles_path.with_extension("json.tmp");
-                if let Err(e) = tokio::fs::write(&tmp, &bytes).await {
-                    warn!(error = %e, path = %tmp.display(), "failed to write temp articles_store.json");
-                }
-                if let Err(e) = tokio::fs::rename(&tmp, &self.articles_path).await {
-                    warn!(error = %e, path = %self.articles_path.display(), "failed to persist articles_store.json");
-                }
+    pub async fn load_session(&self) -> SessionState {
+        SessionState::load_from(&self.session_path).await
+    }
+
+    // ===
+    //
+    //
+    // Enregistre la session de la GUI (écriture atomique via .tmp).
+    //
+    //
+    // ===
+    pub async fn save_session(&self, session: &SessionState) -> Result<(), DataError> {
+        let bytes = to_json(session).map_err(|source| DataError::Serde {
+            path: self.session_path.clone(),
+            source,
+        })?;
+        self.write_store(&self.session_path, &bytes).await
+    }
+
+    // ===
+    //
+    //
+    // Secrets des flux (cookies), à passer au poller via PollConfig::secrets comme le magasin
+    // de “vus”. Ils vivent dans secrets.json, jamais dans feeds.json ni dans les exports.
+    //
+    //
+    // ===
+    pub fn feed_secrets(&self) -> &FeedSecrets {
+        &self.secrets
+    }
+
+    pub fn get_feed_secret(&self, feed_id: &str) -> Option<FeedSecret> {
+        self.secrets.get(feed_id)
+    }
+
+    // ===
+    //
+    //
+    // Enregistre le secret d’un flux (un secret vide le retire) et réécrit secrets.json s’il
+    // change. Le cookie n’est pas vérifié ici: voir FeedSecret::validate.
+    //
+    //
+    // ===
+    pub async fn set_feed_secret(
+        &self,
+        feed_id: &str,
+        secret: FeedSecret,
+    ) -> Result<(), DataError> {
+        if self.secrets.set(feed_id, secret) {
+            self.save_secrets().await?;
+        }
+        Ok(())
+    }
+
+    // Retire le secret d’un flux; true s’il en avait un.
+    pub async fn remove_feed_secret(&self, feed_id: &str) -> Result<bool, DataError> {
+        let removed = self.secrets.remove(&[feed_id.to_string()]);
+        if removed {
+            self.save_secrets().await?;
+        }
+        Ok(removed)
+    }
+
+    // Réécrit secrets.json (lisible par le seul propriétaire sous Unix).
+    async fn save_secrets(&self) -> Result<(), DataError> {
+        if !self.persistent {
+            return Ok(());
+        }
+        let bytes = to_json(&self.secrets.snapshot()).map_err(|source| DataError::Serde {
+            path: self.secrets_path.clone(),
+            source,
+        })?;
+        write_atomic_private(&self.secrets_path, &bytes)
+            .await
+            .map_err(|source| DataError::Io {
+                path: self.secrets_path.clone(),
+                source,
+            })
+    }
+
+    // ===
+    //
+    //
+    // Réécrit tous les fichiers gérés (flux, lectures, articles, santé, positions de lecture,
+    // règles) et les “vus” en attente: appelé à la fermeture pour qu’aucune dernière action ne
+    // soit perdue.
+    //
+    //
+    // ===
+    pub async fn flush_all(&self) -> Result<(), DataError> {
+        for store in Store::ALL {
+            self.touch(store);
+        }
+        self.seen.flush().await;
+        self.save().await
+    }
+
+    // Réécrit les fichiers modifiés sans écriture depuis (statistiques d’un cycle de polling).
+    pub async fn flush_pending(&self) -> Result<(), DataError> {
+        self.save().await
+    }
+
+    // ===
+    //
+    //
+    // Ajoute un flux (titre normalisé, voir FeedDescriptor::normalized) et persiste la liste.
+    //
+    //
+    // ===
+    pub async fn add_feed(&self, feed: FeedDescriptor) -> Result<(), DataError> {
+        add_feed(&self.feeds, feed.normalized(self.max_title_chars())).await;
+        self.persist(Store::Feeds).await
+    }
+
+    // ===
+    //
+    //
+    // Ajoute un flux saisi par l’utilisateur (formulaire, Discover, CLI): refusé si l’URL est
+    // invalide ou déjà suivie (voir feed::try_add_feed); persiste la liste sinon.
+    //
+    //
+    // ===
+    pub async fn try_add_feed(&self, feed: FeedDescriptor) -> Result<(), AddFeedError> {
+        try_add_feed(&self.feeds, feed.normalized(self.max_title_chars())).await?;
+        self.persist(Store::Feeds).await?;
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Supprime un flux (et ses marques de lecture, de “vus”, ses statistiques et son icône)
+    // puis persiste.
+    //
+    //
+    // ===
+    pub async fn remove_feed(&self, feed_id: &str) -> Result<(), DataError> {
+        self.remove_feeds(&[feed_id.to_string()]).await.map(|_| ())
+    }
+
+    // ===
+    //
+    //
+    // Suppression groupée (gestion des flux): un seul passage par magasin et une seule
+    // sauvegarde pour tous les flux. Renvoie le nombre de flux retirés de la liste.
+    //
+    //
+    // ===
+    pub async fn remove_feeds(&self, feed_ids: &[String]) -> Result<usize, DataError> {
+        let removed = remove_feeds(&self.feeds, feed_ids).await.len();
+        self.touch(Store::Feeds);
+        let mut inner = self.read_inner.write().await;
+        for feed_id in feed_ids {
+            inner.read.remove(feed_id);
+            inner.starred.remove(feed_id);
+        }
+        drop(inner);
+        self.touch(Store::Read);
+        let mut positions = self.positions_inner.write().await;
+        let before = positions.len();
+        positions.retain(|p| !feed_ids.contains(&p.feed_id));
+        if positions.len() != before {
+            self.touch(Store::Positions);
+        }
+        drop(positions);
+        let mut stats = self.stats_inner.write().await;
+        let before = stats.len();
+        stats.retain(|feed_id, _| !feed_ids.contains(feed_id));
+        if stats.len() != before {
+            self.touch(Store::Stats);
+        }
+        drop(stats);
+        self.seen
+            .retain_feeds(|feed_id| !feed_ids.iter().any(|id| id == feed_id))
+            .await;
+        if self.persistent {
+            for feed_id in feed_ids {
+                let _ = tokio::fs::remove_file(self.icon_path(feed_id)).await;
             }
-            Err(e) => warn!(error = %e, "failed to serialize articles map"),
         }
+        self.save().await?;
+        if self.secrets.remove(feed_ids) {
+            self.save_secrets().await?;
+        }
+        Ok(removed)
+    }
+
+    // ===
+    //
+    //
+    // Applique `update` à plusieurs flux (gestion des flux) et persiste une fois si au moins
+    // un flux change. Renvoie le nombre de flux modifiés.
+    //
+    //
+    // ===
+    pub async fn update_feeds_bulk(
+        &self,
+        feed_ids: &[String],
+        update: FeedUpdate,
+    ) -> Result<usize, DataError> {
+        let updated = update_feeds(&self.feeds, feed_ids, &update).await;
+        if updated > 0 {
+            self.persist(Store::Feeds).await?;
+        }
+        Ok(updated)
     }
 
     // ===
     //
     //
-    // Ajoute un flux et persiste la liste.
+    // Supprime un flux comme remove_feed après en avoir copié l’état (FeedBackup); None si le
+    // flux est inconnu, rien n’est alors supprimé.
     //
     //
     // ===
-    pub async fn add_feed(&self, feed: FeedDescriptor) {
-        add_feed(&self.feeds, feed).await;
-        self.persist_feeds().await;
+    pub async fn remove_feed_with_backup(
+        &self,
+        feed_id: &str,
+    ) -> Result<Option<FeedBackup>, DataError> {
+        let feeds = list_feeds(&self.feeds).await;
+        let Some(index) = feeds.iter().position(|f| f.id == feed_id) else {
+            return Ok(None);
+        };
+        let read = self.read_inner.read().await;
+        let backup = FeedBackup {
+            feed: feeds[index].clone(),
+            index,
+            articles: self.articles_inner.read().await.get(feed_id).cloned(),
+            read: read.read.get(feed_id).cloned(),
+            starred: read.starred.get(feed_id).cloned(),
+            positions: self
+                .positions_inner
+                .read()
+                .await
+                .iter()
+                .filter(|p| p.feed_id == feed_id)
+                .cloned()
+                .collect(),
+            stats: self.stats_inner.read().await.get(feed_id).cloned(),
+            seen: self.seen.feed_snapshot(feed_id).await,
+            icon: if self.persistent {
+                tokio::fs::read(self.icon_path(feed_id)).await.ok()
+            } else {
+                None
+            },
+            secret: self.get_feed_secret(feed_id),
+        };
+        drop(read);
+        self.remove_feed(feed_id).await?;
+        Ok(Some(backup))
     }
 
     // ===
     //
     //
-    // Supprime un flux (et ses marques de lecture) puis persiste.
+    // Annule remove_feed_with_backup: le flux reprend son rang dans la liste (remplacé s’il a
+    // été rajouté entre-temps) avec ses articles, marques, positions, santé, “vus” et icône.
+    // Les positions restaurées comptent comme les plus récentes (plafond MAX_READING_POSITIONS).
     //
     //
     // ===
-    pub async fn remove_feed(&self, feed_id: &str) {
-        remove_feed(&self.feeds, feed_id).await;
-        self.persist_feeds().await;
+    pub async fn restore_feed(&self, backup: FeedBackup) -> Result<(), DataError> {
+        let FeedBackup {
+            feed,
+            index,
+            articles,
+            read,
+            starred,
+            positions,
+            stats,
+            seen,
+            icon,
+            secret,
+        } = backup;
+        let feed_id = feed.id.clone();
+        let mut ordered = list_feeds(&self.feeds).await;
+        ordered.retain(|f| f.id != feed_id);
+        ordered.insert(index.min(ordered.len()), feed);
+        *self.feeds.write().await = ordered;
+        self.touch(Store::Feeds);
+        if let Some(articles) = articles {
+            self.articles_inner
+                .write()
+                .await
+                .insert(feed_id.clone(), articles);
+            self.touch(Store::Articles);
+        }
         let mut inner = self.read_inner.write().await;
-        inner.read.remove(feed_id);
+        if let Some(read) = read {
+            inner.read.entry(feed_id.clone()).or_default().extend(read);
+        }
+        if let Some(starred) = starred {
+            inner
+                .starred
+                .entry(feed_id.clone())
+                .or_default()
+                .extend(starred);
+        }
         drop(inner);
-        self.persist_read().await;
+        self.touch(Store::Read);
+        if !positions.is_empty() {
+            let mut inner = self.positions_inner.write().await;
+            inner.extend(positions);
+            let excess = inner.len().saturating_sub(MAX_READING_POSITIONS);
+            inner.drain(..excess);
+            drop(inner);
+            self.touch(Store::Positions);
+        }
+        if let Some(stats) = stats {
+            self.stats_inner
+                .write()
+                .await
+                .insert(feed_id.clone(), stats);
+            self.touch(Store::Stats);
+        }
+        self.seen.merge(seen).await;
+        if let Some(icon) = icon {
+            if let Err(e) = write_atomic(&self.icon_path(&feed_id), &icon).await {
+                warn!(feed_id, error = %e, "failed to restore feed icon");
+            }
+        }
+        if let Some(secret) = secret {
+            self.set_feed_secret(&feed_id, secret).await?;
+        }
+        self.save().await
+    }
+
+    // ===
+    //
+    //
+    // Enregistre l’ordre des flux choisi dans la barre latérale et persiste s’il change.
+    //
+    //
+    // ===
+    pub async fn reorder_feeds(&self, ids_in_order: Vec<String>) -> Result<(), DataError> {
+        if reorder_feeds(&self.feeds, &ids_in_order).await {
+            self.persist(Store::Feeds).await?;
+        }
+        Ok(())
     }
 
     // ===
     //
     //
-    // Retourne la liste des flux.
+    // Retourne la liste des flux (dans l’ordre choisi).
     //
     //
     // ===
@@ -211,71 +1003,1196 @@ impl DataApi {
         inner
             .read
             .get(&entry.feed_id)
-            .map(|set| set.contains(&key))
+            .map(|set| set.contains(&key) || set.contains(&entry.legacy_identity()))
             .unwrap_or(false)
     }
 
     // ===
     //
     //
-    // Marque un article comme lu et persiste si nécessaire.
+    // État « lu » de plusieurs articles (dans l’ordre donné), sous un seul verrou.
     //
     //
     // ===
-    pub async fn mark_read(&self, entry: &FeedEntry) {
-        let key = entry.identity();
-        let mut inner = self.read_inner.write().await;
-        let set = inner.read.entry(entry.feed_id.clone()).or_default();
-        if set.insert(key) {
-            drop(inner);
-            self.persist_read().await;
-        } else {
-            debug!("entry already marked as read");
-        }
+    pub async fn read_flags(&self, entries: &[&FeedEntry]) -> Vec<bool> {
+        let inner = self.read_inner.read().await;
+        entries
+            .iter()
+            .map(|entry| {
+                inner.read.get(&entry.feed_id).is_some_and(|set| {
+                    set.contains(&entry.identity()) || set.contains(&entry.legacy_identity())
+                })
+            })
+            .collect()
     }
 
     // ===
     //
     //
-    // Upsert et persiste un lot d’articles pour un feed (déduplication, tri décroissant, truncate).
+    // Nombre d’articles stockés non lus, tous flux confondus (infobulle de l’icône de notification).
     //
     //
     // ===
-    pub async fn upsert_articles(&self, feed_id: &str, entries: Vec<FeedEntry>) {
-        const MAX_PER_FEED: usize = 300;
-        let mut inner = self.articles_inner.write().await;
-        let slot = inner.entry(feed_id.to_string()).or_default();
-        let mut existing: HashSet<String> = slot.iter().map(|e| e.identity()).collect();
-        for e in entries {
-            let id = e.identity();
-            if existing.insert(id) {
-                slot.push(e);
-            }
-        }
-        slot.sort_by(|a, b| b.published_at.cmp(&a.published_at));
-        if slot.len() > MAX_PER_FEED {
-            slot.truncate(MAX_PER_FEED);
-        }
-        drop(inner);
-        self.persist_articles().await;
+    pub async fn unread_count(&self) -> usize {
+        let all = self.list_all_articles().await;
+        let inner = self.read_inner.read().await;
+        all.iter()
+            .filter(|entry| {
+                !inner.read.get(&entry.feed_id).is_some_and(|set| {
+                    set.contains(&entry.identity()) || set.contains(&entry.legacy_identity())
+                })
+            })
+            .count()
     }
 
     // ===
     //
     //
-    // Liste les articles persistés pour un feed donné.
+    // Marque un article comme lu et l’ajoute au journal si nécessaire.
     //
     //
     // ===
-    pub async fn list_articles(&self, feed_id: &str) -> Vec<FeedEntry> {
-        let inner = self.articles_inner.read().await;
-        inner.get(feed_id).cloned().unwrap_or_default()
+    pub async fn mark_read(&self, entry: &FeedEntry) -> Result<(), DataError> {
+        let mark = ReadMark::Read {
+            feed_id: entry.feed_id.clone(),
+            key: entry.identity(),
+        };
+        if !self.record_mark(mark).await? {
+            debug!("entry already marked as read");
+        }
+        Ok(())
     }
 
     // ===
     //
     //
-    // Liste tous les articles persistés, toutes sources confondues.
+    // Marque un lot d’articles comme lus: un seul verrou en écriture et une seule écriture
+    // disque. Retourne le nombre d’articles qui n’étaient pas encore lus.
+    //
+    //
+    // ===
+    pub async fn mark_read_batch(&self, entries: &[FeedEntry]) -> Result<usize, DataError> {
+        Ok(self.mark_read_batch_tracked(entries).await?.len())
+    }
+
+    // ===
+    //
+    //
+    // Comme mark_read_batch, mais renvoie les marques ajoutées (annulation dans la GUI).
+    //
+    //
+    // ===
+    pub async fn mark_read_batch_tracked(
+        &self,
+        entries: &[FeedEntry],
+    ) -> Result<ReadMarks, DataError> {
+        let mut inner = self.read_inner.write().await;
+        let mut added = ReadMarks::default();
+        for entry in entries {
+            let set = inner.read.entry(entry.feed_id.clone()).or_default();
+            let key = entry.identity();
+            if set.insert(key.clone()) {
+                added
+                    .marks
+                    .entry(entry.feed_id.clone())
+                    .or_default()
+                    .insert(key);
+            }
+        }
+        drop(inner);
+        if !added.is_empty() {
+            self.persist(Store::Read).await?;
+        }
+        Ok(added)
+    }
+
+    // ===
+    //
+    //
+    // Retire des marques « lu » (annulation d’un marquage groupé) en une seule écriture.
+    // Retourne le nombre d’articles redevenus non lus.
+    //
+    //
+    // ===
+    pub async fn unmark_read(&self, marks: &ReadMarks) -> Result<usize, DataError> {
+        let mut inner = self.read_inner.write().await;
+        let mut removed = 0;
+        for (feed_id, keys) in &marks.marks {
+            if let Some(set) = inner.read.get_mut(feed_id) {
+                let before = set.len();
+                set.retain(|key| !keys.contains(key));
+                removed += before - set.len();
+                if set.is_empty() {
+                    inner.read.remove(feed_id);
+                }
+            }
+        }
+        drop(inner);
+        if removed > 0 {
+            self.persist(Store::Read).await?;
+        }
+        Ok(removed)
+    }
+
+    // ===
+    //
+    //
+    // Marque comme lus les articles stockés d’un flux, éventuellement limités à ceux publiés
+    // avant `before` (les articles sans date sont alors ignorés).
+    //
+    //
+    // ===
+    pub async fn mark_feed_read(
+        &self,
+        feed_id: &str,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<usize, DataError> {
+        Ok(self.mark_feed_read_tracked(feed_id, before).await?.len())
+    }
+
+    // Comme mark_feed_read, mais renvoie les marques ajoutées (annulation dans la GUI).
+    pub async fn mark_feed_read_tracked(
+        &self,
+        feed_id: &str,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<ReadMarks, DataError> {
+        let entries: Vec<FeedEntry> = self
+            .list_articles(feed_id)
+            .await
+            .into_iter()
+            .filter(|e| match before {
+                Some(limit) => e.sort_date() < limit,
+                None => true,
+            })
+            .collect();
+        self.mark_read_batch_tracked(&entries).await
+    }
+
+    // ===
+    //
+    //
+    // Position de défilement enregistrée pour un article (None: lecture à reprendre du début).
+    //
+    //
+    // ===
+    pub async fn reading_position(&self, entry: &FeedEntry) -> Option<f32> {
+        let key = entry.identity();
+        let inner = self.positions_inner.read().await;
+        inner
+            .iter()
+            .find(|p| p.feed_id == entry.feed_id && p.key == key)
+            .map(|p| p.offset)
+    }
+
+    // ===
+    //
+    //
+    // Enregistre la position de lecture d’un article (un décalage quasi nul l’efface) et
+    // persiste; au-delà de MAX_READING_POSITIONS, les plus anciennes sont oubliées.
+    //
+    //
+    // ===
+    pub async fn set_reading_position(
+        &self,
+        entry: &FeedEntry,
+        offset: f32,
+    ) -> Result<(), DataError> {
+        let key = entry.identity();
+        let mut inner = self.positions_inner.write().await;
+        let index = inner
+            .iter()
+            .position(|p| p.feed_id == entry.feed_id && p.key == key);
+        let previous = index.map(|i| inner.remove(i));
+        if offset >= 1.0 {
+            inner.push(ReadingPosition {
+                feed_id: entry.feed_id.clone(),
+                key,
+                offset,
+            });
+            let excess = inner.len().saturating_sub(MAX_READING_POSITIONS);
+            inner.drain(..excess);
+        }
+        // Seul l’ordre de récence a pu changer: inutile de réécrire le fichier.
+        let unchanged = previous.map(|p| p.offset) == (offset >= 1.0).then_some(offset);
+        drop(inner);
+        if !unchanged {
+            self.persist(Store::Positions).await?;
+        }
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Indique si un article est marqué comme favori.
+    //
+    //
+    // ===
+    pub async fn is_starred(&self, entry: &FeedEntry) -> bool {
+        let inner = self.read_inner.read().await;
+        inner
+            .starred
+            .get(&entry.feed_id)
+            .is_some_and(|set| set.contains(&entry.identity()))
+    }
+
+    // ===
+    //
+    //
+    // Ajoute ou retire un article des favoris (stockés avec l’état lu dans read_store.json,
+    // via le journal). Retourne true si l’état a changé.
+    //
+    //
+    // ===
+    pub async fn set_starred(&self, entry: &FeedEntry, starred: bool) -> Result<bool, DataError> {
+        let (feed_id, key) = (entry.feed_id.clone(), entry.identity());
+        let mark = if starred {
+            ReadMark::Star { feed_id, key }
+        } else {
+            ReadMark::Unstar { feed_id, key }
+        };
+        self.record_mark(mark).await
+    }
+
+    // ===
+    //
+    //
+    // Identifiants distants des favoris synchronisés au dernier cycle du service `service`
+    // (voir sync): vide avant la première synchronisation.
+    //
+    //
+    // ===
+    pub async fn synced_stars(&self, service: &str) -> HashSet<String> {
+        let inner = self.read_inner.read().await;
+        inner
+            .synced_starred
+            .get(service)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // Remplace la base des favoris synchronisés d’un service; persiste si elle change.
+    pub async fn set_synced_stars(
+        &self,
+        service: &str,
+        item_ids: HashSet<String>,
+    ) -> Result<(), DataError> {
+        let mut inner = self.read_inner.write().await;
+        let current = inner.synced_starred.get(service);
+        if current.map_or(item_ids.is_empty(), |ids| *ids == item_ids) {
+            return Ok(());
+        }
+        if item_ids.is_empty() {
+            inner.synced_starred.remove(service);
+        } else {
+            inner.synced_starred.insert(service.to_string(), item_ids);
+        }
+        drop(inner);
+        self.persist(Store::Read).await
+    }
+
+    // ===
+    //
+    //
+    // Compteur de changements des favoris depuis le chargement: un appelant qui a retenu la
+    // valeur sait s’il doit réexporter le flux des favoris.
+    //
+    //
+    // ===
+    pub fn stars_revision(&self) -> usize {
+        self.stars_revision.load(Ordering::Relaxed)
+    }
+
+    // Compteur de changements de l’état de lecture (marques « lu », favoris) depuis le chargement.
+    pub fn read_revision(&self) -> usize {
+        self.read_revision.load(Ordering::Relaxed)
+    }
+
+    // ===
+    //
+    //
+    // Articles favoris présents dans le cache, du plus récent au plus ancien.
+    //
+    //
+    // ===
+    pub async fn starred_articles(&self) -> Vec<FeedEntry> {
+        let read = self.read_inner.read().await;
+        let articles = self.articles_inner.read().await;
+        let mut starred: Vec<FeedEntry> = articles
+            .iter()
+            .filter_map(|(feed_id, entries)| Some((read.starred.get(feed_id)?, entries)))
+            .flat_map(|(keys, entries)| entries.iter().filter(|e| keys.contains(&e.identity())))
+            .cloned()
+            .collect();
+        starred.sort_by_key(|e| Reverse(e.sort_date()));
+        starred
+    }
+
+    // ===
+    //
+    //
+    // Document RSS 2.0 ou Atom listant `entries` dans l’ordre donné.
+    //
+    //
+    // ===
+    pub fn render_feed(entries: &[FeedEntry], meta: ChannelMeta, format: FeedFormat) -> String {
+        match format {
+            FeedFormat::Rss => render_rss(entries, &meta),
+            FeedFormat::Atom => render_atom(entries, &meta),
+        }
+    }
+
+    // ===
+    //
+    //
+    // Écrit le flux des favoris dans `path` (remplacement atomique). Retourne le nombre
+    // d’articles publiés.
+    //
+    //
+    // ===
+    pub async fn export_starred_feed(
+        &self,
+        path: impl AsRef<Path>,
+        meta: ChannelMeta,
+        format: FeedFormat,
+    ) -> std::io::Result<usize> {
+        let entries = self.starred_articles().await;
+        let xml = Self::render_feed(&entries, meta, format);
+        write_atomic(path.as_ref(), xml.as_bytes()).await?;
+        Ok(entries.len())
+    }
+
+    // ===
+    //
+    //
+    // Nombre d’écritures de read_store.json depuis le chargement (diagnostic).
+    //
+    //
+    // ===
+    pub fn read_store_writes(&self) -> usize {
+        self.read_writes.load(Ordering::Relaxed)
+    }
+
+    // ===
+    //
+    //
+    // Définit le nombre maximal d’articles conservés par flux (appliqué au prochain upsert).
+    //
+    //
+    // ===
+    pub fn set_max_articles_per_feed(&self, max: usize) {
+        self.max_articles_per_feed
+            .store(max.max(1), Ordering::Relaxed);
+    }
+
+    // ===
+    //
+    //
+    // Nombre maximal d’articles conservés par flux.
+    //
+    //
+    // ===
+    pub fn max_articles_per_feed(&self) -> usize {
+        self.max_articles_per_feed.load(Ordering::Relaxed)
+    }
+
+    // ===
+    //
+    //
+    // Définit la longueur maximale (en graphèmes) des titres de flux, appliquée aux ajouts
+    // suivants (voir FeedDescriptor::normalized).
+    //
+    //
+    // ===
+    pub fn set_max_title_chars(&self, max: usize) {
+        self.max_title_chars.store(max.max(1), Ordering::Relaxed);
+    }
+
+    // Longueur maximale des titres de flux enregistrés.
+    pub fn max_title_chars(&self) -> usize {
+        self.max_title_chars.load(Ordering::Relaxed)
+    }
+
+    // ===
+    //
+    //
+    // Upsert et persiste un lot d’articles pour un feed (déduplication, tri décroissant, éviction).
+    // Un article déjà présent sous la même identité est remplacé par la nouvelle version
+    // (article modifié par l’éditeur); son état lu/favori, attaché à l’identité, est conservé.
+    //
+    // Ordre d’éviction au-delà de la limite: les articles lus partent en premier (du plus
+    // ancien au plus récent), puis seulement les non lus, eux aussi du plus ancien. La
+    // politique de conservation du flux (RetentionPolicy) remplace ou complète cette limite;
+    // l’état lu/favori des articles évincés est oublié.
+    //
+    //
+    // ===
+    pub async fn upsert_articles(
+        &self,
+        feed_id: &str,
+        entries: Vec<FeedEntry>,
+    ) -> Result<(), DataError> {
+        let max_per_feed = self.max_articles_per_feed();
+        let retention = self.retention_of(feed_id).await;
+        let read_keys: HashSet<String> = {
+            let read = self.read_inner.read().await;
+            read.read.get(feed_id).cloned().unwrap_or_default()
+        };
+        let mut inner = self.articles_inner.write().await;
+        let slot = inner.entry(feed_id.to_string()).or_default();
+        let mut positions: HashMap<String, usize> = slot
+            .iter()
+            .enumerate()
+            .map(|(index, e)| (e.identity(), index))
+            .collect();
+        let mut existing: HashSet<String> = slot.iter().filter_map(FeedEntry::url_key).collect();
+        for e in entries {
+            let identity = e.identity();
+            if let Some(&index) = positions.get(&identity) {
+                // La première récupération connue reste la date de tri des articles sans date
+                let first_seen_at = slot[index].first_seen_at.min(e.first_seen_at);
+                slot[index] = FeedEntry { first_seen_at, ..e };
+                continue;
+            }
+            // Un GUID régénéré ne doit pas dupliquer un article déjà stocké sous le même lien
+            if e.url_key().is_some_and(|key| existing.contains(&key)) {
+                continue;
+            }
+            existing.extend(e.url_key());
+            positions.insert(identity, slot.len());
+            slot.push(e);
+        }
+        let removed = retain_articles(
+            slot,
+            &read_keys,
+            max_per_feed,
+            retention.as_ref(),
+            Utc::now(),
+        );
+        drop(inner);
+        self.touch(Store::Articles);
+        if forget_entries(&mut *self.read_inner.write().await, &removed) {
+            self.touch(Store::Read);
+        }
+        if forget_positions(&mut *self.positions_inner.write().await, &removed) {
+            self.touch(Store::Positions);
+        }
+        self.save().await
+    }
+
+    // ===
+    //
+    //
+    // Politique de conservation configurée pour un flux (None si le flux n’en a pas).
+    //
+    //
+    // ===
+    async fn retention_of(&self, feed_id: &str) -> Option<RetentionPolicy> {
+        let feeds = self.feeds.read().await;
+        feeds
+            .iter()
+            .find(|f| f.id == feed_id)
+            .and_then(|f| f.retention.clone())
+    }
+
+    // ===
+    //
+    //
+    // Nettoie le cache à la demande: applique la conservation de chaque flux à ses articles,
+    // supprime les articles et l’état lu/favori des flux qui n’existent plus, puis persiste.
+    //
+    //
+    // ===
+    pub async fn cleanup(&self) -> Result<CleanupReport, DataError> {
+        self.cleanup_at(Utc::now()).await
+    }
+
+    // ===
+    //
+    //
+    // Comme `cleanup`, avec la date de référence des politiques `MaxAge` fournie.
+    //
+    //
+    // ===
+    pub async fn cleanup_at(&self, now: DateTime<Utc>) -> Result<CleanupReport, DataError> {
+        let policies: HashMap<String, Option<RetentionPolicy>> = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .map(|f| (f.id, f.retention))
+            .collect();
+        let max_per_feed = self.max_articles_per_feed();
+        let size_before = self.stored_size().await;
+
+        let mut removed = Vec::new();
+        let mut orphans = 0;
+        {
+            let read = self.read_inner.read().await;
+            let mut inner = self.articles_inner.write().await;
+            inner.retain(|feed_id, slot| {
+                let known = policies.contains_key(feed_id);
+                if !known {
+                    orphans += slot.len();
+                }
+                known
+            });
+            for (feed_id, slot) in inner.iter_mut() {
+                let read_keys = read.read.get(feed_id).cloned().unwrap_or_default();
+                let retention = policies.get(feed_id).and_then(Option::as_ref);
+                removed.extend(retain_articles(
+                    slot,
+                    &read_keys,
+                    max_per_feed,
+                    retention,
+                    now,
+                ));
+            }
+        }
+        {
+            let known = |feed_id: &String, _: &mut HashSet<String>| policies.contains_key(feed_id);
+            let mut inner = self.read_inner.write().await;
+            inner.read.retain(known);
+            inner.starred.retain(known);
+            forget_entries(&mut inner, &removed);
+        }
+        {
+            let mut positions = self.positions_inner.write().await;
+            positions.retain(|p| policies.contains_key(&p.feed_id));
+            forget_positions(&mut positions, &removed);
+        }
+
+        self.touch(Store::Articles);
+        self.touch(Store::Read);
+        self.touch(Store::Positions);
+        self.save().await?;
+        Ok(CleanupReport {
+            removed: removed.len() + orphans,
+            bytes_saved: size_before.saturating_sub(self.stored_size().await),
+        })
+    }
+
+    // ===
+    //
+    //
+    // Taille sérialisée du cache d’articles et de l’état de lecture (tels qu’écrits sur disque).
+    //
+    //
+    // ===
+    async fn stored_size(&self) -> u64 {
+        let articles = serde_json::to_vec_pretty(&*self.articles_inner.read().await)
+            .map_or(0, |bytes| bytes.len());
+        let read = serde_json::to_vec_pretty(&*self.read_inner.read().await)
+            .map_or(0, |bytes| bytes.len());
+        (articles + read) as u64
+    }
+
+    // ===
+    //
+    //
+    // Compacte les données: `compact_at` avec les marques plus anciennes que
+    // COMPACT_MARKS_AFTER.
+    //
+    //
+    // ===
+    pub async fn compact(&self) -> Result<CompactionReport, DataError> {
+        let age = chrono::Duration::from_std(COMPACT_MARKS_AFTER).unwrap_or_default();
+        self.compact_at(Some(Utc::now() - age)).await
+    }
+
+    // Compactage léger (démarrage): flux supprimés et doublons seulement.
+    pub async fn compact_light(&self) -> Result<CompactionReport, DataError> {
+        self.compact_at(None).await
+    }
+
+    // ===
+    //
+    //
+    // Compacte le cache et l’état de lecture: supprime les données des flux qui n’existent
+    // plus et les articles en double (même identité ou même lien, le premier est gardé).
+    //
+    // Avec `marks_before`, oublie aussi les marques « lu » et les “vus” sans article en cache
+    // et antérieurs à cette date. Leur âge se déduit de l’ordre des “vus”: une entrée vue
+    // avant le plus récent article en cache découvert avant `marks_before` est plus ancienne.
+    // Une marque « lu » qui n’a plus ni article ni “vu” est oubliée dans tous les cas. Les
+    // “vus” d’un flux dont le cache est plein sont gardés: le flux peut encore servir des
+    // articles évincés, qui reviendraient comme nouveaux.
+    //
+    //
+    // ===
+    pub async fn compact_at(
+        &self,
+        marks_before: Option<DateTime<Utc>>,
+    ) -> Result<CompactionReport, DataError> {
+        let max_per_feed = self.max_articles_per_feed();
+        let limits: HashMap<String, usize> = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .map(|f| {
+                let limit = match f.retention {
+                    Some(RetentionPolicy::MaxCount(count)) => count,
+                    Some(RetentionPolicy::KeepAll) => usize::MAX,
+                    _ => max_per_feed,
+                };
+                (f.id, limit)
+            })
+            .collect();
+        let bytes_before = self.compacted_size().await;
+
+        let mut removed_articles = 0;
+        {
+            let mut inner = self.articles_inner.write().await;
+            inner.retain(|feed_id, slot| {
+                let known = limits.contains_key(feed_id);
+                if !known {
+                    removed_articles += slot.len();
+                }
+                known
+            });
+            removed_articles += inner.values_mut().map(dedup_articles).sum::<usize>();
+        }
+        let mut removed_read_marks = 0;
+        {
+            let mut inner = self.read_inner.write().await;
+            inner.read.retain(|feed_id, keys| {
+                let known = limits.contains_key(feed_id);
+                if !known {
+                    removed_read_marks += keys.len();
+                }
+                known
+            });
+            inner
+                .starred
+                .retain(|feed_id, _| limits.contains_key(feed_id));
+        }
+        self.positions_inner
+            .write()
+            .await
+            .retain(|p| limits.contains_key(&p.feed_id));
+        self.seen
+            .retain_feeds(|feed_id| limits.contains_key(feed_id))
+            .await;
+
+        if let Some(cutoff) = marks_before {
+            let seen = self.seen.snapshot().await;
+            // Identités en cache, numéro d’ordre limite et cache plein, par flux
+            let plans: Vec<(String, HashSet<String>, Option<u64>, bool)> = {
+                let articles = self.articles_inner.read().await;
+                articles
+                    .iter()
+                    .map(|(feed_id, slot)| {
+                        let ids = seen.seen.get(feed_id);
+                        let horizon = slot
+                            .iter()
+                            .filter(|e| e.first_seen_at <= cutoff)
+                            .filter_map(|e| ids?.get(&e.identity()).copied())
+                            .max();
+                        let cached = slot
+                            .iter()
+                            .flat_map(|e| {
+                                [e.identity(), e.legacy_identity()]
+                                    .into_iter()
+                                    .chain(e.url_key())
+                            })
+                            .collect();
+                        let full = limits
+                            .get(feed_id)
+                            .is_some_and(|limit| slot.len() >= *limit);
+                        (feed_id.clone(), cached, horizon, full)
+                    })
+                    .collect()
+            };
+            {
+                let mut inner = self.read_inner.write().await;
+                for (feed_id, keys) in inner.read.iter_mut() {
+                    let plan = plans.iter().find(|(id, ..)| id == feed_id);
+                    let cached = plan.map(|(_, cached, ..)| cached);
+                    let horizon = plan.and_then(|(_, _, horizon, _)| *horizon);
+                    let ids = seen.seen.get(feed_id);
+                    let before = keys.len();
+                    keys.retain(|key| {
+                        if cached.is_some_and(|cached| cached.contains(key)) {
+                            return true;
+                        }
+                        match ids.and_then(|ids| ids.get(key)) {
+                            Some(seq) => horizon.is_none_or(|horizon| *seq >= horizon),
+                            None => false,
+                        }
+                    });
+                    removed_read_marks += before - keys.len();
+                }
+            }
+            for (feed_id, cached, horizon, full) in &plans {
+                if let (Some(horizon), false) = (horizon, full) {
+                    self.seen.prune(feed_id, *horizon, cached).await;
+                }
+            }
+        }
+
+        self.touch(Store::Articles);
+        self.touch(Store::Read);
+        self.touch(Store::Positions);
+        self.save().await?;
+        Ok(CompactionReport {
+            removed_articles,
+            removed_read_marks,
+            bytes_before,
+            bytes_after: self.compacted_size().await,
+        })
+    }
+
+    // Taille sérialisée des données visées par le compactage (articles, lecture, “vus”).
+    async fn compacted_size(&self) -> u64 {
+        let seen = serde_json::to_vec_pretty(&self.seen.snapshot().await).map_or(0, |b| b.len());
+        self.stored_size().await + seen as u64
+    }
+
+    // ===
+    //
+    //
+    // Exporte l’état (flux, lus, articles, vus) dans un fichier JSON versionné (écriture via .tmp).
+    //
+    //
+    // ===
+    pub async fn export_state(&self, path: impl AsRef<Path>) -> Result<(), StateError> {
+        let path = path.as_ref();
+        let seen = self.seen.snapshot().await;
+        let bundle = StateBundle {
+            schema_version: STATE_SCHEMA_VERSION,
+            exported_at: Utc::now(),
+            feeds: list_feeds(&self.feeds).await,
+            read: self.read_inner.read().await.read.clone(),
+            articles: self.articles_inner.read().await.clone(),
+            seen,
+            starred: self.read_inner.read().await.starred.clone(),
+        };
+        let bytes = serde_json::to_vec_pretty(&bundle)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, &bytes).await?;
+        tokio::fs::rename(&tmp, path).await?;
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Importe un état exporté en le fusionnant avec l’état local: flux inconnus ajoutés,
+    // union des articles lus et des identités vues, et pour un même article la version la plus
+    // récente l’emporte. Un fichier d’une version de schéma future est refusé sans rien modifier.
+    //
+    //
+    // ===
+    pub async fn import_state(&self, path: impl AsRef<Path>) -> Result<ImportSummary, StateError> {
+        let bytes = tokio::fs::read(path.as_ref()).await?;
+        let value: serde_json::Value = serde_json::from_slice(&bytes)?;
+        let version = value
+            .get("schema_version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or(StateError::MissingVersion)?;
+        if version > u64::from(STATE_SCHEMA_VERSION) {
+            return Err(StateError::UnsupportedVersion {
+                found: version,
+                supported: STATE_SCHEMA_VERSION,
+            });
+        }
+        let bundle: StateBundle = serde_json::from_value(value)?;
+        let mut summary = ImportSummary::default();
+
+        let known: HashSet<String> = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .map(|f| f.id)
+            .collect();
+        for feed in bundle.feeds {
+            if !known.contains(&feed.id) {
+                add_feed(&self.feeds, feed).await;
+                summary.feeds_added += 1;
+            }
+        }
+
+        {
+            let mut inner = self.read_inner.write().await;
+            for (feed_id, ids) in bundle.read {
+                let set = inner.read.entry(feed_id).or_default();
+                for id in ids {
+                    if set.insert(id) {
+                        summary.read_added += 1;
+                    }
+                }
+            }
+            for (feed_id, ids) in bundle.starred {
+                inner.starred.entry(feed_id).or_default().extend(ids);
+            }
+            self.stars_revision.fetch_add(1, Ordering::Relaxed);
+        }
+
+        {
+            let max_per_feed = self.max_articles_per_feed();
+            let read = self.read_inner.read().await;
+            let mut inner = self.articles_inner.write().await;
+            for (feed_id, entries) in bundle.articles {
+                let read_keys = read.read.get(&feed_id).cloned().unwrap_or_default();
+                let slot = inner.entry(feed_id).or_default();
+                for e in entries {
+                    let id = e.identity();
+                    let url_key = e.url_key();
+                    let existing = slot.iter().position(|s| {
+                        s.identity() == id || (url_key.is_some() && s.url_key() == url_key)
+                    });
+                    match existing {
+                        Some(i) if e.published_at > slot[i].published_at => {
+                            slot[i] = e;
+                            summary.articles_updated += 1;
+                        }
+                        Some(_) => {}
+                        None => {
+                            slot.push(e);
+                            summary.articles_added += 1;
+                        }
+                    }
+                }
+                trim_articles(slot, &read_keys, max_per_feed);
+            }
+        }
+
+        if summary.feeds_added > 0 {
+            self.touch(Store::Feeds);
+        }
+        self.touch(Store::Read);
+        self.touch(Store::Articles);
+        self.seen.merge(bundle.seen).await;
+        self.save().await?;
+        Ok(summary)
+    }
+
+    // ===
+    //
+    //
+    // Exporte des articles dans `dir`, un fichier par article (Markdown ou HTML), avec des noms
+    // dérivés des titres et jamais écrasés. Retourne les chemins écrits, dans l’ordre.
+    //
+    //
+    // ===
+    pub async fn export_articles(
+        &self,
+        entries: &[FeedEntry],
+        dir: impl AsRef<Path>,
+        format: ExportFormat,
+    ) -> std::io::Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        tokio::fs::create_dir_all(dir).await?;
+        let titles: HashMap<String, String> = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .map(|f| (f.id, f.title))
+            .collect();
+        let mut taken = HashSet::new();
+        let mut written = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let feed_title = titles
+                .get(&entry.feed_id)
+                .map(String::as_str)
+                .unwrap_or(&entry.feed_id);
+            let path = unique_export_path(dir, &entry.title, format, &mut taken);
+            tokio::fs::write(&path, format.render(entry, feed_title)).await?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    // ===
+    //
+    //
+    // Liste les articles persistés pour un feed donné.
+    //
+    //
+    // ===
+    pub async fn list_articles(&self, feed_id: &str) -> Vec<FeedEntry> {
+        let inner = self.articles_inner.read().await;
+        inner.get(feed_id).cloned().unwrap_or_default()
+    }
+
+    // ===
+    //
+    //
+    // Enregistre le résultat d’une récupération dans les statistiques du flux, sans écrire:
+    // les fichiers sont réécrits une fois par cycle (flush_pending, à CycleCompleted). Tant
+    // qu’une série d’échecs est en cours, la liste des flux est aussi à réécrire pour conserver
+    // le compteur d’échecs consécutifs (et l’éventuelle désactivation) au redémarrage.
+    // De nouveaux articles mettent à jour `last_new_article_at` (intervalle adaptatif).
+    //
+    //
+    // ===
+    pub async fn record_fetch(&self, feed_id: &str, outcome: FetchOutcome) {
+        let mut inner = self.stats_inner.write().await;
+        let stats = inner.entry(feed_id.to_string()).or_default();
+        let previous_failed = stats.last.as_ref().is_some_and(|last| !last.success);
+        let failed = !outcome.success;
+        let new_articles_at = (outcome.new_items > 0).then_some(outcome.fetched_at);
+        stats.record(outcome);
+        drop(inner);
+        self.touch(Store::Stats);
+        if failed || previous_failed {
+            self.touch(Store::Feeds);
+        }
+        if let Some(at) = new_articles_at {
+            if record_new_articles(&self.feeds, feed_id, at).await {
+                self.touch(Store::Feeds);
+            }
+        }
+    }
+
+    // ===
+    //
+    //
+    // Réactive un flux désactivé après des échecs répétés (compteur remis à zéro) et persiste.
+    //
+    //
+    // ===
+    pub async fn reenable_feed(&self, feed_id: &str) -> Result<(), DataError> {
+        reenable_feed(&self.feeds, feed_id).await;
+        self.persist(Store::Feeds).await
+    }
+
+    // ===
+    //
+    //
+    // Met un flux en sourdine jusqu’à `until` (None la lève) et persiste si cela change.
+    //
+    //
+    // ===
+    pub async fn mute_feed(
+        &self,
+        feed_id: &str,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<bool, DataError> {
+        let updated = mute_feed(&self.feeds, feed_id, until).await;
+        if updated {
+            self.persist(Store::Feeds).await?;
+        }
+        Ok(updated)
+    }
+
+    // ===
+    //
+    //
+    // Règles de filtrage enregistrées (`rules.json`), dans leur ordre.
+    //
+    //
+    // ===
+    pub async fn rules(&self) -> Vec<FilterRule> {
+        self.rules_inner.read().await.rules()
+    }
+
+    // ===
+    //
+    //
+    // Remplace les règles de filtrage et persiste. Refusé sans rien changer si une règle est
+    // invalide (motif vide, expression régulière incorrecte).
+    //
+    //
+    // ===
+    pub async fn set_rules(&self, rules: Vec<FilterRule>) -> Result<(), RuleError> {
+        let compiled = RuleSet::compile(&rules)?;
+        *self.rules_inner.write().await = compiled;
+        self.persist(Store::Rules).await?;
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Applique les règles à de nouveaux articles (voir RuleSet::apply): renvoie les articles à
+    // enregistrer et ceux à marquer comme lus.
+    //
+    //
+    // ===
+    pub async fn apply_rules(&self, entries: Vec<FeedEntry>) -> (Vec<FeedEntry>, Vec<FeedEntry>) {
+        self.rules_inner.read().await.apply(entries)
+    }
+
+    // ===
+    //
+    //
+    // Reporte la nouvelle URL d’un flux déplacé (Event::FeedMoved) et persiste si elle change.
+    //
+    //
+    // ===
+    pub async fn update_feed_url(&self, feed_id: &str, url: &str) -> Result<bool, DataError> {
+        let updated = update_feed_url(&self.feeds, feed_id, url).await;
+        if updated {
+            self.persist(Store::Feeds).await?;
+        }
+        Ok(updated)
+    }
+
+    // ===
+    //
+    //
+    // Renomme un flux (titre du canal d’un flux ajouté sans titre: Event::FeedTitle) et
+    // persiste si le titre change.
+    //
+    //
+    // ===
+    pub async fn update_feed_title(&self, feed_id: &str, title: &str) -> Result<bool, DataError> {
+        let updated = update_feed_title(&self.feeds, feed_id, title).await;
+        if updated {
+            self.persist(Store::Feeds).await?;
+        }
+        Ok(updated)
+    }
+
+    // ===
+    //
+    //
+    // Enregistre les métadonnées du canal d’un flux (Event::FeedMetadata); persiste si elles
+    // changent.
+    //
+    //
+    // ===
+    pub async fn update_feed_metadata(
+        &self,
+        feed_id: &str,
+        meta: FeedMeta,
+    ) -> Result<bool, DataError> {
+        let updated = update_feed_metadata(&self.feeds, feed_id, meta).await;
+        if updated {
+            self.persist(Store::Feeds).await?;
+        }
+        Ok(updated)
+    }
+
+    fn icon_path(&self, feed_id: &str) -> PathBuf {
+        self.icons_dir.join(icon_file_name(feed_id))
+    }
+
+    // ===
+    //
+    //
+    // Chemin de l’icône en cache d’un flux (None si aucune icône n’a été trouvée).
+    //
+    //
+    // ===
+    pub async fn feed_icon(&self, feed_id: &str) -> Option<PathBuf> {
+        if !self.persistent {
+            return None;
+        }
+        let path = self.icon_path(feed_id);
+        let meta = tokio::fs::metadata(&path).await.ok()?;
+        (meta.len() > 0).then_some(path)
+    }
+
+    // ===
+    //
+    //
+    // Récupère l’icône d’un flux si elle date de plus d’une semaine (ou n’a jamais été cherchée)
+    // et la met en cache. Un échec est aussi mémorisé (ancienne icône gardée, sinon fichier
+    // vide) pour ne pas réessayer avant le prochain délai.
+    //
+    //
+    // ===
+    pub async fn refresh_feed_icon(&self, client: &Client, feed_id: &str) -> Option<PathBuf> {
+        if !self.persistent {
+            return None;
+        }
+        let path = self.icon_path(feed_id);
+        let fresh = tokio::fs::metadata(&path)
+            .await
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age < ICON_REFRESH_INTERVAL);
+        if fresh {
+            return self.feed_icon(feed_id).await;
+        }
+        let feed = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .find(|f| f.id == feed_id)?;
+        let entries = self.list_articles(feed_id).await;
+        let fetched = match site_root(&feed.url, &entries) {
+            Some(site) => fetch_favicon(client, &site).await,
+            None => None,
+        };
+        let bytes = match fetched {
+            Some(bytes) => bytes,
+            None => tokio::fs::read(&path).await.unwrap_or_default(),
+        };
+        if let Err(e) = tokio::fs::create_dir_all(&self.icons_dir).await {
+            warn!(error = %e, "failed to create icons dir");
+            return None;
+        }
+        if let Err(e) = write_atomic(&path, &bytes).await {
+            warn!(error = %e, path = %path.display(), "failed to cache feed icon");
+        }
+        self.feed_icon(feed_id).await
+    }
+
+    // ===
+    //
+    //
+    // Statistiques de santé de chaque flux (feed_id -> FeedStats).
+    //
+    //
+    // ===
+    pub async fn feed_stats(&self) -> HashMap<String, FeedStats> {
+        self.stats_inner.read().await.clone()
+    }
+
+    // ===
+    //
+    //
+    // Statistiques de lecture de la période (vue Statistiques), calculées sur le cache
+    // d’articles et le store de lecture; les jours sont ceux du fuseau local.
+    //
+    //
+    // ===
+    pub async fn stats(&self, range: StatsRange) -> Stats {
+        let all = self.list_all_articles().await;
+        let feed_stats = self.stats_inner.read().await;
+        let inner = self.read_inner.read().await;
+        let is_read = |entry: &FeedEntry| {
+            inner.read.get(&entry.feed_id).is_some_and(|set| {
+                set.contains(&entry.identity()) || set.contains(&entry.legacy_identity())
+            })
+        };
+        compute_stats(&all, is_read, &feed_stats, range, &chrono::Local)
+    }
+
+    // ===
+    //
+    //
+    // Catégories du cache d’articles avec leur nombre d’articles, les plus fréquentes d’abord
+    // (puis par nom). Les variantes de casse sont regroupées sous une même orthographe.
+    //
+    //
+    // ===
+    pub async fn tags_with_counts(&self) -> Vec<(String, usize)> {
+        let inner = self.articles_inner.read().await;
+        let mut tags: HashMap<String, (String, usize)> = HashMap::new();
+        for category in inner.values().flatten().flat_map(|e| &e.categories) {
+            let (name, count) = tags
+                .entry(category.to_lowercase())
+                .or_insert_with(|| (category.clone(), 0));
+            if category < name {
+                name.clone_from(category);
+            }
+            *count += 1;
+        }
+        let mut tags: Vec<(String, usize)> = tags.into_values().collect();
+        tags.sort_by(|(a, ca), (b, cb)| {
+            cb.cmp(ca)
+                .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+                .then_with(|| a.cmp(b))
+        });
+        tags
+    }
+
+    // ===
+    //
+    //
+    // Liste tous les articles persistés, toutes sources confondues.
     //
     //
     // ===
@@ -285,7 +2202,188 @@ impl DataApi {
         for v in inner.values() {
             all.extend(v.clone());
         }
-        all.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+        all.sort_by_key(|e| Reverse(e.sort_date()));
         all
     }
+
+    // ===
+    //
+    //
+    // Liste tous les articles en fusionnant les doublons publiés par plusieurs flux
+    // (même URL normalisée ou même GUID), du plus récent au plus ancien.
+    //
+    //
+    // ===
+    pub async fn list_all_articles_deduped(&self) -> Vec<DedupedEntry> {
+        let mut deduped = dedup_entries(self.list_all_articles().await);
+        deduped.sort_by_key(|d| Reverse(d.entry.sort_date()));
+        deduped
+    }
+}
+
+// Le poller (spawn_poller, spawn_event_poller) accepte directement l’API: il partage ses “vus”.
+impl From<&DataApi> for SeenStore {
+    fn from(api: &DataApi) -> Self {
+        api.seen.clone()
+    }
+}
+
+// ===
+//
+//
+// Borne les articles d’un flux: au-delà de `max`, les lus partent en premier (du plus ancien
+// au plus récent), puis les non lus; le résultat est trié du plus récent au plus ancien.
+//
+//
+// ===
+fn trim_articles(
+    slot: &mut Vec<FeedEntry>,
+    read_keys: &HashSet<String>,
+    max: usize,
+) -> Vec<FeedEntry> {
+    let mut removed = Vec::new();
+    if slot.len() > max {
+        slot.sort_by_key(|e| (read_keys.contains(&e.identity()), Reverse(e.sort_date())));
+        removed = slot.split_off(max);
+    }
+    slot.sort_by_key(|e| Reverse(e.sort_date()));
+    removed
+}
+
+// ===
+//
+//
+// Applique la politique de conservation d’un flux et retourne les articles retirés:
+// `KeepAll` ne retire rien, `MaxCount(n)` remplace la limite globale, `MaxAge` retire les
+// articles trop anciens puis applique la limite globale, et sans politique seule la limite
+// globale s’applique.
+//
+//
+// ===
+fn retain_articles(
+    slot: &mut Vec<FeedEntry>,
+    read_keys: &HashSet<String>,
+    max: usize,
+    retention: Option<&RetentionPolicy>,
+    now: DateTime<Utc>,
+) -> Vec<FeedEntry> {
+    match retention {
+        Some(RetentionPolicy::KeepAll) => {
+            slot.sort_by_key(|e| Reverse(e.sort_date()));
+            Vec::new()
+        }
+        Some(RetentionPolicy::MaxCount(count)) => trim_articles(slot, read_keys, *count),
+        Some(policy @ RetentionPolicy::MaxAge(_)) => {
+            let (mut removed, kept): (Vec<_>, Vec<_>) = std::mem::take(slot)
+                .into_iter()
+                .partition(|e| policy.is_expired(e.published_at, now));
+            *slot = kept;
+            removed.extend(trim_articles(slot, read_keys, max));
+            removed
+        }
+        None => trim_articles(slot, read_keys, max),
+    }
+}
+
+// Retire d’un cache les articles en double (même identité ou même lien); retourne leur nombre.
+fn dedup_articles(slot: &mut Vec<FeedEntry>) -> usize {
+    let before = slot.len();
+    let mut identities = HashSet::new();
+    let mut urls = HashSet::new();
+    slot.retain(|e| {
+        identities.insert(e.identity()) && e.url_key().is_none_or(|key| urls.insert(key))
+    });
+    before - slot.len()
+}
+
+// ===
+//
+//
+// Oublie l’état lu et favori d’articles retirés du cache (identité actuelle et ancienne).
+// Retourne true si quelque chose a été retiré.
+//
+//
+// ===
+fn forget_entries(data: &mut ReadData, removed: &[FeedEntry]) -> bool {
+    let mut changed = false;
+    for entry in removed {
+        let keys = [entry.identity(), entry.legacy_identity()];
+        for map in [&mut data.read, &mut data.starred] {
+            if let Some(set) = map.get_mut(&entry.feed_id) {
+                for key in &keys {
+                    changed |= set.remove(key);
+                }
+            }
+        }
+    }
+    changed
+}
+
+// Oublie les positions de lecture d’articles retirés du cache; true si quelque chose a été retiré.
+fn forget_positions(positions: &mut Vec<ReadingPosition>, removed: &[FeedEntry]) -> bool {
+    if removed.is_empty() {
+        return false;
+    }
+    let keys: HashSet<(&str, String)> = removed
+        .iter()
+        .map(|e| (e.feed_id.as_str(), e.identity()))
+        .collect();
+    let before = positions.len();
+    positions.retain(|p| !keys.contains(&(p.feed_id.as_str(), p.key.clone())));
+    positions.len() != before
+}
+
+// ===
+//
+//
+// Rejoue le journal des marques sur l’état chargé. Une ligne illisible (dernière ligne tronquée
+// par un arrêt brutal) est ignorée. Retourne true si le journal existait et n’était pas vide.
+//
+//
+// ===
+async fn replay_read_journal(path: &Path, read: &mut ReadData) -> bool {
+    let Ok(text) = tokio::fs::read_to_string(path).await else {
+        return false;
+    };
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<ReadMark>(line) {
+            Ok(mark) => {
+                read.apply(&mark);
+            }
+            Err(e) => warn!(error = %e, "unreadable read journal line ignored"),
+        }
+    }
+    !text.trim().is_empty()
+}
+
+// Ajoute une ligne au journal et la synchronise sur disque avant de rendre la main.
+async fn append_journal(path: &Path, mark: &ReadMark) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(mark).map_err(std::io::Error::other)?;
+    line.push(b'\n');
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        file.write_all(&line)?;
+        file.sync_data()
+    })
+    .await
+    .map_err(std::io::Error::other)?
+}
+
+// Supprime le journal une fois ses marques écrites dans read_store.json.
+async fn remove_journal(path: &Path) {
+    match tokio::fs::remove_file(path).await {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => warn!(error = %e, "failed to remove the read journal"),
+    }
+}
+
+// Format des fichiers gérés: JSON indenté, lisible et diffable.
+fn to_json<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Vec<u8>> {
+    serde_json::to_vec_pretty(value)
 }
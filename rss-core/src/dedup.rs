@@ -0,0 +1,378 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use url::Url;
+
+use crate::feed::FeedEntry;
+
+// Similarité (Jaccard des mots) à partir de laquelle deux titres sont regroupés.
+pub const DEFAULT_TITLE_SIMILARITY: f32 = 0.8;
+
+// Écart maximal entre deux articles d’un même groupe de titres proches.
+pub const TITLE_CLUSTER_WINDOW_HOURS: i64 = 48;
+
+// Longueur minimale (en caractères) d’un mot indexé pour chercher les groupes candidats.
+const MIN_INDEXED_WORD_CHARS: usize = 4;
+
+// ===
+//
+//
+// Normalise une URL d’article pour comparer des liens équivalents: supprime le fragment,
+// les paramètres de suivi `utm_*` et la barre oblique finale du chemin.
+//
+//
+// ===
+pub fn normalize_url(raw: &str) -> String {
+    let trimmed = raw.trim();
+    let mut url = match Url::parse(trimmed) {
+        Ok(url) => url,
+        Err(_) => {
+            let without_fragment = trimmed.split('#').next().unwrap_or_default();
+            return without_fragment.trim_end_matches('/').to_string();
+        }
+    };
+
+    url.set_fragment(None);
+    let kept: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| !key.to_ascii_lowercase().starts_with("utm_"))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(kept);
+    }
+
+    let path = url.path().to_string();
+    if path.len() > 1 && path.ends_with('/') {
+        url.set_path(path.trim_end_matches('/'));
+    }
+    url.to_string()
+}
+
+// ===
+//
+//
+// Clé de comparaison des URL de flux: hôte (en minuscules) et port, chemin sans barre
+// oblique finale et requête; le schéma (http/https) et le fragment sont ignorés.
+// None si l’URL n’est pas une adresse http(s) valide. Avec la feature `imap-feeds`, les
+// boîtes aux lettres (imap/imaps) sont aussi acceptées, le compte faisant partie de la clé.
+//
+//
+// ===
+pub fn feed_url_key(raw: &str) -> Option<String> {
+    let url = Url::parse(raw.trim()).ok()?;
+    let mailbox = cfg!(feature = "imap-feeds") && matches!(url.scheme(), "imap" | "imaps");
+    if !matches!(url.scheme(), "http" | "https") && !mailbox {
+        return None;
+    }
+    let host = url.host_str()?;
+    let mut key = host.to_string();
+    if mailbox {
+        key = format!("{}@{}", url.username(), key);
+    }
+    if let Some(port) = url.port() {
+        key.push_str(&format!(":{}", port));
+    }
+    key.push_str(url.path().trim_end_matches('/'));
+    if let Some(query) = url.query() {
+        key.push('?');
+        key.push_str(query);
+    }
+    Some(key)
+}
+
+// ===
+//
+//
+// Article agrégé après déduplication: l’entrée conservée et les flux qui la publient.
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq)]
+pub struct DedupedEntry {
+    pub entry: FeedEntry,
+    pub feed_ids: Vec<String>,
+    // Articles d’autres flux au titre presque identique, repliés sous celui-ci.
+    pub similar: Vec<FeedEntry>,
+}
+
+impl DedupedEntry {
+    // ===
+    //
+    //
+    // Flux ayant aussi publié l’article, hors flux de l’entrée conservée.
+    //
+    //
+    // ===
+    pub fn other_feed_ids(&self) -> impl Iterator<Item = &str> {
+        self.feed_ids
+            .iter()
+            .map(String::as_str)
+            .filter(move |id| *id != self.entry.feed_id)
+    }
+}
+
+// ===
+//
+//
+// Regroupe les entrées de flux différents dont l’URL normalisée ou le GUID coïncident.
+// Chaque groupe garde l’entrée publiée le plus tôt et apparaît à la position de son
+// premier membre dans l’ordre d’entrée.
+//
+//
+// ===
+pub fn dedup_entries(entries: Vec<FeedEntry>) -> Vec<DedupedEntry> {
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of: HashMap<String, usize> = HashMap::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        let keys = dedup_keys(entry);
+        let mut found: Option<usize> = None;
+        for key in &keys {
+            let Some(&group) = group_of.get(key) else {
+                continue;
+            };
+            match found {
+                None => found = Some(group),
+                Some(target) if target != group => {
+                    // l’entrée relie deux groupes: on fusionne le second dans le premier
+                    let moved = std::mem::take(&mut groups[group]);
+                    groups[target].extend(moved);
+                    for g in group_of.values_mut() {
+                        if *g == group {
+                            *g = target;
+                        }
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        let group = found.unwrap_or_else(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(index);
+        for key in keys {
+            group_of.insert(key, group);
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = groups.into_iter().filter(|g| !g.is_empty()).collect();
+    for group in &mut groups {
+        group.sort_unstable();
+    }
+    groups.sort_by_key(|g| g[0]);
+
+    let mut slots: Vec<Option<FeedEntry>> = entries.into_iter().map(Some).collect();
+    groups
+        .into_iter()
+        .map(|members| {
+            // la plus ancienne date l’emporte; les entrées sans date passent après
+            let kept = *members
+                .iter()
+                .min_by_key(|&&i| {
+                    let published = slots[i].as_ref().and_then(|e| e.published_at);
+                    (published.is_none(), published)
+                })
+                .expect("groupe non vide");
+            let mut feed_ids: Vec<String> = Vec::new();
+            for &i in &members {
+                let feed_id = &slots[i].as_ref().expect("entrée présente").feed_id;
+                if !feed_ids.contains(feed_id) {
+                    feed_ids.push(feed_id.clone());
+                }
+            }
+            DedupedEntry {
+                entry: slots[kept].take().expect("entrée présente"),
+                feed_ids,
+                similar: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+fn dedup_keys(entry: &FeedEntry) -> Vec<String> {
+    let mut keys = Vec::new();
+    if !entry.url.trim().is_empty() {
+        keys.push(format!("url:{}", normalize_url(&entry.url)));
+    }
+    if let Some(guid) = entry.guid.as_deref().filter(|g| !g.trim().is_empty()) {
+        keys.push(format!("guid:{}", guid));
+    }
+    keys
+}
+
+// ===
+//
+//
+// Titre normalisé pour la comparaison: minuscules, ponctuation remplacée par des espaces,
+// espaces fusionnés.
+//
+//
+// ===
+pub fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn title_words(title: &str) -> HashSet<String> {
+    normalize_title(title)
+        .split(' ')
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+// Similarité de deux titres: mots normalisés communs sur mots distincts (0 à 1).
+pub fn title_similarity(a: &str, b: &str) -> f32 {
+    jaccard(&title_words(a), &title_words(b))
+}
+
+// Groupe de titres proches: mots et date du premier article, flux déjà représentés.
+#[derive(Debug, Clone)]
+struct TitleCluster {
+    words: HashSet<String>,
+    date: DateTime<Utc>,
+    feed_ids: Vec<String>,
+}
+
+// ===
+//
+//
+// Regroupement incrémental des titres presque identiques publiés par des flux différents:
+// chaque article rejoint le groupe le plus proche (similarité ≥ seuil avec le premier article
+// du groupe, moins de 48 h d’écart, flux pas encore représenté) ou en ouvre un nouveau. Les
+// groupes candidats sont retrouvés par les mots longs qu’ils partagent avec le titre.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct TitleClusters {
+    threshold: f32,
+    clusters: Vec<TitleCluster>,
+    by_word: HashMap<String, Vec<usize>>,
+    // (flux, identité) -> groupe
+    member_of: HashMap<(String, String), usize>,
+}
+
+impl TitleClusters {
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            clusters: Vec::new(),
+            by_word: HashMap::new(),
+            member_of: HashMap::new(),
+        }
+    }
+
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    // ===
+    //
+    //
+    // Range un article et retourne son groupe; un article déjà rangé garde le sien.
+    //
+    //
+    // ===
+    pub fn insert(&mut self, entry: &FeedEntry) -> usize {
+        let key = (entry.feed_id.clone(), entry.identity());
+        if let Some(&cluster) = self.member_of.get(&key) {
+            return cluster;
+        }
+        let words = title_words(&entry.title);
+        let date = entry.sort_date();
+        let window = Duration::hours(TITLE_CLUSTER_WINDOW_HOURS);
+        let mut candidates: Vec<usize> = index_words(&words)
+            .filter_map(|w| self.by_word.get(w))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        let best = candidates
+            .into_iter()
+            .filter(|&c| {
+                let cluster = &self.clusters[c];
+                (cluster.date - date).abs() <= window && !cluster.feed_ids.contains(&key.0)
+            })
+            .map(|c| (c, jaccard(&words, &self.clusters[c].words)))
+            .filter(|(_, similarity)| *similarity >= self.threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+        let cluster = match best {
+            Some((cluster, _)) => {
+                self.clusters[cluster].feed_ids.push(key.0.clone());
+                cluster
+            }
+            None => {
+                let cluster = self.clusters.len();
+                for word in index_words(&words) {
+                    self.by_word.entry(word.clone()).or_default().push(cluster);
+                }
+                self.clusters.push(TitleCluster {
+                    words,
+                    date,
+                    feed_ids: vec![key.0.clone()],
+                });
+                cluster
+            }
+        };
+        self.member_of.insert(key, cluster);
+        cluster
+    }
+
+    // Groupe d’un article déjà rangé.
+    pub fn cluster_of(&self, entry: &FeedEntry) -> Option<usize> {
+        self.member_of
+            .get(&(entry.feed_id.clone(), entry.identity()))
+            .copied()
+    }
+}
+
+// Mots servant à retrouver les groupes candidats: les mots longs, à défaut tous les mots.
+fn index_words(words: &HashSet<String>) -> impl Iterator<Item = &String> {
+    let long = words
+        .iter()
+        .any(|w| w.chars().count() >= MIN_INDEXED_WORD_CHARS);
+    words
+        .iter()
+        .filter(move |w| !long || w.chars().count() >= MIN_INDEXED_WORD_CHARS)
+}
+
+// ===
+//
+//
+// Groupes de titres proches (indices dans `entries`), dans l’ordre de leur premier membre.
+// Les articles sont rangés dans l’ordre donné: le premier d’un groupe sert de référence.
+//
+//
+// ===
+pub fn cluster_titles(entries: &[FeedEntry], threshold: f32) -> Vec<Vec<usize>> {
+    let mut clusters = TitleClusters::new(threshold);
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let cluster = clusters.insert(entry);
+        if cluster == groups.len() {
+            groups.push(Vec::new());
+        }
+        groups[cluster].push(index);
+    }
+    groups
+}
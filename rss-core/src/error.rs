@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use thiserror::Error;
 
 // ===
@@ -10,8 +12,9 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum PollError {
+    // L’URL est retirée à la conversion (voir From<reqwest::Error>): elle peut porter des secrets.
     #[error("network error: {0}")]
-    Network(#[from] reqwest::Error),
+    Network(reqwest::Error),
     #[error("feed parsing error: {0}")]
     Parse(#[from] rss::Error),
     #[error("poller task failed: {0}")]
@@ -20,8 +23,194 @@ pub enum PollError {
     UpdateChannelClosed,
     #[error("unsupported URL scheme (https required)")]
     UnsupportedScheme,
+    #[error("plain HTTP not allowed (see network.allow_http or the feed's allow_insecure)")]
+    HttpNotAllowed,
     #[error("invalid feed url: {0}")]
     InvalidUrl(#[from] url::ParseError),
     #[error("feed too large: {0} bytes")]
     TooLarge(u64),
+    #[error("unexpected HTTP status: {0}")]
+    HttpStatus(u16),
+    #[error("HTTP status {status}, retry after {}s", retry_after.as_secs())]
+    RetryAfter {
+        status: u16,
+        retry_after: std::time::Duration,
+    },
+    #[error("feed is gone (HTTP 410), consider unsubscribing")]
+    Gone,
+    #[error("too many redirects (more than {})", crate::http::MAX_REDIRECTS)]
+    TooManyRedirects,
+    #[error(
+        "not a feed (content-type: {}, looks like HTML: {looks_like_html})",
+        content_type.as_deref().unwrap_or("unknown")
+    )]
+    NotAFeed {
+        content_type: Option<String>,
+        looks_like_html: bool,
+    },
+    #[error("invalid proxy configuration: {0}")]
+    InvalidProxy(String),
+    // Seul le nom est conservé: la valeur peut être une clé d’API.
+    #[error("invalid HTTP header: {0}")]
+    InvalidHeader(String),
+    #[error("invalid content selector {selector:?}: {reason}")]
+    InvalidSelector { selector: String, reason: String },
+    #[error("sync server rejected the API key")]
+    SyncAuth,
+    #[error("unexpected sync server response: {0}")]
+    SyncResponse(String),
+    // Boîte aux lettres suivie comme un flux (feature `imap-feeds`): réponse du serveur IMAP.
+    #[error("mailbox error: {0}")]
+    Mailbox(String),
+    #[error(transparent)]
+    Data(#[from] DataError),
+}
+
+impl From<reqwest::Error> for PollError {
+    fn from(err: reqwest::Error) -> Self {
+        PollError::Network(err.without_url())
+    }
+}
+
+// ===
+//
+//
+// Erreurs de l’export/import de l’état (migration entre machines).
+//
+//
+// ===
+#[derive(Debug, Error)]
+pub enum StateError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid state file: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("state file has no schema version")]
+    MissingVersion,
+    #[error("unsupported state schema version {found} (supported up to {supported})")]
+    UnsupportedVersion { found: u64, supported: u32 },
+    #[error(transparent)]
+    Data(#[from] DataError),
+}
+
+// ===
+//
+//
+// Échec d’écriture d’un fichier géré par DataApi (flux, lectures, articles...): le chemin est
+// conservé pour dire à l’utilisateur ce qui n’a pas été enregistré. L’état en mémoire est
+// gardé et le fichier réécrit à la prochaine opération.
+//
+//
+// ===
+#[derive(Debug, Error)]
+pub enum DataError {
+    #[error("cannot write {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("cannot serialize {}: {source}", path.display())]
+    Serde {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl DataError {
+    // Fichier qui n’a pas pu être enregistré.
+    pub fn path(&self) -> &Path {
+        match self {
+            DataError::Io { path, .. } | DataError::Serde { path, .. } => path,
+        }
+    }
+}
+
+// ===
+//
+//
+// Refus d’ajout d’un flux: URL inutilisable, ou flux déjà suivi à la même adresse (le flux
+// existant est renvoyé pour que l’interface puisse y mener).
+//
+//
+// ===
+#[derive(Debug, Error)]
+pub enum AddFeedError {
+    #[error("feed already followed: {title} ({id})")]
+    Duplicate { id: String, title: String },
+    #[error("invalid feed url: {0}")]
+    InvalidUrl(String),
+    #[error(transparent)]
+    Data(#[from] DataError),
+}
+
+// ===
+//
+//
+// Règle de filtrage refusée (motif vide, expression régulière invalide), signalée dès la
+// saisie; `InvalidRule` situe la règle fautive dans la liste enregistrée.
+//
+//
+// ===
+#[derive(Debug, Error)]
+pub enum RuleError {
+    #[error("empty pattern")]
+    EmptyPattern,
+    #[error("invalid regular expression: {0}")]
+    InvalidRegex(String),
+    #[error("rule {}: {source}", index + 1)]
+    InvalidRule {
+        index: usize,
+        source: Box<RuleError>,
+    },
+    #[error(transparent)]
+    Data(#[from] DataError),
+}
+
+// ===
+//
+//
+// Erreurs des services « lire plus tard » (Wallabag...): le statut HTTP est conservé pour
+// être affiché tel quel à l’utilisateur.
+//
+//
+// ===
+#[derive(Debug, Error)]
+pub enum ReadLaterError {
+    #[error("network error: {0}")]
+    Network(reqwest::Error),
+    #[error("HTTP {0}")]
+    HttpStatus(u16),
+    #[error("authentication refused (HTTP {0})")]
+    Auth(u16),
+    #[error("invalid service url: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("unexpected response: {0}")]
+    Response(String),
+}
+
+impl From<reqwest::Error> for ReadLaterError {
+    fn from(err: reqwest::Error) -> Self {
+        ReadLaterError::Network(err.without_url())
+    }
+}
+
+// ===
+//
+//
+// Démarrage du serveur de rappel WebSub impossible (adresse d’écoute occupée ou invalide,
+// URL publique mal formée, service déjà arrêté).
+//
+//
+// ===
+#[derive(Debug, Error)]
+pub enum WebSubError {
+    #[error("cannot listen on {address}: {source}")]
+    Bind {
+        address: String,
+        source: std::io::Error,
+    },
+    #[error("invalid callback base url: {0}")]
+    InvalidCallback(String),
+    #[error("feed service is shut down")]
+    ShutDown,
 }
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::feed::FeedEntry;
+
+// Longueur maximale (en caractères) du nom de fichier dérivé du titre, hors suffixe et extension.
+const MAX_SLUG_CHARS: usize = 80;
+
+// ===
+//
+//
+// Format d’export d’un article sur disque.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+        }
+    }
+
+    // ===
+    //
+    //
+    // Rend l’article dans ce format.
+    //
+    //
+    // ===
+    pub fn render(self, entry: &FeedEntry, feed_title: &str) -> String {
+        match self {
+            ExportFormat::Markdown => entry.to_markdown(feed_title),
+            ExportFormat::Html => entry.to_html(feed_title),
+        }
+    }
+}
+
+// ===
+//
+//
+// Transforme un titre en nom de fichier sûr: lettres et chiffres en minuscules, tout le reste
+// (barres obliques, ponctuation, emoji...) remplacé par des tirets, et longueur bornée.
+//
+//
+// ===
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    let mut pending_dash = false;
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.extend(c.to_lowercase());
+        } else {
+            pending_dash = true;
+        }
+        if slug.chars().count() >= MAX_SLUG_CHARS {
+            break;
+        }
+    }
+    let slug: String = slug.chars().take(MAX_SLUG_CHARS).collect();
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "article".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+// ===
+//
+//
+// Chemin libre dans `dir` pour ce titre: `slug.ext`, puis `slug-2.ext`, `slug-3.ext`...
+// `taken` retient les noms déjà attribués dans le même lot d’export.
+//
+//
+// ===
+pub fn unique_export_path(
+    dir: &Path,
+    title: &str,
+    format: ExportFormat,
+    taken: &mut HashSet<PathBuf>,
+) -> PathBuf {
+    let slug = slugify(title);
+    let mut n = 1;
+    loop {
+        let name = if n == 1 {
+            format!("{}.{}", slug, format.extension())
+        } else {
+            format!("{}-{}.{}", slug, n, format.extension())
+        };
+        let path = dir.join(name);
+        if !taken.contains(&path) && !path.exists() {
+            taken.insert(path.clone());
+            return path;
+        }
+        n += 1;
+    }
+}
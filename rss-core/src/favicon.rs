@@ -0,0 +1,137 @@
+// ===
+//
+//
+// Icônes des flux (favicons) pour la barre latérale: racine du site déduite des liens des
+// articles (ou de l’URL du flux), puis `<link rel="icon">` de la page d’accueil ou, à défaut,
+// `/favicon.ico`. Seuls les formats reconnus (PNG, ICO, BMP) sous MAX_ICON_BYTES sont gardés.
+//
+//
+// ===
+
+use std::time::Duration;
+
+use reqwest::Client;
+use tracing::debug;
+use url::Url;
+
+use crate::content_extractor::attr_value;
+use crate::feed::FeedEntry;
+use crate::http::download_capped;
+use crate::sanitize::{find_tag_end, tag_name};
+
+// Taille maximale d’une icône conservée en cache.
+pub const MAX_ICON_BYTES: usize = 256 * 1024;
+
+// Délai minimal entre deux récupérations de l’icône d’un même flux.
+pub const ICON_REFRESH_INTERVAL: Duration = Duration::from_secs(7 * 24 * 3600);
+
+// Taille maximale lue de la page d’accueil pour y chercher la balise <link rel="icon">.
+const MAX_HOMEPAGE_BYTES: usize = 512 * 1024;
+
+const ICON_TIMEOUT: Duration = Duration::from_secs(10);
+
+// ===
+//
+//
+// Racine du site d’un flux: l’origine du premier lien d’article HTTP(S), sinon celle du flux
+// (un flux hébergé par un service tiers pointe souvent vers le vrai site dans ses articles).
+//
+//
+// ===
+pub fn site_root(feed_url: &str, entries: &[FeedEntry]) -> Option<Url> {
+    entries
+        .iter()
+        .map(|e| e.url.as_str())
+        .chain(std::iter::once(feed_url))
+        .filter_map(|u| Url::parse(u).ok())
+        .find(|u| matches!(u.scheme(), "http" | "https") && u.host().is_some())
+        .and_then(|u| u.join("/").ok())
+}
+
+// ===
+//
+//
+// Première icône déclarée par une page (`<link rel="icon">` ou `rel="shortcut icon"`),
+// résolue par rapport à `base`.
+//
+//
+// ===
+pub fn find_icon_link(html: &str, base: &Url) -> Option<Url> {
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        let Some(end) = find_tag_end(rest) else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        if tag_name(tag) != "link" || tag.starts_with('/') {
+            continue;
+        }
+        let is_icon = attr_value(tag, "rel").is_some_and(|rel| {
+            rel.split_whitespace()
+                .any(|t| t.eq_ignore_ascii_case("icon"))
+        });
+        if let Some(href) = attr_value(tag, "href").filter(|_| is_icon) {
+            if let Ok(url) = base.join(href.trim()) {
+                return Some(url);
+            }
+        }
+    }
+    None
+}
+
+// ===
+//
+//
+// Nom du fichier d’icône d’un flux dans le dossier `icons/` (identifiant réduit aux
+// caractères sûrs pour un nom de fichier).
+//
+//
+// ===
+pub fn icon_file_name(feed_id: &str) -> String {
+    feed_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+// Formats d’image reconnus par leur signature.
+fn is_supported_image(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"\x89PNG\r\n\x1a\n")
+        || bytes.starts_with(&[0, 0, 1, 0])
+        || bytes.starts_with(b"BM")
+}
+
+// ===
+//
+//
+// Récupère l’icône d’un site: celle déclarée par la page d’accueil, sinon `/favicon.ico`.
+// None si aucune icône exploitable n’a été trouvée.
+//
+//
+// ===
+pub async fn fetch_favicon(client: &Client, site: &Url) -> Option<Vec<u8>> {
+    let declared = match download_capped(client, site, MAX_HOMEPAGE_BYTES, ICON_TIMEOUT).await {
+        Ok(page) => find_icon_link(&String::from_utf8_lossy(&page), site),
+        Err(e) => {
+            debug!(error = %e, "homepage unavailable for favicon lookup");
+            None
+        }
+    };
+    let fallback = site.join("/favicon.ico").ok();
+    for candidate in declared.into_iter().chain(fallback) {
+        match download_capped(client, &candidate, MAX_ICON_BYTES, ICON_TIMEOUT).await {
+            Ok(bytes) if is_supported_image(&bytes) => return Some(bytes),
+            Ok(_) => debug!(url = %candidate, "unsupported favicon format"),
+            Err(e) => debug!(error = %e, url = %candidate, "favicon download failed"),
+        }
+    }
+    None
+}
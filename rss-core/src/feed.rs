@@ -1,18 +1,474 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use atom_syndication as atom;
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use serde::{Deserialize, Deserializer, Serialize};
 use tokio::sync::RwLock;
+use unicode_segmentation::UnicodeSegmentation;
+use url::Url;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+use crate::content_extractor::ContentSelector;
+use crate::dedup::{feed_url_key, normalize_url};
+use crate::error::{AddFeedError, PollError};
+use crate::redact::redact_url_str;
+use crate::sanitize::{escape_html, sanitize_html, truncate_html};
+use crate::time::parse_feed_date;
+
+// Default: flux vide, sans option; à compléter par `..Default::default()` ou partir de `new`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct FeedDescriptor {
     pub id: String,
     pub title: String,
     pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<FeedAuth>,
+    // Échecs consécutifs du poller; au-delà du seuil configuré, le flux est désactivé.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub consecutive_failures: u32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    // En-têtes HTTP ajoutés à chaque requête du flux (ex. X-Api-Key), validés à l’ajout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<(String, String)>>,
+    // Remplace le User-Agent global pour ce flux seulement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    // Récupère la page de chaque nouvel article pour en extraire le contenu complet.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub full_content: bool,
+    // Sélecteur CSS du conteneur de l’article sur la page; remplace l’heuristique d’extraction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_selector: Option<String>,
+    // Dossier du flux dans la barre latérale (None = hors dossier).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    // Intervalle de relevé propre au flux (None = PollConfig::interval), en secondes.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "opt_duration_secs"
+    )]
+    pub poll_interval: Option<std::time::Duration>,
+    // Coupe les notifications du flux (toasts d’échec et de désactivation).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notifications_disabled: bool,
+    // Autorise le HTTP non chiffré pour ce flux quelle que soit NetworkConfig::allow_http.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_insecure: bool,
+    // Politique de conservation des articles propre au flux (None = limite globale).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention: Option<RetentionPolicy>,
+    // Position choisie par l’utilisateur dans la barre latérale (None = après les flux ordonnés).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<u32>,
+    // Métadonnées du canal relevées à la récupération (voir FeedMeta).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<DateTime<Utc>>,
+    // Flux mis en sourdine jusqu’à cette date: ignoré par les cycles du poller.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted_until: Option<DateTime<Utc>>,
+    // Dernière récupération ayant apporté un nouvel article (intervalle adaptatif du poller).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_new_article_at: Option<DateTime<Utc>>,
+}
+
+// ===
+//
+//
+// Métadonnées d’un canal: lien du site de l’éditeur, description et date de dernière mise à
+// jour (RSS `lastBuildDate` ou à défaut `pubDate`, Atom `updated`).
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedMeta {
+    pub site_url: Option<String>,
+    pub description: Option<String>,
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+fn url_host(url: &str) -> Option<String> {
+    let url = Url::parse(url.trim()).ok()?;
+    url.host_str()
+        .filter(|host| !host.is_empty())
+        .map(str::to_string)
+}
+
+// Coupe aux `max` premiers graphèmes (au moins un), le dernier remplacé par une ellipse.
+fn truncate_graphemes(text: &str, max: usize) -> String {
+    let max = max.max(1);
+    if text.graphemes(true).nth(max).is_none() {
+        return text.to_string();
+    }
+    let kept: String = text.graphemes(true).take(max - 1).collect();
+    format!("{}…", kept.trim_end())
+}
+
+// ===
+//
+//
+// Conservation des articles d’un flux dans le cache: les N plus récents, ceux publiés depuis
+// moins d’une durée donnée (les articles sans date sont gardés), ou tout. Dans feeds.json,
+// la durée est exprimée en secondes: `{ "max_age": 2592000 }`.
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionPolicy {
+    MaxCount(usize),
+    MaxAge(#[serde(with = "duration_secs")] std::time::Duration),
+    KeepAll,
+}
+
+impl RetentionPolicy {
+    // ===
+    //
+    //
+    // Indique si un article publié à `published_at` a dépassé l’âge maximal à la date `now`.
+    // Un article exactement à la limite est conservé.
+    //
+    //
+    // ===
+    pub fn is_expired(&self, published_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        let (Self::MaxAge(max_age), Some(published_at)) = (self, published_at) else {
+            return false;
+        };
+        match chrono::Duration::from_std(*max_age) {
+            Ok(max_age) => now.signed_duration_since(published_at) > max_age,
+            Err(_) => false,
+        }
+    }
+}
+
+mod duration_secs {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+mod opt_duration_secs {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&value.as_secs()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_secs))
+    }
+}
+
+// ===
+//
+//
+// Modification appliquée d’un coup à un ou plusieurs flux (gestion des flux et éditeurs de la
+// GUI, voir DataApi::update_feeds_bulk), sans toucher aux autres champs que le poller tient à
+// jour. Un dossier vide équivaut à None.
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedUpdate {
+    Category(Option<String>),
+    PollInterval(Option<std::time::Duration>),
+    Notifications(bool),
+    // Réglages de requête (éditeur d’en-têtes): User-Agent, en-têtes et identifiants.
+    Request {
+        user_agent: Option<String>,
+        headers: Option<Vec<(String, String)>>,
+        auth: Option<FeedAuth>,
+    },
+    // Sélecteur du contenu complet; un sélecteur active aussi la récupération du contenu complet.
+    ContentSelector(Option<String>),
+}
+
+impl FeedUpdate {
+    // Applique la modification à `feed`; renvoie false si rien ne change.
+    pub fn apply(&self, feed: &mut FeedDescriptor) -> bool {
+        match self {
+            Self::Category(category) => {
+                let category = category
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|c| !c.is_empty())
+                    .map(str::to_string);
+                replace_if_changed(&mut feed.category, category)
+            }
+            Self::PollInterval(interval) => replace_if_changed(&mut feed.poll_interval, *interval),
+            Self::Notifications(enabled) => {
+                replace_if_changed(&mut feed.notifications_disabled, !enabled)
+            }
+            Self::Request {
+                user_agent,
+                headers,
+                auth,
+            } => {
+                let changed = replace_if_changed(&mut feed.user_agent, user_agent.clone());
+                let changed = replace_if_changed(&mut feed.headers, headers.clone()) | changed;
+                replace_if_changed(&mut feed.auth, auth.clone()) | changed
+            }
+            Self::ContentSelector(selector) => {
+                let full_content = feed.full_content || selector.is_some();
+                let changed = replace_if_changed(&mut feed.full_content, full_content);
+                replace_if_changed(&mut feed.content_selector, selector.clone()) | changed
+            }
+        }
+    }
+}
+
+fn replace_if_changed<T: PartialEq>(slot: &mut T, value: T) -> bool {
+    if *slot == value {
+        return false;
+    }
+    *slot = value;
+    true
+}
+
+// ===
+//
+//
+// Regroupe des flux par dossier pour la barre latérale: les flux sans dossier d’abord, puis
+// un groupe par dossier (ordre alphabétique, casse ignorée). L’ordre des flux est conservé
+// dans chaque groupe.
+//
+//
+// ===
+pub fn feeds_by_category(feeds: Vec<FeedDescriptor>) -> Vec<(Option<String>, Vec<FeedDescriptor>)> {
+    let mut groups: Vec<(Option<String>, Vec<FeedDescriptor>)> = Vec::new();
+    for feed in feeds {
+        let key = feed.category.as_deref().map(str::to_lowercase);
+        match groups
+            .iter_mut()
+            .find(|(c, _)| c.as_deref().map(str::to_lowercase) == key)
+        {
+            Some((_, group)) => group.push(feed),
+            None => groups.push((feed.category.clone(), vec![feed])),
+        }
+    }
+    groups.sort_by_cached_key(|(c, _)| (c.is_some(), c.as_deref().map(str::to_lowercase)));
+    groups
+}
+
+// ===
+//
+//
+// Authentification HTTP d’un flux (en-tête Authorization).
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FeedAuth {
+    Basic { username: String, password: Secret },
+    Bearer { token: Secret },
+}
+
+// ===
+//
+//
+// Valeur secrète d’identification. Seul le stockage en clair existe pour l’instant; la forme
+// `{ "plaintext": "..." }` dans feeds.json le signale explicitement et laisse la place à
+// d’autres sources (trousseau du système) sous forme de nouvelles variantes.
+//
+//
+// ===
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Secret {
+    Plaintext(String),
+}
+
+impl Secret {
+    // ===
+    //
+    //
+    // Révèle la valeur du secret (None si la source n’est pas disponible).
+    //
+    //
+    // ===
+    pub fn reveal(&self) -> Option<String> {
+        match self {
+            Secret::Plaintext(value) => Some(value.clone()),
+        }
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret(***)")
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+impl FeedDescriptor {
+    // ===
+    //
+    //
+    // Flux sans authentification ni réglage particulier (tous les autres champs à leur valeur
+    // par défaut).
+    //
+    //
+    // ===
+    pub fn new(id: impl Into<String>, title: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    // Sourdine encore active à `now` (une échéance passée ne compte plus).
+    pub fn is_muted_at(&self, now: DateTime<Utc>) -> bool {
+        self.muted_until.is_some_and(|until| now < until)
+    }
+
+    // ===
+    //
+    //
+    // Vérifie que les en-têtes personnalisés et le User-Agent sont des en-têtes HTTP valides;
+    // à appeler avant l’ajout pour que l’erreur soit signalée à la saisie, pas au polling.
+    //
+    //
+    // ===
+    pub fn validate_headers(&self) -> Result<(), PollError> {
+        for (name, value) in self.headers.iter().flatten() {
+            HeaderName::from_bytes(name.trim().as_bytes())
+                .map_err(|_| PollError::InvalidHeader(name.clone()))?;
+            HeaderValue::from_str(value.trim())
+                .map_err(|_| PollError::InvalidHeader(name.clone()))?;
+        }
+        if let Some(ua) = &self.user_agent {
+            HeaderValue::from_str(ua.trim())
+                .map_err(|_| PollError::InvalidHeader("User-Agent".to_string()))?;
+        }
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Vérifie le sélecteur de contenu (voir ContentSelector) avant l’enregistrement.
+    //
+    //
+    // ===
+    pub fn validate_content_selector(&self) -> Result<(), PollError> {
+        match &self.content_selector {
+            Some(selector) => ContentSelector::parse(selector).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    // ===
+    //
+    //
+    // En-têtes à ajouter aux requêtes de ce flux (User-Agent compris); les entrées invalides
+    // sont ignorées, la validation ayant eu lieu à l’ajout.
+    //
+    //
+    // ===
+    pub fn request_headers(&self) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in self.headers.iter().flatten() {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.trim().as_bytes()),
+                HeaderValue::from_str(value.trim()),
+            ) {
+                map.append(name, value);
+            }
+        }
+        if let Some(Ok(ua)) = self
+            .user_agent
+            .as_deref()
+            .filter(|ua| !ua.trim().is_empty())
+            .map(|ua| HeaderValue::from_str(ua.trim()))
+        {
+            map.insert(USER_AGENT, ua);
+        }
+        map
+    }
+
+    // ===
+    //
+    //
+    // URL affichable dans les logs: identifiants et jetons retirés (voir redact_url).
+    //
+    //
+    // ===
+    pub fn redacted_url(&self) -> String {
+        redact_url_str(&self.url)
+    }
+
+    // Titre provisoire (vide, égal à l’URL ou à son hôte, ajout sans titre): remplacé par celui
+    // du canal.
+    pub fn has_placeholder_title(&self) -> bool {
+        let title = self.title.trim();
+        title.is_empty()
+            || title == self.url.trim()
+            || url_host(&self.url).is_some_and(|host| title == host)
+    }
+
+    // ===
+    //
+    //
+    // Titre prêt à être enregistré: espaces retirés, nom d’hôte de l’URL si le titre est vide,
+    // coupé à `max_title_chars` graphèmes (le dernier devient “…”) contre les titres démesurés
+    // des imports OPML mal formés.
+    //
+    //
+    // ===
+    pub fn normalized(mut self, max_title_chars: usize) -> Self {
+        let title = self.title.trim();
+        let title = if title.is_empty() {
+            url_host(&self.url).unwrap_or_else(|| self.redacted_url())
+        } else {
+            title.to_string()
+        };
+        self.title = truncate_graphemes(&title, max_title_chars);
+        self
+    }
+
+    // Métadonnées du canal actuellement enregistrées.
+    pub fn meta(&self) -> FeedMeta {
+        FeedMeta {
+            site_url: self.site_url.clone(),
+            description: self.description.clone(),
+            last_updated: self.last_updated,
+        }
+    }
+}
+
+// Default: article vide, sans date (first_seen_at à l’époque Unix), pour compléter un
+// littéral avec `..Default::default()`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct FeedEntry {
     pub feed_id: String,
     pub title: String,
@@ -20,48 +476,91 @@ pub struct FeedEntry {
     pub url: String,
     pub published_at: Option<DateTime<Utc>>,
     pub guid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guid_is_permalink: Option<bool>,
     pub author: Option<String>,
-    pub category: Option<String>,
+    // Catégories (RSS `<category>`, `dc:subject`, Atom `category`), sans doublons.
+    // Les articles enregistrés avant leur prise en charge portent un seul `category`.
+    #[serde(
+        default,
+        alias = "category",
+        deserialize_with = "de_categories",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub categories: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content_html: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
+    // Mis en évidence par une règle de filtrage (voir rules.rs).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub highlighted: bool,
+    // Date à laquelle une modification de l’article par l’éditeur a été détectée
+    // (Event::ArticlesUpdated); badge « mis à jour » dans la liste.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    // Première récupération de l’article: date de tri des articles sans date de publication.
+    // Conservée d’un relevé à l’autre (voir DataApi::upsert_articles); les articles mis en
+    // cache avant ce champ prennent la date du chargement, persistée au flush suivant.
+    #[serde(default = "Utc::now")]
+    pub first_seen_at: DateTime<Utc>,
+    // Contenu ou résumé tronqué à la limite par article (PollConfig::max_content_bytes): la vue
+    // détaillée propose l’article complet dans le navigateur.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub content_truncated: bool,
 }
 
 impl FeedEntry {
     // ===
     //
     //
-    // Convertit un rss::Item en FeedEntry interne.
+    // Convertit un rss::Item en FeedEntry interne, sans les espaces de noms du canal: seuls
+    // les préfixes usuels (`content:`, `media:`) sont reconnus. Le poller passe par
+    // `from_rss_item_in`.
     //
     //
     // ===
     pub fn from_rss_item(feed_id: &str, item: &rss::Item) -> Self {
-        let published_at = item
-            .pub_date()
-            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
-            .map(|dt| dt.with_timezone(&Utc));
+        Self::from_rss_item_in(feed_id, item, &BTreeMap::new())
+    }
+
+    // ===
+    //
+    //
+    // Convertit un rss::Item avec les espaces de noms déclarés par le canal
+    // (`channel.namespaces()`): les extensions sont reconnues par leur URI, quel que soit le
+    // préfixe choisi par l’éditeur (`xmlns:c="http://purl.org/rss/1.0/modules/content/"`).
+    //
+    //
+    // ===
+    pub fn from_rss_item_in(
+        feed_id: &str,
+        item: &rss::Item,
+        namespaces: &BTreeMap<String, String>,
+    ) -> Self {
+        let published_at = item.pub_date().and_then(parse_feed_date).or_else(|| {
+            // Flux RDF/RSS 1.0: pas de pubDate, la date est portée par dc:date.
+            item.dublin_core_ext()
+                .and_then(|dc| dc.dates().first().and_then(|d| parse_feed_date(d)))
+        });
 
         let author = item
             .dublin_core_ext()
             .and_then(|dc| dc.creators().first().map(|s| s.to_string()))
             .or_else(|| item.author().map(|s| s.to_string()));
 
-        let category = item
-            .categories()
-            .first()
-            .map(|cat| cat.name().to_string())
-            .or_else(|| {
-                item.dublin_core_ext()
-                    .and_then(|dc| dc.subjects().first().map(|s| s.to_string()))
-            });
-
-        let content_html = item
-            .extensions()
-            .get("content")
-            .and_then(|m| m.get("encoded"))
-            .and_then(|v| v.first())
-            .and_then(|ext| ext.value.clone());
+        let subjects = item
+            .dublin_core_ext()
+            .map(|dc| dc.subjects())
+            .unwrap_or_default();
+        let categories = collect_categories(
+            item.categories()
+                .iter()
+                .map(|cat| cat.name())
+                .chain(subjects.iter().map(String::as_str)),
+        );
+
+        let content_html = rss_content_html(item, namespaces);
 
         let image_url = item.enclosure().map(|e| e.url().to_string());
 
@@ -72,21 +571,97 @@ impl FeedEntry {
             url: item.link().unwrap_or_default().to_owned(),
             published_at,
             guid: item.guid().map(|guid| guid.value().to_owned()),
+            guid_is_permalink: item.guid().map(|guid| guid.is_permalink()),
             author,
-            category,
+            categories,
             content_html,
             image_url,
+            highlighted: false,
+            updated_at: None,
+            first_seen_at: Utc::now(),
+            content_truncated: false,
         }
     }
 
     // ===
     //
     //
-    // Identité stable pour déduplication (priorité: GUID > URL > titre+timestamp).
+    // Empreinte du contenu publié (titre, résumé, contenu), comparée d’un fetch à l’autre
+    // pour repérer un article corrigé par l’éditeur. Les dates n’y entrent pas: certains flux
+    // les réécrivent à chaque génération sans rien changer d’autre.
+    //
+    //
+    // ===
+    pub fn content_fingerprint(&self) -> u64 {
+        fnv1a(&[
+            self.title.as_bytes(),
+            self.summary.as_deref().unwrap_or_default().as_bytes(),
+            self.content_html.as_deref().unwrap_or_default().as_bytes(),
+        ])
+    }
+
+    // ===
+    //
+    //
+    // Identité stable pour déduplication. Un GUID opaque (isPermaLink="false", id Atom) est
+    // garanti stable par l’éditeur; un GUID permalien n’est qu’une URL, souvent régénérée,
+    // on lui préfère donc le lien normalisé. À défaut: empreinte du titre et du contenu.
     //
     //
     // ===
     pub fn identity(&self) -> String {
+        let guid = self.guid.as_deref().filter(|g| !g.trim().is_empty());
+        if let Some(g) = guid {
+            if self.guid_is_permalink != Some(true) {
+                return format!("guid:{}", g);
+            }
+        }
+        if let Some(key) = self.url_key() {
+            return key;
+        }
+        if let Some(g) = guid {
+            return format!("url:{}", normalize_url(g));
+        }
+        let body = self
+            .content_html
+            .as_deref()
+            .or(self.summary.as_deref())
+            .unwrap_or_default();
+        format!(
+            "hash:{:016x}",
+            fnv1a(&[self.title.as_bytes(), body.as_bytes()])
+        )
+    }
+
+    // ===
+    //
+    //
+    // Clé d’URL normalisée de l’article (None sans lien), pour les rapprochements approximatifs.
+    //
+    //
+    // ===
+    pub fn url_key(&self) -> Option<String> {
+        if self.url.trim().is_empty() {
+            None
+        } else {
+            Some(format!("url:{}", normalize_url(&self.url)))
+        }
+    }
+
+    // Date de tri: publication, à défaut première récupération (stable d’un relevé à l’autre).
+    pub fn sort_date(&self) -> DateTime<Utc> {
+        self.published_at.unwrap_or(self.first_seen_at)
+    }
+
+    // ===
+    //
+    //
+    // Identité calculée par les versions précédentes (GUID brut > URL brute > titre+timestamp),
+    // consultée pour retrouver l’état lu/vu déjà persisté.
+    //
+    //
+    // ===
+    pub(crate) fn legacy_identity(&self) -> String {
         if let Some(g) = &self.guid {
             return format!("guid:{}", g);
         }
@@ -97,6 +672,95 @@ impl FeedEntry {
         format!("title:{}@{}", self.title, ts)
     }
 
+    // ===
+    //
+    //
+    // Nettoie le HTML (scripts, styles, handlers on*, grosses URI data:) et borne sa taille;
+    // `content_truncated` signale une coupe.
+    //
+    //
+    // ===
+    pub fn sanitized(mut self, max_content_bytes: usize) -> Self {
+        let mut truncated = false;
+        let mut bound = |html: String| {
+            let clean = sanitize_html(&html);
+            truncated |= clean.len() > max_content_bytes;
+            truncate_html(clean, max_content_bytes)
+        };
+        self.content_html = self.content_html.map(&mut bound);
+        self.summary = self.summary.map(&mut bound);
+        self.content_truncated |= truncated;
+        self
+    }
+
+    // ===
+    //
+    //
+    // Rendu Markdown autonome (titre, flux source, auteur, date, lien puis contenu converti en
+    // texte par html2text) pour l’export d’articles.
+    //
+    //
+    // ===
+    pub fn to_markdown(&self, feed_title: &str) -> String {
+        let mut out = format!("# {}\n\n", self.title.trim());
+        out.push_str(&format!("- Source : {}\n", feed_title));
+        if let Some(author) = &self.author {
+            out.push_str(&format!("- Auteur : {}\n", author));
+        }
+        if let Some(date) = self.published_at {
+            out.push_str(&format!("- Date : {}\n", date.to_rfc3339()));
+        }
+        if !self.url.is_empty() {
+            out.push_str(&format!("- Lien : <{}>\n", self.url));
+        }
+        if let Some(html) = self.export_body() {
+            out.push_str("\n---\n\n");
+            out.push_str(html2text::from_read(html.as_bytes(), 100).trim_end());
+            out.push('\n');
+        }
+        out
+    }
+
+    // ===
+    //
+    //
+    // Rendu HTML autonome: mêmes métadonnées que le Markdown, contenu HTML nettoyé tel quel et
+    // scripts interdits par une politique CSP.
+    //
+    //
+    // ===
+    pub fn to_html(&self, feed_title: &str) -> String {
+        let title = escape_html(self.title.trim());
+        let mut meta = format!("<li>Source : {}</li>", escape_html(feed_title));
+        if let Some(author) = &self.author {
+            meta.push_str(&format!("<li>Auteur : {}</li>", escape_html(author)));
+        }
+        if let Some(date) = self.published_at {
+            meta.push_str(&format!(
+                "<li>Date : <time datetime=\"{0}\">{0}</time></li>",
+                date.to_rfc3339()
+            ));
+        }
+        if !self.url.is_empty() {
+            let url = escape_html(&self.url);
+            meta.push_str(&format!("<li>Lien : <a href=\"{0}\">{0}</a></li>", url));
+        }
+        let body = self.export_body().map(sanitize_html).unwrap_or_default();
+        format!(
+            "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">\
+             <meta http-equiv=\"Content-Security-Policy\" content=\"script-src 'none'; object-src 'none'\">\
+             <title>{title}</title></head><body>\n<h1>{title}</h1>\n<ul>{meta}</ul>\n<hr>\n{body}\n</body></html>\n"
+        )
+    }
+
+    // Contenu complet si présent, sinon le résumé.
+    fn export_body(&self) -> Option<&str> {
+        self.content_html
+            .as_deref()
+            .or(self.summary.as_deref())
+            .filter(|html| !html.trim().is_empty())
+    }
+
     // ===
     //
     //
@@ -113,16 +777,21 @@ impl FeedEntry {
 
         let author = entry.authors().first().map(|p| p.name.clone());
 
-        let category = entry.categories().first().map(|c| c.term.clone());
+        let categories = collect_categories(
+            entry
+                .categories()
+                .iter()
+                .map(|c| c.label.as_deref().unwrap_or(&c.term)),
+        );
 
-        let url = entry
-            .links()
-            .first()
+        // Le premier lien est souvent rel="self" ou rel="edit": on veut la page de l’article.
+        let url = atom_alternate_link(entry.links())
+            .or_else(|| entry.links().first())
             .map(|l| l.href.clone())
             .unwrap_or_default();
 
         let content_html = entry.content().and_then(|c| c.value.clone());
-        let image_url = None;
+        let image_url = atom_image_enclosure(entry.links()).map(|l| l.href.clone());
 
         Self {
             feed_id: feed_id.to_owned(),
@@ -131,14 +800,170 @@ impl FeedEntry {
             url,
             published_at,
             guid: Some(entry.id().to_owned()),
+            guid_is_permalink: None,
             author,
-            category,
+            categories,
             content_html,
             image_url,
+            highlighted: false,
+            updated_at: None,
+            first_seen_at: Utc::now(),
+            content_truncated: false,
         }
     }
 }
 
+// ===
+//
+//
+// Normalise une liste de catégories: espaces retirés, vides ignorées, doublons (sans tenir
+// compte de la casse) fusionnés en gardant la première occurrence.
+//
+//
+// ===
+pub(crate) fn collect_categories<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut categories: Vec<String> = Vec::new();
+    for name in names.into_iter().map(str::trim) {
+        if !name.is_empty() && !categories.iter().any(|c| c.eq_ignore_ascii_case(name)) {
+            categories.push(name.to_string());
+        }
+    }
+    categories
+}
+
+// Espaces de noms reconnus par leur URI.
+const CONTENT_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/content/";
+const MEDIA_NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+// Le préfixe désigne-t-il `uri` ? Déclaration du canal d’abord, sinon le préfixe usuel.
+fn prefix_is(namespaces: &BTreeMap<String, String>, prefix: &str, uri: &str, usual: &str) -> bool {
+    match namespaces.get(prefix) {
+        Some(declared) => declared
+            .trim()
+            .trim_end_matches('/')
+            .eq_ignore_ascii_case(uri.trim_end_matches('/')),
+        None => prefix == usual,
+    }
+}
+
+// ===
+//
+//
+// Contenu HTML d’un article RSS, par priorité: `content:encoded` (sous n’importe quel
+// préfixe lié au module content), puis `<media:description type="html">` (directement dans
+// l’item, dans `media:group` ou `media:content`), puis `itunes:summary`.
+//
+//
+// ===
+fn rss_content_html(item: &rss::Item, namespaces: &BTreeMap<String, String>) -> Option<String> {
+    let non_empty = |value: &str| Some(value.to_string()).filter(|v| !v.trim().is_empty());
+
+    // Le crate rss range déjà le préfixe littéral `content:encoded` dans `content()`.
+    let literal = item
+        .content()
+        .filter(|_| prefix_is(namespaces, "content", CONTENT_NAMESPACE, "content"))
+        .and_then(non_empty);
+    let encoded = || {
+        item.extensions()
+            .iter()
+            .filter(|(prefix, _)| prefix_is(namespaces, prefix, CONTENT_NAMESPACE, "content"))
+            .filter_map(|(_, elements)| elements.get("encoded"))
+            .flatten()
+            .find_map(|ext| ext.value().and_then(non_empty))
+    };
+    let media = || {
+        item.extensions()
+            .iter()
+            .filter(|(prefix, _)| prefix_is(namespaces, prefix, MEDIA_NAMESPACE, "media"))
+            .flat_map(|(_, elements)| {
+                let nested = ["group", "content"]
+                    .into_iter()
+                    .filter_map(|name| elements.get(name))
+                    .flatten()
+                    .filter_map(|parent| parent.children().get("description"));
+                elements
+                    .get("description")
+                    .into_iter()
+                    .chain(nested)
+                    .flatten()
+            })
+            .filter(|d| {
+                d.attrs()
+                    .get("type")
+                    .is_some_and(|t| t.trim().eq_ignore_ascii_case("html"))
+            })
+            .find_map(|d| d.value().and_then(non_empty))
+    };
+    let itunes = || {
+        item.itunes_ext()
+            .and_then(|itunes| itunes.summary())
+            .and_then(non_empty)
+    };
+    literal.or_else(encoded).or_else(media).or_else(itunes)
+}
+
+// ===
+//
+//
+// Lien Atom vers la page web: d’abord rel="alternate" de type text/html, puis n’importe quel
+// rel="alternate" (`rel` absent vaut "alternate"). Sans lien alternate, rien: l’appelant
+// décide s’il se rabat sur le premier lien (entrée) ou non (site du flux).
+//
+//
+// ===
+pub(crate) fn atom_alternate_link(links: &[atom::Link]) -> Option<&atom::Link> {
+    let mut alternates = links
+        .iter()
+        .filter(|l| l.rel() == "alternate" && !l.href().trim().is_empty());
+    let first = alternates.clone().next();
+    alternates
+        .find(|l| {
+            l.mime_type()
+                .is_some_and(|t| t.trim().eq_ignore_ascii_case("text/html"))
+        })
+        .or(first)
+}
+
+// Image jointe: premier lien rel="enclosure" dont le type est image/*.
+pub(crate) fn atom_image_enclosure(links: &[atom::Link]) -> Option<&atom::Link> {
+    links.iter().find(|l| {
+        l.rel() == "enclosure"
+            && !l.href().trim().is_empty()
+            && l.mime_type().is_some_and(|t| {
+                t.trim()
+                    .get(..6)
+                    .is_some_and(|p| p.eq_ignore_ascii_case("image/"))
+            })
+    })
+}
+
+// Ancien format (`"category": "Tech"` ou `null`) comme nouveau (`"categories": [...]`).
+fn de_categories<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawCategories {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match Option::<RawCategories>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(RawCategories::One(name)) => collect_categories([name.as_str()]),
+        Some(RawCategories::Many(names)) => collect_categories(names.iter().map(String::as_str)),
+    })
+}
+
+// Empreinte FNV-1a 64 bits: stable d’une version de Rust à l’autre (identités persistées).
+pub(crate) fn fnv1a(parts: &[&[u8]]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.iter().chain(std::iter::once(&0u8)) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
 pub type SharedFeedList = Arc<RwLock<Vec<FeedDescriptor>>>;
 
 // ===
@@ -155,14 +980,48 @@ pub fn shared_feed_list(initial: Vec<FeedDescriptor>) -> SharedFeedList {
 // ===
 //
 //
-// Ajoute (ou remplace par id) un flux dans le store partagé et persiste côté DataApi.
+// Ajoute (ou remplace sur place, par id) un flux dans le store partagé et persiste côté DataApi.
 //
 //
 // ===
 pub async fn add_feed(store: &SharedFeedList, feed: FeedDescriptor) {
     let mut feeds = store.write().await;
-    feeds.retain(|existing| existing.id != feed.id);
-    feeds.push(feed);
+    match feeds.iter_mut().find(|existing| existing.id == feed.id) {
+        Some(existing) => *existing = feed,
+        None => feeds.push(feed),
+    }
+}
+
+// ===
+//
+//
+// Ajoute un nouveau flux, sauf si son URL est invalide ou déjà suivie par un autre flux
+// (comparaison par `feed_url_key`: http/https et barre oblique finale indifférents). Le
+// contrôle et l’ajout se font sous le même verrou.
+//
+//
+// ===
+pub async fn try_add_feed(
+    store: &SharedFeedList,
+    feed: FeedDescriptor,
+) -> Result<(), AddFeedError> {
+    let key =
+        feed_url_key(&feed.url).ok_or_else(|| AddFeedError::InvalidUrl(feed.redacted_url()))?;
+    let mut feeds = store.write().await;
+    let duplicate = feeds.iter().find(|existing| {
+        existing.id != feed.id && feed_url_key(&existing.url).as_ref() == Some(&key)
+    });
+    if let Some(existing) = duplicate {
+        return Err(AddFeedError::Duplicate {
+            id: existing.id.clone(),
+            title: existing.title.clone(),
+        });
+    }
+    match feeds.iter_mut().find(|existing| existing.id == feed.id) {
+        Some(existing) => *existing = feed,
+        None => feeds.push(feed),
+    }
+    Ok(())
 }
 
 // ===
@@ -180,10 +1039,224 @@ pub async fn remove_feed(store: &SharedFeedList, feed_id: &str) {
 // ===
 //
 //
-// Liste les flux présents dans le store partagé.
+// Supprime plusieurs flux sous un seul verrou; renvoie les ids effectivement retirés.
+//
+//
+// ===
+pub async fn remove_feeds(store: &SharedFeedList, feed_ids: &[String]) -> Vec<String> {
+    let mut feeds = store.write().await;
+    let mut removed = Vec::new();
+    feeds.retain(|existing| {
+        let keep = !feed_ids.contains(&existing.id);
+        if !keep {
+            removed.push(existing.id.clone());
+        }
+        keep
+    });
+    removed
+}
+
+// ===
+//
+//
+// Applique `update` aux flux de `feed_ids` sous un seul verrou; renvoie le nombre de flux
+// modifiés (ids inconnus et flux déjà dans l’état voulu exclus).
+//
+//
+// ===
+pub async fn update_feeds(
+    store: &SharedFeedList,
+    feed_ids: &[String],
+    update: &FeedUpdate,
+) -> usize {
+    let mut feeds = store.write().await;
+    feeds
+        .iter_mut()
+        .filter(|feed| feed_ids.contains(&feed.id))
+        .map(|feed| update.apply(feed))
+        .filter(|changed| *changed)
+        .count()
+}
+
+// ===
+//
+//
+// Liste les flux présents dans le store partagé, dans l’ordre choisi (`sort_order`); les flux
+// sans position suivent, dans leur ordre d’ajout.
 //
 //
 // ===
 pub async fn list_feeds(store: &SharedFeedList) -> Vec<FeedDescriptor> {
-    store.read().await.clone()
+    let mut feeds = store.read().await.clone();
+    feeds.sort_by_key(|f| (f.sort_order.is_none(), f.sort_order));
+    feeds
+}
+
+// ===
+//
+//
+// Réordonne les flux: ceux de `ids_in_order` prennent leur rang comme position, les autres
+// perdent la leur et passent à la suite. Renvoie false si rien n’a changé.
+//
+//
+// ===
+pub async fn reorder_feeds(store: &SharedFeedList, ids_in_order: &[String]) -> bool {
+    let mut feeds = store.write().await;
+    let mut changed = false;
+    for feed in feeds.iter_mut() {
+        let order = ids_in_order
+            .iter()
+            .position(|id| *id == feed.id)
+            .map(|rank| rank as u32);
+        changed |= feed.sort_order != order;
+        feed.sort_order = order;
+    }
+    changed
+}
+
+// ===
+//
+//
+// Enregistre le résultat d’une récupération: un succès remet le compteur d’échecs à zéro,
+// un échec l’incrémente et désactive le flux au seuil `disable_after` (0 = jamais).
+// Retourne true si le flux vient d’être désactivé.
+//
+//
+// ===
+pub async fn record_feed_result(
+    store: &SharedFeedList,
+    feed_id: &str,
+    success: bool,
+    disable_after: u32,
+) -> bool {
+    let mut feeds = store.write().await;
+    let Some(feed) = feeds.iter_mut().find(|f| f.id == feed_id) else {
+        return false;
+    };
+    if success {
+        feed.consecutive_failures = 0;
+        return false;
+    }
+    feed.consecutive_failures = feed.consecutive_failures.saturating_add(1);
+    if !feed.disabled && disable_after > 0 && feed.consecutive_failures >= disable_after {
+        feed.disabled = true;
+        return true;
+    }
+    false
+}
+
+// ===
+//
+//
+// Note la date d’une récupération qui a apporté de nouveaux articles (remet l’intervalle
+// adaptatif au niveau de base). Retourne true si le flux existe.
+//
+//
+// ===
+pub async fn record_new_articles(store: &SharedFeedList, feed_id: &str, at: DateTime<Utc>) -> bool {
+    let mut feeds = store.write().await;
+    match feeds.iter_mut().find(|f| f.id == feed_id) {
+        Some(feed) => {
+            feed.last_new_article_at = Some(at);
+            true
+        }
+        None => false,
+    }
+}
+
+// ===
+//
+//
+// Réactive un flux désactivé et remet son compteur d’échecs à zéro.
+//
+//
+// ===
+pub async fn reenable_feed(store: &SharedFeedList, feed_id: &str) {
+    let mut feeds = store.write().await;
+    if let Some(feed) = feeds.iter_mut().find(|f| f.id == feed_id) {
+        feed.disabled = false;
+        feed.consecutive_failures = 0;
+    }
+}
+
+// ===
+//
+//
+// Met un flux en sourdine jusqu’à `until` (None la lève). Renvoie false si le flux est inconnu
+// ou si rien ne change.
+//
+//
+// ===
+pub async fn mute_feed(
+    store: &SharedFeedList,
+    feed_id: &str,
+    until: Option<DateTime<Utc>>,
+) -> bool {
+    let mut feeds = store.write().await;
+    match feeds.iter_mut().find(|f| f.id == feed_id) {
+        Some(feed) if feed.muted_until != until => {
+            feed.muted_until = until;
+            true
+        }
+        _ => false,
+    }
+}
+
+// ===
+//
+//
+// Enregistre les métadonnées du canal d’un flux. Renvoie false si le flux est inconnu ou si
+// rien ne change.
+//
+//
+// ===
+pub async fn update_feed_metadata(store: &SharedFeedList, feed_id: &str, meta: FeedMeta) -> bool {
+    let mut feeds = store.write().await;
+    match feeds.iter_mut().find(|f| f.id == feed_id) {
+        Some(feed) if feed.meta() != meta => {
+            feed.site_url = meta.site_url;
+            feed.description = meta.description;
+            feed.last_updated = meta.last_updated;
+            true
+        }
+        _ => false,
+    }
+}
+
+// ===
+//
+//
+// Renomme un flux. Renvoie false si le flux est inconnu, le titre vide ou inchangé.
+//
+//
+// ===
+pub async fn update_feed_title(store: &SharedFeedList, feed_id: &str, title: &str) -> bool {
+    let title = title.trim();
+    let mut feeds = store.write().await;
+    match feeds.iter_mut().find(|f| f.id == feed_id) {
+        Some(feed) if !title.is_empty() && feed.title != title => {
+            feed.title = title.to_string();
+            true
+        }
+        _ => false,
+    }
+}
+
+// ===
+//
+//
+// Remplace l’URL d’un flux (déplacé par une redirection permanente). Renvoie false si le flux
+// est inconnu ou a déjà cette URL.
+//
+//
+// ===
+pub async fn update_feed_url(store: &SharedFeedList, feed_id: &str, url: &str) -> bool {
+    let mut feeds = store.write().await;
+    match feeds.iter_mut().find(|f| f.id == feed_id) {
+        Some(feed) if feed.url != url => {
+            feed.url = url.to_string();
+            true
+        }
+        _ => false,
+    }
 }
@@ -0,0 +1,204 @@
+// ===
+//
+//
+// Écriture de flux RSS 2.0 et Atom à partir d’articles (republication des favoris): titre,
+// lien, GUID, dates, auteur, catégories, résumé et contenu HTML. Le document produit est relu
+// par les mêmes analyseurs que les flux suivis.
+//
+//
+// ===
+
+use chrono::{DateTime, Utc};
+
+use crate::feed::{fnv1a, FeedEntry};
+use crate::sanitize::escape_html;
+
+// Description du canal publié.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChannelMeta {
+    pub title: String,
+    // Page présentée par le canal (obligatoire en RSS 2.0).
+    pub link: String,
+    pub description: Option<String>,
+    // Adresse du flux lui-même (`atom:link rel="self"`, identifiant Atom).
+    pub self_url: Option<String>,
+}
+
+// ===
+//
+//
+// Texte ou attribut XML: caractères spéciaux échappés, caractères de contrôle interdits par
+// XML 1.0 retirés.
+//
+//
+// ===
+fn xml(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    escape_html(&cleaned)
+}
+
+fn element(out: &mut String, indent: &str, name: &str, value: &str) {
+    out.push_str(&format!("{}<{}>{}</{}>\n", indent, name, xml(value), name));
+}
+
+// Date de mise à jour du canal: article le plus récent, à défaut maintenant.
+fn newest(entries: &[FeedEntry]) -> DateTime<Utc> {
+    entries
+        .iter()
+        .map(|e| e.updated_at.unwrap_or(e.sort_date()))
+        .max()
+        .unwrap_or_else(Utc::now)
+}
+
+pub(crate) fn render_rss(entries: &[FeedEntry], meta: &ChannelMeta) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(concat!(
+        "<rss version=\"2.0\"",
+        " xmlns:content=\"http://purl.org/rss/1.0/modules/content/\"",
+        " xmlns:dc=\"http://purl.org/dc/elements/1.1/\"",
+        " xmlns:atom=\"http://www.w3.org/2005/Atom\">\n",
+        "  <channel>\n"
+    ));
+    element(&mut out, "    ", "title", &meta.title);
+    element(&mut out, "    ", "link", &meta.link);
+    element(
+        &mut out,
+        "    ",
+        "description",
+        meta.description.as_deref().unwrap_or(&meta.title),
+    );
+    if let Some(url) = &meta.self_url {
+        out.push_str(&format!(
+            "    <atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+            xml(url)
+        ));
+    }
+    element(
+        &mut out,
+        "    ",
+        "lastBuildDate",
+        &newest(entries).to_rfc2822(),
+    );
+    for entry in entries {
+        out.push_str("    <item>\n");
+        element(&mut out, "      ", "title", &entry.title);
+        if !entry.url.is_empty() {
+            element(&mut out, "      ", "link", &entry.url);
+        }
+        // Sans GUID d’origine, le lien en tient lieu (permalien).
+        let guid = match entry.guid.as_deref().filter(|g| !g.trim().is_empty()) {
+            Some(guid) => Some((guid, entry.guid_is_permalink.unwrap_or(false))),
+            None => Some((entry.url.as_str(), true)).filter(|(url, _)| !url.is_empty()),
+        };
+        if let Some((guid, permalink)) = guid {
+            out.push_str(&format!(
+                "      <guid isPermaLink=\"{}\">{}</guid>\n",
+                permalink,
+                xml(guid)
+            ));
+        }
+        element(
+            &mut out,
+            "      ",
+            "pubDate",
+            &entry.sort_date().to_rfc2822(),
+        );
+        if let Some(author) = &entry.author {
+            element(&mut out, "      ", "dc:creator", author);
+        }
+        for category in &entry.categories {
+            element(&mut out, "      ", "category", category);
+        }
+        if let Some(summary) = &entry.summary {
+            element(&mut out, "      ", "description", summary);
+        }
+        if let Some(content) = &entry.content_html {
+            element(&mut out, "      ", "content:encoded", content);
+        }
+        out.push_str("    </item>\n");
+    }
+    out.push_str("  </channel>\n</rss>\n");
+    out
+}
+
+pub(crate) fn render_atom(entries: &[FeedEntry], meta: &ChannelMeta) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    element(&mut out, "  ", "title", &meta.title);
+    if let Some(description) = &meta.description {
+        element(&mut out, "  ", "subtitle", description);
+    }
+    element(
+        &mut out,
+        "  ",
+        "id",
+        meta.self_url.as_deref().unwrap_or(&meta.link),
+    );
+    element(&mut out, "  ", "updated", &newest(entries).to_rfc3339());
+    out.push_str(&format!(
+        "  <link rel=\"alternate\" href=\"{}\"/>\n",
+        xml(&meta.link)
+    ));
+    if let Some(url) = &meta.self_url {
+        out.push_str(&format!("  <link rel=\"self\" href=\"{}\"/>\n", xml(url)));
+    }
+    for entry in entries {
+        out.push_str("  <entry>\n");
+        element(&mut out, "    ", "id", &atom_id(entry));
+        out.push_str(&format!(
+            "    <title type=\"text\">{}</title>\n",
+            xml(&entry.title)
+        ));
+        if !entry.url.is_empty() {
+            out.push_str(&format!(
+                "    <link rel=\"alternate\" href=\"{}\"/>\n",
+                xml(&entry.url)
+            ));
+        }
+        let published = entry.sort_date();
+        element(&mut out, "    ", "published", &published.to_rfc3339());
+        let updated = entry.updated_at.unwrap_or(published);
+        element(&mut out, "    ", "updated", &updated.to_rfc3339());
+        if let Some(author) = &entry.author {
+            out.push_str(&format!(
+                "    <author><name>{}</name></author>\n",
+                xml(author)
+            ));
+        }
+        for category in &entry.categories {
+            out.push_str(&format!("    <category term=\"{}\"/>\n", xml(category)));
+        }
+        if let Some(summary) = &entry.summary {
+            out.push_str(&format!(
+                "    <summary type=\"html\">{}</summary>\n",
+                xml(summary)
+            ));
+        }
+        if let Some(content) = &entry.content_html {
+            out.push_str(&format!(
+                "    <content type=\"html\">{}</content>\n",
+                xml(content)
+            ));
+        }
+        out.push_str("  </entry>\n");
+    }
+    out.push_str("</feed>\n");
+    out
+}
+
+// Identifiant Atom: GUID d’origine, lien, sinon URN dérivée de l’identité de l’article.
+fn atom_id(entry: &FeedEntry) -> String {
+    entry
+        .guid
+        .as_deref()
+        .filter(|g| !g.trim().is_empty())
+        .or(Some(entry.url.as_str()).filter(|url| !url.is_empty()))
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            let hash = fnv1a(&[entry.feed_id.as_bytes(), entry.identity().as_bytes()]);
+            format!("urn:readrss:{:016x}", hash)
+        })
+}
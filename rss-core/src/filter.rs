@@ -0,0 +1,91 @@
+use chrono::{DateTime, Datelike, Duration, TimeZone};
+
+use crate::feed::FeedEntry;
+
+// ===
+//
+//
+// Filtres rapides de la liste d’articles (barre au-dessus de la liste). Ils se combinent
+// entre eux (ET logique) et avec la sélection de flux, « Non lus » et le tri.
+// - `today`: publié depuis minuit (jour civil de `now`).
+// - `this_week`: publié depuis lundi minuit (semaine civile de `now`).
+// - `with_image`: `image_url` renseignée.
+// - `text`: sous-chaîne de l’auteur ou d’une catégorie, sans tenir compte de la casse.
+// - `tag`: une des catégories, à la casse près (choisie dans la vue « Tags »).
+// Un article sans date échoue aux filtres de date; sans auteur ni catégorie, au filtre texte.
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArticleFilter {
+    pub today: bool,
+    pub this_week: bool,
+    pub with_image: bool,
+    pub text: String,
+    pub tag: Option<String>,
+}
+
+impl ArticleFilter {
+    // Vrai si au moins un filtre restreint la liste.
+    pub fn is_active(&self) -> bool {
+        self.today
+            || self.this_week
+            || self.with_image
+            || !self.text.trim().is_empty()
+            || self.tag.is_some()
+    }
+
+    // Désactive tous les filtres.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    // ===
+    //
+    //
+    // Teste un article. `now` fixe le fuseau des jours et semaines (heure locale dans l’UI).
+    //
+    //
+    // ===
+    pub fn matches<Tz: TimeZone>(&self, entry: &FeedEntry, now: &DateTime<Tz>) -> bool {
+        if self.today || self.this_week {
+            let Some(published) = entry.published_at else {
+                return false;
+            };
+            let published = published.with_timezone(&now.timezone());
+            let today = now.date_naive();
+            let day = published.date_naive();
+            if self.today && day != today {
+                return false;
+            }
+            let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            if self.this_week && !(monday..=today).contains(&day) {
+                return false;
+            }
+        }
+        let has_image = entry
+            .image_url
+            .as_deref()
+            .is_some_and(|u| !u.trim().is_empty());
+        if self.with_image && !has_image {
+            return false;
+        }
+        let needle = self.text.trim().to_lowercase();
+        if !needle.is_empty() {
+            let found = entry
+                .author
+                .iter()
+                .chain(&entry.categories)
+                .any(|value| value.to_lowercase().contains(&needle));
+            if !found {
+                return false;
+            }
+        }
+        if let Some(tag) = &self.tag {
+            if !entry.categories.iter().any(|c| c.eq_ignore_ascii_case(tag)) {
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+
+use reqwest::header::{HeaderValue, LOCATION, USER_AGENT};
+use reqwest::{redirect, Client, ClientBuilder, Proxy, RequestBuilder, Response, StatusCode};
+use url::Url;
+
+use crate::config::NetworkConfig;
+use crate::error::PollError;
+use crate::redact::{redact_url, redact_url_str};
+
+// User-Agent utilisé quand NetworkConfig n’en précise pas.
+pub const DEFAULT_USER_AGENT: &str = "ReadRSS/0.1 (+https://github.com/xAMA0x/ReadRSS)";
+
+// Nombre maximal de redirections suivies par requête (par send_following_redirects).
+pub const MAX_REDIRECTS: usize = 5;
+
+// ===
+//
+//
+// Construit le client HTTP partagé (proxy, certificats, User-Agent) depuis NetworkConfig.
+// La décompression gzip/brotli/deflate est explicite: Accept-Encoding est toujours envoyé
+// et les limites de taille du poller portent sur les octets décompressés.
+// Le client ne suit aucune redirection: send_following_redirects les suit toutes, saut par saut,
+// pour décider à chacun des en-têtes transmis et détecter un flux déplacé (301/308).
+//
+//
+// ===
+pub fn build_http_client(network: &NetworkConfig) -> Result<Client, PollError> {
+    let user_agent = network
+        .user_agent
+        .as_deref()
+        .map(str::trim)
+        .filter(|ua| !ua.is_empty())
+        .unwrap_or(DEFAULT_USER_AGENT);
+
+    let mut builder = ClientBuilder::new()
+        .redirect(redirect::Policy::none())
+        .connect_timeout(Duration::from_secs(10))
+        .user_agent(user_agent)
+        .gzip(true)
+        .brotli(true)
+        .deflate(true);
+
+    if let Some(proxy_url) = network
+        .proxy_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        let parsed = Url::parse(proxy_url)
+            .map_err(|e| PollError::InvalidProxy(format!("{}: {}", redact_url_str(proxy_url), e)))?;
+        if !matches!(parsed.scheme(), "http" | "https" | "socks5" | "socks5h") {
+            return Err(PollError::InvalidProxy(format!(
+                "{}: unsupported proxy scheme {}",
+                redact_url(&parsed),
+                parsed.scheme()
+            )));
+        }
+        let proxy =
+            Proxy::all(parsed.as_str()).map_err(|e| PollError::InvalidProxy(e.to_string()))?;
+        builder = builder.proxy(proxy);
+    }
+
+    if network.accept_invalid_certs {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    builder.build().map_err(PollError::from)
+}
+
+fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+fn is_permanent_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+// Réponse finale d’une requête et nouvelle adresse si la ressource a été déplacée.
+pub(crate) struct Followed {
+    pub response: Response,
+    pub moved_to: Option<Url>,
+}
+
+// ===
+//
+//
+// Envoie une requête en suivant à la main toutes les redirections (au plus MAX_REDIRECTS); le
+// client doit venir de build_http_client, qui n’en suit aucune lui-même. `moved_to` est la
+// dernière URL atteinte par une chaîne ininterrompue de 301/308 depuis l’URL demandée; une
+// redirection temporaire en cours de route ou un passage de HTTPS à HTTP l’arrête. `build` reçoit
+// l’URL de chaque saut et indique si elle reste sur l’origine initiale, pour ne pas transmettre
+// identifiants et en-têtes personnalisés à un autre hôte.
+//
+//
+// ===
+pub(crate) async fn send_following_redirects(
+    url: Url,
+    build: impl Fn(&Url, bool) -> RequestBuilder,
+) -> Result<Followed, PollError> {
+    let origin = url.origin();
+    let downgrade_ok = url.scheme() == "http";
+    let mut current = url;
+    let mut moved_to = None;
+    let mut permanent = true;
+    for _ in 0..=MAX_REDIRECTS {
+        let response = build(&current, current.origin() == origin).send().await?;
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok());
+        let next = match location {
+            Some(location) if is_redirect(response.status()) => response.url().join(location)?,
+            _ => return Ok(Followed { response, moved_to }),
+        };
+        permanent &= is_permanent_redirect(response.status());
+        permanent &= next.scheme() == "https" || downgrade_ok;
+        if permanent {
+            moved_to = Some(next.clone());
+        }
+        current = next;
+    }
+    Err(PollError::TooManyRedirects)
+}
+
+// ===
+//
+//
+// Télécharge une ressource HTTP(S) annexe (icône, image d’article) en une seule tentative,
+// bornée par `timeout` et par `max_bytes` (octets décompressés).
+//
+//
+// ===
+pub(crate) async fn download_capped(
+    client: &Client,
+    url: &Url,
+    max_bytes: usize,
+    timeout: Duration,
+) -> Result<Vec<u8>, PollError> {
+    download_capped_as(client, url, max_bytes, timeout, None).await
+}
+
+// Comme download_capped, avec le User-Agent du flux s’il en a un (page complète d’un article).
+pub(crate) async fn download_capped_as(
+    client: &Client,
+    url: &Url,
+    max_bytes: usize,
+    timeout: Duration,
+    user_agent: Option<&HeaderValue>,
+) -> Result<Vec<u8>, PollError> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(PollError::UnsupportedScheme);
+    }
+    let response = send_following_redirects(url.clone(), |target, _| {
+        let request = client.get(target.clone()).timeout(timeout);
+        match user_agent {
+            Some(ua) => request.header(USER_AGENT, ua),
+            None => request,
+        }
+    })
+    .await?
+    .response;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(PollError::HttpStatus(status.as_u16()));
+    }
+    if let Some(len) = response.content_length() {
+        if len > max_bytes as u64 {
+            return Err(PollError::TooLarge(len));
+        }
+    }
+    let mut buf = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        if buf.len() + chunk.len() > max_bytes {
+            return Err(PollError::TooLarge((buf.len() + chunk.len()) as u64));
+        }
+        buf.extend_from_slice(&chunk);
+    }
+    Ok(buf)
+}
@@ -0,0 +1,96 @@
+// ===
+//
+//
+// Images d’un article dans la vue détaillée: le HTML (déjà nettoyé) est découpé en blocs de
+// texte et d’images dans l’ordre du document, puis chaque image est téléchargée à la demande
+// (une tentative, au plus MAX_INLINE_IMAGE_BYTES). Le décodage reste côté interface.
+//
+//
+// ===
+
+use std::time::Duration;
+
+use reqwest::Client;
+use url::Url;
+
+use crate::content_extractor::attr_value;
+use crate::error::PollError;
+use crate::http::download_capped;
+use crate::sanitize::{find_tag_end, tag_name};
+
+// Nombre maximal d’images affichées par article (les suivantes sont ignorées).
+pub const MAX_INLINE_IMAGES: usize = 20;
+
+// Taille maximale d’une image téléchargée.
+pub const MAX_INLINE_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+const IMAGE_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentBlock {
+    // Fragment HTML sans image, rendu en texte.
+    Html(String),
+    Image { url: Url, alt: Option<String> },
+}
+
+// ===
+//
+//
+// Découpe `html` autour de ses balises <img>, dans l’ordre du document. Les adresses relatives
+// sont résolues sur `base` (lien de l’article); une image sans adresse HTTP(S) est retirée,
+// comme celles au-delà de `max_images`.
+//
+//
+// ===
+pub fn split_inline_images(html: &str, base: Option<&Url>, max_images: usize) -> Vec<ContentBlock> {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    let mut images = 0;
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        text.push_str(&rest[..lt]);
+        rest = &rest[lt..];
+        let Some(end) = find_tag_end(rest) else {
+            break;
+        };
+        let tag = &rest[1..end];
+        let raw = &rest[..=end];
+        rest = &rest[end + 1..];
+        if tag.starts_with('/') || tag_name(tag) != "img" {
+            text.push_str(raw);
+            continue;
+        }
+        let url = attr_value(tag, "src").and_then(|src| resolve_image_url(&src, base));
+        let Some(url) = url.filter(|_| images < max_images) else {
+            continue;
+        };
+        images += 1;
+        let before = std::mem::take(&mut text);
+        if !before.trim().is_empty() {
+            blocks.push(ContentBlock::Html(before));
+        }
+        let alt = attr_value(tag, "alt")
+            .map(|alt| alt.trim().to_string())
+            .filter(|alt| !alt.is_empty());
+        blocks.push(ContentBlock::Image { url, alt });
+    }
+    text.push_str(rest);
+    if !text.trim().is_empty() {
+        blocks.push(ContentBlock::Html(text));
+    }
+    blocks
+}
+
+fn resolve_image_url(src: &str, base: Option<&Url>) -> Option<Url> {
+    let src = src.trim().replace("&amp;", "&");
+    let url = match base {
+        Some(base) => base.join(&src).ok()?,
+        None => Url::parse(&src).ok()?,
+    };
+    matches!(url.scheme(), "http" | "https").then_some(url)
+}
+
+// Télécharge une image d’article (octets bruts, décodés par l’interface).
+pub async fn fetch_inline_image(client: &Client, url: &Url) -> Result<Vec<u8>, PollError> {
+    download_capped(client, url, MAX_INLINE_IMAGE_BYTES, IMAGE_TIMEOUT).await
+}
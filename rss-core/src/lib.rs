@@ -5,18 +5,118 @@
 //
 //
 // ===
+pub mod autodiscovery;
 pub mod config;
+pub mod connectivity;
+pub mod content_extractor;
 pub mod data;
+pub mod dedup;
 pub mod error;
+pub mod export;
+pub mod favicon;
 pub mod feed;
+pub mod feed_writer;
+pub mod filter;
+pub mod http;
+pub mod inline_images;
+#[cfg(feature = "imap-feeds")]
+pub mod mailbox;
+pub mod metrics;
+pub mod opml;
 pub mod poller;
+pub mod preview;
+pub mod rate_limit;
+pub mod read_later;
+pub mod redact;
+pub mod rules;
+pub mod sanitize;
+pub mod secrets;
+pub mod service;
+pub mod session;
+pub mod share;
+pub mod stats;
 pub mod storage;
+pub mod sync;
+pub mod time;
+pub mod url_rewrite;
+pub mod websub;
 
-pub use config::{AppConfig, FeedConfig, ThemeConfig, UiConfig};
-pub use data::DataApi;
-pub use error::PollError;
+pub use autodiscovery::find_feed_links;
+pub use config::{
+    contrast_ratio, AllowHttp, AppConfig, ConfigIssue, DateFormat, FeedConfig, IntegrationsConfig,
+    Layout, NetworkConfig, SortMode, StarredFeedConfig, SyncMode, ThemeConfig, ThemePreset,
+    UiConfig, WallabagConfig, WebSubConfig, CONFIG_VERSION, WCAG_AA_CONTRAST,
+};
+pub use connectivity::{
+    Connectivity, ConnectivityCheck, DEFAULT_OFFLINE_AFTER_HOSTS, DEFAULT_OFFLINE_PROBE_INTERVAL,
+};
+pub use content_extractor::{attr_value, extract_readable, extract_selected, ContentSelector};
+pub use data::STATE_SCHEMA_VERSION;
+pub use data::{
+    CleanupReport, CompactionReport, DataApi, FeedBackup, ImportSummary, ReadMarks, StateBundle,
+    COMPACT_MARKS_AFTER, DEFAULT_MAX_TITLE_CHARS, MAX_READING_POSITIONS,
+    READ_JOURNAL_COMPACT_AFTER,
+};
+pub use dedup::{cluster_titles, normalize_title, title_similarity, TitleClusters};
+pub use dedup::{dedup_entries, feed_url_key, normalize_url, DedupedEntry};
+pub use dedup::{DEFAULT_TITLE_SIMILARITY, TITLE_CLUSTER_WINDOW_HOURS};
+pub use error::{
+    AddFeedError, DataError, PollError, ReadLaterError, RuleError, StateError, WebSubError,
+};
+pub use export::{slugify, ExportFormat};
+pub use favicon::{fetch_favicon, find_icon_link, site_root};
+pub use favicon::{ICON_REFRESH_INTERVAL, MAX_ICON_BYTES};
 pub use feed::shared_feed_list;
-pub use feed::{add_feed, list_feeds, remove_feed};
-pub use feed::{FeedDescriptor, FeedEntry, SharedFeedList};
-pub use poller::{poll_once, spawn_poller, Event, PollConfig, PollerHandle};
-pub use storage::SeenStore;
+pub use feed::{add_feed, list_feeds, mute_feed, record_feed_result, reenable_feed, remove_feed};
+pub use feed::{feeds_by_category, remove_feeds, update_feeds, FeedUpdate};
+pub use feed::{
+    record_new_articles, reorder_feeds, try_add_feed, update_feed_metadata, update_feed_title,
+    update_feed_url,
+};
+pub use feed::{FeedAuth, FeedDescriptor, FeedEntry, FeedMeta, RetentionPolicy};
+pub use feed::{Secret, SharedFeedList};
+pub use feed_writer::ChannelMeta;
+pub use filter::ArticleFilter;
+pub use http::{build_http_client, DEFAULT_USER_AGENT, MAX_REDIRECTS};
+pub use inline_images::{fetch_inline_image, split_inline_images, ContentBlock};
+pub use inline_images::{MAX_INLINE_IMAGES, MAX_INLINE_IMAGE_BYTES};
+#[cfg(feature = "imap-feeds")]
+pub use mailbox::{collect_entries, is_mailbox_url, message_entry, MailSession, MailboxFeed};
+#[cfg(feature = "imap-feeds")]
+pub use mailbox::{ImapSession, MAX_MESSAGES_PER_POLL};
+#[cfg(feature = "metrics-http")]
+pub use metrics::{serve_metrics, MetricsServer};
+pub use metrics::{Metrics, MetricsSnapshot, PollerMetrics, SharedMetrics};
+pub use opml::feeds_to_opml;
+pub use poller::{adaptive_interval, DEFAULT_MAX_ADAPTIVE_INTERVAL};
+pub use poller::{poll_once, spawn_event_poller, spawn_poller, Event, EVENT_CHANNEL_CAPACITY};
+pub use poller::{poll_once_in, poll_stream, FeedPollResult};
+pub use poller::{preview_feed, FeedFormat, FeedPreview, PREVIEW_RECENT_TITLES};
+pub use poller::{stagger_offset, DEFAULT_HOST_DELAY, DEFAULT_POLL_JITTER};
+pub use poller::{PollConfig, PollerCommand, PollerHandle};
+pub use poller::{DEFAULT_ARTICLE_TIMEOUT, DEFAULT_MAX_ARTICLE_BYTES};
+pub use preview::{
+    article_preview, count_words, PreviewCache, ReadingStats, DEFAULT_READING_WPM,
+    PREVIEW_MAX_CHARS,
+};
+pub use rate_limit::throttled_for;
+pub use read_later::{ReadLaterService, WallabagClient};
+pub use redact::{redact_url, redact_url_str, redact_values};
+pub use rules::{CompiledRule, FilterRule, RuleAction, RuleField, RuleOutcome, RuleSet};
+pub use sanitize::{find_tag_end, tag_name};
+pub use secrets::{FeedSecret, FeedSecrets};
+pub use service::FeedService;
+pub use session::{SessionState, SessionView};
+pub use share::{mailto_url, markdown_link, share_mailto, title_and_link};
+pub use stats::{compute_stats, local_day, Stats, StatsRange, STATS_TOP_FEEDS};
+pub use stats::{format_bytes, CycleSummary, FeedStats, FetchErrorKind, FetchOutcome};
+pub use storage::{write_atomic, write_atomic_private, SeenStatus, SeenStore};
+pub use sync::{FeverClient, FeverGroup, FeverItem, FeverMark, FeverSync, SyncBackend};
+pub use sync::{GReaderClient, GReaderItem, GReaderSession, GReaderSync, GReaderTag};
+pub use time::{
+    format_absolute, format_relative, in_quiet_hours, parse_feed_date, parse_iso8601,
+    quiet_hours_remaining, relative_time, RelativeTime,
+};
+pub use url_rewrite::{github_feed_url, resolve_rewrite, rewrite_feed_url, youtube_channel_feed};
+pub use url_rewrite::{youtube_channel_id, GitHubFeed, UrlRewrite};
+pub use websub::{SubscriptionInfo, SubscriptionState, WebSub, WebSubLinks};
@@ -0,0 +1,525 @@
+use std::borrow::Cow;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use mail_parser::MessageParser;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+use tracing::debug;
+use url::Url;
+
+use crate::error::PollError;
+use crate::feed::{fnv1a, FeedAuth, FeedDescriptor, FeedEntry, Secret};
+use crate::poller::PollConfig;
+
+// Messages lus au plus par relevé (les plus récents d’abord gardés).
+pub const MAX_MESSAGES_PER_POLL: usize = 50;
+
+pub const IMAPS_PORT: u16 = 993;
+pub const IMAP_PORT: u16 = 143;
+
+// Ligne de réponse IMAP la plus longue acceptée (liste de SEARCH comprise).
+const MAX_LINE_BYTES: u64 = 1024 * 1024;
+
+// ===
+//
+//
+// Boîte aux lettres suivie comme un flux (feature `imap-feeds`): les messages non lus du
+// dossier, éventuellement limités à un expéditeur, deviennent des articles. Elle est
+// enregistrée dans feeds.json comme un FeedDescriptor (voir `descriptor`): URL
+// `imaps://utilisateur@hôte:port/?folder=…&from=…`, identifiants en FeedAuth::Basic et
+// identifiant `imap:<empreinte>`. Les messages restent non lus sur le serveur (dossier ouvert
+// en lecture seule) sauf `mark_seen_on_server`; le SeenStore évite les doublons.
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailboxFeed {
+    pub title: String,
+    pub host: String,
+    pub port: u16,
+    // IMAP sur TLS (imaps); sans TLS, les identifiants passent en clair.
+    pub tls: bool,
+    pub username: String,
+    pub password: Secret,
+    pub folder: String,
+    // Sous-chaîne cherchée dans l’expéditeur (adresse ou nom), sans tenir compte de la casse.
+    pub from_filter: Option<String>,
+    pub mark_seen_on_server: bool,
+}
+
+impl MailboxFeed {
+    // Boîte INBOX en IMAPS (port 993), sans filtre.
+    pub fn new(host: impl Into<String>, username: impl Into<String>, password: Secret) -> Self {
+        Self {
+            title: String::new(),
+            host: host.into(),
+            port: IMAPS_PORT,
+            tls: true,
+            username: username.into(),
+            password,
+            folder: "INBOX".to_string(),
+            from_filter: None,
+            mark_seen_on_server: false,
+        }
+    }
+
+    // Identifiant du flux: empreinte du serveur, du compte, du dossier et du filtre.
+    pub fn id(&self) -> String {
+        let host = self.host.trim().to_lowercase();
+        let port = self.port.to_string();
+        let from = self.from_filter.as_deref().unwrap_or_default();
+        format!(
+            "imap:{:016x}",
+            fnv1a(&[
+                host.as_bytes(),
+                port.as_bytes(),
+                self.username.as_bytes(),
+                self.folder.as_bytes(),
+                from.as_bytes(),
+            ])
+        )
+    }
+
+    // ===
+    //
+    //
+    // Flux à ajouter (DataApi::try_add_feed); le titre vide devient le filtre d’expéditeur ou
+    // le compte. Erreur si l’hôte n’est pas valide.
+    //
+    //
+    // ===
+    pub fn descriptor(&self) -> Result<FeedDescriptor, PollError> {
+        let scheme = if self.tls { "imaps" } else { "imap" };
+        let mut url = Url::parse(&format!("{}://{}/", scheme, self.host.trim()))?;
+        if url.host_str().is_none_or(str::is_empty) || url.set_port(Some(self.port)).is_err() {
+            return Err(PollError::Mailbox(format!("invalid host: {}", self.host)));
+        }
+        let _ = url.set_username(&self.username);
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("folder", &self.folder);
+            if let Some(from) = &self.from_filter {
+                query.append_pair("from", from);
+            }
+            if self.mark_seen_on_server {
+                query.append_pair("mark_seen", "1");
+            }
+        }
+        let title = match self.title.trim() {
+            "" => self.from_filter.as_deref().unwrap_or(&self.username),
+            title => title,
+        };
+        let mut feed = FeedDescriptor::new(self.id(), title, url.to_string());
+        feed.auth = Some(FeedAuth::Basic {
+            username: self.username.clone(),
+            password: self.password.clone(),
+        });
+        feed.allow_insecure = !self.tls;
+        Ok(feed)
+    }
+
+    // Boîte décrite par un flux `imap://` ou `imaps://` (None pour un autre flux).
+    pub fn from_descriptor(feed: &FeedDescriptor) -> Option<Self> {
+        let url = Url::parse(&feed.url).ok()?;
+        let tls = match url.scheme() {
+            "imaps" => true,
+            "imap" => false,
+            _ => return None,
+        };
+        let default_port = if tls { IMAPS_PORT } else { IMAP_PORT };
+        let query = |key: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.into_owned())
+        };
+        let (username, password) = match &feed.auth {
+            Some(FeedAuth::Basic { username, password }) => (username.clone(), password.clone()),
+            _ => (url.username().to_string(), Secret::Plaintext(String::new())),
+        };
+        Some(Self {
+            title: feed.title.clone(),
+            host: url.host_str()?.to_string(),
+            port: url.port().unwrap_or(default_port),
+            tls,
+            username,
+            password,
+            folder: query("folder").unwrap_or_else(|| "INBOX".to_string()),
+            from_filter: query("from").filter(|from| !from.trim().is_empty()),
+            mark_seen_on_server: query("mark_seen").is_some_and(|v| v == "1"),
+        })
+    }
+}
+
+// Adresse de boîte aux lettres (`imap://` ou `imaps://`) plutôt que de flux HTTP.
+pub fn is_mailbox_url(url: &str) -> bool {
+    Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "imap" | "imaps"))
+}
+
+// ===
+//
+//
+// Session IMAP réduite aux opérations du relevé (identifiants de messages en UID), mise
+// derrière un trait pour tester collect_entries sans serveur.
+//
+//
+// ===
+pub trait MailSession {
+    // UID des messages non lus du dossier, filtrés côté serveur par expéditeur si possible.
+    fn search_unseen(
+        &mut self,
+        from: Option<&str>,
+    ) -> impl Future<Output = Result<Vec<u32>, PollError>> + Send;
+
+    // Message brut (RFC 5322), sans le marquer lu.
+    fn fetch_message(
+        &mut self,
+        uid: u32,
+    ) -> impl Future<Output = Result<Vec<u8>, PollError>> + Send;
+
+    fn mark_seen(&mut self, uid: u32) -> impl Future<Output = Result<(), PollError>> + Send;
+}
+
+// ===
+//
+//
+// Articles tirés des messages non lus (les MAX_MESSAGES_PER_POLL plus récents). Le filtre
+// d’expéditeur est réappliqué ici, les serveurs ne cherchant pas tous de la même façon; un
+// message illisible est ignoré. Avec `mark_seen_on_server`, chaque message lu est marqué.
+//
+//
+// ===
+pub async fn collect_entries<S: MailSession>(
+    session: &mut S,
+    mailbox: &MailboxFeed,
+    feed_id: &str,
+) -> Result<Vec<FeedEntry>, PollError> {
+    let from = mailbox.from_filter.as_deref();
+    let mut uids = session.search_unseen(from).await?;
+    uids.sort_unstable();
+    let skip = uids.len().saturating_sub(MAX_MESSAGES_PER_POLL);
+    let mut entries = Vec::new();
+    for &uid in &uids[skip..] {
+        let raw = session.fetch_message(uid).await?;
+        match parse_message(feed_id, &raw) {
+            Some((entry, sender)) => {
+                if from.is_none_or(|from| contains_ignore_case(&sender, from)) {
+                    entries.push(entry);
+                }
+            }
+            None => debug!(uid, "unreadable message skipped"),
+        }
+        if mailbox.mark_seen_on_server {
+            session.mark_seen(uid).await?;
+        }
+    }
+    Ok(entries)
+}
+
+// ===
+//
+//
+// Convertit un message brut en article: sujet → titre, partie HTML (ou texte converti) →
+// content_html, Date → published_at, Message-ID → guid, expéditeur → auteur. Pas de lien.
+//
+//
+// ===
+pub fn message_entry(feed_id: &str, raw: &[u8]) -> Option<FeedEntry> {
+    parse_message(feed_id, raw).map(|(entry, _)| entry)
+}
+
+// Article et expéditeur (nom et adresse) d’un message.
+fn parse_message(feed_id: &str, raw: &[u8]) -> Option<(FeedEntry, String)> {
+    let message = MessageParser::default().parse(raw)?;
+    let sender = message.from().and_then(|from| from.first());
+    let name = sender.and_then(|s| s.name()).map(str::trim);
+    let address = sender.and_then(|s| s.address()).map(str::trim);
+    let entry = FeedEntry {
+        feed_id: feed_id.to_owned(),
+        title: message.subject().unwrap_or_default().trim().to_owned(),
+        summary: None,
+        url: String::new(),
+        published_at: message
+            .date()
+            .and_then(|date| DateTime::from_timestamp(date.to_timestamp(), 0)),
+        guid: message.message_id().map(ToOwned::to_owned),
+        guid_is_permalink: Some(false),
+        author: name
+            .filter(|n| !n.is_empty())
+            .or(address)
+            .map(str::to_string),
+        categories: Vec::new(),
+        content_html: message.body_html(0).map(Cow::into_owned),
+        image_url: None,
+        highlighted: false,
+        updated_at: None,
+        first_seen_at: Utc::now(),
+        content_truncated: false,
+    };
+    let sender = format!(
+        "{} <{}>",
+        name.unwrap_or_default(),
+        address.unwrap_or_default()
+    );
+    Some((entry, sender))
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack
+        .to_lowercase()
+        .contains(needle.trim().to_lowercase().as_str())
+}
+
+// ===
+//
+//
+// Relevé d’une boîte par le poller: connexion, messages non lus, déconnexion. Chaque échange
+// est borné par `request_timeout` et chaque message par `max_feed_bytes`; le contenu est
+// nettoyé comme celui d’un flux.
+//
+//
+// ===
+pub(crate) async fn fetch_mailbox(
+    feed: &FeedDescriptor,
+    cfg: &PollConfig,
+) -> Result<Vec<FeedEntry>, PollError> {
+    let mailbox = MailboxFeed::from_descriptor(feed)
+        .ok_or_else(|| PollError::Mailbox("invalid mailbox address".to_string()))?;
+    let mut session =
+        ImapSession::connect(&mailbox, cfg.request_timeout, cfg.max_feed_bytes).await?;
+    let entries = collect_entries(&mut session, &mailbox, &feed.id).await;
+    session.logout().await;
+    Ok(entries?
+        .into_iter()
+        .map(|entry| entry.sanitized(cfg.max_content_bytes))
+        .collect())
+}
+
+trait Io: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Io for T {}
+
+// ===
+//
+//
+// Client IMAP4rev1 minimal (LOGIN, EXAMINE/SELECT, UID SEARCH/FETCH/STORE, LOGOUT) sur TCP,
+// chiffré par TLS (racines webpki) pour imaps. Pas de STARTTLS.
+//
+//
+// ===
+pub struct ImapSession {
+    stream: BufReader<Box<dyn Io>>,
+    next_tag: u32,
+    timeout: Duration,
+    max_bytes: usize,
+}
+
+// Réponse à une commande: lignes non étiquetées et littéraux (`{n}` octets), dans l’ordre.
+#[derive(Default)]
+struct Response {
+    untagged: Vec<String>,
+    literals: Vec<Vec<u8>>,
+}
+
+impl ImapSession {
+    // Connexion, identification et ouverture du dossier (en lecture seule sauf
+    // mark_seen_on_server).
+    pub async fn connect(
+        mailbox: &MailboxFeed,
+        timeout: Duration,
+        max_bytes: usize,
+    ) -> Result<Self, PollError> {
+        let host = mailbox.host.trim();
+        let tcp = within(timeout, TcpStream::connect((host, mailbox.port)))
+            .await?
+            .map_err(io_error)?;
+        let stream: Box<dyn Io> = if mailbox.tls {
+            let name = ServerName::try_from(host.to_string())
+                .map_err(|_| PollError::Mailbox(format!("invalid host: {}", host)))?;
+            let tls = within(timeout, tls_connector().connect(name, tcp))
+                .await?
+                .map_err(io_error)?;
+            Box::new(tls)
+        } else {
+            Box::new(tcp)
+        };
+        let mut session = Self {
+            stream: BufReader::new(stream),
+            next_tag: 0,
+            timeout,
+            max_bytes,
+        };
+        let greeting = within(timeout, session.read_line()).await??;
+        if !greeting.starts_with("* OK") {
+            return Err(PollError::Mailbox(format!(
+                "unexpected greeting: {}",
+                greeting
+            )));
+        }
+        let password = mailbox.password.reveal().unwrap_or_default();
+        session
+            .command(&format!(
+                "LOGIN {} {}",
+                quote(&mailbox.username)?,
+                quote(&password)?
+            ))
+            .await?;
+        let open = if mailbox.mark_seen_on_server {
+            "SELECT"
+        } else {
+            "EXAMINE"
+        };
+        session
+            .command(&format!("{} {}", open, quote(&mailbox.folder)?))
+            .await?;
+        Ok(session)
+    }
+
+    // Fin de session polie; une erreur n’a plus d’importance à ce stade.
+    pub async fn logout(&mut self) {
+        if let Err(e) = self.command("LOGOUT").await {
+            debug!(error = %e, "imap logout failed");
+        }
+    }
+
+    async fn command(&mut self, command: &str) -> Result<Response, PollError> {
+        self.next_tag += 1;
+        let tag = format!("a{}", self.next_tag);
+        let line = format!("{} {}\r\n", tag, command);
+        let stream = self.stream.get_mut();
+        within(self.timeout, async {
+            stream.write_all(line.as_bytes()).await?;
+            stream.flush().await
+        })
+        .await?
+        .map_err(io_error)?;
+        within(self.timeout, self.read_response(&tag)).await?
+    }
+
+    async fn read_response(&mut self, tag: &str) -> Result<Response, PollError> {
+        let mut response = Response::default();
+        loop {
+            let mut line = self.read_line().await?;
+            // `{n}` en fin de ligne: n octets bruts, puis la suite de la même ligne.
+            while let Some(size) = literal_size(&line) {
+                if size > self.max_bytes {
+                    return Err(PollError::TooLarge(size as u64));
+                }
+                let mut literal = vec![0; size];
+                self.stream
+                    .read_exact(&mut literal)
+                    .await
+                    .map_err(io_error)?;
+                response.literals.push(literal);
+                line = self.read_line().await?;
+            }
+            if let Some(status) = line.strip_prefix(tag).and_then(|l| l.strip_prefix(' ')) {
+                return if status.starts_with("OK") {
+                    Ok(response)
+                } else {
+                    Err(PollError::Mailbox(status.trim().to_string()))
+                };
+            }
+            response.untagged.push(line);
+        }
+    }
+
+    async fn read_line(&mut self) -> Result<String, PollError> {
+        let mut line = Vec::new();
+        (&mut self.stream)
+            .take(MAX_LINE_BYTES)
+            .read_until(b'\n', &mut line)
+            .await
+            .map_err(io_error)?;
+        if line.is_empty() {
+            return Err(PollError::Mailbox("connection closed".to_string()));
+        }
+        if !line.ends_with(b"\n") {
+            return Err(PollError::Mailbox("response line too long".to_string()));
+        }
+        Ok(String::from_utf8_lossy(&line)
+            .trim_end_matches(['\r', '\n'])
+            .to_string())
+    }
+}
+
+impl MailSession for ImapSession {
+    async fn search_unseen(&mut self, from: Option<&str>) -> Result<Vec<u32>, PollError> {
+        let mut query = "UID SEARCH UNSEEN".to_string();
+        // Chaîne non ASCII: pas de CHARSET ici, le filtre est appliqué après lecture.
+        if let Some(from) = from.filter(|from| from.is_ascii()) {
+            query.push_str(" FROM ");
+            query.push_str(&quote(from.trim())?);
+        }
+        let response = self.command(&query).await?;
+        Ok(response
+            .untagged
+            .iter()
+            .filter_map(|line| line.strip_prefix("* SEARCH"))
+            .flat_map(|uids| uids.split_whitespace().filter_map(|uid| uid.parse().ok()))
+            .collect())
+    }
+
+    async fn fetch_message(&mut self, uid: u32) -> Result<Vec<u8>, PollError> {
+        let response = self
+            .command(&format!("UID FETCH {} BODY.PEEK[]", uid))
+            .await?;
+        response
+            .literals
+            .into_iter()
+            .next()
+            .ok_or_else(|| PollError::Mailbox(format!("message {} not returned", uid)))
+    }
+
+    async fn mark_seen(&mut self, uid: u32) -> Result<(), PollError> {
+        self.command(&format!("UID STORE {} +FLAGS.SILENT (\\Seen)", uid))
+            .await
+            .map(|_| ())
+    }
+}
+
+// Taille annoncée d’un littéral en fin de ligne (`{123}`).
+fn literal_size(line: &str) -> Option<usize> {
+    let open = line.rfind('{')?;
+    line[open + 1..].strip_suffix('}')?.parse().ok()
+}
+
+// Chaîne entre guillemets IMAP; retours à la ligne refusés (ils termineraient la commande).
+fn quote(value: &str) -> Result<String, PollError> {
+    if value.contains(['\r', '\n', '\0']) {
+        return Err(PollError::Mailbox(
+            "line breaks are not allowed in IMAP strings".to_string(),
+        ));
+    }
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+fn tls_connector() -> TlsConnector {
+    let mut roots = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let provider = tokio_rustls::rustls::crypto::ring::default_provider();
+    let config = ClientConfig::builder_with_provider(Arc::new(provider))
+        .with_safe_default_protocol_versions()
+        .expect("TLS versions supported by ring")
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+}
+
+async fn within<T>(timeout: Duration, future: impl Future<Output = T>) -> Result<T, PollError> {
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_| PollError::Mailbox("timed out".to_string()))
+}
+
+fn io_error(err: std::io::Error) -> PollError {
+    PollError::Mailbox(err.to_string())
+}
@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::stats::FetchErrorKind;
+
+// ===
+//
+//
+// Métriques du poller pour la supervision d’une instance sans interface: compteurs (relevés,
+// échecs par type, nouveaux articles) et jauges (flux configurés, durée du dernier cycle).
+// Le poller et poll_once les tiennent à jour aux endroits où ils journalisent, via
+// `PollConfig::metrics`; la GUI les affiche et `serve_metrics` (feature `metrics-http`) les
+// expose au format texte de Prometheus sur `/metrics`.
+//
+//
+// ===
+pub trait Metrics: Send + Sync + std::fmt::Debug {
+    // Un flux récupéré (réussi ou non).
+    fn record_fetch(&self);
+
+    fn record_fetch_error(&self, kind: FetchErrorKind);
+
+    // Articles jamais vus trouvés lors d’un relevé (avant règles de filtrage).
+    fn record_new_articles(&self, count: usize);
+
+    // Durée d’un cycle terminé (planifié ou poll_once).
+    fn record_cycle(&self, duration: Duration);
+
+    // Flux configurés, désactivés compris (poll_once ne reçoit pas forcément toute la liste:
+    // à renseigner par l’appelant).
+    fn set_feeds_configured(&self, count: usize);
+
+    fn snapshot(&self) -> MetricsSnapshot;
+}
+
+pub type SharedMetrics = Arc<dyn Metrics>;
+
+// ===
+//
+//
+// Implémentation par défaut, en mémoire (remise à zéro au redémarrage, comme tout compteur
+// Prometheus).
+//
+//
+// ===
+#[derive(Debug, Default)]
+pub struct PollerMetrics {
+    fetch_total: AtomicU64,
+    fetch_errors: Mutex<BTreeMap<&'static str, u64>>,
+    articles_new_total: AtomicU64,
+    feeds_configured: AtomicU64,
+    last_cycle_nanos: AtomicU64,
+}
+
+impl PollerMetrics {
+    pub fn shared() -> SharedMetrics {
+        Arc::new(Self::default())
+    }
+}
+
+impl Metrics for PollerMetrics {
+    fn record_fetch(&self) {
+        self.fetch_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_fetch_error(&self, kind: FetchErrorKind) {
+        *self
+            .fetch_errors
+            .lock()
+            .unwrap()
+            .entry(kind.label())
+            .or_default() += 1;
+    }
+
+    fn record_new_articles(&self, count: usize) {
+        self.articles_new_total
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    fn record_cycle(&self, duration: Duration) {
+        self.last_cycle_nanos
+            .store(duration.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn set_feeds_configured(&self, count: usize) {
+        self.feeds_configured.store(count as u64, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            fetch_total: self.fetch_total.load(Ordering::Relaxed),
+            fetch_errors_total: self
+                .fetch_errors
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(kind, count)| (kind.to_string(), *count))
+                .collect(),
+            articles_new_total: self.articles_new_total.load(Ordering::Relaxed),
+            feeds_configured: self.feeds_configured.load(Ordering::Relaxed),
+            last_cycle_duration_seconds: Duration::from_nanos(
+                self.last_cycle_nanos.load(Ordering::Relaxed),
+            )
+            .as_secs_f64(),
+        }
+    }
+}
+
+// ===
+//
+//
+// Valeurs des métriques à un instant donné; `fetch_errors_total` est indexé par type d’échec
+// (FetchErrorKind::label).
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    pub fetch_total: u64,
+    pub fetch_errors_total: BTreeMap<String, u64>,
+    pub articles_new_total: u64,
+    pub feeds_configured: u64,
+    pub last_cycle_duration_seconds: f64,
+}
+
+impl MetricsSnapshot {
+    // Échecs tous types confondus.
+    pub fn fetch_errors(&self) -> u64 {
+        self.fetch_errors_total.values().sum()
+    }
+
+    // ===
+    //
+    //
+    // Format texte d’exposition de Prometheus (version 0.0.4), noms préfixés par `readrss_`.
+    //
+    //
+    // ===
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        write_metric(
+            &mut out,
+            "fetch_total",
+            "counter",
+            "Feeds fetched.",
+            &[(String::new(), self.fetch_total as f64)],
+        );
+        let errors: Vec<(String, f64)> = self
+            .fetch_errors_total
+            .iter()
+            .map(|(kind, count)| (format!("{{kind=\"{}\"}}", kind), *count as f64))
+            .collect();
+        write_metric(
+            &mut out,
+            "fetch_errors_total",
+            "counter",
+            "Failed feed fetches, by error kind.",
+            &errors,
+        );
+        write_metric(
+            &mut out,
+            "articles_new_total",
+            "counter",
+            "New articles found.",
+            &[(String::new(), self.articles_new_total as f64)],
+        );
+        write_metric(
+            &mut out,
+            "feeds_configured",
+            "gauge",
+            "Configured feeds.",
+            &[(String::new(), self.feeds_configured as f64)],
+        );
+        write_metric(
+            &mut out,
+            "last_cycle_duration_seconds",
+            "gauge",
+            "Duration of the last poll cycle.",
+            &[(String::new(), self.last_cycle_duration_seconds)],
+        );
+        out
+    }
+}
+
+// Une métrique: entêtes HELP et TYPE puis une ligne par jeu d’étiquettes (`{kind="…"}`).
+fn write_metric(out: &mut String, name: &str, kind: &str, help: &str, samples: &[(String, f64)]) {
+    let _ = writeln!(out, "# HELP readrss_{} {}", name, help);
+    let _ = writeln!(out, "# TYPE readrss_{} {}", name, kind);
+    for (labels, value) in samples {
+        let _ = writeln!(out, "readrss_{}{} {}", name, labels, value);
+    }
+}
+
+// ===
+//
+//
+// Point d’accès HTTP `/metrics` (feature `metrics-http`) pour une instance sans interface.
+// Arrêté quand le MetricsServer est abandonné.
+//
+//
+// ===
+#[cfg(feature = "metrics-http")]
+pub struct MetricsServer {
+    local_addr: std::net::SocketAddr,
+    task: tokio::task::JoinHandle<()>,
+}
+
+#[cfg(feature = "metrics-http")]
+impl MetricsServer {
+    pub fn local_addr(&self) -> std::net::SocketAddr {
+        self.local_addr
+    }
+}
+
+#[cfg(feature = "metrics-http")]
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+// ===
+//
+//
+// Sert `metrics` sur `bind_address` (port 0: port libre choisi par le système): GET /metrics
+// renvoie to_prometheus(), toute autre route 404.
+//
+//
+// ===
+#[cfg(feature = "metrics-http")]
+pub async fn serve_metrics(
+    bind_address: &str,
+    metrics: SharedMetrics,
+) -> std::io::Result<MetricsServer> {
+    let listener = tokio::net::TcpListener::bind(bind_address).await?;
+    let local_addr = listener.local_addr()?;
+    let task = tokio::spawn(http::serve(listener, metrics));
+    tracing::info!(address = %local_addr, "metrics endpoint listening");
+    Ok(MetricsServer { local_addr, task })
+}
+
+#[cfg(feature = "metrics-http")]
+mod http {
+    use std::convert::Infallible;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use http_body_util::Full;
+    use hyper::body::Incoming;
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper::{header, Method, Request, Response, StatusCode};
+    use hyper_util::rt::TokioIo;
+    use tokio::net::TcpListener;
+    use tracing::{debug, warn};
+
+    use super::SharedMetrics;
+
+    pub(super) async fn serve(listener: TcpListener, metrics: SharedMetrics) {
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    warn!(error = %e, "metrics endpoint: accept failed");
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            let metrics = metrics.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let response = handle(&metrics, &request);
+                    async move { Ok::<_, Infallible>(response) }
+                });
+                if let Err(e) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    debug!(error = %e, "metrics connection failed");
+                }
+            });
+        }
+    }
+
+    fn handle(metrics: &SharedMetrics, request: &Request<Incoming>) -> Response<Full<Bytes>> {
+        let (status, body) = match (request.method(), request.uri().path()) {
+            (&Method::GET, "/metrics") => (StatusCode::OK, metrics.snapshot().to_prometheus()),
+            (_, "/metrics") => (StatusCode::METHOD_NOT_ALLOWED, String::new()),
+            _ => (StatusCode::NOT_FOUND, String::new()),
+        };
+        let mut response = Response::new(Full::new(Bytes::from(body)));
+        *response.status_mut() = status;
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("text/plain; version=0.0.4"),
+        );
+        response
+    }
+}
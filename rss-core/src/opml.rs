@@ -0,0 +1,36 @@
+// ===
+//
+//
+// Export OPML 2.0 de la liste des abonnements (format d’échange reconnu par les autres lecteurs).
+// Les identifiants d’authentification ne sont jamais exportés.
+//
+//
+// ===
+
+use crate::feed::FeedDescriptor;
+use crate::sanitize::escape_html;
+
+// ===
+//
+//
+// Document OPML listant les flux, un `<outline type="rss">` par abonnement.
+//
+//
+// ===
+pub fn feeds_to_opml(feeds: &[FeedDescriptor], title: &str) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<opml version=\"2.0\">\n  <head>\n");
+    out.push_str(&format!("    <title>{}</title>\n", escape_html(title)));
+    out.push_str("  </head>\n  <body>\n");
+    for feed in feeds {
+        let text = escape_html(&feed.title);
+        out.push_str(&format!(
+            "    <outline type=\"rss\" text=\"{}\" title=\"{}\" xmlUrl=\"{}\"/>\n",
+            text,
+            text,
+            escape_html(&feed.url)
+        ));
+    }
+    out.push_str("  </body>\n</opml>\n");
+    out
+}
@@ -1,22 +1,65 @@
+use std::collections::HashMap;
 use std::path::Path;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use chrono::Utc;
-use futures_util::StreamExt;
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use futures_util::{Stream, StreamExt};
 use reqwest::Client;
 use tokio::sync::{broadcast, mpsc};
 use tokio::task::JoinHandle;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 use url::Url;
 
+use crate::autodiscovery::find_feed_links;
+use crate::config::{AllowHttp, AppConfig, FeedConfig};
+use crate::connectivity::{
+    self, Connectivity, ConnectivityCheck, DEFAULT_OFFLINE_AFTER_HOSTS,
+    DEFAULT_OFFLINE_PROBE_INTERVAL,
+};
+use crate::content_extractor::{extract_readable, extract_selected, ContentSelector};
+use crate::data::DEFAULT_MAX_ARTICLES_PER_FEED;
 use crate::error::PollError;
-use crate::feed::{FeedDescriptor, FeedEntry, SharedFeedList};
-use crate::storage::SeenStore;
+use crate::feed::{
+    atom_alternate_link, record_feed_result, shared_feed_list, FeedAuth, FeedDescriptor,
+    FeedEntry, FeedMeta, SharedFeedList,
+};
+use crate::http::{download_capped_as, send_following_redirects};
+use crate::metrics::SharedMetrics;
+use crate::rate_limit;
+use crate::redact::{redact_url, redact_url_str};
+use crate::sanitize::truncate_html;
+use crate::secrets::FeedSecrets;
+use crate::stats::{CycleSummary, FetchErrorKind, FetchOutcome};
+use crate::storage::{SeenStatus, SeenStore};
+use crate::sync::SyncBackend;
+use crate::time::{parse_feed_date, quiet_hours_remaining};
+use crate::websub::{WebSub, WebSubLinks};
 
 // ===
 //
 //
-// Configuration du poller (intervalle, timeouts, retries et backoff).
+// Configuration du poller (intervalle, timeouts, retries, backoff et limites de taille).
+// `max_backoff_ms` plafonne aussi bien le backoff exponentiel que les délais Retry-After.
+// `max_content_bytes` borne le contenu et le résumé de chaque article, `max_articles_per_feed`
+// le nombre d’entrées gardées par récupération (voir bounded_entries).
+// `article_timeout` et `max_article_bytes` bornent chaque page d’article téléchargée pour les
+// flux en contenu complet, sans retry.
+// `jitter` étale les flux d’un cycle planifié sur une fenêtre (décalage stable par flux, borné à la
+// moitié de l’intervalle) et `host_delay` espace deux récupérations sur un même hôte. Les deux
+// valent zéro par défaut (flux enchaînés); from_feed_config les active pour l’application.
+// `allow_http` indique quels flux peuvent être récupérés en HTTP (LoopbackOnly par défaut).
+// `per_host_rate_limit` (n requêtes par période) limite chaque hôte par un seau de jetons commun
+// à tous ses flux, retries compris (voir rate_limit); None par défaut.
+// `push` (WebSub) abonne les flux qui annoncent un hub et espace leurs relevés planifiés; None par
+// défaut (voir websub).
+// `adaptive_polling` espace les relevés planifiés d’un flux silencieux (voir adaptive_interval),
+// jamais au-delà de `max_adaptive_interval`; désactivé par défaut.
+// `metrics` reçoit les compteurs de relevés et la durée de chaque cycle (voir metrics); None par
+// défaut.
+// `connectivity` est l’état en ligne partagé avec les rafraîchissements manuels: hors ligne
+// après des erreurs réseau sur `offline_after_hosts` serveurs sans aucune réponse, le poller
+// remplace ses cycles par une sonde toutes les `offline_probe_interval` (voir connectivity).
+// `secrets` donne le cookie de chaque flux (tenu par DataApi, voir secrets); vide par défaut.
 //
 //
 // ===
@@ -26,8 +69,65 @@ pub struct PollConfig {
     pub request_timeout: Duration,
     pub max_retries: usize,
     pub retry_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub max_content_bytes: usize,
+    pub max_feed_bytes: usize,
+    pub max_articles_per_feed: usize,
+    pub disable_after_failures: u32,
+    pub article_timeout: Duration,
+    pub max_article_bytes: usize,
+    pub jitter: Duration,
+    pub host_delay: Duration,
+    pub allow_http: AllowHttp,
+    pub per_host_rate_limit: Option<(u32, Duration)>,
+    pub quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    pub quiet_hours_catch_up: bool,
+    pub push: Option<WebSub>,
+    pub adaptive_polling: bool,
+    pub max_adaptive_interval: Duration,
+    pub metrics: Option<SharedMetrics>,
+    pub connectivity: Connectivity,
+    pub offline_after_hosts: usize,
+    pub offline_probe_interval: Duration,
+    pub secrets: FeedSecrets,
 }
 
+// Taille maximale par défaut du HTML conservé pour un article (après nettoyage).
+pub const DEFAULT_MAX_CONTENT_BYTES: usize = 256 * 1024;
+
+// Taille maximale par défaut d’un document de flux, mesurée après décompression.
+pub const DEFAULT_MAX_FEED_BYTES: usize = 10 * 1024 * 1024;
+
+// Nombre d’échecs consécutifs par défaut avant désactivation automatique d’un flux.
+pub const DEFAULT_DISABLE_AFTER_FAILURES: u32 = 10;
+
+// Délai maximal par défaut pour télécharger une page d’article (contenu complet).
+pub const DEFAULT_ARTICLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Taille maximale par défaut d’une page d’article téléchargée.
+pub const DEFAULT_MAX_ARTICLE_BYTES: usize = 2 * 1024 * 1024;
+
+// Fenêtre d’étalement des flux utilisée par l’application (from_feed_config).
+pub const DEFAULT_POLL_JITTER: Duration = Duration::from_secs(60);
+
+// Écart minimal entre deux récupérations sur un même hôte utilisé par l’application.
+pub const DEFAULT_HOST_DELAY: Duration = Duration::from_secs(1);
+
+// Plafond par défaut de l’intervalle adaptatif d’un flux silencieux.
+pub const DEFAULT_MAX_ADAPTIVE_INTERVAL: Duration = Duration::from_secs(12 * 3600);
+
+// Paliers de l’intervalle adaptatif: (jours sans nouvel article, multiplicateur de l’intervalle).
+const ADAPTIVE_STEPS: [(i64, u32); 2] = [(30, 4), (7, 2)];
+
+// Évènements gardés pour un abonné en retard avant qu’il ne reçoive `RecvError::Lagged`.
+pub const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+// Pages d’articles téléchargées en parallèle pour un même flux.
+const FULL_CONTENT_CONCURRENCY: usize = 4;
+
+// Flux récupérés en parallèle par poll_stream (la limite par hôte s’applique toujours).
+const POLL_ONCE_CONCURRENCY: usize = 8;
+
 impl Default for PollConfig {
     fn default() -> Self {
         Self {
@@ -35,12 +135,52 @@ impl Default for PollConfig {
             request_timeout: Duration::from_secs(15),
             max_retries: 3,
             retry_backoff_ms: 500,
+            max_backoff_ms: 60_000,
+            max_content_bytes: DEFAULT_MAX_CONTENT_BYTES,
+            max_feed_bytes: DEFAULT_MAX_FEED_BYTES,
+            max_articles_per_feed: DEFAULT_MAX_ARTICLES_PER_FEED,
+            disable_after_failures: DEFAULT_DISABLE_AFTER_FAILURES,
+            article_timeout: DEFAULT_ARTICLE_TIMEOUT,
+            max_article_bytes: DEFAULT_MAX_ARTICLE_BYTES,
+            jitter: Duration::ZERO,
+            host_delay: Duration::ZERO,
+            allow_http: AllowHttp::default(),
+            per_host_rate_limit: None,
+            quiet_hours: None,
+            quiet_hours_catch_up: true,
+            push: None,
+            adaptive_polling: false,
+            max_adaptive_interval: DEFAULT_MAX_ADAPTIVE_INTERVAL,
+            metrics: None,
+            connectivity: Connectivity::default(),
+            offline_after_hosts: DEFAULT_OFFLINE_AFTER_HOSTS,
+            offline_probe_interval: DEFAULT_OFFLINE_PROBE_INTERVAL,
+            secrets: FeedSecrets::default(),
         }
     }
 }
 
+// ===
+//
+//
+// Commandes envoyées à la tâche du poller en cours d’exécution.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub enum PollerCommand {
+    Pause,
+    Resume,
+    PollNow,
+    UpdateConfig(Box<PollConfig>),
+    UpdateClient(Client),
+    SetSync(Option<Box<SyncBackend>>),
+}
+
 pub struct PollerHandle {
     cancel_tx: broadcast::Sender<()>,
+    command_tx: mpsc::UnboundedSender<PollerCommand>,
+    events: broadcast::Sender<Event>,
     join: JoinHandle<()>,
 }
 
@@ -56,12 +196,99 @@ impl PollerHandle {
         let _ = self.cancel_tx.send(());
         self.join.await.map_err(PollError::from)
     }
+
+    // ===
+    //
+    //
+    // Suspend les cycles périodiques (aucune activité réseau) sans arrêter la tâche.
+    //
+    //
+    // ===
+    pub fn pause(&self) {
+        self.send(PollerCommand::Pause);
+    }
+
+    // ===
+    //
+    //
+    // Reprend les cycles périodiques après une pause.
+    //
+    //
+    // ===
+    pub fn resume(&self) {
+        self.send(PollerCommand::Resume);
+    }
+
+    // ===
+    //
+    //
+    // Déclenche immédiatement un cycle de polling, hors ticker (même en pause).
+    //
+    //
+    // ===
+    pub fn poll_now(&self) {
+        self.send(PollerCommand::PollNow);
+    }
+
+    // ===
+    //
+    //
+    // Applique une nouvelle configuration à chaud (intervalle, timeouts, retries).
+    //
+    //
+    // ===
+    pub fn update_config(&self, config: PollConfig) {
+        self.send(PollerCommand::UpdateConfig(Box::new(config)));
+    }
+
+    // ===
+    //
+    //
+    // Remplace le client HTTP utilisé pour les prochains cycles (réglages réseau modifiés).
+    //
+    //
+    // ===
+    pub fn update_client(&self, client: Client) {
+        self.send(PollerCommand::UpdateClient(client));
+    }
+
+    // ===
+    //
+    //
+    // Active (Some) ou désactive (None) la synchronisation avec un serveur (Fever, Google
+    // Reader) à la place du polling local.
+    //
+    //
+    // ===
+    pub fn set_sync(&self, sync: Option<SyncBackend>) {
+        self.send(PollerCommand::SetSync(sync.map(Box::new)));
+    }
+
+    // ===
+    //
+    //
+    // Nouveau récepteur des évènements du poller: il reçoit tous ceux émis après cet appel.
+    // Un abonné trop lent perd les plus anciens (`RecvError::Lagged`), sans bloquer les autres.
+    //
+    //
+    // ===
+    pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.events.subscribe()
+    }
+
+    fn send(&self, cmd: PollerCommand) {
+        if self.command_tx.send(cmd).is_err() {
+            warn!("poller task is not running; command dropped");
+        }
+    }
 }
 
 // ===
 //
 //
-// Lance une tâche périodique qui récupère les flux et émet des évènements.
+// Adaptateur mpsc de spawn_event_poller, gardé pour la transition: les évènements sont relayés
+// vers `update_tx` dans l’ordre. Un retard du relais est loggé avec le nombre d’évènements
+// perdus; le relais s’arrête quand le poller est arrêté ou que le récepteur est fermé.
 //
 //
 // ===
@@ -70,80 +297,869 @@ pub fn spawn_poller(
     config: PollConfig,
     client: Client,
     update_tx: mpsc::Sender<Event>,
-    seen: SeenStore,
+    seen: impl Into<SeenStore>,
+) -> PollerHandle {
+    let (events, mut relay) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+    tokio::spawn(async move {
+        loop {
+            match relay.recv().await {
+                Ok(event) => {
+                    if update_tx.send(event).await.is_err() {
+                        debug!("update receiver dropped; stopping event relay");
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        "event relay lagged behind the poller; events dropped"
+                    );
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+    spawn_event_poller(feeds, config, client, events, seen)
+}
+
+// ===
+//
+//
+// Lance une tâche périodique qui récupère les flux et diffuse ses évènements sur `events`.
+// Les récepteurs créés avant l’appel (`events.subscribe()`) ne manquent pas le premier cycle;
+// PollerHandle::subscribe en ajoute d’autres ensuite.
+// `seen`: un SeenStore ou directement `&DataApi`, dont le poller partage les “vus”.
+//
+//
+// ===
+pub fn spawn_event_poller(
+    feeds: SharedFeedList,
+    mut config: PollConfig,
+    mut client: Client,
+    events: broadcast::Sender<Event>,
+    seen: impl Into<SeenStore>,
 ) -> PollerHandle {
+    let seen = seen.into();
+    let update_tx = events.clone();
     let (cancel_tx, mut cancel_rx) = broadcast::channel(1);
+    let (command_tx, mut command_rx) = mpsc::unbounded_channel();
     let join = tokio::spawn(async move {
         let mut ticker = tokio::time::interval(config.interval);
         ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // Dernier tick du ticker (le premier tombe immédiatement), base d’un nouvel intervalle.
+        let mut last_tick = tokio::time::Instant::now();
+        let mut paused = false;
+        let mut sync: Option<Box<SyncBackend>> = None;
+        // Rafraîchissement de rattrapage prévu à la fin des heures calmes.
+        let mut catch_up: Option<tokio::time::Instant> = None;
+        let mut schedule = PollSchedule::default();
+        // Prochaine sonde de connectivité, tant que le réseau semble coupé.
+        let mut probe_at: Option<tokio::time::Instant> = None;
 
         loop {
             tokio::select! {
+                biased;
                 _ = cancel_rx.recv() => {
                     info!("poller shutdown requested");
                     break;
                 }
-                _ = ticker.tick() => {
-                    let feeds_snapshot = feeds.read().await.clone();
-                    for feed in feeds_snapshot {
-                        match fetch_feed_with_retries(&client, &feed, &config).await {
-                            Ok(entries) if !entries.is_empty() => {
-                                // Filter already seen
-                                let mut new_entries = Vec::new();
-                                for e in entries {
-                                    if seen.is_new_and_mark(&e).await {
-                                        new_entries.push(e);
-                                    }
-                                }
-                                if !new_entries.is_empty() {
-                                    let evt = Event::NewArticles(feed.id.clone(), new_entries);
-                                    if update_tx.send(evt).await.is_err() {
-                                        warn!("update receiver dropped");
-                                    }
-                                }
-                            }
-                            Ok(_) => {}
-                            Err(err) => {
-                                warn!(feed = %feed.url, error = %err, "failed to fetch feed");
+                Some(cmd) = command_rx.recv() => match cmd {
+                    PollerCommand::Pause => {
+                        info!("poller paused");
+                        paused = true;
+                    }
+                    PollerCommand::Resume => {
+                        info!("poller resumed");
+                        paused = false;
+                    }
+                    PollerCommand::PollNow => {
+                        // L’arrêt interrompt aussi un cycle immédiat (attente d’un jeton comprise).
+                        tokio::select! {
+                            biased;
+                            _ = cancel_rx.recv() => {
+                                info!("poller shutdown requested");
+                                break;
                             }
+                            _ = run_cycle(sync.as_deref(), &feeds, &config, &client, &seen, &update_tx, None) => {}
+                        }
+                        probe_at = offline_probe_at(&config);
+                    }
+                    PollerCommand::UpdateConfig(new_config) => {
+                        info!(interval_secs = new_config.interval.as_secs(), "poller config updated");
+                        if new_config.interval != config.interval {
+                            // Prochain relevé compté depuis le dernier, immédiat s’il est dépassé.
+                            let start = (last_tick + new_config.interval)
+                                .max(tokio::time::Instant::now());
+                            ticker = tokio::time::interval_at(start, new_config.interval);
+                            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                        }
+                        config = *new_config;
+                    }
+                    PollerCommand::UpdateClient(new_client) => {
+                        info!("poller HTTP client replaced");
+                        client = new_client;
+                    }
+                    PollerCommand::SetSync(new_sync) => {
+                        info!(enabled = new_sync.is_some(), "poller sync mode updated");
+                        sync = new_sync;
+                    }
+                },
+                _ = tokio::time::sleep_until(catch_up.unwrap_or_else(tokio::time::Instant::now)),
+                    if catch_up.is_some() =>
+                {
+                    catch_up = None;
+                    // Fin estimée à l’heure murale: revérifier (changement d’heure, réglage modifié).
+                    if let Some(wait) = config.quiet_hours_remaining() {
+                        catch_up = Some(tokio::time::Instant::now() + wait);
+                        continue;
+                    }
+                    if paused {
+                        continue;
+                    }
+                    info!("quiet hours over; catching up");
+                    tokio::select! {
+                        biased;
+                        _ = cancel_rx.recv() => {
+                            info!("poller shutdown requested");
+                            break;
+                        }
+                        _ = run_cycle(sync.as_deref(), &feeds, &config, &client, &seen, &update_tx, None) => {}
+                    }
+                    probe_at = offline_probe_at(&config);
+                }
+                _ = tokio::time::sleep_until(probe_at.unwrap_or_else(tokio::time::Instant::now)),
+                    if probe_at.is_some() =>
+                {
+                    probe_at = None;
+                    // Retour en ligne déjà constaté par un rafraîchissement manuel.
+                    if config.connectivity.is_online() {
+                        continue;
+                    }
+                    if paused || config.quiet_hours_remaining().is_some() {
+                        probe_at = offline_probe_at(&config);
+                        continue;
+                    }
+                    let snapshot = feeds.read().await.clone();
+                    let reachable = tokio::select! {
+                        biased;
+                        _ = cancel_rx.recv() => {
+                            info!("poller shutdown requested");
+                            break;
+                        }
+                        reachable = connectivity::probe(&client, &snapshot, &config) => reachable,
+                    };
+                    if !reachable {
+                        debug!("still offline");
+                        probe_at = offline_probe_at(&config);
+                        continue;
+                    }
+                    // De retour en ligne: tous les flux sont relevés tout de suite.
+                    report_connectivity(&config.connectivity, true, &update_tx);
+                    tokio::select! {
+                        biased;
+                        _ = cancel_rx.recv() => {
+                            info!("poller shutdown requested");
+                            break;
+                        }
+                        _ = run_cycle(sync.as_deref(), &feeds, &config, &client, &seen, &update_tx, None) => {}
+                    }
+                    probe_at = offline_probe_at(&config);
+                }
+                tick = ticker.tick() => {
+                    last_tick = tick;
+                    if paused {
+                        debug!("poller paused; skipping scheduled cycle");
+                        continue;
+                    }
+                    if !config.connectivity.is_online() {
+                        debug!("offline; scheduled cycle replaced by connectivity probes");
+                        probe_at = probe_at.or_else(|| offline_probe_at(&config));
+                        continue;
+                    }
+                    if let Some(wait) = config.quiet_hours_remaining() {
+                        debug!(remaining_secs = wait.as_secs(), "quiet hours; skipping scheduled cycle");
+                        if config.quiet_hours_catch_up && catch_up.is_none() {
+                            catch_up = Some(tokio::time::Instant::now() + wait);
                         }
+                        continue;
                     }
+                    // Un cycle étalé peut durer longtemps: l’arrêt reste prioritaire.
+                    tokio::select! {
+                        biased;
+                        _ = cancel_rx.recv() => {
+                            info!("poller shutdown requested");
+                            break;
+                        }
+                        _ = run_cycle(sync.as_deref(), &feeds, &config, &client, &seen, &update_tx, Some(&mut schedule)) => {}
+                    }
+                    probe_at = offline_probe_at(&config);
                 }
             }
         }
     });
 
-    PollerHandle { cancel_tx, join }
+    PollerHandle {
+        cancel_tx,
+        command_tx,
+        events,
+        join,
+    }
+}
+
+// Échéance de la prochaine sonde si le réseau semble coupé, None en ligne.
+fn offline_probe_at(config: &PollConfig) -> Option<tokio::time::Instant> {
+    (!config.connectivity.is_online())
+        .then(|| tokio::time::Instant::now() + config.offline_probe_interval)
+}
+
+// ===
+//
+//
+// Un cycle du poller: synchronisation serveur si elle est active, sinon polling local des flux.
+// `schedule` est fourni pour un cycle planifié (étalé sur la fenêtre de jitter, intervalle
+// adaptatif), None pour « Rafraîchir maintenant » et le rattrapage des heures calmes.
+//
+//
+// ===
+async fn run_cycle(
+    sync: Option<&SyncBackend>,
+    feeds: &SharedFeedList,
+    cfg: &PollConfig,
+    client: &Client,
+    seen: &SeenStore,
+    update_tx: &broadcast::Sender<Event>,
+    schedule: Option<&mut PollSchedule>,
+) {
+    let Some(sync) = sync else {
+        poll_cycle(feeds, cfg, client, seen, update_tx, schedule).await;
+        return;
+    };
+    match sync.run(client, seen, cfg).await {
+        Ok(events) => {
+            for event in events {
+                if update_tx.send(event).is_err() {
+                    debug!("no event subscriber; dropping sync events");
+                    return;
+                }
+            }
+        }
+        Err(err) => warn!(endpoint = %sync.redacted_endpoint(), error = %err, "sync failed"),
+    }
+}
+
+// ===
+//
+//
+// Un cycle complet du poller: chaque flux actif est récupéré et ses évènements émis aussitôt.
+// Les flux partent à leur décalage dans la fenêtre de jitter, jamais moins de `host_delay` après
+// le précédent sur le même hôte. Les échecs consécutifs sont comptés et un flux est désactivé
+// (FeedDisabled) au seuil. Les flux en sourdine (`muted_until`) attendent la fin de l’échéance.
+// Un cycle planifié saute les flux dont l’intervalle effectif (adaptatif) n’est pas écoulé.
+// Le cycle se termine par CycleCompleted (bilan des récupérations) s’il a relevé au moins un flux.
+//
+//
+// ===
+async fn poll_cycle(
+    feeds: &SharedFeedList,
+    config: &PollConfig,
+    client: &Client,
+    seen: &SeenStore,
+    update_tx: &broadcast::Sender<Event>,
+    schedule: Option<&mut PollSchedule>,
+) {
+    let cycle_start = tokio::time::Instant::now();
+    let staggered = schedule.is_some();
+    let window = if staggered {
+        config.jitter.min(config.interval / 2)
+    } else {
+        Duration::ZERO
+    };
+    let now = Utc::now();
+    // Cycle planifié seulement: un rafraîchissement demandé relève aussi les flux poussés.
+    let push = config.push.as_ref().filter(|_| staggered);
+    let mut planned: Vec<(Duration, FeedDescriptor)> = feeds
+        .read()
+        .await
+        .iter()
+        .filter(|f| !f.disabled && !f.is_muted_at(now))
+        // Flux poussés par un hub WebSub: un relevé de contrôle tous les N cycles.
+        .filter(|f| push.is_none_or(|push| push.should_poll(&f.id)))
+        // Cycle planifié: flux silencieux espacés (intervalle adaptatif).
+        .filter(|f| {
+            schedule
+                .as_deref()
+                .is_none_or(|s| s.is_due(f, config, now, cycle_start))
+        })
+        .map(|f| (stagger_offset(&f.id, window), f.clone()))
+        .collect();
+    planned.sort_by_key(|(offset, _)| *offset);
+    if let Some(schedule) = schedule {
+        for (_, feed) in &planned {
+            schedule.polled(&feed.id, cycle_start);
+        }
+    }
+    // Rien à relever (flux désactivés, en sourdine ou poussés): pas de bilan.
+    if planned.is_empty() {
+        return;
+    }
+
+    let mut summary = CycleSummary::default();
+    let mut check = ConnectivityCheck::new(planned.iter().map(|(_, f)| f), config);
+    let mut last_by_host: HashMap<String, tokio::time::Instant> = HashMap::new();
+    for (offset, feed) in planned {
+        // Hors ligne constaté: le reste du cycle est abandonné.
+        if check.verdict() == Some(false) {
+            break;
+        }
+        let host = feed_host(&feed);
+        let mut start_at = cycle_start + offset;
+        if let Some(previous) = host.as_ref().and_then(|h| last_by_host.get(h)) {
+            start_at = start_at.max(*previous + config.host_delay);
+        }
+        tokio::time::sleep_until(start_at).await;
+        if let Some(host) = host {
+            last_by_host.insert(host, tokio::time::Instant::now());
+        }
+
+        let mut events = poll_feed(&feed, config, client, seen).await;
+        summarize(&events, &mut summary);
+        for evt in &events {
+            if let Event::FetchCompleted(_, outcome) = evt {
+                check.record(&feed, outcome);
+            }
+        }
+        // Le compteur est mis à jour avant l’envoi: FetchCompleted persiste déjà le nouvel état.
+        count_failures(feeds, &feed, config, &mut events).await;
+        for evt in events {
+            if update_tx.send(evt).is_err() {
+                debug!("no event subscriber; event dropped");
+            }
+        }
+    }
+    summary.duration = cycle_start.elapsed();
+    info!(
+        feeds = summary.feeds_polled,
+        bytes = summary.bytes_downloaded,
+        new = summary.new_articles,
+        errors = summary.errors,
+        "poll cycle completed"
+    );
+    if let Some(metrics) = &config.metrics {
+        metrics.set_feeds_configured(feeds.read().await.len());
+        metrics.record_cycle(summary.duration);
+    }
+    if let Some(online) = check.verdict() {
+        report_connectivity(&config.connectivity, online, update_tx);
+    }
+    if update_tx.send(Event::CycleCompleted(summary)).is_err() {
+        debug!("no event subscriber; event dropped");
+    }
+}
+
+// Enregistre l’état de connectivité constaté et annonce un changement (ConnectivityChanged,
+// aussi rendu à l’appelant).
+pub(crate) fn report_connectivity(
+    connectivity: &Connectivity,
+    online: bool,
+    update_tx: &broadcast::Sender<Event>,
+) -> Option<Event> {
+    let online = connectivity.set_online(online)?;
+    if online {
+        info!("network reachable again");
+    } else {
+        warn!("no feed server reachable; offline until a probe succeeds");
+    }
+    let event = Event::ConnectivityChanged(online);
+    if update_tx.send(event.clone()).is_err() {
+        debug!("no event subscriber; event dropped");
+    }
+    Some(event)
+}
+
+// Ajoute au bilan du cycle les récupérations (FetchCompleted) parmi `events`.
+fn summarize(events: &[Event], summary: &mut CycleSummary) {
+    for evt in events {
+        if let Event::FetchCompleted(_, outcome) = evt {
+            summary.record(outcome);
+        }
+    }
+}
+
+// ===
+//
+//
+// Dates des relevés planifiés de chaque flux, tenues par la tâche du poller pour l’intervalle
+// adaptatif. Non persistées: après un redémarrage, chaque flux est relevé au premier cycle.
+//
+//
+// ===
+#[derive(Debug, Default)]
+struct PollSchedule {
+    last_polled: HashMap<String, tokio::time::Instant>,
+}
+
+impl PollSchedule {
+    // Relevé dû si l’intervalle effectif est écoulé, à une demi-période près (dérive du ticker).
+    fn is_due(
+        &self,
+        feed: &FeedDescriptor,
+        config: &PollConfig,
+        now: DateTime<Utc>,
+        at: tokio::time::Instant,
+    ) -> bool {
+        if !config.adaptive_polling && feed.poll_interval.is_none() {
+            return true;
+        }
+        let Some(last) = self.last_polled.get(&feed.id) else {
+            return true;
+        };
+        at.duration_since(*last) + config.interval / 2 >= config.effective_interval(feed, now)
+    }
+
+    fn polled(&mut self, feed_id: &str, at: tokio::time::Instant) {
+        self.last_polled.insert(feed_id.to_string(), at);
+    }
+}
+
+// ===
+//
+//
+// Intervalle adaptatif d’un flux: `base` ×2 après 7 jours sans nouvel article, ×4 après 30,
+// plafonné à `max` (jamais sous `base`). Sans nouvel article connu, `base`.
+//
+//
+// ===
+pub fn adaptive_interval(
+    base: Duration,
+    last_new_article_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    max: Duration,
+) -> Duration {
+    let Some(last) = last_new_article_at else {
+        return base;
+    };
+    let silent_days = (now - last).num_days();
+    let factor = ADAPTIVE_STEPS
+        .iter()
+        .find(|(days, _)| silent_days >= *days)
+        .map_or(1, |(_, factor)| *factor);
+    (base * factor).min(max.max(base))
+}
+
+// ===
+//
+//
+// Décalage d’un flux dans une fenêtre d’étalement, dérivé de son id (FNV-1a): identique d’une
+// exécution à l’autre, contrairement au hasher par défaut de la bibliothèque standard.
+//
+//
+// ===
+pub fn stagger_offset(feed_id: &str, window: Duration) -> Duration {
+    let window_ms = window.as_millis() as u64;
+    if window_ms == 0 {
+        return Duration::ZERO;
+    }
+    let hash = feed_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    Duration::from_millis(hash % window_ms)
+}
+
+// Hôte d’un flux (en minuscules), clé de l’espacement entre récupérations.
+fn feed_host(feed: &FeedDescriptor) -> Option<String> {
+    Url::parse(&feed.url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+}
+
+// ===
+//
+//
+// Récupère un flux et ne garde que les entrées jamais vues ou modifiées depuis le dernier fetch
+// (persistées en un seul lot). Émet toujours FetchCompleted (avec les entrées reçues, nouvelles
+// et déjà vues), suivi de NewArticles s’il y a des nouveautés, d’ArticlesUpdated pour les
+// articles modifiés, de FeedMetadata si le canal a changé, de FeedTitle si le flux n’a qu’un
+// titre provisoire et de FeedMoved si le flux a été déplacé.
+//
+//
+// ===
+async fn poll_feed(
+    feed: &FeedDescriptor,
+    cfg: &PollConfig,
+    client: &Client,
+    seen: &SeenStore,
+) -> Vec<Event> {
+    let fetched_at = Utc::now();
+    let started = Instant::now();
+    let report = fetch_feed_with_retries(client, feed, cfg).await;
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let mut new_entries = Vec::new();
+    let mut updated_entries = Vec::new();
+    let mut fetched_items = 0;
+    let mut meta = None;
+    let mut title = None;
+    let failure = match report.result {
+        Ok(parsed) => {
+            meta = Some(parsed.meta()).filter(|m| *m != feed.meta());
+            if feed.has_placeholder_title() && !parsed.title.is_empty() {
+                title = Some(parsed.title);
+            }
+            if let (Some(push), Some(links)) = (&cfg.push, &parsed.push) {
+                push.observe(client, feed, links);
+            }
+            fetched_items = parsed.entries.len();
+            (new_entries, updated_entries) =
+                classify_entries(parsed.entries, seen, fetched_at).await;
+            info!(
+                feed = %feed.redacted_url(),
+                fetched = fetched_items,
+                new = new_entries.len(),
+                updated = updated_entries.len(),
+                filtered = fetched_items - new_entries.len(),
+                "feed polled"
+            );
+            if let Some(metrics) = &cfg.metrics {
+                metrics.record_fetch();
+                metrics.record_new_articles(new_entries.len());
+            }
+            if feed.full_content {
+                fetch_full_contents(client, feed, cfg, &mut new_entries).await;
+                fetch_full_contents(client, feed, cfg, &mut updated_entries).await;
+            }
+            None
+        }
+        Err(err) => {
+            let message = cfg.secrets.redact(&feed.id, &err.to_string());
+            warn!(feed = %feed.redacted_url(), error = %message, "failed to fetch feed");
+            let kind = FetchErrorKind::from(&err);
+            if let Some(metrics) = &cfg.metrics {
+                metrics.record_fetch();
+                metrics.record_fetch_error(kind);
+            }
+            Some((message, kind))
+        }
+    };
+    let (error, error_kind) = failure.unzip();
+
+    let outcome = FetchOutcome {
+        fetched_at,
+        success: error.is_none(),
+        http_status: report.http_status,
+        new_items: new_entries.len(),
+        fetched_items,
+        filtered_items: fetched_items - new_entries.len(),
+        attempts: report.attempts,
+        duration_ms,
+        error,
+        error_kind,
+        bytes_downloaded: report.bytes_downloaded,
+    };
+    let mut events = vec![Event::FetchCompleted(feed.id.clone(), outcome)];
+    if !new_entries.is_empty() {
+        events.push(Event::NewArticles(feed.id.clone(), new_entries));
+    }
+    if !updated_entries.is_empty() {
+        events.push(Event::ArticlesUpdated(feed.id.clone(), updated_entries));
+    }
+    if let Some(meta) = meta {
+        events.push(Event::FeedMetadata(feed.id.clone(), meta));
+    }
+    if let Some(title) = title {
+        events.push(Event::FeedTitle(feed.id.clone(), title));
+    }
+    if let Some(new_url) = report.moved_to {
+        info!(feed = %feed.redacted_url(), to = %redact_url_str(new_url.as_str()), "feed moved permanently");
+        events.push(Event::FeedMoved(feed.id.clone(), new_url.to_string()));
+    }
+    events
+}
+
+// Sépare les entrées jamais vues et les entrées modifiées (datées du relevé), puis persiste le
+// lot dans le SeenStore.
+async fn classify_entries(
+    entries: Vec<FeedEntry>,
+    seen: &SeenStore,
+    fetched_at: DateTime<Utc>,
+) -> (Vec<FeedEntry>, Vec<FeedEntry>) {
+    let mut new_entries = Vec::new();
+    let mut updated_entries = Vec::new();
+    for mut e in entries {
+        match seen.check_and_mark(&e).await {
+            SeenStatus::New => new_entries.push(e),
+            SeenStatus::Updated => {
+                e.updated_at = Some(fetched_at);
+                updated_entries.push(e);
+            }
+            SeenStatus::Unchanged => {}
+        }
+    }
+    seen.flush().await;
+    (new_entries, updated_entries)
+}
+
+// ===
+//
+//
+// Contenu poussé par un hub WebSub pour `feed`: parsé et trié comme un relevé, sans
+// FetchCompleted (les statistiques de fetch restent celles du polling). Ne produit que
+// NewArticles et ArticlesUpdated.
+//
+//
+// ===
+pub(crate) async fn pushed_events(
+    feed: &FeedDescriptor,
+    bytes: bytes::Bytes,
+    content_type: Option<String>,
+    cfg: &PollConfig,
+    client: &Client,
+    seen: &SeenStore,
+) -> Result<Vec<Event>, PollError> {
+    let body = FeedBody {
+        status: 200,
+        content_type,
+        bytes,
+        moved_to: None,
+    };
+    let parsed = parse_feed(feed, &body, cfg)?;
+    let (mut new_entries, mut updated_entries) =
+        classify_entries(parsed.entries, seen, Utc::now()).await;
+    info!(
+        feed = %feed.redacted_url(),
+        new = new_entries.len(),
+        updated = updated_entries.len(),
+        "websub content received"
+    );
+    if feed.full_content {
+        fetch_full_contents(client, feed, cfg, &mut new_entries).await;
+        fetch_full_contents(client, feed, cfg, &mut updated_entries).await;
+    }
+    let mut events = Vec::new();
+    if !new_entries.is_empty() {
+        events.push(Event::NewArticles(feed.id.clone(), new_entries));
+    }
+    if !updated_entries.is_empty() {
+        events.push(Event::ArticlesUpdated(feed.id.clone(), updated_entries));
+    }
+    Ok(events)
+}
+
+// ===
+//
+//
+// Contenu complet: télécharge la page des nouveaux articles (concurrence bornée, délai et taille
+// plafonnés par page) et remplace content_html par le contenu extrait, via le sélecteur CSS du
+// flux s’il en a un, l’heuristique sinon. Un échec laisse l’entrée telle que publiée dans le
+// flux; un sélecteur invalide ou sans correspondance est signalé dans le journal.
+//
+//
+// ===
+async fn fetch_full_contents(
+    client: &Client,
+    feed: &FeedDescriptor,
+    cfg: &PollConfig,
+    entries: &mut [FeedEntry],
+) {
+    let selector = match feed.content_selector.as_deref().map(ContentSelector::parse) {
+        Some(Ok(selector)) => Some(selector),
+        Some(Err(err)) => {
+            warn!(feed = %feed.redacted_url(), error = %err, "content selector rejected; keeping feed summaries");
+            return;
+        }
+        None => None,
+    };
+    let selector = selector.as_ref();
+    let urls: Vec<String> = entries.iter().map(|entry| entry.url.clone()).collect();
+    let pages: Vec<Option<String>> = futures_util::stream::iter(urls)
+        .map(|url| async move {
+            let base = Url::parse(&url).ok()?;
+            match download_article(client, feed, cfg, &base).await {
+                Ok(html) => match selector {
+                    Some(selector) => {
+                        let content = extract_selected(&html, selector, &base);
+                        if content.is_none() {
+                            warn!(feed = %feed.redacted_url(), url = %redact_url_str(&url), selector = selector.as_str(), "content selector matched nothing; keeping feed summary");
+                        }
+                        content
+                    }
+                    None => extract_readable(&html, &base),
+                },
+                Err(err) => {
+                    debug!(feed = %feed.redacted_url(), url = %redact_url_str(&url), error = %err, "full content fetch failed");
+                    None
+                }
+            }
+        })
+        .buffered(FULL_CONTENT_CONCURRENCY)
+        .collect()
+        .await;
+    for (entry, content) in entries.iter_mut().zip(pages) {
+        if let Some(content) = content {
+            entry.content_truncated = content.len() > cfg.max_content_bytes;
+            entry.content_html = Some(truncate_html(content, cfg.max_content_bytes));
+        }
+    }
+}
+
+// ===
+//
+//
+// Télécharge une page d’article (download_capped_as): seul le User-Agent du flux est repris (ni
+// authentification ni en-têtes personnalisés, la page est souvent sur un autre hôte).
+//
+//
+// ===
+async fn download_article(
+    client: &Client,
+    feed: &FeedDescriptor,
+    cfg: &PollConfig,
+    url: &Url,
+) -> Result<String, PollError> {
+    let user_agent = feed
+        .request_headers()
+        .get(reqwest::header::USER_AGENT)
+        .cloned();
+    let bytes = download_capped_as(
+        client,
+        url,
+        cfg.max_article_bytes,
+        cfg.article_timeout,
+        user_agent.as_ref(),
+    )
+    .await?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
 }
 
 // ===
 //
 //
-// Récupère et parse un flux (RSS, fallback Atom) avec limites et politique HTTPS.
+// Récupère et parse un flux (RSS, fallback Atom); renvoie aussi le statut HTTP s’il est connu
+// et la nouvelle adresse du flux s’il a été déplacé (parsé avec succès à cette adresse).
 //
 //
 // ===
 async fn fetch_feed(
     client: &Client,
     feed: &FeedDescriptor,
-    timeout: Duration,
-) -> Result<Vec<FeedEntry>, PollError> {
-    let url = Url::parse(&feed.url)?;
-    #[cfg(not(test))]
-    if url.scheme() != "https" {
-        let host_ok = matches!(
-            url.host_str(),
-            Some("localhost") | Some("127.0.0.1") | Some("::1")
+    cfg: &PollConfig,
+    downloaded: &mut u64,
+) -> (Option<u16>, Result<Fetched, PollError>) {
+    #[cfg(feature = "imap-feeds")]
+    if crate::mailbox::is_mailbox_url(&feed.url) {
+        let result = crate::mailbox::fetch_mailbox(feed, cfg).await;
+        return (
+            None,
+            result.map(|entries| (ParsedFeed::mailbox(entries), None)),
         );
-        if !host_ok {
-            return Err(PollError::UnsupportedScheme);
-        }
+    }
+    match download_feed(client, feed, cfg, downloaded).await {
+        Ok(body) => (
+            Some(body.status),
+            parse_feed(feed, &body, cfg).map(|parsed| (parsed, body.moved_to)),
+        ),
+        Err(PollError::HttpStatus(status)) => (Some(status), Err(PollError::HttpStatus(status))),
+        Err(err @ PollError::RetryAfter { status, .. }) => (Some(status), Err(err)),
+        Err(PollError::Gone) => (Some(410), Err(PollError::Gone)),
+        Err(PollError::Network(e)) => (e.status().map(|s| s.as_u16()), Err(e.into())),
+        Err(e) => (None, Err(e)),
+    }
+}
+
+// Flux parsé et sa nouvelle adresse s’il a été déplacé.
+type Fetched = (ParsedFeed, Option<Url>);
+
+// Corps HTTP d’un flux téléchargé, avec le statut, le Content-Type annoncé et la nouvelle
+// adresse du flux s’il a été déplacé (redirection permanente).
+struct FeedBody {
+    status: u16,
+    content_type: Option<String>,
+    bytes: bytes::Bytes,
+    moved_to: Option<Url>,
+}
+
+// ===
+//
+//
+// Télécharge le corps d’un flux avec limites de taille et politique HTTP (PollConfig::allow_http,
+// sauf exception du flux). Les octets reçus s’ajoutent à `downloaded`, même si le corps est
+// ensuite refusé (trop grand).
+//
+//
+// ===
+async fn download_feed(
+    client: &Client,
+    feed: &FeedDescriptor,
+    cfg: &PollConfig,
+    downloaded: &mut u64,
+) -> Result<FeedBody, PollError> {
+    let url = Url::parse(&feed.url)?;
+    if !feed.allow_insecure && !cfg.allow_http.allows(&url) {
+        return Err(if url.scheme() == "http" {
+            PollError::HttpNotAllowed
+        } else {
+            PollError::UnsupportedScheme
+        });
     }
 
-    const MAX_FEED_BYTES: usize = 10 * 1024 * 1024;
-    let response = client.get(url).timeout(timeout).send().await?;
+    let headers = feed.request_headers();
+    let cookie = cfg.secrets.cookie_header(&feed.id);
+    let followed = send_following_redirects(url, |target, same_origin| {
+        let request = client.get(target.clone()).timeout(cfg.request_timeout);
+        // Hors de l’origine du flux, seul le User-Agent suit (ni secrets, cookie ou en-têtes).
+        if !same_origin {
+            return match headers.get(reqwest::header::USER_AGENT) {
+                Some(ua) => request.header(reqwest::header::USER_AGENT, ua),
+                None => request,
+            };
+        }
+        let request = request.headers(headers.clone());
+        let request = match &cookie {
+            Some(cookie) => request.header(reqwest::header::COOKIE, cookie.clone()),
+            None => request,
+        };
+        match &feed.auth {
+            Some(FeedAuth::Basic { username, password }) => {
+                request.basic_auth(username, password.reveal())
+            }
+            Some(FeedAuth::Bearer { token }) => match token.reveal() {
+                Some(token) => request.bearer_auth(token),
+                None => request,
+            },
+            None => request,
+        }
+    })
+    .await?;
+    let response = followed.response;
+    let max_bytes = cfg.max_feed_bytes;
+    let status = response.status();
+    if status == reqwest::StatusCode::GONE {
+        return Err(PollError::Gone);
+    }
+    if !status.is_success() {
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        return Err(match retry_after {
+            Some(retry_after) => PollError::RetryAfter {
+                status: status.as_u16(),
+                retry_after,
+            },
+            None => PollError::HttpStatus(status.as_u16()),
+        });
+    }
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    // Content-Length n’est fiable que sans Content-Encoding (reqwest l’omet après décompression);
+    // le flux d’octets ci-dessous est déjà décompressé, la limite porte donc sur la taille réelle.
     if let Some(len) = response.content_length() {
-        if len > MAX_FEED_BYTES as u64 {
+        if len > max_bytes as u64 {
             return Err(PollError::TooLarge(len));
         }
     }
@@ -151,55 +1167,397 @@ async fn fetch_feed(
     let mut stream = response.bytes_stream();
     while let Some(chunk) = stream.next().await {
         let chunk = chunk?;
-        if bytes_buf.len() + chunk.len() > MAX_FEED_BYTES {
+        *downloaded += chunk.len() as u64;
+        if bytes_buf.len() + chunk.len() > max_bytes {
             return Err(PollError::TooLarge((bytes_buf.len() + chunk.len()) as u64));
         }
         bytes_buf.extend_from_slice(&chunk);
     }
-    let bytes = bytes_buf.freeze();
+    Ok(FeedBody {
+        status: status.as_u16(),
+        content_type,
+        bytes: bytes_buf.freeze(),
+        moved_to: followed.moved_to,
+    })
+}
+
+// ===
+//
+//
+// Lit un en-tête Retry-After: nombre de secondes ou date HTTP (délai nul si déjà passée).
+//
+//
+// ===
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let delay = at.with_timezone(&Utc) - Utc::now();
+    Some(delay.to_std().unwrap_or(Duration::ZERO))
+}
+
+// ===
+//
+//
+// Indique si un échec mérite une nouvelle tentative: réseau, timeouts, 5xx et 429 oui;
+// les autres 4xx, 410 Gone, les réponses qui ne sont pas des flux et les flux illisibles
+// non (le même document échouerait de nouveau à l’analyse).
+//
+//
+// ===
+fn is_retryable(err: &PollError) -> bool {
+    match err {
+        PollError::Network(_) | PollError::RetryAfter { .. } => true,
+        PollError::HttpStatus(status) => *status >= 500 || *status == 429,
+        _ => false,
+    }
+}
+
+// Nature présumée d’un corps de réponse, d’après ses premiers octets et le Content-Type.
+#[derive(Debug, PartialEq, Eq)]
+enum BodyKind {
+    Xml,
+    Html,
+    Json,
+    Unknown,
+}
+
+// ===
+//
+//
+// Devine la nature du corps: le contenu prime (un vrai flux servi en text/html reste du XML),
+// le Content-Type ne sert qu’à départager les documents balisés sans racine de flux.
+//
+//
+// ===
+fn sniff_body(bytes: &[u8], content_type: Option<&str>) -> BodyKind {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(4096)]).to_ascii_lowercase();
+    let mut rest = head.trim_start_matches('\u{feff}').trim_start();
+    if rest.starts_with('{') || rest.starts_with('[') {
+        return BodyKind::Json;
+    }
+    let declared_html = content_type.is_some_and(|ct| ct.to_ascii_lowercase().contains("html"));
+    if !rest.starts_with('<') {
+        return if declared_html {
+            BodyKind::Html
+        } else {
+            BodyKind::Unknown
+        };
+    }
+
+    // Prologue XML, commentaires et doctype précèdent l’élément racine.
+    loop {
+        rest = rest.trim_start();
+        let end = if rest.starts_with("<?") {
+            rest.find("?>").map(|i| i + 2)
+        } else if rest.starts_with("<!--") {
+            rest.find("-->").map(|i| i + 3)
+        } else if let Some(doctype) = rest.strip_prefix("<!doctype") {
+            if doctype.trim_start().starts_with("html") {
+                return BodyKind::Html;
+            }
+            rest.find('>').map(|i| i + 1)
+        } else {
+            break;
+        };
+        match end {
+            Some(end) => rest = &rest[end..],
+            None => return BodyKind::Unknown,
+        }
+    }
+
+    let root: String = rest
+        .trim_start_matches('<')
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == ':')
+        .collect();
+    match root.as_str() {
+        "html" | "head" | "body" => BodyKind::Html,
+        "rss" | "feed" | "rdf:rdf" | "channel" => BodyKind::Xml,
+        _ if declared_html => BodyKind::Html,
+        _ => BodyKind::Xml,
+    }
+}
+
+// ===
+//
+//
+// Parse un corps de flux: RSS (2.0 et RDF/RSS 1.0) d’abord, puis Atom en repli (uniquement
+// pour du XML). Les articles sans date la gardent vide: ils sont triés selon
+// `FeedEntry::first_seen_at`.
+//
+//
+// ===
+fn parse_feed(
+    feed: &FeedDescriptor,
+    body: &FeedBody,
+    cfg: &PollConfig,
+) -> Result<ParsedFeed, PollError> {
+    parse_document(feed, body, cfg)
+}
+
+// Document de flux parsé: format, métadonnées du canal et articles.
+struct ParsedFeed {
+    format: FeedFormat,
+    title: String,
+    description: Option<String>,
+    site_link: Option<String>,
+    last_updated: Option<DateTime<Utc>>,
+    entries: Vec<FeedEntry>,
+    // Hub WebSub et URL canonique annoncés par le flux.
+    push: Option<WebSubLinks>,
+}
+
+impl ParsedFeed {
+    // Messages d’une boîte aux lettres (feature `imap-feeds`): ni métadonnées ni titre de canal;
+    // le format ne sert qu’aux aperçus, qui ne concernent pas les boîtes.
+    #[cfg(feature = "imap-feeds")]
+    fn mailbox(entries: Vec<FeedEntry>) -> Self {
+        Self {
+            format: FeedFormat::Rss,
+            title: String::new(),
+            description: None,
+            site_link: None,
+            last_updated: None,
+            entries,
+            push: None,
+        }
+    }
+
+    fn meta(&self) -> FeedMeta {
+        FeedMeta {
+            site_url: self.site_link.clone(),
+            description: self.description.clone(),
+            last_updated: self.last_updated,
+        }
+    }
+}
+
+// ===
+//
+//
+// Entrées d’un document, bornées avant d’atteindre le SeenStore: au plus
+// `max_articles_per_feed` (les plus récentes, dans l’ordre du document, les entrées sans date
+// après les autres), puis HTML nettoyé et tronqué à `max_content_bytes` par article.
+//
+//
+// ===
+pub(crate) fn bounded_entries(mut entries: Vec<FeedEntry>, cfg: &PollConfig) -> Vec<FeedEntry> {
+    let max = cfg.max_articles_per_feed.max(1);
+    if entries.len() > max {
+        let mut newest: Vec<usize> = (0..entries.len()).collect();
+        newest.sort_by_key(|&i| std::cmp::Reverse(entries[i].published_at));
+        let mut kept = vec![false; entries.len()];
+        for &i in &newest[..max] {
+            kept[i] = true;
+        }
+        debug!(
+            feed_id = %entries[0].feed_id,
+            received = entries.len(),
+            kept = max,
+            "feed entries capped"
+        );
+        let mut index = 0;
+        entries.retain(|_| {
+            index += 1;
+            kept[index - 1]
+        });
+    }
+    entries
+        .into_iter()
+        .map(|entry| entry.sanitized(cfg.max_content_bytes))
+        .collect()
+}
+
+fn parse_document(
+    feed: &FeedDescriptor,
+    body: &FeedBody,
+    cfg: &PollConfig,
+) -> Result<ParsedFeed, PollError> {
+    match sniff_body(&body.bytes, body.content_type.as_deref()) {
+        BodyKind::Xml => {}
+        kind => {
+            return Err(PollError::NotAFeed {
+                content_type: body.content_type.clone(),
+                looks_like_html: kind == BodyKind::Html,
+            })
+        }
+    }
+    let bytes = &body.bytes;
+    let non_empty = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
     let mut cursor_rss = std::io::Cursor::new(bytes.to_vec());
     match rss::Channel::read_from(&mut cursor_rss) {
-        Ok(channel) => {
-            let entries = channel
-                .items()
-                .iter()
-                .map(|item| {
-                    let mut entry = FeedEntry::from_rss_item(&feed.id, item);
-                    if entry.published_at.is_none() {
-                        entry.published_at = Some(Utc::now());
-                    }
-                    entry
-                })
-                .collect();
-            Ok(entries)
-        }
+        Ok(channel) => Ok(ParsedFeed {
+            format: FeedFormat::Rss,
+            title: channel.title().trim().to_string(),
+            description: non_empty(channel.description()),
+            site_link: non_empty(channel.link()),
+            last_updated: channel
+                .last_build_date()
+                .or(channel.pub_date())
+                .and_then(parse_feed_date)
+                .or_else(|| {
+                    // RDF/RSS 1.0: date du canal en dc:date.
+                    channel
+                        .dublin_core_ext()
+                        .and_then(|dc| dc.dates().first().and_then(|d| parse_feed_date(d)))
+                }),
+            entries: bounded_entries(
+                channel
+                    .items()
+                    .iter()
+                    .map(|item| FeedEntry::from_rss_item_in(&feed.id, item, channel.namespaces()))
+                    .collect(),
+                cfg,
+            ),
+            push: channel.atom_ext().and_then(|atom| {
+                WebSubLinks::from_links(atom.links().iter().map(|l| (l.rel(), l.href())))
+            }),
+        }),
         Err(rss_err) => {
             let mut cursor = std::io::Cursor::new(bytes.to_vec());
             match atom_syndication::Feed::read_from(&mut cursor) {
-                Ok(atom_feed) => {
-                    let entries = atom_feed
-                        .entries()
-                        .iter()
-                        .map(|e| {
-                            let mut entry = FeedEntry::from_atom_entry(&feed.id, e);
-                            if entry.published_at.is_none() {
-                                entry.published_at = Some(Utc::now());
-                            }
-                            entry
-                        })
-                        .collect();
-                    Ok(entries)
-                }
+                Ok(atom_feed) => Ok(ParsedFeed {
+                    format: FeedFormat::Atom,
+                    title: atom_feed.title().trim().to_string(),
+                    description: atom_feed.subtitle().and_then(|s| non_empty(&s.value)),
+                    // Lien vers le site (alternate HTML de préférence), jamais le flux lui-même.
+                    site_link: atom_alternate_link(atom_feed.links())
+                        .and_then(|l| non_empty(l.href())),
+                    last_updated: Some(atom_feed.updated().with_timezone(&Utc)),
+                    entries: bounded_entries(
+                        atom_feed
+                            .entries()
+                            .iter()
+                            .map(|e| FeedEntry::from_atom_entry(&feed.id, e))
+                            .collect(),
+                        cfg,
+                    ),
+                    push: WebSubLinks::from_links(
+                        atom_feed.links().iter().map(|l| (l.rel(), l.href())),
+                    ),
+                }),
                 Err(_e2) => Err(PollError::from(rss_err)),
             }
         }
     }
 }
 
+// Format de flux reconnu (RSS couvre aussi RDF/RSS 1.0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedFormat {
+    #[default]
+    Rss,
+    Atom,
+}
+
+// Nombre de titres récents rapportés par l’aperçu d’un flux.
+pub const PREVIEW_RECENT_TITLES: usize = 3;
+
+// ===
+//
+//
+// Aperçu d’un flux avant abonnement: métadonnées du canal, nombre d’articles et titres les
+// plus récents. `url` est l’adresse finale si le flux a été déplacé.
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedPreview {
+    pub url: String,
+    pub format: FeedFormat,
+    pub title: String,
+    pub description: Option<String>,
+    pub site_link: Option<String>,
+    pub item_count: usize,
+    pub recent_titles: Vec<String>,
+}
+
+// ===
+//
+//
+// Récupère un flux une seule fois (sans retry ni authentification) avec les mêmes règles que
+// le poller (HTTP autorisé, redirections, tailles maximales, détection des pages HTML), et en
+// dresse l’aperçu. Les articles sans date passent après les autres, dans l’ordre du document.
+// Une page HTML qui annonce un flux (autodécouverte) mène au premier flux annoncé; `url` est
+// alors l’adresse de ce flux.
+//
+//
+// ===
+pub async fn preview_feed(
+    client: &Client,
+    url: &str,
+    timeout: Duration,
+    allow_http: AllowHttp,
+) -> Result<FeedPreview, PollError> {
+    let mut feed = FeedDescriptor {
+        id: "preview".into(),
+        url: url.trim().to_string(),
+        ..Default::default()
+    };
+    let cfg = PollConfig {
+        request_timeout: timeout,
+        allow_http,
+        ..PollConfig::default()
+    };
+    let mut body = download_feed(client, &feed, &cfg, &mut 0).await?;
+    let parsed = match parse_document(&feed, &body, &cfg) {
+        Err(
+            err @ PollError::NotAFeed {
+                looks_like_html: true,
+                ..
+            },
+        ) => {
+            let page = match &body.moved_to {
+                Some(moved) => moved.clone(),
+                None => Url::parse(&feed.url)?,
+            };
+            let html = String::from_utf8_lossy(&body.bytes);
+            let Some(link) = find_feed_links(&html, &page).into_iter().next() else {
+                return Err(err);
+            };
+            debug!(page = %redact_url(&page), feed = %redact_url(&link), "feed autodiscovered");
+            feed.url = link.to_string();
+            body = download_feed(client, &feed, &cfg, &mut 0).await?;
+            parse_document(&feed, &body, &cfg)?
+        }
+        other => other?,
+    };
+    let mut entries = parsed.entries;
+    entries.sort_by_key(|e| (e.published_at.is_none(), std::cmp::Reverse(e.published_at)));
+    Ok(FeedPreview {
+        url: body.moved_to.map_or(feed.url, |moved| moved.to_string()),
+        format: parsed.format,
+        title: parsed.title,
+        description: parsed.description,
+        site_link: parsed.site_link,
+        item_count: entries.len(),
+        recent_titles: entries
+            .into_iter()
+            .take(PREVIEW_RECENT_TITLES)
+            .map(|e| e.title)
+            .collect(),
+    })
+}
+
+// Résultat de la récupération d’un flux après retries (statut de la dernière tentative).
+struct FetchReport {
+    result: Result<ParsedFeed, PollError>,
+    http_status: Option<u16>,
+    attempts: usize,
+    moved_to: Option<Url>,
+    bytes_downloaded: u64,
+}
+
 // ===
 //
 //
-// Wrapper avec retries exponentiels pour la récupération d’un flux.
+// Wrapper avec retries pour la récupération d’un flux: backoff exponentiel, ou délai
+// Retry-After annoncé par le serveur, plafonné par max_backoff_ms. Chaque tentative attend un
+// jeton de la limite par hôte.
 //
 //
 // ===
@@ -207,18 +1565,44 @@ async fn fetch_feed_with_retries(
     client: &Client,
     feed: &FeedDescriptor,
     cfg: &PollConfig,
-) -> Result<Vec<FeedEntry>, PollError> {
+) -> FetchReport {
     let mut attempt = 0usize;
+    let mut bytes_downloaded = 0;
     loop {
-        match fetch_feed(client, feed, cfg.request_timeout).await {
-            Ok(entries) => return Ok(entries),
+        rate_limit::acquire(&feed.url, cfg.per_host_rate_limit).await;
+        let (http_status, result) = fetch_feed(client, feed, cfg, &mut bytes_downloaded).await;
+        match result {
+            Ok((parsed, moved_to)) => {
+                return FetchReport {
+                    result: Ok(parsed),
+                    http_status,
+                    attempts: attempt + 1,
+                    moved_to,
+                    bytes_downloaded,
+                }
+            }
             Err(err) => {
                 attempt += 1;
-                if attempt > cfg.max_retries {
-                    return Err(err);
+                if attempt > cfg.max_retries || !is_retryable(&err) {
+                    return FetchReport {
+                        result: Err(err),
+                        http_status,
+                        attempts: attempt,
+                        moved_to: None,
+                        bytes_downloaded,
+                    };
+                }
+                let backoff = match &err {
+                    PollError::RetryAfter { retry_after, .. } => {
+                        u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX)
+                    }
+                    _ => cfg
+                        .retry_backoff_ms
+                        .saturating_mul(1u64 << (attempt - 1).min(32)),
                 }
-                let backoff = cfg.retry_backoff_ms * (1u64 << (attempt - 1));
-                warn!(feed = %feed.url, %attempt, backoff_ms = backoff, error = %err, "retrying after error");
+                .min(cfg.max_backoff_ms);
+                let error = cfg.secrets.redact(&feed.id, &err.to_string());
+                warn!(feed = %feed.redacted_url(), %attempt, backoff_ms = backoff, %error, "retrying after error");
                 tokio::time::sleep(Duration::from_millis(backoff)).await;
             }
         }
@@ -228,16 +1612,109 @@ async fn fetch_feed_with_retries(
 // ===
 //
 //
-// Evènements émis par le poller.
+// Evènements émis par le poller. FeedMoved porte la nouvelle URL d’un flux redirigé de façon
+// permanente (301/308); l’appelant décide de la reporter (FeedConfig::auto_update_moved_feeds).
+// FeedMetadata n’est émis que si le lien du site, la description ou la date du canal ont changé.
+// FeedTitle porte le titre du canal d’un flux ajouté sans titre
+// (FeedDescriptor::has_placeholder_title). ArticlesUpdated porte les articles déjà vus dont
+// l’éditeur a modifié le contenu (`updated_at` renseigné), à substituer à la copie en cache.
+// CycleCompleted clôt chaque cycle de polling local (pas les cycles de synchronisation serveur).
+// ConnectivityChanged annonce le passage hors ligne (false) ou le retour en ligne (true).
 //
 //
 // ===
 #[derive(Debug, Clone)]
 pub enum Event {
     NewArticles(String, Vec<FeedEntry>),
+    ArticlesUpdated(String, Vec<FeedEntry>),
+    FetchCompleted(String, FetchOutcome),
+    FeedDisabled(String),
+    FeedMoved(String, String),
+    FeedMetadata(String, FeedMeta),
+    FeedTitle(String, String),
+    CycleCompleted(CycleSummary),
+    ConnectivityChanged(bool),
 }
 
 impl PollConfig {
+    // ===
+    //
+    //
+    // Construit PollConfig depuis la section feeds d’AppConfig (UI et runtime alignés).
+    //
+    //
+    // ===
+    pub fn from_feed_config(feeds: &FeedConfig) -> Self {
+        Self {
+            interval: Duration::from_secs(feeds.update_interval_minutes.max(1) * 60),
+            request_timeout: Duration::from_secs(feeds.request_timeout_seconds.max(1)),
+            max_retries: feeds.retry_attempts.max(1) as usize,
+            max_content_bytes: feeds.max_content_bytes.max(1024),
+            max_feed_bytes: feeds.max_feed_bytes.max(64 * 1024),
+            max_articles_per_feed: feeds.max_articles_per_feed.max(1),
+            disable_after_failures: feeds.disable_after_failures,
+            jitter: DEFAULT_POLL_JITTER,
+            host_delay: DEFAULT_HOST_DELAY,
+            per_host_rate_limit: feeds
+                .host_requests_per_minute
+                .map(|n| (n, Duration::from_secs(60))),
+            quiet_hours: feeds.quiet_hours,
+            quiet_hours_catch_up: feeds.quiet_hours_catch_up,
+            adaptive_polling: feeds.adaptive_polling,
+            max_adaptive_interval: Duration::from_secs(feeds.max_adaptive_interval_minutes * 60),
+            ..PollConfig::default()
+        }
+    }
+
+    // ===
+    //
+    //
+    // Comme from_feed_config, avec la politique HTTP de la section network.
+    //
+    //
+    // ===
+    pub fn from_app_config(config: &AppConfig) -> Self {
+        Self {
+            allow_http: config.network.allow_http,
+            ..Self::from_feed_config(&config.feeds)
+        }
+    }
+
+    // ===
+    //
+    //
+    // Temps restant avant la fin des heures calmes si l’heure locale est dedans, sinon None
+    // (pas de fenêtre configurée, ou hors fenêtre).
+    //
+    //
+    // ===
+    pub fn quiet_hours_remaining(&self) -> Option<Duration> {
+        quiet_hours_remaining(self.quiet_hours?, &Local::now())
+    }
+
+    // ===
+    //
+    //
+    // Intervalle entre deux relevés planifiés de `feed` à `now`: l’intervalle du flux
+    // (FeedDescriptor::poll_interval) ou à défaut celui de base, étiré par adaptive_interval si
+    // `adaptive_polling` est actif. Les relevés suivent le ticker: un intervalle de flux plus
+    // court que `interval` revient à un relevé par cycle.
+    //
+    //
+    // ===
+    pub fn effective_interval(&self, feed: &FeedDescriptor, now: DateTime<Utc>) -> Duration {
+        let base = feed.poll_interval.unwrap_or(self.interval);
+        if !self.adaptive_polling {
+            return base;
+        }
+        adaptive_interval(
+            base,
+            feed.last_new_article_at,
+            now,
+            self.max_adaptive_interval,
+        )
+    }
+
     // ===
     //
     //
@@ -260,6 +1737,30 @@ impl PollConfig {
                     max_retries: Option<usize>,
                     #[serde(default)]
                     retry_backoff_ms: Option<u64>,
+                    #[serde(default)]
+                    max_backoff_ms: Option<u64>,
+                    #[serde(default)]
+                    max_content_bytes: Option<usize>,
+                    #[serde(default)]
+                    max_feed_bytes: Option<usize>,
+                    #[serde(default)]
+                    disable_after_failures: Option<u32>,
+                    #[serde(default)]
+                    jitter: Option<u64>,
+                    #[serde(default)]
+                    host_delay: Option<u64>,
+                    // [requêtes, période en ms]
+                    #[serde(default)]
+                    per_host_rate_limit: Option<(u32, u64)>,
+                    // ["23:00:00", "07:00:00"]
+                    #[serde(default)]
+                    quiet_hours: Option<(NaiveTime, NaiveTime)>,
+                    #[serde(default)]
+                    quiet_hours_catch_up: Option<bool>,
+                    #[serde(default)]
+                    adaptive_polling: Option<bool>,
+                    #[serde(default)]
+                    max_adaptive_interval: Option<u64>,
                 }
                 if let Ok(raw) = serde_json::from_slice::<RawCfg>(&bytes) {
                     PollConfig {
@@ -273,6 +1774,35 @@ impl PollConfig {
                             .unwrap_or(defaults.request_timeout),
                         max_retries: raw.max_retries.unwrap_or(defaults.max_retries),
                         retry_backoff_ms: raw.retry_backoff_ms.unwrap_or(defaults.retry_backoff_ms),
+                        max_backoff_ms: raw.max_backoff_ms.unwrap_or(defaults.max_backoff_ms),
+                        max_content_bytes: raw
+                            .max_content_bytes
+                            .unwrap_or(defaults.max_content_bytes),
+                        max_feed_bytes: raw.max_feed_bytes.unwrap_or(defaults.max_feed_bytes),
+                        disable_after_failures: raw
+                            .disable_after_failures
+                            .unwrap_or(defaults.disable_after_failures),
+                        jitter: raw
+                            .jitter
+                            .map(Duration::from_millis)
+                            .unwrap_or(defaults.jitter),
+                        host_delay: raw
+                            .host_delay
+                            .map(Duration::from_millis)
+                            .unwrap_or(defaults.host_delay),
+                        per_host_rate_limit: raw
+                            .per_host_rate_limit
+                            .map(|(n, ms)| (n, Duration::from_millis(ms))),
+                        quiet_hours: raw.quiet_hours,
+                        quiet_hours_catch_up: raw
+                            .quiet_hours_catch_up
+                            .unwrap_or(defaults.quiet_hours_catch_up),
+                        adaptive_polling: raw.adaptive_polling.unwrap_or(defaults.adaptive_polling),
+                        max_adaptive_interval: raw
+                            .max_adaptive_interval
+                            .map(Duration::from_millis)
+                            .unwrap_or(defaults.max_adaptive_interval),
+                        ..defaults
                     }
                 } else {
                     defaults
@@ -286,7 +1816,97 @@ impl PollConfig {
 // ===
 //
 //
-// Exécute un tour de polling synchrone (tests, rafraîchissement immédiat).
+// Compte le résultat d’une récupération dans la liste des flux (échecs consécutifs, remis à
+// zéro par un succès) et ajoute FeedDisabled aux évènements si le seuil est atteint.
+//
+//
+// ===
+async fn count_failures(
+    store: &SharedFeedList,
+    feed: &FeedDescriptor,
+    config: &PollConfig,
+    events: &mut Vec<Event>,
+) {
+    let success = events
+        .iter()
+        .any(|evt| matches!(evt, Event::FetchCompleted(_, outcome) if outcome.success));
+    if record_feed_result(store, &feed.id, success, config.disable_after_failures).await {
+        warn!(feed = %feed.redacted_url(), failures = config.disable_after_failures, "feed disabled after repeated failures");
+        events.push(Event::FeedDisabled(feed.id.clone()));
+    }
+}
+
+// ===
+//
+//
+// Résultat d’un flux dans un tour poll_stream, avec l’avancement du tour (`completed` flux
+// terminés sur `total`) et son bilan cumulé: celui du dernier résultat est le bilan du tour.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct FeedPollResult {
+    pub feed_id: String,
+    pub events: Vec<Event>,
+    pub completed: usize,
+    pub total: usize,
+    pub summary: CycleSummary,
+}
+
+// ===
+//
+//
+// Tour de polling immédiat (« tout rafraîchir », CLI) rendu flux par flux dans l’ordre où
+// ils se terminent, pour afficher l’avancement et les articles sans attendre le plus lent.
+// Les flux désactivés sont ignorés, ceux en sourdine récupérés quand même (demande
+// explicite); au plus POLL_ONCE_CONCURRENCY récupérations en parallèle. Comme un cycle du
+// poller, les échecs consécutifs sont comptés dans `store` (FeedDisabled au seuil).
+//
+//
+// ===
+pub fn poll_stream<'a>(
+    store: &'a SharedFeedList,
+    feeds: &'a [FeedDescriptor],
+    cfg: &'a PollConfig,
+    client: &'a Client,
+    seen: &'a SeenStore,
+) -> impl Stream<Item = FeedPollResult> + 'a {
+    let active: Vec<&FeedDescriptor> = feeds.iter().filter(|f| !f.disabled).collect();
+    let total = active.len();
+    let started = Instant::now();
+    let mut summary = CycleSummary::default();
+    futures_util::stream::iter(active)
+        .map(move |feed| async move {
+            let mut events = poll_feed(feed, cfg, client, seen).await;
+            count_failures(store, feed, cfg, &mut events).await;
+            (feed.id.clone(), events)
+        })
+        .buffer_unordered(POLL_ONCE_CONCURRENCY)
+        .enumerate()
+        .map(move |(index, (feed_id, events))| {
+            summarize(&events, &mut summary);
+            summary.duration = started.elapsed();
+            if index + 1 == total {
+                if let Some(metrics) = &cfg.metrics {
+                    metrics.record_cycle(summary.duration);
+                }
+            }
+            FeedPollResult {
+                feed_id,
+                events,
+                completed: index + 1,
+                total,
+                summary: summary.clone(),
+            }
+        })
+}
+
+// ===
+//
+//
+// Collecte un tour poll_stream (tests, aperçus): évènements dans l’ordre de `feeds` et bilan du
+// tour (qui n’est pas ajouté aux évènements). Les échecs sont comptés sur une copie de `feeds`:
+// FeedDisabled est émis au seuil, mais rien n’est conservé (voir poll_once_in).
 //
 //
 // ===
@@ -295,26 +1915,28 @@ pub async fn poll_once(
     cfg: &PollConfig,
     client: &Client,
     seen: &SeenStore,
-) -> Vec<Event> {
-    let mut out = Vec::new();
-    for feed in feeds {
-        match fetch_feed_with_retries(client, feed, cfg).await {
-            Ok(entries) if !entries.is_empty() => {
-                let mut new_entries = Vec::new();
-                for e in entries {
-                    if seen.is_new_and_mark(&e).await {
-                        new_entries.push(e);
-                    }
-                }
-                if !new_entries.is_empty() {
-                    out.push(Event::NewArticles(feed.id.clone(), new_entries));
-                }
-            }
-            Ok(_) => {}
-            Err(err) => {
-                warn!(feed = %feed.url, error = %err, "failed to fetch feed");
-            }
-        }
-    }
-    out
+) -> (Vec<Event>, CycleSummary) {
+    poll_once_in(&shared_feed_list(feeds.to_vec()), feeds, cfg, client, seen).await
+}
+
+// Comme poll_once, échecs consécutifs comptés dans `store` (CLI: liste des flux de DataApi).
+pub async fn poll_once_in(
+    store: &SharedFeedList,
+    feeds: &[FeedDescriptor],
+    cfg: &PollConfig,
+    client: &Client,
+    seen: &SeenStore,
+) -> (Vec<Event>, CycleSummary) {
+    let mut results: Vec<FeedPollResult> =
+        poll_stream(store, feeds, cfg, client, seen).collect().await;
+    let summary = results
+        .last()
+        .map(|result| result.summary.clone())
+        .unwrap_or_default();
+    results.sort_by_key(|result| feeds.iter().position(|f| f.id == result.feed_id));
+    let events = results
+        .into_iter()
+        .flat_map(|result| result.events)
+        .collect();
+    (events, summary)
 }
@@ -0,0 +1,229 @@
+// ===
+//
+//
+// Aperçus texte et temps de lecture des articles pour la liste. La conversion html2text est
+// coûteuse: le cache ne la calcule qu’une fois par article, et la refait seulement si son
+// contenu change (empreinte du HTML ou du résumé), au lieu de la relancer à chaque frame.
+//
+//
+// ===
+
+use std::collections::hash_map::{DefaultHasher, Entry};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use crate::feed::FeedEntry;
+
+// Longueur maximale d’un aperçu (caractères, points de suspension compris).
+pub const PREVIEW_MAX_CHARS: usize = 300;
+
+// Largeur de rendu html2text (colonnes) avant la troncature.
+const PREVIEW_WRAP_WIDTH: usize = 100;
+
+// Vitesse de lecture par défaut (mots par minute), surchargée par UiConfig.reading_wpm.
+pub const DEFAULT_READING_WPM: u32 = 220;
+
+// ===
+//
+//
+// Longueur d’un article: nombre de mots et minutes de lecture (arrondies au-dessus, 0 pour
+// un article sans texte).
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReadingStats {
+    pub words: usize,
+    pub minutes: u32,
+}
+
+impl ReadingStats {
+    pub fn from_words(words: usize, wpm: u32) -> Self {
+        let wpm = wpm.max(1) as usize;
+        Self {
+            words,
+            minutes: words.div_ceil(wpm) as u32,
+        }
+    }
+}
+
+// Idéogrammes chinois et japonais, kana: écrits sans espaces entre les mots.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{2FA1F}'
+    )
+}
+
+// ===
+//
+//
+// Nombre de mots d’un texte: suites de caractères séparées par des espaces (contenant au moins
+// une lettre ou un chiffre); les caractères CJK comptent pour un demi-mot chacun.
+//
+//
+// ===
+pub fn count_words(text: &str) -> usize {
+    let mut words = 0;
+    let mut cjk_chars: usize = 0;
+    let mut in_word = false;
+    for c in text.chars() {
+        if is_cjk(c) {
+            cjk_chars += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else if c.is_whitespace() {
+            in_word = false;
+        }
+    }
+    words + cjk_chars.div_ceil(2)
+}
+
+impl FeedEntry {
+    // Temps de lecture à `wpm` mots par minute, sans cache (voir PreviewCache::reading_stats).
+    pub fn reading_stats(&self, wpm: u32) -> ReadingStats {
+        let words = article_text(self).map_or(0, |text| count_words(&text));
+        ReadingStats::from_words(words, wpm)
+    }
+}
+
+// Texte brut de l’article: contenu complet s’il existe, sinon résumé.
+fn article_text(entry: &FeedEntry) -> Option<String> {
+    let html = entry.content_html.as_ref().or(entry.summary.as_ref())?;
+    Some(html2text::from_read(html.as_bytes(), PREVIEW_WRAP_WIDTH))
+}
+
+// ===
+//
+//
+// Aperçu texte d’un article: contenu complet s’il existe, sinon résumé, tronqué à
+// PREVIEW_MAX_CHARS. Chaîne vide si l’article n’a ni l’un ni l’autre.
+//
+//
+// ===
+pub fn article_preview(entry: &FeedEntry) -> String {
+    article_text(entry)
+        .map(truncate_preview)
+        .unwrap_or_default()
+}
+
+fn truncate_preview(text: String) -> String {
+    if text.chars().count() <= PREVIEW_MAX_CHARS {
+        return text;
+    }
+    let mut truncated: String = text
+        .chars()
+        .take(PREVIEW_MAX_CHARS.saturating_sub(3))
+        .collect();
+    truncated.push_str("...");
+    truncated
+}
+
+// ===
+//
+//
+// Cache des aperçus et du nombre de mots, indexé par identité d’article (FeedEntry::identity).
+//
+//
+// ===
+#[derive(Debug, Default)]
+pub struct PreviewCache {
+    previews: HashMap<String, CachedPreview>,
+    computed: usize,
+}
+
+#[derive(Debug)]
+struct CachedPreview {
+    fingerprint: u64,
+    text: String,
+    words: usize,
+}
+
+impl CachedPreview {
+    fn compute(entry: &FeedEntry, fingerprint: u64) -> Self {
+        let text = article_text(entry).unwrap_or_default();
+        Self {
+            fingerprint,
+            words: count_words(&text),
+            text: truncate_preview(text),
+        }
+    }
+}
+
+impl PreviewCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ===
+    //
+    //
+    // Aperçu de l’article, calculé au premier appel ou si son contenu a changé depuis.
+    //
+    //
+    // ===
+    pub fn get(&mut self, entry: &FeedEntry) -> &str {
+        &self.cached(entry).text
+    }
+
+    // Temps de lecture de l’article à `wpm` mots par minute (même calcul que l’aperçu).
+    pub fn reading_stats(&mut self, entry: &FeedEntry, wpm: u32) -> ReadingStats {
+        ReadingStats::from_words(self.cached(entry).words, wpm)
+    }
+
+    fn cached(&mut self, entry: &FeedEntry) -> &CachedPreview {
+        let fingerprint = content_fingerprint(entry);
+        match self.previews.entry(entry.identity()) {
+            Entry::Occupied(slot) if slot.get().fingerprint == fingerprint => slot.into_mut(),
+            Entry::Occupied(slot) => {
+                self.computed += 1;
+                let cached = slot.into_mut();
+                *cached = CachedPreview::compute(entry, fingerprint);
+                cached
+            }
+            Entry::Vacant(slot) => {
+                self.computed += 1;
+                slot.insert(CachedPreview::compute(entry, fingerprint))
+            }
+        }
+    }
+
+    // Nombre de conversions html2text effectuées depuis la création (ou le dernier clear).
+    pub fn computed(&self) -> usize {
+        self.computed
+    }
+
+    pub fn len(&self) -> usize {
+        self.previews.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.previews.is_empty()
+    }
+
+    // Oublie les aperçus des articles absents de `entries` (articles supprimés ou purgés).
+    pub fn retain(&mut self, entries: &[FeedEntry]) {
+        let alive: HashSet<String> = entries.iter().map(FeedEntry::identity).collect();
+        self.previews.retain(|identity, _| alive.contains(identity));
+    }
+
+    pub fn clear(&mut self) {
+        self.previews.clear();
+        self.computed = 0;
+    }
+}
+
+fn content_fingerprint(entry: &FeedEntry) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    entry.content_html.hash(&mut hasher);
+    entry.summary.hash(&mut hasher);
+    hasher.finish()
+}
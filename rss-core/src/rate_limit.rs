@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use tokio::time::Instant;
+use url::Url;
+
+// ===
+//
+//
+// Limite de requêtes par hôte (PollConfig::per_host_rate_limit): un seau de jetons par hôte,
+// partagé dans le processus par le poller et poll_once. `(n, période)` autorise une
+// rafale de n requêtes puis une toutes les période / n, quel que soit le nombre de flux.
+//
+//
+// ===
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    // Recharge au débit `capacity / period`, sans dépasser `capacity`.
+    fn refill(&mut self, capacity: u32, period: Duration, now: Instant) {
+        let rate = f64::from(capacity) / period.as_secs_f64();
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(f64::from(capacity));
+        self.refilled_at = now;
+    }
+}
+
+fn buckets() -> &'static Mutex<HashMap<String, Bucket>> {
+    static BUCKETS: OnceLock<Mutex<HashMap<String, Bucket>>> = OnceLock::new();
+    BUCKETS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Clé du seau: hôte en minuscules, avec le port s’il est explicite.
+fn host_key(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let host = url.host_str()?.to_ascii_lowercase();
+    Some(match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host,
+    })
+}
+
+// Jeton rendu au seau si l’attente est abandonnée (future annulée avant la fin du délai).
+struct Reservation {
+    key: String,
+    done: bool,
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+        if let Some(bucket) = buckets().lock().unwrap().get_mut(&self.key) {
+            bucket.tokens += 1.0;
+        }
+    }
+}
+
+// ===
+//
+//
+// Attend un jeton pour l’hôte de `url`. Le jeton est réservé tout de suite (les appels
+// concurrents se mettent en file) puis l’appel dort jusqu’à sa date; annuler la future rend le
+// jeton. Sans limite, URL sans hôte ou limite nulle: retour immédiat.
+//
+//
+// ===
+pub(crate) async fn acquire(url: &str, limit: Option<(u32, Duration)>) {
+    let Some((capacity, period)) = limit.filter(|(n, p)| *n > 0 && !p.is_zero()) else {
+        return;
+    };
+    let Some(key) = host_key(url) else {
+        return;
+    };
+    let wait = {
+        let now = Instant::now();
+        let mut map = buckets().lock().unwrap();
+        let bucket = map.entry(key.clone()).or_insert(Bucket {
+            tokens: f64::from(capacity),
+            refilled_at: now,
+        });
+        bucket.refill(capacity, period, now);
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            return;
+        }
+        let rate = f64::from(capacity) / period.as_secs_f64();
+        Duration::from_secs_f64(-bucket.tokens / rate)
+    };
+    let mut reservation = Reservation { key, done: false };
+    tokio::time::sleep(wait).await;
+    reservation.done = true;
+}
+
+// ===
+//
+//
+// Délai avant le prochain jeton libre pour l’hôte de `url`, si une requête devrait attendre
+// maintenant (vue Santé des flux: « limité »). None sans limite ou si un jeton est disponible.
+//
+//
+// ===
+pub fn throttled_for(url: &str, limit: Option<(u32, Duration)>) -> Option<Duration> {
+    let (capacity, period) = limit.filter(|(n, p)| *n > 0 && !p.is_zero())?;
+    let key = host_key(url)?;
+    let mut map = buckets().lock().unwrap();
+    let bucket = map.get_mut(&key)?;
+    bucket.refill(capacity, period, Instant::now());
+    if bucket.tokens >= 1.0 {
+        return None;
+    }
+    let rate = f64::from(capacity) / period.as_secs_f64();
+    Some(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+}
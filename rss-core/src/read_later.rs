@@ -0,0 +1,202 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use tracing::debug;
+use url::Url;
+
+use crate::config::WallabagConfig;
+use crate::error::ReadLaterError;
+use crate::feed::Secret;
+
+// Marge avant expiration du jeton: on le renouvelle un peu avant que le serveur ne le refuse.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+// ===
+//
+//
+// Service « lire plus tard »: enregistre un article (URL + titre) pour une lecture ultérieure.
+//
+//
+// ===
+pub trait ReadLaterService {
+    fn save(
+        &self,
+        url: &str,
+        title: &str,
+    ) -> impl Future<Output = Result<(), ReadLaterError>> + Send;
+}
+
+// ===
+//
+//
+// Client Wallabag: OAuth2 (grant `password` avec client_id/client_secret), jeton mis en cache
+// et renouvelé via `refresh_token` à l’expiration ou sur 401, sans intervention de l’appelant.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct WallabagClient {
+    client: Client,
+    base: Url,
+    config: WallabagConfig,
+    token: Arc<Mutex<Option<WallabagToken>>>,
+}
+
+#[derive(Debug, Clone)]
+struct WallabagToken {
+    access: Secret,
+    refresh: Option<Secret>,
+    expires_at: Instant,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    expires_in: u64,
+}
+
+impl WallabagClient {
+    // ===
+    //
+    //
+    // Crée un client pour l’instance décrite par la configuration (aucun appel réseau).
+    //
+    //
+    // ===
+    pub fn new(client: Client, config: WallabagConfig) -> Result<Self, ReadLaterError> {
+        let mut base = Url::parse(config.url.trim())?;
+        if !base.path().ends_with('/') {
+            let path = format!("{}/", base.path());
+            base.set_path(&path);
+        }
+        Ok(Self {
+            client,
+            base,
+            config,
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    // ===
+    //
+    //
+    // Jeton d’accès valide: cache, sinon refresh_token, sinon nouvelle authentification.
+    //
+    //
+    // ===
+    async fn access_token(&self) -> Result<String, ReadLaterError> {
+        let mut slot = self.token.lock().await;
+        if let Some(token) = slot.as_ref() {
+            if Instant::now() < token.expires_at {
+                return Ok(token.access.reveal().unwrap_or_default());
+            }
+        }
+        let refreshed = match slot.as_ref().and_then(|t| t.refresh.clone()) {
+            Some(refresh) => match self.request_token(Some(&refresh)).await {
+                Ok(token) => Some(token),
+                Err(e) => {
+                    debug!(error = %e, "wallabag refresh failed, logging in again");
+                    None
+                }
+            },
+            None => None,
+        };
+        let token = match refreshed {
+            Some(token) => token,
+            None => self.request_token(None).await?,
+        };
+        let access = token.access.reveal().unwrap_or_default();
+        *slot = Some(token);
+        Ok(access)
+    }
+
+    // Expire le jeton en cache (refusé par le serveur) en gardant le refresh_token.
+    async fn expire_token(&self) {
+        if let Some(token) = self.token.lock().await.as_mut() {
+            token.expires_at = Instant::now();
+        }
+    }
+
+    async fn request_token(
+        &self,
+        refresh: Option<&Secret>,
+    ) -> Result<WallabagToken, ReadLaterError> {
+        let client_secret = self.config.client_secret.reveal().unwrap_or_default();
+        let mut form = vec![
+            ("client_id", self.config.client_id.clone()),
+            ("client_secret", client_secret),
+        ];
+        match refresh {
+            Some(refresh) => {
+                form.push(("grant_type", "refresh_token".to_string()));
+                form.push(("refresh_token", refresh.reveal().unwrap_or_default()));
+            }
+            None => {
+                form.push(("grant_type", "password".to_string()));
+                form.push(("username", self.config.username.clone()));
+                form.push((
+                    "password",
+                    self.config.password.reveal().unwrap_or_default(),
+                ));
+            }
+        }
+        let response = self
+            .client
+            .post(self.base.join("oauth/v2/token")?)
+            .form(&form)
+            .send()
+            .await?;
+        let status = response.status();
+        if status == StatusCode::BAD_REQUEST || status == StatusCode::UNAUTHORIZED {
+            return Err(ReadLaterError::Auth(status.as_u16()));
+        }
+        if !status.is_success() {
+            return Err(ReadLaterError::HttpStatus(status.as_u16()));
+        }
+        let body: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| ReadLaterError::Response(e.without_url().to_string()))?;
+        let lifetime = Duration::from_secs(body.expires_in).saturating_sub(TOKEN_EXPIRY_MARGIN);
+        Ok(WallabagToken {
+            access: Secret::Plaintext(body.access_token),
+            refresh: body.refresh_token.map(Secret::Plaintext),
+            expires_at: Instant::now() + lifetime,
+        })
+    }
+
+    async fn post_entry(&self, url: &str, title: &str) -> Result<StatusCode, ReadLaterError> {
+        let token = self.access_token().await?;
+        let response = self
+            .client
+            .post(self.base.join("api/entries.json")?)
+            .bearer_auth(token)
+            .form(&[("url", url), ("title", title)])
+            .send()
+            .await?;
+        Ok(response.status())
+    }
+}
+
+impl ReadLaterService for WallabagClient {
+    async fn save(&self, url: &str, title: &str) -> Result<(), ReadLaterError> {
+        let mut status = self.post_entry(url, title).await?;
+        if status == StatusCode::UNAUTHORIZED {
+            // Jeton révoqué ou expiré plus tôt que prévu: un renouvellement puis un seul réessai.
+            self.expire_token().await;
+            status = self.post_entry(url, title).await?;
+        }
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(ReadLaterError::HttpStatus(status.as_u16()))
+        }
+    }
+}
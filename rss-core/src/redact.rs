@@ -0,0 +1,78 @@
+use url::Url;
+
+// Paramètres de requête traités comme secrets (comparaison insensible à la casse).
+const SECRET_QUERY_PARAMS: &[&str] = &["token", "key", "auth", "access_token", "api_key", "apikey"];
+
+// ===
+//
+//
+// URL affichable dans les logs et les erreurs persistées: retire les identifiants (user:pass@)
+// et les paramètres de requête porteurs de jetons. Une URL sans secret ressort inchangée.
+//
+//
+// ===
+pub fn redact_url(url: &Url) -> String {
+    let has_secret_param = url.query_pairs().any(|(key, _)| is_secret_param(&key));
+    if url.username().is_empty() && url.password().is_none() && !has_secret_param {
+        return url.to_string();
+    }
+
+    let mut url = url.clone();
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    if has_secret_param {
+        let kept: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| !is_secret_param(key))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        if kept.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(kept);
+        }
+    }
+    url.to_string()
+}
+
+// ===
+//
+//
+// Variante pour une chaîne brute; sans URL hiérarchique exploitable (ex. `user:pass@hôte:port`,
+// lu comme un schéma `user:`), tout ce qui précède le dernier `@` est retiré.
+//
+//
+// ===
+pub fn redact_url_str(raw: &str) -> String {
+    match Url::parse(raw.trim()) {
+        Ok(url) if !url.cannot_be_a_base() => redact_url(&url),
+        _ => raw.rsplit('@').next().unwrap_or_default().to_string(),
+    }
+}
+
+fn is_secret_param(key: &str) -> bool {
+    SECRET_QUERY_PARAMS
+        .iter()
+        .any(|secret| key.eq_ignore_ascii_case(secret))
+}
+
+// ===
+//
+//
+// Masque dans un texte libre (message d’erreur, log) chaque occurrence des valeurs secrètes
+// données, par exemple les cookies d’un flux; les plus longues d’abord, pour qu’une valeur
+// contenue dans une autre ne laisse pas de fragment en clair.
+//
+//
+// ===
+pub fn redact_values(text: &str, secrets: &[String]) -> String {
+    let mut secrets: Vec<&str> = secrets
+        .iter()
+        .map(|s| s.as_str())
+        .filter(|s| !s.is_empty())
+        .collect();
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+    secrets
+        .into_iter()
+        .fold(text.to_string(), |text, secret| text.replace(secret, "***"))
+}
@@ -0,0 +1,234 @@
+// ===
+//
+//
+// Règles de filtrage par mots-clés appliquées aux nouveaux articles, avant leur
+// enregistrement: masquer, marquer comme lu ou mettre en évidence les articles dont le titre,
+// le résumé ou l’auteur contient un motif (sous-chaîne sans tenir compte de la casse, ou
+// expression régulière). Les règles sont enregistrées dans `rules.json` par DataApi.
+//
+//
+// ===
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::RuleError;
+use crate::feed::FeedEntry;
+use crate::sanitize::find_tag_end;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleField {
+    #[default]
+    Title,
+    // Texte du résumé, balises HTML retirées.
+    Summary,
+    Author,
+}
+
+impl RuleField {
+    pub const ALL: [RuleField; 3] = [RuleField::Title, RuleField::Summary, RuleField::Author];
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleAction {
+    // L’article n’est ni enregistré ni affiché (il reste « vu »: il ne reviendra pas).
+    #[default]
+    Hide,
+    MarkRead,
+    // Titre affiché avec la couleur d’accent (`FeedEntry::highlighted`).
+    Highlight,
+}
+
+impl RuleAction {
+    pub const ALL: [RuleAction; 3] = [
+        RuleAction::Hide,
+        RuleAction::MarkRead,
+        RuleAction::Highlight,
+    ];
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct FilterRule {
+    // Flux concerné (None = tous les flux).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub field: RuleField,
+    pub pattern: String,
+    // Motif lu comme une expression régulière plutôt que comme une sous-chaîne.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub regex: bool,
+    #[serde(default)]
+    pub action: RuleAction,
+}
+
+impl FilterRule {
+    // ===
+    //
+    //
+    // Prépare la règle: motif vide refusé, expression régulière compilée. À appeler à la
+    // saisie pour signaler l’erreur tout de suite.
+    //
+    //
+    // ===
+    pub fn compile(&self) -> Result<CompiledRule, RuleError> {
+        let pattern = self.pattern.trim();
+        if pattern.is_empty() {
+            return Err(RuleError::EmptyPattern);
+        }
+        let matcher = if self.regex {
+            let regex = Regex::new(pattern).map_err(|e| RuleError::InvalidRegex(e.to_string()))?;
+            Matcher::Regex(regex)
+        } else {
+            Matcher::Substring(pattern.to_lowercase())
+        };
+        Ok(CompiledRule {
+            rule: self.clone(),
+            matcher,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    // Sous-chaîne en minuscules, comparée au texte en minuscules.
+    Substring(String),
+    Regex(Regex),
+}
+
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    rule: FilterRule,
+    matcher: Matcher,
+}
+
+impl CompiledRule {
+    pub fn rule(&self) -> &FilterRule {
+        &self.rule
+    }
+
+    // Vrai si l’article est dans la portée de la règle et que le champ visé contient le motif.
+    pub fn matches(&self, entry: &FeedEntry) -> bool {
+        if self
+            .rule
+            .scope
+            .as_ref()
+            .is_some_and(|feed_id| *feed_id != entry.feed_id)
+        {
+            return false;
+        }
+        let text = match self.rule.field {
+            RuleField::Title => entry.title.clone(),
+            RuleField::Summary => plain_text(entry.summary.as_deref().unwrap_or_default()),
+            RuleField::Author => entry.author.clone().unwrap_or_default(),
+        };
+        match &self.matcher {
+            Matcher::Substring(needle) => text.to_lowercase().contains(needle.as_str()),
+            Matcher::Regex(regex) => regex.is_match(&text),
+        }
+    }
+}
+
+// Actions retenues pour un article (plusieurs règles peuvent s’appliquer).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleOutcome {
+    pub hide: bool,
+    pub mark_read: bool,
+    pub highlight: bool,
+}
+
+// ===
+//
+//
+// Ensemble de règles compilées, appliqué à chaque lot de nouveaux articles.
+//
+//
+// ===
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<CompiledRule>,
+}
+
+impl RuleSet {
+    // Compile toutes les règles; la première invalide est renvoyée avec sa position.
+    pub fn compile(rules: &[FilterRule]) -> Result<Self, RuleError> {
+        let rules = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                rule.compile().map_err(|e| RuleError::InvalidRule {
+                    index,
+                    source: Box::new(e),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    // Règles d’origine, dans l’ordre.
+    pub fn rules(&self) -> Vec<FilterRule> {
+        self.rules.iter().map(|rule| rule.rule.clone()).collect()
+    }
+
+    pub fn evaluate(&self, entry: &FeedEntry) -> RuleOutcome {
+        let mut outcome = RuleOutcome::default();
+        for rule in self.rules.iter().filter(|rule| rule.matches(entry)) {
+            match rule.rule.action {
+                RuleAction::Hide => outcome.hide = true,
+                RuleAction::MarkRead => outcome.mark_read = true,
+                RuleAction::Highlight => outcome.highlight = true,
+            }
+        }
+        outcome
+    }
+
+    // ===
+    //
+    //
+    // Applique les règles à un lot: les articles masqués sont retirés, `highlighted` est
+    // renseigné sur les autres. Renvoie les articles gardés et ceux à marquer comme lus.
+    //
+    //
+    // ===
+    pub fn apply(&self, entries: Vec<FeedEntry>) -> (Vec<FeedEntry>, Vec<FeedEntry>) {
+        let mut kept = Vec::with_capacity(entries.len());
+        let mut read = Vec::new();
+        for mut entry in entries {
+            let outcome = self.evaluate(&entry);
+            if outcome.hide {
+                continue;
+            }
+            entry.highlighted = outcome.highlight;
+            if outcome.mark_read {
+                read.push(entry.clone());
+            }
+            kept.push(entry);
+        }
+        (kept, read)
+    }
+}
+
+// Texte d’un fragment HTML sans ses balises (entités laissées telles quelles).
+fn plain_text(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        out.push_str(&rest[..lt]);
+        rest = &rest[lt..];
+        match find_tag_end(rest) {
+            Some(end) => {
+                out.push(' ');
+                rest = &rest[end + 1..];
+            }
+            None => break,
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,154 @@
+use std::sync::OnceLock;
+
+// ===
+//
+//
+// Nettoyage du HTML des articles avant stockage et affichage, par liste blanche: balises de
+// texte, de structure et de médias, attributs sans effet actif, URL aux schémas connus. Scripts,
+// styles, contenus embarqués, formulaires, SVG/MathML et commentaires disparaissent.
+//
+//
+// ===
+
+// Taille au-delà de laquelle une URI data: est retirée (images inline en base64).
+pub const MAX_DATA_URI_BYTES: usize = 8 * 1024;
+
+// Mention ajoutée à la fin d’un contenu tronqué.
+pub const TRUNCATION_NOTE: &str = "<p><em>[…] Contenu tronqué.</em></p>";
+
+// Éléments retirés avec leur contenu (tout élément hors liste blanche perd sa balise mais garde
+// son texte).
+const STRIPPED_ELEMENTS: &[&str] = &[
+    "iframe", "object", "form", "svg", "math", "noscript", "noframes", "frameset", "template",
+    "textarea", "select", "title",
+];
+
+// Balises ajoutées à la liste blanche d’ammonia (médias et sections courants dans les flux).
+const EXTRA_TAGS: &[&str] = &["section", "video", "audio", "source"];
+
+// ===
+//
+//
+// Nettoyeur à liste blanche (ammonia, sur l’analyseur html5ever): seules les balises et
+// attributs connus restent, les URL passent par une liste de schémas (javascript: et autres
+// sont retirés, même encodés en entités ou coupés par des blancs). Les URI data: ne sont
+// admises que pour les images, sous `MAX_DATA_URI_BYTES`.
+//
+//
+// ===
+fn cleaner() -> &'static ammonia::Builder<'static> {
+    static CLEANER: OnceLock<ammonia::Builder<'static>> = OnceLock::new();
+    CLEANER.get_or_init(|| {
+        let mut builder = ammonia::Builder::default();
+        builder
+            .add_tags(EXTRA_TAGS)
+            .add_clean_content_tags(STRIPPED_ELEMENTS)
+            .add_generic_attributes(["class"])
+            .add_tag_attributes("video", ["src", "poster", "controls", "width", "height"])
+            .add_tag_attributes("audio", ["src", "controls"])
+            .add_tag_attributes("source", ["src", "type"])
+            .add_url_schemes(["data"])
+            .link_rel(None)
+            .attribute_filter(|element, attribute, value| {
+                let is_data = value
+                    .trim_start()
+                    .get(..5)
+                    .is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"));
+                if !is_data {
+                    return Some(value.into());
+                }
+                let image = value.trim_start()[5..]
+                    .get(..6)
+                    .is_some_and(|mime| mime.eq_ignore_ascii_case("image/"));
+                (element == "img"
+                    && attribute == "src"
+                    && image
+                    && value.len() <= MAX_DATA_URI_BYTES)
+                    .then(|| value.into())
+            });
+        builder
+    })
+}
+
+// ===
+//
+//
+// Retourne une version nettoyée du fragment HTML.
+//
+//
+// ===
+pub fn sanitize_html(html: &str) -> String {
+    cleaner().clean(html).to_string()
+}
+
+// ===
+//
+//
+// Tronque un fragment HTML à `max_bytes` (frontière de caractère, hors balise), mention
+// comprise. Une limite plus petite que la mention donne le début du texte sans elle.
+//
+//
+// ===
+pub fn truncate_html(html: String, max_bytes: usize) -> String {
+    if html.len() <= max_bytes {
+        return html;
+    }
+    let note = if max_bytes >= TRUNCATION_NOTE.len() {
+        TRUNCATION_NOTE
+    } else {
+        ""
+    };
+    let mut cut = max_bytes - note.len();
+    while !html.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    let head = &html[..cut];
+    // Ne pas couper au milieu d’une balise.
+    let head = match (head.rfind('<'), head.rfind('>')) {
+        (Some(lt), Some(gt)) if lt > gt => &head[..lt],
+        (Some(lt), None) => &head[..lt],
+        _ => head,
+    };
+    let mut out = String::with_capacity(head.len() + note.len());
+    out.push_str(head);
+    out.push_str(note);
+    out
+}
+
+// Position du '>' fermant la balise ouverte en tête de `s`, en ignorant ceux entre guillemets.
+pub fn find_tag_end(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices().skip(1) {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+// Nom d’une balise (sans « / » initial), en minuscules.
+pub fn tag_name(tag: &str) -> String {
+    tag.trim_start_matches('/')
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+// ===
+//
+//
+// Échappe un texte pour l’insérer dans du HTML (contenu ou valeur d’attribut).
+//
+//
+// ===
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
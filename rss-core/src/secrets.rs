@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+
+use crate::error::PollError;
+use crate::feed::Secret;
+use crate::redact::redact_values;
+
+// ===
+//
+//
+// Secrets d’un flux gardés hors de feeds.json (secrets.json, lisible par le seul propriétaire
+// sous Unix), rattachés au flux par son identifiant. `cookie` est envoyé tel quel dans l’en-tête
+// Cookie des requêtes du flux (flux derrière une page de connexion), jamais hors de son origine.
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedSecret {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie: Option<Secret>,
+}
+
+// Valeurs de cookie plus courtes ignorées par le masquage (trop communes pour être des secrets).
+const MIN_REDACTED_COOKIE_VALUE: usize = 4;
+
+impl FeedSecret {
+    // Secret réduit à un cookie (vide ou blanc = aucun).
+    pub fn with_cookie(cookie: &str) -> Self {
+        let cookie = cookie.trim();
+        Self {
+            cookie: (!cookie.is_empty()).then(|| Secret::Plaintext(cookie.to_string())),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cookie.is_none()
+    }
+
+    // Vérifie que le cookie peut former un en-tête HTTP avant l’enregistrement.
+    pub fn validate(&self) -> Result<(), PollError> {
+        match self.cookie_header() {
+            Some(Err(e)) => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    // En-tête Cookie marqué sensible (masqué dans le Debug de reqwest/http).
+    fn cookie_header(&self) -> Option<Result<HeaderValue, PollError>> {
+        let cookie = self.cookie.as_ref()?.reveal()?;
+        Some(
+            HeaderValue::from_str(cookie.trim())
+                .map(|mut value| {
+                    value.set_sensitive(true);
+                    value
+                })
+                .map_err(|_| PollError::InvalidHeader("Cookie".to_string())),
+        )
+    }
+
+    // ===
+    //
+    //
+    // Valeurs à masquer dans un message: le cookie entier et la valeur de chaque paire
+    // `nom=valeur` (un serveur peut n’en renvoyer qu’une).
+    //
+    //
+    // ===
+    fn sensitive_values(&self) -> Vec<String> {
+        let Some(cookie) = self.cookie.as_ref().and_then(Secret::reveal) else {
+            return Vec::new();
+        };
+        let mut values: Vec<String> = cookie
+            .split(';')
+            .filter_map(|pair| pair.split_once('=').map(|(_, value)| value.trim()))
+            .filter(|value| value.chars().count() >= MIN_REDACTED_COOKIE_VALUE)
+            .map(str::to_string)
+            .collect();
+        values.push(cookie.trim().to_string());
+        values
+    }
+}
+
+// ===
+//
+//
+// Secrets de tous les flux (clones liés): tenus par DataApi, lus par le poller via
+// `PollConfig::secrets`. Vide par défaut.
+//
+//
+// ===
+#[derive(Debug, Clone, Default)]
+pub struct FeedSecrets {
+    inner: Arc<RwLock<HashMap<String, FeedSecret>>>,
+}
+
+impl FeedSecrets {
+    pub(crate) fn from_map(secrets: HashMap<String, FeedSecret>) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(secrets)),
+        }
+    }
+
+    pub fn get(&self, feed_id: &str) -> Option<FeedSecret> {
+        self.inner.read().unwrap().get(feed_id).cloned()
+    }
+
+    // Remplace le secret d’un flux (un secret vide le retire); true si quelque chose a changé.
+    pub(crate) fn set(&self, feed_id: &str, secret: FeedSecret) -> bool {
+        let mut inner = self.inner.write().unwrap();
+        if secret.is_empty() {
+            return inner.remove(feed_id).is_some();
+        }
+        inner.insert(feed_id.to_string(), secret.clone()) != Some(secret)
+    }
+
+    // Retire les secrets des flux donnés; true si au moins un existait.
+    pub(crate) fn remove(&self, feed_ids: &[String]) -> bool {
+        let mut inner = self.inner.write().unwrap();
+        let before = inner.len();
+        inner.retain(|feed_id, _| !feed_ids.contains(feed_id));
+        inner.len() != before
+    }
+
+    // Copie ordonnée par identifiant, pour un secrets.json stable.
+    pub(crate) fn snapshot(&self) -> BTreeMap<String, FeedSecret> {
+        self.inner
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(feed_id, secret)| (feed_id.clone(), secret.clone()))
+            .collect()
+    }
+
+    // En-tête Cookie d’un flux, s’il en a un valide.
+    pub(crate) fn cookie_header(&self, feed_id: &str) -> Option<HeaderValue> {
+        self.get(feed_id)?.cookie_header()?.ok()
+    }
+
+    // Message (erreur, log) d’un flux débarrassé des valeurs de ses secrets.
+    pub fn redact(&self, feed_id: &str, message: &str) -> String {
+        match self.get(feed_id) {
+            Some(secret) => redact_values(message, &secret.sensitive_values()),
+            None => message.to_string(),
+        }
+    }
+}
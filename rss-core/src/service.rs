@@ -0,0 +1,621 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use chrono::Utc;
+use futures_util::StreamExt;
+use reqwest::Client;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::config::{AppConfig, NetworkConfig, WebSubConfig};
+use crate::connectivity::{Connectivity, ConnectivityCheck};
+use crate::data::DataApi;
+use crate::error::{AddFeedError, DataError, WebSubError};
+use crate::feed::{list_feeds, shared_feed_list, FeedDescriptor, FeedEntry, SharedFeedList};
+use crate::filter::ArticleFilter;
+use crate::http::build_http_client;
+use crate::metrics::{PollerMetrics, SharedMetrics};
+use crate::poller::{
+    poll_stream, report_connectivity, spawn_event_poller, Event, PollConfig, PollerHandle,
+    EVENT_CHANNEL_CAPACITY,
+};
+use crate::storage::SeenStore;
+use crate::sync::SyncBackend;
+use crate::websub::WebSub;
+
+/// Everything needed to run a feed reader on top of rss-core: the feed list, the data store
+/// (articles, read state, stats and the "seen" store), the HTTP client and a background poller.
+///
+/// Events from the poller and from [`FeedService::refresh_all`] /
+/// [`FeedService::refresh_feed`] are persisted first, then broadcast to every receiver
+/// returned by [`FeedService::subscribe_events`]. Constructors must be called from within a
+/// Tokio runtime (the poller is spawned on it).
+///
+/// ```no_run
+/// use rss_core::{ArticleFilter, Event, FeedDescriptor, FeedService};
+///
+/// # async fn run() {
+/// let service = FeedService::open("/home/me/.config/my-reader").await;
+/// let mut events = service.subscribe_events();
+///
+/// let feed = FeedDescriptor::new("rust", "Rust Blog", "https://blog.rust-lang.org/feed.xml");
+/// service.add_feed(feed).await.expect("invalid or duplicate feed");
+/// service.refresh_feed("rust").await;
+///
+/// while let Ok(event) = events.try_recv() {
+///     if let Event::NewArticles(feed_id, entries) = event {
+///         println!("{}: {} new articles", feed_id, entries.len());
+///     }
+/// }
+/// for entry in service.articles(&ArticleFilter::default()).await {
+///     println!("{}", entry.title);
+/// }
+/// service.shutdown().await;
+/// # }
+/// ```
+pub struct FeedService {
+    feeds: SharedFeedList,
+    data: Arc<DataApi>,
+    client: RwLock<Client>,
+    config: RwLock<PollConfig>,
+    auto_update_moved: Arc<AtomicBool>,
+    events: broadcast::Sender<Event>,
+    poller: Mutex<Option<PollerHandle>>,
+    pump: Mutex<Option<JoinHandle<()>>>,
+    // Entrée de la tâche de persistance, partagée avec WebSub (contenus poussés); rendue à
+    // l’arrêt pour que la tâche se termine.
+    updates: Mutex<Option<broadcast::Sender<Event>>>,
+    websub: Mutex<Option<WebSub>>,
+    // Métriques du poller et des rafraîchissements, partagées par toutes les PollConfig.
+    metrics: SharedMetrics,
+    // État en ligne du poller et des rafraîchissements, partagé de même.
+    connectivity: Connectivity,
+    // Avancement de refresh_all en cours: (flux terminés, flux à relever).
+    refresh_progress: Mutex<Option<(usize, usize)>>,
+    network_error: Option<String>,
+    websub_error: Option<String>,
+}
+
+impl FeedService {
+    // ===
+    //
+    //
+    // Ouvre (ou crée) un dossier de données: `config.json` s’il existe (réglages par défaut
+    // sinon), `feeds.json`, `seen_store.json`, etc.
+    //
+    //
+    // ===
+    pub async fn open(config_dir: impl AsRef<Path>) -> Self {
+        let config_dir = config_dir.as_ref();
+        let config = match tokio::fs::read(config_dir.join("config.json")).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                warn!(error = %e, "unreadable config.json, using defaults");
+                AppConfig::default()
+            }),
+            Err(_) => AppConfig::default(),
+        };
+        Self::open_with(config_dir, &config).await
+    }
+
+    // ===
+    //
+    //
+    // Ouvre un dossier de données avec une configuration déjà chargée (limites des magasins,
+    // réglages de polling et réseau). Un réseau mal configuré (proxy invalide) retombe sur le
+    // client par défaut; l’erreur reste disponible via network_error().
+    //
+    //
+    // ===
+    pub async fn open_with(config_dir: impl AsRef<Path>, config: &AppConfig) -> Self {
+        let config_dir = config_dir.as_ref();
+        let feeds = shared_feed_list(Vec::new());
+        let data = DataApi::load_from_dir(feeds.clone(), config_dir).await;
+        data.set_max_seen_per_feed(config.feeds.max_seen_per_feed);
+        data.set_max_articles_per_feed(config.feeds.max_articles_per_feed);
+        data.set_max_title_chars(config.feeds.max_title_chars);
+        // Compactage léger: données des flux supprimés et articles en double.
+        match data.compact_light().await {
+            Ok(report) if report.removed_articles + report.removed_read_marks > 0 => debug!(
+                removed_articles = report.removed_articles,
+                removed_read_marks = report.removed_read_marks,
+                "data compacted at startup"
+            ),
+            Ok(_) => {}
+            Err(e) => warn!(error = %e, "startup compaction failed"),
+        }
+        let (client, network_error) = match build_http_client(&config.network) {
+            Ok(client) => (client, None),
+            Err(e) => {
+                warn!(error = %e, "invalid network settings, using the default HTTP client");
+                (default_client(), Some(e.to_string()))
+            }
+        };
+        let mut service = Self::start(feeds, data, client, PollConfig::from_app_config(config));
+        service
+            .auto_update_moved
+            .store(config.feeds.auto_update_moved_feeds, Ordering::Relaxed);
+        service.network_error = network_error;
+        if config.websub.enabled {
+            if let Err(e) = service.start_websub(&config.websub).await {
+                warn!(error = %e, "websub disabled: callback server could not start");
+                service.websub_error = Some(e.to_string());
+            }
+        }
+        service
+    }
+
+    // ===
+    //
+    //
+    // Service sans aucun fichier (flux, articles et vus en mémoire), réglages par défaut.
+    //
+    //
+    // ===
+    pub fn in_memory() -> Self {
+        let feeds = shared_feed_list(Vec::new());
+        let data = DataApi::in_memory(feeds.clone());
+        Self::start(feeds, data, default_client(), PollConfig::default())
+    }
+
+    fn start(feeds: SharedFeedList, data: DataApi, client: Client, mut config: PollConfig) -> Self {
+        let data = Arc::new(data);
+        let metrics = config.metrics.clone().unwrap_or_else(PollerMetrics::shared);
+        config.metrics = Some(metrics.clone());
+        config.secrets = data.feed_secrets().clone();
+        let connectivity = config.connectivity.clone();
+        let auto_update_moved = Arc::new(AtomicBool::new(true));
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let (update_tx, mut updates) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let poller = spawn_event_poller(
+            feeds.clone(),
+            config.clone(),
+            client.clone(),
+            update_tx.clone(),
+            data.as_ref(),
+        );
+        // Évènements du poller: persistés puis diffusés; la tâche s’arrête avec le poller.
+        let pump = {
+            let data = data.clone();
+            let events = events.clone();
+            let auto_update_moved = auto_update_moved.clone();
+            tokio::spawn(async move {
+                loop {
+                    let event = match updates.recv().await {
+                        Ok(event) => event,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!(skipped, "poller events dropped before being persisted");
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    };
+                    let auto_update_moved = auto_update_moved.load(Ordering::Relaxed);
+                    let event = apply_event(&data, event, auto_update_moved).await;
+                    let _ = events.send(event);
+                }
+            })
+        };
+        Self {
+            feeds,
+            data,
+            client: RwLock::new(client),
+            config: RwLock::new(config),
+            auto_update_moved,
+            events,
+            poller: Mutex::new(Some(poller)),
+            pump: Mutex::new(Some(pump)),
+            updates: Mutex::new(Some(update_tx)),
+            websub: Mutex::new(None),
+            metrics,
+            connectivity,
+            refresh_progress: Mutex::new(None),
+            network_error: None,
+            websub_error: None,
+        }
+    }
+
+    pub fn feeds(&self) -> &SharedFeedList {
+        &self.feeds
+    }
+
+    pub fn data_api(&self) -> &Arc<DataApi> {
+        &self.data
+    }
+
+    pub fn seen_store(&self) -> &SeenStore {
+        self.data.seen_store()
+    }
+
+    pub fn client(&self) -> Client {
+        self.client.read().unwrap().clone()
+    }
+
+    pub fn poll_config(&self) -> PollConfig {
+        self.config.read().unwrap().clone()
+    }
+
+    // Erreur des réglages réseau à l’ouverture (client par défaut utilisé à la place).
+    pub fn network_error(&self) -> Option<&str> {
+        self.network_error.as_deref()
+    }
+
+    // Serveur WebSub qui n’a pas pu démarrer à l’ouverture (polling seul).
+    pub fn websub_error(&self) -> Option<&str> {
+        self.websub_error.as_deref()
+    }
+
+    pub fn websub(&self) -> Option<WebSub> {
+        self.websub.lock().unwrap().clone()
+    }
+
+    // Réseau joignable? Faux après un tour sans aucune réponse (Event::ConnectivityChanged).
+    pub fn is_online(&self) -> bool {
+        self.connectivity.is_online()
+    }
+
+    // Compteurs et jauges du poller (relevés, échecs, nouveaux articles, durée des cycles).
+    pub fn metrics(&self) -> &SharedMetrics {
+        &self.metrics
+    }
+
+    // ===
+    //
+    //
+    // Démarre le serveur de rappel WebSub (remplace celui qui tournait) et le confie au poller:
+    // les flux qui annoncent un hub y sont abonnés à leur prochain relevé. Renvoie l’adresse
+    // d’écoute effective.
+    //
+    //
+    // ===
+    pub async fn start_websub(&self, config: &WebSubConfig) -> Result<SocketAddr, WebSubError> {
+        let updates = self.updates.lock().unwrap().clone();
+        let Some(updates) = updates else {
+            return Err(WebSubError::ShutDown);
+        };
+        let websub = WebSub::start(
+            config,
+            self.feeds.clone(),
+            self.seen_store().clone(),
+            updates,
+            self.client(),
+            self.poll_config(),
+        )
+        .await?;
+        let addr = websub.local_addr();
+        if let Some(previous) = self.websub.lock().unwrap().replace(websub.clone()) {
+            previous.stop();
+        }
+        let mut poll_config = self.poll_config();
+        poll_config.push = Some(websub);
+        self.set_poll_config(poll_config);
+        Ok(addr)
+    }
+
+    // ===
+    //
+    //
+    // Nouveaux réglages de polling, pour le poller comme pour les rafraîchissements manuels.
+    // Sans `push`, le serveur WebSub en cours reste utilisé; sans `metrics`, ceux du service.
+    // L’état de connectivité et les secrets des flux restent toujours ceux du service.
+    //
+    //
+    // ===
+    pub fn set_poll_config(&self, mut config: PollConfig) {
+        if config.push.is_none() {
+            config.push = self.websub();
+        }
+        if config.metrics.is_none() {
+            config.metrics = Some(self.metrics.clone());
+        }
+        config.connectivity = self.connectivity.clone();
+        config.secrets = self.data.feed_secrets().clone();
+        *self.config.write().unwrap() = config.clone();
+        self.with_poller(|poller| poller.update_config(config));
+    }
+
+    // ===
+    //
+    //
+    // Remplace le client HTTP (réglages réseau modifiés).
+    //
+    //
+    // ===
+    pub fn set_client(&self, client: Client) {
+        *self.client.write().unwrap() = client.clone();
+        self.with_poller(|poller| poller.update_client(client));
+    }
+
+    // Nouvelle URL d’un flux déplacé (301/308) enregistrée ou non (FeedConfig).
+    pub fn set_auto_update_moved_feeds(&self, enabled: bool) {
+        self.auto_update_moved.store(enabled, Ordering::Relaxed);
+    }
+
+    // Synchronisation serveur (Fever, Google Reader) à la place du polling local (None: local).
+    pub fn set_sync(&self, sync: Option<SyncBackend>) {
+        self.with_poller(|poller| poller.set_sync(sync));
+    }
+
+    pub fn pause(&self) {
+        self.with_poller(PollerHandle::pause);
+    }
+
+    pub fn resume(&self) {
+        self.with_poller(PollerHandle::resume);
+    }
+
+    // Cycle immédiat du poller en arrière-plan (évènements diffusés aux abonnés).
+    pub fn poll_now(&self) {
+        self.with_poller(PollerHandle::poll_now);
+    }
+
+    fn with_poller(&self, f: impl FnOnce(&PollerHandle)) {
+        match self.poller.lock().unwrap().as_ref() {
+            Some(poller) => f(poller),
+            None => debug!("feed service is shut down; poller command ignored"),
+        }
+    }
+
+    // ===
+    //
+    //
+    // Ajoute un flux (refusé si l’URL est invalide ou déjà suivie); il est récupéré au
+    // prochain cycle ou par refresh_feed.
+    //
+    //
+    // ===
+    pub async fn add_feed(&self, feed: FeedDescriptor) -> Result<(), AddFeedError> {
+        self.data.try_add_feed(feed).await
+    }
+
+    // Retire un flux (lectures, stats, vus); ses articles ne sont plus listés par articles()
+    // et quittent le cache au prochain DataApi::cleanup.
+    pub async fn remove_feed(&self, feed_id: &str) -> Result<(), DataError> {
+        self.data.remove_feed(feed_id).await
+    }
+
+    // ===
+    //
+    //
+    // Récupère tout de suite les flux actifs; les évènements sont persistés et diffusés aux
+    // abonnés dès que chaque flux est terminé, puis renvoyés, le dernier étant CycleCompleted
+    // (bilan du tour). L’avancement est lisible pendant ce temps via refresh_progress().
+    //
+    //
+    // ===
+    pub async fn refresh_all(&self) -> Vec<Event> {
+        let feeds = list_feeds(&self.feeds).await;
+        let active = feeds.iter().filter(|f| !f.disabled).count();
+        *self.refresh_progress.lock().unwrap() = Some((0, active));
+        let events = self.refresh(&feeds, true).await;
+        *self.refresh_progress.lock().unwrap() = None;
+        events
+    }
+
+    // Avancement du refresh_all en cours: (flux terminés, flux à relever); None hors
+    // rafraîchissement.
+    pub fn refresh_progress(&self) -> Option<(usize, usize)> {
+        *self.refresh_progress.lock().unwrap()
+    }
+
+    // ===
+    //
+    //
+    // Récupère tout de suite un flux (None s’il n’est pas suivi), comme refresh_all.
+    //
+    //
+    // ===
+    pub async fn refresh_feed(&self, feed_id: &str) -> Option<Vec<Event>> {
+        let feed = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .find(|f| f.id == feed_id)?;
+        Some(self.refresh(&[feed], false).await)
+    }
+
+    async fn refresh(&self, feeds: &[FeedDescriptor], track_progress: bool) -> Vec<Event> {
+        let config = self.poll_config();
+        let client = self.client();
+        let auto_update_moved = self.auto_update_moved.load(Ordering::Relaxed);
+        let mut events = Vec::new();
+        let mut summary = Default::default();
+        let mut check = ConnectivityCheck::new(feeds.iter().filter(|f| !f.disabled), &config);
+        let mut polled = std::pin::pin!(poll_stream(
+            &self.feeds,
+            feeds,
+            &config,
+            &client,
+            self.seen_store()
+        ));
+        while let Some(result) = polled.next().await {
+            let feed = feeds.iter().find(|f| f.id == result.feed_id);
+            for event in result.events {
+                if let (Some(feed), Event::FetchCompleted(_, outcome)) = (feed, &event) {
+                    check.record(feed, outcome);
+                }
+                let event = apply_event(&self.data, event, auto_update_moved).await;
+                let _ = self.events.send(event.clone());
+                events.push(event);
+            }
+            if track_progress {
+                *self.refresh_progress.lock().unwrap() = Some((result.completed, result.total));
+            }
+            summary = result.summary;
+        }
+        self.metrics
+            .set_feeds_configured(self.feeds.read().await.len());
+        // Un flux seul en échec (ajout, adresse erronée) ne fait pas passer hors ligne.
+        if let Some(online) = check.verdict().filter(|online| *online || track_progress) {
+            events.extend(report_connectivity(
+                &self.connectivity,
+                online,
+                &self.events,
+            ));
+        }
+        let summary = apply_event(
+            &self.data,
+            Event::CycleCompleted(summary),
+            auto_update_moved,
+        )
+        .await;
+        let _ = self.events.send(summary.clone());
+        events.push(summary);
+        events
+    }
+
+    // ===
+    //
+    //
+    // Articles des flux suivis qui passent `filter`, du plus récent au plus ancien.
+    //
+    //
+    // ===
+    pub async fn articles(&self, filter: &ArticleFilter) -> Vec<FeedEntry> {
+        let now = chrono::Local::now();
+        let followed: HashSet<String> = list_feeds(&self.feeds)
+            .await
+            .into_iter()
+            .map(|f| f.id)
+            .collect();
+        let mut articles: Vec<FeedEntry> = self
+            .data
+            .list_all_articles()
+            .await
+            .into_iter()
+            .filter(|entry| followed.contains(&entry.feed_id) && filter.matches(entry, &now))
+            .collect();
+        articles.sort_by_key(|e| std::cmp::Reverse(e.sort_date()));
+        articles
+    }
+
+    // ===
+    //
+    //
+    // Nouvel abonné aux évènements (nouveaux articles, relevés, flux désactivés ou déplacés);
+    // un abonné trop lent reçoit RecvError::Lagged et doit relire les articles.
+    //
+    //
+    // ===
+    pub fn subscribe_events(&self) -> broadcast::Receiver<Event> {
+        self.events.subscribe()
+    }
+
+    // ===
+    //
+    //
+    // Arrête le poller, traite ses derniers évènements puis réécrit toutes les données.
+    // Les appels suivants sont sans effet (Ok).
+    //
+    //
+    // ===
+    pub async fn shutdown(&self) -> Result<(), DataError> {
+        let poller = self.poller.lock().unwrap().take();
+        let Some(poller) = poller else {
+            return Ok(());
+        };
+        if let Err(e) = poller.stop().await {
+            warn!(error = %e, "poller did not stop cleanly");
+        }
+        if let Some(websub) = self.websub.lock().unwrap().take() {
+            websub.stop();
+        }
+        self.updates.lock().unwrap().take();
+        let pump = self.pump.lock().unwrap().take();
+        if let Some(pump) = pump {
+            let _ = pump.await;
+        }
+        self.data.flush_all().await
+    }
+}
+
+// Client par défaut (sans proxy ni en-têtes particuliers).
+fn default_client() -> Client {
+    build_http_client(&NetworkConfig::default()).expect("failed to build default HTTP client")
+}
+
+// ===
+//
+//
+// Persiste un évènement dans DataApi (articles, santé du flux, URL déplacée, métadonnées,
+// titre du canal) et le renvoie tel qu’il doit être diffusé. Les règles de filtrage passent
+// d’abord sur les nouveautés (masquées, marquées lues ou mises en évidence); celles d’un flux
+// en sourdine, reçues par un rafraîchissement manuel, sont marquées lues d’office. Un article
+// modifié remplace sa copie en cache, état lu/favori inchangé; une règle « Masquer » écarte
+// la nouvelle version (l’ancienne reste).
+// La désactivation d’un flux est déjà enregistrée dans la liste des flux par le poller.
+//
+//
+// ===
+async fn apply_event(data: &DataApi, mut event: Event, auto_update_moved: bool) -> Event {
+    let result = match &mut event {
+        Event::NewArticles(feed_id, entries) => {
+            let (kept, read) = data.apply_rules(std::mem::take(entries)).await;
+            *entries = kept;
+            store_new_articles(data, feed_id, entries, read).await
+        }
+        Event::ArticlesUpdated(feed_id, entries) => {
+            let (kept, _) = data.apply_rules(std::mem::take(entries)).await;
+            *entries = kept;
+            if entries.is_empty() {
+                Ok(())
+            } else {
+                data.upsert_articles(feed_id, entries.clone()).await
+            }
+        }
+        Event::FetchCompleted(feed_id, outcome) => {
+            data.record_fetch(feed_id, outcome.clone()).await;
+            Ok(())
+        }
+        // Statistiques du cycle écrites en une fois.
+        Event::CycleCompleted(_) => data.flush_pending().await,
+        Event::FeedDisabled(_) | Event::ConnectivityChanged(_) => Ok(()),
+        Event::FeedMoved(feed_id, url) if auto_update_moved => {
+            data.update_feed_url(feed_id, url).await.map(|updated| {
+                if updated {
+                    debug!(feed_id = %feed_id, "moved feed URL updated");
+                }
+            })
+        }
+        Event::FeedMoved(..) => Ok(()),
+        Event::FeedMetadata(feed_id, meta) => data
+            .update_feed_metadata(feed_id, meta.clone())
+            .await
+            .map(drop),
+        Event::FeedTitle(feed_id, title) => data.update_feed_title(feed_id, title).await.map(drop),
+    };
+    // Déjà loggé par DataApi, qui réécrira le fichier à sa prochaine écriture.
+    if let Err(e) = result {
+        debug!(error = %e, "event not persisted yet");
+    }
+    event
+}
+
+// Enregistre les nouveautés gardées par les règles puis marque comme lues celles désignées
+// (toutes si le flux est en sourdine).
+async fn store_new_articles(
+    data: &DataApi,
+    feed_id: &str,
+    entries: &[FeedEntry],
+    mut read: Vec<FeedEntry>,
+) -> Result<(), DataError> {
+    let now = Utc::now();
+    let muted = data
+        .list_feeds()
+        .await
+        .iter()
+        .any(|f| f.id == feed_id && f.is_muted_at(now));
+    if muted {
+        read = entries.to_vec();
+    }
+    // Tout masqué: rien à enregistrer.
+    if entries.is_empty() {
+        return Ok(());
+    }
+    data.upsert_articles(feed_id, entries.to_vec()).await?;
+    if !read.is_empty() {
+        data.mark_read_batch(&read).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::feed::FeedEntry;
+
+// ===
+//
+//
+// État de la dernière session de la GUI (`session.json`), restauré au démarrage: flux
+// sélectionné, vue ouverte, filtre « Non lus » et défilement de la liste d’articles. Le tri
+// n’en fait pas partie: il est déjà enregistré dans UiConfig::sort_mode.
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionState {
+    pub selected_feed: Option<String>,
+    pub view: SessionView,
+    pub unread_only: bool,
+    pub list_scroll: f32,
+}
+
+// ===
+//
+//
+// Vue ouverte à la fermeture; un article est retrouvé par son identité (FeedEntry::identity).
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SessionView {
+    #[default]
+    ArticleList,
+    Article {
+        identity: String,
+    },
+    DiscoverHome,
+    DiscoverCategory {
+        name: String,
+    },
+    Settings,
+    FeedHealth,
+    Tags,
+    Logs,
+    Stats,
+    FeedManager,
+}
+
+impl SessionState {
+    // ===
+    //
+    //
+    // Lit une session enregistrée; fichier absent ou illisible: session par défaut, sans
+    // avertissement (la session n’est qu’un confort).
+    //
+    //
+    // ===
+    pub async fn load_from(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match tokio::fs::read(path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                debug!(error = %e, path = %path.display(), "ignoring unreadable session file");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    // Article de la vue enregistrée, s’il est encore dans `articles` (None sinon).
+    pub fn article<'a>(&self, articles: &'a [FeedEntry]) -> Option<&'a FeedEntry> {
+        match &self.view {
+            SessionView::Article { identity } => {
+                articles.iter().find(|entry| entry.identity() == *identity)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::feed::FeedEntry;
+
+// ===
+//
+//
+// Textes de partage d’un article (menu « Partager » de l’interface): lien Markdown, titre et
+// lien sur deux lignes, adresse mailto pré-remplie. L’article complet en Markdown reprend
+// `FeedEntry::to_markdown` de l’export.
+//
+//
+// ===
+
+// `[titre](lien)`, titre sur une ligne et crochets échappés; sans titre, le lien sert de texte.
+pub fn markdown_link(entry: &FeedEntry) -> String {
+    let title = one_line(&entry.title);
+    let text = if title.is_empty() {
+        entry.url.clone()
+    } else {
+        let mut escaped = String::with_capacity(title.len());
+        for c in title.chars() {
+            if matches!(c, '\\' | '[' | ']') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    };
+    // Espaces et parenthèses couperaient la cible du lien.
+    let url = entry
+        .url
+        .trim()
+        .replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29");
+    format!("[{}]({})", text, url)
+}
+
+// Titre puis lien, sur deux lignes.
+pub fn title_and_link(entry: &FeedEntry) -> String {
+    let title = one_line(&entry.title);
+    if title.is_empty() {
+        entry.url.trim().to_string()
+    } else {
+        format!("{}\n{}", title, entry.url.trim())
+    }
+}
+
+// ===
+//
+//
+// Adresse `mailto:` avec sujet et corps (RFC 6068): tout octet hors des caractères non
+// réservés est encodé en %XX (espace en %20, jamais en « + ») et les fins de ligne en CRLF.
+//
+//
+// ===
+pub fn mailto_url(subject: &str, body: &str) -> String {
+    let body = body.replace("\r\n", "\n").replace('\n', "\r\n");
+    format!(
+        "mailto:?subject={}&body={}",
+        percent_encode(&one_line(subject)),
+        percent_encode(&body)
+    )
+}
+
+// Partage par e-mail: le titre en sujet, titre et lien dans le corps.
+pub fn share_mailto(entry: &FeedEntry) -> String {
+    mailto_url(&entry.title, &title_and_link(entry))
+}
+
+fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::PollError;
+use crate::feed::FeedEntry;
+
+// ===
+//
+//
+// Résultat d’une récupération de flux (toutes tentatives confondues), émis par le poller.
+// `fetched_items` compte les entrées du document, `filtered_items` celles écartées car déjà vues
+// (SeenStore): `new_items` + `filtered_items` = `fetched_items`.
+// `bytes_downloaded` additionne les corps reçus (retries et téléchargements interrompus compris),
+// après décompression: reqwest ne rend pas la taille compressée, le chiffre majore donc le trafic
+// réel d’un flux servi en gzip/brotli (en-têtes HTTP non comptés).
+//
+//
+// ===
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FetchOutcome {
+    pub fetched_at: DateTime<Utc>,
+    pub success: bool,
+    pub http_status: Option<u16>,
+    pub new_items: usize,
+    #[serde(default)]
+    pub fetched_items: usize,
+    #[serde(default)]
+    pub filtered_items: usize,
+    pub attempts: usize,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_kind: Option<FetchErrorKind>,
+    #[serde(default)]
+    pub bytes_downloaded: u64,
+}
+
+// ===
+//
+//
+// Catégorie d’échec d’une récupération, pour des messages adaptés côté UI.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FetchErrorKind {
+    Network,
+    HttpStatus,
+    Gone,
+    TooLarge,
+    NotAFeed { looks_like_html: bool },
+    Parse,
+    Other,
+}
+
+impl FetchErrorKind {
+    // Nom stable du type d’échec (étiquette `kind` des métriques, comme en JSON).
+    pub fn label(&self) -> &'static str {
+        match self {
+            FetchErrorKind::Network => "network",
+            FetchErrorKind::HttpStatus => "http_status",
+            FetchErrorKind::Gone => "gone",
+            FetchErrorKind::TooLarge => "too_large",
+            FetchErrorKind::NotAFeed { .. } => "not_a_feed",
+            FetchErrorKind::Parse => "parse",
+            FetchErrorKind::Other => "other",
+        }
+    }
+}
+
+impl From<&PollError> for FetchErrorKind {
+    fn from(err: &PollError) -> Self {
+        match err {
+            PollError::Network(_) => FetchErrorKind::Network,
+            PollError::HttpStatus(_) | PollError::RetryAfter { .. } => FetchErrorKind::HttpStatus,
+            PollError::Gone => FetchErrorKind::Gone,
+            PollError::TooLarge(_) => FetchErrorKind::TooLarge,
+            PollError::NotAFeed {
+                looks_like_html, ..
+            } => FetchErrorKind::NotAFeed {
+                looks_like_html: *looks_like_html,
+            },
+            PollError::Parse(_) => FetchErrorKind::Parse,
+            _ => FetchErrorKind::Other,
+        }
+    }
+}
+
+// ===
+//
+//
+// Statistiques cumulées d’un flux (santé): dernier résultat, échecs et temps moyen.
+//
+//
+// ===
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct FeedStats {
+    pub last: Option<FetchOutcome>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub fetch_count: u64,
+    pub failure_count: u64,
+    pub total_fetch_ms: u64,
+    // Nouveaux articles cumulés et récupérations réussies comptées (moyenne par récupération;
+    // les statistiques antérieures à ces compteurs n’y entrent pas).
+    pub total_new_items: u64,
+    pub counted_polls: u64,
+    // Octets téléchargés cumulés (voir FetchOutcome::bytes_downloaded).
+    pub total_bytes: u64,
+}
+
+impl FeedStats {
+    // ===
+    //
+    //
+    // Intègre le résultat d’une récupération aux statistiques.
+    //
+    //
+    // ===
+    pub fn record(&mut self, outcome: FetchOutcome) {
+        self.fetch_count += 1;
+        self.total_fetch_ms += outcome.duration_ms;
+        self.total_bytes += outcome.bytes_downloaded;
+        if outcome.success {
+            self.last_success_at = Some(outcome.fetched_at);
+            self.total_new_items += outcome.new_items as u64;
+            self.counted_polls += 1;
+        } else {
+            self.failure_count += 1;
+            self.last_failure_at = Some(outcome.fetched_at);
+        }
+        self.last = Some(outcome);
+    }
+
+    // ===
+    //
+    //
+    // Durée moyenne d’une récupération en millisecondes (None si jamais récupéré).
+    //
+    //
+    // ===
+    pub fn average_fetch_ms(&self) -> Option<u64> {
+        self.total_fetch_ms.checked_div(self.fetch_count)
+    }
+}
+
+// ===
+//
+//
+// Bilan d’un cycle de polling (tick planifié, « Rafraîchir maintenant » ou poll_once): flux
+// récupérés, octets téléchargés, nouveaux articles (avant règles de filtrage), échecs et durée.
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CycleSummary {
+    pub feeds_polled: usize,
+    pub bytes_downloaded: u64,
+    pub new_articles: usize,
+    pub errors: usize,
+    pub duration: Duration,
+}
+
+impl CycleSummary {
+    // Ajoute la récupération d’un flux au bilan.
+    pub fn record(&mut self, outcome: &FetchOutcome) {
+        self.feeds_polled += 1;
+        self.bytes_downloaded += outcome.bytes_downloaded;
+        self.new_articles += outcome.new_items;
+        if !outcome.success {
+            self.errors += 1;
+        }
+    }
+}
+
+// Taille lisible en unités françaises (octets, Ko, Mo, Go; base 1024, une décimale).
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["Ko", "Mo", "Go"];
+    if bytes < 1024 {
+        return format!("{} o", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit]).replace('.', ",")
+}
+
+// Nombre de flux du classement « top » de la vue Statistiques.
+pub const STATS_TOP_FEEDS: usize = 10;
+
+// ===
+//
+//
+// Période des statistiques: jours locaux `first..=last`.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsRange {
+    pub first: NaiveDate,
+    pub last: NaiveDate,
+}
+
+impl StatsRange {
+    // Les `days` derniers jours, `today` compris.
+    pub fn last_days(today: NaiveDate, days: u32) -> Self {
+        let back = Days::new(u64::from(days.max(1) - 1));
+        Self {
+            first: today.checked_sub_days(back).unwrap_or(NaiveDate::MIN),
+            last: today,
+        }
+    }
+
+    pub fn contains(&self, day: NaiveDate) -> bool {
+        (self.first..=self.last).contains(&day)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let last = self.last;
+        self.first.iter_days().take_while(move |day| *day <= last)
+    }
+}
+
+// ===
+//
+//
+// Statistiques de lecture sur une période: articles reçus par jour (jour local de
+// publication), articles sans date (« inconnu »), flux les plus actifs, lus / non lus et
+// moyenne de nouveaux articles par récupération (sur tout l’historique).
+//
+//
+// ===
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stats {
+    // Un élément par jour de la période, du plus ancien au plus récent (zéros compris).
+    pub per_day: Vec<(NaiveDate, usize)>,
+    pub unknown_date: usize,
+    // (feed_id, articles), les plus actifs d’abord, au plus STATS_TOP_FEEDS.
+    pub top_feeds: Vec<(String, usize)>,
+    pub read: usize,
+    pub unread: usize,
+    pub average_per_poll: Option<f64>,
+    // Totaux depuis le début des statistiques de santé, tous flux confondus.
+    pub fetches: u64,
+    pub bytes_downloaded: u64,
+}
+
+impl Stats {
+    pub fn total(&self) -> usize {
+        self.read + self.unread
+    }
+}
+
+// Jour local (dans `tz`) d’une date de publication.
+pub fn local_day<Tz: TimeZone>(at: DateTime<Utc>, tz: &Tz) -> NaiveDate {
+    at.with_timezone(tz).date_naive()
+}
+
+// ===
+//
+//
+// Calcule les statistiques d’une période. Les articles publiés hors période sont ignorés;
+// ceux sans date sont comptés à part (`unknown_date`) et entrent dans les autres totaux.
+//
+//
+// ===
+pub fn compute_stats<Tz: TimeZone>(
+    entries: &[FeedEntry],
+    is_read: impl Fn(&FeedEntry) -> bool,
+    feed_stats: &HashMap<String, FeedStats>,
+    range: StatsRange,
+    tz: &Tz,
+) -> Stats {
+    let mut per_day: HashMap<NaiveDate, usize> = HashMap::new();
+    let mut per_feed: HashMap<&str, usize> = HashMap::new();
+    let mut stats = Stats::default();
+    for entry in entries {
+        match entry.published_at.map(|at| local_day(at, tz)) {
+            Some(day) if range.contains(day) => *per_day.entry(day).or_default() += 1,
+            Some(_) => continue,
+            None => stats.unknown_date += 1,
+        }
+        *per_feed.entry(entry.feed_id.as_str()).or_default() += 1;
+        if is_read(entry) {
+            stats.read += 1;
+        } else {
+            stats.unread += 1;
+        }
+    }
+    stats.per_day = range
+        .days()
+        .map(|day| (day, per_day.get(&day).copied().unwrap_or(0)))
+        .collect();
+
+    let mut top: Vec<(String, usize)> = per_feed
+        .into_iter()
+        .map(|(feed_id, count)| (feed_id.to_string(), count))
+        .collect();
+    top.sort_by(|(a, ca), (b, cb)| cb.cmp(ca).then_with(|| a.cmp(b)));
+    top.truncate(STATS_TOP_FEEDS);
+    stats.top_feeds = top;
+
+    let (items, polls) = feed_stats.values().fold((0, 0), |(items, polls), st| {
+        (items + st.total_new_items, polls + st.counted_polls)
+    });
+    stats.average_per_poll = (polls > 0).then(|| items as f64 / polls as f64);
+    stats.fetches = feed_stats.values().map(|st| st.fetch_count).sum();
+    stats.bytes_downloaded = feed_stats.values().map(|st| st.total_bytes).sum();
+    stats
+}
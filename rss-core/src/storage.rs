@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
@@ -8,15 +9,160 @@ use tracing::{debug, warn};
 
 use crate::feed::FeedEntry;
 
+// Nombre d’identités conservées par flux par défaut (surchargé via FeedConfig).
+pub const DEFAULT_MAX_SEEN_PER_FEED: usize = 1000;
+
+// ===
+//
+//
+// Écriture atomique: contenu écrit dans `<fichier>.tmp` puis renommé sur la cible, pour qu’un
+// lecteur concurrent (GUI et CLI sur le même dossier) ne voie jamais un fichier à moitié écrit.
+//
+//
+// ===
+pub async fn write_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let tmp = prepare_tmp(path).await;
+    tokio::fs::write(&tmp, bytes).await?;
+    tokio::fs::rename(&tmp, path).await
+}
+
+// ===
+//
+//
+// Variante de write_atomic pour un fichier de secrets: sous Unix, le fichier temporaire est créé
+// en 0600 (droits réimposés s’il existait déjà) avant d’être renommé sur la cible, qui n’est
+// donc jamais lisible par d’autres utilisateurs, même brièvement.
+//
+//
+// ===
+pub async fn write_atomic_private(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let tmp = prepare_tmp(path).await;
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(&tmp).await?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .await?;
+    }
+    file.write_all(bytes).await?;
+    file.flush().await?;
+    drop(file);
+    tokio::fs::rename(&tmp, path).await
+}
+
+// Crée le dossier parent si besoin; renvoie le chemin du fichier temporaire `<fichier>.tmp`.
+async fn prepare_tmp(path: &Path) -> PathBuf {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await.ok();
+    }
+    match path.extension() {
+        Some(ext) => path.with_extension(format!("{}.tmp", ext.to_string_lossy())),
+        None => path.with_extension("tmp"),
+    }
+}
+
+// ===
+//
+//
+// Identités vues par flux, associées à leur numéro d’ordre d’insertion (éviction du plus ancien).
+// `urls` indexe en plus les URL normalisées, pour reconnaître un article dont le GUID a changé.
+// `fingerprints` garde l’empreinte du contenu de chaque identité vue (article modifié).
+//
+//
+// ===
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(from = "RawSeenData")]
 pub struct SeenData {
-    pub seen: HashMap<String, HashSet<String>>,
+    pub seen: HashMap<String, HashMap<String, u64>>,
+    pub urls: HashMap<String, HashMap<String, u64>>,
+    pub fingerprints: HashMap<String, HashMap<String, u64>>,
+    pub next_seq: u64,
+}
+
+// ===
+//
+//
+// Verdict de SeenStore::check_and_mark sur une entrée récupérée.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeenStatus {
+    New,
+    // Déjà vue, mais son contenu a changé depuis le dernier fetch.
+    Updated,
+    Unchanged,
+}
+
+// ===
+//
+//
+// Format sur disque accepté au chargement: ordonné (actuel) ou ensemble sans ordre (ancien).
+//
+//
+// ===
+#[derive(Deserialize)]
+struct RawSeenData {
+    #[serde(default)]
+    seen: HashMap<String, RawSeenFeed>,
+    #[serde(default)]
+    urls: HashMap<String, HashMap<String, u64>>,
+    #[serde(default)]
+    fingerprints: HashMap<String, HashMap<String, u64>>,
+    #[serde(default)]
+    next_seq: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawSeenFeed {
+    Ordered(HashMap<String, u64>),
+    Legacy(HashSet<String>),
+}
+
+impl From<RawSeenData> for SeenData {
+    fn from(raw: RawSeenData) -> Self {
+        let mut next_seq = raw.next_seq;
+        let seen = raw
+            .seen
+            .into_iter()
+            .map(|(feed_id, feed)| {
+                let ids = match feed {
+                    RawSeenFeed::Ordered(ids) => {
+                        if let Some(max) = ids.values().max() {
+                            next_seq = next_seq.max(max + 1);
+                        }
+                        ids
+                    }
+                    RawSeenFeed::Legacy(ids) => {
+                        next_seq = next_seq.max(1);
+                        ids.into_iter().map(|id| (id, 0)).collect()
+                    }
+                };
+                (feed_id, ids)
+            })
+            .collect();
+        Self {
+            seen,
+            urls: raw.urls,
+            fingerprints: raw.fingerprints,
+            next_seq,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct SeenStore {
     inner: Arc<RwLock<SeenData>>,
     path: Option<PathBuf>,
+    dirty: Arc<AtomicBool>,
+    max_per_feed: Arc<AtomicUsize>,
 }
 
 impl SeenStore {
@@ -31,70 +177,356 @@ impl SeenStore {
         Self {
             inner: Arc::new(RwLock::new(SeenData::default())),
             path: None,
+            dirty: Arc::new(AtomicBool::new(false)),
+            max_per_feed: Arc::new(AtomicUsize::new(DEFAULT_MAX_SEEN_PER_FEED)),
         }
     }
 
     // ===
     //
     //
-    // Charge (ou initialise) un magasin persisté depuis un fichier JSON.
+    // Charge (ou initialise) un magasin persisté depuis un fichier JSON (fallback .json.tmp).
     //
     //
     // ===
     pub async fn load_from(path: impl AsRef<Path>) -> Self {
         let path = path.as_ref().to_path_buf();
         let data = match tokio::fs::read(&path).await {
-            Ok(bytes) => serde_json::from_slice::<SeenData>(&bytes).unwrap_or_default(),
+            Ok(bytes) => match serde_json::from_slice::<SeenData>(&bytes) {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!(error = %e, path = %path.display(), "failed to parse seen store, trying tmp fallback");
+                    match tokio::fs::read(path.with_extension("json.tmp")).await {
+                        Ok(tmp_bytes) => serde_json::from_slice(&tmp_bytes).unwrap_or_default(),
+                        Err(_) => SeenData::default(),
+                    }
+                }
+            },
             Err(_) => SeenData::default(),
         };
         Self {
             inner: Arc::new(RwLock::new(data)),
             path: Some(path),
+            dirty: Arc::new(AtomicBool::new(false)),
+            max_per_feed: Arc::new(AtomicUsize::new(DEFAULT_MAX_SEEN_PER_FEED)),
         }
     }
 
     // ===
     //
     //
-    // Retourne true si l’article est nouveau et le marque comme vu (avec persistance).
+    // Définit le nombre maximal d’identités conservées par flux (appliqué aux prochains ajouts).
+    //
+    //
+    // ===
+    pub fn set_max_per_feed(&self, max: usize) {
+        self.max_per_feed.store(max.max(1), Ordering::Relaxed);
+    }
+
+    // ===
+    //
+    //
+    // Retourne true si l’article est nouveau et le marque comme vu (persistance différée à flush()).
+    // Un article dont le GUID a changé mais dont l’URL normalisée a déjà été vue n’est pas nouveau.
     //
     //
     // ===
     pub async fn is_new_and_mark(&self, entry: &FeedEntry) -> bool {
+        self.check_and_mark(entry).await == SeenStatus::New
+    }
+
+    // ===
+    //
+    //
+    // Comme is_new_and_mark, en distinguant un article déjà vu dont le contenu a changé
+    // (empreinte différente de celle enregistrée): `Updated`. L’empreinte est mise à jour.
+    // Une identité vue avant l’enregistrement des empreintes reçoit la sienne sans être
+    // signalée.
+    //
+    //
+    // ===
+    pub async fn check_and_mark(&self, entry: &FeedEntry) -> SeenStatus {
         let key = entry.identity();
-        let feed_id = entry.feed_id.clone();
+        let fingerprint = entry.content_fingerprint();
+        let max_per_feed = self.max_per_feed.load(Ordering::Relaxed);
         let mut inner = self.inner.write().await;
-        let set = inner.seen.entry(feed_id).or_default();
-        if set.contains(&key) {
-            false
-        } else {
-            set.insert(key);
+        let SeenData {
+            seen,
+            urls,
+            fingerprints,
+            next_seq,
+        } = &mut *inner;
+        let seq = *next_seq;
+        let set = seen.entry(entry.feed_id.clone()).or_default();
+        let prints = fingerprints.entry(entry.feed_id.clone()).or_default();
+        let legacy = entry.legacy_identity();
+        if set.contains_key(&key) || set.contains_key(&legacy) {
+            let stored = if set.contains_key(&key) { key } else { legacy };
+            let previous = prints.insert(stored, fingerprint);
+            if previous == Some(fingerprint) {
+                return SeenStatus::Unchanged;
+            }
+            self.dirty.store(true, Ordering::Release);
+            return match previous {
+                Some(_) => SeenStatus::Updated,
+                None => SeenStatus::Unchanged,
+            };
+        }
+        set.insert(key.clone(), seq);
+        prints.insert(key, fingerprint);
+        if prune_oldest(set, max_per_feed) {
+            prints.retain(|id, _| set.contains_key(id));
+        }
+
+        // GUID régénéré: le lien a déjà été vu sous une autre identité
+        let mut status = SeenStatus::New;
+        if let Some(url_key) = entry.url_key() {
+            let urls = urls.entry(entry.feed_id.clone()).or_default();
+            if urls.insert(url_key, seq).is_some() {
+                status = SeenStatus::Unchanged;
+            }
+            prune_oldest(urls, max_per_feed);
+        }
+        *next_seq += 1;
+        self.dirty.store(true, Ordering::Release);
+        status
+    }
+
+    // ===
+    //
+    //
+    // Indique si l’article serait écarté comme déjà vu (identité ou URL normalisée), sans rien
+    // marquer: diagnostic d’un flux qui ne publie rien de nouveau.
+    //
+    //
+    // ===
+    pub async fn contains(&self, entry: &FeedEntry) -> bool {
+        let inner = self.inner.read().await;
+        let by_identity = inner.seen.get(&entry.feed_id).is_some_and(|set| {
+            set.contains_key(&entry.identity()) || set.contains_key(&entry.legacy_identity())
+        });
+        by_identity
+            || entry.url_key().is_some_and(|url_key| {
+                inner
+                    .urls
+                    .get(&entry.feed_id)
+                    .is_some_and(|urls| urls.contains_key(&url_key))
+            })
+    }
+
+    // ===
+    //
+    //
+    // Oublie toutes les identités vues d’un flux (suppression du flux).
+    //
+    //
+    // ===
+    pub async fn remove_feed(&self, feed_id: &str) {
+        let mut inner = self.inner.write().await;
+        let had_urls = inner.urls.remove(feed_id).is_some();
+        inner.fingerprints.remove(feed_id);
+        if inner.seen.remove(feed_id).is_some() || had_urls {
             drop(inner);
-            if let Err(err) = self.persist().await {
-                warn!(%err, "failed to persist seen store");
+            self.dirty.store(true, Ordering::Release);
+            self.flush().await;
+        }
+    }
+
+    // ===
+    //
+    //
+    // Oublie les flux pour lesquels `keep` est faux (état laissé par un flux supprimé avant que
+    // DataApi ne possède ce magasin). Persiste aussitôt si quelque chose a été retiré.
+    //
+    //
+    // ===
+    pub async fn retain_feeds(&self, keep: impl Fn(&str) -> bool) {
+        let mut inner = self.inner.write().await;
+        let before = inner.seen.len() + inner.urls.len() + inner.fingerprints.len();
+        inner.seen.retain(|feed_id, _| keep(feed_id));
+        inner.urls.retain(|feed_id, _| keep(feed_id));
+        inner.fingerprints.retain(|feed_id, _| keep(feed_id));
+        let after = inner.seen.len() + inner.urls.len() + inner.fingerprints.len();
+        drop(inner);
+        if after != before {
+            self.dirty.store(true, Ordering::Release);
+            self.flush().await;
+        }
+    }
+
+    // ===
+    //
+    //
+    // Oublie les identités et liens d’un flux vus avant `horizon` (numéro d’ordre), sauf ceux de
+    // `keep`. Retourne le nombre d’entrées retirées; persiste aussitôt s’il y en a.
+    //
+    //
+    // ===
+    pub(crate) async fn prune(&self, feed_id: &str, horizon: u64, keep: &HashSet<String>) -> usize {
+        let mut inner = self.inner.write().await;
+        let SeenData {
+            seen,
+            urls,
+            fingerprints,
+            ..
+        } = &mut *inner;
+        let mut removed = 0;
+        for map in [seen.get_mut(feed_id), urls.get_mut(feed_id)]
+            .into_iter()
+            .flatten()
+        {
+            let before = map.len();
+            map.retain(|key, seq| *seq >= horizon || keep.contains(key));
+            removed += before - map.len();
+        }
+        if let Some(prints) = fingerprints.get_mut(feed_id) {
+            let ids = seen.get(feed_id);
+            prints.retain(|id, _| ids.is_some_and(|ids| ids.contains_key(id)));
+        }
+        drop(inner);
+        if removed > 0 {
+            self.dirty.store(true, Ordering::Release);
+            self.flush().await;
+        }
+        removed
+    }
+
+    // ===
+    //
+    //
+    // Copie de l’état courant (export vers une autre machine).
+    //
+    //
+    // ===
+    pub async fn snapshot(&self) -> SeenData {
+        self.inner.read().await.clone()
+    }
+
+    // Copie de l’état d’un seul flux (sauvegarde avant suppression, restaurée par merge).
+    pub async fn feed_snapshot(&self, feed_id: &str) -> SeenData {
+        let inner = self.inner.read().await;
+        let pick = |map: &HashMap<String, HashMap<String, u64>>| {
+            map.get(feed_id)
+                .map(|ids| HashMap::from([(feed_id.to_string(), ids.clone())]))
+                .unwrap_or_default()
+        };
+        SeenData {
+            seen: pick(&inner.seen),
+            urls: pick(&inner.urls),
+            fingerprints: pick(&inner.fingerprints),
+            next_seq: 0,
+        }
+    }
+
+    // ===
+    //
+    //
+    // Fusionne un état importé: les identités inconnues sont ajoutées après les locales, dans
+    // leur ordre d’origine, puis le plafond par flux est réappliqué. Persiste aussitôt.
+    //
+    //
+    // ===
+    pub async fn merge(&self, other: SeenData) {
+        let max_per_feed = self.max_per_feed.load(Ordering::Relaxed);
+        let mut inner = self.inner.write().await;
+        let SeenData {
+            seen,
+            urls,
+            fingerprints,
+            next_seq,
+        } = &mut *inner;
+        let base = *next_seq;
+        let top = merge_ordered(seen, other.seen, base, max_per_feed).max(merge_ordered(
+            urls,
+            other.urls,
+            base,
+            max_per_feed,
+        ));
+        *next_seq = (*next_seq).max(top);
+        // Empreintes locales prioritaires; celles des identités évincées sont oubliées.
+        for (feed_id, prints) in other.fingerprints {
+            let local = fingerprints.entry(feed_id.clone()).or_default();
+            for (id, fingerprint) in prints {
+                local.entry(id).or_insert(fingerprint);
             }
-            true
+            let ids = seen.get(&feed_id);
+            local.retain(|id, _| ids.is_some_and(|ids| ids.contains_key(id)));
         }
+        drop(inner);
+        self.dirty.store(true, Ordering::Release);
+        self.flush().await;
     }
 
     // ===
     //
     //
-    // Sérialise et sauve l’état si un chemin est configuré; sinon no-op.
+    // Persiste l’état s’il a changé depuis la dernière écriture (une écriture par lot).
+    //
+    //
+    // ===
+    pub async fn flush(&self) {
+        if !self.dirty.swap(false, Ordering::AcqRel) {
+            return;
+        }
+        if let Err(err) = self.persist().await {
+            self.dirty.store(true, Ordering::Release);
+            warn!(%err, "failed to persist seen store");
+        }
+    }
+
+    // ===
+    //
+    //
+    // Sérialise et sauve l’état si un chemin est configuré (écriture atomique via .tmp); sinon no-op.
     //
     //
     // ===
     async fn persist(&self) -> Result<(), std::io::Error> {
         if let Some(path) = &self.path {
-            let inner = self.inner.read().await;
-            let bytes = serde_json::to_vec_pretty(&*inner).expect("serialize seen data");
-            if let Some(parent) = path.parent() {
-                tokio::fs::create_dir_all(parent).await.ok();
-            }
-            tokio::fs::write(path, bytes).await?;
+            let bytes = {
+                let inner = self.inner.read().await;
+                serde_json::to_vec_pretty(&*inner).expect("serialize seen data")
+            };
+            write_atomic(path, &bytes).await?;
         } else {
             debug!("seen store is in-memory only; skipping persist");
         }
         Ok(())
     }
 }
+
+// Évince les plus anciens au-delà de `max`; true si quelque chose a été retiré.
+fn prune_oldest(set: &mut HashMap<String, u64>, max: usize) -> bool {
+    let before = set.len();
+    while set.len() > max {
+        let oldest = set
+            .iter()
+            .min_by_key(|(_, seq)| **seq)
+            .map(|(id, _)| id.clone());
+        match oldest {
+            Some(id) => set.remove(&id),
+            None => break,
+        };
+    }
+    set.len() < before
+}
+
+// Ajoute les identités absentes de `local`, décalées de `base`; retourne le prochain numéro libre.
+fn merge_ordered(
+    local: &mut HashMap<String, HashMap<String, u64>>,
+    other: HashMap<String, HashMap<String, u64>>,
+    base: u64,
+    max: usize,
+) -> u64 {
+    let mut top = base;
+    for (feed_id, ids) in other {
+        let set = local.entry(feed_id).or_default();
+        for (id, seq) in ids {
+            let seq = base.saturating_add(seq);
+            top = top.max(seq.saturating_add(1));
+            set.entry(id).or_insert(seq);
+        }
+        prune_oldest(set, max);
+    }
+    top
+}
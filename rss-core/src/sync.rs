@@ -0,0 +1,1237 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use tracing::debug;
+use url::Url;
+
+use crate::data::DataApi;
+use crate::error::{DataError, PollError};
+use crate::feed::{collect_categories, FeedDescriptor, FeedEntry, Secret};
+use crate::poller::{Event, PollConfig};
+use crate::redact::redact_url;
+use crate::storage::SeenStore;
+
+// Préfixe des identifiants de flux et d’articles issus d’un serveur Fever.
+pub const FEVER_ID_PREFIX: &str = "fever:";
+
+// Nombre maximal d’articles demandés par appel `with_ids` (limite de l’API Fever).
+const FEVER_ITEMS_PER_CALL: usize = 50;
+
+// ===
+//
+//
+// Client de l’API Fever (FreshRSS, Miniflux, Tiny Tiny RSS...): chaque appel est un POST sur
+// `<endpoint>?api&<requête>` portant la clé `api_key` (md5 de « email:mot de passe »).
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct FeverClient {
+    endpoint: Url,
+    api_key: Secret,
+}
+
+// Groupe Fever (catégorie) et flux qu’il contient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeverGroup {
+    pub id: u64,
+    pub title: String,
+    pub feed_ids: Vec<u64>,
+}
+
+// ===
+//
+//
+// Article tel que renvoyé par `items`; `is_read`/`is_saved` valent 0 ou 1.
+//
+//
+// ===
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeverItem {
+    #[serde(deserialize_with = "de_id")]
+    pub id: u64,
+    #[serde(deserialize_with = "de_id")]
+    pub feed_id: u64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub html: Option<String>,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub is_saved: u8,
+    #[serde(default)]
+    pub is_read: u8,
+    #[serde(default)]
+    pub created_on_time: i64,
+}
+
+// Marque applicable à un article côté serveur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeverMark {
+    Read,
+    Unread,
+    Saved,
+    Unsaved,
+}
+
+impl FeverMark {
+    fn as_str(self) -> &'static str {
+        match self {
+            FeverMark::Read => "read",
+            FeverMark::Unread => "unread",
+            FeverMark::Saved => "saved",
+            FeverMark::Unsaved => "unsaved",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthEnvelope {
+    #[serde(default)]
+    auth: u8,
+}
+
+#[derive(Deserialize)]
+struct RawFeed {
+    #[serde(deserialize_with = "de_id")]
+    id: u64,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct RawGroup {
+    #[serde(deserialize_with = "de_id")]
+    id: u64,
+    #[serde(default)]
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct RawFeedsGroup {
+    #[serde(deserialize_with = "de_id")]
+    group_id: u64,
+    #[serde(default)]
+    feed_ids: String,
+}
+
+#[derive(Deserialize)]
+struct FeedsResponse {
+    #[serde(default)]
+    feeds: Vec<RawFeed>,
+}
+
+#[derive(Deserialize)]
+struct GroupsResponse {
+    #[serde(default)]
+    groups: Vec<RawGroup>,
+    #[serde(default)]
+    feeds_groups: Vec<RawFeedsGroup>,
+}
+
+#[derive(Deserialize)]
+struct ItemsResponse {
+    #[serde(default)]
+    items: Vec<FeverItem>,
+}
+
+#[derive(Deserialize)]
+struct UnreadResponse {
+    #[serde(default)]
+    unread_item_ids: String,
+}
+
+#[derive(Deserialize)]
+struct SavedResponse {
+    #[serde(default)]
+    saved_item_ids: String,
+}
+
+impl FeverClient {
+    // ===
+    //
+    //
+    // Crée un client pour l’URL de l’API (ex. https://rss.example.org/api/fever.php).
+    //
+    //
+    // ===
+    pub fn new(endpoint: &str, api_key: Secret) -> Result<Self, PollError> {
+        let endpoint = Url::parse(endpoint.trim())?;
+        Ok(Self { endpoint, api_key })
+    }
+
+    // ===
+    //
+    //
+    // URL de l’API affichable dans les logs.
+    //
+    //
+    // ===
+    pub fn redacted_endpoint(&self) -> String {
+        redact_url(&self.endpoint)
+    }
+
+    // ===
+    //
+    //
+    // Groupes et appartenance des flux (`groups` + `feeds_groups`).
+    //
+    //
+    // ===
+    pub async fn groups(&self, client: &Client) -> Result<Vec<FeverGroup>, PollError> {
+        let resp: GroupsResponse = self.call(client, "groups", &[]).await?;
+        let mut members: HashMap<u64, Vec<u64>> = HashMap::new();
+        for fg in resp.feeds_groups {
+            members
+                .entry(fg.group_id)
+                .or_default()
+                .extend(parse_id_list(&fg.feed_ids));
+        }
+        Ok(resp
+            .groups
+            .into_iter()
+            .map(|g| FeverGroup {
+                feed_ids: members.remove(&g.id).unwrap_or_default(),
+                id: g.id,
+                title: g.title,
+            })
+            .collect())
+    }
+
+    // ===
+    //
+    //
+    // Flux suivis sur le serveur, convertis en FeedDescriptor (`fever:<id>`).
+    //
+    //
+    // ===
+    pub async fn feeds(&self, client: &Client) -> Result<Vec<FeedDescriptor>, PollError> {
+        let resp: FeedsResponse = self.call(client, "feeds", &[]).await?;
+        Ok(resp
+            .feeds
+            .into_iter()
+            .map(|f| FeedDescriptor {
+                id: fever_id(f.id),
+                title: if f.title.is_empty() {
+                    f.url.clone()
+                } else {
+                    f.title
+                },
+                url: f.url,
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    // ===
+    //
+    //
+    // Articles désignés par leurs identifiants (par paquets de 50, limite de l’API).
+    //
+    //
+    // ===
+    pub async fn items_with_ids(
+        &self,
+        client: &Client,
+        ids: &[u64],
+    ) -> Result<Vec<FeverItem>, PollError> {
+        let mut items = Vec::new();
+        for chunk in ids.chunks(FEVER_ITEMS_PER_CALL) {
+            let list = chunk
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            let query = format!("items&with_ids={}", list);
+            let resp: ItemsResponse = self.call(client, &query, &[]).await?;
+            items.extend(resp.items);
+        }
+        Ok(items)
+    }
+
+    // ===
+    //
+    //
+    // Identifiants des articles non lus côté serveur.
+    //
+    //
+    // ===
+    pub async fn unread_item_ids(&self, client: &Client) -> Result<Vec<u64>, PollError> {
+        let resp: UnreadResponse = self.call(client, "unread_item_ids", &[]).await?;
+        Ok(parse_id_list(&resp.unread_item_ids))
+    }
+
+    // ===
+    //
+    //
+    // Identifiants des articles sauvegardés (favoris) côté serveur.
+    //
+    //
+    // ===
+    pub async fn saved_item_ids(&self, client: &Client) -> Result<Vec<u64>, PollError> {
+        let resp: SavedResponse = self.call(client, "saved_item_ids", &[]).await?;
+        Ok(parse_id_list(&resp.saved_item_ids))
+    }
+
+    // ===
+    //
+    //
+    // Applique une marque (lu, non lu, sauvegardé...) à un article sur le serveur.
+    //
+    //
+    // ===
+    pub async fn mark_item(
+        &self,
+        client: &Client,
+        item_id: u64,
+        mark: FeverMark,
+    ) -> Result<(), PollError> {
+        let id = item_id.to_string();
+        let _: AuthEnvelope = self
+            .call(
+                client,
+                "",
+                &[("mark", "item"), ("as", mark.as_str()), ("id", &id)],
+            )
+            .await?;
+        Ok(())
+    }
+
+    // ===
+    //
+    //
+    // Appel générique: POST authentifié, contrôle du statut et du champ `auth` de la réponse.
+    //
+    //
+    // ===
+    async fn call<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        query: &str,
+        form: &[(&str, &str)],
+    ) -> Result<T, PollError> {
+        let mut url = self.endpoint.clone();
+        if query.is_empty() {
+            url.set_query(Some("api"));
+        } else {
+            url.set_query(Some(&format!("api&{}", query)));
+        }
+        let api_key = self.api_key.reveal().unwrap_or_default();
+        let mut fields = vec![("api_key", api_key.as_str())];
+        fields.extend_from_slice(form);
+
+        let response = client.post(url).form(&fields).send().await?;
+        let bytes = check_status(response).await?.bytes().await?;
+        let auth: AuthEnvelope =
+            serde_json::from_slice(&bytes).map_err(|e| PollError::SyncResponse(e.to_string()))?;
+        if auth.auth != 1 {
+            return Err(PollError::SyncAuth);
+        }
+        serde_json::from_slice(&bytes).map_err(|e| PollError::SyncResponse(e.to_string()))
+    }
+}
+
+// ===
+//
+//
+// Synchronisation avec un serveur Fever, exécutée par le poller à la place du polling local:
+// liste des flux, récupération des non lus (aller-retour `unread_item_ids` → `with_ids`)
+// et réconciliation de l’état lu dans les deux sens (lu d’un côté = lu partout). Les articles
+// sauvegardés (`saved_item_ids`) sont réconciliés avec les favoris comme pour Google Reader:
+// le côté qui a changé depuis le dernier cycle l’emporte.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct FeverSync {
+    fever: FeverClient,
+    api: DataApi,
+}
+
+impl FeverSync {
+    pub fn new(fever: FeverClient, api: DataApi) -> Self {
+        Self { fever, api }
+    }
+
+    // ===
+    //
+    //
+    // URL du serveur affichable dans les logs.
+    //
+    //
+    // ===
+    pub fn redacted_endpoint(&self) -> String {
+        self.fever.redacted_endpoint()
+    }
+
+    // ===
+    //
+    //
+    // Un cycle de synchronisation; renvoie NewArticles pour les articles jamais vus.
+    //
+    //
+    // ===
+    pub async fn run(
+        &self,
+        client: &Client,
+        seen: &SeenStore,
+        cfg: &PollConfig,
+    ) -> Result<Vec<Event>, PollError> {
+        let remote_feeds = self.fever.feeds(client).await?;
+        let categories: HashMap<String, String> = self
+            .fever
+            .groups(client)
+            .await?
+            .into_iter()
+            .flat_map(|g| {
+                let title = g.title;
+                g.feed_ids
+                    .into_iter()
+                    .map(move |id| (fever_id(id), title.clone()))
+            })
+            .collect();
+        sync_feed_list(&self.api, FEVER_ID_PREFIX, &remote_feeds).await?;
+
+        let unread: HashSet<u64> = self
+            .fever
+            .unread_item_ids(client)
+            .await?
+            .into_iter()
+            .collect();
+
+        let saved: HashSet<u64> = self
+            .fever
+            .saved_item_ids(client)
+            .await?
+            .into_iter()
+            .collect();
+
+        // Réconciliation de l’état lu et des favoris pour les articles déjà connus localement
+        let synced = self.api.synced_stars(FEVER_ID_PREFIX).await;
+        let mut known: HashSet<u64> = HashSet::new();
+        let mut read_locally = Vec::new();
+        let mut read_remotely = Vec::new();
+        let mut marks_remotely = Vec::new();
+        let mut now_saved = HashSet::new();
+        for feed in &remote_feeds {
+            for entry in self.api.list_articles(&feed.id).await {
+                let Some(item_id) = entry.guid.as_deref().and_then(parse_fever_id) else {
+                    continue;
+                };
+                known.insert(item_id);
+                let star = StarSync::reconcile(
+                    synced.contains(&item_id.to_string()),
+                    self.api.is_starred(&entry).await,
+                    saved.contains(&item_id),
+                );
+                match star {
+                    StarSync::SetLocal(value) => {
+                        self.api.set_starred(&entry, value).await?;
+                    }
+                    StarSync::SetRemote(true) => marks_remotely.push((item_id, FeverMark::Saved)),
+                    StarSync::SetRemote(false) => {
+                        marks_remotely.push((item_id, FeverMark::Unsaved))
+                    }
+                    StarSync::Agreed(_) => {}
+                }
+                if star.starred() {
+                    now_saved.insert(item_id.to_string());
+                }
+                let is_read = self.api.is_read(&entry).await;
+                match (unread.contains(&item_id), is_read) {
+                    (false, false) => read_locally.push(entry),
+                    (true, true) => read_remotely.push(item_id),
+                    _ => {}
+                }
+            }
+        }
+        self.api.mark_read_batch(&read_locally).await?;
+        marks_remotely.extend(read_remotely.into_iter().map(|id| (id, FeverMark::Read)));
+        for (item_id, mark) in marks_remotely {
+            self.fever.mark_item(client, item_id, mark).await?;
+        }
+        self.api
+            .set_synced_stars(FEVER_ID_PREFIX, now_saved)
+            .await?;
+
+        // Récupération des non lus encore inconnus
+        let mut missing: Vec<u64> = unread.difference(&known).copied().collect();
+        missing.sort_unstable();
+        let items = self.fever.items_with_ids(client, &missing).await?;
+        let mut batches: HashMap<String, Vec<FeedEntry>> = HashMap::new();
+        for item in items {
+            let mut entry = item.into_entry().sanitized(cfg.max_content_bytes);
+            if entry.categories.is_empty() {
+                if let Some(group) = categories.get(&entry.feed_id) {
+                    entry.categories.push(group.clone());
+                }
+            }
+            if seen.is_new_and_mark(&entry).await {
+                batches
+                    .entry(entry.feed_id.clone())
+                    .or_default()
+                    .push(entry);
+            }
+        }
+        seen.flush().await;
+        debug!(
+            endpoint = %self.redacted_endpoint(),
+            fetched = missing.len(),
+            "fever sync completed"
+        );
+
+        Ok(batches
+            .into_iter()
+            .map(|(feed_id, entries)| Event::NewArticles(feed_id, entries))
+            .collect())
+    }
+}
+
+impl FeverItem {
+    // ===
+    //
+    //
+    // Convertit l’article en FeedEntry; le GUID `fever:<id>` est opaque donc stable.
+    //
+    //
+    // ===
+    pub fn into_entry(self) -> FeedEntry {
+        FeedEntry {
+            feed_id: fever_id(self.feed_id),
+            title: self.title,
+            summary: None,
+            url: self.url,
+            published_at: DateTime::<Utc>::from_timestamp(self.created_on_time, 0)
+                .filter(|_| self.created_on_time > 0),
+            guid: Some(fever_id(self.id)),
+            guid_is_permalink: Some(false),
+            author: self.author.filter(|a| !a.is_empty()),
+            categories: Vec::new(),
+            content_html: self.html.filter(|h| !h.is_empty()),
+            image_url: None,
+            highlighted: false,
+            updated_at: None,
+            first_seen_at: Utc::now(),
+            content_truncated: false,
+        }
+    }
+}
+
+// ===
+//
+//
+// Aligne les flux locaux portant le préfixe du serveur sur ceux du serveur (ajouts, titres,
+// suppressions); les flux suivis localement ne sont pas touchés.
+//
+//
+// ===
+async fn sync_feed_list(
+    api: &DataApi,
+    prefix: &str,
+    remote: &[FeedDescriptor],
+) -> Result<(), DataError> {
+    let local: HashMap<String, FeedDescriptor> = api
+        .list_feeds()
+        .await
+        .into_iter()
+        .filter(|f| f.id.starts_with(prefix))
+        .map(|f| (f.id.clone(), f))
+        .collect();
+    for feed in remote {
+        let unchanged = local
+            .get(&feed.id)
+            .is_some_and(|l| l.title == feed.title && l.url == feed.url);
+        if !unchanged {
+            api.add_feed(feed.clone()).await?;
+        }
+    }
+    let remote_ids: HashSet<&str> = remote.iter().map(|f| f.id.as_str()).collect();
+    for id in local.keys() {
+        if !remote_ids.contains(id.as_str()) {
+            api.remove_feed(id).await?;
+        }
+    }
+    Ok(())
+}
+
+// ===
+//
+//
+// Décision pour le favori d’un article connu des deux côtés, d’après son état à la fin du
+// dernier cycle (`synced`): le côté qui a changé depuis l’emporte, retrait compris. Avant toute
+// synchronisation (base vide), un favori présent d’un seul côté est ajouté de l’autre.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StarSync {
+    Agreed(bool),
+    SetLocal(bool),
+    SetRemote(bool),
+}
+
+impl StarSync {
+    fn reconcile(synced: bool, local: bool, remote: bool) -> Self {
+        if local == remote {
+            StarSync::Agreed(local)
+        } else if local != synced {
+            StarSync::SetRemote(local)
+        } else {
+            StarSync::SetLocal(remote)
+        }
+    }
+
+    // Favori des deux côtés une fois la décision appliquée.
+    fn starred(self) -> bool {
+        match self {
+            StarSync::Agreed(starred)
+            | StarSync::SetLocal(starred)
+            | StarSync::SetRemote(starred) => starred,
+        }
+    }
+}
+
+fn fever_id(id: u64) -> String {
+    format!("{}{}", FEVER_ID_PREFIX, id)
+}
+
+fn parse_fever_id(value: &str) -> Option<u64> {
+    value.strip_prefix(FEVER_ID_PREFIX)?.parse().ok()
+}
+
+// Liste d’identifiants séparés par des virgules (format des réponses Fever).
+fn parse_id_list(raw: &str) -> Vec<u64> {
+    raw.split(',')
+        .filter_map(|id| id.trim().parse().ok())
+        .collect()
+}
+
+// Les serveurs envoient les identifiants tantôt en nombre, tantôt en chaîne.
+fn de_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawId {
+        Number(u64),
+        Text(String),
+    }
+    match RawId::deserialize(deserializer)? {
+        RawId::Number(id) => Ok(id),
+        RawId::Text(text) => text.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
+
+// Préfixe des identifiants de flux et d’articles issus d’un serveur Google Reader.
+pub const GREADER_ID_PREFIX: &str = "greader:";
+
+// Taille des pages demandées à `stream/contents` (suivies via le jeton `continuation`).
+const GREADER_PAGE_SIZE: usize = 100;
+
+const GREADER_READING_LIST: &str = "user/-/state/com.google/reading-list";
+
+// ===
+//
+//
+// Étiquettes d’état Google Reader modifiables via `edit-tag`.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GReaderTag {
+    Read,
+    Starred,
+}
+
+impl GReaderTag {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GReaderTag::Read => "user/-/state/com.google/read",
+            GReaderTag::Starred => "user/-/state/com.google/starred",
+        }
+    }
+}
+
+// ===
+//
+//
+// Client de l’API compatible Google Reader (Miniflux, FreshRSS, The Old Reader):
+// authentification ClientLogin puis en-tête `Authorization: GoogleLogin auth=<jeton>`.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct GReaderClient {
+    endpoint: Url,
+    username: String,
+    password: Secret,
+}
+
+// Jeton obtenu par ClientLogin, à joindre à chaque appel.
+#[derive(Debug, Clone)]
+pub struct GReaderSession {
+    auth: Secret,
+}
+
+// ===
+//
+//
+// Article renvoyé par `stream/contents`; l’état lu/favori est porté par ses catégories.
+//
+//
+// ===
+#[derive(Debug, Clone, Deserialize)]
+pub struct GReaderItem {
+    id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    published: i64,
+    #[serde(default)]
+    canonical: Vec<GReaderLink>,
+    #[serde(default)]
+    alternate: Vec<GReaderLink>,
+    #[serde(default)]
+    summary: Option<GReaderContent>,
+    #[serde(default)]
+    content: Option<GReaderContent>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    categories: Vec<String>,
+    origin: GReaderOrigin,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GReaderLink {
+    href: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GReaderContent {
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GReaderOrigin {
+    #[serde(rename = "streamId")]
+    stream_id: String,
+}
+
+#[derive(Deserialize)]
+struct SubscriptionList {
+    #[serde(default)]
+    subscriptions: Vec<RawSubscription>,
+}
+
+#[derive(Deserialize)]
+struct RawSubscription {
+    id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct StreamPage {
+    #[serde(default)]
+    items: Vec<GReaderItem>,
+    #[serde(default)]
+    continuation: Option<String>,
+}
+
+impl GReaderClient {
+    // ===
+    //
+    //
+    // Crée un client pour la racine de l’API (ex. https://rss.example.org/api/greader.php).
+    //
+    //
+    // ===
+    pub fn new(endpoint: &str, username: &str, password: Secret) -> Result<Self, PollError> {
+        let mut endpoint = Url::parse(endpoint.trim())?;
+        if !endpoint.path().ends_with('/') {
+            let path = format!("{}/", endpoint.path());
+            endpoint.set_path(&path);
+        }
+        Ok(Self {
+            endpoint,
+            username: username.to_string(),
+            password,
+        })
+    }
+
+    // ===
+    //
+    //
+    // URL de l’API affichable dans les logs.
+    //
+    //
+    // ===
+    pub fn redacted_endpoint(&self) -> String {
+        redact_url(&self.endpoint)
+    }
+
+    // ===
+    //
+    //
+    // Authentification ClientLogin; identifiants refusés → PollError::SyncAuth.
+    //
+    //
+    // ===
+    pub async fn login(&self, client: &Client) -> Result<GReaderSession, PollError> {
+        let url = self.endpoint.join("accounts/ClientLogin")?;
+        let password = self.password.reveal().unwrap_or_default();
+        let response = client
+            .post(url)
+            .form(&[
+                ("Email", self.username.as_str()),
+                ("Passwd", password.as_str()),
+            ])
+            .send()
+            .await?;
+        let body = check_status(response).await?.text().await?;
+        body.lines()
+            .find_map(|line| line.strip_prefix("Auth="))
+            .map(|token| GReaderSession {
+                auth: Secret::Plaintext(token.trim().to_string()),
+            })
+            .ok_or(PollError::SyncAuth)
+    }
+
+    // ===
+    //
+    //
+    // Abonnements (`subscription/list`), convertis en FeedDescriptor (`greader:<id>`).
+    //
+    //
+    // ===
+    pub async fn subscriptions(
+        &self,
+        client: &Client,
+        session: &GReaderSession,
+    ) -> Result<Vec<FeedDescriptor>, PollError> {
+        let mut url = self.endpoint.join("reader/api/0/subscription/list")?;
+        url.query_pairs_mut().append_pair("output", "json");
+        let list: SubscriptionList = self.get_json(client, session, url).await?;
+        Ok(list
+            .subscriptions
+            .into_iter()
+            .map(|s| FeedDescriptor {
+                id: format!("{}{}", GREADER_ID_PREFIX, s.id),
+                title: if s.title.is_empty() {
+                    s.url.clone()
+                } else {
+                    s.title
+                },
+                url: s.url,
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    // ===
+    //
+    //
+    // Tous les articles d’un flux d’articles (`stream/contents`), page par page en suivant
+    // le jeton `continuation`; `exclude` retire les articles portant cette étiquette.
+    //
+    //
+    // ===
+    pub async fn stream_contents(
+        &self,
+        client: &Client,
+        session: &GReaderSession,
+        stream: &str,
+        exclude: Option<GReaderTag>,
+    ) -> Result<Vec<GReaderItem>, PollError> {
+        let base = self
+            .endpoint
+            .join(&format!("reader/api/0/stream/contents/{}", stream))?;
+        let mut items = Vec::new();
+        let mut continuation: Option<String> = None;
+        loop {
+            let mut url = base.clone();
+            {
+                let mut query = url.query_pairs_mut();
+                query
+                    .append_pair("output", "json")
+                    .append_pair("n", &GREADER_PAGE_SIZE.to_string());
+                if let Some(tag) = exclude {
+                    query.append_pair("xt", tag.as_str());
+                }
+                if let Some(token) = &continuation {
+                    query.append_pair("c", token);
+                }
+            }
+            let page: StreamPage = self.get_json(client, session, url).await?;
+            items.extend(page.items);
+            match page.continuation.filter(|c| !c.is_empty()) {
+                // Un serveur qui renvoie le même jeton bouclerait indéfiniment.
+                Some(next) if continuation.as_ref() != Some(&next) => continuation = Some(next),
+                _ => break,
+            }
+        }
+        Ok(items)
+    }
+
+    // ===
+    //
+    //
+    // Ajoute (ou retire) une étiquette d’état à des articles via `edit-tag`; le jeton
+    // d’écriture `T` est demandé à `token` juste avant.
+    //
+    //
+    // ===
+    pub async fn edit_tag(
+        &self,
+        client: &Client,
+        session: &GReaderSession,
+        item_ids: &[String],
+        tag: GReaderTag,
+        add: bool,
+    ) -> Result<(), PollError> {
+        if item_ids.is_empty() {
+            return Ok(());
+        }
+        let token_url = self.endpoint.join("reader/api/0/token")?;
+        let response = client
+            .get(token_url)
+            .header(reqwest::header::AUTHORIZATION, session.header())
+            .send()
+            .await?;
+        let token = check_status(response).await?.text().await?;
+
+        let mut form = vec![("T", token.trim().to_string())];
+        form.extend(item_ids.iter().map(|id| ("i", id.clone())));
+        form.push((if add { "a" } else { "r" }, tag.as_str().to_string()));
+        let url = self.endpoint.join("reader/api/0/edit-tag")?;
+        let response = client
+            .post(url)
+            .header(reqwest::header::AUTHORIZATION, session.header())
+            .form(&form)
+            .send()
+            .await?;
+        check_status(response).await?;
+        Ok(())
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        session: &GReaderSession,
+        url: Url,
+    ) -> Result<T, PollError> {
+        let response = client
+            .get(url)
+            .header(reqwest::header::AUTHORIZATION, session.header())
+            .send()
+            .await?;
+        let bytes = check_status(response).await?.bytes().await?;
+        serde_json::from_slice(&bytes).map_err(|e| PollError::SyncResponse(e.to_string()))
+    }
+}
+
+impl GReaderSession {
+    fn header(&self) -> String {
+        format!(
+            "GoogleLogin auth={}",
+            self.auth.reveal().unwrap_or_default()
+        )
+    }
+}
+
+impl GReaderItem {
+    // Identifiant de l’article côté serveur (forme longue `tag:google.com,2005:reader/item/…`).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.has_tag(GReaderTag::Read)
+    }
+
+    pub fn is_starred(&self) -> bool {
+        self.has_tag(GReaderTag::Starred)
+    }
+
+    fn has_tag(&self, tag: GReaderTag) -> bool {
+        // Les serveurs remplacent parfois `-` par l’identifiant de l’utilisateur.
+        let suffix = tag.as_str().trim_start_matches("user/-");
+        self.categories
+            .iter()
+            .any(|c| c.starts_with("user/") && c.ends_with(suffix))
+    }
+
+    // ===
+    //
+    //
+    // Convertit l’article en FeedEntry; le GUID `greader:<id>` est opaque donc stable.
+    //
+    //
+    // ===
+    pub fn into_entry(self) -> FeedEntry {
+        let categories = collect_categories(
+            self.categories
+                .iter()
+                .filter_map(|c| c.split_once("/label/").map(|(_, label)| label)),
+        );
+        let url = self
+            .canonical
+            .into_iter()
+            .chain(self.alternate)
+            .map(|l| l.href)
+            .find(|href| !href.is_empty())
+            .unwrap_or_default();
+        let summary = self.summary.map(|s| s.content).filter(|s| !s.is_empty());
+        let content_html = self
+            .content
+            .map(|c| c.content)
+            .filter(|c| !c.is_empty())
+            .or_else(|| summary.clone());
+        FeedEntry {
+            feed_id: format!("{}{}", GREADER_ID_PREFIX, self.origin.stream_id),
+            title: self.title,
+            summary,
+            url,
+            published_at: DateTime::<Utc>::from_timestamp(self.published, 0)
+                .filter(|_| self.published > 0),
+            guid: Some(format!("{}{}", GREADER_ID_PREFIX, self.id)),
+            guid_is_permalink: Some(false),
+            author: self.author.filter(|a| !a.is_empty()),
+            categories,
+            content_html,
+            image_url: None,
+            highlighted: false,
+            updated_at: None,
+            first_seen_at: Utc::now(),
+            content_truncated: false,
+        }
+    }
+}
+
+// ===
+//
+//
+// Synchronisation avec un serveur Google Reader: abonnements, nouveaux non lus et état
+// lu/favori dans les deux sens. En cas de conflit (lu d’un côté, non lu de l’autre),
+// l’article est considéré comme lu partout. Les favoris suivent le côté qui a changé depuis
+// le dernier cycle (DataApi::synced_stars), pour qu’un retrait se propage aussi.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct GReaderSync {
+    reader: GReaderClient,
+    api: DataApi,
+}
+
+impl GReaderSync {
+    pub fn new(reader: GReaderClient, api: DataApi) -> Self {
+        Self { reader, api }
+    }
+
+    // ===
+    //
+    //
+    // URL du serveur affichable dans les logs.
+    //
+    //
+    // ===
+    pub fn redacted_endpoint(&self) -> String {
+        self.reader.redacted_endpoint()
+    }
+
+    // ===
+    //
+    //
+    // Un cycle de synchronisation; renvoie NewArticles pour les articles jamais vus.
+    //
+    //
+    // ===
+    pub async fn run(
+        &self,
+        client: &Client,
+        seen: &SeenStore,
+        cfg: &PollConfig,
+    ) -> Result<Vec<Event>, PollError> {
+        let session = self.reader.login(client).await?;
+        let remote_feeds = self.reader.subscriptions(client, &session).await?;
+        sync_feed_list(&self.api, GREADER_ID_PREFIX, &remote_feeds).await?;
+
+        let unread = self
+            .reader
+            .stream_contents(
+                client,
+                &session,
+                GREADER_READING_LIST,
+                Some(GReaderTag::Read),
+            )
+            .await?;
+        let starred: HashSet<String> = self
+            .reader
+            .stream_contents(client, &session, GReaderTag::Starred.as_str(), None)
+            .await?
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        let unread_ids: HashSet<&str> = unread.iter().map(|item| item.id.as_str()).collect();
+
+        // Réconciliation pour les articles déjà connus localement
+        let synced = self.api.synced_stars(GREADER_ID_PREFIX).await;
+        let mut known: HashSet<String> = HashSet::new();
+        let mut read_locally = Vec::new();
+        let mut read_remotely = Vec::new();
+        let mut star_remotely = Vec::new();
+        let mut unstar_remotely = Vec::new();
+        let mut now_starred = HashSet::new();
+        for feed in &remote_feeds {
+            for entry in self.api.list_articles(&feed.id).await {
+                let Some(item_id) = entry
+                    .guid
+                    .as_deref()
+                    .and_then(|g| g.strip_prefix(GREADER_ID_PREFIX))
+                    .map(str::to_string)
+                else {
+                    continue;
+                };
+                let is_read = self.api.is_read(&entry).await;
+                match (unread_ids.contains(item_id.as_str()), is_read) {
+                    (false, false) => read_locally.push(entry.clone()),
+                    (true, true) => read_remotely.push(item_id.clone()),
+                    _ => {}
+                }
+                let star = StarSync::reconcile(
+                    synced.contains(&item_id),
+                    self.api.is_starred(&entry).await,
+                    starred.contains(&item_id),
+                );
+                match star {
+                    StarSync::SetLocal(value) => {
+                        self.api.set_starred(&entry, value).await?;
+                    }
+                    StarSync::SetRemote(true) => star_remotely.push(item_id.clone()),
+                    StarSync::SetRemote(false) => unstar_remotely.push(item_id.clone()),
+                    StarSync::Agreed(_) => {}
+                }
+                if star.starred() {
+                    now_starred.insert(item_id.clone());
+                }
+                known.insert(item_id);
+            }
+        }
+        self.api.mark_read_batch(&read_locally).await?;
+        self.reader
+            .edit_tag(client, &session, &read_remotely, GReaderTag::Read, true)
+            .await?;
+        self.reader
+            .edit_tag(client, &session, &star_remotely, GReaderTag::Starred, true)
+            .await?;
+        self.reader
+            .edit_tag(
+                client,
+                &session,
+                &unstar_remotely,
+                GReaderTag::Starred,
+                false,
+            )
+            .await?;
+        self.api
+            .set_synced_stars(GREADER_ID_PREFIX, now_starred)
+            .await?;
+
+        // Nouveaux non lus
+        let mut batches: HashMap<String, Vec<FeedEntry>> = HashMap::new();
+        let mut fetched = 0;
+        for item in unread {
+            if known.contains(&item.id) {
+                continue;
+            }
+            fetched += 1;
+            let entry = item.into_entry().sanitized(cfg.max_content_bytes);
+            if seen.is_new_and_mark(&entry).await {
+                batches
+                    .entry(entry.feed_id.clone())
+                    .or_default()
+                    .push(entry);
+            }
+        }
+        seen.flush().await;
+        debug!(
+            endpoint = %self.redacted_endpoint(),
+            fetched,
+            "greader sync completed"
+        );
+
+        Ok(batches
+            .into_iter()
+            .map(|(feed_id, entries)| Event::NewArticles(feed_id, entries))
+            .collect())
+    }
+}
+
+// ===
+//
+//
+// Serveur de synchronisation actif, exécuté par le poller à chaque cycle.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub enum SyncBackend {
+    Fever(FeverSync),
+    GReader(GReaderSync),
+}
+
+impl SyncBackend {
+    pub async fn run(
+        &self,
+        client: &Client,
+        seen: &SeenStore,
+        cfg: &PollConfig,
+    ) -> Result<Vec<Event>, PollError> {
+        match self {
+            SyncBackend::Fever(sync) => sync.run(client, seen, cfg).await,
+            SyncBackend::GReader(sync) => sync.run(client, seen, cfg).await,
+        }
+    }
+
+    pub fn redacted_endpoint(&self) -> String {
+        match self {
+            SyncBackend::Fever(sync) => sync.redacted_endpoint(),
+            SyncBackend::GReader(sync) => sync.redacted_endpoint(),
+        }
+    }
+}
+
+impl From<FeverSync> for SyncBackend {
+    fn from(sync: FeverSync) -> Self {
+        SyncBackend::Fever(sync)
+    }
+}
+
+impl From<GReaderSync> for SyncBackend {
+    fn from(sync: GReaderSync) -> Self {
+        SyncBackend::GReader(sync)
+    }
+}
+
+// Contrôle du statut HTTP d’un serveur de synchronisation: 401/403 → SyncAuth.
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, PollError> {
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        return Err(PollError::SyncAuth);
+    }
+    if !status.is_success() {
+        return Err(PollError::HttpStatus(status.as_u16()));
+    }
+    Ok(response)
+}
@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+// ===
+//
+//
+// Tranche d’ancienneté d’une date de publication par rapport à `now`, à mettre en mots par
+// l’appelant (format_relative en français, tables de traduction côté GUI). Les dates dans le
+// futur (horloges décalées) tombent dans JustNow.
+//
+//
+// ===
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeTime {
+    JustNow,
+    Seconds(i64),
+    Minutes(i64),
+    Hours(i64),
+    Yesterday,
+    Days(i64),
+    LastWeek,
+    Weeks(i64),
+    Months(i64),
+    Years(i64),
+}
+
+pub fn relative_time(published: DateTime<Utc>, now: DateTime<Utc>) -> RelativeTime {
+    let elapsed = now.signed_duration_since(published);
+    let seconds = elapsed.num_seconds();
+    if seconds < 10 {
+        return RelativeTime::JustNow;
+    }
+    if seconds < 60 {
+        return RelativeTime::Seconds(seconds);
+    }
+    let minutes = elapsed.num_minutes();
+    if minutes < 60 {
+        return RelativeTime::Minutes(minutes);
+    }
+    let hours = elapsed.num_hours();
+    if hours < 24 {
+        return RelativeTime::Hours(hours);
+    }
+    let days = elapsed.num_days();
+    match days {
+        1 => RelativeTime::Yesterday,
+        2..=6 => RelativeTime::Days(days),
+        7..=13 => RelativeTime::LastWeek,
+        14..=29 => RelativeTime::Weeks(days / 7),
+        30..=364 => RelativeTime::Months(days / 30),
+        _ => RelativeTime::Years(days / 365),
+    }
+}
+
+// ===
+//
+//
+// Formate une date de publication de façon relative à `now` (« il y a 3 h », « hier »...).
+//
+//
+// ===
+pub fn format_relative(published: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    match relative_time(published, now) {
+        RelativeTime::JustNow => "à l’instant".to_string(),
+        RelativeTime::Seconds(n) => format!("il y a {} s", n),
+        RelativeTime::Minutes(n) => format!("il y a {} min", n),
+        RelativeTime::Hours(n) => format!("il y a {} h", n),
+        RelativeTime::Yesterday => "hier".to_string(),
+        RelativeTime::Days(n) => format!("il y a {} j", n),
+        RelativeTime::LastWeek => "la semaine dernière".to_string(),
+        RelativeTime::Weeks(n) => format!("il y a {} semaines", n),
+        RelativeTime::Months(n) => format!("il y a {} mois", n),
+        RelativeTime::Years(1) => "il y a 1 an".to_string(),
+        RelativeTime::Years(n) => format!("il y a {} ans", n),
+    }
+}
+
+// ===
+//
+//
+// Format complet d’une date (infobulles, vue détaillée).
+//
+//
+// ===
+pub fn format_absolute(published: DateTime<Utc>) -> String {
+    published.format("%d/%m/%Y %H:%M").to_string()
+}
+
+// ===
+//
+//
+// Parse une date ISO-8601 (dc:date des flux RDF/RSS 1.0): date-heure RFC 3339 complète,
+// ou simple date « AAAA-MM-JJ », interprétée à minuit UTC.
+//
+//
+// ===
+pub fn parse_iso8601(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| naive.and_utc())
+}
+
+// Formats avec fuseau essayés après RFC 2822 et RFC 3339 (`%#z`: +02, +0200 ou +02:00).
+const ZONED_FORMATS: [&str; 5] = [
+    "%Y-%m-%dT%H:%M:%S%.f%#z",
+    "%Y-%m-%d %H:%M:%S%.f%#z",
+    "%Y-%m-%d %H:%M:%S%.f %#z",
+    "%d %B %Y %H:%M:%S %z",
+    // Date « à la Twitter »: Mon Oct 21 07:28:00 +0000 2024
+    "%a %b %d %H:%M:%S %z %Y",
+];
+
+// Formats sans fuseau, interprétés en UTC.
+const NAIVE_FORMATS: [&str; 8] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M:%S",
+    "%d %b %Y %H:%M:%S",
+    "%d %B %Y %H:%M:%S",
+    // asctime: Mon Oct 21 07:28:00 2024
+    "%a %b %d %H:%M:%S %Y",
+];
+
+// Dates seules, à minuit UTC.
+const DATE_FORMATS: [&str; 6] = [
+    "%Y/%m/%d",
+    "%d %b %Y",
+    "%d %B %Y",
+    "%b %d, %Y",
+    "%B %d, %Y",
+    "%d %b %y",
+];
+
+// Abréviations de fuseau hors RFC 2822 rencontrées dans les flux.
+const ZONE_NAMES: [(&str, &str); 8] = [
+    ("UTC", "+0000"),
+    ("WET", "+0000"),
+    ("BST", "+0100"),
+    ("CET", "+0100"),
+    ("CEST", "+0200"),
+    ("EET", "+0200"),
+    ("EEST", "+0300"),
+    ("JST", "+0900"),
+];
+
+// ===
+//
+//
+// Date d’un flux (pubDate, lastBuildDate, dc:date), tolérante: RFC 2822 (années sur deux
+// chiffres comprises), RFC 3339 / ISO-8601, puis formats courants des flux mal formés
+// (« 2024-10-21 07:28:00 », jour de la semaine erroné, fuseau CEST…). Sans fuseau, la date
+// est lue en UTC. None seulement si rien ne correspond.
+//
+//
+// ===
+pub fn parse_feed_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc2822(&value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Some(dt) = parse_iso8601(&value) {
+        return Some(dt);
+    }
+    let value = normalize_date(&value);
+    if let Ok(dt) = DateTime::parse_from_rfc2822(&value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    ZONED_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(&value, format).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|| {
+            NAIVE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(&value, format).ok())
+                .map(|naive| naive.and_utc())
+        })
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(&value, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|naive| naive.and_utc())
+        })
+}
+
+// Retire un jour de la semaine en tête (« Mon, », souvent faux) et remplace une abréviation de
+// fuseau finale inconnue de RFC 2822 par son décalage.
+fn normalize_date(value: &str) -> String {
+    let value = match value.split_once(", ") {
+        Some((day, rest)) if day.len() >= 3 && day.chars().all(|c| c.is_ascii_alphabetic()) => rest,
+        _ => value,
+    };
+    match value.rsplit_once(' ') {
+        Some((head, zone)) => match ZONE_NAMES
+            .iter()
+            .find(|(name, _)| zone.eq_ignore_ascii_case(name))
+        {
+            Some((_, offset)) => format!("{} {}", head, offset),
+            None => value.to_string(),
+        },
+        None => value.to_string(),
+    }
+}
+
+// ===
+//
+//
+// Heures calmes (FeedConfig::quiet_hours): `at` (heure murale locale) est-elle dans la
+// fenêtre [début, fin[ ? Une fenêtre dont le début suit la fin passe minuit (23:00–07:00);
+// début et fin égaux: fenêtre vide.
+//
+//
+// ===
+pub fn in_quiet_hours(window: (NaiveTime, NaiveTime), at: NaiveTime) -> bool {
+    let (start, end) = window;
+    if start <= end {
+        start <= at && at < end
+    } else {
+        at >= start || at < end
+    }
+}
+
+// ===
+//
+//
+// Temps restant avant la fin des heures calmes si `now` est dedans, sinon None. Le calcul suit
+// l’heure murale: la nuit d’un changement d’heure, il peut être décalé d’une heure (le poller
+// revérifie la fenêtre à l’échéance).
+//
+//
+// ===
+pub fn quiet_hours_remaining<Tz: TimeZone>(
+    window: (NaiveTime, NaiveTime),
+    now: &DateTime<Tz>,
+) -> Option<Duration> {
+    let local = now.naive_local();
+    if !in_quiet_hours(window, local.time()) {
+        return None;
+    }
+    let mut end = local.date().and_time(window.1);
+    if end <= local {
+        end += chrono::Duration::days(1);
+    }
+    (end - local).to_std().ok()
+}
@@ -0,0 +1,256 @@
+// ===
+//
+//
+// Adresses de sites connus converties en flux: un subreddit, une chaîne YouTube ou un dépôt
+// GitHub collés dans le formulaire d’ajout mènent à leur flux RSS/Atom. Les conversions sont
+// pures, sauf pour un identifiant YouTube (@pseudo, /c/…): la page de la chaîne donne alors
+// l’identifiant `UC…` attendu par le flux.
+//
+//
+// ===
+
+use std::time::Duration;
+
+use url::Url;
+
+use crate::autodiscovery::find_feed_links;
+use crate::content_extractor::attr_value;
+use crate::error::PollError;
+use crate::http::download_capped;
+use crate::sanitize::{find_tag_end, tag_name};
+
+// Taille maximale lue d’une page de chaîne YouTube (pages lourdes en JSON embarqué).
+const MAX_CHANNEL_PAGE_BYTES: usize = 4 * 1024 * 1024;
+
+// Premiers segments de chemin GitHub qui ne sont pas des comptes.
+const GITHUB_RESERVED: [&str; 12] = [
+    "about",
+    "apps",
+    "collections",
+    "enterprise",
+    "explore",
+    "features",
+    "login",
+    "marketplace",
+    "orgs",
+    "settings",
+    "sponsors",
+    "topics",
+];
+
+// Flux proposés pour un dépôt GitHub.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GitHubFeed {
+    #[default]
+    Releases,
+    Commits,
+    Tags,
+}
+
+impl GitHubFeed {
+    pub const ALL: [GitHubFeed; 3] = [GitHubFeed::Releases, GitHubFeed::Commits, GitHubFeed::Tags];
+
+    fn file(self) -> &'static str {
+        match self {
+            GitHubFeed::Releases => "releases.atom",
+            GitHubFeed::Commits => "commits.atom",
+            GitHubFeed::Tags => "tags.atom",
+        }
+    }
+}
+
+// Conversion reconnue pour une adresse collée.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlRewrite {
+    // Flux déduit de l’adresse seule.
+    Feed(Url),
+    // Page de chaîne YouTube dont l’identifiant reste à lire (resolve_rewrite).
+    YouTubeHandle(Url),
+    // Dépôt GitHub: flux des versions, des commits ou des tags, au choix.
+    GitHub { owner: String, repo: String },
+}
+
+// ===
+//
+//
+// Conversion d’une adresse de site connu (None pour les autres adresses et pour celles qui
+// sont déjà des flux).
+//
+//
+// ===
+pub fn rewrite_feed_url(url: &Url) -> Option<UrlRewrite> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?.to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    match host {
+        "reddit.com" | "old.reddit.com" | "new.reddit.com" => reddit_feed(&segments),
+        "youtube.com" | "m.youtube.com" => youtube_feed(url, &segments),
+        "github.com" => github_repo(&segments),
+        _ => None,
+    }
+}
+
+// Subreddit, utilisateur ou fil de discussion: le même chemin suivi de `/.rss`.
+fn reddit_feed(segments: &[&str]) -> Option<UrlRewrite> {
+    let (kind, rest) = segments.split_first()?;
+    let kind = match *kind {
+        "r" => "r",
+        "u" | "user" => "user",
+        _ => return None,
+    };
+    let last = rest.last()?;
+    if last.ends_with(".rss") || last.ends_with(".json") {
+        return None;
+    }
+    let url = format!("https://www.reddit.com/{}/{}/.rss", kind, rest.join("/"));
+    Url::parse(&url).ok().map(UrlRewrite::Feed)
+}
+
+fn youtube_feed(url: &Url, segments: &[&str]) -> Option<UrlRewrite> {
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.into_owned())
+    };
+    match segments {
+        ["channel", id, ..] if is_channel_id(id) => {
+            Some(UrlRewrite::Feed(youtube_channel_feed(id)))
+        }
+        ["user", name, ..] => youtube_videos_feed("user", name),
+        ["playlist"] => youtube_videos_feed("playlist_id", &query("list")?),
+        [handle, ..] if handle.starts_with('@') && handle.len() > 1 => youtube_page(handle),
+        ["c", name, ..] => youtube_page(&format!("c/{}", name)),
+        _ => None,
+    }
+}
+
+fn youtube_videos_feed(key: &str, value: &str) -> Option<UrlRewrite> {
+    let mut url = Url::parse("https://www.youtube.com/feeds/videos.xml").ok()?;
+    url.query_pairs_mut().append_pair(key, value);
+    Some(UrlRewrite::Feed(url))
+}
+
+fn youtube_page(path: &str) -> Option<UrlRewrite> {
+    let url = Url::parse(&format!("https://www.youtube.com/{}", path)).ok()?;
+    Some(UrlRewrite::YouTubeHandle(url))
+}
+
+fn github_repo(segments: &[&str]) -> Option<UrlRewrite> {
+    let [owner, repo, rest @ ..] = segments else {
+        return None;
+    };
+    if GITHUB_RESERVED.contains(owner) || rest.last().is_some_and(|s| s.ends_with(".atom")) {
+        return None;
+    }
+    Some(UrlRewrite::GitHub {
+        owner: owner.to_string(),
+        repo: repo.trim_end_matches(".git").to_string(),
+    })
+}
+
+// Flux Atom d’un dépôt GitHub.
+pub fn github_feed_url(owner: &str, repo: &str, kind: GitHubFeed) -> Url {
+    let mut url = Url::parse("https://github.com/").expect("static url");
+    url.path_segments_mut()
+        .expect("base url")
+        .extend([owner, repo, kind.file()]);
+    url
+}
+
+// Flux des vidéos d’une chaîne YouTube.
+pub fn youtube_channel_feed(channel_id: &str) -> Url {
+    let mut url = Url::parse("https://www.youtube.com/feeds/videos.xml").expect("static url");
+    url.query_pairs_mut().append_pair("channel_id", channel_id);
+    url
+}
+
+// Identifiant de chaîne: `UC` suivi de 22 caractères base64 « URL ».
+fn is_channel_id(id: &str) -> bool {
+    id.len() == 24
+        && id.starts_with("UC")
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+// ===
+//
+//
+// Identifiant de la chaîne présentée par une page YouTube: flux annoncé, lien canonique
+// `/channel/UC…`, puis métadonnées JSON de la page (`externalId`, `channelId`).
+//
+//
+// ===
+pub fn youtube_channel_id(html: &str) -> Option<String> {
+    let base = Url::parse("https://www.youtube.com/").expect("static url");
+    let announced = find_feed_links(html, &base).into_iter().find_map(|link| {
+        link.query_pairs()
+            .find(|(k, v)| k == "channel_id" && is_channel_id(v))
+            .map(|(_, v)| v.into_owned())
+    });
+    announced
+        .or_else(|| canonical_channel_id(html))
+        .or_else(|| {
+            ["\"externalId\":\"", "\"channelId\":\""]
+                .iter()
+                .find_map(|marker| {
+                    html.match_indices(marker).find_map(|(i, _)| {
+                        let id = html.get(i + marker.len()..i + marker.len() + 24)?;
+                        is_channel_id(id).then(|| id.to_string())
+                    })
+                })
+        })
+}
+
+fn canonical_channel_id(html: &str) -> Option<String> {
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        let end = find_tag_end(rest)?;
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        if tag_name(tag) != "link" || attr_value(tag, "rel").is_none_or(|r| r != "canonical") {
+            continue;
+        }
+        let href = attr_value(tag, "href")?;
+        let id = href.split("/channel/").nth(1)?.split(['/', '?']).next()?;
+        return is_channel_id(id).then(|| id.to_string());
+    }
+    None
+}
+
+// ===
+//
+//
+// Adresse du flux pour une conversion. Seule la page d’un identifiant YouTube est téléchargée
+// (une tentative, bornée par `timeout`); sans identifiant de chaîne, elle est rejetée comme
+// page HTML sans flux.
+//
+//
+// ===
+pub async fn resolve_rewrite(
+    client: &reqwest::Client,
+    rewrite: &UrlRewrite,
+    github: GitHubFeed,
+    timeout: Duration,
+) -> Result<Url, PollError> {
+    match rewrite {
+        UrlRewrite::Feed(url) => Ok(url.clone()),
+        UrlRewrite::GitHub { owner, repo } => Ok(github_feed_url(owner, repo, github)),
+        UrlRewrite::YouTubeHandle(page) => {
+            let html = download_capped(client, page, MAX_CHANNEL_PAGE_BYTES, timeout).await?;
+            youtube_channel_id(&String::from_utf8_lossy(&html))
+                .map(|id| youtube_channel_feed(&id))
+                .ok_or(PollError::NotAFeed {
+                    content_type: Some("text/html".to_string()),
+                    looks_like_html: true,
+                })
+        }
+    }
+}
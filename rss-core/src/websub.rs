@@ -0,0 +1,666 @@
+// ===
+//
+//
+// Abonnements WebSub (ex-PubSubHubbub): un flux qui annonce un hub (`<link rel="hub">` et
+// `<link rel="self">`) est abonné auprès de ce hub avec une URL de rappel servie par un petit
+// serveur HTTP intégré. Le hub vérifie l’abonnement (GET avec `hub.challenge`), puis pousse
+// le flux à chaque publication (POST signé HMAC avec le secret de l’abonnement). Le contenu
+// poussé suit le même chemin que le polling (parsing, SeenStore, Event). L’abonnement est
+// renouvelé avant son échéance; un flux abonné est relevé moins souvent (`should_poll`).
+// Les abonnements ne sont pas persistés: ils sont redemandés au premier relevé.
+//
+//
+// ===
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{header, Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use reqwest::Client;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use url::Url;
+
+use crate::config::WebSubConfig;
+use crate::error::WebSubError;
+use crate::feed::{FeedDescriptor, SharedFeedList};
+use crate::poller::{pushed_events, Event, PollConfig};
+use crate::redact::redact_url_str;
+use crate::storage::SeenStore;
+
+// Segment de chemin des URL de rappel: `<base>/websub/<jeton>`.
+const CALLBACK_SEGMENT: &str = "/websub/";
+
+// Marge de renouvellement maximale avant l’échéance d’un abonnement.
+const MAX_RENEWAL_MARGIN: Duration = Duration::from_secs(3600);
+
+// Délai accordé à un hub pour répondre à une demande d’abonnement.
+const HUB_REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+// ===
+//
+//
+// Liens WebSub d’un flux: le hub et l’URL canonique du flux (`topic`, lien `self`).
+//
+//
+// ===
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSubLinks {
+    pub hub: String,
+    pub topic: String,
+}
+
+impl WebSubLinks {
+    // Premier hub et premier lien `self` parmi des couples (rel, href); None s’il en manque un.
+    pub fn from_links<'a>(links: impl IntoIterator<Item = (&'a str, &'a str)>) -> Option<Self> {
+        let mut hub = None;
+        let mut topic = None;
+        for (rel, href) in links {
+            let href = href.trim();
+            if href.is_empty() {
+                continue;
+            }
+            match rel.trim() {
+                "hub" if hub.is_none() => hub = Some(href.to_string()),
+                "self" if topic.is_none() => topic = Some(href.to_string()),
+                _ => {}
+            }
+        }
+        Some(Self {
+            hub: hub?,
+            topic: topic?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionState {
+    // Demande envoyée, vérification du hub attendue.
+    Pending,
+    Active,
+    // Refusé par le hub (`hub.mode=denied`): pas de nouvel essai tant que les liens sont les mêmes.
+    Denied,
+    // Le hub n’a pas accepté la demande: nouvel essai au prochain relevé.
+    Failed,
+}
+
+// État d’un abonnement, pour l’affichage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionInfo {
+    pub feed_id: String,
+    pub hub: String,
+    pub topic: String,
+    pub state: SubscriptionState,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+struct Subscription {
+    links: WebSubLinks,
+    // Dernier segment de l’URL de rappel, propre à l’abonnement.
+    token: String,
+    secret: String,
+    state: SubscriptionState,
+    expires_at: Option<DateTime<Utc>>,
+    // Incrémenté à chaque vérification: un renouvellement programmé pour une ancienne
+    // échéance est abandonné.
+    generation: u64,
+    skipped_cycles: u32,
+}
+
+impl Subscription {
+    fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.state == SubscriptionState::Active && self.expires_at.is_some_and(|at| at > now)
+    }
+}
+
+struct Inner {
+    config: WebSubConfig,
+    callback_base: String,
+    local_addr: SocketAddr,
+    feeds: SharedFeedList,
+    seen: SeenStore,
+    // Rendu par stop(): le canal des évènements peut alors se fermer.
+    events: Mutex<Option<broadcast::Sender<Event>>>,
+    // Réglages de parsing et de politique HTTP (sans `push`, pour ne pas se référencer).
+    poll_config: PollConfig,
+    client: Mutex<Client>,
+    subscriptions: Mutex<HashMap<String, Subscription>>,
+    server: Mutex<Option<JoinHandle<()>>>,
+    rng: SystemRandom,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if let Some(server) = self.server.lock().unwrap().take() {
+            server.abort();
+        }
+    }
+}
+
+// ===
+//
+//
+// Gestionnaire WebSub: serveur de rappel et abonnements par flux. Clonable (état partagé);
+// le poller le reçoit dans `PollConfig::push`.
+//
+//
+// ===
+#[derive(Clone)]
+pub struct WebSub {
+    inner: Arc<Inner>,
+}
+
+impl std::fmt::Debug for WebSub {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSub")
+            .field("local_addr", &self.inner.local_addr)
+            .field("callback_base", &self.inner.callback_base)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebSub {
+    // ===
+    //
+    //
+    // Démarre le serveur de rappel sur `config.bind_address` (port 0: port libre choisi par
+    // le système). Les contenus poussés sont traités comme un relevé et leurs évènements
+    // envoyés sur `events`.
+    //
+    //
+    // ===
+    pub async fn start(
+        config: &WebSubConfig,
+        feeds: SharedFeedList,
+        seen: SeenStore,
+        events: broadcast::Sender<Event>,
+        client: Client,
+        poll_config: PollConfig,
+    ) -> Result<Self, WebSubError> {
+        let bind_error = |source| WebSubError::Bind {
+            address: config.bind_address.clone(),
+            source,
+        };
+        let listener = TcpListener::bind(&config.bind_address)
+            .await
+            .map_err(bind_error)?;
+        let local_addr = listener.local_addr().map_err(bind_error)?;
+        let callback_base = match &config.callback_base_url {
+            Some(base) => {
+                let url = Url::parse(base.trim())
+                    .ok()
+                    .filter(|url| matches!(url.scheme(), "http" | "https"))
+                    .ok_or_else(|| WebSubError::InvalidCallback(base.clone()))?;
+                url.as_str().trim_end_matches('/').to_string()
+            }
+            None => format!("http://{}", local_addr),
+        };
+        let inner = Arc::new(Inner {
+            config: config.clone(),
+            callback_base,
+            local_addr,
+            feeds,
+            seen,
+            events: Mutex::new(Some(events)),
+            poll_config: PollConfig {
+                push: None,
+                ..poll_config
+            },
+            client: Mutex::new(client),
+            subscriptions: Mutex::new(HashMap::new()),
+            server: Mutex::new(None),
+            rng: SystemRandom::new(),
+        });
+        let server = tokio::spawn(serve(listener, Arc::downgrade(&inner)));
+        *inner.server.lock().unwrap() = Some(server);
+        info!(address = %local_addr, "websub callback server listening");
+        Ok(Self { inner })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.inner.local_addr
+    }
+
+    // Arrête le serveur de rappel (les abonnements expireront côté hub); plus aucun contenu
+    // poussé n’est transmis.
+    pub fn stop(&self) {
+        if let Some(server) = self.inner.server.lock().unwrap().take() {
+            server.abort();
+        }
+        self.inner.events.lock().unwrap().take();
+    }
+
+    // Abonnements connus, triés par flux.
+    pub fn subscriptions(&self) -> Vec<SubscriptionInfo> {
+        let subscriptions = self.inner.subscriptions.lock().unwrap();
+        let mut infos: Vec<SubscriptionInfo> = subscriptions
+            .iter()
+            .map(|(feed_id, sub)| SubscriptionInfo {
+                feed_id: feed_id.clone(),
+                hub: sub.links.hub.clone(),
+                topic: sub.links.topic.clone(),
+                state: sub.state,
+                expires_at: sub.expires_at,
+            })
+            .collect();
+        infos.sort_by(|a, b| a.feed_id.cmp(&b.feed_id));
+        infos
+    }
+
+    // ===
+    //
+    //
+    // Appelé par le poller à chaque relevé réussi d’un flux qui annonce un hub: demande un
+    // abonnement s’il n’y en a pas, si les liens ont changé ou si la demande précédente a
+    // échoué.
+    //
+    //
+    // ===
+    pub fn observe(&self, client: &Client, feed: &FeedDescriptor, links: &WebSubLinks) {
+        *self.inner.client.lock().unwrap() = client.clone();
+        {
+            let mut subscriptions = self.inner.subscriptions.lock().unwrap();
+            let wanted = match subscriptions.get(&feed.id) {
+                None => true,
+                Some(sub) if sub.links != *links => true,
+                Some(sub) => sub.state == SubscriptionState::Failed,
+            };
+            if !wanted {
+                return;
+            }
+            let subscription = Subscription {
+                links: links.clone(),
+                token: self.random_hex(16),
+                secret: self.random_hex(32),
+                state: SubscriptionState::Pending,
+                expires_at: None,
+                generation: 0,
+                skipped_cycles: 0,
+            };
+            subscriptions.insert(feed.id.clone(), subscription);
+        }
+        let websub = self.clone();
+        let feed_id = feed.id.clone();
+        tokio::spawn(async move { websub.request_subscription(&feed_id).await });
+    }
+
+    // ===
+    //
+    //
+    // Vrai si le flux doit être relevé à ce cycle: toujours sans abonnement actif, sinon un
+    // cycle sur `poll_interval_factor` (filet de sécurité si le hub cesse de pousser).
+    //
+    //
+    // ===
+    pub fn should_poll(&self, feed_id: &str) -> bool {
+        let mut subscriptions = self.inner.subscriptions.lock().unwrap();
+        let Some(sub) = subscriptions.get_mut(feed_id) else {
+            return true;
+        };
+        if !sub.is_active_at(Utc::now()) {
+            return true;
+        }
+        sub.skipped_cycles += 1;
+        if sub.skipped_cycles >= self.inner.config.poll_interval_factor.max(1) {
+            sub.skipped_cycles = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn random_hex(&self, len: usize) -> String {
+        let mut bytes = vec![0u8; len];
+        self.inner
+            .rng
+            .fill(&mut bytes)
+            .expect("system random generator failed");
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    // Envoie la demande d’abonnement au hub; l’abonnement devient actif à la vérification.
+    async fn request_subscription(&self, feed_id: &str) {
+        let request = {
+            let subscriptions = self.inner.subscriptions.lock().unwrap();
+            subscriptions.get(feed_id).map(|sub| {
+                (
+                    sub.links.clone(),
+                    format!(
+                        "{}{}{}",
+                        self.inner.callback_base, CALLBACK_SEGMENT, sub.token
+                    ),
+                    sub.secret.clone(),
+                    sub.token.clone(),
+                )
+            })
+        };
+        let Some((links, callback, secret, token)) = request else {
+            return;
+        };
+        let result = self.post_to_hub(&links, &callback, &secret).await;
+        let mut subscriptions = self.inner.subscriptions.lock().unwrap();
+        let Some(sub) = subscriptions
+            .get_mut(feed_id)
+            .filter(|sub| sub.token == token)
+        else {
+            return;
+        };
+        match result {
+            Ok(()) => {
+                debug!(feed_id, hub = %redact_url_str(&links.hub), "websub subscription requested")
+            }
+            Err(reason) => {
+                warn!(feed_id, hub = %redact_url_str(&links.hub), %reason, "websub subscription request failed");
+                sub.state = SubscriptionState::Failed;
+            }
+        }
+    }
+
+    async fn post_to_hub(
+        &self,
+        links: &WebSubLinks,
+        callback: &str,
+        secret: &str,
+    ) -> Result<(), String> {
+        let hub = Url::parse(&links.hub).map_err(|e| e.to_string())?;
+        if !self.inner.poll_config.allow_http.allows(&hub) {
+            return Err("hub URL not allowed by network.allow_http".into());
+        }
+        let lease_seconds = self.inner.config.lease_seconds.to_string();
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("hub.mode", "subscribe")
+            .append_pair("hub.topic", &links.topic)
+            .append_pair("hub.callback", callback)
+            .append_pair("hub.lease_seconds", &lease_seconds)
+            .append_pair("hub.secret", secret)
+            .finish();
+        let client = self.inner.client.lock().unwrap().clone();
+        let response = client
+            .post(hub)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(body)
+            .timeout(HUB_REQUEST_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| e.without_url().to_string())?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(format!("HTTP {}", status.as_u16()))
+        }
+    }
+
+    // ===
+    //
+    //
+    // Vérification d’intention du hub (GET): répond le `hub.challenge` si le jeton et le
+    // `hub.topic` correspondent à un abonnement demandé, puis programme le renouvellement.
+    //
+    //
+    // ===
+    fn verify(&self, token: &str, query: &str) -> Response<Full<Bytes>> {
+        let params: HashMap<String, String> = url::form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+        let param = |name: &str| params.get(name).map(String::as_str);
+        let mut subscriptions = self.inner.subscriptions.lock().unwrap();
+        let Some((feed_id, sub)) = subscriptions.iter_mut().find(|(_, sub)| sub.token == token)
+        else {
+            return reply(StatusCode::NOT_FOUND, "");
+        };
+        match param("hub.mode") {
+            Some("subscribe") => {
+                let (Some(topic), Some(challenge)) = (param("hub.topic"), param("hub.challenge"))
+                else {
+                    return reply(StatusCode::BAD_REQUEST, "");
+                };
+                if topic != sub.links.topic {
+                    return reply(StatusCode::NOT_FOUND, "");
+                }
+                let lease_seconds = param("hub.lease_seconds")
+                    .and_then(|lease| lease.parse::<u64>().ok())
+                    .unwrap_or(self.inner.config.lease_seconds)
+                    .max(1);
+                let lease = Duration::from_secs(lease_seconds);
+                sub.state = SubscriptionState::Active;
+                sub.expires_at = Some(Utc::now() + chrono::Duration::seconds(lease_seconds as i64));
+                sub.generation += 1;
+                info!(feed_id, lease_seconds, "websub subscription verified");
+                self.schedule_renewal(feed_id.clone(), sub.generation, lease);
+                reply(StatusCode::OK, challenge)
+            }
+            Some("denied") => {
+                warn!(
+                    feed_id,
+                    reason = param("hub.reason").unwrap_or_default(),
+                    "websub subscription denied by hub"
+                );
+                sub.state = SubscriptionState::Denied;
+                sub.expires_at = None;
+                reply(StatusCode::OK, "")
+            }
+            // Aucune désinscription n’est jamais demandée.
+            Some("unsubscribe") => reply(StatusCode::NOT_FOUND, ""),
+            _ => reply(StatusCode::BAD_REQUEST, ""),
+        }
+    }
+
+    // Renouvelle l’abonnement un dixième de la durée (au plus une heure) avant l’échéance.
+    fn schedule_renewal(&self, feed_id: String, generation: u64, lease: Duration) {
+        let margin = (lease / 10).min(MAX_RENEWAL_MARGIN);
+        let delay = lease - margin;
+        let inner = Arc::downgrade(&self.inner);
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let Some(inner) = inner.upgrade() else {
+                return;
+            };
+            WebSub { inner }.renew(&feed_id, generation).await;
+        });
+    }
+
+    async fn renew(&self, feed_id: &str, generation: u64) {
+        let followed = self
+            .inner
+            .feeds
+            .read()
+            .await
+            .iter()
+            .any(|f| f.id == feed_id);
+        {
+            let mut subscriptions = self.inner.subscriptions.lock().unwrap();
+            let current = subscriptions
+                .get(feed_id)
+                .is_some_and(|sub| sub.generation == generation);
+            if !current {
+                return;
+            }
+            if !followed {
+                subscriptions.remove(feed_id);
+                return;
+            }
+        }
+        debug!(feed_id, "renewing websub subscription");
+        self.request_subscription(feed_id).await;
+    }
+
+    // ===
+    //
+    //
+    // Contenu poussé par le hub (POST): ignoré (202 quand même, comme le veut la
+    // spécification) si la signature `X-Hub-Signature` ne correspond pas au secret, sinon
+    // traité comme un relevé du flux.
+    //
+    //
+    // ===
+    async fn receive(&self, token: &str, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        let subscription = {
+            let subscriptions = self.inner.subscriptions.lock().unwrap();
+            subscriptions
+                .iter()
+                .find(|(_, sub)| sub.token == token && sub.state == SubscriptionState::Active)
+                .map(|(feed_id, sub)| (feed_id.clone(), sub.secret.clone()))
+        };
+        let Some((feed_id, secret)) = subscription else {
+            return reply(StatusCode::NOT_FOUND, "");
+        };
+        let header = |name| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let content_type = header(header::CONTENT_TYPE);
+        let signature = header(header::HeaderName::from_static("x-hub-signature"));
+        let limit = self.inner.poll_config.max_feed_bytes;
+        let body = match Limited::new(request.into_body(), limit).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(e) => {
+                warn!(feed_id, error = %e, "websub push rejected");
+                return reply(StatusCode::PAYLOAD_TOO_LARGE, "");
+            }
+        };
+        if !signature_matches(&secret, signature.as_deref(), &body) {
+            warn!(feed_id, "websub push ignored: missing or invalid signature");
+            return reply(StatusCode::ACCEPTED, "");
+        }
+        let feed = self
+            .inner
+            .feeds
+            .read()
+            .await
+            .iter()
+            .find(|f| f.id == feed_id)
+            .cloned();
+        let Some(feed) = feed else {
+            self.inner.subscriptions.lock().unwrap().remove(&feed_id);
+            return reply(StatusCode::GONE, "");
+        };
+        let client = self.inner.client.lock().unwrap().clone();
+        let events = pushed_events(
+            &feed,
+            body,
+            content_type,
+            &self.inner.poll_config,
+            &client,
+            &self.inner.seen,
+        )
+        .await;
+        let sender = self.inner.events.lock().unwrap().clone();
+        match (events, sender) {
+            (Ok(events), Some(sender)) => {
+                for event in events {
+                    if sender.send(event).is_err() {
+                        debug!("no event subscriber; pushed event dropped");
+                    }
+                }
+            }
+            (Ok(_), None) => debug!(feed_id, "websub stopped; pushed content dropped"),
+            (Err(e), _) => warn!(feed_id, error = %e, "websub push could not be parsed"),
+        }
+        reply(StatusCode::ACCEPTED, "")
+    }
+}
+
+async fn serve(listener: TcpListener, inner: Weak<Inner>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!(error = %e, "websub callback server: accept failed");
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+        let inner = inner.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let inner = inner.clone();
+                async move { Ok::<_, Infallible>(handle(inner, request).await) }
+            });
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!(error = %e, "websub callback connection failed");
+            }
+        });
+    }
+}
+
+async fn handle(inner: Weak<Inner>, request: Request<Incoming>) -> Response<Full<Bytes>> {
+    let Some(inner) = inner.upgrade() else {
+        return reply(StatusCode::SERVICE_UNAVAILABLE, "");
+    };
+    let websub = WebSub { inner };
+    let token = match request.uri().path().rsplit_once(CALLBACK_SEGMENT) {
+        Some((_, token)) if !token.is_empty() && !token.contains('/') => token.to_string(),
+        _ => return reply(StatusCode::NOT_FOUND, ""),
+    };
+    match *request.method() {
+        Method::GET => websub.verify(&token, request.uri().query().unwrap_or_default()),
+        Method::POST => websub.receive(&token, request).await,
+        _ => reply(StatusCode::METHOD_NOT_ALLOWED, ""),
+    }
+}
+
+fn reply(status: StatusCode, body: &str) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response
+}
+
+// ===
+//
+//
+// Vérifie `X-Hub-Signature: <algo>=<hex>` (HMAC du corps avec le secret de l’abonnement);
+// sha1, sha256, sha384 et sha512 sont acceptés.
+//
+//
+// ===
+fn signature_matches(secret: &str, signature: Option<&str>, body: &[u8]) -> bool {
+    let Some((method, hex)) = signature.and_then(|s| s.trim().split_once('=')) else {
+        return false;
+    };
+    let algorithm = match method.to_ascii_lowercase().as_str() {
+        "sha1" => hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+        "sha256" => hmac::HMAC_SHA256,
+        "sha384" => hmac::HMAC_SHA384,
+        "sha512" => hmac::HMAC_SHA512,
+        _ => return false,
+    };
+    let Some(expected) = decode_hex(hex) else {
+        return false;
+    };
+    let key = hmac::Key::new(algorithm, secret.as_bytes());
+    hmac::verify(&key, body, &expected).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use tokio::sync::mpsc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    adaptive_interval, shared_feed_list, spawn_poller, DataApi, FeedConfig, FeedDescriptor,
+    FetchOutcome, PollConfig, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>Lancement</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#;
+
+const BASE: Duration = Duration::from_secs(30 * 60);
+const MAX: Duration = Duration::from_secs(12 * 3600);
+
+fn days_ago(now: DateTime<Utc>, days: i64) -> Option<DateTime<Utc>> {
+    Some(now - chrono::Duration::days(days))
+}
+
+fn outcome(new_items: usize) -> FetchOutcome {
+    FetchOutcome {
+        fetched_at: Utc::now(),
+        success: true,
+        http_status: Some(200),
+        new_items,
+        fetched_items: 1,
+        filtered_items: 1 - new_items,
+        attempts: 1,
+        duration_ms: 10,
+        error: None,
+        error_kind: None,
+        bytes_downloaded: 0,
+    }
+}
+
+#[test]
+fn interval_stretches_at_each_silence_threshold() {
+    let now = Utc::now();
+    assert_eq!(adaptive_interval(BASE, None, now, MAX), BASE);
+    assert_eq!(adaptive_interval(BASE, days_ago(now, 0), now, MAX), BASE);
+    assert_eq!(adaptive_interval(BASE, days_ago(now, 6), now, MAX), BASE);
+    assert_eq!(
+        adaptive_interval(BASE, days_ago(now, 7), now, MAX),
+        BASE * 2
+    );
+    assert_eq!(
+        adaptive_interval(BASE, days_ago(now, 29), now, MAX),
+        BASE * 2
+    );
+    assert_eq!(
+        adaptive_interval(BASE, days_ago(now, 30), now, MAX),
+        BASE * 4
+    );
+    assert_eq!(
+        adaptive_interval(BASE, days_ago(now, 365), now, MAX),
+        BASE * 4
+    );
+}
+
+#[test]
+fn interval_is_capped_but_never_below_the_base() {
+    let now = Utc::now();
+    let hour = Duration::from_secs(3600);
+    assert_eq!(adaptive_interval(BASE, days_ago(now, 30), now, hour), hour);
+    assert_eq!(adaptive_interval(BASE, days_ago(now, 7), now, hour), hour);
+    let short = Duration::from_secs(60);
+    assert_eq!(adaptive_interval(BASE, days_ago(now, 30), now, short), BASE);
+}
+
+#[test]
+fn effective_interval_is_opt_in() {
+    let now = Utc::now();
+    let mut feed = FeedDescriptor::new("f", "F", "https://example.org/feed");
+    feed.last_new_article_at = days_ago(now, 40);
+
+    let mut feeds = FeedConfig::default();
+    let cfg = PollConfig::from_feed_config(&feeds);
+    assert!(!cfg.adaptive_polling);
+    assert_eq!(cfg.effective_interval(&feed, now), cfg.interval);
+
+    feeds.adaptive_polling = true;
+    feeds.max_adaptive_interval_minutes = 60;
+    let cfg = PollConfig::from_feed_config(&feeds);
+    assert_eq!(cfg.max_adaptive_interval, Duration::from_secs(3600));
+    assert_eq!(
+        cfg.effective_interval(&feed, now),
+        Duration::from_secs(3600)
+    );
+}
+
+#[tokio::test]
+async fn new_articles_reset_the_silence() {
+    let feeds = shared_feed_list(vec![FeedDescriptor::new("f", "F", "https://e/feed")]);
+    let api = DataApi::in_memory(feeds.clone());
+
+    api.record_fetch("f", outcome(0)).await;
+    assert_eq!(feeds.read().await[0].last_new_article_at, None);
+
+    let fresh = outcome(1);
+    api.record_fetch("f", fresh.clone()).await;
+    assert_eq!(
+        feeds.read().await[0].last_new_article_at,
+        Some(fresh.fetched_at)
+    );
+}
+
+#[tokio::test]
+async fn silent_feeds_are_skipped_by_scheduled_cycles() {
+    let server = MockServer::start().await;
+    for route in ["/silent", "/active"] {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_string(RSS))
+            .mount(&server)
+            .await;
+    }
+    let mut silent = FeedDescriptor::new("silent", "Silent", format!("{}/silent", server.uri()));
+    silent.last_new_article_at = days_ago(Utc::now(), 40);
+    let active = FeedDescriptor::new("active", "Active", format!("{}/active", server.uri()));
+    let config = PollConfig {
+        interval: Duration::from_millis(100),
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        adaptive_polling: true,
+        ..PollConfig::default()
+    };
+    let (tx, _rx) = mpsc::channel(64);
+    let handle = spawn_poller(
+        shared_feed_list(vec![silent, active]),
+        config,
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+
+    // Four times the base interval: the silent feed waits 400 ms after its first poll.
+    tokio::time::sleep(Duration::from_millis(280)).await;
+    handle.stop().await.expect("stop poller");
+    let requests = server.received_requests().await.unwrap();
+    let count = |route: &str| requests.iter().filter(|r| r.url.path() == route).count();
+    assert_eq!(count("/silent"), 1);
+    assert!(count("/active") >= 2);
+}
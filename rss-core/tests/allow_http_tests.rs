@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, preview_feed, AllowHttp, AppConfig, Event, FeedDescriptor, NetworkConfig,
+    PollConfig, PollError, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>A</title><link>https://example.org/a</link><guid>a</guid></item></channel></rss>"#;
+
+fn allows(policy: AllowHttp, url: &str) -> bool {
+    policy.allows(&Url::parse(url).unwrap())
+}
+
+fn descriptor(url: String, allow_insecure: bool) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "intranet".into(),
+        title: "Intranet".into(),
+        url,
+        allow_insecure,
+        ..Default::default()
+    }
+}
+
+async fn serve() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(RSS))
+        .mount(&server)
+        .await;
+    server
+}
+
+// Polls the feed once under `policy` and returns whether the fetch succeeded.
+async fn fetch_succeeds(feed: FeedDescriptor, policy: AllowHttp) -> bool {
+    let cfg = PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 1,
+        allow_http: policy,
+        ..PollConfig::default()
+    };
+    let events = poll_once(&[feed], &cfg, &Client::new(), &SeenStore::in_memory())
+        .await
+        .0;
+    events
+        .iter()
+        .any(|e| matches!(e, Event::FetchCompleted(_, o) if o.success))
+}
+
+#[test]
+fn https_is_always_allowed_and_other_schemes_never() {
+    for policy in [
+        AllowHttp::Never,
+        AllowHttp::LoopbackOnly,
+        AllowHttp::PrivateNetworks,
+        AllowHttp::Always,
+    ] {
+        assert!(allows(policy, "https://example.org/feed"));
+        assert!(!allows(policy, "ftp://127.0.0.1/feed"));
+    }
+    assert!(!allows(AllowHttp::Never, "http://localhost/feed"));
+    assert!(allows(AllowHttp::Always, "http://example.org/feed"));
+}
+
+#[test]
+fn loopback_only_accepts_localhost_and_loopback_ips() {
+    let policy = AllowHttp::LoopbackOnly;
+    assert_eq!(AllowHttp::default(), policy);
+    assert!(allows(policy, "http://localhost:8080/feed"));
+    assert!(allows(policy, "http://127.0.0.2/feed"));
+    assert!(allows(policy, "http://[::1]/feed"));
+    assert!(!allows(policy, "http://192.168.1.10/feed"));
+    assert!(!allows(policy, "http://example.org/feed"));
+}
+
+#[test]
+fn private_networks_accepts_rfc1918_and_ula_literals_only() {
+    let policy = AllowHttp::PrivateNetworks;
+    assert!(allows(policy, "http://localhost/feed"));
+    assert!(allows(policy, "http://10.1.2.3/feed"));
+    assert!(allows(policy, "http://172.16.0.1/feed"));
+    assert!(allows(policy, "http://192.168.1.10/feed"));
+    assert!(allows(policy, "http://[fd12:3456::1]/feed"));
+    assert!(!allows(policy, "http://172.32.0.1/feed"));
+    assert!(!allows(policy, "http://8.8.8.8/feed"));
+    assert!(!allows(policy, "http://[fe80::1]/feed"));
+    // Host names are not resolved.
+    assert!(!allows(policy, "http://intranet.local/feed"));
+}
+
+#[test]
+fn config_defaults_to_loopback_only() {
+    let network: NetworkConfig =
+        serde_json::from_str(r#"{"accept_invalid_certs": false}"#).unwrap();
+    assert_eq!(network.allow_http, AllowHttp::LoopbackOnly);
+
+    let json = r#"{"allow_http": "private_networks"}"#;
+    let config = AppConfig {
+        network: serde_json::from_str(json).unwrap(),
+        ..AppConfig::default()
+    };
+    assert_eq!(
+        PollConfig::from_app_config(&config).allow_http,
+        AllowHttp::PrivateNetworks
+    );
+}
+
+#[tokio::test]
+async fn poller_applies_the_policy_and_the_feed_exception() {
+    let server = serve().await;
+    let url = format!("{}/feed", server.uri());
+    assert!(fetch_succeeds(descriptor(url.clone(), false), AllowHttp::LoopbackOnly).await);
+    assert!(!fetch_succeeds(descriptor(url.clone(), false), AllowHttp::Never).await);
+    assert!(fetch_succeeds(descriptor(url.clone(), true), AllowHttp::Never).await);
+
+    // Refused before any request is sent.
+    let requests = server.received_requests().await.unwrap().len();
+    assert_eq!(requests, 2);
+
+    let timeout = Duration::from_secs(2);
+    let err = preview_feed(&Client::new(), &url, timeout, AllowHttp::Never)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PollError::HttpNotAllowed), "{}", err);
+}
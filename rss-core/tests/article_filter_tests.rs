@@ -0,0 +1,140 @@
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+
+use rss_core::{ArticleFilter, FeedEntry};
+
+// Wednesday 2024-06-12, 10:00 UTC.
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 12, 10, 0, 0).unwrap()
+}
+
+fn entry(published_at: Option<DateTime<Utc>>) -> FeedEntry {
+    FeedEntry {
+        feed_id: "f1".into(),
+        title: "Article".into(),
+        url: "https://example.org/article".into(),
+        published_at,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn default_filter_keeps_everything() {
+    let filter = ArticleFilter::default();
+    assert!(!filter.is_active());
+    assert!(filter.matches(&entry(None), &now()));
+    assert!(filter.matches(&entry(Some(now() - Duration::days(400))), &now()));
+}
+
+#[test]
+fn date_filters_follow_calendar_days_and_skip_undated_entries() {
+    let today = ArticleFilter {
+        today: true,
+        ..ArticleFilter::default()
+    };
+    let week = ArticleFilter {
+        this_week: true,
+        ..ArticleFilter::default()
+    };
+    let midnight = Utc.with_ymd_and_hms(2024, 6, 12, 0, 0, 0).unwrap();
+    let monday = Utc.with_ymd_and_hms(2024, 6, 10, 0, 0, 0).unwrap();
+
+    assert!(today.matches(&entry(Some(midnight)), &now()));
+    assert!(!today.matches(&entry(Some(midnight - Duration::seconds(1))), &now()));
+    assert!(week.matches(&entry(Some(monday)), &now()));
+    assert!(!week.matches(&entry(Some(monday - Duration::seconds(1))), &now()));
+    assert!(!today.matches(&entry(None), &now()));
+    assert!(!week.matches(&entry(None), &now()));
+
+    // Days are taken in the timezone of `now`: 23:30 UTC is already tomorrow in UTC+2.
+    let paris = FixedOffset::east_opt(2 * 3600).unwrap();
+    let late = Utc.with_ymd_and_hms(2024, 6, 11, 23, 30, 0).unwrap();
+    assert!(!today.matches(&entry(Some(late)), &now()));
+    assert!(today.matches(&entry(Some(late)), &now().with_timezone(&paris)));
+}
+
+#[test]
+fn image_and_text_filters_skip_missing_fields() {
+    let with_image = ArticleFilter {
+        with_image: true,
+        ..ArticleFilter::default()
+    };
+    let pictured = FeedEntry {
+        image_url: Some("https://example.org/a.png".into()),
+        ..entry(None)
+    };
+    let blank = FeedEntry {
+        image_url: Some("  ".into()),
+        ..entry(None)
+    };
+    assert!(with_image.matches(&pictured, &now()));
+    assert!(!with_image.matches(&blank, &now()));
+    assert!(!with_image.matches(&entry(None), &now()));
+
+    let mut by_text = ArticleFilter {
+        text: "  dupont ".into(),
+        ..ArticleFilter::default()
+    };
+    assert!(by_text.is_active());
+    let authored = FeedEntry {
+        author: Some("Marie Dupont".into()),
+        ..entry(None)
+    };
+    let categorized = FeedEntry {
+        categories: vec!["Politique".into()],
+        ..entry(None)
+    };
+    assert!(by_text.matches(&authored, &now()));
+    assert!(!by_text.matches(&categorized, &now()));
+    assert!(!by_text.matches(&entry(None), &now()));
+
+    by_text.text = "POLITIQUE".into();
+    assert!(by_text.matches(&categorized, &now()));
+    by_text.clear();
+    assert_eq!(by_text, ArticleFilter::default());
+}
+
+#[test]
+fn filters_combine() {
+    let filter = ArticleFilter {
+        this_week: true,
+        with_image: true,
+        text: "tech".into(),
+        ..ArticleFilter::default()
+    };
+    let matching = FeedEntry {
+        categories: vec!["Tech".into()],
+        image_url: Some("https://example.org/a.png".into()),
+        ..entry(Some(now() - Duration::hours(30)))
+    };
+    assert!(filter.matches(&matching, &now()));
+    let old = FeedEntry {
+        published_at: Some(now() - Duration::days(5)),
+        ..matching.clone()
+    };
+    assert!(!filter.matches(&old, &now()));
+    let no_image = FeedEntry {
+        image_url: None,
+        ..matching
+    };
+    assert!(!filter.matches(&no_image, &now()));
+}
+
+#[test]
+fn tag_filter_matches_any_category_ignoring_case() {
+    let filter = ArticleFilter {
+        tag: Some("rust".into()),
+        ..ArticleFilter::default()
+    };
+    assert!(filter.is_active());
+    let tagged = FeedEntry {
+        categories: vec!["Linux".into(), "Rust".into()],
+        ..entry(None)
+    };
+    let prefixed = FeedEntry {
+        categories: vec!["Rustacean".into()],
+        ..entry(None)
+    };
+    assert!(filter.matches(&tagged, &now()));
+    assert!(!filter.matches(&prefixed, &now()));
+    assert!(!filter.matches(&entry(None), &now()));
+}
@@ -0,0 +1,151 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    ArticleFilter, Event, FeedDescriptor, FeedEntry, FeedService, PollConfig, SeenStatus, SeenStore,
+};
+
+fn entry(summary: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: "f1".into(),
+        title: "Annonce".into(),
+        summary: Some(summary.into()),
+        url: "https://example.org/annonce".into(),
+        guid: Some("annonce-1".into()),
+        guid_is_permalink: Some(false),
+        ..Default::default()
+    }
+}
+
+fn temp_file() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_seen_updates_{}.json", nanos))
+}
+
+#[tokio::test]
+async fn seen_store_reports_content_changes() {
+    let path = temp_file();
+    let seen = SeenStore::load_from(&path).await;
+    assert_eq!(seen.check_and_mark(&entry("v1")).await, SeenStatus::New);
+    assert_eq!(
+        seen.check_and_mark(&entry("v1")).await,
+        SeenStatus::Unchanged
+    );
+    assert_eq!(seen.check_and_mark(&entry("v2")).await, SeenStatus::Updated);
+    assert_eq!(
+        seen.check_and_mark(&entry("v2")).await,
+        SeenStatus::Unchanged
+    );
+    // A different date alone is not an edit.
+    let mut redated = entry("v2");
+    redated.published_at = Some(chrono::Utc::now());
+    assert_eq!(seen.check_and_mark(&redated).await, SeenStatus::Unchanged);
+    seen.flush().await;
+
+    // Fingerprints survive a reload.
+    let reloaded = SeenStore::load_from(&path).await;
+    assert_eq!(
+        reloaded.check_and_mark(&entry("v2")).await,
+        SeenStatus::Unchanged
+    );
+    assert_eq!(
+        reloaded.check_and_mark(&entry("v3")).await,
+        SeenStatus::Updated
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn entries_seen_before_fingerprints_are_not_reported_as_updated() {
+    let path = temp_file();
+    let identity = entry("v1").identity();
+    let legacy = serde_json::json!({ "seen": { "f1": { identity: 0 } }, "next_seq": 1 });
+    std::fs::write(&path, legacy.to_string()).unwrap();
+
+    let seen = SeenStore::load_from(&path).await;
+    assert_eq!(
+        seen.check_and_mark(&entry("v1")).await,
+        SeenStatus::Unchanged
+    );
+    assert_eq!(seen.check_and_mark(&entry("v2")).await, SeenStatus::Updated);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn second_poll_with_edited_description_replaces_the_cached_article() {
+    let server = MockServer::start().await;
+    let polls = AtomicUsize::new(0);
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(move |_: &wiremock::Request| {
+            let description = match polls.fetch_add(1, Ordering::SeqCst) {
+                0 => "Version initiale",
+                _ => "Version corrigée",
+            };
+            let body = format!(
+                r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>Annonce</title><link>http://e/1</link><guid isPermaLink="false">1</guid>
+<description>{description}</description></item>
+<item><title>Stable</title><link>http://e/2</link><guid isPermaLink="false">2</guid>
+<description>Inchangé</description></item></channel></rss>"#
+            );
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(body)
+        })
+        .mount(&server)
+        .await;
+
+    let service = FeedService::in_memory();
+    // Only the explicit refreshes below may consume the mock's versions.
+    service.pause();
+    service.set_poll_config(PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    });
+    service
+        .add_feed(FeedDescriptor::new(
+            "news",
+            "News",
+            format!("{}/feed", server.uri()),
+        ))
+        .await
+        .unwrap();
+
+    service.refresh_feed("news").await.unwrap();
+    let articles = service.articles(&ArticleFilter::default()).await;
+    assert_eq!(articles.len(), 2);
+    let first = articles.iter().find(|a| a.title == "Annonce").unwrap();
+    service.data_api().mark_read(first).await.unwrap();
+    service.data_api().set_starred(first, true).await.unwrap();
+
+    let events = service.refresh_feed("news").await.unwrap();
+    assert!(!events.iter().any(|e| matches!(e, Event::NewArticles(..))));
+    let updated = events
+        .iter()
+        .find_map(|e| match e {
+            Event::ArticlesUpdated(feed_id, entries) => Some((feed_id, entries)),
+            _ => None,
+        })
+        .expect("no ArticlesUpdated event");
+    assert_eq!(updated.0, "news");
+    assert_eq!(updated.1.len(), 1);
+    assert!(updated.1[0].updated_at.is_some());
+
+    let articles = service.articles(&ArticleFilter::default()).await;
+    assert_eq!(articles.len(), 2);
+    let edited = articles.iter().find(|a| a.title == "Annonce").unwrap();
+    assert_eq!(edited.summary.as_deref(), Some("Version corrigée"));
+    assert!(edited.updated_at.is_some());
+    assert!(service.data_api().is_read(edited).await);
+    assert!(service.data_api().is_starred(edited).await);
+    let stable = articles.iter().find(|a| a.title == "Stable").unwrap();
+    assert!(stable.updated_at.is_none());
+}
@@ -0,0 +1,106 @@
+use atom_syndication::{Entry, Link};
+use rss_core::FeedEntry;
+
+fn link(rel: &str, mime_type: Option<&str>, href: &str) -> Link {
+    Link {
+        href: href.to_string(),
+        rel: rel.to_string(),
+        mime_type: mime_type.map(str::to_string),
+        ..Link::default()
+    }
+}
+
+fn entry(links: Vec<Link>) -> FeedEntry {
+    let mut entry = Entry::default();
+    entry.set_id("urn:entry:1");
+    entry.set_title("Entry");
+    entry.set_links(links);
+    FeedEntry::from_atom_entry("blog", &entry)
+}
+
+#[test]
+fn html_alternate_wins_over_earlier_links() {
+    let parsed = entry(vec![
+        link(
+            "self",
+            Some("application/atom+xml"),
+            "https://blog.example/entry.atom",
+        ),
+        link("edit", None, "https://blog.example/api/entries/1"),
+        link(
+            "alternate",
+            Some("application/json"),
+            "https://blog.example/1.json",
+        ),
+        link("alternate", Some("text/html"), "https://blog.example/1"),
+    ]);
+    assert_eq!(parsed.url, "https://blog.example/1");
+}
+
+#[test]
+fn any_alternate_when_none_is_html() {
+    let parsed = entry(vec![
+        link("self", None, "https://blog.example/entry.atom"),
+        link(
+            "alternate",
+            Some("application/xhtml+xml"),
+            "https://blog.example/1.xhtml",
+        ),
+        link(
+            "alternate",
+            Some("application/json"),
+            "https://blog.example/1.json",
+        ),
+    ]);
+    assert_eq!(parsed.url, "https://blog.example/1.xhtml");
+
+    // A link without `rel` is an alternate link.
+    let parsed = entry(vec![
+        link("edit", None, "https://blog.example/api/entries/1"),
+        Link {
+            href: "https://blog.example/1".into(),
+            ..Link::default()
+        },
+    ]);
+    assert_eq!(parsed.url, "https://blog.example/1");
+}
+
+#[test]
+fn first_link_when_there_is_no_alternate() {
+    let parsed = entry(vec![
+        link("self", None, "https://blog.example/entry.atom"),
+        link("related", Some("text/html"), "https://other.example/"),
+    ]);
+    assert_eq!(parsed.url, "https://blog.example/entry.atom");
+
+    assert_eq!(entry(Vec::new()).url, "");
+}
+
+#[test]
+fn image_enclosure_becomes_the_image_url() {
+    let parsed = entry(vec![
+        link(
+            "enclosure",
+            Some("audio/mpeg"),
+            "https://blog.example/1.mp3",
+        ),
+        link(
+            "enclosure",
+            Some("image/jpeg"),
+            "https://blog.example/1.jpg",
+        ),
+        link("alternate", Some("text/html"), "https://blog.example/1"),
+    ]);
+    assert_eq!(parsed.url, "https://blog.example/1");
+    assert_eq!(
+        parsed.image_url.as_deref(),
+        Some("https://blog.example/1.jpg")
+    );
+
+    let parsed = entry(vec![link(
+        "enclosure",
+        Some("audio/mpeg"),
+        "https://blog.example/1.mp3",
+    )]);
+    assert_eq!(parsed.image_url, None);
+}
@@ -0,0 +1,240 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::Utc;
+use rss_core::{
+    feeds_by_category, shared_feed_list, DataApi, FeedAuth, FeedDescriptor, FeedEntry, FeedUpdate,
+    PollConfig, Secret,
+};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_bulk_{}", nanos))
+}
+
+fn descriptor(id: &str) -> FeedDescriptor {
+    FeedDescriptor::new(
+        id,
+        id.to_uppercase(),
+        format!("https://example.org/{}.xml", id),
+    )
+}
+
+fn entry(feed_id: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: "A".into(),
+        url: format!("https://example.org/{}/1", feed_id),
+        guid: Some(format!("{}-1", feed_id)),
+        ..Default::default()
+    }
+}
+
+fn ids(feeds: &[FeedDescriptor]) -> Vec<&str> {
+    feeds.iter().map(|f| f.id.as_str()).collect()
+}
+
+async fn api_with(dir: &std::path::Path, feed_ids: &[&str]) -> DataApi {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), dir).await;
+    for id in feed_ids {
+        api.add_feed(descriptor(id)).await.unwrap();
+    }
+    api
+}
+
+#[tokio::test]
+async fn remove_feeds_drops_feeds_and_their_state_in_one_save() {
+    let dir = temp_dir();
+    let api = api_with(&dir, &["a", "b", "c", "d"]).await;
+    for id in ["a", "b", "c"] {
+        api.mark_read(&entry(id)).await.unwrap();
+    }
+    let writes = api.read_store_writes();
+
+    // Unknown ids are ignored.
+    let removed = api
+        .remove_feeds(&["a".into(), "c".into(), "zz".into()])
+        .await
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(api.read_store_writes(), writes + 1);
+    assert_eq!(ids(&api.list_feeds().await), ["b", "d"]);
+    assert!(!api.is_read(&entry("a")).await);
+    assert!(api.is_read(&entry("b")).await);
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(ids(&reloaded.list_feeds().await), ["b", "d"]);
+    assert!(!reloaded.is_read(&entry("c")).await);
+    assert!(reloaded.is_read(&entry("b")).await);
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn bulk_updates_apply_to_selected_feeds_and_persist() {
+    let dir = temp_dir();
+    let api = api_with(&dir, &["a", "b", "c"]).await;
+    let selection: Vec<String> = vec!["a".into(), "b".into(), "zz".into()];
+
+    let updated = api
+        .update_feeds_bulk(&selection, FeedUpdate::Category(Some("  Tech ".into())))
+        .await
+        .unwrap();
+    assert_eq!(updated, 2);
+    // Feeds already in the requested state are not counted.
+    let again = api
+        .update_feeds_bulk(&selection, FeedUpdate::Category(Some("Tech".into())))
+        .await
+        .unwrap();
+    assert_eq!(again, 0);
+
+    let hourly = Some(Duration::from_secs(3600));
+    api.update_feeds_bulk(&["b".into(), "c".into()], FeedUpdate::PollInterval(hourly))
+        .await
+        .unwrap();
+    api.update_feeds_bulk(&["c".into()], FeedUpdate::Notifications(false))
+        .await
+        .unwrap();
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feeds = reloaded.list_feeds().await;
+    let by_id = |id: &str| feeds.iter().find(|f| f.id == id).unwrap();
+    assert_eq!(by_id("a").category.as_deref(), Some("Tech"));
+    assert_eq!(by_id("b").category.as_deref(), Some("Tech"));
+    assert_eq!(by_id("c").category, None);
+    assert_eq!(by_id("a").poll_interval, None);
+    assert_eq!(by_id("b").poll_interval, hourly);
+    assert!(!by_id("b").notifications_disabled);
+    assert!(by_id("c").notifications_disabled);
+
+    // An empty folder name clears the folder.
+    reloaded
+        .update_feeds_bulk(&["a".into()], FeedUpdate::Category(Some(" ".into())))
+        .await
+        .unwrap();
+    let feeds = reloaded.list_feeds().await;
+    assert_eq!(feeds.iter().find(|f| f.id == "a").unwrap().category, None);
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn editor_updates_keep_what_the_poller_changed_meanwhile() {
+    let dir = temp_dir();
+    let feeds = shared_feed_list(Vec::new());
+    let api = DataApi::load_from_dir(feeds.clone(), &dir).await;
+    api.add_feed(descriptor("a")).await.unwrap();
+    // The poller moves the feed and counts a failure after the editor took its snapshot.
+    api.update_feed_url("a", "https://example.org/moved.xml")
+        .await
+        .unwrap();
+    rss_core::record_feed_result(&feeds, "a", false, 5).await;
+
+    let auth = Some(FeedAuth::Bearer {
+        token: Secret::Plaintext("t0k3n".into()),
+    });
+    let update = FeedUpdate::Request {
+        user_agent: Some("Custom/1.0".into()),
+        headers: Some(vec![("X-Api-Key".into(), "k3y".into())]),
+        auth: auth.clone(),
+    };
+    assert_eq!(
+        api.update_feeds_bulk(&["a".into()], update.clone())
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        api.update_feeds_bulk(&["a".into()], update).await.unwrap(),
+        0
+    );
+    api.update_feeds_bulk(
+        &["a".into()],
+        FeedUpdate::ContentSelector(Some("main".into())),
+    )
+    .await
+    .unwrap();
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feed = reloaded.list_feeds().await.remove(0);
+    assert_eq!(feed.url, "https://example.org/moved.xml");
+    assert_eq!(feed.consecutive_failures, 1);
+    assert_eq!(feed.user_agent.as_deref(), Some("Custom/1.0"));
+    assert_eq!(feed.auth, auth);
+    assert_eq!(feed.content_selector.as_deref(), Some("main"));
+    assert!(feed.full_content);
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[test]
+fn feed_settings_are_stored_compactly() {
+    let mut feed = descriptor("a");
+    let json = serde_json::to_value(&feed).unwrap();
+    for key in ["category", "poll_interval", "notifications_disabled"] {
+        assert!(json.get(key).is_none(), "{key} should be omitted");
+    }
+
+    feed.poll_interval = Some(Duration::from_secs(1800));
+    feed.notifications_disabled = true;
+    let json = serde_json::to_value(&feed).unwrap();
+    assert_eq!(json["poll_interval"], 1800);
+    assert_eq!(json["notifications_disabled"], true);
+    let back: FeedDescriptor = serde_json::from_value(json).unwrap();
+    assert_eq!(back, feed);
+}
+
+#[test]
+fn sidebar_groups_uncategorized_feeds_first_then_folders_alphabetically() {
+    let feed = |id: &str, category: Option<&str>| FeedDescriptor {
+        category: category.map(str::to_string),
+        ..descriptor(id)
+    };
+    let groups = feeds_by_category(vec![
+        feed("a", Some("Tech")),
+        feed("b", None),
+        feed("c", Some("actu")),
+        feed("d", Some("tech")),
+        feed("e", None),
+    ]);
+    let summary: Vec<(Option<&str>, Vec<&str>)> = groups
+        .iter()
+        .map(|(category, feeds)| (category.as_deref(), ids(feeds)))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (None, vec!["b", "e"]),
+            (Some("actu"), vec!["c"]),
+            (Some("Tech"), vec!["a", "d"]),
+        ]
+    );
+}
+
+#[test]
+fn feed_poll_interval_replaces_the_global_one() {
+    let now = Utc::now();
+    let config = PollConfig {
+        interval: Duration::from_secs(900),
+        adaptive_polling: false,
+        ..PollConfig::default()
+    };
+    let mut feed = descriptor("a");
+    assert_eq!(config.effective_interval(&feed, now), config.interval);
+    feed.poll_interval = Some(Duration::from_secs(3 * 3600));
+    assert_eq!(
+        config.effective_interval(&feed, now),
+        Duration::from_secs(3 * 3600)
+    );
+
+    // The adaptive stretch starts from the feed's own interval.
+    let adaptive = PollConfig {
+        adaptive_polling: true,
+        max_adaptive_interval: Duration::from_secs(24 * 3600),
+        ..config
+    };
+    feed.last_new_article_at = Some(now - chrono::Duration::days(10));
+    assert_eq!(
+        adaptive.effective_interval(&feed, now),
+        Duration::from_secs(6 * 3600)
+    );
+}
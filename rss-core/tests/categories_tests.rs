@@ -0,0 +1,119 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rss_core::{shared_feed_list, DataApi, FeedEntry};
+
+const RSS_ITEM: &str = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title>Journal</title>
+    <link>https://example.org</link>
+    <description>Test</description>
+    <item>
+      <title>Article</title>
+      <link>https://example.org/a</link>
+      <category>Rust</category>
+      <category> Linux </category>
+      <category>rust</category>
+      <dc:subject>Logiciel libre</dc:subject>
+    </item>
+  </channel>
+</rss>"#;
+
+const ATOM_ENTRY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Blog</title>
+  <id>urn:blog</id>
+  <updated>2024-06-01T00:00:00Z</updated>
+  <entry>
+    <title>Billet</title>
+    <id>urn:blog:1</id>
+    <updated>2024-06-01T00:00:00Z</updated>
+    <link href="https://example.org/b"/>
+    <category term="rust"/>
+    <category term="web-dev" label="Web"/>
+  </entry>
+</feed>"#;
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_categories_{}", nanos))
+}
+
+#[test]
+fn all_rss_categories_and_subjects_are_kept() {
+    let channel = rss::Channel::read_from(RSS_ITEM.as_bytes()).unwrap();
+    let entry = FeedEntry::from_rss_item("journal", &channel.items()[0]);
+    assert_eq!(entry.categories, ["Rust", "Linux", "Logiciel libre"]);
+}
+
+#[test]
+fn atom_categories_prefer_their_label() {
+    let feed: atom_syndication::Feed = ATOM_ENTRY.parse().unwrap();
+    let entry = FeedEntry::from_atom_entry("blog", &feed.entries()[0]);
+    assert_eq!(entry.categories, ["rust", "Web"]);
+}
+
+#[tokio::test]
+async fn old_store_with_a_single_category_still_loads() {
+    let dir = temp_dir();
+    std::fs::create_dir_all(&dir).unwrap();
+    let old_store = r#"{
+      "journal": [
+        {"feed_id": "journal", "title": "A", "summary": null, "url": "https://example.org/a",
+         "published_at": null, "guid": "a", "author": null, "category": "Tech"},
+        {"feed_id": "journal", "title": "B", "summary": null, "url": "https://example.org/b",
+         "published_at": null, "guid": "b", "author": null, "category": null}
+      ]
+    }"#;
+    std::fs::write(dir.join("articles_store.json"), old_store).unwrap();
+
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let mut articles = api.list_articles("journal").await;
+    articles.sort_by(|a, b| a.title.cmp(&b.title));
+    assert_eq!(articles[0].categories, ["Tech"]);
+    assert!(articles[1].categories.is_empty());
+
+    // Saved back in the new format.
+    let json = serde_json::to_value(&articles[0]).unwrap();
+    assert_eq!(json["categories"], serde_json::json!(["Tech"]));
+    assert!(json.get("category").is_none());
+}
+
+#[tokio::test]
+async fn tags_are_counted_across_feeds_most_frequent_first() {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    let entry = |feed_id: &str, n: usize, categories: &[&str]| FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("Article {}", n),
+        url: format!("https://example.org/{}/{}", feed_id, n),
+        guid: Some(format!("{}-{}", feed_id, n)),
+        categories: categories.iter().map(|c| c.to_string()).collect(),
+        ..Default::default()
+    };
+    api.upsert_articles(
+        "f1",
+        vec![
+            entry("f1", 1, &["rust", "Linux"]),
+            entry("f1", 2, &["Rust"]),
+            entry("f1", 3, &[]),
+        ],
+    )
+    .await
+    .unwrap();
+    api.upsert_articles("f2", vec![entry("f2", 1, &["Rust", "Web"])])
+        .await
+        .unwrap();
+
+    let tags = api.tags_with_counts().await;
+    assert_eq!(
+        tags,
+        [
+            ("Rust".to_string(), 3),
+            ("Linux".to_string(), 1),
+            ("Web".to_string(), 1),
+        ]
+    );
+}
@@ -0,0 +1,147 @@
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+
+use rss_core::{shared_feed_list, CompactionReport, DataApi, FeedDescriptor, FeedEntry};
+
+fn temp_dir() -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_compaction_{}", nanos))
+}
+
+fn entry(feed_id: &str, guid: &str, first_seen_at: DateTime<Utc>) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: guid.into(),
+        url: format!("https://example.org/{}", guid),
+        guid: Some(guid.into()),
+        first_seen_at,
+        ..Default::default()
+    }
+}
+
+// Store left by months of use: a deleted feed's articles and read marks, a duplicate from
+// before the dedup fixes, and read/seen identities of articles evicted long ago.
+fn write_store(dir: &Path) {
+    let old = Utc::now() - Duration::days(200);
+    let recent = Utc::now() - Duration::days(2);
+    std::fs::create_dir_all(dir).unwrap();
+    let feeds = vec![FeedDescriptor::new(
+        "kept",
+        "Kept",
+        "https://example.org/kept.xml",
+    )];
+    let articles = json!({
+        "kept": [
+            entry("kept", "a1", old),
+            entry("kept", "a1", old),
+            entry("kept", "a2", old),
+            entry("kept", "a3", recent),
+        ],
+        "gone": [entry("gone", "g1", old), entry("gone", "g2", old)],
+    });
+    let read = json!({
+        "read": {
+            "kept": ["guid:a1", "guid:old-evicted", "guid:never-seen", "guid:recent-evicted"],
+            "gone": ["guid:g1"],
+        },
+    });
+    let seen = json!({
+        "seen": {"kept": {
+            "guid:old-evicted": 1,
+            "guid:a1": 3,
+            "guid:a2": 5,
+            "guid:recent-evicted": 8,
+            "guid:a3": 10,
+        }},
+        "urls": {},
+        "fingerprints": {},
+        "next_seq": 11,
+    });
+    for (name, value) in [
+        ("feeds.json", serde_json::to_value(feeds).unwrap()),
+        ("articles_store.json", articles),
+        ("read_store.json", read),
+        ("seen_store.json", seen),
+    ] {
+        std::fs::write(dir.join(name), serde_json::to_vec(&value).unwrap()).unwrap();
+    }
+}
+
+async fn load(dir: &Path) -> DataApi {
+    DataApi::load_from_dir(shared_feed_list(Vec::new()), dir).await
+}
+
+fn evicted(guid: &str) -> FeedEntry {
+    entry("kept", guid, Utc::now())
+}
+
+#[tokio::test]
+async fn compaction_drops_orphans_duplicates_and_stale_marks() {
+    let dir = temp_dir();
+    write_store(&dir);
+    let api = load(&dir).await;
+
+    let report = api.compact().await.unwrap();
+    assert_eq!(report.removed_articles, 3);
+    // The deleted feed's mark, one evicted before the oldest cached article, one never seen.
+    assert_eq!(report.removed_read_marks, 3);
+    assert!(report.bytes_after < report.bytes_before, "{:?}", report);
+
+    assert_eq!(api.list_articles("kept").await.len(), 3);
+    assert!(api.list_articles("gone").await.is_empty());
+    assert!(api.is_read(&evicted("a1")).await);
+    assert!(api.is_read(&evicted("recent-evicted")).await);
+    assert!(!api.is_read(&evicted("old-evicted")).await);
+    assert!(!api.seen_store().contains(&evicted("old-evicted")).await);
+    assert!(api.seen_store().contains(&evicted("recent-evicted")).await);
+
+    // Persisted, and a second pass has nothing left to do.
+    let reloaded = load(&dir).await;
+    assert!(!reloaded.is_read(&evicted("old-evicted")).await);
+    let again = reloaded.compact().await.unwrap();
+    assert_eq!((again.removed_articles, again.removed_read_marks), (0, 0));
+}
+
+#[tokio::test]
+async fn light_compaction_keeps_marks_and_full_caches_keep_seen() {
+    let dir = temp_dir();
+    write_store(&dir);
+    let api = load(&dir).await;
+    let report = api.compact_light().await.unwrap();
+    assert_eq!(
+        report,
+        CompactionReport {
+            removed_articles: 3,
+            removed_read_marks: 1,
+            ..report
+        }
+    );
+    assert!(api.is_read(&evicted("old-evicted")).await);
+
+    // A full cache may still be missing articles the feed serves: their seen marks stay.
+    api.set_max_articles_per_feed(3);
+    let report = api.compact().await.unwrap();
+    assert_eq!(report.removed_read_marks, 2);
+    assert!(api.seen_store().contains(&evicted("old-evicted")).await);
+}
+
+#[tokio::test]
+async fn startup_runs_a_light_compaction() {
+    let dir = temp_dir();
+    write_store(&dir);
+    let service = rss_core::FeedService::open(&dir).await;
+    service.pause();
+    drop(service);
+
+    let stored = std::fs::read_to_string(dir.join("articles_store.json")).unwrap();
+    assert!(!stored.contains("g1"));
+    let api = load(&dir).await;
+    assert_eq!(api.list_articles("kept").await.len(), 3);
+    assert!(api.is_read(&evicted("old-evicted")).await);
+}
@@ -0,0 +1,83 @@
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use wiremock::matchers::{header_regex, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    build_http_client, poll_once, Event, FeedDescriptor, FetchErrorKind, FetchOutcome,
+    NetworkConfig, PollConfig, SeenStore,
+};
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn rss_with_padding(padding: usize) -> String {
+    format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><description>{}</description><item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#,
+        " ".repeat(padding)
+    )
+}
+
+async fn poll_gzipped(body: Vec<u8>, max_feed_bytes: usize) -> (Vec<Event>, FetchOutcome) {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header_regex("accept-encoding", "gzip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .insert_header("content-encoding", "gzip")
+                .set_body_bytes(body),
+        )
+        .mount(&server)
+        .await;
+
+    let feed = FeedDescriptor {
+        id: "feed1".into(),
+        title: "Feed".into(),
+        url: format!("{}/feed", server.uri()),
+        ..Default::default()
+    };
+    let cfg = PollConfig {
+        max_retries: 0,
+        max_feed_bytes,
+        ..PollConfig::default()
+    };
+    let client = build_http_client(&NetworkConfig::default()).expect("client");
+    let events = poll_once(&[feed], &cfg, &client, &SeenStore::in_memory())
+        .await
+        .0;
+    let outcome = events
+        .iter()
+        .find_map(|e| match e {
+            Event::FetchCompleted(_, outcome) => Some(outcome.clone()),
+            _ => None,
+        })
+        .expect("fetch outcome");
+    (events, outcome)
+}
+
+#[tokio::test]
+async fn gzip_compressed_feed_is_decoded() {
+    let (events, outcome) = poll_gzipped(gzip(rss_with_padding(0).as_bytes()), 1024 * 1024).await;
+    assert!(outcome.success, "{:?}", outcome.error);
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::NewArticles(_, entries) if entries.len() == 1)));
+}
+
+#[tokio::test]
+async fn size_limit_applies_to_decompressed_bytes() {
+    // ~2 MiB once decompressed, only a few KiB on the wire
+    let body = gzip(rss_with_padding(2 * 1024 * 1024).as_bytes());
+    assert!(body.len() < 64 * 1024);
+
+    let (_, outcome) = poll_gzipped(body, 1024 * 1024).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.error_kind, Some(FetchErrorKind::TooLarge));
+}
@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use rss_core::{AppConfig, ConfigIssue, Layout, SortMode, ThemePreset, CONFIG_VERSION};
+use serde_json::json;
+
+fn temp_config(name: &str, content: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "readrss_config_{}_{}",
+        name,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.json");
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+// A config written before `version` and most optional sections existed.
+const V0_CONFIG: &str = r#"{
+  "theme": {
+    "background_color": [1, 2, 3],
+    "panel_color": [4, 5, 6],
+    "accent_color": [7, 8, 9],
+    "text_color": [10, 11, 12],
+    "secondary_text_color": [13, 14, 15],
+    "border_color": [16, 17, 18]
+  },
+  "feeds": {
+    "update_interval_minutes": 45,
+    "max_articles_per_feed": 250,
+    "request_timeout_seconds": 20,
+    "retry_attempts": 5
+  },
+  "ui": {
+    "font_size": 18.0,
+    "left_panel_width": 320.0,
+    "show_article_preview": false,
+    "articles_per_page": 40,
+    "last_selected_feed": "f1"
+  }
+}"#;
+
+#[test]
+fn v0_config_keeps_user_values() {
+    let path = temp_config("v0", V0_CONFIG);
+    let (config, issue) = AppConfig::load_from_path(&path);
+    assert_eq!(issue, None);
+
+    assert_eq!(config.version, CONFIG_VERSION);
+    assert_eq!(config.theme.background_color, [1, 2, 3]);
+    assert_eq!(config.theme.preset, ThemePreset::DarkVsCode);
+    assert_eq!(config.feeds.update_interval_minutes, 45);
+    assert_eq!(config.feeds.max_articles_per_feed, 250);
+    assert_eq!(config.feeds.retry_attempts, 5);
+    assert!(config.feeds.auto_update_moved_feeds);
+    assert_eq!(config.ui.font_size, 18.0);
+    assert!(!config.ui.show_article_preview);
+    assert_eq!(config.ui.sort_mode, SortMode::NewestFirst);
+    assert_eq!(config.ui.layout, Layout::SinglePane);
+
+    // The old file is kept aside and the new one carries the version.
+    let backup = path.with_extension("json.bak");
+    assert_eq!(std::fs::read_to_string(&backup).unwrap(), V0_CONFIG);
+    let rewritten: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(rewritten["version"], json!(CONFIG_VERSION));
+    assert_eq!(rewritten["feeds"]["update_interval_minutes"], json!(45));
+    assert!(rewritten["ui"].get("last_selected_feed").is_none());
+
+    // Loading again is a no-op.
+    std::fs::remove_file(&backup).unwrap();
+    let (again, issue) = AppConfig::load_from_path(&path);
+    assert_eq!(issue, None);
+    assert_eq!(again.ui.articles_per_page, 40);
+    assert!(!backup.exists());
+}
+
+#[test]
+fn invalid_field_only_resets_that_field() {
+    let raw = json!({
+        "version": CONFIG_VERSION,
+        "feeds": { "update_interval_minutes": 45, "retry_attempts": "three" },
+        "ui": { "font_size": 18.0, "sort_mode": "sideways" }
+    });
+    let path = temp_config("invalid", &raw.to_string());
+    let (config, issue) = AppConfig::load_from_path(&path);
+
+    assert_eq!(config.feeds.update_interval_minutes, 45);
+    assert_eq!(config.feeds.retry_attempts, 3);
+    assert_eq!(config.ui.font_size, 18.0);
+    assert_eq!(config.ui.sort_mode, SortMode::NewestFirst);
+    let backup = path.with_extension("json.bak");
+    assert_eq!(
+        issue,
+        Some(ConfigIssue::Ignored {
+            fields: vec!["feeds.retry_attempts".into(), "ui.sort_mode".into()],
+            backup: Some(backup.clone()),
+        })
+    );
+    assert!(std::fs::read_to_string(backup).unwrap().contains("three"));
+}
+
+#[test]
+fn unknown_keys_survive_a_rewrite() {
+    let config = AppConfig::migrate(json!({
+        "feeds": { "update_interval_minutes": 15 },
+        "newer_section": { "enabled": true }
+    }));
+    assert_eq!(config.feeds.update_interval_minutes, 15);
+
+    let saved = serde_json::to_value(&config).unwrap();
+    assert_eq!(saved["newer_section"], json!({ "enabled": true }));
+    assert_eq!(saved["version"], json!(CONFIG_VERSION));
+}
+
+#[test]
+fn unparsable_file_is_backed_up() {
+    let path = temp_config("broken", "{ \"feeds\": ");
+    let (config, issue) = AppConfig::load_from_path(&path);
+
+    assert_eq!(config.feeds.update_interval_minutes, 30);
+    let backup = path.with_extension("json.bak");
+    match issue {
+        Some(ConfigIssue::Unreadable {
+            backup: Some(b), ..
+        }) => assert_eq!(b, backup),
+        other => panic!("unexpected issue: {:?}", other),
+    }
+    assert_eq!(std::fs::read_to_string(backup).unwrap(), "{ \"feeds\": ");
+}
+
+#[test]
+fn split_layout_and_list_width_round_trip() {
+    let config = AppConfig::migrate(json!({
+        "version": CONFIG_VERSION,
+        "ui": { "layout": "split_horizontal", "split_list_width": 512.5 }
+    }));
+    assert_eq!(config.ui.layout, Layout::SplitHorizontal);
+    assert_eq!(config.ui.split_list_width, 512.5);
+
+    let saved = serde_json::to_value(&config).unwrap();
+    assert_eq!(saved["ui"]["layout"], json!("split_horizontal"));
+    assert_eq!(saved["ui"]["split_list_width"], json!(512.5));
+}
@@ -0,0 +1,363 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    extract_readable, extract_selected, poll_once, ContentSelector, Event, FeedDescriptor,
+    FeedEntry, PollConfig, PollError, SeenStore,
+};
+
+const BLOG: &str = include_str!("fixtures/article_blog.html");
+const NEWS: &str = include_str!("fixtures/article_news.html");
+const PAYWALL: &str = include_str!("fixtures/article_paywall.html");
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        article_timeout: Duration::from_millis(300),
+        ..PollConfig::default()
+    }
+}
+
+fn feed(server: &MockServer) -> FeedDescriptor {
+    feed_at(&format!("{}/feed", server.uri()))
+}
+
+fn feed_at(url: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "journal".into(),
+        title: "Le Journal du Code".into(),
+        url: url.into(),
+        full_content: true,
+        ..Default::default()
+    }
+}
+
+fn summary_feed(server: &MockServer, paths: &[&str]) -> String {
+    let items: String = paths
+        .iter()
+        .map(|p| {
+            format!(
+                "<item><title>{p}</title><link>{uri}{p}</link><guid>{p}</guid>\
+                 <description>Résumé court.</description></item>",
+                uri = server.uri()
+            )
+        })
+        .collect();
+    format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>{items}</channel></rss>"#
+    )
+}
+
+fn new_entries(events: Vec<Event>) -> Vec<FeedEntry> {
+    events
+        .into_iter()
+        .flat_map(|event| match event {
+            Event::NewArticles(_, entries) => entries,
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+#[test]
+fn blog_layout_keeps_the_post_body_only() {
+    let base = Url::parse("https://journal.example.org/2024/05/sortie-2-3/").unwrap();
+    let content = extract_readable(BLOG, &base).expect("article body");
+
+    assert!(content.contains("La version 2.3 est disponible depuis ce matin."));
+    assert!(content.contains("leurs retours détaillés."));
+    assert!(content.contains("cargo install journal"));
+    assert!(content.contains(r#"href="https://journal.example.org/2024/04/index-incremental/""#));
+    assert!(content
+        .contains(r#"src="https://journal.example.org/wp-content/uploads/2024/05/benchmark.png""#));
+
+    for noise in [
+        "À propos",
+        "Partager sur",
+        "3 commentaires",
+        "Articles récents",
+        "droits réservés",
+        "dataLayer",
+    ] {
+        assert!(!content.contains(noise), "{noise} should be stripped");
+    }
+    assert!(!content.contains("onload"));
+}
+
+#[test]
+fn news_layout_skips_ads_sidebars_and_related_links() {
+    let base =
+        Url::parse("https://quotidien.example.com/culture/2024/05/bibliotheques.html").unwrap();
+    let content = extract_readable(NEWS, &base).expect("story body");
+
+    assert!(content.contains("ouvriront jusqu’à 21 heures"));
+    assert!(content.contains("enquête de satisfaction"));
+    // Lazy-loaded image and relative links are resolved against the page URL.
+    assert!(content.contains(
+        r#"src="https://quotidien.example.com/culture/2024/05/photos/bibliotheque-centrale.jpg""#
+    ));
+    assert!(
+        content.contains(r#"href="https://quotidien.example.com/culture/pratique/horaires.html""#)
+    );
+
+    for noise in [
+        "cookies",
+        "Publicité",
+        "Les plus lus",
+        "À lire aussi",
+        "Mentions légales",
+        "ads.example",
+    ] {
+        assert!(!content.contains(noise), "{noise} should be stripped");
+    }
+}
+
+#[test]
+fn pages_without_an_article_yield_nothing() {
+    let base = Url::parse("https://example.org/").unwrap();
+    let listing = r#"<html><body><ul><li><a href="/a">Premier lien</a></li><li><a href="/b">Second lien</a></li></ul>
+        <p>Court.</p></body></html>"#;
+    assert_eq!(extract_readable(listing, &base), None);
+    assert_eq!(extract_readable("", &base), None);
+}
+
+#[test]
+fn selector_returns_the_inner_html_of_the_first_match() {
+    let base = Url::parse("https://gazette.example.fr/eco/2024/port.html").unwrap();
+    let selector = ContentSelector::parse("article.story > div.story-body").unwrap();
+    let content = extract_selected(PAYWALL, &selector, &base).expect("story body");
+
+    assert!(content.contains("navette électrique"));
+    assert!(content.contains("selon la direction du port"));
+    assert!(content.contains(r#"href="https://gazette.example.fr/eco/navettes.html""#));
+    assert!(content.contains(r#"src="https://gazette.example.fr/eco/2024/img/quais.jpg""#));
+    // Inner HTML only: neither the container itself nor its siblings.
+    assert!(!content.contains("data-section"));
+    assert!(!content.contains("Réservé aux abonnés"));
+    assert!(!content.contains("Abonnez-vous"));
+    assert!(!content.contains("commentaire à ignorer"));
+
+    // Attribute selectors and comma lists pick the first matching element in the document.
+    for source in [
+        "[data-section=eco]",
+        "div.missing, .premium",
+        "main div.story-body",
+    ] {
+        let selector = ContentSelector::parse(source).unwrap();
+        let content = extract_selected(PAYWALL, &selector, &base).unwrap();
+        assert!(content.contains("navette électrique"), "{source}");
+    }
+}
+
+#[test]
+fn selector_miss_yields_nothing() {
+    let base = Url::parse("https://gazette.example.fr/").unwrap();
+    for source in ["#article-content", "section .story-body", "article > p"] {
+        let selector = ContentSelector::parse(source).unwrap();
+        assert_eq!(
+            extract_selected(PAYWALL, &selector, &base),
+            None,
+            "{source}"
+        );
+    }
+    let selector = ContentSelector::parse(".story-body").unwrap();
+    assert_eq!(extract_selected("", &selector, &base), None);
+}
+
+#[test]
+fn selector_tolerates_malformed_html() {
+    let base = Url::parse("https://example.org/a/").unwrap();
+    let html = r#"<html><body><div class=wrap><P>Intro<div id=main class="x y">
+        <p>Premier paragraphe<p>Second <b>gras</i> paragraphe<br>
+        <a href=lien.html>lien</a>
+        </body>"#;
+    let selector = ContentSelector::parse("div#main.y").unwrap();
+    let content = extract_selected(html, &selector, &base).expect("unclosed container");
+    assert!(content.contains("Premier paragraphe"));
+    assert!(content.contains("Second"));
+    assert!(content.contains(r#"href="https://example.org/a/lien.html""#));
+
+    let selector = ContentSelector::parse(".wrap").unwrap();
+    assert!(extract_selected(html, &selector, &base)
+        .unwrap()
+        .contains("Intro"));
+}
+
+#[test]
+fn malformed_selectors_are_rejected() {
+    for source in ["", "  ", "div[", "div >", "div..body", "a:no-such-class"] {
+        assert!(
+            matches!(
+                ContentSelector::parse(source),
+                Err(PollError::InvalidSelector { .. })
+            ),
+            "{source:?} should be rejected"
+        );
+    }
+    // Any CSS selector scraper understands is accepted.
+    for source in ["a + b", "p ~ p", "[href^=http]", "article > p:first-child"] {
+        assert!(ContentSelector::parse(source).is_ok(), "{source:?}");
+    }
+    assert_eq!(
+        ContentSelector::parse(" article  .body ").unwrap().as_str(),
+        "article  .body"
+    );
+}
+
+#[test]
+fn feeds_validate_their_selector_before_saving() {
+    let invalid = FeedDescriptor {
+        content_selector: Some("div..body".into()),
+        ..feed_at("https://example.org/feed")
+    };
+    assert!(invalid.validate_content_selector().is_err());
+    let valid = FeedDescriptor {
+        content_selector: Some(".story-body".into()),
+        ..invalid
+    };
+    assert!(valid.validate_content_selector().is_ok());
+    assert!(feed_at("https://example.org/feed")
+        .validate_content_selector()
+        .is_ok());
+}
+
+#[tokio::test]
+async fn full_content_replaces_summaries_for_new_entries() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(summary_feed(&server, &["/post"])))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/post"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html; charset=utf-8")
+                .set_body_string(BLOG),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let seen = SeenStore::in_memory();
+    let events = poll_once(&[feed(&server)], &config(), &Client::new(), &seen)
+        .await
+        .0;
+    let entries = new_entries(events);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].summary.as_deref(), Some("Résumé court."));
+    let content = entries[0].content_html.as_deref().unwrap();
+    assert!(content.contains("index incrémental"));
+
+    // Already-seen entries are not fetched again (the mock expects a single hit).
+    poll_once(&[feed(&server)], &config(), &Client::new(), &seen).await;
+}
+
+#[tokio::test]
+async fn slow_or_oversized_pages_keep_the_feed_entry() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(summary_feed(&server, &["/slow", "/huge", "/ok"])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/slow"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(BLOG)
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/huge"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(BLOG.repeat(20)))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/ok"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(NEWS))
+        .mount(&server)
+        .await;
+
+    let cfg = PollConfig {
+        max_article_bytes: 64 * 1024,
+        ..config()
+    };
+    let started = std::time::Instant::now();
+    let events = poll_once(
+        &[feed(&server)],
+        &cfg,
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    assert!(started.elapsed() < Duration::from_secs(3));
+
+    let entries = new_entries(events);
+    assert_eq!(entries.len(), 3);
+    let by_title = |t: &str| entries.iter().find(|e| e.title == t).unwrap();
+    assert_eq!(by_title("/slow").content_html, None);
+    assert_eq!(by_title("/huge").content_html, None);
+    assert!(by_title("/ok")
+        .content_html
+        .as_deref()
+        .unwrap()
+        .contains("21 heures"));
+}
+
+#[tokio::test]
+async fn feed_selector_replaces_readability_and_falls_back_to_the_summary() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(summary_feed(&server, &["/port", "/blog"])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/port"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(PAYWALL))
+        .mount(&server)
+        .await;
+    // The blog layout has no `.story-body`: the summary is kept, readability is not tried.
+    Mock::given(method("GET"))
+        .and(path("/blog"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(BLOG))
+        .mount(&server)
+        .await;
+
+    let descriptor = FeedDescriptor {
+        content_selector: Some("article .story-body".into()),
+        ..feed(&server)
+    };
+    let events = poll_once(
+        &[descriptor],
+        &config(),
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+
+    let entries = new_entries(events);
+    assert_eq!(entries.len(), 2);
+    let by_title = |t: &str| entries.iter().find(|e| e.title == t).unwrap();
+    let port = by_title("/port").content_html.as_deref().unwrap();
+    assert!(port.contains("navette électrique"));
+    assert!(!port.contains("Réservé aux abonnés"));
+    let blog = by_title("/blog");
+    assert_eq!(blog.content_html, None);
+    assert_eq!(blog.summary.as_deref(), Some("Résumé court."));
+}
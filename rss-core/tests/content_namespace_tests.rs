@@ -0,0 +1,53 @@
+use rss_core::FeedEntry;
+
+const CONTENT_PREFIX: &str = include_str!("fixtures/content_prefix_rss.xml");
+const MEDIA_ITUNES: &str = include_str!("fixtures/media_itunes_rss.xml");
+
+fn entries(xml: &str) -> Vec<FeedEntry> {
+    let channel = rss::Channel::read_from(xml.as_bytes()).unwrap();
+    channel
+        .items()
+        .iter()
+        .map(|item| FeedEntry::from_rss_item_in("feed", item, channel.namespaces()))
+        .collect()
+}
+
+#[test]
+fn content_module_is_found_under_any_prefix() {
+    let entries = entries(CONTENT_PREFIX);
+    assert_eq!(
+        entries[0].content_html.as_deref(),
+        Some("<p>Texte <strong>complet</strong> du billet.</p>")
+    );
+    // `content:` bound to another namespace is not the content module.
+    assert_eq!(entries[1].content_html, None);
+    assert_eq!(entries[1].summary.as_deref(), Some("Résumé seul"));
+}
+
+#[test]
+fn usual_content_prefix_is_read() {
+    let entries = entries(MEDIA_ITUNES);
+    assert_eq!(
+        entries[2].content_html.as_deref(),
+        Some("<p>Transcription.</p>")
+    );
+
+    // Without the channel declarations, the usual prefix is still recognised.
+    let channel = rss::Channel::read_from(MEDIA_ITUNES.as_bytes()).unwrap();
+    let entry = FeedEntry::from_rss_item("feed", &channel.items()[2]);
+    assert_eq!(entry.content_html.as_deref(), Some("<p>Transcription.</p>"));
+}
+
+#[test]
+fn media_description_then_itunes_summary_are_fallbacks() {
+    let entries = entries(MEDIA_ITUNES);
+    // Only `type="html"` counts, here nested in `media:group`.
+    assert_eq!(
+        entries[0].content_html.as_deref(),
+        Some("<p>Notes <em>détaillées</em>.</p>")
+    );
+    assert_eq!(
+        entries[1].content_html.as_deref(),
+        Some("Résumé iTunes seul")
+    );
+}
@@ -0,0 +1,89 @@
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, Event, FeedDescriptor, FetchErrorKind, FetchOutcome, PollConfig, SeenStore,
+};
+
+fn descriptor(server: &MockServer, route: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "feed1".into(),
+        title: "Feed".into(),
+        url: format!("{}{}", server.uri(), route),
+        ..Default::default()
+    }
+}
+
+async fn poll_body(content_type: &str, body: &str) -> FetchOutcome {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", content_type)
+                .set_body_string(body),
+        )
+        .mount(&server)
+        .await;
+    let cfg = PollConfig {
+        max_retries: 2,
+        retry_backoff_ms: 5,
+        ..PollConfig::default()
+    };
+    let events = poll_once(
+        &[descriptor(&server, "/page")],
+        &cfg,
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    events
+        .into_iter()
+        .find_map(|e| match e {
+            Event::FetchCompleted(_, outcome) => Some(outcome),
+            _ => None,
+        })
+        .expect("fetch outcome")
+}
+
+#[tokio::test]
+async fn html_page_is_reported_as_not_a_feed() {
+    let outcome = poll_body(
+        "text/html; charset=utf-8",
+        "<!DOCTYPE html>\n<html><head><title>Article</title></head><body><p>Bonjour</p></body></html>",
+    )
+    .await;
+    assert!(!outcome.success);
+    assert_eq!(
+        outcome.error_kind,
+        Some(FetchErrorKind::NotAFeed {
+            looks_like_html: true
+        })
+    );
+    // Not retried: a web page will not turn into a feed
+    assert_eq!(outcome.attempts, 1);
+}
+
+#[tokio::test]
+async fn json_document_is_not_a_feed_nor_html() {
+    let outcome = poll_body("application/json", r#"{"status": "ok", "items": []}"#).await;
+    assert!(!outcome.success);
+    assert_eq!(
+        outcome.error_kind,
+        Some(FetchErrorKind::NotAFeed {
+            looks_like_html: false
+        })
+    );
+}
+
+#[tokio::test]
+async fn real_feed_served_as_html_still_parses() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- generated -->
+<rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#;
+    let outcome = poll_body("text/html", rss).await;
+    assert!(outcome.success, "{:?}", outcome.error);
+    assert_eq!(outcome.new_items, 1);
+}
@@ -1,4 +1,4 @@
-use rss_core::{shared_feed_list, DataApi, FeedDescriptor};
+use rss_core::{shared_feed_list, DataApi, FeedDescriptor, FeedEntry, SeenStore};
 
 #[tokio::test]
 async fn load_uses_tmp_fallback_on_corrupted_json() {
@@ -25,6 +25,7 @@ async fn load_uses_tmp_fallback_on_corrupted_json() {
         id: "x".into(),
         title: "T".into(),
         url: "http://example.com".into(),
+        ..Default::default()
     };
     let vec = vec![fd.clone()];
     let bytes = serde_json::to_vec(&vec).unwrap();
@@ -44,3 +45,43 @@ async fn load_uses_tmp_fallback_on_corrupted_json() {
     // cleanup
     let _ = tokio::fs::remove_dir_all(&dir).await;
 }
+
+#[tokio::test]
+async fn seen_store_uses_tmp_fallback_on_corrupted_json() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_seen_corrupt_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    tokio::fs::create_dir_all(&dir).await.unwrap();
+
+    // Write corrupted seen_store.json and a valid tmp file
+    let path = dir.join("seen_store.json");
+    tokio::fs::write(&path, b"{ \"seen\": { truncated")
+        .await
+        .unwrap();
+    tokio::fs::write(
+        dir.join("seen_store.json.tmp"),
+        br#"{ "seen": { "f1": ["guid:1"] } }"#,
+    )
+    .await
+    .unwrap();
+
+    let seen = SeenStore::load_from(&path).await;
+    let entry = FeedEntry {
+        feed_id: "f1".into(),
+        title: "A".into(),
+        url: "http://e/1".into(),
+        guid: Some("1".into()),
+        ..Default::default()
+    };
+    assert!(
+        !seen.is_new_and_mark(&entry).await,
+        "should fall back to tmp file when main is corrupted"
+    );
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    compute_stats, format_bytes, poll_once, CycleSummary, Event, FeedDescriptor, FeedService,
+    FeedStats, PollConfig, SeenStore, StatsRange,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#;
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 2,
+        retry_backoff_ms: 5,
+        ..PollConfig::default()
+    }
+}
+
+async fn server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ok"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(RSS))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn poll_once_sums_bytes_new_articles_and_errors() {
+    let server = server().await;
+    let feeds = [
+        FeedDescriptor::new("ok", "Ok", format!("{}/ok", server.uri())),
+        FeedDescriptor::new("missing", "Missing", format!("{}/missing", server.uri())),
+    ];
+    let (events, summary) =
+        poll_once(&feeds, &config(), &Client::new(), &SeenStore::in_memory()).await;
+
+    assert_eq!(summary.feeds_polled, 2);
+    assert_eq!(summary.bytes_downloaded, RSS.len() as u64);
+    assert_eq!(summary.new_articles, 1);
+    assert_eq!(summary.errors, 1);
+    assert!(!events.iter().any(|e| matches!(e, Event::CycleCompleted(_))));
+    let per_feed: u64 = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::FetchCompleted(_, outcome) => Some(outcome.bytes_downloaded),
+            _ => None,
+        })
+        .sum();
+    assert_eq!(per_feed, summary.bytes_downloaded);
+}
+
+#[tokio::test]
+async fn service_refresh_ends_with_the_cycle_summary() {
+    let server = server().await;
+    let service = FeedService::in_memory();
+    service
+        .add_feed(FeedDescriptor::new(
+            "ok",
+            "Ok",
+            format!("{}/ok", server.uri()),
+        ))
+        .await
+        .unwrap();
+    let mut rx = service.subscribe_events();
+
+    let events = service.refresh_all().await;
+    let Some(Event::CycleCompleted(summary)) = events.last() else {
+        panic!("refresh_all must end with CycleCompleted");
+    };
+    assert_eq!(summary.feeds_polled, 1);
+    assert_eq!(summary.bytes_downloaded, RSS.len() as u64);
+    let mut broadcast = None;
+    while let Ok(event) = rx.try_recv() {
+        if let Event::CycleCompleted(summary) = event {
+            broadcast = Some(summary);
+        }
+    }
+    assert_eq!(broadcast.as_ref(), Some(summary));
+
+    // Cumulative totals in the statistics.
+    let range = StatsRange::last_days(chrono::Local::now().date_naive(), 7);
+    let stats = service.data_api().stats(range).await;
+    assert_eq!(stats.fetches, 1);
+    assert_eq!(stats.bytes_downloaded, RSS.len() as u64);
+    service.shutdown().await.unwrap();
+}
+
+#[test]
+fn totals_and_sizes_are_readable() {
+    let mut feed_stats = HashMap::new();
+    feed_stats.insert(
+        "a".to_string(),
+        FeedStats {
+            fetch_count: 3,
+            total_bytes: 1536,
+            ..FeedStats::default()
+        },
+    );
+    let range = StatsRange::last_days(chrono::Local::now().date_naive(), 1);
+    let stats = compute_stats(&[], |_| false, &feed_stats, range, &chrono::Utc);
+    assert_eq!((stats.fetches, stats.bytes_downloaded), (3, 1536));
+
+    assert_eq!(format_bytes(512), "512 o");
+    assert_eq!(format_bytes(1536), "1,5 Ko");
+    assert_eq!(format_bytes(5 * 1024 * 1024), "5,0 Mo");
+    assert_eq!(CycleSummary::default().bytes_downloaded, 0);
+}
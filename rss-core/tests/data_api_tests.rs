@@ -21,8 +21,9 @@ async fn data_api_persists_feeds_and_read_state() {
         id: "f1".into(),
         title: "Feed 1".into(),
         url: "http://example.com/feed".into(),
+        ..Default::default()
     };
-    api.add_feed(fd.clone()).await;
+    api.add_feed(fd.clone()).await.unwrap();
 
     let feeds_list = api.list_feeds().await;
     assert_eq!(feeds_list.len(), 1);
@@ -38,16 +39,11 @@ async fn data_api_persists_feeds_and_read_state() {
     let entry = rss_core::FeedEntry {
         feed_id: "f1".into(),
         title: "A".into(),
-        summary: None,
         url: "http://e/1".into(),
-        published_at: None,
         guid: Some("guid-1".into()),
-        author: None,
-        category: None,
-        content_html: None,
-        image_url: None,
+        ..Default::default()
     };
-    api2.mark_read(&entry).await;
+    api2.mark_read(&entry).await.unwrap();
 
     // Reopen
     let feeds3 = shared_feed_list(Vec::new());
@@ -57,3 +53,252 @@ async fn data_api_persists_feeds_and_read_state() {
     // Cleanup: remove temp dir
     let _ = tokio::fs::remove_dir_all(&dir).await;
 }
+
+#[tokio::test]
+async fn upsert_articles_evicts_read_before_unread_when_over_limit() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_limit_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    tokio::fs::create_dir_all(&dir).await.unwrap();
+
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let base = chrono::Utc::now();
+    let make = |i: i64| rss_core::FeedEntry {
+        feed_id: "f1".into(),
+        title: format!("A{}", i),
+        url: format!("http://e/{}", i),
+        published_at: Some(base - chrono::Duration::minutes(i)),
+        guid: Some(format!("guid-{}", i)),
+        ..Default::default()
+    };
+
+    // 0 is the newest, 4 the oldest; mark the two newest as read
+    let entries: Vec<_> = (0..5).map(make).collect();
+    api.upsert_articles("f1", entries.clone()).await.unwrap();
+    api.mark_read(&entries[0]).await.unwrap();
+    api.mark_read(&entries[1]).await.unwrap();
+
+    // Shrink the limit: read entries must go first, then the oldest unread
+    api.set_max_articles_per_feed(2);
+    api.upsert_articles("f1", Vec::new()).await.unwrap();
+    let kept: Vec<String> = api
+        .list_articles("f1")
+        .await
+        .into_iter()
+        .map(|e| e.title)
+        .collect();
+    assert_eq!(kept, vec!["A2".to_string(), "A3".to_string()]);
+
+    // When only read entries remain above the limit, keep the newest of them
+    api.set_max_articles_per_feed(3);
+    api.upsert_articles("f1", vec![entries[0].clone()])
+        .await
+        .unwrap();
+    let kept: Vec<String> = api
+        .list_articles("f1")
+        .await
+        .into_iter()
+        .map(|e| e.title)
+        .collect();
+    assert_eq!(
+        kept,
+        vec!["A0".to_string(), "A2".to_string(), "A3".to_string()]
+    );
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+fn dated_entry(feed_id: &str, i: i64, days_ago: i64) -> rss_core::FeedEntry {
+    rss_core::FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("A{}", i),
+        url: format!("http://e/{}/{}", feed_id, i),
+        published_at: Some(chrono::Utc::now() - chrono::Duration::days(days_ago)),
+        guid: Some(format!("guid-{}", i)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn mark_read_batch_writes_read_store_once() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_batch_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let entries: Vec<_> = (0..500).map(|i| dated_entry("f1", i, 0)).collect();
+
+    assert_eq!(api.mark_read_batch(&entries).await.unwrap(), 500);
+    assert_eq!(api.read_store_writes(), 1);
+
+    // Already read: nothing to persist
+    assert_eq!(api.mark_read_batch(&entries).await.unwrap(), 0);
+    assert_eq!(api.read_store_writes(), 1);
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(reloaded.is_read(&entries[0]).await);
+    assert!(reloaded.is_read(&entries[499]).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn mark_feed_read_is_scoped_to_feed_and_age() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_feed_read_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let recent = dated_entry("f1", 0, 1);
+    let old = dated_entry("f1", 1, 10);
+    let other = dated_entry("f2", 2, 10);
+    api.upsert_articles("f1", vec![recent.clone(), old.clone()])
+        .await
+        .unwrap();
+    api.upsert_articles("f2", vec![other.clone()])
+        .await
+        .unwrap();
+
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(7);
+    assert_eq!(api.mark_feed_read("f1", Some(cutoff)).await.unwrap(), 1);
+    assert!(api.is_read(&old).await);
+    assert!(!api.is_read(&recent).await);
+    assert!(!api.is_read(&other).await);
+
+    assert_eq!(api.mark_feed_read("f1", None).await.unwrap(), 1);
+    assert!(api.is_read(&recent).await);
+    assert!(!api.is_read(&other).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn unread_count_spans_all_feeds() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_unread_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(api.unread_count().await, 0);
+
+    let first = dated_entry("f1", 0, 1);
+    api.upsert_articles("f1", vec![first.clone(), dated_entry("f1", 1, 2)])
+        .await
+        .unwrap();
+    api.upsert_articles("f2", vec![dated_entry("f2", 2, 3)])
+        .await
+        .unwrap();
+    assert_eq!(api.unread_count().await, 3);
+
+    api.mark_read(&first).await.unwrap();
+    assert_eq!(api.unread_count().await, 2);
+    api.mark_feed_read("f2", None).await.unwrap();
+    assert_eq!(api.unread_count().await, 1);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn read_flags_and_revision_follow_the_read_state() {
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+    let first = dated_entry("f1", 0, 1);
+    let second = dated_entry("f1", 1, 2);
+    let other = dated_entry("f2", 0, 1);
+    let start = api.read_revision();
+
+    api.mark_read(&first).await.unwrap();
+    assert_eq!(
+        api.read_flags(&[&first, &second, &other]).await,
+        [true, false, false]
+    );
+    let after_mark = api.read_revision();
+    assert!(after_mark > start);
+
+    // Marking an article already read changes nothing.
+    api.mark_read(&first).await.unwrap();
+    assert_eq!(api.read_revision(), after_mark);
+
+    let marks = api
+        .mark_read_batch_tracked(&[second.clone(), other.clone()])
+        .await
+        .unwrap();
+    assert!(api.read_revision() > after_mark);
+    let after_batch = api.read_revision();
+    api.unmark_read(&marks).await.unwrap();
+    assert!(api.read_revision() > after_batch);
+    assert_eq!(
+        api.read_flags(&[&first, &second, &other]).await,
+        [true, false, false]
+    );
+}
+
+#[tokio::test]
+async fn flush_all_rewrites_every_store() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_flush_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let entry = dated_entry("f1", 0, 1);
+    api.upsert_articles("f1", vec![entry.clone()])
+        .await
+        .unwrap();
+    api.mark_read(&entry).await.unwrap();
+    // Single marks land in the journal first; fold it into read_store.json.
+    api.compact_read_journal().await.unwrap();
+    api.set_reading_position(&entry, 120.0).await.unwrap();
+
+    // Files lost behind the API's back (e.g. a crash mid-write) come back from memory.
+    for name in [
+        "read_store.json",
+        "articles_store.json",
+        "reading_positions.json",
+    ] {
+        tokio::fs::remove_file(dir.join(name)).await.unwrap();
+    }
+    api.flush_all().await.unwrap();
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(reloaded.is_read(&entry).await);
+    assert_eq!(reloaded.list_articles("f1").await.len(), 1);
+    assert_eq!(reloaded.reading_position(&entry).await, Some(120.0));
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[test]
+fn ui_config_without_window_state_still_loads() {
+    let json = r#"{"font_size": 14.0, "left_panel_width": 300.0,
+        "show_article_preview": true, "articles_per_page": 20}"#;
+    let ui: rss_core::UiConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(ui.window_size, None);
+
+    let saved = rss_core::UiConfig {
+        window_size: Some([1024.0, 768.0]),
+        ..rss_core::UiConfig::default()
+    };
+    let json = serde_json::to_string(&saved).unwrap();
+    let ui: rss_core::UiConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(ui.window_size, Some([1024.0, 768.0]));
+}
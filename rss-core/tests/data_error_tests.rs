@@ -0,0 +1,86 @@
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use rss_core::{shared_feed_list, DataApi, DataError, FeedDescriptor, FeedEntry};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_{}_{}",
+        name,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Makes the config directory read-only. Root ignores permission bits, so the temporary file
+// used by the atomic write is also taken by a directory: the write fails either way.
+fn lock_dir(dir: &Path) {
+    std::fs::create_dir(dir.join("feeds.json.tmp")).unwrap();
+    std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o555)).unwrap();
+}
+
+fn unlock_dir(dir: &Path) {
+    std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+    std::fs::remove_dir(dir.join("feeds.json.tmp")).unwrap();
+}
+
+#[tokio::test]
+async fn write_to_read_only_dir_is_reported() {
+    let dir = temp_dir("data_error");
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    lock_dir(&dir);
+
+    let err = api
+        .add_feed(FeedDescriptor::new(
+            "f1",
+            "Feed 1",
+            "http://example.com/feed",
+        ))
+        .await
+        .expect_err("write to a read-only directory must fail");
+    assert!(matches!(err, DataError::Io { .. }), "{:?}", err);
+    assert_eq!(err.path(), dir.join("feeds.json"));
+    // The feed is kept in memory.
+    assert_eq!(api.list_feeds().await.len(), 1);
+
+    unlock_dir(&dir);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn failed_write_is_retried_on_next_operation() {
+    let dir = temp_dir("data_retry");
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    lock_dir(&dir);
+    assert!(api
+        .add_feed(FeedDescriptor::new(
+            "f1",
+            "Feed 1",
+            "http://example.com/feed"
+        ))
+        .await
+        .is_err());
+    assert!(!dir.join("feeds.json").exists());
+
+    unlock_dir(&dir);
+    // Any later write also flushes the store that failed before.
+    let article = FeedEntry {
+        feed_id: "f1".into(),
+        title: "Article".into(),
+        url: "http://example.com/1".into(),
+        guid: Some("1".into()),
+        ..Default::default()
+    };
+    api.mark_read(&article).await.unwrap();
+    let saved = std::fs::read_to_string(dir.join("feeds.json")).unwrap();
+    assert!(saved.contains("http://example.com/feed"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
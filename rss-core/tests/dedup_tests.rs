@@ -0,0 +1,222 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{Duration, TimeZone, Utc};
+
+use rss_core::{
+    cluster_titles, dedup_entries, normalize_title, normalize_url, shared_feed_list,
+    title_similarity, DataApi, FeedEntry, TitleClusters, DEFAULT_TITLE_SIMILARITY,
+};
+
+fn entry(feed_id: &str, url: &str, guid: Option<&str>, minutes_ago: Option<i64>) -> FeedEntry {
+    let base = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("{} {}", feed_id, url),
+        url: url.into(),
+        published_at: minutes_ago.map(|m| base - Duration::minutes(m)),
+        guid: guid.map(Into::into),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn normalize_url_strips_tracking_fragment_and_trailing_slash() {
+    assert_eq!(
+        normalize_url("https://Example.com/post/1/?utm_source=rss&utm_medium=feed#comments"),
+        "https://example.com/post/1"
+    );
+    assert_eq!(
+        normalize_url("https://example.com/post?id=3&utm_campaign=x"),
+        "https://example.com/post?id=3"
+    );
+    assert_eq!(
+        normalize_url("  https://example.com/  "),
+        "https://example.com/"
+    );
+    assert_eq!(normalize_url("/relative/path/#top"), "/relative/path");
+}
+
+#[test]
+fn normalize_url_keeps_meaningful_differences() {
+    assert_ne!(
+        normalize_url("https://example.com/post?id=3"),
+        normalize_url("https://example.com/post?id=4")
+    );
+    assert_ne!(
+        normalize_url("https://example.com/a"),
+        normalize_url("https://example.com/b")
+    );
+}
+
+#[test]
+fn duplicates_collapse_on_normalized_url_or_guid() {
+    let entries = vec![
+        entry("main", "https://site/a/?utm_source=main", None, Some(5)),
+        entry("tech", "https://site/a", None, Some(30)),
+        entry("main", "https://site/b", Some("tag:site,b"), Some(10)),
+        entry("other", "https://mirror/b", Some("tag:site,b"), Some(2)),
+        entry("tech", "https://site/c", None, None),
+    ];
+
+    let deduped = dedup_entries(entries);
+    assert_eq!(deduped.len(), 3);
+
+    // earliest publication wins, position of the first member is kept
+    assert_eq!(deduped[0].entry.feed_id, "tech");
+    assert_eq!(deduped[0].feed_ids, vec!["main", "tech"]);
+    assert_eq!(
+        deduped[0].other_feed_ids().collect::<Vec<_>>(),
+        vec!["main"]
+    );
+
+    assert_eq!(deduped[1].entry.feed_id, "main");
+    assert_eq!(deduped[1].feed_ids, vec!["main", "other"]);
+
+    assert_eq!(deduped[2].feed_ids, vec!["tech"]);
+    assert_eq!(deduped[2].other_feed_ids().count(), 0);
+}
+
+#[test]
+fn undated_copies_lose_against_dated_ones() {
+    let entries = vec![
+        entry("a", "https://site/x", None, None),
+        entry("b", "https://site/x#frag", None, Some(60)),
+    ];
+    let deduped = dedup_entries(entries);
+    assert_eq!(deduped.len(), 1);
+    assert_eq!(deduped[0].entry.feed_id, "b");
+}
+
+#[tokio::test]
+async fn data_api_lists_deduplicated_articles() {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("readrss_dedup_{}", nanos));
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+
+    api.upsert_articles(
+        "main",
+        vec![
+            entry("main", "https://site/a", None, Some(5)),
+            entry("main", "https://site/b", None, Some(1)),
+        ],
+    )
+    .await
+    .unwrap();
+    api.upsert_articles(
+        "category",
+        vec![entry(
+            "category",
+            "https://site/a/?utm_medium=rss",
+            None,
+            Some(20),
+        )],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(api.list_all_articles().await.len(), 3);
+    let deduped = api.list_all_articles_deduped().await;
+    assert_eq!(deduped.len(), 2);
+    assert_eq!(deduped[0].entry.url, "https://site/b");
+    assert_eq!(deduped[1].entry.feed_id, "category");
+    assert_eq!(deduped[1].feed_ids.len(), 2);
+
+    // per-feed listings are untouched
+    assert_eq!(api.list_articles("main").await.len(), 2);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+fn titled(feed_id: &str, title: &str, hours_ago: i64) -> FeedEntry {
+    let mut e = entry(
+        feed_id,
+        &format!("https://{}.example/{}", feed_id, hours_ago),
+        None,
+        Some(hours_ago * 60),
+    );
+    e.title = title.into();
+    e
+}
+
+#[test]
+fn titles_are_normalized_before_comparison() {
+    assert_eq!(
+        normalize_title("  Acme Corp. ANNOUNCES: record-breaking Q3!  "),
+        "acme corp announces record breaking q3"
+    );
+    assert_eq!(
+        title_similarity("Acme announces Q3 results", "ACME announces Q3 results!"),
+        1.0
+    );
+    assert!(title_similarity("Acme announces Q3 results", "Rust 2.0 released") < 0.1);
+}
+
+#[test]
+fn near_duplicate_titles_cluster_across_feeds_only() {
+    let entries = vec![
+        titled(
+            "wire",
+            "Acme Corp announces record third-quarter results",
+            1,
+        ),
+        titled(
+            "daily",
+            "Acme Corp announces record third quarter results!",
+            2,
+        ),
+        // Same feed as the first: never merged, even with the same title.
+        titled(
+            "wire",
+            "Acme Corp announces record third-quarter results",
+            3,
+        ),
+        titled("tech", "Rust 2.0 released", 4),
+        // Outside the 48-hour window of the first article.
+        titled(
+            "weekly",
+            "Acme Corp announces record third quarter results",
+            60,
+        ),
+        titled(
+            "blog",
+            "ACME CORP ANNOUNCES RECORD THIRD QUARTER RESULTS",
+            5,
+        ),
+    ];
+    let groups = cluster_titles(&entries, DEFAULT_TITLE_SIMILARITY);
+    assert_eq!(groups, vec![vec![0, 1, 5], vec![2], vec![3], vec![4]]);
+
+    // A stricter threshold keeps the reworded title apart.
+    let strict = cluster_titles(&entries[..2], 1.0);
+    assert_eq!(strict.len(), 1);
+    let reworded = vec![
+        titled(
+            "wire",
+            "Acme Corp announces record third-quarter results",
+            1,
+        ),
+        titled(
+            "daily",
+            "Acme announces record results for the third quarter",
+            2,
+        ),
+    ];
+    assert_eq!(cluster_titles(&reworded, 0.95).len(), 2);
+}
+
+#[test]
+fn clusters_grow_incrementally() {
+    let mut clusters = TitleClusters::new(DEFAULT_TITLE_SIMILARITY);
+    let first = titled("wire", "Storm closes schools across the region", 1);
+    let id = clusters.insert(&first);
+    assert_eq!(clusters.insert(&first), id);
+    let later = titled("local", "Storm closes schools across the region", 2);
+    assert_eq!(clusters.insert(&later), id);
+    assert_eq!(clusters.cluster_of(&later), Some(id));
+    let other = titled("local", "Council approves new budget", 3);
+    assert_ne!(clusters.insert(&other), id);
+    assert_eq!(clusters.cluster_of(&titled("x", "unknown", 1)), None);
+}
@@ -0,0 +1,90 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rss_core::{feed_url_key, shared_feed_list, AddFeedError, DataApi, FeedDescriptor};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_duplicate_{}", nanos))
+}
+
+fn descriptor(id: &str, url: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: format!("Titre {}", id),
+        url: url.into(),
+        ..Default::default()
+    }
+}
+
+async fn api_with_one_feed() -> DataApi {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    api.try_add_feed(descriptor("first", "https://Example.org/blog/feed"))
+        .await
+        .unwrap();
+    api
+}
+
+#[test]
+fn feed_url_key_ignores_scheme_case_and_trailing_slash() {
+    let key = feed_url_key("https://example.org/feed");
+    assert!(key.is_some());
+    assert_eq!(feed_url_key("http://EXAMPLE.org/feed/"), key);
+    assert_ne!(feed_url_key("https://example.org/feed?lang=fr"), key);
+    assert_ne!(feed_url_key("https://example.org:8443/feed"), key);
+    assert_eq!(feed_url_key("ftp://example.org/feed"), None);
+    assert_eq!(feed_url_key("pas une url"), None);
+}
+
+#[tokio::test]
+async fn trailing_slash_is_a_duplicate() {
+    let api = api_with_one_feed().await;
+    let err = api
+        .try_add_feed(descriptor("second", "https://example.org/blog/feed/"))
+        .await
+        .unwrap_err();
+    match err {
+        AddFeedError::Duplicate { id, title } => {
+            assert_eq!(id, "first");
+            assert_eq!(title, "Titre first");
+        }
+        other => panic!("unexpected error: {}", other),
+    }
+    assert_eq!(api.list_feeds().await.len(), 1);
+}
+
+#[tokio::test]
+async fn http_and_https_are_the_same_feed() {
+    let api = api_with_one_feed().await;
+    let err = api
+        .try_add_feed(descriptor("second", "http://example.org/blog/feed"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AddFeedError::Duplicate { .. }));
+}
+
+#[tokio::test]
+async fn different_paths_and_invalid_urls() {
+    let api = api_with_one_feed().await;
+    api.try_add_feed(descriptor("second", "https://example.org/blog/rss"))
+        .await
+        .unwrap();
+    api.try_add_feed(descriptor("third", "https://example.org/feed"))
+        .await
+        .unwrap();
+    assert_eq!(api.list_feeds().await.len(), 3);
+
+    let err = api
+        .try_add_feed(descriptor("fourth", "example.org/feed"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AddFeedError::InvalidUrl(_)));
+
+    // Re-adding the same id updates the feed instead of reporting itself as a duplicate.
+    api.try_add_feed(descriptor("first", "https://example.org/blog/feed/"))
+        .await
+        .unwrap();
+    assert_eq!(api.list_feeds().await.len(), 3);
+}
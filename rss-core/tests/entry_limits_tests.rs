@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use reqwest::Client;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::sanitize::TRUNCATION_NOTE;
+use rss_core::{poll_once, Event, FeedConfig, FeedDescriptor, FeedEntry, PollConfig, SeenStore};
+
+fn entry(content: &str, summary: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: "podcast".into(),
+        title: "Épisode".into(),
+        summary: Some(summary.into()),
+        url: "https://podcast.example.org/1".into(),
+        guid: Some("1".into()),
+        guid_is_permalink: Some(false),
+        content_html: Some(content.into()),
+        ..Default::default()
+    }
+}
+
+fn config(max_articles_per_feed: usize) -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        max_articles_per_feed,
+        ..PollConfig::default()
+    }
+}
+
+async fn feed_server(body: String) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(body),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+// RSS document with one item per (guid, day of January 2024, show notes).
+fn rss(items: &[(String, u32, String)]) -> String {
+    let items: String = items
+        .iter()
+        .map(|(guid, day, notes)| {
+            let date = Utc.with_ymd_and_hms(2024, 1, *day, 12, 0, 0).unwrap();
+            format!(
+                "<item><title>{guid}</title><link>https://e/{guid}</link><guid>{guid}</guid>\
+                 <pubDate>{}</pubDate><description><![CDATA[{notes}]]></description></item>",
+                date.to_rfc2822()
+            )
+        })
+        .collect();
+    format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>Podcast</title>{items}</channel></rss>"#
+    )
+}
+
+fn new_articles(events: &[Event]) -> Vec<String> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::NewArticles(_, entries) => Some(entries.iter().map(|e| e.title.clone())),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+#[test]
+fn oversized_content_is_cut_at_a_character_boundary_and_flagged() {
+    // Multi-byte characters straddle every possible limit.
+    let notes = format!("<p>{}</p>", "é🎙".repeat(1000));
+    for max in [100, 101, 102, 103, 104, 105] {
+        let cut = entry(&notes, "<p>Court</p>").sanitized(max);
+        let content = cut.content_html.as_deref().unwrap();
+        assert!(cut.content_truncated, "limit {}", max);
+        assert!(content.ends_with(TRUNCATION_NOTE));
+        assert!(content.len() <= max);
+        assert_eq!(cut.summary.as_deref(), Some("<p>Court</p>"));
+    }
+
+    // Under the limit, nothing changes; the summary alone can also be cut.
+    let small = entry("<p>Notes</p>", "<p>Résumé</p>").sanitized(1024);
+    assert!(!small.content_truncated);
+    assert_eq!(small.content_html.as_deref(), Some("<p>Notes</p>"));
+    let long_summary = entry("<p>Notes</p>", &"résumé ".repeat(500)).sanitized(1024);
+    assert!(long_summary.content_truncated);
+
+    // The flag survives the article store; untruncated articles do not write it.
+    let json = serde_json::to_string(&entry(&notes, "").sanitized(100)).unwrap();
+    let back: FeedEntry = serde_json::from_str(&json).unwrap();
+    assert!(back.content_truncated);
+    assert!(!serde_json::to_string(&small)
+        .unwrap()
+        .contains("content_truncated"));
+}
+
+#[test]
+fn entry_content_limit_accepts_its_descriptive_name() {
+    let feeds: FeedConfig =
+        serde_json::from_str(r#"{ "max_entry_content_bytes": 65536 }"#).unwrap();
+    assert_eq!(feeds.max_content_bytes, 65536);
+    assert_eq!(
+        PollConfig::from_feed_config(&feeds).max_content_bytes,
+        65536
+    );
+}
+
+#[tokio::test]
+async fn huge_show_notes_are_bounded_at_parse_time() {
+    let notes = format!("<p>{}</p>", "Notes d’épisode — ".repeat(120_000));
+    assert!(notes.len() > 2 * 1024 * 1024);
+    let server = feed_server(rss(&[("ep1".into(), 1, notes)])).await;
+    let feed = FeedDescriptor::new("podcast", "Podcast", server.uri());
+    let cfg = PollConfig {
+        max_feed_bytes: 8 * 1024 * 1024,
+        ..config(100)
+    };
+
+    let (events, _) = poll_once(&[feed], &cfg, &Client::new(), &SeenStore::in_memory()).await;
+    let entry = events
+        .iter()
+        .find_map(|e| match e {
+            Event::NewArticles(_, entries) => entries.first(),
+            _ => None,
+        })
+        .expect("the episode is new");
+    assert!(entry.content_truncated);
+    let summary = entry.summary.as_deref().unwrap();
+    assert!(summary.len() <= cfg.max_content_bytes);
+}
+
+#[tokio::test]
+async fn items_beyond_the_per_feed_cap_never_reach_the_seen_store() {
+    // Oldest first, as some feeds publish: the cap keeps the newest three.
+    let items: Vec<(String, u32, String)> = (1..=10)
+        .map(|day| (format!("ep{:02}", day), day, String::new()))
+        .collect();
+    let server = feed_server(rss(&items)).await;
+    let feed = FeedDescriptor::new("podcast", "Podcast", server.uri());
+    let seen = SeenStore::in_memory();
+
+    let (events, summary) = poll_once(
+        std::slice::from_ref(&feed),
+        &config(3),
+        &Client::new(),
+        &seen,
+    )
+    .await;
+    assert_eq!(new_articles(&events), ["ep08", "ep09", "ep10"]);
+    assert_eq!(summary.new_articles, 3);
+
+    // The dropped items were never marked as seen: a larger cap finds them as new.
+    let (events, _) = poll_once(&[feed], &config(100), &Client::new(), &seen).await;
+    let mut found = new_articles(&events);
+    found.sort();
+    let expected: Vec<String> = (1..=7).map(|day| format!("ep{:02}", day)).collect();
+    assert_eq!(found, expected);
+}
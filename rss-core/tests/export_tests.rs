@@ -0,0 +1,102 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{TimeZone, Utc};
+use rss_core::{shared_feed_list, slugify, DataApi, ExportFormat, FeedDescriptor, FeedEntry};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_export_{}", nanos))
+}
+
+fn entry(title: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: "journal".into(),
+        title: title.into(),
+        summary: Some("<p>Résumé</p>".into()),
+        url: "https://journal.example.org/2024/05/sortie-2-3/".into(),
+        published_at: Some(Utc.with_ymd_and_hms(2024, 5, 6, 8, 0, 0).unwrap()),
+        author: Some("Alice".into()),
+        content_html: Some(
+            "<p>Les <strong>nouveautés</strong> de la 2.3.</p><script>alert(1)</script>".into(),
+        ),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn slugs_replace_slashes_and_drop_emoji() {
+    assert_eq!(
+        slugify("Sortie 2.3 / 2.4 : quoi de neuf ?"),
+        "sortie-2-3-2-4-quoi-de-neuf"
+    );
+    assert_eq!(slugify("🚀 Lancement 🎉 réussi"), "lancement-réussi");
+    assert_eq!(slugify("../../etc/passwd"), "etc-passwd");
+    assert_eq!(slugify("🔥🔥🔥"), "article");
+    assert_eq!(slugify(&"a".repeat(200)).len(), 80);
+}
+
+#[test]
+fn renderers_include_metadata_and_content() {
+    let e = entry("Sortie <2.3> & co");
+    let md = e.to_markdown("Le Journal du Code");
+    assert!(md.starts_with("# Sortie <2.3> & co\n"));
+    assert!(md.contains("- Source : Le Journal du Code"));
+    assert!(md.contains("- Auteur : Alice"));
+    assert!(md.contains("- Date : 2024-05-06T08:00:00+00:00"));
+    assert!(md.contains("<https://journal.example.org/2024/05/sortie-2-3/>"));
+    assert!(md.contains("nouveautés"));
+    assert!(!md.contains("<p>"));
+
+    let html = e.to_html("Le Journal du Code");
+    assert!(html.contains("<h1>Sortie &lt;2.3&gt; &amp; co</h1>"));
+    assert!(html.contains("<strong>nouveautés</strong>"));
+    assert!(html.contains("href=\"https://journal.example.org/2024/05/sortie-2-3/\""));
+    assert!(!html.contains("alert(1)"));
+}
+
+#[tokio::test]
+async fn duplicate_titles_get_distinct_files() {
+    let dir = temp_dir();
+    let feeds = shared_feed_list(Vec::new());
+    let api = DataApi::load_from_dir(feeds, dir.join("config")).await;
+    api.add_feed(FeedDescriptor {
+        id: "journal".into(),
+        title: "Le Journal du Code".into(),
+        url: "https://journal.example.org/feed/".into(),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    let out = dir.join("export");
+    let entries = vec![entry("Revue / semaine"), entry("Revue / semaine")];
+    let first = api
+        .export_articles(&entries, &out, ExportFormat::Markdown)
+        .await
+        .unwrap();
+    let names: Vec<_> = first
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(names, ["revue-semaine.md", "revue-semaine-2.md"]);
+
+    // A second export never overwrites earlier files.
+    let second = api
+        .export_articles(&entries[..1], &out, ExportFormat::Markdown)
+        .await
+        .unwrap();
+    assert!(second[0].ends_with("revue-semaine-3.md"));
+
+    let html = api
+        .export_articles(&entries[..1], &out, ExportFormat::Html)
+        .await
+        .unwrap();
+    assert!(html[0].ends_with("revue-semaine.html"));
+    let body = std::fs::read_to_string(&first[0]).unwrap();
+    assert!(body.contains("- Source : Le Journal du Code"));
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
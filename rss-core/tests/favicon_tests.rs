@@ -0,0 +1,163 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    build_http_client, find_icon_link, shared_feed_list, site_root, DataApi, FeedDescriptor,
+    FeedEntry, NetworkConfig, MAX_ICON_BYTES,
+};
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\nfake-png-body";
+const ICO: &[u8] = &[0, 0, 1, 0, 1, 0, 16, 16];
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_favicon_{}", nanos))
+}
+
+fn descriptor(url: String) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "site:1".into(),
+        title: "Site".into(),
+        url,
+        ..Default::default()
+    }
+}
+
+fn entry(url: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: "site:1".into(),
+        title: "A".into(),
+        url: url.into(),
+        ..Default::default()
+    }
+}
+
+async fn api_for(server: &MockServer) -> (DataApi, std::path::PathBuf) {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor(format!("{}/feeds/main.xml", server.uri())))
+        .await
+        .unwrap();
+    (api, dir)
+}
+
+async fn mount(server: &MockServer, at: &str, response: ResponseTemplate, times: u64) {
+    Mock::given(method("GET"))
+        .and(path(at))
+        .respond_with(response)
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn site_root_prefers_article_links() {
+    let entries = [entry("https://blog.example.org/2024/post")];
+    let root = site_root("https://feeds.host.net/blog", &entries).unwrap();
+    assert_eq!(root.as_str(), "https://blog.example.org/");
+
+    let root = site_root("https://feeds.host.net/blog/rss.xml", &[]).unwrap();
+    assert_eq!(root.as_str(), "https://feeds.host.net/");
+    assert!(site_root("not a url", &[]).is_none());
+}
+
+#[test]
+fn icon_link_is_found_and_resolved() {
+    let base = Url::parse("https://example.org/").unwrap();
+    let html = r#"<html><head>
+        <link rel="stylesheet" href="/style.css">
+        <link rel="apple-touch-icon" href="/touch.png">
+        <LINK REL="Shortcut Icon" HREF="static/icon.png">
+        </head></html>"#;
+    assert_eq!(
+        find_icon_link(html, &base).unwrap().as_str(),
+        "https://example.org/static/icon.png"
+    );
+    assert!(find_icon_link("<link rel='stylesheet' href='a.css'>", &base).is_none());
+}
+
+#[tokio::test]
+async fn declared_icon_is_cached_and_refreshed_weekly() {
+    let server = MockServer::start().await;
+    let page = r#"<html><head><link rel="icon" href="/img/icon.png"></head></html>"#;
+    mount(
+        &server,
+        "/",
+        ResponseTemplate::new(200).set_body_string(page),
+        1,
+    )
+    .await;
+    mount(
+        &server,
+        "/img/icon.png",
+        ResponseTemplate::new(200).set_body_bytes(PNG),
+        1,
+    )
+    .await;
+
+    let (api, dir) = api_for(&server).await;
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    assert!(api.feed_icon("site:1").await.is_none());
+
+    let path = api.refresh_feed_icon(&client, "site:1").await.unwrap();
+    assert!(path.starts_with(dir.join("icons")));
+    assert_eq!(std::fs::read(&path).unwrap(), PNG);
+    assert_eq!(api.feed_icon("site:1").await, Some(path.clone()));
+
+    // Fresh icon: no new request (the mocks above expect exactly one hit each).
+    assert_eq!(api.refresh_feed_icon(&client, "site:1").await, Some(path));
+
+    api.remove_feed("site:1").await.unwrap();
+    assert!(api.feed_icon("site:1").await.is_none());
+}
+
+#[tokio::test]
+async fn falls_back_to_favicon_ico() {
+    let server = MockServer::start().await;
+    mount(
+        &server,
+        "/",
+        ResponseTemplate::new(200).set_body_string("<html></html>"),
+        1,
+    )
+    .await;
+    mount(
+        &server,
+        "/favicon.ico",
+        ResponseTemplate::new(200).set_body_bytes(ICO),
+        1,
+    )
+    .await;
+
+    let (api, _) = api_for(&server).await;
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let path = api.refresh_feed_icon(&client, "site:1").await.unwrap();
+    assert_eq!(std::fs::read(path).unwrap(), ICO);
+}
+
+#[tokio::test]
+async fn missing_or_oversized_icon_is_not_retried_within_a_week() {
+    let server = MockServer::start().await;
+    mount(&server, "/", ResponseTemplate::new(404), 1).await;
+    let mut huge = ICO.to_vec();
+    huge.resize(MAX_ICON_BYTES + 1, 0);
+    mount(
+        &server,
+        "/favicon.ico",
+        ResponseTemplate::new(200).set_body_bytes(huge),
+        1,
+    )
+    .await;
+
+    let (api, _) = api_for(&server).await;
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    assert!(api.refresh_feed_icon(&client, "site:1").await.is_none());
+    assert!(api.refresh_feed_icon(&client, "site:1").await.is_none());
+    assert!(api.feed_icon("site:1").await.is_none());
+}
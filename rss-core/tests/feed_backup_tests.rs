@@ -0,0 +1,143 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{Duration, Utc};
+use rss_core::{shared_feed_list, DataApi, FeedDescriptor, FeedEntry, FetchOutcome};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_backup_{}", nanos))
+}
+
+fn entry(feed_id: &str, i: i64) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("{} {}", feed_id, i),
+        summary: Some(format!("Résumé {}", i)),
+        url: format!("https://example.org/{}/{}", feed_id, i),
+        published_at: Some(Utc::now() - Duration::hours(i)),
+        guid: Some(format!("{}-{}", feed_id, i)),
+        categories: vec!["rust".into()],
+        ..Default::default()
+    }
+}
+
+fn outcome() -> FetchOutcome {
+    FetchOutcome {
+        fetched_at: Utc::now(),
+        success: true,
+        http_status: Some(200),
+        new_items: 3,
+        fetched_items: 3,
+        filtered_items: 0,
+        attempts: 1,
+        duration_ms: 42,
+        error: None,
+        error_kind: None,
+        bytes_downloaded: 1024,
+    }
+}
+
+async fn populated(dir: &std::path::Path) -> DataApi {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), dir).await;
+    for id in ["a", "b", "c"] {
+        api.add_feed(FeedDescriptor::new(
+            id,
+            id.to_uppercase(),
+            format!("https://example.org/{}", id),
+        ))
+        .await
+        .unwrap();
+        let entries: Vec<FeedEntry> = (0..3).map(|i| entry(id, i)).collect();
+        for e in &entries {
+            api.seen_store().is_new_and_mark(e).await;
+        }
+        api.upsert_articles(id, entries).await.unwrap();
+    }
+    api.mark_read(&entry("b", 0)).await.unwrap();
+    api.mark_read(&entry("b", 2)).await.unwrap();
+    api.set_starred(&entry("b", 1), true).await.unwrap();
+    api.set_reading_position(&entry("b", 1), 320.0)
+        .await
+        .unwrap();
+    api.record_fetch("b", outcome()).await;
+    api
+}
+
+async fn state_of(api: &DataApi, feed_id: &str) -> (Vec<FeedEntry>, Vec<bool>, Vec<bool>) {
+    let articles = api.list_articles(feed_id).await;
+    let mut read = Vec::new();
+    let mut starred = Vec::new();
+    for e in &articles {
+        read.push(api.is_read(e).await);
+        starred.push(api.is_starred(e).await);
+    }
+    (articles, read, starred)
+}
+
+#[tokio::test]
+async fn remove_then_restore_round_trips_the_feed_state() {
+    let dir = temp_dir();
+    let api = populated(&dir).await;
+    let before = state_of(&api, "b").await;
+    let stats_before = api.feed_stats().await.get("b").cloned();
+
+    let backup = api
+        .remove_feed_with_backup("b")
+        .await
+        .unwrap()
+        .expect("known feed");
+    let ids: Vec<String> = api.list_feeds().await.into_iter().map(|f| f.id).collect();
+    assert_eq!(ids, ["a", "c"]);
+    assert!(!api.is_read(&entry("b", 0)).await);
+    assert!(!api.seen_store().contains(&entry("b", 0)).await);
+    assert_eq!(api.reading_position(&entry("b", 1)).await, None);
+
+    api.restore_feed(backup).await.unwrap();
+    let ids: Vec<String> = api.list_feeds().await.into_iter().map(|f| f.id).collect();
+    assert_eq!(ids, ["a", "b", "c"]);
+    assert_eq!(state_of(&api, "b").await, before);
+    assert_eq!(api.reading_position(&entry("b", 1)).await, Some(320.0));
+    assert_eq!(api.feed_stats().await.get("b").cloned(), stats_before);
+    for i in 0..3 {
+        assert!(api.seen_store().contains(&entry("b", i)).await);
+    }
+
+    // The restored state is on disk, not only in memory.
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(state_of(&reloaded, "b").await, before);
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn unknown_feeds_have_no_backup() {
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+    assert!(api.remove_feed_with_backup("nope").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn undoing_mark_all_read_only_clears_the_marks_it_added() {
+    let dir = temp_dir();
+    let api = populated(&dir).await;
+    let entries = api.list_articles("b").await;
+
+    let marks = api.mark_read_batch_tracked(&entries).await.unwrap();
+    // b-0 and b-2 were already read.
+    assert_eq!(marks.len(), 1);
+    assert!(api.is_read(&entry("b", 1)).await);
+
+    assert_eq!(api.unmark_read(&marks).await.unwrap(), 1);
+    assert!(api.is_read(&entry("b", 0)).await);
+    assert!(!api.is_read(&entry("b", 1)).await);
+    assert!(api.is_read(&entry("b", 2)).await);
+
+    let marks = api.mark_feed_read_tracked("a", None).await.unwrap();
+    assert_eq!(marks.len(), 3);
+    api.unmark_read(&marks).await.unwrap();
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(!reloaded.is_read(&entry("a", 0)).await);
+    assert!(reloaded.is_read(&entry("b", 0)).await);
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
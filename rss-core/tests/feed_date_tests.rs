@@ -0,0 +1,116 @@
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    parse_feed_date, poll_once, Event, FeedDescriptor, FeedEntry, PollConfig, SeenStore,
+};
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap()
+}
+
+#[test]
+fn real_world_dates_are_parsed() {
+    let at_0728 = utc(2024, 10, 21, 7, 28, 0);
+    let cases = [
+        // RFC 2822, with a named zone, with a two-digit year, without the weekday.
+        ("Mon, 21 Oct 2024 07:28:00 +0000", at_0728),
+        ("Mon, 21 Oct 2024 09:28:00 +0200", at_0728),
+        ("Mon, 21 Oct 2024 03:28:00 EDT", at_0728),
+        ("Mon, 21 Oct 24 07:28:00 GMT", at_0728),
+        ("21 Oct 2024 07:28:00 +0000", at_0728),
+        // Wrong weekday, as emitted by some CMS templates.
+        ("Tue, 21 Oct 2024 07:28:00 +0000", at_0728),
+        // Zone abbreviations outside RFC 2822.
+        ("Mon, 21 Oct 2024 09:28:00 CEST", at_0728),
+        ("Mon, 21 Oct 2024 07:28:00 UTC", at_0728),
+        // RFC 3339 / ISO-8601 and sloppy variants.
+        ("2024-10-21T07:28:00Z", at_0728),
+        (
+            "2024-10-21T09:28:00.123+02:00",
+            utc(2024, 10, 21, 7, 28, 0) + chrono::Duration::milliseconds(123),
+        ),
+        ("2024-10-21T09:28:00+0200", at_0728),
+        ("2024-10-21 07:28:00", at_0728),
+        ("2024-10-21 09:28:00 +02:00", at_0728),
+        ("2024-10-21T07:28", at_0728),
+        ("  2024-10-21   07:28:00 ", at_0728),
+        // Other layouts seen in the wild.
+        ("Mon Oct 21 07:28:00 +0000 2024", at_0728),
+        ("21 October 2024 07:28:00", at_0728),
+        ("October 21, 2024", utc(2024, 10, 21, 0, 0, 0)),
+        ("2024/10/21", utc(2024, 10, 21, 0, 0, 0)),
+        ("2024-10-21", utc(2024, 10, 21, 0, 0, 0)),
+    ];
+    for (raw, expected) in cases {
+        assert_eq!(parse_feed_date(raw), Some(expected), "{:?}", raw);
+    }
+}
+
+#[test]
+fn garbage_dates_are_rejected() {
+    for raw in [
+        "",
+        "   ",
+        "yesterday",
+        "32 Oct 2024 07:28:00 +0000",
+        "2024-13-01",
+    ] {
+        assert_eq!(parse_feed_date(raw), None, "{:?}", raw);
+    }
+}
+
+#[test]
+fn rss_items_use_the_tolerant_parser() {
+    let item = rss::ItemBuilder::default()
+        .title(Some("A".to_string()))
+        .pub_date(Some("2024-10-21 07:28:00".to_string()))
+        .build();
+    let entry = FeedEntry::from_rss_item("f", &item);
+    assert_eq!(entry.published_at, Some(utc(2024, 10, 21, 7, 28, 0)));
+}
+
+#[tokio::test]
+async fn unparsable_dates_stay_empty_and_sort_by_first_fetch() {
+    let body = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/"
+  xmlns:dc="http://purl.org/dc/elements/1.1/">
+<channel rdf:about="http://e/"><title>T</title><link>http://e/</link><description>D</description></channel>
+<item rdf:about="http://e/1"><title>Sloppy</title><link>http://e/1</link><dc:date>2024-10-21 07:28:00</dc:date></item>
+<item rdf:about="http://e/2"><title>Broken</title><link>http://e/2</link><dc:date>someday</dc:date></item>
+</rdf:RDF>"#;
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rdf"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rdf+xml")
+                .set_body_string(body),
+        )
+        .mount(&server)
+        .await;
+    let feed = FeedDescriptor::new("rdf", "Rdf", format!("{}/rdf", server.uri()));
+    let cfg = PollConfig {
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    };
+    let before = Utc::now();
+    let (events, _) = poll_once(&[feed], &cfg, &Client::new(), &SeenStore::in_memory()).await;
+    let entries = events
+        .into_iter()
+        .find_map(|e| match e {
+            Event::NewArticles(_, entries) => Some(entries),
+            _ => None,
+        })
+        .expect("no new articles");
+    let entry = |title: &str| entries.iter().find(|e| e.title == title).unwrap();
+    assert_eq!(
+        entry("Sloppy").published_at,
+        Some(utc(2024, 10, 21, 7, 28, 0))
+    );
+    assert_eq!(entry("Broken").published_at, None);
+    assert!(entry("Broken").sort_date() >= before);
+}
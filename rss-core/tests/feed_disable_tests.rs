@@ -0,0 +1,226 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Client;
+use tokio::sync::mpsc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, poll_once_in, record_feed_result, shared_feed_list, spawn_poller, DataApi, Event,
+    FeedDescriptor, PollConfig, SeenStore,
+};
+
+fn descriptor(server: &MockServer, route: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "dead".into(),
+        title: "Dead".into(),
+        url: format!("{}{}", server.uri(), route),
+        ..Default::default()
+    }
+}
+
+fn config(disable_after_failures: u32) -> PollConfig {
+    PollConfig {
+        interval: Duration::from_secs(3600),
+        request_timeout: Duration::from_secs(2),
+        max_retries: 1,
+        retry_backoff_ms: 5,
+        disable_after_failures,
+        ..PollConfig::default()
+    }
+}
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_disable_{}", nanos))
+}
+
+#[tokio::test]
+async fn feed_is_disabled_after_threshold_and_then_skipped() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/dead"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let dir = temp_dir();
+    let feeds = shared_feed_list(Vec::new());
+    let api = DataApi::load_from_dir(feeds.clone(), &dir).await;
+    api.add_feed(descriptor(&server, "/dead")).await.unwrap();
+
+    let (tx, mut rx) = mpsc::channel(16);
+    let handle = spawn_poller(
+        feeds.clone(),
+        config(3),
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+
+    // The first tick polls immediately; the next two cycles are forced
+    let mut disabled = false;
+    for cycle in 0..3 {
+        if cycle > 0 {
+            handle.poll_now();
+        }
+        loop {
+            let evt = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .expect("timed out waiting for poller")
+                .expect("poller channel closed");
+            match evt {
+                Event::FetchCompleted(feed_id, outcome) => {
+                    assert!(!outcome.success);
+                    api.record_fetch(&feed_id, outcome).await;
+                    if cycle < 2 {
+                        break;
+                    }
+                }
+                Event::FeedDisabled(feed_id) => {
+                    assert_eq!(cycle, 2);
+                    assert_eq!(feed_id, "dead");
+                    disabled = true;
+                    break;
+                }
+                // The end of the previous cycle: the frontend writes its stats.
+                Event::CycleCompleted(_) => api.flush_pending().await.unwrap(),
+                Event::NewArticles(..)
+                | Event::ArticlesUpdated(..)
+                | Event::FeedMoved(..)
+                | Event::FeedMetadata(..)
+                | Event::FeedTitle(..)
+                | Event::ConnectivityChanged(_) => {
+                    panic!("unexpected event")
+                }
+            }
+        }
+    }
+    assert!(disabled);
+    let requests = server.received_requests().await.unwrap().len();
+    assert_eq!(requests, 6);
+
+    // Disabled: further cycles do not hit the server
+    handle.poll_now();
+    while let Ok(Some(evt)) = tokio::time::timeout(Duration::from_millis(300), rx.recv()).await {
+        // Only cycle summaries, no fetch.
+        assert!(
+            matches!(evt, Event::CycleCompleted(_)),
+            "disabled feed must not be polled"
+        );
+        api.flush_pending().await.unwrap();
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), requests);
+    handle.stop().await.expect("stop poller");
+
+    // The disabled state and the counter survive a restart
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feed = reloaded.list_feeds().await.remove(0);
+    assert!(feed.disabled);
+    assert_eq!(feed.consecutive_failures, 3);
+
+    reloaded.reenable_feed("dead").await.unwrap();
+    let again = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feed = again.list_feeds().await.remove(0);
+    assert!(!feed.disabled);
+    assert_eq!(feed.consecutive_failures, 0);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+// Same flow as `rss-cli poll`: one poll_once_in round per run, stats flushed at the end.
+#[tokio::test]
+async fn poll_once_rounds_count_failures_and_disable_the_feed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/dead"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let dir = temp_dir();
+    let cfg = PollConfig {
+        max_retries: 0,
+        ..config(3)
+    };
+    let client = Client::new();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor(&server, "/dead")).await.unwrap();
+
+    for round in 1..=3 {
+        let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+        let feeds = api.list_feeds().await;
+        let (events, _) =
+            poll_once_in(api.feed_list(), &feeds, &cfg, &client, api.seen_store()).await;
+        let disabled = events
+            .iter()
+            .any(|e| matches!(e, Event::FeedDisabled(id) if id == "dead"));
+        assert_eq!(disabled, round == 3, "round {}", round);
+        for event in events {
+            if let Event::FetchCompleted(feed_id, outcome) = event {
+                api.record_fetch(&feed_id, outcome).await;
+            }
+        }
+        api.flush_pending().await.unwrap();
+    }
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feed = reloaded.list_feeds().await.remove(0);
+    assert!(feed.disabled);
+    assert_eq!(feed.consecutive_failures, 3);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn poll_once_reports_the_threshold_without_a_store() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/dead"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let feed = FeedDescriptor {
+        consecutive_failures: 2,
+        ..descriptor(&server, "/dead")
+    };
+    let cfg = PollConfig {
+        max_retries: 0,
+        ..config(3)
+    };
+    let (events, _) = poll_once(&[feed], &cfg, &Client::new(), &SeenStore::in_memory()).await;
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::FeedDisabled(id) if id == "dead")));
+}
+
+#[tokio::test]
+async fn successful_fetch_resets_the_failure_counter() {
+    let server = MockServer::start().await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "/dead")]);
+
+    assert!(!record_feed_result(&feeds, "dead", false, 3).await);
+    assert!(!record_feed_result(&feeds, "dead", false, 3).await);
+    assert!(!record_feed_result(&feeds, "dead", true, 3).await);
+    assert_eq!(feeds.read().await[0].consecutive_failures, 0);
+
+    assert!(!record_feed_result(&feeds, "dead", false, 3).await);
+    assert!(!record_feed_result(&feeds, "dead", false, 3).await);
+    assert!(record_feed_result(&feeds, "dead", false, 3).await);
+    assert!(feeds.read().await[0].disabled);
+}
+
+#[tokio::test]
+async fn zero_threshold_never_disables() {
+    let server = MockServer::start().await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "/dead")]);
+    for _ in 0..20 {
+        assert!(!record_feed_result(&feeds, "dead", false, 0).await);
+    }
+    assert!(!feeds.read().await[0].disabled);
+}
@@ -0,0 +1,113 @@
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    build_http_client, poll_once, Event, FeedDescriptor, NetworkConfig, PollConfig, PollError,
+    SeenStore, DEFAULT_USER_AGENT,
+};
+
+fn sample_rss() -> &'static str {
+    r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#
+}
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    }
+}
+
+fn feed(url: String) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "publisher".into(),
+        title: "Publisher".into(),
+        url,
+        ..Default::default()
+    }
+}
+
+fn fetched_ok(events: &[Event]) -> bool {
+    events
+        .iter()
+        .any(|e| matches!(e, Event::FetchCompleted(_, outcome) if outcome.error_kind.is_none()))
+}
+
+#[tokio::test]
+async fn custom_headers_and_user_agent_are_sent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("x-api-key", "k3y"))
+        .and(header("accept-language", "fr"))
+        .and(header("user-agent", "Mozilla/5.0 (compatible; Lecteur)"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(sample_rss()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut descriptor = feed(format!("{}/feed", server.uri()));
+    descriptor.headers = Some(vec![
+        ("X-Api-Key".into(), "k3y".into()),
+        ("Accept-Language".into(), "fr".into()),
+    ]);
+    descriptor.user_agent = Some("Mozilla/5.0 (compatible; Lecteur)".into());
+    descriptor.validate_headers().unwrap();
+
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let events = poll_once(&[descriptor], &config(), &client, &SeenStore::in_memory())
+        .await
+        .0;
+    assert!(fetched_ok(&events));
+}
+
+#[tokio::test]
+async fn global_user_agent_is_used_without_override() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("user-agent", DEFAULT_USER_AGENT))
+        .respond_with(ResponseTemplate::new(200).set_body_string(sample_rss()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let events = poll_once(
+        &[feed(format!("{}/feed", server.uri()))],
+        &config(),
+        &client,
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    assert!(fetched_ok(&events));
+}
+
+#[test]
+fn invalid_headers_are_rejected_before_polling() {
+    let mut descriptor = feed("https://example.org/feed".into());
+    descriptor.headers = Some(vec![("X Api Key".into(), "k3y".into())]);
+    assert!(matches!(
+        descriptor.validate_headers(),
+        Err(PollError::InvalidHeader(name)) if name == "X Api Key"
+    ));
+
+    descriptor.headers = Some(vec![("X-Api-Key".into(), "k3y\r\nInjected: 1".into())]);
+    let err = descriptor.validate_headers().unwrap_err();
+    // The value may be a secret: only the header name is reported.
+    assert_eq!(err.to_string(), "invalid HTTP header: X-Api-Key");
+
+    descriptor.headers = None;
+    descriptor.user_agent = Some("Lecteur\n".into());
+    assert!(descriptor.validate_headers().is_ok());
+    descriptor.user_agent = Some("Lecteur\nX: 1".into());
+    assert!(descriptor.validate_headers().is_err());
+}
+
+#[test]
+fn feeds_without_overrides_keep_their_json_shape() {
+    let json = serde_json::to_value(feed("https://example.org/feed".into())).unwrap();
+    assert!(json.get("headers").is_none());
+    assert!(json.get("user_agent").is_none());
+}
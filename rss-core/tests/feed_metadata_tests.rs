@@ -0,0 +1,134 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{TimeZone, Utc};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    build_http_client, poll_once, shared_feed_list, DataApi, Event, FeedDescriptor, FeedMeta,
+    NetworkConfig, PollConfig, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0">
+  <channel>
+    <title>Journal</title>
+    <link>https://journal.example.org/</link>
+    <description> Toute l’actualité </description>
+    <lastBuildDate>Tue, 04 Jun 2024 08:00:00 GMT</lastBuildDate>
+    <item><title>A</title><link>https://journal.example.org/a</link><guid>a</guid></item>
+  </channel>
+</rss>"#;
+
+const ATOM: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Blog</title>
+  <subtitle>Notes techniques</subtitle>
+  <id>urn:blog</id>
+  <updated>2024-06-01T12:00:00Z</updated>
+  <link rel="self" href="https://blog.example.org/atom.xml"/>
+  <link rel="alternate" type="application/json" href="https://blog.example.org/feed.json"/>
+  <link rel="alternate" type="text/html" href="https://blog.example.org/"/>
+</feed>"#;
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_metadata_{}", nanos))
+}
+
+fn descriptor(url: String) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "f1".into(),
+        title: "Flux".into(),
+        url,
+        ..Default::default()
+    }
+}
+
+async fn serve(body: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn poll(feeds: &[FeedDescriptor]) -> Vec<Event> {
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let config = PollConfig {
+        request_timeout: Duration::from_secs(2),
+        ..PollConfig::default()
+    };
+    poll_once(feeds, &config, &client, &SeenStore::in_memory())
+        .await
+        .0
+}
+
+fn metadata(events: &[Event]) -> Option<FeedMeta> {
+    events.iter().find_map(|event| match event {
+        Event::FeedMetadata(_, meta) => Some(meta.clone()),
+        _ => None,
+    })
+}
+
+#[tokio::test]
+async fn rss_channel_metadata_is_reported_and_persisted() {
+    let server = serve(RSS).await;
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor(format!("{}/feed", server.uri())))
+        .await
+        .unwrap();
+
+    let meta = metadata(&poll(&api.list_feeds().await).await).expect("metadata event");
+    assert_eq!(
+        meta.site_url.as_deref(),
+        Some("https://journal.example.org/")
+    );
+    assert_eq!(meta.description.as_deref(), Some("Toute l’actualité"));
+    assert_eq!(
+        meta.last_updated,
+        Some(Utc.with_ymd_and_hms(2024, 6, 4, 8, 0, 0).unwrap())
+    );
+    assert!(api.update_feed_metadata("f1", meta.clone()).await.unwrap());
+
+    // Reloaded from feeds.json; an unchanged channel emits no further event.
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feeds = reloaded.list_feeds().await;
+    assert_eq!(feeds[0].meta(), meta);
+    assert_eq!(metadata(&poll(&feeds).await), None);
+    assert!(!reloaded.update_feed_metadata("f1", meta).await.unwrap());
+}
+
+#[tokio::test]
+async fn atom_subtitle_alternate_link_and_updated() {
+    let server = serve(ATOM).await;
+    let feed = descriptor(format!("{}/feed", server.uri()));
+    let meta = metadata(&poll(&[feed]).await).expect("metadata event");
+    assert_eq!(
+        meta,
+        FeedMeta {
+            site_url: Some("https://blog.example.org/".into()),
+            description: Some("Notes techniques".into()),
+            last_updated: Some(Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn feeds_saved_without_metadata_still_load() {
+    let dir = temp_dir();
+    std::fs::create_dir_all(&dir).unwrap();
+    let old = r#"[{"id": "f1", "title": "Flux", "url": "https://example.org/feed"}]"#;
+    std::fs::write(dir.join("feeds.json"), old).unwrap();
+
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let feeds = api.list_feeds().await;
+    assert_eq!(feeds.len(), 1);
+    assert_eq!(feeds[0].meta(), FeedMeta::default());
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use reqwest::Client;
+use tokio::sync::mpsc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    mute_feed, shared_feed_list, spawn_poller, ArticleFilter, Event, FeedDescriptor, FeedService,
+    PollConfig, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>Lancement</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#;
+
+async fn serve_feed() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(RSS),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+fn config() -> PollConfig {
+    PollConfig {
+        interval: Duration::from_millis(50),
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn muted_feed_is_skipped_until_the_deadline() {
+    let server = serve_feed().await;
+    let mut feed = FeedDescriptor::new("noisy", "Noisy", format!("{}/feed", server.uri()));
+    feed.muted_until = Some(Utc::now() + chrono::Duration::milliseconds(600));
+    let feeds = shared_feed_list(vec![feed]);
+    let (tx, mut rx) = mpsc::channel(8);
+
+    let handle = spawn_poller(feeds, config(), Client::new(), tx, SeenStore::in_memory());
+
+    // Several cycles run inside the window without touching the feed.
+    let quiet = tokio::time::timeout(Duration::from_millis(400), rx.recv()).await;
+    assert!(quiet.is_err(), "muted feed must not produce events");
+    assert!(server.received_requests().await.unwrap().is_empty());
+
+    // Once the deadline passes, the next cycle fetches it again.
+    let resumed = tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            match rx.recv().await {
+                Some(Event::NewArticles(feed_id, _)) => return feed_id,
+                Some(_) => continue,
+                None => panic!("poller stopped"),
+            }
+        }
+    })
+    .await
+    .expect("feed did not resume after the mute");
+    assert_eq!(resumed, "noisy");
+
+    handle.stop().await.expect("stop poller");
+}
+
+#[tokio::test]
+async fn mute_is_set_cleared_and_persisted() {
+    let feeds = shared_feed_list(vec![FeedDescriptor::new("a", "A", "https://a.example/")]);
+    let until = Utc::now() + chrono::Duration::hours(24);
+    assert!(mute_feed(&feeds, "a", Some(until)).await);
+    assert!(!mute_feed(&feeds, "a", Some(until)).await);
+    assert!(!mute_feed(&feeds, "unknown", Some(until)).await);
+
+    let feed = feeds.read().await[0].clone();
+    assert!(feed.is_muted_at(Utc::now()));
+    assert!(!feed.is_muted_at(until));
+    let json = serde_json::to_value(&feed).unwrap();
+    let back: FeedDescriptor = serde_json::from_value(json).unwrap();
+    assert_eq!(back.muted_until, Some(until));
+
+    assert!(mute_feed(&feeds, "a", None).await);
+    let json = serde_json::to_value(&feeds.read().await[0]).unwrap();
+    assert!(json.get("muted_until").is_none());
+}
+
+#[tokio::test]
+async fn manual_refresh_of_a_muted_feed_marks_its_articles_read() {
+    let server = serve_feed().await;
+    let service = FeedService::in_memory();
+    service.set_poll_config(config());
+    service
+        .add_feed(FeedDescriptor::new(
+            "noisy",
+            "Noisy",
+            format!("{}/feed", server.uri()),
+        ))
+        .await
+        .unwrap();
+    let until = Utc::now() + chrono::Duration::hours(1);
+    assert!(service
+        .data_api()
+        .mute_feed("noisy", Some(until))
+        .await
+        .unwrap());
+
+    let events = service.refresh_feed("noisy").await.unwrap();
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::NewArticles(id, _) if id == "noisy")));
+    let articles = service.articles(&ArticleFilter::default()).await;
+    assert_eq!(articles.len(), 1);
+    assert!(service.data_api().is_read(&articles[0]).await);
+}
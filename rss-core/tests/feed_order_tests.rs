@@ -0,0 +1,78 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rss_core::{shared_feed_list, DataApi, FeedDescriptor};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_order_{}", nanos))
+}
+
+fn descriptor(id: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: id.to_uppercase(),
+        url: format!("https://example.org/{}.xml", id),
+        ..Default::default()
+    }
+}
+
+async fn ids(api: &DataApi) -> Vec<String> {
+    api.list_feeds().await.into_iter().map(|f| f.id).collect()
+}
+
+#[tokio::test]
+async fn order_survives_reload_and_new_feeds_are_appended() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    for id in ["a", "b", "c"] {
+        api.add_feed(descriptor(id)).await.unwrap();
+    }
+    assert_eq!(ids(&api).await, ["a", "b", "c"]);
+
+    api.reorder_feeds(vec!["c".into(), "a".into(), "b".into()])
+        .await
+        .unwrap();
+    api.add_feed(descriptor("d")).await.unwrap();
+    assert_eq!(ids(&api).await, ["c", "a", "b", "d"]);
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(ids(&reloaded).await, ["c", "a", "b", "d"]);
+    let feeds = reloaded.list_feeds().await;
+    assert_eq!(feeds[0].sort_order, Some(0));
+    assert_eq!(feeds[3].sort_order, None);
+}
+
+#[tokio::test]
+async fn partial_order_puts_unlisted_feeds_after_in_insertion_order() {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    for id in ["a", "b", "c", "d"] {
+        api.add_feed(descriptor(id)).await.unwrap();
+    }
+    api.reorder_feeds(vec!["d".into(), "b".into()])
+        .await
+        .unwrap();
+    assert_eq!(ids(&api).await, ["d", "b", "a", "c"]);
+}
+
+#[tokio::test]
+async fn updating_a_feed_keeps_its_position() {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    for id in ["a", "b", "c"] {
+        api.add_feed(descriptor(id)).await.unwrap();
+    }
+    api.add_feed(FeedDescriptor {
+        full_content: true,
+        content_selector: None,
+        category: None,
+        poll_interval: None,
+        notifications_disabled: false,
+        allow_insecure: false,
+        ..descriptor("a")
+    })
+    .await
+    .unwrap();
+    assert_eq!(ids(&api).await, ["a", "b", "c"]);
+}
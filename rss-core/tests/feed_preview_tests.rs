@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{find_feed_links, preview_feed, AllowHttp, FeedFormat, PollError};
+use url::Url;
+
+const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0">
+  <channel>
+    <title> Le Journal </title>
+    <link>https://journal.example.org/</link>
+    <description>Toute l’actualité</description>
+    <item><title>Mardi</title><link>https://journal.example.org/2</link>
+      <pubDate>Tue, 04 Jun 2024 08:00:00 GMT</pubDate></item>
+    <item><title>Sans date</title><link>https://journal.example.org/x</link></item>
+    <item><title>Jeudi</title><link>https://journal.example.org/4</link>
+      <pubDate>Thu, 06 Jun 2024 08:00:00 GMT</pubDate></item>
+    <item><title>Lundi</title><link>https://journal.example.org/1</link>
+      <pubDate>Mon, 03 Jun 2024 08:00:00 GMT</pubDate></item>
+  </channel>
+</rss>"#;
+
+const ATOM: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Le Blog</title>
+  <subtitle>Notes techniques</subtitle>
+  <id>urn:blog</id>
+  <updated>2024-06-01T00:00:00Z</updated>
+  <link rel="self" href="https://blog.example.org/atom.xml"/>
+  <link href="https://blog.example.org/"/>
+  <entry>
+    <title>Billet</title>
+    <id>urn:blog:1</id>
+    <updated>2024-06-01T00:00:00Z</updated>
+    <link href="https://blog.example.org/1"/>
+  </entry>
+</feed>"#;
+
+async fn serve(content_type: &str, body: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", content_type)
+                .set_body_string(body),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn preview(server: &MockServer) -> Result<rss_core::FeedPreview, PollError> {
+    let url = format!("{}/feed", server.uri());
+    let timeout = Duration::from_secs(5);
+    preview_feed(&Client::new(), &url, timeout, AllowHttp::LoopbackOnly).await
+}
+
+#[tokio::test]
+async fn rss_channel_metadata_and_most_recent_titles() {
+    let server = serve("application/rss+xml", RSS).await;
+    let preview = preview(&server).await.unwrap();
+    assert_eq!(preview.format, FeedFormat::Rss);
+    assert_eq!(preview.title, "Le Journal");
+    assert_eq!(preview.description.as_deref(), Some("Toute l’actualité"));
+    assert_eq!(
+        preview.site_link.as_deref(),
+        Some("https://journal.example.org/")
+    );
+    assert_eq!(preview.item_count, 4);
+    // Dated items first, newest first; the undated one comes last.
+    assert_eq!(preview.recent_titles, ["Jeudi", "Mardi", "Lundi"]);
+    assert_eq!(preview.url, format!("{}/feed", server.uri()));
+}
+
+#[tokio::test]
+async fn atom_title_subtitle_and_alternate_link() {
+    let server = serve("application/atom+xml", ATOM).await;
+    let preview = preview(&server).await.unwrap();
+    assert_eq!(preview.format, FeedFormat::Atom);
+    assert_eq!(preview.title, "Le Blog");
+    assert_eq!(preview.description.as_deref(), Some("Notes techniques"));
+    assert_eq!(
+        preview.site_link.as_deref(),
+        Some("https://blog.example.org/")
+    );
+    assert_eq!(preview.item_count, 1);
+    assert_eq!(preview.recent_titles, ["Billet"]);
+}
+
+#[tokio::test]
+async fn html_page_is_not_a_feed() {
+    let server = serve("text/html", "<!DOCTYPE html><html><body></body></html>").await;
+    let err = preview(&server).await.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            PollError::NotAFeed {
+                looks_like_html: true,
+                ..
+            }
+        ),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn feed_links_are_alternate_feed_types_only() {
+    let html = r#"<html><head>
+      <link rel="stylesheet" href="/style.css">
+      <link rel="alternate" type="text/html" hreflang="en" href="/en/">
+      <link rel="alternate" type="application/atom+xml" title="Atom" href="/atom.xml">
+      <link rel="alternate" type="application/atom+xml" href="https://blog.example.org/atom.xml">
+      <LINK REL="Alternate" TYPE="application/rss+xml" HREF="feed.rss">
+    </head><body><a rel="alternate" type="application/rss+xml" href="/no"></a></body></html>"#;
+    let base = Url::parse("https://blog.example.org/posts/").unwrap();
+    let links: Vec<String> = find_feed_links(html, &base)
+        .into_iter()
+        .map(|u| u.to_string())
+        .collect();
+    assert_eq!(
+        links,
+        [
+            "https://blog.example.org/atom.xml",
+            "https://blog.example.org/posts/feed.rss"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn html_page_announcing_a_feed_is_autodiscovered() {
+    let server = MockServer::start().await;
+    let page = r#"<!DOCTYPE html><html><head>
+      <link rel="alternate" type="application/rss+xml" href="/feed">
+    </head><body>Journal</body></html>"#;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_string(page),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(RSS),
+        )
+        .mount(&server)
+        .await;
+
+    let url = format!("{}/", server.uri());
+    let timeout = Duration::from_secs(5);
+    let preview = preview_feed(&Client::new(), &url, timeout, AllowHttp::LoopbackOnly)
+        .await
+        .unwrap();
+    assert_eq!(preview.title, "Le Journal");
+    // The preview points at the feed, so subscribing follows the feed and not the page.
+    assert_eq!(preview.url, format!("{}/feed", server.uri()));
+}
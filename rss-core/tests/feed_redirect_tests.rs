@@ -0,0 +1,213 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    build_http_client, poll_once, shared_feed_list, DataApi, Event, FeedDescriptor, NetworkConfig,
+    PollConfig, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>A</title><link>https://example.org/a</link><guid>a</guid></item></channel></rss>"#;
+
+fn descriptor(server: &MockServer, route: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "moving".into(),
+        title: "Moving".into(),
+        url: format!("{}{}", server.uri(), route),
+        ..Default::default()
+    }
+}
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 1,
+        retry_backoff_ms: 5,
+        ..PollConfig::default()
+    }
+}
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_redirect_{}", nanos))
+}
+
+async fn redirect(server: &MockServer, from: &str, status: u16, to: &str) {
+    Mock::given(method("GET"))
+        .and(path(from))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .insert_header("Location", format!("{}{}", server.uri(), to)),
+        )
+        .mount(server)
+        .await;
+}
+
+async fn serve_feed(server: &MockServer, at: &str) {
+    Mock::given(method("GET"))
+        .and(path(at))
+        .respond_with(ResponseTemplate::new(200).set_body_string(RSS))
+        .mount(server)
+        .await;
+}
+
+// Polls the single stored feed once and applies FeedMoved like the GUI and CLI do.
+async fn poll_and_apply(api: &DataApi) -> Vec<Event> {
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let feeds = api.list_feeds().await;
+    let events = poll_once(&feeds, &config(), &client, &SeenStore::in_memory())
+        .await
+        .0;
+    for event in &events {
+        if let Event::FeedMoved(feed_id, url) = event {
+            api.update_feed_url(feed_id, url).await.unwrap();
+        }
+    }
+    events
+}
+
+#[tokio::test]
+async fn permanent_redirect_updates_stored_url() {
+    let server = MockServer::start().await;
+    redirect(&server, "/old.xml", 301, "/new.xml").await;
+    serve_feed(&server, "/new.xml").await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor(&server, "/old.xml")).await.unwrap();
+
+    let events = poll_and_apply(&api).await;
+    let new_url = format!("{}/new.xml", server.uri());
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::FeedMoved(id, url) if id == "moving" && *url == new_url)));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::NewArticles(_, entries) if entries.len() == 1)));
+
+    let stored = std::fs::read_to_string(dir.join("feeds.json")).unwrap();
+    assert!(stored.contains("/new.xml") && !stored.contains("/old.xml"));
+    assert_eq!(api.list_feeds().await[0].url, new_url);
+}
+
+#[tokio::test]
+async fn permanent_redirect_308_is_detected_too() {
+    let server = MockServer::start().await;
+    redirect(&server, "/old.xml", 308, "/new.xml").await;
+    serve_feed(&server, "/new.xml").await;
+
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let feed = descriptor(&server, "/old.xml");
+    let events = poll_once(&[feed], &config(), &client, &SeenStore::in_memory())
+        .await
+        .0;
+    assert!(events.iter().any(|e| matches!(e, Event::FeedMoved(..))));
+}
+
+#[tokio::test]
+async fn temporary_redirect_keeps_stored_url() {
+    let server = MockServer::start().await;
+    redirect(&server, "/feed.xml", 302, "/mirror.xml").await;
+    serve_feed(&server, "/mirror.xml").await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor(&server, "/feed.xml"))
+        .await
+        .unwrap();
+
+    let events = poll_and_apply(&api).await;
+    assert!(!events.iter().any(|e| matches!(e, Event::FeedMoved(..))));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::FetchCompleted(_, outcome) if outcome.success)));
+
+    let stored = std::fs::read_to_string(dir.join("feeds.json")).unwrap();
+    assert!(stored.contains("/feed.xml") && !stored.contains("/mirror.xml"));
+}
+
+#[tokio::test]
+async fn redirect_loop_fails_with_clear_error() {
+    let server = MockServer::start().await;
+    redirect(&server, "/a.xml", 301, "/b.xml").await;
+    redirect(&server, "/b.xml", 301, "/a.xml").await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor(&server, "/a.xml")).await.unwrap();
+
+    let events = poll_and_apply(&api).await;
+    let error = events
+        .iter()
+        .find_map(|e| match e {
+            Event::FetchCompleted(_, outcome) if !outcome.success => outcome.error.clone(),
+            _ => None,
+        })
+        .expect("loop should fail");
+    assert!(error.contains("too many redirects"), "{}", error);
+    assert!(!events.iter().any(|e| matches!(e, Event::FeedMoved(..))));
+    assert!(std::fs::read_to_string(dir.join("feeds.json"))
+        .unwrap()
+        .contains("/a.xml"));
+}
+
+#[tokio::test]
+async fn temporary_redirect_loop_is_capped() {
+    let server = MockServer::start().await;
+    redirect(&server, "/a.xml", 302, "/b.xml").await;
+    redirect(&server, "/b.xml", 307, "/a.xml").await;
+
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let feed = descriptor(&server, "/a.xml");
+    let events = poll_once(&[feed], &config(), &client, &SeenStore::in_memory())
+        .await
+        .0;
+    let Some(Event::FetchCompleted(_, outcome)) = events.first() else {
+        panic!("missing FetchCompleted");
+    };
+    assert!(!outcome.success);
+    assert!(outcome
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("too many redirects"));
+}
+
+#[tokio::test]
+async fn custom_headers_stay_on_the_feed_origin_across_temporary_redirects() {
+    let server = MockServer::start().await;
+    let mirror = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed.xml"))
+        .respond_with(
+            ResponseTemplate::new(302)
+                .insert_header("Location", format!("{}/feed.xml", mirror.uri())),
+        )
+        .mount(&server)
+        .await;
+    serve_feed(&mirror, "/feed.xml").await;
+
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+    let feed = FeedDescriptor {
+        headers: Some(vec![("X-Api-Key".into(), "k3y".into())]),
+        ..descriptor(&server, "/feed.xml")
+    };
+    let events = poll_once(&[feed], &config(), &client, &SeenStore::in_memory())
+        .await
+        .0;
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::FetchCompleted(_, outcome) if outcome.success)));
+
+    // The feed's own host got the header, the other origin did not.
+    let first = &server.received_requests().await.unwrap()[0];
+    assert_eq!(first.headers.get("x-api-key").unwrap(), "k3y");
+    let requests = mirror.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(!requests[0].headers.contains_key("x-api-key"));
+}
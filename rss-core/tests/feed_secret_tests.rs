@@ -0,0 +1,215 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Client;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, redact_values, shared_feed_list, DataApi, Event, FeedDescriptor, FeedSecret,
+    PollConfig, Secret, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/a</link><guid>a</guid></item></channel></rss>"#;
+
+const COOKIE: &str = "sessionid=s3cr3t-v4lue; lang=fr";
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_secrets_{}", nanos))
+}
+
+fn rss_response() -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("content-type", "application/rss+xml")
+        .set_body_string(RSS)
+}
+
+fn config(api: &DataApi) -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        secrets: api.feed_secrets().clone(),
+        ..PollConfig::default()
+    }
+}
+
+fn fetch_succeeded(events: &[Event]) -> bool {
+    events
+        .iter()
+        .any(|e| matches!(e, Event::FetchCompleted(_, outcome) if outcome.success))
+}
+
+#[tokio::test]
+async fn cookie_is_sent_to_the_feed_origin_only() {
+    let login_walled = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("cookie", COOKIE))
+        .respond_with(rss_response())
+        .mount(&login_walled)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&login_walled)
+        .await;
+    let feed = FeedDescriptor::new("members", "Members", format!("{}/feed", login_walled.uri()));
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+
+    // Without the cookie, the login wall answers 401.
+    let (events, _) = poll_once(
+        std::slice::from_ref(&feed),
+        &config(&api),
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await;
+    assert!(!fetch_succeeded(&events));
+
+    api.set_feed_secret("members", FeedSecret::with_cookie(COOKIE))
+        .await
+        .unwrap();
+    let (events, _) = poll_once(
+        &[feed],
+        &config(&api),
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await;
+    assert!(fetch_succeeded(&events));
+
+    // A redirect to another origin does not carry the cookie along.
+    let mirror = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(rss_response())
+        .mount(&mirror)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/moved"))
+        .respond_with(
+            ResponseTemplate::new(302).insert_header("location", format!("{}/feed", mirror.uri())),
+        )
+        .mount(&login_walled)
+        .await;
+    let moved = FeedDescriptor::new(
+        "members",
+        "Members",
+        format!("{}/moved", login_walled.uri()),
+    );
+    let (events, _) = poll_once(
+        &[moved],
+        &config(&api),
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await;
+    assert!(fetch_succeeded(&events));
+    let requests = mirror.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(!requests[0].headers.contains_key("cookie"));
+}
+
+#[tokio::test]
+async fn secrets_live_outside_feeds_json_and_leave_with_the_feed() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(FeedDescriptor::new(
+        "members",
+        "Members",
+        "https://members.example.org/feed",
+    ))
+    .await
+    .unwrap();
+    api.set_feed_secret("members", FeedSecret::with_cookie(COOKIE))
+        .await
+        .unwrap();
+
+    let feeds_json = tokio::fs::read_to_string(dir.join("feeds.json"))
+        .await
+        .unwrap();
+    assert!(!feeds_json.contains("s3cr3t"));
+    let secrets_json = tokio::fs::read_to_string(dir.join("secrets.json"))
+        .await
+        .unwrap();
+    assert!(secrets_json.contains("\"members\""));
+    assert!(secrets_json.contains("s3cr3t-v4lue"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(dir.join("secrets.json"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // Reloaded with the other stores; a blank cookie removes the secret.
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let secret = reloaded.get_feed_secret("members").unwrap();
+    assert_eq!(secret.cookie, Some(Secret::Plaintext(COOKIE.to_string())));
+    reloaded
+        .set_feed_secret("members", FeedSecret::with_cookie("  "))
+        .await
+        .unwrap();
+    assert_eq!(reloaded.get_feed_secret("members"), None);
+    reloaded
+        .set_feed_secret("members", FeedSecret::with_cookie(COOKIE))
+        .await
+        .unwrap();
+
+    // Removal (and its undo) carries the secret along.
+    let backup = reloaded
+        .remove_feed_with_backup("members")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(reloaded.get_feed_secret("members"), None);
+    let secrets_json = tokio::fs::read_to_string(dir.join("secrets.json"))
+        .await
+        .unwrap();
+    assert!(!secrets_json.contains("s3cr3t"));
+    reloaded.restore_feed(backup).await.unwrap();
+    assert!(reloaded.get_feed_secret("members").is_some());
+
+    reloaded.remove_feed("members").await.unwrap();
+    assert!(!reloaded.remove_feed_secret("members").await.unwrap());
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn cookie_values_are_redacted_from_messages_and_debug_output() {
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+    api.set_feed_secret("members", FeedSecret::with_cookie(COOKIE))
+        .await
+        .unwrap();
+    let secrets = api.feed_secrets();
+
+    // The whole header and each value long enough to be a secret are masked.
+    let message = format!(
+        "server said: bad cookie `{}` (s3cr3t-v4lue); lang=fr",
+        COOKIE
+    );
+    let redacted = secrets.redact("members", &message);
+    assert!(!redacted.contains("s3cr3t"), "{}", redacted);
+    assert!(redacted.contains("lang=fr"));
+    assert_eq!(secrets.redact("other", &message), message);
+
+    assert!(!format!("{:?}", api.get_feed_secret("members")).contains("s3cr3t"));
+    assert!(!format!("{:?}", secrets).contains("s3cr3t"));
+
+    // Longer values go first, so no fragment of an enclosing secret stays readable.
+    let values = ["abcd".to_string(), "abcdef".to_string()];
+    assert_eq!(redact_values("x=abcdef", &values), "x=***");
+}
+
+#[test]
+fn cookies_that_cannot_form_a_header_are_refused() {
+    assert!(FeedSecret::with_cookie("sid=1\nX-Injected: yes")
+        .validate()
+        .is_err());
+    assert!(FeedSecret::with_cookie(COOKIE).validate().is_ok());
+    assert!(FeedSecret::with_cookie("").is_empty());
+}
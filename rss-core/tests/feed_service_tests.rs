@@ -0,0 +1,171 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{AddFeedError, ArticleFilter, Event, FeedDescriptor, FeedService, PollConfig};
+
+const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0">
+  <channel>
+    <title>Journal</title>
+    <link>https://example.org/</link>
+    <description>Test</description>
+    <item><title>Ancien</title><link>https://example.org/1</link><guid>1</guid>
+      <pubDate>Mon, 03 Jun 2024 08:00:00 GMT</pubDate></item>
+    <item><title>Récent</title><link>https://example.org/2</link><guid>2</guid>
+      <pubDate>Tue, 04 Jun 2024 08:00:00 GMT</pubDate></item>
+  </channel>
+</rss>"#;
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_service_{}", nanos))
+}
+
+async fn serve_feed(route: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(RSS),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+fn fast_config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..PollConfig::default()
+    }
+}
+
+fn new_article_count(events: &[Event]) -> usize {
+    events
+        .iter()
+        .map(|e| match e {
+            Event::NewArticles(_, entries) => entries.len(),
+            _ => 0,
+        })
+        .sum()
+}
+
+#[tokio::test]
+async fn refresh_persists_and_broadcasts_events() {
+    let server = serve_feed("/feed").await;
+    let service = FeedService::in_memory();
+    service.set_poll_config(fast_config());
+    let mut events = service.subscribe_events();
+
+    let url = format!("{}/feed", server.uri());
+    service
+        .add_feed(FeedDescriptor::new("journal", "Journal", url.clone()))
+        .await
+        .unwrap();
+    let err = service
+        .add_feed(FeedDescriptor::new("copy", "Copie", url))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AddFeedError::Duplicate { .. }));
+
+    let returned = service.refresh_feed("journal").await.unwrap();
+    assert_eq!(new_article_count(&returned), 2);
+    assert!(service.refresh_feed("unknown").await.is_none());
+
+    // Subscribers see the same events, in order.
+    let mut broadcast = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        broadcast.push(event);
+    }
+    assert_eq!(broadcast.len(), returned.len());
+    assert_eq!(new_article_count(&broadcast), 2);
+
+    let titles: Vec<String> = service
+        .articles(&ArticleFilter::default())
+        .await
+        .into_iter()
+        .map(|e| e.title)
+        .collect();
+    assert_eq!(titles, ["Récent", "Ancien"]);
+    let stats = service.data_api().feed_stats().await;
+    assert_eq!(stats["journal"].fetch_count, 1);
+
+    // Already seen: nothing new the second time.
+    assert_eq!(new_article_count(&service.refresh_all().await), 0);
+
+    service.remove_feed("journal").await.unwrap();
+    assert!(service.articles(&ArticleFilter::default()).await.is_empty());
+    service.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn background_poller_events_reach_every_subscriber() {
+    let server = serve_feed("/feed").await;
+    let service = FeedService::in_memory();
+    service.set_poll_config(fast_config());
+    let mut first = service.subscribe_events();
+    let mut second = service.subscribe_events();
+    service
+        .add_feed(FeedDescriptor::new(
+            "journal",
+            "Journal",
+            format!("{}/feed", server.uri()),
+        ))
+        .await
+        .unwrap();
+
+    service.poll_now();
+    for receiver in [&mut first, &mut second] {
+        let entries = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Event::NewArticles(_, entries) = receiver.recv().await.unwrap() {
+                    return entries;
+                }
+            }
+        })
+        .await
+        .expect("no NewArticles event from the poller");
+        assert_eq!(entries.len(), 2);
+    }
+    // Persisted before being broadcast.
+    assert_eq!(service.data_api().list_articles("journal").await.len(), 2);
+    service.shutdown().await.unwrap();
+    // A second shutdown is a no-op.
+    service.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn open_reloads_what_the_previous_session_stored() {
+    let server = serve_feed("/feed").await;
+    let dir = temp_dir();
+    let service = FeedService::open(&dir).await;
+    assert!(service.network_error().is_none());
+    service.set_poll_config(fast_config());
+    service
+        .add_feed(FeedDescriptor::new(
+            "journal",
+            "Journal",
+            format!("{}/feed", server.uri()),
+        ))
+        .await
+        .unwrap();
+    service.refresh_all().await;
+    service.shutdown().await.unwrap();
+
+    let reopened = FeedService::open(&dir).await;
+    reopened.set_poll_config(fast_config());
+    assert_eq!(reopened.articles(&ArticleFilter::default()).await.len(), 2);
+    // The seen store was flushed too: nothing is reported as new again.
+    assert_eq!(new_article_count(&reopened.refresh_all().await), 0);
+    reopened.shutdown().await.unwrap();
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
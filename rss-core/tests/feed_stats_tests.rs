@@ -0,0 +1,158 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, shared_feed_list, DataApi, Event, FeedDescriptor, FetchErrorKind, FetchOutcome,
+    PollConfig, SeenStore,
+};
+
+fn sample_rss() -> &'static str {
+    r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid></item><item><title>B</title><link>http://e/2</link><guid>2</guid></item></channel></rss>"#
+}
+
+fn descriptor(server: &MockServer, route: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "feed1".into(),
+        title: "Feed".into(),
+        url: format!("{}{}", server.uri(), route),
+        ..Default::default()
+    }
+}
+
+fn config(max_retries: usize) -> PollConfig {
+    PollConfig {
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries,
+        retry_backoff_ms: 5,
+        ..PollConfig::default()
+    }
+}
+
+fn outcomes(events: &[Event]) -> Vec<&FetchOutcome> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::FetchCompleted(_, outcome) => Some(outcome),
+            _ => None,
+        })
+        .collect()
+}
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_stats_{}", nanos))
+}
+
+#[tokio::test]
+async fn successful_fetch_reports_status_and_new_items() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(sample_rss()))
+        .mount(&server)
+        .await;
+
+    let events = poll_once(
+        &[descriptor(&server, "/feed")],
+        &config(2),
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    let outcomes = outcomes(&events);
+    assert_eq!(outcomes.len(), 1);
+    assert!(outcomes[0].success);
+    assert_eq!(outcomes[0].http_status, Some(200));
+    assert_eq!(outcomes[0].new_items, 2);
+    assert_eq!(outcomes[0].attempts, 1);
+    assert!(outcomes[0].error.is_none());
+}
+
+#[tokio::test]
+async fn failed_fetch_counts_every_retry_attempt() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/broken"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let events = poll_once(
+        &[descriptor(&server, "/broken")],
+        &config(2),
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    assert_eq!(events.len(), 1);
+    let outcomes = outcomes(&events);
+    assert!(!outcomes[0].success);
+    assert_eq!(outcomes[0].http_status, Some(503));
+    assert_eq!(outcomes[0].attempts, 3);
+    assert_eq!(outcomes[0].new_items, 0);
+    assert!(outcomes[0].error.is_some());
+    assert_eq!(outcomes[0].error_kind, Some(FetchErrorKind::HttpStatus));
+}
+
+#[tokio::test]
+async fn feed_stats_are_accumulated_and_persisted() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let now = chrono::Utc::now();
+    let ok = FetchOutcome {
+        fetched_at: now,
+        success: true,
+        http_status: Some(200),
+        new_items: 3,
+        fetched_items: 5,
+        filtered_items: 2,
+        attempts: 1,
+        duration_ms: 100,
+        error: None,
+        error_kind: None,
+        bytes_downloaded: 0,
+    };
+    let failed = FetchOutcome {
+        fetched_at: now + chrono::Duration::seconds(60),
+        success: false,
+        http_status: Some(500),
+        new_items: 0,
+        fetched_items: 0,
+        filtered_items: 0,
+        attempts: 4,
+        duration_ms: 300,
+        error: Some("unexpected HTTP status: 500".into()),
+        error_kind: Some(FetchErrorKind::HttpStatus),
+        bytes_downloaded: 0,
+    };
+    api.record_fetch("feed1", ok.clone()).await;
+    api.record_fetch("feed1", failed.clone()).await;
+    // Written once per cycle, not after every fetch.
+    let unflushed = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(unflushed.feed_stats().await.is_empty());
+    api.flush_pending().await.unwrap();
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let stats = reloaded.feed_stats().await;
+    let st = stats.get("feed1").expect("stats persisted");
+    assert_eq!(st.fetch_count, 2);
+    assert_eq!(st.failure_count, 1);
+    assert_eq!(st.average_fetch_ms(), Some(200));
+    assert_eq!(st.last_success_at, Some(ok.fetched_at));
+    assert_eq!(st.last_failure_at, Some(failed.fetched_at));
+    assert_eq!(st.last.as_ref(), Some(&failed));
+
+    reloaded.remove_feed("feed1").await.unwrap();
+    assert!(reloaded.feed_stats().await.is_empty());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,152 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, shared_feed_list, ChannelMeta, DataApi, Event, FeedDescriptor, FeedEntry,
+    FeedFormat, PollConfig, SeenStore,
+};
+
+fn entry(title: &str, url: &str, published_at: DateTime<Utc>) -> FeedEntry {
+    FeedEntry {
+        feed_id: "blog".into(),
+        title: title.into(),
+        url: url.into(),
+        published_at: Some(published_at),
+        first_seen_at: published_at,
+        ..Default::default()
+    }
+}
+
+fn entries() -> Vec<FeedEntry> {
+    let mut opaque = entry(
+        "Tom & Jerry <3",
+        "https://example.org/posts/1?a=1&b=2",
+        Utc.with_ymd_and_hms(2026, 3, 1, 8, 30, 0).unwrap(),
+    );
+    opaque.guid = Some("tag:example.org,2026:1".into());
+    opaque.guid_is_permalink = Some(false);
+    opaque.author = Some("Ana".into());
+    opaque.categories = vec!["rust".into(), "café".into()];
+    opaque.summary = Some("Short \"summary\"".into());
+    opaque.content_html = Some("<p>Body with <b>bold</b> text</p>".into());
+    let linked = entry(
+        "Second",
+        "https://example.org/posts/2",
+        Utc.with_ymd_and_hms(2026, 2, 27, 17, 0, 0).unwrap(),
+    );
+    vec![opaque, linked]
+}
+
+fn meta() -> ChannelMeta {
+    ChannelMeta {
+        title: "Starred".into(),
+        link: "https://example.org/".into(),
+        description: Some("Articles worth keeping".into()),
+        self_url: Some("https://example.org/starred.xml".into()),
+    }
+}
+
+// Serves `body` and parses it back through the poller.
+async fn parse_back(body: String) -> Vec<FeedEntry> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/starred.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(&server)
+        .await;
+    let feed = FeedDescriptor::new(
+        "starred",
+        "Starred",
+        format!("{}/starred.xml", server.uri()),
+    );
+    let cfg = PollConfig {
+        request_timeout: Duration::from_secs(2),
+        ..PollConfig::default()
+    };
+    let (events, _) = poll_once(&[feed], &cfg, &Client::new(), &SeenStore::in_memory()).await;
+    events
+        .into_iter()
+        .find_map(|evt| match evt {
+            Event::NewArticles(_, entries) => Some(entries),
+            _ => None,
+        })
+        .expect("entries parsed back")
+}
+
+fn assert_same(parsed: &[FeedEntry], original: &[FeedEntry]) {
+    assert_eq!(parsed.len(), original.len());
+    for (parsed, original) in parsed.iter().zip(original) {
+        assert_eq!(parsed.title, original.title);
+        assert_eq!(parsed.url, original.url);
+        assert_eq!(parsed.published_at, original.published_at);
+        assert_eq!(parsed.author, original.author);
+        assert_eq!(parsed.categories, original.categories);
+        assert_eq!(parsed.summary, original.summary);
+        assert_eq!(parsed.content_html, original.content_html);
+    }
+}
+
+#[tokio::test]
+async fn rss_output_round_trips_through_the_parser() {
+    let original = entries();
+    let xml = DataApi::render_feed(&original, meta(), FeedFormat::Rss);
+    let parsed = parse_back(xml).await;
+    assert_same(&parsed, &original);
+    // Read and starred marks still match the re-published articles.
+    assert_eq!(parsed[0].identity(), original[0].identity());
+    assert_eq!(parsed[1].identity(), original[1].identity());
+    assert_eq!(parsed[0].guid.as_deref(), Some("tag:example.org,2026:1"));
+    assert_eq!(parsed[0].guid_is_permalink, Some(false));
+    // Without a guid, the link stands in as a permalink.
+    assert_eq!(
+        parsed[1].guid.as_deref(),
+        Some("https://example.org/posts/2")
+    );
+    assert_eq!(parsed[1].guid_is_permalink, Some(true));
+}
+
+#[tokio::test]
+async fn atom_output_round_trips_through_the_parser() {
+    let original = entries();
+    let xml = DataApi::render_feed(&original, meta(), FeedFormat::Atom);
+    assert!(xml.contains("<id>https://example.org/starred.xml</id>"));
+    let parsed = parse_back(xml).await;
+    assert_same(&parsed, &original);
+    assert_eq!(parsed[0].guid.as_deref(), Some("tag:example.org,2026:1"));
+    // Atom requires an id: the link stands in for a missing guid.
+    assert_eq!(
+        parsed[1].guid.as_deref(),
+        Some("https://example.org/posts/2")
+    );
+}
+
+#[tokio::test]
+async fn starred_articles_are_exported_and_changes_counted() {
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+    let articles = entries();
+    api.upsert_articles("blog", articles.clone()).await.unwrap();
+    let before = api.stars_revision();
+    api.set_starred(&articles[1], true).await.unwrap();
+    assert_eq!(api.stars_revision(), before + 1);
+    // Marking as read is not a star change.
+    api.mark_read(&articles[0]).await.unwrap();
+    assert_eq!(api.stars_revision(), before + 1);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let file = std::env::temp_dir().join(format!("readrss_starred_{}.xml", nanos));
+    let count = api
+        .export_starred_feed(&file, meta(), FeedFormat::Atom)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+    let written = std::fs::read_to_string(&file).unwrap();
+    assert!(written.contains("<title type=\"text\">Second</title>"));
+    assert!(!written.contains("Tom &amp; Jerry"));
+}
@@ -0,0 +1,327 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Client;
+use serde_json::json;
+use tokio::sync::mpsc;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    shared_feed_list, spawn_poller, AppConfig, DataApi, Event, FeverClient, FeverSync, PollConfig,
+    PollError, Secret, SeenStore, SyncMode,
+};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_fever_{}", nanos))
+}
+
+fn fever(server: &MockServer) -> FeverClient {
+    FeverClient::new(
+        &format!("{}/fever.php", server.uri()),
+        Secret::Plaintext("0123456789abcdef".into()),
+    )
+    .unwrap()
+}
+
+fn ok(body: serde_json::Value) -> ResponseTemplate {
+    let mut body = body;
+    body["api_version"] = json!(3);
+    body["auth"] = json!(1);
+    ResponseTemplate::new(200).set_body_json(body)
+}
+
+// Mounts `feeds`, `groups`, `unread_item_ids` and an empty `saved_item_ids`.
+async fn mount_listing(server: &MockServer, unread: &str) {
+    mount_listing_with(server, unread, "").await;
+}
+
+// Mounts the listing calls as a FreshRSS-like server would answer them.
+async fn mount_listing_with(server: &MockServer, unread: &str, saved: &str) {
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("saved_item_ids", ""))
+        .respond_with(ok(json!({ "saved_item_ids": saved })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("feeds", ""))
+        .and(body_string_contains("api_key=0123456789abcdef"))
+        .respond_with(ok(json!({
+            "feeds": [
+                {"id": 10, "favicon_id": 1, "title": "Le Journal du Code",
+                 "url": "https://journal.example.org/feed/", "site_url": "https://journal.example.org/",
+                 "is_spark": 0, "last_updated_on_time": 1715000000}
+            ],
+            "feeds_groups": [{"group_id": 2, "feed_ids": "10"}]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("groups", ""))
+        .respond_with(ok(json!({
+            "groups": [{"id": "2", "title": "Tech"}],
+            "feeds_groups": [{"group_id": "2", "feed_ids": "10"}]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("unread_item_ids", ""))
+        .respond_with(ok(json!({ "unread_item_ids": unread })))
+        .mount(server)
+        .await;
+}
+
+fn item(id: u64, title: &str) -> serde_json::Value {
+    json!({
+        "id": id.to_string(),
+        "feed_id": 10,
+        "title": title,
+        "author": "",
+        "html": "<p>Contenu</p><script>alert(1)</script>",
+        "url": format!("https://journal.example.org/{}/", id),
+        "is_saved": 0,
+        "is_read": 0,
+        "created_on_time": 1715000000 + id as i64
+    })
+}
+
+fn collect_new(events: Vec<Event>) -> Vec<rss_core::FeedEntry> {
+    events
+        .into_iter()
+        .flat_map(|event| match event {
+            Event::NewArticles(_, entries) => entries,
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn unread_item_ids_round_trip_fetches_only_unknown_items() {
+    let server = MockServer::start().await;
+    mount_listing(&server, "1,2").await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("items", ""))
+        .and(query_param("with_ids", "1,2"))
+        .respond_with(ok(json!({
+            "items": [item(1, "Sortie de la 2.3"), item(2, "Compte rendu du meetup")],
+            "total_items": 2
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = FeverSync::new(fever(&server), api.clone());
+    let seen = SeenStore::in_memory();
+    let cfg = PollConfig::default();
+    let http = Client::new();
+
+    let entries = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.feed_id == "fever:10"));
+    assert_eq!(entries[0].categories, ["Tech"]);
+    assert!(entries.iter().all(|e| !e
+        .content_html
+        .as_deref()
+        .unwrap_or_default()
+        .contains("script")));
+
+    let feeds = api.list_feeds().await;
+    assert_eq!(feeds.len(), 1);
+    assert_eq!(feeds[0].id, "fever:10");
+    assert_eq!(feeds[0].title, "Le Journal du Code");
+
+    // The GUI stores announced articles; a second cycle must not refetch them.
+    api.upsert_articles("fever:10", entries).await.unwrap();
+    let again = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    assert!(again.is_empty());
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn read_state_is_reconciled_in_both_directions() {
+    let server = MockServer::start().await;
+    mount_listing(&server, "1,2").await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("items", ""))
+        .respond_with(ok(json!({ "items": [item(1, "Un"), item(2, "Deux")] })))
+        .mount(&server)
+        .await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = FeverSync::new(fever(&server), api.clone());
+    let seen = SeenStore::in_memory();
+    let cfg = PollConfig::default();
+    let http = Client::new();
+
+    let entries = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    api.upsert_articles("fever:10", entries).await.unwrap();
+    let stored = api.list_articles("fever:10").await;
+    let one = stored.iter().find(|e| e.title == "Un").unwrap().clone();
+    let two = stored.iter().find(|e| e.title == "Deux").unwrap().clone();
+    api.mark_read(&one).await.unwrap();
+
+    // Item 2 was read in another client, item 1 is still unread on the server.
+    server.reset().await;
+    mount_listing(&server, "1").await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(body_string_contains("mark=item"))
+        .and(body_string_contains("as=read"))
+        .and(body_string_contains("id=1"))
+        .respond_with(ok(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    assert!(api.is_read(&two).await);
+    assert!(api.is_read(&one).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+async fn expect_mark(server: &MockServer, mark: &str, id: u64, times: u64) {
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(body_string_contains("mark=item"))
+        .and(body_string_contains(format!("as={}&", mark)))
+        .and(body_string_contains(format!("id={}", id)))
+        .respond_with(ok(json!({})))
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn saved_items_round_trip_including_removals() {
+    let server = MockServer::start().await;
+    mount_listing(&server, "1,2").await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("items", ""))
+        .respond_with(ok(json!({ "items": [item(1, "Un"), item(2, "Deux")] })))
+        .mount(&server)
+        .await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = FeverSync::new(fever(&server), api.clone());
+    let seen = SeenStore::in_memory();
+    let cfg = PollConfig::default();
+    let http = Client::new();
+
+    let entries = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    api.upsert_articles("fever:10", entries).await.unwrap();
+    let stored = api.list_articles("fever:10").await;
+    let one = stored.iter().find(|e| e.title == "Un").unwrap().clone();
+    let two = stored.iter().find(|e| e.title == "Deux").unwrap().clone();
+
+    // Starred here (1), saved in another client (2): each side gets the other's mark.
+    api.set_starred(&one, true).await.unwrap();
+    server.reset().await;
+    mount_listing_with(&server, "1,2", "2").await;
+    expect_mark(&server, "saved", 1, 1).await;
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    server.verify().await;
+    assert!(api.is_starred(&two).await);
+
+    // Unstarred here (1), unsaved in another client (2): neither comes back.
+    api.set_starred(&one, false).await.unwrap();
+    server.reset().await;
+    mount_listing_with(&server, "1,2", "1").await;
+    expect_mark(&server, "unsaved", 1, 1).await;
+    expect_mark(&server, "saved", 1, 0).await;
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    server.verify().await;
+    assert!(!api.is_starred(&one).await);
+    assert!(!api.is_starred(&two).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn rejected_api_key_is_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"api_version": 3, "auth": 0})),
+        )
+        .mount(&server)
+        .await;
+
+    let err = fever(&server).feeds(&Client::new()).await.unwrap_err();
+    assert!(matches!(err, PollError::SyncAuth));
+}
+
+#[tokio::test]
+async fn poller_runs_sync_when_enabled() {
+    let server = MockServer::start().await;
+    mount_listing(&server, "7").await;
+    Mock::given(method("POST"))
+        .and(path("/fever.php"))
+        .and(query_param("with_ids", "7"))
+        .respond_with(ok(json!({ "items": [item(7, "Sept")] })))
+        .mount(&server)
+        .await;
+
+    let dir = temp_dir();
+    let feeds = shared_feed_list(Vec::new());
+    let api = DataApi::load_from_dir(feeds.clone(), &dir).await;
+    let (tx, mut rx) = mpsc::channel(16);
+    let handle = spawn_poller(
+        feeds,
+        PollConfig {
+            interval: Duration::from_secs(3600),
+            ..PollConfig::default()
+        },
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+    handle.set_sync(Some(FeverSync::new(fever(&server), api).into()));
+    handle.poll_now();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("sync event")
+        .expect("channel open");
+    match event {
+        Event::NewArticles(feed_id, entries) => {
+            assert_eq!(feed_id, "fever:10");
+            assert_eq!(entries[0].guid.as_deref(), Some("fever:7"));
+        }
+        other => panic!("unexpected event: {:?}", other),
+    }
+
+    handle.stop().await.unwrap();
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[test]
+fn sync_mode_defaults_to_local_and_round_trips() {
+    let config: AppConfig =
+        serde_json::from_value(serde_json::to_value(AppConfig::default()).unwrap()).unwrap();
+    assert_eq!(config.sync, SyncMode::Local);
+
+    let fever = SyncMode::Fever {
+        endpoint: "https://rss.example.org/api/fever.php".into(),
+        api_key: Secret::Plaintext("k".into()),
+    };
+    let value = serde_json::to_value(&fever).unwrap();
+    assert_eq!(value["mode"], "fever");
+    assert_eq!(serde_json::from_value::<SyncMode>(value).unwrap(), fever);
+}
@@ -0,0 +1,191 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    shared_feed_list, ArticleFilter, DataApi, FeedDescriptor, FeedEntry, FeedService, FilterRule,
+    PollConfig, RuleAction, RuleError, RuleField, RuleSet,
+};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_rules_{}", nanos))
+}
+
+fn entry(feed_id: &str, title: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: title.into(),
+        url: format!("https://example.org/{}", title.replace(' ', "-")),
+        guid: Some(title.into()),
+        ..Default::default()
+    }
+}
+
+fn rule(field: RuleField, pattern: &str, action: RuleAction) -> FilterRule {
+    FilterRule {
+        field,
+        pattern: pattern.into(),
+        action,
+        ..FilterRule::default()
+    }
+}
+
+#[test]
+fn substring_rules_ignore_case_and_respect_scope() {
+    let mut sponsored = rule(RuleField::Title, "Sponsorisé", RuleAction::Hide);
+    let compiled = sponsored.compile().unwrap();
+    assert!(compiled.matches(&entry("a", "Contenu SPONSORISÉ du jour")));
+    assert!(!compiled.matches(&entry("a", "Actualité")));
+
+    sponsored.scope = Some("b".into());
+    let scoped = sponsored.compile().unwrap();
+    assert!(!scoped.matches(&entry("a", "sponsorisé")));
+    assert!(scoped.matches(&entry("b", "sponsorisé")));
+}
+
+#[test]
+fn regex_summary_and_author_fields() {
+    let mut release = rule(RuleField::Title, r"^v\d+\.\d+", RuleAction::Highlight);
+    release.regex = true;
+    let compiled = release.compile().unwrap();
+    assert!(compiled.matches(&entry("a", "v1.2 publiée")));
+    assert!(!compiled.matches(&entry("a", "La v1.2 publiée")));
+
+    // Tags are stripped before matching, so a pattern never hits markup.
+    let mut with_summary = entry("a", "Titre");
+    with_summary.summary = Some(r#"<p>Un <a href="https://pub.example">lien</a></p>"#.into());
+    let summary = rule(RuleField::Summary, "pub.example", RuleAction::Hide);
+    assert!(!summary.compile().unwrap().matches(&with_summary));
+    let summary = rule(RuleField::Summary, "lien", RuleAction::Hide);
+    assert!(summary.compile().unwrap().matches(&with_summary));
+
+    with_summary.author = Some("Robot Éditorial".into());
+    let author = rule(RuleField::Author, "robot", RuleAction::MarkRead);
+    assert!(author.compile().unwrap().matches(&with_summary));
+}
+
+#[test]
+fn invalid_rules_are_reported_with_their_position() {
+    let empty = rule(RuleField::Title, "   ", RuleAction::Hide);
+    assert!(matches!(empty.compile(), Err(RuleError::EmptyPattern)));
+
+    let mut broken = rule(RuleField::Title, "(unclosed", RuleAction::Hide);
+    broken.regex = true;
+    assert!(matches!(broken.compile(), Err(RuleError::InvalidRegex(_))));
+
+    let valid = rule(RuleField::Title, "ok", RuleAction::Hide);
+    match RuleSet::compile(&[valid, broken]) {
+        Err(RuleError::InvalidRule { index, source }) => {
+            assert_eq!(index, 1);
+            assert!(matches!(*source, RuleError::InvalidRegex(_)));
+        }
+        other => panic!("expected InvalidRule, got {:?}", other),
+    }
+}
+
+#[test]
+fn apply_hides_marks_read_and_highlights() {
+    let rules = RuleSet::compile(&[
+        rule(RuleField::Title, "promo", RuleAction::Hide),
+        rule(RuleField::Title, "digest", RuleAction::MarkRead),
+        rule(RuleField::Title, "rust", RuleAction::Highlight),
+    ])
+    .unwrap();
+    let (kept, read) = rules.apply(vec![
+        entry("a", "Promo rust"),
+        entry("a", "Weekly digest rust"),
+        entry("a", "Rust 2.0"),
+        entry("a", "Météo"),
+    ]);
+
+    let titles: Vec<&str> = kept.iter().map(|e| e.title.as_str()).collect();
+    assert_eq!(titles, ["Weekly digest rust", "Rust 2.0", "Météo"]);
+    assert_eq!(
+        kept.iter().map(|e| e.highlighted).collect::<Vec<_>>(),
+        [true, true, false]
+    );
+    assert_eq!(read.len(), 1);
+    assert_eq!(read[0].title, "Weekly digest rust");
+}
+
+#[tokio::test]
+async fn rules_are_persisted_and_invalid_sets_are_refused() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(api.rules().await.is_empty());
+
+    let mut scoped = rule(RuleField::Author, "bot", RuleAction::MarkRead);
+    scoped.scope = Some("feed".into());
+    let rules = vec![rule(RuleField::Title, "promo", RuleAction::Hide), scoped];
+    api.set_rules(rules.clone()).await.unwrap();
+
+    let empty = rule(RuleField::Title, "", RuleAction::Hide);
+    let refused = api.set_rules(vec![empty]).await;
+    assert!(matches!(
+        refused,
+        Err(RuleError::InvalidRule { index: 0, .. })
+    ));
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(reloaded.rules().await, rules);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn refresh_applies_rules_before_storing_articles() {
+    const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>Promo du jour</title><link>http://e/1</link><guid>1</guid></item>
+<item><title>Digest hebdo</title><link>http://e/2</link><guid>2</guid></item>
+<item><title>Annonce</title><link>http://e/3</link><guid>3</guid></item>
+</channel></rss>"#;
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(RSS),
+        )
+        .mount(&server)
+        .await;
+
+    let service = FeedService::in_memory();
+    service.set_poll_config(PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    });
+    service
+        .add_feed(FeedDescriptor::new(
+            "news",
+            "News",
+            format!("{}/feed", server.uri()),
+        ))
+        .await
+        .unwrap();
+    service
+        .data_api()
+        .set_rules(vec![
+            rule(RuleField::Title, "promo", RuleAction::Hide),
+            rule(RuleField::Title, "digest", RuleAction::MarkRead),
+            rule(RuleField::Title, "annonce", RuleAction::Highlight),
+        ])
+        .await
+        .unwrap();
+
+    service.refresh_feed("news").await.unwrap();
+    let articles = service.articles(&ArticleFilter::default()).await;
+    let mut titles: Vec<&str> = articles.iter().map(|e| e.title.as_str()).collect();
+    titles.sort();
+    assert_eq!(titles, ["Annonce", "Digest hebdo"]);
+    for article in &articles {
+        let read = service.data_api().is_read(article).await;
+        assert_eq!(read, article.title == "Digest hebdo");
+        assert_eq!(article.highlighted, article.title == "Annonce");
+    }
+}
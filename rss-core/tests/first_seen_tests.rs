@@ -0,0 +1,85 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{ArticleFilter, FeedDescriptor, FeedEntry, FeedService, PollConfig};
+
+type Listing = Vec<(String, Option<DateTime<Utc>>, DateTime<Utc>)>;
+
+async fn listing(service: &FeedService) -> Listing {
+    service
+        .articles(&ArticleFilter::default())
+        .await
+        .into_iter()
+        .map(|a| (a.title, a.published_at, a.first_seen_at))
+        .collect()
+}
+
+#[tokio::test]
+async fn dateless_entries_keep_their_order_and_timestamps_across_polls() {
+    let server = MockServer::start().await;
+    let polls = AtomicUsize::new(0);
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(move |_: &wiremock::Request| {
+            // The second poll edits one entry so it is stored again.
+            let description = match polls.fetch_add(1, Ordering::SeqCst) {
+                0 => "Avant",
+                _ => "Après",
+            };
+            let body = format!(
+                r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>A</title><link>http://e/1</link><description>{description}</description></item>
+<item><title>B</title><link>http://e/2</link></item>
+<item><title>C</title><link>http://e/3</link></item></channel></rss>"#
+            );
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(body)
+        })
+        .mount(&server)
+        .await;
+
+    let service = FeedService::in_memory();
+    service.pause();
+    service.set_poll_config(PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    });
+    service
+        .add_feed(FeedDescriptor::new(
+            "news",
+            "News",
+            format!("{}/feed", server.uri()),
+        ))
+        .await
+        .unwrap();
+
+    service.refresh_feed("news").await.unwrap();
+    let first = listing(&service).await;
+    assert_eq!(first.len(), 3);
+    assert!(first.iter().all(|(_, published, _)| published.is_none()));
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    service.refresh_feed("news").await.unwrap();
+    let second = listing(&service).await;
+    assert_eq!(first, second);
+    let edited = service.articles(&ArticleFilter::default()).await;
+    let edited = edited.iter().find(|a| a.title == "A").unwrap();
+    assert_eq!(edited.summary.as_deref(), Some("Après"));
+}
+
+#[test]
+fn entries_cached_before_first_seen_at_still_load() {
+    let before = Utc::now();
+    let entry: FeedEntry = serde_json::from_str(
+        r#"{"feed_id":"f","title":"T","summary":null,"url":"http://e/1","published_at":null,"guid":null}"#,
+    )
+    .unwrap();
+    assert!(entry.first_seen_at >= before);
+    assert_eq!(entry.sort_date(), entry.first_seen_at);
+}
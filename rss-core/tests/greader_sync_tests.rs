@@ -0,0 +1,364 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Client;
+use serde_json::json;
+use wiremock::matchers::{
+    body_string_contains, header, method, path, query_param, query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    shared_feed_list, DataApi, Event, FeedEntry, GReaderClient, GReaderSync, GReaderTag,
+    PollConfig, PollError, Secret, SeenStore,
+};
+
+const API: &str = "/api/greader.php";
+const READING_LIST: &str =
+    "/api/greader.php/reader/api/0/stream/contents/user/-/state/com.google/reading-list";
+const STARRED: &str =
+    "/api/greader.php/reader/api/0/stream/contents/user/-/state/com.google/starred";
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_greader_{}", nanos))
+}
+
+fn reader(server: &MockServer) -> GReaderClient {
+    GReaderClient::new(
+        &format!("{}{}", server.uri(), API),
+        "alice",
+        Secret::Plaintext("app-password".into()),
+    )
+    .unwrap()
+}
+
+fn item(n: u32, title: &str, tags: &[&str]) -> serde_json::Value {
+    let mut categories = vec![
+        "user/-/state/com.google/reading-list".to_string(),
+        "user/-/label/Tech".to_string(),
+    ];
+    categories.extend(tags.iter().map(|t| t.to_string()));
+    json!({
+        "id": format!("tag:google.com,2005:reader/item/{:016x}", n),
+        "crawlTimeMsec": "1715000000000",
+        "published": 1715000000 + n as i64,
+        "title": title,
+        "canonical": [{"href": format!("https://journal.example.org/{}/", n)}],
+        "alternate": [{"href": format!("https://journal.example.org/{}/", n), "type": "text/html"}],
+        "summary": {"direction": "ltr", "content": "<p>Contenu</p>"},
+        "author": "Alice",
+        "categories": categories,
+        "origin": {"streamId": "feed/3", "title": "Le Journal du Code", "htmlUrl": "https://journal.example.org/"}
+    })
+}
+
+fn item_id(n: u32) -> String {
+    format!("tag:google.com,2005:reader/item/{:016x}", n)
+}
+
+async fn mount_login(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(format!("{}/accounts/ClientLogin", API)))
+        .and(body_string_contains("Email=alice"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("SID=sid\nLSID=lsid\nAuth=alice/0123abcd\n"),
+        )
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/reader/api/0/subscription/list", API)))
+        .and(header("authorization", "GoogleLogin auth=alice/0123abcd"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscriptions": [{
+                "id": "feed/3",
+                "title": "Le Journal du Code",
+                "categories": [{"id": "user/-/label/Tech", "label": "Tech"}],
+                "url": "https://journal.example.org/feed/",
+                "htmlUrl": "https://journal.example.org/",
+                "iconUrl": ""
+            }]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/reader/api/0/token", API)))
+        .respond_with(ResponseTemplate::new(200).set_body_string("write-token\n"))
+        .mount(server)
+        .await;
+}
+
+async fn mount_stream(server: &MockServer, route: &str, items: Vec<serde_json::Value>) {
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": items })))
+        .mount(server)
+        .await;
+}
+
+fn collect_new(events: Vec<Event>) -> Vec<FeedEntry> {
+    events
+        .into_iter()
+        .flat_map(|event| match event {
+            Event::NewArticles(_, entries) => entries,
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn rejected_login_is_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("{}/accounts/ClientLogin", API)))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Error=BadAuthentication\n"))
+        .mount(&server)
+        .await;
+
+    let err = reader(&server).login(&Client::new()).await.unwrap_err();
+    assert!(matches!(err, PollError::SyncAuth));
+}
+
+#[tokio::test]
+async fn stream_contents_follows_continuation_tokens() {
+    let server = MockServer::start().await;
+    mount_login(&server).await;
+    Mock::given(method("GET"))
+        .and(path(READING_LIST))
+        .and(query_param("xt", "user/-/state/com.google/read"))
+        .and(query_param_is_missing("c"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [item(1, "Un", &[]), item(2, "Deux", &[])],
+            "continuation": "page-2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(READING_LIST))
+        .and(query_param("c", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [item(3, "Trois", &[])]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let http = Client::new();
+    let client = reader(&server);
+    let session = client.login(&http).await.unwrap();
+    let items = client
+        .stream_contents(
+            &http,
+            &session,
+            "user/-/state/com.google/reading-list",
+            Some(GReaderTag::Read),
+        )
+        .await
+        .unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[2].id(), item_id(3));
+
+    let entry = items[0].clone().into_entry();
+    assert_eq!(entry.feed_id, "greader:feed/3");
+    assert_eq!(entry.categories, ["Tech"]);
+    assert_eq!(entry.url, "https://journal.example.org/1/");
+}
+
+#[tokio::test]
+async fn sync_announces_unread_and_pushes_local_marks() {
+    let server = MockServer::start().await;
+    mount_login(&server).await;
+    mount_stream(
+        &server,
+        READING_LIST,
+        vec![item(1, "Un", &[]), item(2, "Deux", &[])],
+    )
+    .await;
+    mount_stream(&server, STARRED, vec![]).await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = GReaderSync::new(reader(&server), api.clone());
+    let seen = SeenStore::in_memory();
+    let cfg = PollConfig::default();
+    let http = Client::new();
+
+    let entries = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    assert_eq!(entries.len(), 2);
+    assert_eq!(api.list_feeds().await[0].id, "greader:feed/3");
+    api.upsert_articles("greader:feed/3", entries)
+        .await
+        .unwrap();
+
+    let stored = api.list_articles("greader:feed/3").await;
+    let one = stored.iter().find(|e| e.title == "Un").unwrap().clone();
+    api.mark_read(&one).await.unwrap();
+    api.set_starred(&one, true).await.unwrap();
+
+    // Item 1 is still unread on the server: the local read mark wins and is pushed,
+    // along with the new star.
+    Mock::given(method("POST"))
+        .and(path(format!("{}/reader/api/0/edit-tag", API)))
+        .and(body_string_contains("T=write-token"))
+        .and(body_string_contains(
+            "a=user%2F-%2Fstate%2Fcom.google%2Fread",
+        ))
+        .and(body_string_contains("reader%2Fitem%2F0000000000000001"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{}/reader/api/0/edit-tag", API)))
+        .and(body_string_contains(
+            "a=user%2F-%2Fstate%2Fcom.google%2Fstarred",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let again = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    assert!(again.is_empty());
+    assert!(api.is_read(&one).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn remote_read_and_star_are_applied_locally() {
+    let server = MockServer::start().await;
+    mount_login(&server).await;
+    mount_stream(
+        &server,
+        READING_LIST,
+        vec![item(1, "Un", &[]), item(2, "Deux", &[])],
+    )
+    .await;
+    mount_stream(&server, STARRED, vec![]).await;
+
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = GReaderSync::new(reader(&server), api.clone());
+    let seen = SeenStore::in_memory();
+    let cfg = PollConfig::default();
+    let http = Client::new();
+
+    let entries = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    api.upsert_articles("greader:feed/3", entries)
+        .await
+        .unwrap();
+
+    // Item 2 was read and starred in another client.
+    server.reset().await;
+    mount_login(&server).await;
+    mount_stream(&server, READING_LIST, vec![item(1, "Un", &[])]).await;
+    mount_stream(
+        &server,
+        STARRED,
+        vec![item(
+            2,
+            "Deux",
+            &[
+                "user/-/state/com.google/read",
+                "user/-/state/com.google/starred",
+            ],
+        )],
+    )
+    .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{}/reader/api/0/edit-tag", API)))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK"))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    let stored = api.list_articles("greader:feed/3").await;
+    let one = stored.iter().find(|e| e.title == "Un").unwrap();
+    let two = stored.iter().find(|e| e.title == "Deux").unwrap();
+    assert!(!api.is_read(one).await);
+    assert!(api.is_read(two).await);
+    assert!(api.is_starred(two).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+// Serves items 1 and 2 as unread, with the given items in the starred stream.
+async fn remount(server: &MockServer, starred: &[u32]) {
+    server.reset().await;
+    mount_login(server).await;
+    mount_stream(
+        server,
+        READING_LIST,
+        vec![item(1, "Un", &[]), item(2, "Deux", &[])],
+    )
+    .await;
+    let starred = starred
+        .iter()
+        .map(|&n| item(n, "Favori", &["user/-/state/com.google/starred"]))
+        .collect();
+    mount_stream(server, STARRED, starred).await;
+}
+
+async fn expect_edit(server: &MockServer, body: &str, times: u64) {
+    Mock::given(method("POST"))
+        .and(path(format!("{}/reader/api/0/edit-tag", API)))
+        .and(body_string_contains(body))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK"))
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn unstarring_on_either_side_is_not_undone() {
+    let server = MockServer::start().await;
+    remount(&server, &[]).await;
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = GReaderSync::new(reader(&server), api.clone());
+    let seen = SeenStore::in_memory();
+    let cfg = PollConfig::default();
+    let http = Client::new();
+
+    let entries = collect_new(sync.run(&http, &seen, &cfg).await.unwrap());
+    api.upsert_articles("greader:feed/3", entries)
+        .await
+        .unwrap();
+    let stored = api.list_articles("greader:feed/3").await;
+    let one = stored.iter().find(|e| e.title == "Un").unwrap().clone();
+    let two = stored.iter().find(|e| e.title == "Deux").unwrap().clone();
+
+    // Starred locally (1) and on the server (2): each side gets the other's star.
+    api.set_starred(&one, true).await.unwrap();
+    remount(&server, &[2]).await;
+    expect_edit(&server, "a=user%2F-%2Fstate%2Fcom.google%2Fstarred", 1).await;
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    server.verify().await;
+    assert!(api.is_starred(&two).await);
+
+    // Unstarred locally (1) and on the server (2): both removals win.
+    api.set_starred(&one, false).await.unwrap();
+    remount(&server, &[1]).await;
+    expect_edit(&server, "r=user%2F-%2Fstate%2Fcom.google%2Fstarred", 1).await;
+    expect_edit(&server, "a=user%2F-%2Fstate%2Fcom.google%2Fstarred", 0).await;
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    server.verify().await;
+    assert!(!api.is_starred(&one).await);
+    assert!(!api.is_starred(&two).await);
+
+    // The next cycle has nothing left to change, even after a restart.
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let sync = GReaderSync::new(reader(&server), reloaded.clone());
+    remount(&server, &[]).await;
+    expect_edit(&server, "starred", 0).await;
+    sync.run(&http, &seen, &cfg).await.unwrap();
+    server.verify().await;
+    assert!(!reloaded.is_starred(&two).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+use rss_core::{
+    poll_once, shared_feed_list, spawn_poller, throttled_for, Event, FeedDescriptor, PollConfig,
+    SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#;
+
+// Answers every request with the same feed and records when it arrived.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<Instant>>>);
+
+impl Respond for Recorder {
+    fn respond(&self, _: &Request) -> ResponseTemplate {
+        self.0.lock().unwrap().push(Instant::now());
+        ResponseTemplate::new(200)
+            .insert_header("content-type", "application/rss+xml")
+            .set_body_string(RSS)
+    }
+}
+
+// Buckets are keyed by host and port and live for the whole process: a fresh server (not one
+// from wiremock's pool) keeps tests from sharing a bucket.
+async fn recording_server() -> (MockServer, Recorder) {
+    let server = MockServer::builder().start().await;
+    let recorder = Recorder::default();
+    Mock::given(method("GET"))
+        .respond_with(recorder.clone())
+        .mount(&server)
+        .await;
+    (server, recorder)
+}
+
+fn three_feeds(server: &MockServer) -> Vec<FeedDescriptor> {
+    ["a", "b", "c"]
+        .into_iter()
+        .map(|id| FeedDescriptor::new(id, id, format!("{}/{}", server.uri(), id)))
+        .collect()
+}
+
+fn limited_config(limit: (u32, Duration)) -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(5),
+        max_retries: 0,
+        per_host_rate_limit: Some(limit),
+        ..PollConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn requests_to_one_host_are_spaced_out() {
+    let (server, recorder) = recording_server().await;
+    let spacing = Duration::from_millis(300);
+    let cfg = limited_config((1, spacing));
+
+    let events = poll_once(
+        &three_feeds(&server),
+        &cfg,
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    let fetched = events
+        .iter()
+        .filter(|e| matches!(e, Event::FetchCompleted(_, o) if o.success))
+        .count();
+    assert_eq!(fetched, 3);
+
+    let stamps = recorder.0.lock().unwrap().clone();
+    assert_eq!(stamps.len(), 3);
+    for pair in stamps.windows(2) {
+        // A little slack for timer granularity.
+        let gap = pair[1] - pair[0];
+        assert!(gap >= spacing - Duration::from_millis(20), "gap {:?}", gap);
+    }
+    // The bucket is empty right after the third request.
+    let url = format!("{}/a", server.uri());
+    assert!(throttled_for(&url, cfg.per_host_rate_limit).is_some());
+    assert!(throttled_for(&url, None).is_none());
+}
+
+#[tokio::test]
+async fn burst_capacity_is_not_delayed() {
+    let (server, recorder) = recording_server().await;
+    let cfg = limited_config((3, Duration::from_secs(60)));
+
+    let started = Instant::now();
+    poll_once(
+        &three_feeds(&server),
+        &cfg,
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await;
+    assert_eq!(recorder.0.lock().unwrap().len(), 3);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn stopping_the_poller_interrupts_the_wait_for_a_token() {
+    let (server, recorder) = recording_server().await;
+    // One token per minute: the second feed would wait for a long time.
+    let cfg = limited_config((1, Duration::from_secs(60)));
+    let feeds = shared_feed_list(three_feeds(&server));
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    let handle = spawn_poller(feeds, cfg, Client::new(), tx, SeenStore::in_memory());
+
+    tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("first feed not fetched")
+        .expect("channel closed");
+    tokio::time::timeout(Duration::from_secs(2), handle.stop())
+        .await
+        .expect("stop waited for a token")
+        .expect("stop poller");
+    assert_eq!(recorder.0.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,67 @@
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{build_http_client, NetworkConfig, PollError};
+
+#[test]
+fn invalid_proxy_url_is_reported_instead_of_panicking() {
+    let network = NetworkConfig {
+        proxy_url: Some("not a proxy".into()),
+        ..NetworkConfig::default()
+    };
+    match build_http_client(&network) {
+        Err(PollError::InvalidProxy(msg)) => assert!(msg.contains("not a proxy")),
+        other => panic!("expected InvalidProxy, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn custom_user_agent_is_sent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("user-agent", "CustomAgent/1.0"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let network = NetworkConfig {
+        user_agent: Some("CustomAgent/1.0".into()),
+        ..NetworkConfig::default()
+    };
+    let client = build_http_client(&network).unwrap();
+    let resp = client
+        .get(format!("{}/feed", server.uri()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+}
+
+#[tokio::test]
+async fn requests_are_routed_through_the_configured_proxy() {
+    // The mock server plays the proxy: plain-HTTP requests arrive in absolute form
+    let proxy = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("via proxy"))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let network = NetworkConfig {
+        proxy_url: Some(proxy.uri()),
+        ..NetworkConfig::default()
+    };
+    let client = build_http_client(&network).unwrap();
+    let body = client
+        .get("http://feeds.example.invalid/feed")
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(body, "via proxy");
+}
@@ -0,0 +1,146 @@
+use rss_core::{shared_feed_list, DataApi, FeedEntry, SeenStore};
+
+// Excerpt of a WordPress feed behind a cache that timestamps the GUID on every rebuild:
+// same articles, same links (modulo tracking parameters), different GUIDs.
+const POLL_1: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+  <channel>
+    <title>Le Journal du Code</title>
+    <link>https://journal.example.org/</link>
+    <description>Actualités</description>
+    <item>
+      <title>Sortie de la version 2.3</title>
+      <link>https://journal.example.org/2024/05/sortie-2-3/</link>
+      <guid>https://journal.example.org/2024/05/sortie-2-3/?ts=1715000000</guid>
+      <pubDate>Mon, 06 May 2024 08:00:00 +0000</pubDate>
+      <description>Les nouveautés de la 2.3.</description>
+    </item>
+    <item>
+      <title>Compte rendu du meetup</title>
+      <link>https://journal.example.org/2024/05/meetup/</link>
+      <guid>https://journal.example.org/2024/05/meetup/?ts=1715000000</guid>
+      <pubDate>Sun, 05 May 2024 18:30:00 +0000</pubDate>
+      <description>Retour sur la soirée.</description>
+    </item>
+  </channel>
+</rss>"#;
+
+const POLL_2: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+  <channel>
+    <title>Le Journal du Code</title>
+    <link>https://journal.example.org/</link>
+    <description>Actualités</description>
+    <item>
+      <title>Sortie de la version 2.3</title>
+      <link>https://journal.example.org/2024/05/sortie-2-3/?utm_source=rss</link>
+      <guid>https://journal.example.org/2024/05/sortie-2-3/?ts=1715003600</guid>
+      <pubDate>Mon, 06 May 2024 08:00:00 +0000</pubDate>
+      <description>Les nouveautés de la 2.3.</description>
+    </item>
+    <item>
+      <title>Compte rendu du meetup</title>
+      <link>https://journal.example.org/2024/05/meetup/?utm_source=rss</link>
+      <guid>https://journal.example.org/2024/05/meetup/?ts=1715003600</guid>
+      <pubDate>Sun, 05 May 2024 18:30:00 +0000</pubDate>
+      <description>Retour sur la soirée.</description>
+    </item>
+  </channel>
+</rss>"#;
+
+fn parse(xml: &str) -> Vec<FeedEntry> {
+    let channel = rss::Channel::read_from(xml.as_bytes()).expect("valid rss");
+    channel
+        .items()
+        .iter()
+        .map(|item| FeedEntry::from_rss_item("journal", item))
+        .collect()
+}
+
+fn bare(guid: Option<&str>, permalink: Option<bool>, url: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: "f1".into(),
+        title: "Titre".into(),
+        summary: Some("Résumé".into()),
+        url: url.into(),
+        guid: guid.map(Into::into),
+        guid_is_permalink: permalink,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn permalink_guids_fall_back_to_normalized_url() {
+    let first = parse(POLL_1);
+    let second = parse(POLL_2);
+    assert_eq!(first[0].guid_is_permalink, Some(true));
+    assert_ne!(first[0].guid, second[0].guid);
+    for (a, b) in first.iter().zip(&second) {
+        assert_eq!(a.identity(), b.identity());
+    }
+}
+
+#[test]
+fn opaque_guid_is_preferred_over_url() {
+    let a = bare(Some("tag:example.org,2024:42"), Some(false), "http://e/a");
+    let b = bare(Some("tag:example.org,2024:42"), Some(false), "http://e/b");
+    assert_eq!(a.identity(), "guid:tag:example.org,2024:42");
+    assert_eq!(a.identity(), b.identity());
+}
+
+#[test]
+fn entries_without_guid_or_link_hash_title_and_content() {
+    let a = bare(None, None, "");
+    let mut b = bare(None, None, "");
+    assert_eq!(a.identity(), b.identity());
+    assert!(a.identity().starts_with("hash:"));
+    b.summary = Some("Autre résumé".into());
+    assert_ne!(a.identity(), b.identity());
+}
+
+#[tokio::test]
+async fn rotating_guids_are_not_announced_twice() {
+    let seen = SeenStore::in_memory();
+    for entry in parse(POLL_1) {
+        assert!(seen.is_new_and_mark(&entry).await);
+    }
+    for entry in parse(POLL_2) {
+        assert!(!seen.is_new_and_mark(&entry).await);
+    }
+}
+
+#[tokio::test]
+async fn seen_url_catches_changed_opaque_guid() {
+    let seen = SeenStore::in_memory();
+    let first = bare(Some("id-1715000000"), Some(false), "http://e/post/");
+    let second = bare(Some("id-1715003600"), Some(false), "http://e/post");
+    assert_ne!(first.identity(), second.identity());
+    assert!(seen.is_new_and_mark(&first).await);
+    assert!(!seen.is_new_and_mark(&second).await);
+}
+
+#[tokio::test]
+async fn read_state_survives_guid_churn() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_identity_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+
+    api.upsert_articles("journal", parse(POLL_1)).await.unwrap();
+    let stored = api.list_articles("journal").await;
+    api.mark_read(&stored[0]).await.unwrap();
+
+    api.upsert_articles("journal", parse(POLL_2)).await.unwrap();
+    assert_eq!(api.list_articles("journal").await.len(), 2);
+    for entry in parse(POLL_2) {
+        let expected = entry.title == stored[0].title;
+        assert_eq!(api.is_read(&entry).await, expected);
+    }
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
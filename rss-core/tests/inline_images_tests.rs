@@ -0,0 +1,90 @@
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    build_http_client, fetch_inline_image, split_inline_images, ContentBlock, NetworkConfig,
+    PollError, MAX_INLINE_IMAGE_BYTES,
+};
+
+fn image(url: &str, alt: Option<&str>) -> ContentBlock {
+    ContentBlock::Image {
+        url: Url::parse(url).unwrap(),
+        alt: alt.map(str::to_string),
+    }
+}
+
+#[test]
+fn images_are_interleaved_in_document_order() {
+    let base = Url::parse("https://blog.example/posts/1").unwrap();
+    let html = r#"<p>Intro</p><img src="/shot.png" alt=" Capture "><p>Milieu <img src="https://cdn.example/b.jpg?w=1&amp;h=2"></p><p>Fin</p>"#;
+    let blocks = split_inline_images(html, Some(&base), 10);
+    assert_eq!(
+        blocks,
+        vec![
+            ContentBlock::Html("<p>Intro</p>".into()),
+            image("https://blog.example/shot.png", Some("Capture")),
+            ContentBlock::Html("<p>Milieu ".into()),
+            image("https://cdn.example/b.jpg?w=1&h=2", None),
+            ContentBlock::Html("</p><p>Fin</p>".into()),
+        ]
+    );
+}
+
+#[test]
+fn unusable_and_extra_images_are_dropped() {
+    let html = concat!(
+        r#"<img src="data:image/png;base64,AAAA"><img>"#,
+        r#"<img src="relative.png"><img src="https://a.example/1.png">"#,
+        r#"<img src="https://a.example/2.png"><p>Texte</p>"#,
+    );
+    // Without a base, relative sources cannot be resolved.
+    let blocks = split_inline_images(html, None, 1);
+    assert_eq!(
+        blocks,
+        vec![
+            image("https://a.example/1.png", None),
+            ContentBlock::Html("<p>Texte</p>".into()),
+        ]
+    );
+
+    let text_only = split_inline_images("<p>Pas d’image</p>", None, 5);
+    assert_eq!(
+        text_only,
+        vec![ContentBlock::Html("<p>Pas d’image</p>".into())]
+    );
+}
+
+#[tokio::test]
+async fn image_download_is_capped() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/small.png"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"png-bytes".to_vec()))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/huge.png"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(vec![0u8; MAX_INLINE_IMAGE_BYTES + 1]),
+        )
+        .mount(&server)
+        .await;
+    let client = build_http_client(&NetworkConfig::default()).unwrap();
+
+    let small = Url::parse(&format!("{}/small.png", server.uri())).unwrap();
+    assert_eq!(
+        fetch_inline_image(&client, &small).await.unwrap(),
+        b"png-bytes"
+    );
+    let huge = Url::parse(&format!("{}/huge.png", server.uri())).unwrap();
+    assert!(matches!(
+        fetch_inline_image(&client, &huge).await,
+        Err(PollError::TooLarge(_))
+    ));
+    let missing = Url::parse(&format!("{}/missing.png", server.uri())).unwrap();
+    assert!(matches!(
+        fetch_inline_image(&client, &missing).await,
+        Err(PollError::HttpStatus(404))
+    ));
+}
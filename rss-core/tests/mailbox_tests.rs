@@ -0,0 +1,243 @@
+#![cfg(feature = "imap-feeds")]
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use reqwest::Client;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+use rss_core::{
+    collect_entries, message_entry, poll_once, shared_feed_list, AddFeedError, DataApi, Event,
+    MailSession, MailboxFeed, PollConfig, PollError, Secret, SeenStore,
+};
+
+fn message(id: &str, from: &str, subject: &str) -> Vec<u8> {
+    format!(
+        "From: {from}\r\nTo: me@example.org\r\nSubject: {subject}\r\n\
+Date: Mon, 21 Oct 2024 09:28:00 +0200\r\nMessage-ID: <{id}@news.example>\r\n\
+MIME-Version: 1.0\r\nContent-Type: multipart/alternative; boundary=\"b\"\r\n\r\n\
+--b\r\nContent-Type: text/plain\r\n\r\nPlain {subject}\r\n\
+--b\r\nContent-Type: text/html\r\n\r\n<p>Hello <b>{subject}</b></p>\r\n--b--\r\n"
+    )
+    .into_bytes()
+}
+
+fn mailbox() -> MailboxFeed {
+    MailboxFeed::new(
+        "imap.example.org",
+        "me@example.org",
+        Secret::Plaintext("hunter2".into()),
+    )
+}
+
+// Mailbox with scripted messages; ignores the server-side FROM criterion on purpose.
+#[derive(Default)]
+struct MockSession {
+    messages: BTreeMap<u32, Vec<u8>>,
+    searched_from: Vec<Option<String>>,
+    marked: Vec<u32>,
+}
+
+impl MailSession for MockSession {
+    async fn search_unseen(&mut self, from: Option<&str>) -> Result<Vec<u32>, PollError> {
+        self.searched_from.push(from.map(str::to_string));
+        Ok(self.messages.keys().rev().copied().collect())
+    }
+
+    async fn fetch_message(&mut self, uid: u32) -> Result<Vec<u8>, PollError> {
+        self.messages
+            .get(&uid)
+            .cloned()
+            .ok_or_else(|| PollError::Mailbox(format!("no message {}", uid)))
+    }
+
+    async fn mark_seen(&mut self, uid: u32) -> Result<(), PollError> {
+        self.marked.push(uid);
+        Ok(())
+    }
+}
+
+#[test]
+fn messages_become_entries() {
+    let raw = message("a1", "Weekly Rust <weekly@news.example>", "Issue 42");
+    let entry = message_entry("imap:x", &raw).expect("parsed");
+    assert_eq!(entry.feed_id, "imap:x");
+    assert_eq!(entry.title, "Issue 42");
+    assert_eq!(entry.guid.as_deref(), Some("a1@news.example"));
+    assert_eq!(entry.guid_is_permalink, Some(false));
+    assert_eq!(entry.author.as_deref(), Some("Weekly Rust"));
+    assert_eq!(
+        entry.published_at,
+        Some(Utc.with_ymd_and_hms(2024, 10, 21, 7, 28, 0).unwrap())
+    );
+    assert!(entry
+        .content_html
+        .as_deref()
+        .is_some_and(|html| html.contains("<b>Issue 42</b>")));
+    assert!(entry.url.is_empty());
+}
+
+#[tokio::test]
+async fn collect_filters_by_sender_and_leaves_messages_unread() {
+    let mut session = MockSession::default();
+    session
+        .messages
+        .insert(1, message("a1", "Weekly Rust <weekly@news.example>", "One"));
+    session
+        .messages
+        .insert(2, message("a2", "Boss <boss@work.example>", "Meeting"));
+    session
+        .messages
+        .insert(3, message("a3", "Weekly Rust <weekly@news.example>", "Two"));
+    let mut mailbox = mailbox();
+    mailbox.from_filter = Some("WEEKLY@news.example".into());
+
+    let entries = collect_entries(&mut session, &mailbox, "imap:x")
+        .await
+        .unwrap();
+    let titles: Vec<&str> = entries.iter().map(|e| e.title.as_str()).collect();
+    assert_eq!(titles, vec!["One", "Two"]);
+    assert_eq!(
+        session.searched_from,
+        vec![Some("WEEKLY@news.example".to_string())]
+    );
+    assert!(session.marked.is_empty());
+
+    mailbox.mark_seen_on_server = true;
+    collect_entries(&mut session, &mailbox, "imap:x")
+        .await
+        .unwrap();
+    assert_eq!(session.marked, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn mailbox_descriptors_round_trip_and_are_deduplicated() {
+    let mut mailbox = mailbox();
+    mailbox.folder = "Newsletters/Tech".into();
+    mailbox.from_filter = Some("weekly@news.example".into());
+    let feed = mailbox.descriptor().unwrap();
+    assert!(feed.id.starts_with("imap:"));
+    assert_eq!(feed.id, mailbox.id());
+    assert_eq!(feed.title, "weekly@news.example");
+    assert!(!feed.redacted_url().contains("me%40example.org"));
+    assert!(!feed.url.contains("hunter2"));
+
+    let parsed = MailboxFeed::from_descriptor(&feed).unwrap();
+    assert_eq!(
+        parsed,
+        MailboxFeed {
+            title: feed.title.clone(),
+            ..mailbox.clone()
+        }
+    );
+
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+    api.try_add_feed(feed.clone()).await.unwrap();
+    let mut again = feed.clone();
+    again.id = "other".into();
+    assert!(matches!(
+        api.try_add_feed(again).await,
+        Err(AddFeedError::Duplicate { .. })
+    ));
+    // Another account on the same server is a different feed.
+    let mut other = mailbox.clone();
+    other.username = "you@example.org".into();
+    api.try_add_feed(other.descriptor().unwrap()).await.unwrap();
+}
+
+// Minimal scripted IMAP server: answers the commands of one poll per connection.
+async fn imap_server(messages: Vec<Vec<u8>>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let messages = messages.clone();
+            tokio::spawn(async move {
+                let (read, mut write) = stream.into_split();
+                let mut lines = BufReader::new(read).lines();
+                write.write_all(b"* OK fake IMAP ready\r\n").await.unwrap();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    let (tag, command) = line.split_once(' ').unwrap();
+                    let mut reply = Vec::new();
+                    if command.starts_with("LOGIN") && !command.contains("\"hunter2\"") {
+                        reply.extend(format!("{} NO bad credentials\r\n", tag).bytes());
+                        write.write_all(&reply).await.unwrap();
+                        continue;
+                    }
+                    if command.starts_with("UID SEARCH") {
+                        let uids: Vec<String> =
+                            (1..=messages.len()).map(|uid| uid.to_string()).collect();
+                        reply.extend(format!("* SEARCH {}\r\n", uids.join(" ")).bytes());
+                    }
+                    if let Some(rest) = command.strip_prefix("UID FETCH ") {
+                        let uid: usize = rest.split(' ').next().unwrap().parse().unwrap();
+                        let body = &messages[uid - 1];
+                        reply.extend(
+                            format!("* {uid} FETCH (UID {uid} BODY[] {{{}}}\r\n", body.len())
+                                .bytes(),
+                        );
+                        reply.extend(body);
+                        reply.extend(b")\r\n");
+                    }
+                    if command == "LOGOUT" {
+                        reply.extend(b"* BYE\r\n");
+                    }
+                    reply.extend(format!("{} OK done\r\n", tag).bytes());
+                    write.write_all(&reply).await.unwrap();
+                }
+            });
+        }
+    });
+    port
+}
+
+#[tokio::test]
+async fn poller_reads_unseen_messages_once() {
+    let port = imap_server(vec![
+        message("a1", "weekly@news.example", "First"),
+        message("a2", "weekly@news.example", "Second"),
+    ])
+    .await;
+    let mut mailbox = mailbox();
+    mailbox.host = "127.0.0.1".into();
+    mailbox.port = port;
+    mailbox.tls = false;
+    let feeds = [mailbox.descriptor().unwrap()];
+    let cfg = PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    };
+    let seen = SeenStore::in_memory();
+
+    let (events, summary) = poll_once(&feeds, &cfg, &Client::new(), &seen).await;
+    assert_eq!(summary.errors, 0, "{:?}", events);
+    let titles: Vec<String> = events
+        .iter()
+        .find_map(|e| match e {
+            Event::NewArticles(_, entries) => Some(entries.iter().map(|e| e.title.clone())),
+            _ => None,
+        })
+        .expect("no new articles")
+        .collect();
+    assert_eq!(titles, vec!["First", "Second"]);
+
+    // Still unread on the server, but already seen: nothing new.
+    let (events, summary) = poll_once(&feeds, &cfg, &Client::new(), &seen).await;
+    assert_eq!(summary.errors, 0);
+    assert_eq!(summary.new_articles, 0);
+    assert!(!events.iter().any(|e| matches!(e, Event::NewArticles(..))));
+
+    // Rejected credentials surface as a fetch error.
+    let mut wrong = mailbox.clone();
+    wrong.password = Secret::Plaintext("nope".into());
+    let (events, _) = poll_once(&[wrong.descriptor().unwrap()], &cfg, &Client::new(), &seen).await;
+    let error = events.iter().find_map(|e| match e {
+        Event::FetchCompleted(_, outcome) => outcome.error.clone(),
+        _ => None,
+    });
+    assert!(error.is_some_and(|e| e.contains("bad credentials")));
+}
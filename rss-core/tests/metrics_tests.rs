@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use tokio::sync::broadcast;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, shared_feed_list, spawn_event_poller, Event, FeedDescriptor, FeedService,
+    MetricsSnapshot, PollConfig, PollerMetrics, SeenStore, EVENT_CHANNEL_CAPACITY,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>A</title><link>http://e/1</link><guid>1</guid></item>
+<item><title>B</title><link>http://e/2</link><guid>2</guid></item></channel></rss>"#;
+
+async fn server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ok"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(RSS))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/html"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_string("<html><body>Not a feed</body></html>"),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+fn feeds(server: &MockServer) -> Vec<FeedDescriptor> {
+    vec![
+        FeedDescriptor::new("ok", "Ok", format!("{}/ok", server.uri())),
+        FeedDescriptor::new("missing", "Missing", format!("{}/missing", server.uri())),
+        FeedDescriptor::new("html", "Html", format!("{}/html", server.uri())),
+    ]
+}
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 0,
+        metrics: Some(PollerMetrics::shared()),
+        ..PollConfig::default()
+    }
+}
+
+fn snapshot(cfg: &PollConfig) -> MetricsSnapshot {
+    cfg.metrics.as_ref().unwrap().snapshot()
+}
+
+#[tokio::test]
+async fn poll_once_counts_fetches_errors_by_kind_and_new_articles() {
+    let server = server().await;
+    let cfg = config();
+    let seen = SeenStore::in_memory();
+
+    poll_once(&feeds(&server), &cfg, &Client::new(), &seen).await;
+    let first = snapshot(&cfg);
+    assert_eq!(first.fetch_total, 3);
+    assert_eq!(first.fetch_errors_total.get("http_status"), Some(&1));
+    assert_eq!(first.fetch_errors_total.get("not_a_feed"), Some(&1));
+    assert_eq!(first.fetch_errors(), 2);
+    assert_eq!(first.articles_new_total, 2);
+    assert!(first.last_cycle_duration_seconds > 0.0);
+
+    // Already seen entries are fetched again but are not counted as new.
+    poll_once(&feeds(&server), &cfg, &Client::new(), &seen).await;
+    let second = snapshot(&cfg);
+    assert_eq!(second.fetch_total, 6);
+    assert_eq!(second.fetch_errors(), 4);
+    assert_eq!(second.articles_new_total, 2);
+}
+
+#[tokio::test]
+async fn background_cycle_updates_counters_and_gauges() {
+    let server = server().await;
+    let mut list = feeds(&server);
+    list[2].disabled = true;
+    let cfg = config();
+    let (events, mut rx) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+    let handle = spawn_event_poller(
+        shared_feed_list(list),
+        cfg.clone(),
+        Client::new(),
+        events,
+        SeenStore::in_memory(),
+    );
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !matches!(rx.recv().await, Ok(Event::CycleCompleted(_))) {}
+    })
+    .await
+    .expect("no cycle completed");
+    handle.stop().await.expect("stop poller");
+
+    let metrics = snapshot(&cfg);
+    assert_eq!(metrics.fetch_total, 2);
+    assert_eq!(metrics.fetch_errors_total.get("http_status"), Some(&1));
+    assert_eq!(metrics.articles_new_total, 2);
+    // Disabled feeds are configured even if they are not fetched.
+    assert_eq!(metrics.feeds_configured, 3);
+}
+
+#[tokio::test]
+async fn service_refresh_records_into_the_service_metrics() {
+    let server = server().await;
+    let service = FeedService::in_memory();
+    service.pause();
+    service.set_poll_config(PollConfig {
+        max_retries: 0,
+        ..PollConfig::default()
+    });
+    for feed in feeds(&server).into_iter().take(2) {
+        service.add_feed(feed).await.unwrap();
+    }
+
+    service.refresh_feed("ok").await.unwrap();
+    let metrics = service.metrics().snapshot();
+    assert_eq!(metrics.fetch_total, 1);
+    assert_eq!(metrics.articles_new_total, 2);
+    assert_eq!(metrics.feeds_configured, 2);
+    service.shutdown().await.unwrap();
+}
+
+#[test]
+fn prometheus_text_lists_every_metric_with_kind_labels() {
+    let mut snapshot = MetricsSnapshot {
+        fetch_total: 7,
+        articles_new_total: 12,
+        feeds_configured: 3,
+        last_cycle_duration_seconds: 1.5,
+        ..MetricsSnapshot::default()
+    };
+    snapshot.fetch_errors_total.insert("network".into(), 2);
+    let text = snapshot.to_prometheus();
+
+    for line in [
+        "# TYPE readrss_fetch_total counter",
+        "readrss_fetch_total 7",
+        "# TYPE readrss_fetch_errors_total counter",
+        "readrss_fetch_errors_total{kind=\"network\"} 2",
+        "readrss_articles_new_total 12",
+        "# TYPE readrss_feeds_configured gauge",
+        "readrss_feeds_configured 3",
+        "readrss_last_cycle_duration_seconds 1.5",
+    ] {
+        assert!(
+            text.lines().any(|l| l == line),
+            "missing {:?} in\n{}",
+            line,
+            text
+        );
+    }
+}
+
+#[cfg(feature = "metrics-http")]
+#[tokio::test]
+async fn metrics_endpoint_serves_the_prometheus_text() {
+    let server = server().await;
+    let cfg = config();
+    poll_once(
+        &feeds(&server),
+        &cfg,
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await;
+
+    let metrics = cfg.metrics.clone().unwrap();
+    let endpoint = rss_core::serve_metrics("127.0.0.1:0", metrics)
+        .await
+        .unwrap();
+    let base = format!("http://{}", endpoint.local_addr());
+    let response = Client::new()
+        .get(format!("{}/metrics", base))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let body = response.text().await.unwrap();
+    assert!(body.contains("readrss_fetch_total 3"));
+
+    let missing = Client::new()
+        .get(format!("{}/", base))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+}
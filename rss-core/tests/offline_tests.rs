@@ -0,0 +1,218 @@
+use std::net::{SocketAddr, TcpListener};
+use std::time::Duration;
+
+use chrono::Utc;
+use reqwest::Client;
+use tokio::sync::broadcast;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    shared_feed_list, spawn_event_poller, ConnectivityCheck, Event, FeedDescriptor, FeedService,
+    FetchErrorKind, FetchOutcome, PollConfig, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/a</link><guid>a</guid></item></channel></rss>"#;
+
+// A standalone server (not taken from wiremock's pool), so that dropping it closes its port.
+async fn feed_server(listener: Option<TcpListener>) -> MockServer {
+    let builder = MockServer::builder();
+    let server = match listener {
+        Some(listener) => builder.listener(listener).start().await,
+        None => builder.start().await,
+    };
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(RSS),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+// Address of a server that has gone away: connections are refused.
+async fn dropped_server() -> SocketAddr {
+    let server = feed_server(None).await;
+    let address = *server.address();
+    drop(server);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    address
+}
+
+fn feed(id: &str, address: SocketAddr) -> FeedDescriptor {
+    FeedDescriptor::new(id, id, format!("http://{}/feed", address))
+}
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(1),
+        max_retries: 0,
+        offline_after_hosts: 2,
+        offline_probe_interval: Duration::from_millis(100),
+        ..PollConfig::default()
+    }
+}
+
+fn outcome(http_status: Option<u16>, error_kind: Option<FetchErrorKind>) -> FetchOutcome {
+    FetchOutcome {
+        fetched_at: Utc::now(),
+        success: error_kind.is_none(),
+        http_status,
+        new_items: 0,
+        fetched_items: 0,
+        filtered_items: 0,
+        attempts: 1,
+        duration_ms: 1,
+        error: error_kind.map(|kind| kind.label().to_string()),
+        error_kind,
+        bytes_downloaded: 0,
+    }
+}
+
+async fn next_event(rx: &mut broadcast::Receiver<Event>, within: Duration) -> Option<Event> {
+    tokio::time::timeout(within, rx.recv()).await.ok()?.ok()
+}
+
+#[test]
+fn offline_needs_network_errors_on_distinct_servers_and_no_answer() {
+    let feeds = [
+        FeedDescriptor::new("a1", "a1", "https://a.example/1"),
+        FeedDescriptor::new("a2", "a2", "https://a.example/2"),
+        FeedDescriptor::new("b", "b", "https://b.example/feed"),
+        FeedDescriptor::new("c", "c", "https://c.example/feed"),
+    ];
+    let cfg = config();
+    let network = outcome(None, Some(FetchErrorKind::Network));
+
+    // Two failures on the same server are not enough.
+    let mut check = ConnectivityCheck::new(&feeds, &cfg);
+    check.record(&feeds[0], &network);
+    check.record(&feeds[1], &network);
+    assert_eq!(check.verdict(), None);
+    check.record(&feeds[2], &network);
+    assert_eq!(check.verdict(), Some(false));
+
+    // Any answer, even an HTTP error, proves the network works.
+    check.record(
+        &feeds[3],
+        &outcome(Some(503), Some(FetchErrorKind::HttpStatus)),
+    );
+    assert_eq!(check.verdict(), Some(true));
+
+    // With a single server, its network error is the whole round.
+    let mut single = ConnectivityCheck::new(&feeds[..2], &cfg);
+    single.record(&feeds[0], &network);
+    assert_eq!(single.verdict(), Some(false));
+}
+
+#[tokio::test]
+async fn poller_backs_off_while_offline_and_polls_everything_when_back() {
+    let (a, b) = (dropped_server().await, dropped_server().await);
+    let feeds = shared_feed_list(vec![feed("a", a), feed("b", b)]);
+    let (tx, mut rx) = broadcast::channel(64);
+    let cfg = PollConfig {
+        interval: Duration::from_millis(50),
+        ..config()
+    };
+    let connectivity = cfg.connectivity.clone();
+    let handle = spawn_event_poller(feeds, cfg, Client::new(), tx, SeenStore::in_memory());
+
+    let mut failures = 0;
+    loop {
+        match next_event(&mut rx, Duration::from_secs(5)).await {
+            Some(Event::FetchCompleted(_, outcome)) => {
+                assert_eq!(outcome.error_kind, Some(FetchErrorKind::Network));
+                failures += 1;
+            }
+            Some(Event::ConnectivityChanged(online)) => {
+                assert!(!online);
+                break;
+            }
+            other => panic!("unexpected event before going offline: {:?}", other),
+        }
+    }
+    assert_eq!(failures, 2);
+    assert!(!connectivity.is_online());
+
+    // Scheduled cycles are replaced by probes: no feed is fetched while offline.
+    let mut quiet = rx.resubscribe();
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    while let Ok(event) = quiet.try_recv() {
+        assert!(
+            matches!(event, Event::CycleCompleted(_)),
+            "unexpected event while offline: {:?}",
+            event
+        );
+    }
+
+    // The servers come back on the same ports: back online, then every feed is fetched.
+    let _a = feed_server(Some(TcpListener::bind(a).unwrap())).await;
+    let _b = feed_server(Some(TcpListener::bind(b).unwrap())).await;
+    let mut rx = quiet;
+    let mut back_online = false;
+    let mut fetched = Vec::new();
+    while fetched.len() < 2 {
+        match next_event(&mut rx, Duration::from_secs(5)).await {
+            Some(Event::ConnectivityChanged(online)) => back_online = online,
+            Some(Event::FetchCompleted(id, outcome)) => {
+                assert!(back_online, "feeds are fetched once back online");
+                assert!(outcome.success);
+                fetched.push(id);
+            }
+            Some(_) => {}
+            None => panic!("timed out waiting for the poll after reconnection"),
+        }
+    }
+    fetched.sort();
+    assert_eq!(fetched, ["a", "b"]);
+    assert!(connectivity.is_online());
+
+    handle.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn manual_refresh_reports_connectivity_changes() {
+    let service = FeedService::in_memory();
+    service.pause();
+    service.set_poll_config(config());
+    let (a, b) = (dropped_server().await, dropped_server().await);
+    service.add_feed(feed("a", a)).await.unwrap();
+    let mut rx = service.subscribe_events();
+
+    // One failing feed on its own (just added, mistyped address) is not an outage.
+    let events = service.refresh_feed("a").await.unwrap();
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, Event::ConnectivityChanged(_))));
+    assert!(service.is_online());
+
+    service.add_feed(feed("b", b)).await.unwrap();
+    let events = service.refresh_all().await;
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::ConnectivityChanged(false))));
+    assert!(!service.is_online());
+
+    // Any answer brings the service back online, even from a single feed.
+    let live = feed_server(None).await;
+    service
+        .add_feed(FeedDescriptor::new(
+            "live",
+            "live",
+            format!("{}/feed", live.uri()),
+        ))
+        .await
+        .unwrap();
+    service.refresh_feed("live").await.unwrap();
+    assert!(service.is_online());
+
+    let mut changes = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        if let Event::ConnectivityChanged(online) = event {
+            changes.push(online);
+        }
+    }
+    assert_eq!(changes, [false, true]);
+}
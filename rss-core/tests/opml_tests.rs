@@ -0,0 +1,31 @@
+use rss_core::{feeds_to_opml, FeedAuth, FeedDescriptor, Secret};
+
+fn descriptor(id: &str, title: &str, url: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: title.into(),
+        url: url.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn opml_lists_feeds_with_escaped_attributes_and_no_credentials() {
+    let mut private = descriptor("p", "Privé", "https://example.org/private.xml");
+    private.auth = Some(FeedAuth::Basic {
+        username: "alice".into(),
+        password: Secret::Plaintext("hunter2".into()),
+    });
+    let feeds = vec![
+        descriptor("a", "Tom & \"Jerry\"", "https://example.org/rss?a=1&b=2"),
+        private,
+    ];
+
+    let opml = feeds_to_opml(&feeds, "Mes <flux>");
+    assert!(opml.starts_with("<?xml"));
+    assert!(opml.contains("<title>Mes &lt;flux&gt;</title>"));
+    assert!(opml.contains(r#"text="Tom &amp; &quot;Jerry&quot;""#));
+    assert!(opml.contains(r#"xmlUrl="https://example.org/rss?a=1&amp;b=2""#));
+    assert_eq!(opml.matches("<outline type=\"rss\"").count(), 2);
+    assert!(!opml.contains("alice") && !opml.contains("hunter2"));
+}
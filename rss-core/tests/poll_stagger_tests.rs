@@ -0,0 +1,111 @@
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use tokio::sync::mpsc;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    shared_feed_list, spawn_poller, stagger_offset, Event, FeedDescriptor, FetchOutcome,
+    PollConfig, SeenStore,
+};
+
+const RSS: &str = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>A</title><link>https://example.org/a</link></item></channel></rss>"#;
+
+fn descriptor(server: &MockServer, id: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: id.into(),
+        url: format!("{}/{}", server.uri(), id),
+        ..Default::default()
+    }
+}
+
+async fn rss_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(RSS))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn next_outcome(rx: &mut mpsc::Receiver<Event>) -> (String, FetchOutcome) {
+    loop {
+        let evt = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for poller")
+            .expect("poller channel closed");
+        if let Event::FetchCompleted(feed_id, outcome) = evt {
+            return (feed_id, outcome);
+        }
+    }
+}
+
+#[test]
+fn offsets_are_stable_and_within_window() {
+    let window = Duration::from_secs(60);
+    // FNV-1a of the id: the same value on every run and every platform.
+    assert_eq!(
+        stagger_offset("alpha", window),
+        Duration::from_millis(47_115)
+    );
+    assert_eq!(
+        stagger_offset("beta", window),
+        Duration::from_millis(58_247)
+    );
+    assert_eq!(
+        stagger_offset("alpha", window),
+        stagger_offset("alpha", window)
+    );
+    for id in ["a", "news-feed", "https://example.org/rss"] {
+        assert!(stagger_offset(id, window) < window);
+    }
+    assert_eq!(stagger_offset("alpha", Duration::ZERO), Duration::ZERO);
+}
+
+#[tokio::test]
+async fn same_host_feeds_are_spaced_by_host_delay() {
+    let server = rss_server().await;
+    let feeds = shared_feed_list(vec![
+        descriptor(&server, "first"),
+        descriptor(&server, "second"),
+    ]);
+    let config = PollConfig {
+        interval: Duration::from_secs(3600),
+        host_delay: Duration::from_millis(400),
+        ..PollConfig::default()
+    };
+
+    let (tx, mut rx) = mpsc::channel(16);
+    let handle = spawn_poller(feeds, config, Client::new(), tx, SeenStore::in_memory());
+    let (_, a) = next_outcome(&mut rx).await;
+    let (_, b) = next_outcome(&mut rx).await;
+    handle.stop().await.unwrap();
+
+    let gap = (b.fetched_at - a.fetched_at).num_milliseconds().abs();
+    assert!(gap >= 400, "same-host fetches only {} ms apart", gap);
+}
+
+#[tokio::test]
+async fn scheduled_cycle_waits_for_the_feed_offset() {
+    let server = rss_server().await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "alpha")]);
+    let jitter = Duration::from_millis(800);
+    let config = PollConfig {
+        interval: Duration::from_secs(3600),
+        jitter,
+        ..PollConfig::default()
+    };
+
+    let started = Instant::now();
+    let (tx, mut rx) = mpsc::channel(16);
+    let handle = spawn_poller(feeds, config, Client::new(), tx, SeenStore::in_memory());
+    let (feed_id, outcome) = next_outcome(&mut rx).await;
+    handle.stop().await.unwrap();
+
+    assert_eq!(feed_id, "alpha");
+    assert!(outcome.success);
+    assert!(started.elapsed() >= stagger_offset("alpha", jitter));
+}
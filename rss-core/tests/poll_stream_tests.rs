@@ -0,0 +1,119 @@
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, poll_stream, shared_feed_list, Event, FeedDescriptor, FeedPollResult, PollConfig,
+    SeenStore,
+};
+
+const SLOW: Duration = Duration::from_millis(1500);
+
+fn rss(guid: &str) -> String {
+    format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title>
+<item><title>{guid}</title><link>http://e/{guid}</link><guid>{guid}</guid></item></channel></rss>"#
+    )
+}
+
+async fn server() -> MockServer {
+    let server = MockServer::start().await;
+    for (route, delay) in [
+        ("/slow", SLOW),
+        ("/a", Duration::ZERO),
+        ("/b", Duration::ZERO),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(rss(&route[1..]))
+                    .set_delay(delay),
+            )
+            .mount(&server)
+            .await;
+    }
+    server
+}
+
+// The slow feed comes first, so a sequential refresh would report nothing before it ends.
+fn feeds(server: &MockServer) -> Vec<FeedDescriptor> {
+    ["slow", "a", "b"]
+        .iter()
+        .map(|id| FeedDescriptor::new(*id, *id, format!("{}/{}", server.uri(), id)))
+        .collect()
+}
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..PollConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn results_arrive_before_the_slowest_feed_completes() {
+    let server = server().await;
+    let feeds = feeds(&server);
+    let store = shared_feed_list(feeds.clone());
+    let cfg = config();
+    let client = Client::new();
+    let seen = SeenStore::in_memory();
+
+    let started = Instant::now();
+    let mut arrivals: Vec<(FeedPollResult, Duration)> = Vec::new();
+    let mut stream = std::pin::pin!(poll_stream(&store, &feeds, &cfg, &client, &seen));
+    while let Some(result) = stream.next().await {
+        arrivals.push((result, started.elapsed()));
+    }
+
+    let order: Vec<&str> = arrivals.iter().map(|(r, _)| r.feed_id.as_str()).collect();
+    assert_eq!(order.len(), 3);
+    assert_eq!(order[2], "slow");
+    assert!(
+        arrivals[0].1 < SLOW,
+        "first result took {:?}",
+        arrivals[0].1
+    );
+    assert!(arrivals[2].1 >= SLOW);
+
+    let progress: Vec<(usize, usize)> = arrivals
+        .iter()
+        .map(|(r, _)| (r.completed, r.total))
+        .collect();
+    assert_eq!(progress, vec![(1, 3), (2, 3), (3, 3)]);
+    // Each result carries its own feed's events and the running summary.
+    let (first, _) = &arrivals[0];
+    assert!(first
+        .events
+        .iter()
+        .any(|e| matches!(e, Event::NewArticles(id, _) if *id == first.feed_id)));
+    assert_eq!(first.summary.feeds_polled, 1);
+    let (last, _) = &arrivals[2];
+    assert_eq!(last.summary.feeds_polled, 3);
+    assert_eq!(last.summary.new_articles, 3);
+}
+
+#[tokio::test]
+async fn poll_once_collects_events_in_feed_order() {
+    let server = server().await;
+    let mut feeds = feeds(&server);
+    feeds[2].disabled = true;
+    let (events, summary) =
+        poll_once(&feeds, &config(), &Client::new(), &SeenStore::in_memory()).await;
+
+    let fetched: Vec<&str> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::FetchCompleted(id, _) => Some(id.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fetched, vec!["slow", "a"]);
+    assert_eq!(summary.feeds_polled, 2);
+    assert!(summary.duration >= SLOW);
+}
@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use tokio::sync::mpsc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{shared_feed_list, spawn_poller, Event, FeedDescriptor, PollConfig, SeenStore};
+
+fn rss_with_item(guid: &str) -> String {
+    format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>{guid}</title><link>http://e/{guid}</link><guid>{guid}</guid></item></channel></rss>"#
+    )
+}
+
+async fn mount_feed(server: &MockServer, route: &str, guid: &str) {
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(rss_with_item(guid)),
+        )
+        .mount(server)
+        .await;
+}
+
+fn descriptor(server: &MockServer, id: &str, route: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: id.into(),
+        url: format!("{}{}", server.uri(), route),
+        ..Default::default()
+    }
+}
+
+// Next NewArticles feed id, skipping the FetchCompleted and CycleCompleted events around it
+async fn next_articles(rx: &mut mpsc::Receiver<Event>, within: Duration) -> Option<String> {
+    let deadline = tokio::time::Instant::now() + within;
+    loop {
+        match tokio::time::timeout_at(deadline, rx.recv()).await {
+            Ok(Some(Event::NewArticles(fid, _))) => return Some(fid),
+            Ok(Some(Event::FetchCompleted(..) | Event::CycleCompleted(_))) => continue,
+            _ => return None,
+        }
+    }
+}
+
+fn config(interval: Duration) -> PollConfig {
+    PollConfig {
+        interval,
+        request_timeout: Duration::from_secs(2),
+        max_retries: 1,
+        retry_backoff_ms: 10,
+        ..PollConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn paused_poller_emits_nothing_until_resumed() {
+    let server = MockServer::start().await;
+    mount_feed(&server, "/feed", "1").await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "feed1", "/feed")]);
+    let (tx, mut rx) = mpsc::channel(8);
+
+    let handle = spawn_poller(
+        feeds,
+        config(Duration::from_millis(50)),
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+    handle.pause();
+
+    // Several intervals go by without any event
+    let quiet = tokio::time::timeout(Duration::from_millis(300), rx.recv()).await;
+    assert!(quiet.is_err(), "paused poller must not emit events");
+
+    handle.resume();
+    let fid = next_articles(&mut rx, Duration::from_secs(2))
+        .await
+        .expect("timed out after resume");
+    assert_eq!(fid, "feed1");
+
+    handle.stop().await.expect("stop poller");
+}
+
+#[tokio::test]
+async fn poll_now_runs_a_cycle_without_waiting_for_the_ticker() {
+    let server = MockServer::start().await;
+    mount_feed(&server, "/a", "a1").await;
+    mount_feed(&server, "/b", "b1").await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "feedA", "/a")]);
+    let (tx, mut rx) = mpsc::channel(8);
+
+    let handle = spawn_poller(
+        feeds.clone(),
+        config(Duration::from_secs(3600)),
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+
+    // The first tick fires immediately
+    let fid = next_articles(&mut rx, Duration::from_secs(2))
+        .await
+        .expect("timed out");
+    assert_eq!(fid, "feedA");
+
+    // A feed added afterwards is picked up right away by poll_now, even while paused
+    rss_core::add_feed(&feeds, descriptor(&server, "feedB", "/b")).await;
+    handle.pause();
+    handle.poll_now();
+    let fid = next_articles(&mut rx, Duration::from_secs(2))
+        .await
+        .expect("poll_now did not trigger a cycle");
+    assert_eq!(fid, "feedB");
+
+    handle.stop().await.expect("stop poller");
+}
+
+#[tokio::test]
+async fn update_config_applies_new_interval_to_running_poller() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let server = MockServer::start().await;
+    // Each request serves a fresh item so that every cycle yields an event
+    let counter = AtomicUsize::new(0);
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(move |_: &wiremock::Request| {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(rss_with_item(&n.to_string()))
+        })
+        .mount(&server)
+        .await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "feed1", "/feed")]);
+    let (tx, mut rx) = mpsc::channel(64);
+
+    let handle = spawn_poller(
+        feeds,
+        config(Duration::from_secs(3600)),
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+
+    // Immediate first tick, then nothing for a long while
+    next_articles(&mut rx, Duration::from_secs(2))
+        .await
+        .expect("timed out");
+    // Only the summary of the first cycle is still pending.
+    while let Ok(evt) = tokio::time::timeout(Duration::from_millis(200), rx.recv()).await {
+        assert!(
+            matches!(evt, Some(Event::CycleCompleted(_))),
+            "no cycle expected with a one-hour interval"
+        );
+    }
+
+    handle.update_config(config(Duration::from_millis(50)));
+    let mut received = 0;
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(800);
+    while let Ok(Some(_)) = tokio::time::timeout_at(deadline, rx.recv()).await {
+        received += 1;
+    }
+    assert!(
+        received >= 3,
+        "expected events at the new cadence, got {}",
+        received
+    );
+
+    handle.stop().await.expect("stop poller");
+}
+
+#[tokio::test]
+async fn new_interval_counts_from_the_last_poll() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let server = MockServer::start().await;
+    let counter = AtomicUsize::new(0);
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(move |_: &wiremock::Request| {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(rss_with_item(&n.to_string()))
+        })
+        .mount(&server)
+        .await;
+    let feeds = shared_feed_list(vec![descriptor(&server, "feed1", "/feed")]);
+    let (tx, mut rx) = mpsc::channel(64);
+
+    let handle = spawn_poller(
+        feeds,
+        config(Duration::from_secs(3600)),
+        Client::new(),
+        tx,
+        SeenStore::in_memory(),
+    );
+    next_articles(&mut rx, Duration::from_secs(2))
+        .await
+        .expect("timed out");
+
+    // One second after the last poll, a one-second interval is already due: poll right away
+    // instead of waiting a full new interval from now.
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    handle.update_config(config(Duration::from_secs(1)));
+    let fid = next_articles(&mut rx, Duration::from_millis(600))
+        .await
+        .expect("the overdue poll should run right away");
+    assert_eq!(fid, "feed1");
+
+    handle.stop().await.expect("stop poller");
+}
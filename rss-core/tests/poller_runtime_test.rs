@@ -1,9 +1,12 @@
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 use reqwest::Client;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 
-use rss_core::{shared_feed_list, Event, FeedDescriptor, PollConfig, SeenStore};
+use rss_core::{
+    shared_feed_list, spawn_event_poller, Event, FeedDescriptor, PollConfig, SeenStore,
+    EVENT_CHANNEL_CAPACITY,
+};
 
 #[tokio::test]
 async fn spawn_poller_emits_event() {
@@ -22,6 +25,7 @@ async fn spawn_poller_emits_event() {
         id: "feed1".into(),
         title: "t".into(),
     url: format!("{}/feed", server.uri()),
+        ..Default::default()
     }]);
 
     let cfg = PollConfig {
@@ -29,6 +33,7 @@ async fn spawn_poller_emits_event() {
         request_timeout: std::time::Duration::from_secs(2),
         max_retries: 1,
         retry_backoff_ms: 10,
+        ..PollConfig::default()
     };
     let client = Client::new();
     let (tx, mut rx) = mpsc::channel(8);
@@ -36,17 +41,68 @@ async fn spawn_poller_emits_event() {
 
     let handle = rss_core::spawn_poller(feeds, cfg, client, tx, seen);
 
-    // Wait for an event up to 2 seconds
+    // Wait for an event up to 2 seconds (the fetch outcome comes first)
     let evt = tokio::time::timeout(std::time::Duration::from_secs(2), rx.recv())
         .await
         .expect("timed out")
         .expect("channel closed");
+    assert!(matches!(&evt, Event::FetchCompleted(fid, o) if fid == "feed1" && o.success));
 
+    let evt = tokio::time::timeout(std::time::Duration::from_secs(2), rx.recv())
+        .await
+        .expect("timed out")
+        .expect("channel closed");
     match evt {
         Event::NewArticles(fid, entries) => {
             assert_eq!(fid, "feed1");
             assert!(!entries.is_empty());
         }
+        other => panic!("unexpected event: {:?}", other),
+    }
+
+    handle.stop().await.expect("stop poller");
+}
+
+#[tokio::test]
+async fn every_subscriber_gets_new_articles() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#),
+        )
+        .mount(&server)
+        .await;
+
+    let feeds = shared_feed_list(vec![FeedDescriptor::new(
+        "feed1",
+        "t",
+        format!("{}/feed", server.uri()),
+    )]);
+    let cfg = PollConfig {
+        interval: std::time::Duration::from_secs(3600),
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    };
+    // Receivers created before the poller starts do not miss its first cycle.
+    let (events, first) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+    let handle = spawn_event_poller(feeds, cfg, Client::new(), events, SeenStore::in_memory());
+    let second = handle.subscribe();
+
+    for mut rx in [first, second] {
+        let entries = tokio::time::timeout(std::time::Duration::from_secs(2), async {
+            loop {
+                if let Event::NewArticles(_, entries) = rx.recv().await.expect("channel closed") {
+                    return entries;
+                }
+            }
+        })
+        .await
+        .expect("timed out");
+        assert_eq!(entries.len(), 1);
     }
 
     handle.stop().await.expect("stop poller");
@@ -1,7 +1,7 @@
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 use reqwest::Client;
-use rss_core::{poller::poll_once, FeedDescriptor, PollConfig, SeenStore};
+use rss_core::{poller::poll_once, FeedDescriptor, FeedService, PollConfig, SeenStore};
 
 fn sample_rss() -> String {
     r#"<?xml version=\"1.0\" encoding=\"UTF-8\"?>
@@ -47,28 +47,149 @@ async fn poll_once_emits_new_articles_and_deduplicates() {
         id: "feed1".into(),
         title: "Test".into(),
     url: format!("{}/feed", server.uri()),
+        ..Default::default()
     };
-    let feeds = vec![feed];
+    let mut feeds = vec![feed];
     let cfg = PollConfig {
         interval: std::time::Duration::from_millis(10),
         request_timeout: std::time::Duration::from_secs(2),
         max_retries: 1,
         retry_backoff_ms: 10,
+        ..PollConfig::default()
     };
     let client = Client::new();
     let seen = SeenStore::in_memory();
 
-    // First poll -> 2 new articles
-    let events = poll_once(&feeds, &cfg, &client, &seen).await;
-    assert_eq!(events.len(), 1);
-    match &events[0] {
+    // First poll -> 2 new articles, plus the channel metadata seen for the first time
+    let events = poll_once(&feeds, &cfg, &client, &seen).await.0;
+    assert_eq!(events.len(), 3);
+    match &events[1] {
         rss_core::Event::NewArticles(fid, entries) => {
             assert_eq!(fid, "feed1");
             assert_eq!(entries.len(), 2);
         }
+        other => panic!("unexpected event: {:?}", other),
     }
+    match &events[2] {
+        rss_core::Event::FeedMetadata(_, meta) => {
+            feeds[0].site_url = meta.site_url.clone();
+            feeds[0].description = meta.description.clone();
+            feeds[0].last_updated = meta.last_updated;
+        }
+        other => panic!("unexpected event: {:?}", other),
+    }
+
+    // Second poll -> 0 new articles after dedup (only the fetch outcome is reported)
+    let events2 = poll_once(&feeds, &cfg, &client, &seen).await.0;
+    assert_eq!(events2.len(), 1);
+    assert!(matches!(&events2[0], rss_core::Event::FetchCompleted(_, o) if o.new_items == 0));
+}
+
+async fn feed_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(sample_rss()),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+fn feed_config() -> PollConfig {
+    PollConfig {
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn fetch_outcome_counts_fetched_new_and_filtered_entries() {
+    let server = feed_server().await;
+    let feeds = vec![FeedDescriptor::new("feed1", "Test", format!("{}/feed", server.uri()))];
+    let client = Client::new();
+    let seen = SeenStore::in_memory();
+
+    let counts = |events: &[rss_core::Event]| match &events[0] {
+        rss_core::Event::FetchCompleted(_, o) => (o.fetched_items, o.new_items, o.filtered_items),
+        other => panic!("unexpected event: {:?}", other),
+    };
+    let events = poll_once(&feeds, &feed_config(), &client, &seen).await.0;
+    assert_eq!(counts(&events), (2, 2, 0));
+    let events = poll_once(&feeds, &feed_config(), &client, &seen).await.0;
+    assert_eq!(counts(&events), (2, 0, 2));
+}
+
+#[tokio::test]
+async fn seen_store_contains_does_not_mark_entries() {
+    let server = feed_server().await;
+    let feeds = vec![FeedDescriptor::new("feed1", "Test", format!("{}/feed", server.uri()))];
+    let seen = SeenStore::in_memory();
+
+    let events = poll_once(&feeds, &feed_config(), &Client::new(), &seen).await.0;
+    let entries = events
+        .iter()
+        .find_map(|e| match e {
+            rss_core::Event::NewArticles(_, entries) => Some(entries.clone()),
+            _ => None,
+        })
+        .expect("new articles");
+    // Marked by the poll.
+    assert!(seen.contains(&entries[0]).await);
+
+    // Checking an unseen entry twice: the first check must not mark it.
+    let unseen = rss_core::FeedEntry {
+        guid: Some("3".into()),
+        url: "http://example.com/3".into(),
+        ..entries[0].clone()
+    };
+    assert!(!seen.contains(&unseen).await);
+    assert!(!seen.contains(&unseen).await);
+}
+
+#[tokio::test]
+async fn feed_added_without_title_takes_the_channel_title() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rss+xml")
+                .set_body_string(sample_rss()),
+        )
+        .mount(&server)
+        .await;
+
+    let service = FeedService::in_memory();
+    service.set_poll_config(PollConfig {
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    });
+    let url = format!("{}/feed", server.uri());
+    service
+        .add_feed(FeedDescriptor::new("feed1", "", url.clone()))
+        .await
+        .unwrap();
+
+    let events = service.refresh_feed("feed1").await.unwrap();
+    assert!(events.iter().any(
+        |e| matches!(e, rss_core::Event::FeedTitle(id, t) if id == "feed1" && t == "Test Feed")
+    ));
+    let feeds = service.data_api().list_feeds().await;
+    assert_eq!(feeds[0].title, "Test Feed");
+
+    // A real title is never replaced.
+    let events = service.refresh_feed("feed1").await.unwrap();
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, rss_core::Event::FeedTitle(..))));
 
-    // Second poll -> 0 new articles after dedup
-    let events2 = poll_once(&feeds, &cfg, &client, &seen).await;
-    assert!(events2.is_empty());
+    // A title equal to the URL counts as a placeholder too.
+    let placeholder = FeedDescriptor::new("feed2", url.clone(), url);
+    assert!(placeholder.has_placeholder_title());
 }
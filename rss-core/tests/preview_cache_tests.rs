@@ -0,0 +1,120 @@
+use rss_core::{
+    article_preview, count_words, FeedEntry, PreviewCache, ReadingStats, DEFAULT_READING_WPM,
+    PREVIEW_MAX_CHARS,
+};
+
+fn entry(n: usize) -> FeedEntry {
+    FeedEntry {
+        feed_id: "journal".into(),
+        title: format!("Article {}", n),
+        summary: Some(format!("<p>Résumé {}</p>", n)),
+        url: format!("https://journal.example.org/{}/", n),
+        guid: Some(format!("id-{}", n)),
+        guid_is_permalink: Some(false),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn previews_are_plain_text_and_truncated() {
+    let mut long = entry(1);
+    long.content_html = Some(format!("<p>{}</p>", "mot ".repeat(200)));
+    let preview = article_preview(&long);
+    assert_eq!(preview.chars().count(), PREVIEW_MAX_CHARS);
+    assert!(preview.ends_with("..."));
+    assert!(!preview.contains("<p>"));
+
+    // Full content wins over the summary; no text at all gives an empty preview.
+    assert!(!preview.contains("Résumé"));
+    let mut empty = entry(2);
+    empty.summary = None;
+    assert_eq!(article_preview(&empty), "");
+}
+
+#[test]
+fn each_preview_is_computed_once_across_frames() {
+    let entries: Vec<FeedEntry> = (0..2_000).map(entry).collect();
+    let mut cache = PreviewCache::new();
+
+    // Simulates many frames scrolling over the whole history.
+    for _ in 0..5 {
+        for e in &entries {
+            assert!(!cache.get(e).is_empty());
+        }
+    }
+    assert_eq!(cache.computed(), 2_000);
+    assert_eq!(cache.len(), 2_000);
+    assert!(cache.get(&entries[42]).contains("Résumé 42"));
+}
+
+#[test]
+fn changed_content_invalidates_its_preview() {
+    let mut cache = PreviewCache::new();
+    let mut e = entry(7);
+    assert!(cache.get(&e).contains("Résumé 7"));
+
+    e.content_html = Some("<p>Article complet récupéré</p>".into());
+    assert!(cache.get(&e).contains("Article complet récupéré"));
+    assert!(cache.get(&e).contains("Article complet récupéré"));
+    assert_eq!(cache.computed(), 2);
+    assert_eq!(cache.len(), 1);
+
+    // Articles no longer in the list are dropped.
+    cache.get(&entry(8));
+    cache.retain(&[entry(8)]);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get(&entry(8)).contains("Résumé 8"));
+    assert_eq!(cache.computed(), 3);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn latin_reading_time_counts_whitespace_separated_words() {
+    assert_eq!(count_words("Bonjour, le monde — l’été 2024 !"), 5);
+    assert_eq!(count_words("  \n "), 0);
+
+    let mut e = entry(3);
+    e.content_html = Some(format!("<h1>Titre</h1><p>{}</p>", "mot ".repeat(440)));
+    assert_eq!(
+        e.reading_stats(DEFAULT_READING_WPM),
+        ReadingStats {
+            words: 441,
+            minutes: 3
+        }
+    );
+    // A few words still take a minute; no text at all takes none.
+    assert_eq!(entry(4).reading_stats(220).minutes, 1);
+    let mut empty = entry(5);
+    empty.summary = None;
+    assert_eq!(empty.reading_stats(220), ReadingStats::default());
+}
+
+#[test]
+fn cjk_reading_time_counts_two_characters_per_word() {
+    assert_eq!(count_words("東京は日本の首都です。"), 5);
+    assert_eq!(count_words("カタカナ"), 2);
+    // Mixed scripts: Latin words plus half the CJK characters, rounded up.
+    assert_eq!(count_words("Rust 言語"), 2);
+
+    let mut e = entry(6);
+    e.content_html = Some(format!("<p>{}</p>", "漢字".repeat(300)));
+    assert_eq!(e.reading_stats(100), ReadingStats::from_words(300, 100));
+    assert_eq!(e.reading_stats(100).minutes, 3);
+}
+
+#[test]
+fn cached_reading_stats_follow_the_content_and_speed() {
+    let mut cache = PreviewCache::new();
+    let mut e = entry(9);
+    e.content_html = Some(format!("<p>{}</p>", "mot ".repeat(500)));
+    assert_eq!(cache.reading_stats(&e, 250).minutes, 2);
+    assert_eq!(cache.reading_stats(&e, 100).minutes, 5);
+    assert_eq!(cache.get(&e).chars().count(), PREVIEW_MAX_CHARS);
+    assert_eq!(cache.computed(), 1);
+
+    e.content_html = Some("<p>Court</p>".into());
+    assert_eq!(cache.reading_stats(&e, 250).words, 1);
+    assert_eq!(cache.computed(), 2);
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use chrono::{FixedOffset, NaiveTime, TimeZone, Utc};
+use rss_core::{in_quiet_hours, quiet_hours_remaining, PollConfig};
+
+fn t(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+// The laptop case: no polling from 23:00 to 07:00.
+fn night() -> (NaiveTime, NaiveTime) {
+    (t(23, 0), t(7, 0))
+}
+
+#[test]
+fn window_crossing_midnight() {
+    assert!(!in_quiet_hours(night(), t(22, 59)));
+    assert!(in_quiet_hours(night(), t(23, 0)));
+    assert!(in_quiet_hours(night(), t(0, 0)));
+    assert!(in_quiet_hours(night(), t(6, 59)));
+    assert!(!in_quiet_hours(night(), t(7, 0)));
+    assert!(!in_quiet_hours(night(), t(12, 0)));
+}
+
+#[test]
+fn window_within_a_day_and_empty_window() {
+    let lunch = (t(12, 0), t(14, 0));
+    assert!(!in_quiet_hours(lunch, t(11, 59)));
+    assert!(in_quiet_hours(lunch, t(12, 0)));
+    assert!(!in_quiet_hours(lunch, t(14, 0)));
+
+    let empty = (t(8, 0), t(8, 0));
+    assert!(!in_quiet_hours(empty, t(8, 0)));
+    assert!(!in_quiet_hours(empty, t(20, 0)));
+}
+
+#[test]
+fn remaining_time_wraps_to_the_next_morning() {
+    let cet = FixedOffset::east_opt(3600).unwrap();
+    let evening = cet.with_ymd_and_hms(2026, 1, 10, 23, 30, 0).unwrap();
+    assert_eq!(
+        quiet_hours_remaining(night(), &evening),
+        Some(Duration::from_secs(7 * 3600 + 30 * 60))
+    );
+    let morning = cet.with_ymd_and_hms(2026, 1, 11, 6, 45, 0).unwrap();
+    assert_eq!(
+        quiet_hours_remaining(night(), &morning),
+        Some(Duration::from_secs(15 * 60))
+    );
+    let noon = cet.with_ymd_and_hms(2026, 1, 11, 12, 0, 0).unwrap();
+    assert_eq!(quiet_hours_remaining(night(), &noon), None);
+}
+
+// DST days in Europe/Paris: the window follows the local wall clock, so the same UTC instant
+// is judged with the offset in force on either side of the change.
+#[test]
+fn dst_transition_days_follow_the_wall_clock() {
+    let cet = FixedOffset::east_opt(3600).unwrap();
+    let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+
+    // Spring forward, 2026-03-29: 02:00 CET becomes 03:00 CEST.
+    let before = Utc.with_ymd_and_hms(2026, 3, 29, 0, 30, 0).unwrap();
+    assert!(in_quiet_hours(night(), before.with_timezone(&cet).time()));
+    // 05:30 UTC is 07:30 CEST: the night is over one hour earlier in UTC terms.
+    let after = Utc.with_ymd_and_hms(2026, 3, 29, 5, 30, 0).unwrap();
+    assert!(!in_quiet_hours(night(), after.with_timezone(&cest).time()));
+    // A window ending inside the skipped hour ends when the clock jumps past it.
+    let skipped = (t(1, 0), t(2, 30));
+    let jump = Utc.with_ymd_and_hms(2026, 3, 29, 1, 0, 0).unwrap();
+    assert!(!in_quiet_hours(skipped, jump.with_timezone(&cest).time()));
+
+    // Fall back, 2026-10-25: 03:00 CEST becomes 02:00 CET, 02:30 happens twice.
+    let repeated = (t(2, 0), t(3, 0));
+    let first = Utc.with_ymd_and_hms(2026, 10, 25, 0, 30, 0).unwrap();
+    let second = Utc.with_ymd_and_hms(2026, 10, 25, 1, 30, 0).unwrap();
+    assert!(in_quiet_hours(repeated, first.with_timezone(&cest).time()));
+    assert!(in_quiet_hours(repeated, second.with_timezone(&cet).time()));
+    // 06:30 UTC is 07:30 CET: awake again.
+    let morning = Utc.with_ymd_and_hms(2026, 10, 25, 6, 30, 0).unwrap();
+    assert!(!in_quiet_hours(night(), morning.with_timezone(&cet).time()));
+}
+
+#[test]
+fn poll_config_reads_quiet_hours_from_the_feed_config() {
+    let mut feeds = rss_core::FeedConfig::default();
+    assert_eq!(PollConfig::from_feed_config(&feeds).quiet_hours, None);
+    assert_eq!(PollConfig::default().quiet_hours_remaining(), None);
+
+    feeds.quiet_hours = Some(night());
+    feeds.quiet_hours_catch_up = false;
+    let cfg = PollConfig::from_feed_config(&feeds);
+    assert_eq!(cfg.quiet_hours, Some(night()));
+    assert!(!cfg.quiet_hours_catch_up);
+
+    let json = serde_json::to_value(&feeds).unwrap();
+    assert_eq!(
+        json["quiet_hours"],
+        serde_json::json!(["23:00:00", "07:00:00"])
+    );
+}
@@ -0,0 +1,92 @@
+use chrono::{TimeZone, Utc};
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{parse_iso8601, poll_once, Event, FeedDescriptor, PollConfig, SeenStore};
+
+// Modeled on arXiv's legacy RSS 1.0 export: items are siblings of <channel>, dates in dc:date.
+const ARXIV_RDF: &str = include_str!("fixtures/arxiv_rdf.xml");
+
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: std::time::Duration::from_secs(2),
+        max_retries: 0,
+        ..PollConfig::default()
+    }
+}
+
+#[test]
+fn iso8601_dates_are_parsed() {
+    assert_eq!(
+        parse_iso8601("2024-05-07T14:02:11-04:00"),
+        Some(Utc.with_ymd_and_hms(2024, 5, 7, 18, 2, 11).unwrap())
+    );
+    assert_eq!(
+        parse_iso8601(" 2024-05-06 "),
+        Some(Utc.with_ymd_and_hms(2024, 5, 6, 0, 0, 0).unwrap())
+    );
+    assert_eq!(parse_iso8601("Tue, 07 May 2024 14:02:11 GMT"), None);
+}
+
+#[tokio::test]
+async fn arxiv_rdf_feed_is_parsed_in_order_with_dates() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rss/cs.IR"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/rdf+xml")
+                .set_body_string(ARXIV_RDF),
+        )
+        .mount(&server)
+        .await;
+
+    let feed = FeedDescriptor {
+        id: "arxiv".into(),
+        title: "arXiv cs.IR".into(),
+        url: format!("{}/rss/cs.IR", server.uri()),
+        ..Default::default()
+    };
+    let events = poll_once(&[feed], &config(), &Client::new(), &SeenStore::in_memory())
+        .await
+        .0;
+    let entries = events
+        .into_iter()
+        .find_map(|event| match event {
+            Event::NewArticles(_, entries) => Some(entries),
+            _ => None,
+        })
+        .expect("RDF items should be announced");
+
+    let urls: Vec<_> = entries.iter().map(|e| e.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        [
+            "http://arxiv.org/abs/2405.03101",
+            "http://arxiv.org/abs/2405.03245",
+            "http://arxiv.org/abs/2405.02890",
+        ]
+    );
+    assert!(entries[0].title.starts_with("Sparse Retrieval"));
+    assert!(entries[0]
+        .summary
+        .as_deref()
+        .unwrap()
+        .contains("learned sparse retrieval"));
+    assert!(entries[0]
+        .author
+        .as_deref()
+        .unwrap()
+        .contains("Claire Martin"));
+
+    let dates: Vec<_> = entries.iter().map(|e| e.published_at).collect();
+    assert_eq!(
+        dates,
+        [
+            Some(Utc.with_ymd_and_hms(2024, 5, 7, 18, 2, 11).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 5, 7, 9, 45, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 5, 6, 0, 0, 0).unwrap()),
+        ]
+    );
+}
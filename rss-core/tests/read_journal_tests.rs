@@ -0,0 +1,91 @@
+use rss_core::{shared_feed_list, DataApi, FeedEntry, READ_JOURNAL_COMPACT_AFTER};
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "readrss_{}_{}",
+        name,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ))
+}
+
+fn entry(i: usize) -> FeedEntry {
+    FeedEntry {
+        feed_id: "f1".into(),
+        title: format!("A{}", i),
+        url: format!("http://e/{}", i),
+        guid: Some(format!("guid-{}", i)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn single_marks_are_journaled_instead_of_rewriting_the_store() {
+    let dir = temp_dir("journal");
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.mark_read(&entry(0)).await.unwrap();
+    assert!(api.set_starred(&entry(1), true).await.unwrap());
+    assert!(api.set_starred(&entry(2), true).await.unwrap());
+    assert!(api.set_starred(&entry(2), false).await.unwrap());
+
+    assert_eq!(api.read_store_writes(), 0);
+    assert!(!dir.join("read_store.json").exists());
+    let journal = std::fs::read_to_string(dir.join("read_store.journal")).unwrap();
+    assert_eq!(journal.lines().count(), 4);
+
+    // Reloading replays the journal, then folds it into read_store.json.
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(reloaded.is_read(&entry(0)).await);
+    assert!(reloaded.is_starred(&entry(1)).await);
+    assert!(!reloaded.is_starred(&entry(2)).await);
+    assert!(!dir.join("read_store.journal").exists());
+    assert!(dir.join("read_store.json").exists());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn truncated_last_journal_line_is_ignored() {
+    let dir = temp_dir("journal_torn");
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.mark_read(&entry(0)).await.unwrap();
+    api.mark_read(&entry(1)).await.unwrap();
+    drop(api);
+
+    // Simulate a crash in the middle of the second append.
+    let path = dir.join("read_store.journal");
+    let text = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, &text[..text.len() - 10]).unwrap();
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(reloaded.is_read(&entry(0)).await);
+    assert!(!reloaded.is_read(&entry(1)).await);
+    assert!(!path.exists());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn journal_is_compacted_past_the_threshold() {
+    let dir = temp_dir("journal_compact");
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    for i in 0..READ_JOURNAL_COMPACT_AFTER {
+        api.mark_read(&entry(i)).await.unwrap();
+    }
+    assert_eq!(api.read_store_writes(), 1);
+    assert!(!dir.join("read_store.journal").exists());
+
+    api.mark_read(&entry(READ_JOURNAL_COMPACT_AFTER))
+        .await
+        .unwrap();
+    api.compact_read_journal().await.unwrap();
+    assert_eq!(api.read_store_writes(), 2);
+    assert!(!dir.join("read_store.journal").exists());
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert!(reloaded.is_read(&entry(READ_JOURNAL_COMPACT_AFTER)).await);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,176 @@
+use reqwest::Client;
+use serde_json::json;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{ReadLaterError, ReadLaterService, Secret, WallabagClient, WallabagConfig};
+
+fn wallabag(server: &MockServer) -> WallabagClient {
+    WallabagClient::new(
+        Client::new(),
+        WallabagConfig {
+            url: server.uri(),
+            client_id: "1_readrss".into(),
+            client_secret: Secret::Plaintext("client-secret".into()),
+            username: "alice".into(),
+            password: Secret::Plaintext("hunter2".into()),
+        },
+    )
+    .unwrap()
+}
+
+fn token(access: &str, refresh: &str, expires_in: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "access_token": access,
+        "expires_in": expires_in,
+        "refresh_token": refresh,
+        "scope": null,
+        "token_type": "bearer"
+    }))
+}
+
+async fn mount_entries(server: &MockServer, access: &str, expected: u64) {
+    Mock::given(method("POST"))
+        .and(path("/api/entries.json"))
+        .and(header(
+            "authorization",
+            format!("Bearer {}", access).as_str(),
+        ))
+        .and(body_string_contains(
+            "url=https%3A%2F%2Fjournal.example.org%2Fpost%2F",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 1})))
+        .expect(expected)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn token_is_fetched_once_and_reused() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .and(body_string_contains("grant_type=password"))
+        .and(body_string_contains("client_id=1_readrss"))
+        .respond_with(token("access-1", "refresh-1", 3600))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_entries(&server, "access-1", 2).await;
+
+    let client = wallabag(&server);
+    client
+        .save("https://journal.example.org/post/", "Un")
+        .await
+        .unwrap();
+    client
+        .save("https://journal.example.org/post/", "Deux")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn expired_token_is_refreshed_transparently() {
+    let server = MockServer::start().await;
+    // A lifetime shorter than the safety margin makes the first token immediately stale.
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .and(body_string_contains("grant_type=password"))
+        .respond_with(token("access-1", "refresh-1", 10))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .and(body_string_contains("refresh_token=refresh-1"))
+        .respond_with(token("access-2", "refresh-2", 3600))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_entries(&server, "access-1", 1).await;
+    mount_entries(&server, "access-2", 1).await;
+
+    let client = wallabag(&server);
+    client
+        .save("https://journal.example.org/post/", "Un")
+        .await
+        .unwrap();
+    client
+        .save("https://journal.example.org/post/", "Deux")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn revoked_token_is_renewed_and_request_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .and(body_string_contains("grant_type=password"))
+        .respond_with(token("revoked", "refresh-1", 3600))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .respond_with(token("access-2", "refresh-2", 3600))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/entries.json"))
+        .and(header("authorization", "Bearer revoked"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_entries(&server, "access-2", 1).await;
+
+    wallabag(&server)
+        .save("https://journal.example.org/post/", "Un")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn failures_carry_the_http_status() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .respond_with(token("access-1", "refresh-1", 3600))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/entries.json"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let err = wallabag(&server)
+        .save("https://journal.example.org/post/", "Un")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ReadLaterError::HttpStatus(503)));
+    assert_eq!(err.to_string(), "HTTP 503");
+}
+
+#[tokio::test]
+async fn rejected_credentials_are_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/v2/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "invalid_grant",
+            "error_description": "Invalid username and password combination"
+        })))
+        .mount(&server)
+        .await;
+
+    let err = wallabag(&server)
+        .save("https://journal.example.org/post/", "Un")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ReadLaterError::Auth(400)));
+}
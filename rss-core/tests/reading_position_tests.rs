@@ -0,0 +1,121 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rss_core::{
+    shared_feed_list, DataApi, FeedDescriptor, FeedEntry, RetentionPolicy, MAX_READING_POSITIONS,
+};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_positions_{}", nanos))
+}
+
+fn descriptor(id: &str, retention: Option<RetentionPolicy>) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: id.into(),
+        url: format!("https://example.org/{}.xml", id),
+        retention,
+        ..Default::default()
+    }
+}
+
+fn entry(feed_id: &str, n: usize) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("Article {}", n),
+        url: format!("https://example.org/{}/{}", feed_id, n),
+        guid: Some(format!("{}-{}", feed_id, n)),
+        ..Default::default()
+    }
+}
+
+fn titles(entries: &[FeedEntry]) -> Vec<String> {
+    entries.iter().map(|e| e.title.clone()).collect()
+}
+
+#[tokio::test]
+async fn position_survives_reload_and_zero_clears_it() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    let article = entry("f1", 1);
+    assert_eq!(api.reading_position(&article).await, None);
+
+    api.set_reading_position(&article, 840.5).await.unwrap();
+    api.set_reading_position(&entry("f1", 2), 0.0)
+        .await
+        .unwrap();
+    assert_eq!(api.reading_position(&article).await, Some(840.5));
+    assert_eq!(api.reading_position(&entry("f1", 2)).await, None);
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(reloaded.reading_position(&article).await, Some(840.5));
+
+    // Back at the top: nothing left to resume.
+    reloaded.set_reading_position(&article, 0.0).await.unwrap();
+    assert_eq!(reloaded.reading_position(&article).await, None);
+    assert!(std::fs::read_to_string(dir.join("reading_positions.json"))
+        .unwrap()
+        .trim()
+        .eq("[]"));
+}
+
+#[tokio::test]
+async fn oldest_positions_are_dropped_beyond_the_limit() {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    for n in 0..=MAX_READING_POSITIONS {
+        api.set_reading_position(&entry("f1", n), 100.0)
+            .await
+            .unwrap();
+    }
+    assert_eq!(api.reading_position(&entry("f1", 0)).await, None);
+    assert_eq!(api.reading_position(&entry("f1", 1)).await, Some(100.0));
+
+    // Updating a position makes it the most recent one.
+    api.set_reading_position(&entry("f1", 1), 200.0)
+        .await
+        .unwrap();
+    api.set_reading_position(&entry("f1", 9999), 100.0)
+        .await
+        .unwrap();
+    assert_eq!(api.reading_position(&entry("f1", 1)).await, Some(200.0));
+    assert_eq!(api.reading_position(&entry("f1", 2)).await, None);
+}
+
+#[tokio::test]
+async fn positions_follow_pruned_articles_and_removed_feeds() {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    api.add_feed(descriptor("few", Some(RetentionPolicy::MaxCount(1))))
+        .await
+        .unwrap();
+    api.add_feed(descriptor("gone", None)).await.unwrap();
+
+    let first = FeedEntry {
+        published_at: Some(chrono::Utc::now() - chrono::Duration::days(1)),
+        ..entry("few", 1)
+    };
+    api.upsert_articles("few", vec![first.clone()])
+        .await
+        .unwrap();
+    api.set_reading_position(&first, 300.0).await.unwrap();
+    api.upsert_articles("gone", vec![entry("gone", 1)])
+        .await
+        .unwrap();
+    api.set_reading_position(&entry("gone", 1), 300.0)
+        .await
+        .unwrap();
+
+    // The newer article pushes the first one out of the cache.
+    let newer = FeedEntry {
+        published_at: Some(chrono::Utc::now()),
+        ..entry("few", 2)
+    };
+    api.upsert_articles("few", vec![newer]).await.unwrap();
+    assert_eq!(titles(&api.list_articles("few").await), ["Article 2"]);
+    assert_eq!(api.reading_position(&first).await, None);
+
+    api.remove_feed("gone").await.unwrap();
+    assert_eq!(api.reading_position(&entry("gone", 1)).await, None);
+}
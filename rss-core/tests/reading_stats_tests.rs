@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use rss_core::{
+    compute_stats, local_day, shared_feed_list, DataApi, FeedEntry, FeedStats, FetchOutcome,
+    StatsRange, STATS_TOP_FEEDS,
+};
+
+fn entry(feed_id: &str, n: usize, published_at: Option<DateTime<Utc>>) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("Article {}", n),
+        url: format!("https://example.org/{}/{}", feed_id, n),
+        published_at,
+        guid: Some(format!("{}-{}", feed_id, n)),
+        ..Default::default()
+    }
+}
+
+fn utc(rfc3339: &str) -> Option<DateTime<Utc>> {
+    Some(
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc),
+    )
+}
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn count_on(per_day: &[(NaiveDate, usize)], on: NaiveDate) -> usize {
+    per_day
+        .iter()
+        .find(|(d, _)| *d == on)
+        .map_or(0, |(_, c)| *c)
+}
+
+#[test]
+fn articles_are_bucketed_by_local_day() {
+    let paris = FixedOffset::east_opt(2 * 3600).unwrap();
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    // 23:30 UTC is already the next day in UTC+2, still the same day in UTC-5.
+    let late = utc("2026-03-09T23:30:00Z");
+    assert_eq!(local_day(late.unwrap(), &paris), day(2026, 3, 10));
+    assert_eq!(local_day(late.unwrap(), &new_york), day(2026, 3, 9));
+
+    let entries = vec![
+        entry("a", 1, late),
+        entry("a", 2, utc("2026-03-10T02:00:00Z")),
+        entry("a", 3, None),
+    ];
+    let range = StatsRange::last_days(day(2026, 3, 10), 30);
+    assert_eq!(range.first, day(2026, 2, 9));
+
+    let stats = compute_stats(&entries, |_| false, &HashMap::new(), range, &paris);
+    assert_eq!(stats.per_day.len(), 30);
+    assert_eq!(stats.per_day.first().unwrap().0, range.first);
+    assert_eq!(count_on(&stats.per_day, day(2026, 3, 10)), 2);
+    assert_eq!(count_on(&stats.per_day, day(2026, 3, 9)), 0);
+    assert_eq!(stats.unknown_date, 1);
+
+    // 02:00 UTC on the 10th is 21:00 on the 9th in UTC-5.
+    let stats = compute_stats(&entries, |_| false, &HashMap::new(), range, &new_york);
+    assert_eq!(count_on(&stats.per_day, day(2026, 3, 9)), 2);
+    assert_eq!(count_on(&stats.per_day, day(2026, 3, 10)), 0);
+}
+
+#[test]
+fn articles_outside_the_range_are_ignored() {
+    let paris = FixedOffset::east_opt(2 * 3600).unwrap();
+    let range = StatsRange::last_days(day(2026, 3, 10), 7);
+    let entries = vec![
+        // 21:59 UTC on Mar 3 is 23:59 local, the day before the range starts.
+        entry("a", 1, utc("2026-03-03T21:59:00Z")),
+        // 22:00 UTC is local midnight, the first day of the range.
+        entry("a", 2, utc("2026-03-03T22:00:00Z")),
+        // Tomorrow (local) is past the end of the range.
+        entry("a", 3, utc("2026-03-10T22:30:00Z")),
+    ];
+    let stats = compute_stats(&entries, |_| true, &HashMap::new(), range, &paris);
+    assert_eq!(count_on(&stats.per_day, day(2026, 3, 4)), 1);
+    assert_eq!(stats.per_day.iter().map(|(_, c)| c).sum::<usize>(), 1);
+    assert_eq!((stats.read, stats.unread), (1, 0));
+}
+
+#[test]
+fn top_feeds_read_counts_and_average_per_poll() {
+    let tz = Utc;
+    let range = StatsRange::last_days(day(2026, 3, 10), 30);
+    let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).single();
+    let mut entries = Vec::new();
+    for feed in 0..12 {
+        for n in 0..=feed {
+            entries.push(entry(&format!("feed{:02}", feed), n, now));
+        }
+    }
+    let stats = compute_stats(
+        &entries,
+        |e| e.title.ends_with(" 0"),
+        &HashMap::new(),
+        range,
+        &tz,
+    );
+    assert_eq!(stats.top_feeds.len(), STATS_TOP_FEEDS);
+    assert_eq!(stats.top_feeds[0], ("feed11".to_string(), 12));
+    assert_eq!(stats.top_feeds[9], ("feed02".to_string(), 3));
+    assert_eq!(stats.read, 12);
+    assert_eq!(stats.total(), entries.len());
+    assert_eq!(stats.average_per_poll, None);
+
+    let outcome = |success: bool, new_items: usize| FetchOutcome {
+        fetched_at: Utc::now(),
+        success,
+        http_status: Some(200),
+        new_items,
+        fetched_items: new_items,
+        filtered_items: 0,
+        attempts: 1,
+        duration_ms: 10,
+        error: None,
+        error_kind: None,
+        bytes_downloaded: 0,
+    };
+    let mut feed_stats = FeedStats::default();
+    feed_stats.record(outcome(true, 4));
+    feed_stats.record(outcome(true, 1));
+    feed_stats.record(outcome(false, 0));
+    let all = HashMap::from([("feed00".to_string(), feed_stats)]);
+    let stats = compute_stats(&entries, |_| false, &all, range, &tz);
+    assert_eq!(stats.average_per_poll, Some(2.5));
+}
+
+#[tokio::test]
+async fn data_api_stats_use_the_read_store() {
+    let api = DataApi::in_memory(shared_feed_list(Vec::new()));
+    let now = Some(Utc::now());
+    let entries = [entry("a", 1, now), entry("a", 2, now), entry("b", 1, None)];
+    api.upsert_articles("a", entries[..2].to_vec())
+        .await
+        .unwrap();
+    api.upsert_articles("b", entries[2..].to_vec())
+        .await
+        .unwrap();
+    api.mark_read(&entries[0]).await.unwrap();
+
+    let today = chrono::Local::now().date_naive();
+    let stats = api.stats(StatsRange::last_days(today, 30)).await;
+    assert_eq!((stats.read, stats.unread), (1, 2));
+    assert_eq!(stats.unknown_date, 1);
+    assert_eq!(count_on(&stats.per_day, today), 2);
+    assert_eq!(stats.top_feeds[0], ("a".to_string(), 2));
+}
@@ -0,0 +1,56 @@
+use chrono::{Duration, TimeZone, Utc};
+
+use rss_core::{format_absolute, format_relative};
+
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap()
+}
+
+fn ago(delta: Duration) -> String {
+    format_relative(now() - delta, now())
+}
+
+#[test]
+fn seconds_and_future_dates() {
+    assert_eq!(ago(Duration::seconds(0)), "à l’instant");
+    assert_eq!(ago(Duration::seconds(9)), "à l’instant");
+    assert_eq!(ago(Duration::seconds(10)), "il y a 10 s");
+    assert_eq!(ago(Duration::seconds(59)), "il y a 59 s");
+    assert_eq!(ago(Duration::seconds(-300)), "à l’instant");
+}
+
+#[test]
+fn minutes_and_hours() {
+    assert_eq!(ago(Duration::seconds(60)), "il y a 1 min");
+    assert_eq!(ago(Duration::minutes(59)), "il y a 59 min");
+    assert_eq!(ago(Duration::minutes(60)), "il y a 1 h");
+    assert_eq!(ago(Duration::hours(3)), "il y a 3 h");
+    assert_eq!(
+        ago(Duration::hours(23) + Duration::minutes(59)),
+        "il y a 23 h"
+    );
+}
+
+#[test]
+fn yesterday_and_last_week() {
+    assert_eq!(ago(Duration::hours(24)), "hier");
+    assert_eq!(ago(Duration::hours(47)), "hier");
+    assert_eq!(ago(Duration::days(2)), "il y a 2 j");
+    assert_eq!(ago(Duration::days(6)), "il y a 6 j");
+    assert_eq!(ago(Duration::days(7)), "la semaine dernière");
+    assert_eq!(ago(Duration::days(13)), "la semaine dernière");
+    assert_eq!(ago(Duration::days(14)), "il y a 2 semaines");
+}
+
+#[test]
+fn months_and_years() {
+    assert_eq!(ago(Duration::days(30)), "il y a 1 mois");
+    assert_eq!(ago(Duration::days(364)), "il y a 12 mois");
+    assert_eq!(ago(Duration::days(365)), "il y a 1 an");
+    assert_eq!(ago(Duration::days(3 * 365 + 10)), "il y a 3 ans");
+}
+
+#[test]
+fn absolute_format_is_day_first() {
+    assert_eq!(format_absolute(now()), "15/06/2024 12:00");
+}
@@ -0,0 +1,163 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, TimeZone, Utc};
+use rss_core::{shared_feed_list, DataApi, FeedDescriptor, FeedEntry, RetentionPolicy};
+
+const DAY: Duration = Duration::from_secs(24 * 3600);
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_retention_{}", nanos))
+}
+
+fn descriptor(id: &str, retention: Option<RetentionPolicy>) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: id.into(),
+        url: format!("https://example.org/{}.xml", id),
+        retention,
+        ..Default::default()
+    }
+}
+
+fn entry(feed_id: &str, n: usize, published_at: Option<DateTime<Utc>>) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: format!("Article {}", n),
+        url: format!("https://example.org/{}/{}", feed_id, n),
+        published_at,
+        guid: Some(format!("{}-{}", feed_id, n)),
+        ..Default::default()
+    }
+}
+
+fn titles(entries: &[FeedEntry]) -> Vec<String> {
+    entries.iter().map(|e| e.title.clone()).collect()
+}
+
+async fn api_with(feeds: Vec<FeedDescriptor>) -> DataApi {
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), temp_dir()).await;
+    for feed in feeds {
+        api.add_feed(feed).await.unwrap();
+    }
+    api
+}
+
+#[test]
+fn max_age_keeps_entries_exactly_at_the_limit() {
+    let now = Utc.with_ymd_and_hms(2026, 3, 31, 12, 0, 0).unwrap();
+    let policy = RetentionPolicy::MaxAge(DAY * 30);
+    let thirty_days = chrono::Duration::days(30);
+    assert!(!policy.is_expired(Some(now - thirty_days), now));
+    assert!(policy.is_expired(Some(now - thirty_days - chrono::Duration::seconds(1)), now));
+    assert!(!policy.is_expired(None, now));
+    assert!(!RetentionPolicy::KeepAll.is_expired(Some(now - thirty_days * 100), now));
+}
+
+#[test]
+fn retention_round_trips_through_feeds_json() {
+    let feed = descriptor("a", Some(RetentionPolicy::MaxAge(DAY * 7)));
+    let json = serde_json::to_string(&feed).unwrap();
+    assert!(
+        json.contains(r#""retention":{"max_age":604800}"#),
+        "{}",
+        json
+    );
+    assert_eq!(serde_json::from_str::<FeedDescriptor>(&json).unwrap(), feed);
+
+    let keep_all = descriptor("b", Some(RetentionPolicy::KeepAll));
+    let json = serde_json::to_string(&keep_all).unwrap();
+    assert!(json.contains(r#""retention":"keep_all""#), "{}", json);
+
+    let none = serde_json::to_string(&descriptor("c", None)).unwrap();
+    assert!(!none.contains("retention"));
+}
+
+#[tokio::test]
+async fn max_count_overrides_global_limit_on_upsert() {
+    let api = api_with(vec![descriptor("few", Some(RetentionPolicy::MaxCount(2)))]).await;
+    api.set_max_articles_per_feed(10);
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let entries: Vec<_> = (0..5)
+        .map(|n| entry("few", n, Some(base + chrono::Duration::hours(n as i64))))
+        .collect();
+    api.upsert_articles("few", entries).await.unwrap();
+    assert_eq!(
+        titles(&api.list_articles("few").await),
+        ["Article 4", "Article 3"]
+    );
+}
+
+#[tokio::test]
+async fn keep_all_ignores_global_limit() {
+    let api = api_with(vec![descriptor("all", Some(RetentionPolicy::KeepAll))]).await;
+    api.set_max_articles_per_feed(2);
+    let entries: Vec<_> = (0..5).map(|n| entry("all", n, None)).collect();
+    api.upsert_articles("all", entries).await.unwrap();
+    assert_eq!(api.list_articles("all").await.len(), 5);
+}
+
+#[tokio::test]
+async fn cleanup_applies_max_age_boundary_and_prunes_state() {
+    // One day ahead, so that the upsert (evaluated at the real clock) keeps every entry.
+    let now = Utc::now() + chrono::Duration::days(1);
+    let api = api_with(vec![descriptor(
+        "aged",
+        Some(RetentionPolicy::MaxAge(DAY * 30)),
+    )])
+    .await;
+    let boundary = entry("aged", 1, Some(now - chrono::Duration::days(30)));
+    let older = entry(
+        "aged",
+        2,
+        Some(now - chrono::Duration::days(30) - chrono::Duration::seconds(1)),
+    );
+    let undated = entry("aged", 3, None);
+    api.upsert_articles("aged", vec![boundary.clone(), older.clone(), undated])
+        .await
+        .unwrap();
+    api.mark_read(&older).await.unwrap();
+    api.set_starred(&older, true).await.unwrap();
+    api.mark_read(&boundary).await.unwrap();
+
+    let report = api.cleanup_at(now).await.unwrap();
+    assert_eq!(report.removed, 1);
+    assert!(report.bytes_saved > 0);
+
+    let kept = titles(&api.list_articles("aged").await);
+    assert!(kept.contains(&"Article 1".to_string()));
+    assert!(kept.contains(&"Article 3".to_string()));
+    assert!(!kept.contains(&"Article 2".to_string()));
+    assert!(!api.is_read(&older).await);
+    assert!(!api.is_starred(&older).await);
+    assert!(api.is_read(&boundary).await);
+
+    let again = api.cleanup_at(now).await.unwrap();
+    assert_eq!(again.removed, 0);
+}
+
+#[tokio::test]
+async fn cleanup_drops_articles_of_removed_feeds() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    api.add_feed(descriptor("gone", None)).await.unwrap();
+    api.add_feed(descriptor("kept", None)).await.unwrap();
+    api.upsert_articles("gone", vec![entry("gone", 1, None), entry("gone", 2, None)])
+        .await
+        .unwrap();
+    api.upsert_articles("kept", vec![entry("kept", 1, None)])
+        .await
+        .unwrap();
+    api.remove_feed("gone").await.unwrap();
+
+    let report = api.cleanup().await.unwrap();
+    assert_eq!(report.removed, 2);
+    assert!(api.list_articles("gone").await.is_empty());
+    assert_eq!(api.list_articles("kept").await.len(), 1);
+
+    let stored = std::fs::read_to_string(dir.join("articles_store.json")).unwrap();
+    assert!(!stored.contains("Article 2"));
+}
@@ -0,0 +1,184 @@
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    poll_once, Event, FeedDescriptor, FetchErrorKind, FetchOutcome, PollConfig, SeenStore,
+};
+
+fn sample_rss() -> &'static str {
+    r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid></item></channel></rss>"#
+}
+
+fn descriptor(server: &MockServer, route: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: "feed1".into(),
+        title: "Feed".into(),
+        url: format!("{}{}", server.uri(), route),
+        ..Default::default()
+    }
+}
+
+// Large exponential backoff: any unexpected sleep shows up in the elapsed time.
+fn config() -> PollConfig {
+    PollConfig {
+        request_timeout: Duration::from_secs(2),
+        max_retries: 2,
+        retry_backoff_ms: 2_000,
+        ..PollConfig::default()
+    }
+}
+
+async fn poll(server: &MockServer, route: &str, cfg: &PollConfig) -> FetchOutcome {
+    let events = poll_once(
+        &[descriptor(server, route)],
+        cfg,
+        &Client::new(),
+        &SeenStore::in_memory(),
+    )
+    .await
+    .0;
+    events
+        .into_iter()
+        .find_map(|e| match e {
+            Event::FetchCompleted(_, outcome) => Some(outcome),
+            _ => None,
+        })
+        .expect("fetch outcome")
+}
+
+#[tokio::test]
+async fn server_error_then_success_is_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/flaky"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/flaky"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(sample_rss()))
+        .mount(&server)
+        .await;
+
+    let cfg = PollConfig {
+        retry_backoff_ms: 5,
+        ..config()
+    };
+    let outcome = poll(&server, "/flaky", &cfg).await;
+    assert!(outcome.success);
+    assert_eq!(outcome.attempts, 2);
+    assert_eq!(outcome.new_items, 1);
+}
+
+#[tokio::test]
+async fn not_found_fails_immediately_without_sleeping() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let outcome = poll(&server, "/missing", &config()).await;
+    assert!(started.elapsed() < Duration::from_millis(1_000));
+    assert!(!outcome.success);
+    assert_eq!(outcome.attempts, 1);
+    assert_eq!(outcome.http_status, Some(404));
+    assert_eq!(outcome.error_kind, Some(FetchErrorKind::HttpStatus));
+}
+
+#[tokio::test]
+async fn parse_failure_is_not_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/broken"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let outcome = poll(&server, "/broken", &config()).await;
+    assert!(started.elapsed() < Duration::from_millis(1_000));
+    assert!(!outcome.success);
+    assert_eq!(outcome.attempts, 1);
+    assert_eq!(outcome.error_kind, Some(FetchErrorKind::Parse));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn gone_is_reported_distinctly() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/gone"))
+        .respond_with(ResponseTemplate::new(410))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let outcome = poll(&server, "/gone", &config()).await;
+    assert_eq!(outcome.attempts, 1);
+    assert_eq!(outcome.http_status, Some(410));
+    assert_eq!(outcome.error_kind, Some(FetchErrorKind::Gone));
+}
+
+#[tokio::test]
+async fn too_many_requests_waits_for_retry_after() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/limited"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/limited"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(sample_rss()))
+        .mount(&server)
+        .await;
+
+    let cfg = PollConfig {
+        retry_backoff_ms: 5,
+        ..config()
+    };
+    let started = Instant::now();
+    let outcome = poll(&server, "/limited", &cfg).await;
+    let elapsed = started.elapsed();
+    assert!(outcome.success);
+    assert_eq!(outcome.attempts, 2);
+    assert!(elapsed >= Duration::from_millis(1_000), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_millis(1_900), "{:?}", elapsed);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_by_max_backoff() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/unavailable"))
+        .respond_with(ResponseTemplate::new(503).insert_header("retry-after", "3600"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/unavailable"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(sample_rss()))
+        .mount(&server)
+        .await;
+
+    let cfg = PollConfig {
+        max_backoff_ms: 20,
+        ..config()
+    };
+    let started = Instant::now();
+    let outcome = poll(&server, "/unavailable", &cfg).await;
+    assert!(outcome.success);
+    assert!(started.elapsed() < Duration::from_millis(1_000));
+}
@@ -0,0 +1,155 @@
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::sanitize::{sanitize_html, truncate_html, TRUNCATION_NOTE};
+use rss_core::{poll_once, Event, FeedDescriptor, PollConfig, SeenStore};
+
+fn rss_with_description(html: &str) -> String {
+    format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><item><title>A</title><link>http://e/1</link><guid>1</guid><description><![CDATA[{}]]></description></item></channel></rss>"#,
+        html
+    )
+}
+
+#[tokio::test]
+async fn polled_content_is_sanitized_and_bounded() {
+    let huge_image = format!(
+        r#"<img src="data:image/png;base64,{}" alt="x">"#,
+        "A".repeat(5 * 1024 * 1024)
+    );
+    let html = format!(
+        r#"<p onclick="steal()">Bonjour</p><script>alert(1)</script>{}<a href="javascript:alert(2)">lien</a>"#,
+        huge_image
+    );
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(rss_with_description(&html)))
+        .mount(&server)
+        .await;
+
+    let feed = FeedDescriptor {
+        id: "f".into(),
+        title: "F".into(),
+        url: format!("{}/feed", server.uri()),
+        ..Default::default()
+    };
+    let cfg = PollConfig {
+        max_retries: 0,
+        ..PollConfig::default()
+    };
+    let events = poll_once(&[feed], &cfg, &Client::new(), &SeenStore::in_memory())
+        .await
+        .0;
+    let Some(entries) = events.into_iter().find_map(|e| match e {
+        Event::NewArticles(_, entries) => Some(entries),
+        _ => None,
+    }) else {
+        panic!("expected new entries");
+    };
+    let summary = entries[0].summary.clone().unwrap_or_default();
+    assert!(summary.contains("Bonjour"));
+    assert!(!summary.contains("<script"));
+    assert!(!summary.contains("onclick"));
+    assert!(!summary.contains("javascript:"));
+    assert!(summary.len() < 1024);
+}
+
+#[test]
+fn sanitize_keeps_regular_markup() {
+    let html = r#"<p class="x">Texte <b>gras</b> <img src="data:image/gif;base64,R0lGOD" alt="pt"></p><!-- note --><STYLE>p{}</STYLE>"#;
+    let clean = sanitize_html(html);
+    assert_eq!(
+        clean,
+        r#"<p class="x">Texte <b>gras</b> <img src="data:image/gif;base64,R0lGOD" alt="pt"></p>"#
+    );
+}
+
+#[test]
+fn truncate_adds_note_and_never_splits_a_tag() {
+    let html = format!("<p>{}</p><a href=\"http://e/\">fin</a>", "é".repeat(100));
+    let cut = truncate_html(html.clone(), 205);
+    assert!(cut.ends_with(TRUNCATION_NOTE));
+    assert!(cut.len() <= 205);
+    let body = cut.trim_end_matches(TRUNCATION_NOTE);
+    assert!(html.starts_with(body));
+    assert!(!body.ends_with("<a"));
+    assert_eq!(truncate_html("<p>court</p>".into(), 1024), "<p>court</p>");
+}
+
+#[test]
+fn truncation_note_counts_against_the_limit() {
+    let html = "x".repeat(1000);
+    for max in [TRUNCATION_NOTE.len(), TRUNCATION_NOTE.len() + 1, 500] {
+        let cut = truncate_html(html.clone(), max);
+        assert!(cut.len() <= max, "limit {}", max);
+        assert!(cut.ends_with(TRUNCATION_NOTE));
+    }
+    // Too small for the note: the text alone, still within the limit.
+    assert_eq!(truncate_html(html, 10), "x".repeat(10));
+}
+
+#[test]
+fn stray_angle_bracket_keeps_the_rest_as_text() {
+    assert_eq!(
+        sanitize_html("<p>Je t’aime <3 et la suite"),
+        "<p>Je t’aime &lt;3 et la suite</p>"
+    );
+}
+
+#[test]
+fn embedded_content_and_page_level_tags_are_stripped() {
+    let html = concat!(
+        r#"<base href="https://evil.example/"><meta http-equiv="refresh" content="0;url=x">"#,
+        r#"<p>Avant</p><iframe src="https://evil.example/">repli</iframe>"#,
+        r#"<object data="x.swf"><param name="a"></object><embed src="x.swf">"#,
+        r#"<form action="https://evil.example/"><input name="pw"></form>"#,
+        "</iframe><p>Après</p>",
+    );
+    assert_eq!(sanitize_html(html), "<p>Avant</p><p>Après</p>");
+}
+
+#[test]
+fn encoded_and_split_script_schemes_are_dropped() {
+    for href in [
+        "jav&#x61;script:alert(1)",
+        "java\tscript:alert(1)",
+        "java&#10;script:alert(1)",
+        " JAVASCRIPT:alert(1)",
+        "vbscript:msgbox(1)",
+    ] {
+        let clean = sanitize_html(&format!(r#"<a href="{}">lien</a>"#, href));
+        assert_eq!(clean, "<a>lien</a>", "{:?}", href);
+    }
+    assert_eq!(
+        sanitize_html(r#"<a href="https://e/">lien</a>"#),
+        r#"<a href="https://e/">lien</a>"#
+    );
+}
+
+#[test]
+fn svg_math_frames_and_active_attributes_are_dropped() {
+    let html = concat!(
+        r#"<svg onload="alert(1)"><script>alert(2)</script></svg>"#,
+        r#"<math><mi xlink:href="javascript:alert(3)">x</mi></math>"#,
+        r#"<link rel="stylesheet" href="https://evil.example/x.css">"#,
+        r#"<frameset><frame src="javascript:alert(4)"></frameset>"#,
+        r#"<button formaction="javascript:alert(5)">b</button>"#,
+        r#"<img src="x" onerror="alert(6)" style="position:fixed">"#,
+        r#"<a href="data:text/html,<script>alert(7)</script>">d</a>"#,
+    );
+    let clean = sanitize_html(html);
+    for needle in [
+        "alert",
+        "<svg",
+        "<math",
+        "<link",
+        "<frame",
+        "formaction",
+        "style=",
+    ] {
+        assert!(!clean.contains(needle), "{} in {}", needle, clean);
+    }
+    assert!(clean.contains(r#"<img src="x">"#), "{}", clean);
+}
@@ -0,0 +1,162 @@
+use rss_core::{FeedEntry, SeenStore};
+
+fn entry(i: usize) -> FeedEntry {
+    FeedEntry {
+        feed_id: "f1".into(),
+        title: format!("A{}", i),
+        url: format!("http://e/{}", i),
+        guid: Some(format!("guid-{}", i)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn seen_store_defers_writes_until_flush() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_seen_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let path = dir.join("seen_store.json");
+
+    let seen = SeenStore::load_from(&path).await;
+    for i in 0..50 {
+        assert!(seen.is_new_and_mark(&entry(i)).await);
+    }
+    // Nothing hits the disk while a batch is in progress
+    assert!(tokio::fs::metadata(&path).await.is_err());
+
+    seen.flush().await;
+    assert!(tokio::fs::metadata(&path).await.is_ok());
+    assert!(tokio::fs::metadata(path.with_extension("json.tmp"))
+        .await
+        .is_err());
+
+    // Reload: the whole batch was persisted in one write
+    let reloaded = SeenStore::load_from(&path).await;
+    for i in 0..50 {
+        assert!(!reloaded.is_new_and_mark(&entry(i)).await);
+    }
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn seen_store_prunes_oldest_identities_over_cap() {
+    let seen = SeenStore::in_memory();
+    seen.set_max_per_feed(3);
+    for i in 0..5 {
+        assert!(seen.is_new_and_mark(&entry(i)).await);
+    }
+    // The three most recent are still known
+    for i in 2..5 {
+        assert!(!seen.is_new_and_mark(&entry(i)).await);
+    }
+    // The two oldest were evicted and look new again
+    assert!(seen.is_new_and_mark(&entry(0)).await);
+}
+
+#[tokio::test]
+async fn seen_store_migrates_legacy_set_format() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_seen_legacy_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    tokio::fs::create_dir_all(&dir).await.unwrap();
+    let path = dir.join("seen_store.json");
+    tokio::fs::write(
+        &path,
+        br#"{ "seen": { "f1": ["guid:guid-0", "guid:guid-1"] } }"#,
+    )
+    .await
+    .unwrap();
+
+    let seen = SeenStore::load_from(&path).await;
+    assert!(!seen.is_new_and_mark(&entry(0)).await);
+    assert!(!seen.is_new_and_mark(&entry(1)).await);
+    assert!(seen.is_new_and_mark(&entry(2)).await);
+
+    // Legacy identities are the oldest and get evicted first
+    seen.set_max_per_feed(2);
+    assert!(seen.is_new_and_mark(&entry(3)).await);
+    assert!(!seen.is_new_and_mark(&entry(2)).await);
+    assert!(!seen.is_new_and_mark(&entry(3)).await);
+
+    // The file is rewritten in the ordered format and reloads cleanly
+    seen.flush().await;
+    let reloaded = SeenStore::load_from(&path).await;
+    assert!(!reloaded.is_new_and_mark(&entry(3)).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn removing_a_feed_drops_its_seen_state() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_seen_remove_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    let seen = SeenStore::in_memory();
+    let api = rss_core::DataApi::load_from_dir(rss_core::shared_feed_list(Vec::new()), &dir)
+        .await
+        .with_seen_store(seen.clone());
+
+    assert!(seen.is_new_and_mark(&entry(0)).await);
+    api.remove_feed("f1").await.unwrap();
+    assert!(seen.is_new_and_mark(&entry(0)).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn data_api_loads_seen_state_and_forgets_unfollowed_feeds() {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!(
+        "readrss_seen_unified_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    // A seen store left by an older version: "gone" was removed without cleaning it.
+    let seen = SeenStore::load_from(dir.join("seen_store.json")).await;
+    let mut gone = entry(1);
+    gone.feed_id = "gone".into();
+    assert!(seen.is_new_and_mark(&entry(0)).await);
+    assert!(seen.is_new_and_mark(&gone).await);
+    seen.flush().await;
+    std::fs::write(
+        dir.join("feeds.json"),
+        r#"[{ "id": "f1", "title": "F1", "url": "http://e/feed" }]"#,
+    )
+    .unwrap();
+
+    let api = rss_core::DataApi::load_from_dir(rss_core::shared_feed_list(Vec::new()), &dir).await;
+    let reloaded = SeenStore::load_from(dir.join("seen_store.json")).await;
+    assert!(!reloaded.contains(&gone).await);
+    assert!(!api.is_new_and_mark(&entry(0)).await);
+    assert!(api.is_new_and_mark(&gone).await);
+
+    // Pending identities are written with the other stores.
+    assert!(api.is_new_and_mark(&entry(2)).await);
+    api.flush_all().await.unwrap();
+    let reloaded = SeenStore::load_from(dir.join("seen_store.json")).await;
+    assert!(reloaded.contains(&entry(2)).await);
+
+    api.remove_feed("f1").await.unwrap();
+    let reloaded = SeenStore::load_from(dir.join("seen_store.json")).await;
+    assert!(!reloaded.contains(&entry(0)).await);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
@@ -0,0 +1,89 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rss_core::{shared_feed_list, DataApi, FeedEntry, SessionState, SessionView};
+
+fn temp_dir() -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_session_{}", nanos))
+}
+
+fn entry(n: usize) -> FeedEntry {
+    FeedEntry {
+        feed_id: "f1".into(),
+        title: format!("Article {}", n),
+        url: format!("https://example.org/{}", n),
+        guid: Some(format!("guid-{}", n)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn session_round_trips_through_the_data_dir() {
+    let dir = temp_dir();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(api.load_session().await, SessionState::default());
+
+    let session = SessionState {
+        selected_feed: Some("f1".into()),
+        view: SessionView::Article {
+            identity: entry(2).identity(),
+        },
+        unread_only: true,
+        list_scroll: 840.5,
+    };
+    api.save_session(&session).await.unwrap();
+
+    let reloaded = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(reloaded.load_session().await, session);
+
+    let category = SessionState {
+        view: SessionView::DiscoverCategory {
+            name: "Tech".into(),
+        },
+        ..SessionState::default()
+    };
+    reloaded.save_session(&category).await.unwrap();
+    assert_eq!(reloaded.load_session().await, category);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[tokio::test]
+async fn corrupt_or_partial_session_file_falls_back() {
+    let dir = temp_dir();
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("session.json"), "{ pas du json").unwrap();
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), &dir).await;
+    assert_eq!(api.load_session().await, SessionState::default());
+
+    // Missing fields take their default value.
+    std::fs::write(dir.join("session.json"), r#"{"unread_only": true}"#).unwrap();
+    let session = api.load_session().await;
+    assert!(session.unread_only);
+    assert_eq!(session.view, SessionView::ArticleList);
+    assert_eq!(session.selected_feed, None);
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
+
+#[test]
+fn saved_article_is_found_only_while_it_is_stored() {
+    let session = SessionState {
+        view: SessionView::Article {
+            identity: entry(2).identity(),
+        },
+        ..SessionState::default()
+    };
+    let articles = vec![entry(1), entry(2)];
+    assert_eq!(
+        session.article(&articles).map(|a| a.title.as_str()),
+        Some("Article 2")
+    );
+
+    // Pruned since the last run: the GUI falls back to the article list.
+    assert!(session.article(&[entry(1)]).is_none());
+    assert!(SessionState::default().article(&articles).is_none());
+}
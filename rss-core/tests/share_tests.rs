@@ -0,0 +1,55 @@
+use rss_core::{mailto_url, markdown_link, share_mailto, title_and_link, FeedEntry};
+
+fn entry(title: &str, url: &str) -> FeedEntry {
+    FeedEntry {
+        feed_id: "journal".into(),
+        title: title.into(),
+        url: url.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn markdown_link_escapes_the_title_and_protects_the_target() {
+    let article = entry(
+        "  Sortie [2.3]\n de ReadRSS  ",
+        "https://example.org/a (b)?q=1&r=2",
+    );
+    assert_eq!(
+        markdown_link(&article),
+        "[Sortie \\[2.3\\] de ReadRSS](https://example.org/a%20%28b%29?q=1&r=2)"
+    );
+    // Untitled articles use the link as text.
+    let untitled = entry(" ", "https://example.org/x");
+    assert_eq!(
+        markdown_link(&untitled),
+        "[https://example.org/x](https://example.org/x)"
+    );
+}
+
+#[test]
+fn title_and_link_are_on_two_lines() {
+    let article = entry("Bonjour\tle monde", "https://example.org/x");
+    assert_eq!(
+        title_and_link(&article),
+        "Bonjour le monde\nhttps://example.org/x"
+    );
+    assert_eq!(
+        title_and_link(&entry("", "https://example.org/x")),
+        "https://example.org/x"
+    );
+}
+
+#[test]
+fn mailto_percent_encodes_subject_and_body() {
+    assert_eq!(
+        mailto_url("Café & co", "a+b=c?\nligne 2 #1"),
+        "mailto:?subject=Caf%C3%A9%20%26%20co&body=a%2Bb%3Dc%3F%0D%0Aligne%202%20%231"
+    );
+    let article = entry("Q&A: 100% utile", "https://example.org/p?id=1&lang=fr");
+    assert_eq!(
+        share_mailto(&article),
+        "mailto:?subject=Q%26A%3A%20100%25%20utile\
+         &body=Q%26A%3A%20100%25%20utile%0D%0Ahttps%3A%2F%2Fexample.org%2Fp%3Fid%3D1%26lang%3Dfr"
+    );
+}
@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{Duration, Utc};
+use rss_core::{
+    shared_feed_list, DataApi, FeedDescriptor, FeedEntry, ImportSummary, SeenStore, StateError,
+};
+
+fn temp_dir(tag: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("readrss_state_{}_{}", tag, nanos))
+}
+
+fn feed(id: &str) -> FeedDescriptor {
+    FeedDescriptor {
+        id: id.into(),
+        title: id.into(),
+        url: format!("https://example.com/{}.xml", id),
+        ..Default::default()
+    }
+}
+
+fn entry(feed_id: &str, n: u32, title: &str, hours_ago: i64) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: title.into(),
+        url: format!("https://example.com/{}/{}", feed_id, n),
+        published_at: Some(Utc::now() - Duration::hours(hours_ago)),
+        guid: Some(format!("{}-{}", feed_id, n)),
+        guid_is_permalink: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn machine(dir: &PathBuf) -> (DataApi, SeenStore) {
+    let seen = SeenStore::load_from(dir.join("seen_store.json")).await;
+    let api = DataApi::load_from_dir(shared_feed_list(Vec::new()), dir)
+        .await
+        .with_seen_store(seen.clone());
+    (api, seen)
+}
+
+#[tokio::test]
+async fn diverging_states_are_merged_not_overwritten() {
+    let desktop_dir = temp_dir("desktop");
+    let laptop_dir = temp_dir("laptop");
+    let (desktop, desktop_seen) = machine(&desktop_dir).await;
+    let (laptop, laptop_seen) = machine(&laptop_dir).await;
+
+    // Desktop: feeds f1 + f2, has read the first article
+    desktop.add_feed(feed("f1")).await.unwrap();
+    desktop.add_feed(feed("f2")).await.unwrap();
+    let a1_old = entry("f1", 1, "First (draft)", 5);
+    let a2 = entry("f1", 2, "Second", 4);
+    desktop
+        .upsert_articles("f1", vec![a1_old.clone(), a2.clone()])
+        .await
+        .unwrap();
+    desktop.mark_read(&a1_old).await.unwrap();
+    assert!(desktop_seen.is_new_and_mark(&a1_old).await);
+
+    // Laptop: feeds f1 + f3, a newer revision of the first article, has read the second one
+    laptop.add_feed(feed("f1")).await.unwrap();
+    laptop.add_feed(feed("f3")).await.unwrap();
+    let a1_new = entry("f1", 1, "First (final)", 1);
+    let a3 = entry("f1", 3, "Third", 2);
+    laptop
+        .upsert_articles("f1", vec![a1_new.clone(), a3.clone()])
+        .await
+        .unwrap();
+    laptop.mark_read(&a2).await.unwrap();
+    assert!(laptop_seen.is_new_and_mark(&a3).await);
+
+    let export = laptop_dir.join("export.json");
+    laptop.export_state(&export).await.expect("export");
+    let summary = desktop.import_state(&export).await.expect("import");
+    assert_eq!(
+        summary,
+        ImportSummary {
+            feeds_added: 1,
+            read_added: 1,
+            articles_added: 1,
+            articles_updated: 1,
+        }
+    );
+
+    // Union of feeds and read sets
+    let mut ids: Vec<String> = desktop
+        .list_feeds()
+        .await
+        .into_iter()
+        .map(|f| f.id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["f1", "f2", "f3"]);
+    assert!(desktop.is_read(&a1_old).await);
+    assert!(desktop.is_read(&a2).await);
+    assert!(!desktop.is_read(&a3).await);
+
+    // Newest revision wins, nothing is lost
+    let titles: Vec<String> = desktop
+        .list_articles("f1")
+        .await
+        .into_iter()
+        .map(|e| e.title)
+        .collect();
+    assert_eq!(titles, vec!["First (final)", "Third", "Second"]);
+
+    // Seen identities from both machines
+    assert!(!desktop_seen.is_new_and_mark(&a1_old).await);
+    assert!(!desktop_seen.is_new_and_mark(&a3).await);
+
+    // The merge was persisted, and importing again changes nothing
+    let (reloaded, _) = machine(&desktop_dir).await;
+    assert_eq!(reloaded.list_feeds().await.len(), 3);
+    assert!(reloaded.is_read(&a2).await);
+    let again = reloaded.import_state(&export).await.expect("re-import");
+    assert_eq!(again, ImportSummary::default());
+
+    let _ = tokio::fs::remove_dir_all(&desktop_dir).await;
+    let _ = tokio::fs::remove_dir_all(&laptop_dir).await;
+}
+
+#[tokio::test]
+async fn future_schema_version_is_refused_without_changes() {
+    let dir = temp_dir("future");
+    let (api, _) = machine(&dir).await;
+    api.add_feed(feed("f1")).await.unwrap();
+
+    let path = dir.join("future.json");
+    tokio::fs::write(
+        &path,
+        br#"{ "schema_version": 99, "feeds": "a shape this build cannot read" }"#,
+    )
+    .await
+    .unwrap();
+
+    match api.import_state(&path).await {
+        Err(StateError::UnsupportedVersion { found, supported }) => {
+            assert_eq!(found, 99);
+            assert_eq!(supported, rss_core::STATE_SCHEMA_VERSION);
+        }
+        other => panic!("expected UnsupportedVersion, got {:?}", other),
+    }
+    assert_eq!(api.list_feeds().await.len(), 1);
+
+    // Files without a version are rejected as well
+    tokio::fs::write(&path, br#"{ "feeds": [] }"#)
+        .await
+        .unwrap();
+    assert!(matches!(
+        api.import_state(&path).await,
+        Err(StateError::MissingVersion)
+    ));
+
+    let _ = tokio::fs::remove_dir_all(&dir).await;
+}
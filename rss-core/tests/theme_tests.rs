@@ -0,0 +1,103 @@
+use rss_core::{contrast_ratio, ThemeConfig, ThemePreset, WCAG_AA_CONTRAST};
+
+#[test]
+fn presets_fill_every_color() {
+    let dark = ThemeConfig::default();
+    for preset in ThemePreset::ALL {
+        let Some(palette) = preset.palette() else {
+            assert_eq!(preset, ThemePreset::Custom);
+            continue;
+        };
+        assert_eq!(palette.preset, preset);
+        if preset != ThemePreset::DarkVsCode {
+            assert_ne!(
+                palette.background_color, dark.background_color,
+                "{preset:?}"
+            );
+            assert_ne!(palette.text_color, dark.text_color, "{preset:?}");
+        }
+        // Secondary text stays distinguishable from regular text.
+        assert_ne!(
+            palette.secondary_text_color, palette.text_color,
+            "{preset:?}"
+        );
+    }
+}
+
+#[test]
+fn light_preset_switches_to_light_mode() {
+    let mut theme = ThemeConfig::default();
+    assert!(theme.is_dark());
+    theme.apply_preset(ThemePreset::Light);
+    assert!(!theme.is_dark());
+    assert_eq!(theme.preset, ThemePreset::Light);
+    assert!(ThemePreset::HighContrast.palette().unwrap().is_dark());
+    assert!(ThemePreset::Solarized.palette().unwrap().is_dark());
+}
+
+#[test]
+fn custom_keeps_current_colors() {
+    let mut theme = ThemePreset::Solarized.palette().unwrap();
+    theme.accent_color = [200, 10, 10];
+    theme.apply_preset(ThemePreset::Custom);
+    assert_eq!(theme.preset, ThemePreset::Custom);
+    assert_eq!(theme.accent_color, [200, 10, 10]);
+    assert_eq!(theme.background_color, [0, 43, 54]);
+}
+
+#[test]
+fn themes_saved_before_presets_still_load() {
+    let json = r#"{
+        "background_color": [30, 30, 30],
+        "panel_color": [37, 37, 38],
+        "accent_color": [0, 122, 204],
+        "text_color": [204, 204, 204],
+        "secondary_text_color": [150, 150, 150],
+        "border_color": [60, 60, 60]
+    }"#;
+    let theme: ThemeConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(theme.preset, ThemePreset::DarkVsCode);
+
+    let saved = serde_json::to_value(ThemePreset::HighContrast.palette().unwrap()).unwrap();
+    assert_eq!(saved["preset"], "high_contrast");
+}
+
+#[test]
+fn contrast_ratio_follows_wcag() {
+    assert!((contrast_ratio([0, 0, 0], [255, 255, 255]) - 21.0).abs() < 1e-9);
+    assert!((contrast_ratio([120, 40, 200], [120, 40, 200]) - 1.0).abs() < 1e-9);
+    // Symmetric, and #767676 on white is the classic AA threshold (4.54:1).
+    let grey = contrast_ratio([118, 118, 118], [255, 255, 255]);
+    assert_eq!(grey, contrast_ratio([255, 255, 255], [118, 118, 118]));
+    assert!((WCAG_AA_CONTRAST..4.6).contains(&grey), "{grey}");
+}
+
+#[test]
+fn high_contrast_preset_meets_wcag_aa() {
+    let theme = ThemePreset::HighContrast.palette().unwrap();
+    for text in [
+        theme.text_color,
+        theme.secondary_text_color,
+        theme.accent_color,
+    ] {
+        for background in [theme.background_color, theme.panel_color] {
+            let ratio = contrast_ratio(text, background);
+            assert!(
+                ratio >= WCAG_AA_CONTRAST,
+                "{text:?} on {background:?}: {ratio:.2}"
+            );
+        }
+    }
+    assert!(theme.min_text_contrast() >= WCAG_AA_CONTRAST);
+    // Borders are non-text UI: 3:1 against the background.
+    assert!(contrast_ratio(theme.border_color, theme.background_color) >= 3.0);
+}
+
+#[test]
+fn min_text_contrast_reports_the_weakest_pair() {
+    let mut theme = ThemePreset::HighContrast.palette().unwrap();
+    theme.secondary_text_color = [40, 40, 40];
+    let expected = contrast_ratio([40, 40, 40], theme.panel_color);
+    assert_eq!(theme.min_text_contrast(), expected);
+    assert!(theme.min_text_contrast() < WCAG_AA_CONTRAST);
+}
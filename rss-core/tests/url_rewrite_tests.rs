@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    github_feed_url, resolve_rewrite, rewrite_feed_url, youtube_channel_id, GitHubFeed, PollError,
+    UrlRewrite,
+};
+
+const CHANNEL: &str = "UCaYhcUwRBNscFNUKTjgPFiA";
+
+fn rewrite(url: &str) -> Option<UrlRewrite> {
+    rewrite_feed_url(&Url::parse(url).unwrap())
+}
+
+fn feed(url: &str) -> Option<String> {
+    match rewrite(url) {
+        Some(UrlRewrite::Feed(feed)) => Some(feed.to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn reddit_urls_gain_the_rss_suffix() {
+    assert_eq!(
+        feed("https://www.reddit.com/r/rust").as_deref(),
+        Some("https://www.reddit.com/r/rust/.rss")
+    );
+    assert_eq!(
+        feed("https://old.reddit.com/r/rust/top/?t=week").as_deref(),
+        Some("https://www.reddit.com/r/rust/top/.rss")
+    );
+    assert_eq!(
+        feed("https://reddit.com/u/spez").as_deref(),
+        Some("https://www.reddit.com/user/spez/.rss")
+    );
+    assert_eq!(rewrite("https://www.reddit.com/r/rust/.rss"), None);
+    assert_eq!(rewrite("https://www.reddit.com/"), None);
+}
+
+#[test]
+fn youtube_urls_point_to_the_videos_feed() {
+    assert_eq!(
+        feed(&format!(
+            "https://www.youtube.com/channel/{}/videos",
+            CHANNEL
+        )),
+        Some(format!(
+            "https://www.youtube.com/feeds/videos.xml?channel_id={}",
+            CHANNEL
+        ))
+    );
+    assert_eq!(
+        feed("https://youtube.com/user/Google").as_deref(),
+        Some("https://www.youtube.com/feeds/videos.xml?user=Google")
+    );
+    assert_eq!(
+        feed("https://m.youtube.com/playlist?list=PL123").as_deref(),
+        Some("https://www.youtube.com/feeds/videos.xml?playlist_id=PL123")
+    );
+    // Handles need the channel page.
+    assert_eq!(
+        rewrite("https://www.youtube.com/@rustlang/videos"),
+        Some(UrlRewrite::YouTubeHandle(
+            Url::parse("https://www.youtube.com/@rustlang").unwrap()
+        ))
+    );
+    assert_eq!(rewrite("https://www.youtube.com/watch?v=abc"), None);
+    assert_eq!(
+        rewrite(&format!(
+            "https://www.youtube.com/feeds/videos.xml?channel_id={}",
+            CHANNEL
+        )),
+        None
+    );
+}
+
+#[test]
+fn github_repositories_offer_releases_commits_and_tags() {
+    let repo = rewrite("https://github.com/rust-lang/rust.git").unwrap();
+    assert_eq!(
+        repo,
+        UrlRewrite::GitHub {
+            owner: "rust-lang".into(),
+            repo: "rust".into()
+        }
+    );
+    let urls: Vec<String> = GitHubFeed::ALL
+        .iter()
+        .map(|kind| github_feed_url("rust-lang", "rust", *kind).to_string())
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://github.com/rust-lang/rust/releases.atom",
+            "https://github.com/rust-lang/rust/commits.atom",
+            "https://github.com/rust-lang/rust/tags.atom",
+        ]
+    );
+    assert!(rewrite("https://github.com/rust-lang/rust/issues/1").is_some());
+    assert_eq!(rewrite("https://github.com/rust-lang"), None);
+    assert_eq!(rewrite("https://github.com/settings/profile"), None);
+    assert_eq!(
+        rewrite("https://github.com/rust-lang/rust/releases.atom"),
+        None
+    );
+    assert_eq!(rewrite("https://example.org/r/rust"), None);
+}
+
+#[test]
+fn channel_id_is_read_from_the_page() {
+    let announced = format!(
+        r#"<link rel="alternate" type="application/rss+xml" title="RSS"
+href="https://www.youtube.com/feeds/videos.xml?channel_id={}">"#,
+        CHANNEL
+    );
+    assert_eq!(youtube_channel_id(&announced).as_deref(), Some(CHANNEL));
+    let canonical = format!(
+        r#"<link rel="canonical" href="https://www.youtube.com/channel/{}">"#,
+        CHANNEL
+    );
+    assert_eq!(youtube_channel_id(&canonical).as_deref(), Some(CHANNEL));
+    let json = format!(r#"{{"channelId":"short","externalId":"{}"}}"#, CHANNEL);
+    assert_eq!(youtube_channel_id(&json).as_deref(), Some(CHANNEL));
+    assert_eq!(youtube_channel_id("<html>nothing</html>"), None);
+}
+
+#[tokio::test]
+async fn handles_resolve_through_the_channel_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/@rustlang"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"<html><script>var data = {{"externalId":"{}"}};</script></html>"#,
+            CHANNEL
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/@empty"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+    let client = Client::new();
+    let timeout = Duration::from_secs(2);
+    let page = |handle: &str| {
+        UrlRewrite::YouTubeHandle(Url::parse(&format!("{}/{}", server.uri(), handle)).unwrap())
+    };
+
+    let url = resolve_rewrite(&client, &page("@rustlang"), GitHubFeed::Releases, timeout)
+        .await
+        .unwrap();
+    assert_eq!(
+        url.as_str(),
+        format!(
+            "https://www.youtube.com/feeds/videos.xml?channel_id={}",
+            CHANNEL
+        )
+    );
+    let missing = resolve_rewrite(&client, &page("@empty"), GitHubFeed::Releases, timeout).await;
+    assert!(matches!(
+        missing,
+        Err(PollError::NotAFeed {
+            looks_like_html: true,
+            ..
+        })
+    ));
+
+    let repo = rewrite("https://github.com/tokio-rs/tokio").unwrap();
+    let url = resolve_rewrite(&client, &repo, GitHubFeed::Tags, timeout)
+        .await
+        .unwrap();
+    assert_eq!(url.as_str(), "https://github.com/tokio-rs/tokio/tags.atom");
+}
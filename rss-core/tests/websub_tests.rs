@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use ring::hmac;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rss_core::{
+    Event, FeedDescriptor, FeedService, SubscriptionState, WebSub, WebSubConfig, WebSubLinks,
+};
+
+fn atom(hub: &str, topic: &str, ids: &[&str]) -> String {
+    let entries: String = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"<entry><id>{id}</id><title>Article {id}</title>
+                <link href="https://example.org/{id}"/>
+                <updated>2024-01-01T00:00:00Z</updated></entry>"#
+            )
+        })
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+          <title>Pushed</title><id>urn:pushed</id><updated>2024-01-01T00:00:00Z</updated>
+          <link rel="hub" href="{hub}"/>
+          <link rel="self" href="{topic}"/>
+          {entries}
+        </feed>"#
+    )
+}
+
+fn websub_config() -> WebSubConfig {
+    WebSubConfig {
+        enabled: true,
+        bind_address: "127.0.0.1:0".into(),
+        ..WebSubConfig::default()
+    }
+}
+
+// Waits until the hub has received `count` subscription requests and returns their form fields.
+async fn hub_requests(hub: &MockServer, count: usize) -> Vec<HashMap<String, String>> {
+    for _ in 0..100 {
+        let requests: Vec<_> = hub
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| r.url.path() == "/hub")
+            .collect();
+        if requests.len() >= count {
+            return requests
+                .iter()
+                .map(|r| url::form_urlencoded::parse(&r.body).into_owned().collect())
+                .collect();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("the hub did not receive {} subscription request(s)", count);
+}
+
+struct Setup {
+    server: MockServer,
+    service: FeedService,
+    websub: WebSub,
+    topic: String,
+    callback: String,
+    secret: String,
+}
+
+// A followed feed announcing `server`/hub, polled once so that it asks the hub to subscribe.
+async fn subscribed_feed() -> Setup {
+    let server = MockServer::start().await;
+    let hub = format!("{}/hub", server.uri());
+    let topic = format!("{}/feed.xml", server.uri());
+    Mock::given(method("GET"))
+        .and(path("/feed.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(atom(&hub, &topic, &["a"])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hub"))
+        .respond_with(ResponseTemplate::new(202))
+        .mount(&server)
+        .await;
+
+    let service = FeedService::in_memory();
+    service.start_websub(&websub_config()).await.unwrap();
+    let websub = service.websub().unwrap();
+    service
+        .add_feed(FeedDescriptor::new("f1", "Pushed", &topic))
+        .await
+        .unwrap();
+    service.refresh_feed("f1").await.unwrap();
+
+    let request = hub_requests(&server, 1).await.remove(0);
+    assert_eq!(request["hub.mode"], "subscribe");
+    assert_eq!(request["hub.topic"], topic);
+    assert!(request["hub.callback"].starts_with(&format!("http://{}/", websub.local_addr())));
+    Setup {
+        service,
+        websub,
+        topic,
+        callback: request["hub.callback"].clone(),
+        secret: request["hub.secret"].clone(),
+        server,
+    }
+}
+
+async fn verify(setup: &Setup, topic: &str, lease_seconds: u64) -> reqwest::Response {
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("hub.mode", "subscribe")
+        .append_pair("hub.topic", topic)
+        .append_pair("hub.challenge", "défi-123")
+        .append_pair("hub.lease_seconds", &lease_seconds.to_string())
+        .finish();
+    reqwest::get(format!("{}?{}", setup.callback, query))
+        .await
+        .unwrap()
+}
+
+#[test]
+fn links_need_both_a_hub_and_a_self_link() {
+    let links = WebSubLinks::from_links([
+        ("alternate", "https://example.org/"),
+        ("hub", "https://hub.example.org/"),
+        ("self", "https://example.org/feed.xml"),
+        ("hub", "https://second-hub.example.org/"),
+    ]);
+    assert_eq!(
+        links,
+        Some(WebSubLinks {
+            hub: "https://hub.example.org/".into(),
+            topic: "https://example.org/feed.xml".into(),
+        })
+    );
+    assert_eq!(
+        WebSubLinks::from_links([("hub", "https://hub.example.org/")]),
+        None
+    );
+}
+
+#[tokio::test]
+async fn hub_verification_echoes_the_challenge_for_the_right_topic_only() {
+    let setup = subscribed_feed().await;
+    assert_eq!(
+        setup.websub.subscriptions()[0].state,
+        SubscriptionState::Pending
+    );
+
+    let wrong = verify(&setup, "https://elsewhere.example.org/feed", 3600).await;
+    assert_eq!(wrong.status(), 404);
+
+    let response = verify(&setup, &setup.topic, 3600).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text().await.unwrap(), "défi-123");
+    let subscription = &setup.websub.subscriptions()[0];
+    assert_eq!(subscription.state, SubscriptionState::Active);
+    assert!(subscription.expires_at.is_some());
+
+    // Active subscriptions are polled once every `poll_interval_factor` cycles.
+    let factor = WebSubConfig::default().poll_interval_factor;
+    let polls = (0..factor * 2)
+        .filter(|_| setup.websub.should_poll("f1"))
+        .count();
+    assert_eq!(polls, 2);
+    assert!(setup.websub.should_poll("not-subscribed"));
+
+    setup.service.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn signed_pushes_become_articles_and_forged_ones_are_ignored() {
+    let setup = subscribed_feed().await;
+    assert_eq!(verify(&setup, &setup.topic, 3600).await.status(), 200);
+    let mut events = setup.service.subscribe_events();
+    let client = reqwest::Client::new();
+
+    // Wrong secret: acknowledged, but the content is dropped.
+    let forged = atom("", &setup.topic, &["a", "forged"]);
+    let key = hmac::Key::new(hmac::HMAC_SHA256, b"not-the-secret");
+    let signature = hmac::sign(&key, forged.as_bytes());
+    let response = client
+        .post(&setup.callback)
+        .header("Content-Type", "application/atom+xml")
+        .header(
+            "X-Hub-Signature",
+            format!("sha256={}", hex(signature.as_ref())),
+        )
+        .body(forged)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 202);
+
+    let pushed = atom("", &setup.topic, &["a", "b"]);
+    let key = hmac::Key::new(hmac::HMAC_SHA256, setup.secret.as_bytes());
+    let signature = hmac::sign(&key, pushed.as_bytes());
+    let response = client
+        .post(&setup.callback)
+        .header("Content-Type", "application/atom+xml")
+        .header(
+            "X-Hub-Signature",
+            format!("sha256={}", hex(signature.as_ref())),
+        )
+        .body(pushed)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 202);
+
+    let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    match event {
+        Event::NewArticles(feed_id, entries) => {
+            assert_eq!(feed_id, "f1");
+            let titles: Vec<_> = entries.iter().map(|e| e.title.as_str()).collect();
+            assert_eq!(titles, ["Article b"]);
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+
+    setup.service.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn subscriptions_are_renewed_before_the_lease_ends() {
+    let setup = subscribed_feed().await;
+    assert_eq!(verify(&setup, &setup.topic, 1).await.status(), 200);
+
+    let renewal = hub_requests(&setup.server, 2).await.remove(1);
+    assert_eq!(renewal["hub.mode"], "subscribe");
+    assert_eq!(renewal["hub.topic"], setup.topic);
+    assert_eq!(renewal["hub.callback"], setup.callback);
+
+    setup.service.shutdown().await.unwrap();
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+// ===
+//
+//
+// Recense les tables de traduction (locales/<code>.json) et génère la liste embarquée par
+// src/i18n.rs: ajouter une langue revient à déposer un fichier dans locales/.
+//
+//
+// ===
+fn main() {
+    let dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("locales");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut tables: Vec<(String, PathBuf)> = std::fs::read_dir(&dir)
+        .expect("rss-gui/locales is missing")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let code = path.file_stem()?.to_str()?.to_string();
+            Some((code, path))
+        })
+        .collect();
+    tables.sort();
+
+    let mut out = String::from("pub static LOCALES: &[(&str, &str)] = &[\n");
+    for (code, path) in &tables {
+        println!("cargo:rerun-if-changed={}", path.display());
+        out.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            code,
+            path.display().to_string()
+        ));
+    }
+    out.push_str("];\n");
+    let target = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("locales.rs");
+    std::fs::write(target, out).expect("failed to write locales.rs");
+}
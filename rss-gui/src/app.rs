@@ -1,175 +1,51 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, NaiveTime, Timelike, Utc};
 use eframe::egui::{self, Color32, Rounding, Stroke};
 use reqwest::Client;
 use rss_core::{
-    list_feeds, poll_once, AppConfig, DataApi, Event, FeedDescriptor, FeedEntry, PollConfig,
-    PollerHandle, SeenStore, SharedFeedList,
+    contrast_ratio, dedup_entries, feed_url_key, feeds_by_category, format_bytes, github_feed_url,
+    list_feeds, markdown_link, preview_feed, resolve_rewrite, rewrite_feed_url, share_mailto,
+    split_inline_images, throttled_for, title_and_link, AddFeedError, AllowHttp, AppConfig,
+    ArticleFilter, ChannelMeta, ConfigIssue, ContentBlock, CycleSummary, DataApi, DataError,
+    DateFormat, DedupedEntry, Event, ExportFormat, FeedAuth, FeedBackup, FeedDescriptor, FeedEntry,
+    FeedFormat, FeedPreview, FeedSecret, FeedService, FeedStats, FeedUpdate, FetchErrorKind,
+    FetchOutcome, FeverClient, FeverSync, FilterRule, GReaderClient, GReaderSync, GitHubFeed,
+    Layout, PollConfig, PollError, PreviewCache, ReadLaterService, ReadMarks, RetentionPolicy,
+    RuleAction, RuleError, RuleField, Secret, SessionState, SessionView, SharedFeedList, SortMode,
+    Stats, StatsRange, SubscriptionState, SyncMode, ThemePreset, TitleClusters, UrlRewrite,
+    WallabagClient, WallabagConfig, DEFAULT_TITLE_SIMILARITY, MAX_INLINE_IMAGES, STATS_TOP_FEEDS,
+    WCAG_AA_CONTRAST,
 };
 use tokio::runtime::Runtime;
-use tokio::sync::mpsc;
+use tokio::sync::broadcast;
 use url::Url;
 
-// ===
-//
-//
-// UI principale de l’application: structures de navigation, vues et interactions.
-//
-//
-// ===
-
-struct RecFeed {
-    title: &'static str,
-    url: &'static str,
-    desc: &'static str,
-}
-
-struct RecCategory {
-    name: &'static str,
-    feeds: &'static [RecFeed],
-}
+use crate::article_images::{ArticleImages, ImageSlot};
+use crate::article_list;
+use crate::discover::{catalog_search, query_as_url, recommended_categories, RecFeed};
+use crate::discover::{DISCOVER_LANGUAGES, TOP_FEEDS};
+use crate::i18n::{self, format_date, format_date_with, format_day, format_relative_date};
+use crate::i18n::{tr, tr_args, tr_count};
+use crate::log_capture::{LogBuffer, LogLine};
+use crate::open_path::open_path;
+use crate::richtext;
+use crate::task_cache::{TaskCache, TaskSlot};
+use crate::toasts::{ToastLevel, Toasts};
+use crate::tray::{Tray, TrayCommand};
+use crate::webview::{self, Viewer};
+use crate::window_geometry;
 
 // ===
 //
 //
-// Catégories/flux recommandés (affichés dans Discover).
+// UI principale de l’application: structures de navigation, vues et interactions.
 //
 //
 // ===
-fn recommended_categories() -> &'static [RecCategory] {
-    const TECH: &[RecFeed] = &[
-        RecFeed {
-            title: "Ars Technica",
-            url: "https://arstechnica.com/feed/",
-            desc: "Actualités et analyses high‑tech, science et société.",
-        },
-        RecFeed {
-            title: "TechCrunch",
-            url: "https://techcrunch.com/feed/",
-            desc: "Startups, produits et innovations du monde de la tech.",
-        },
-        RecFeed {
-            title: "The Register",
-            url: "https://www.theregister.com/headlines.atom",
-            desc: "IT, logiciels, matériel et industrie (ton décalé).",
-        },
-        RecFeed {
-            title: "Numerama",
-            url: "https://www.numerama.com/feed/",
-            desc: "Culture numérique, société, environnement et science (FR).",
-        },
-        RecFeed {
-            title: "Korben",
-            url: "https://korben.info/feed",
-            desc: "Veille tech, tips et découvertes (FR).",
-        },
-    ];
-
-    const DEV: &[RecFeed] = &[
-        RecFeed {
-            title: "Rust Blog",
-            url: "https://blog.rust-lang.org/feed.xml",
-            desc: "Annonces officielles du langage Rust.",
-        },
-        RecFeed {
-            title: "GitHub Blog",
-            url: "https://github.blog/feed/",
-            desc: "Actualités GitHub, produits et écosystème open‑source.",
-        },
-        RecFeed {
-            title: "Stack Overflow Blog",
-            url: "https://stackoverflow.blog/feed/",
-            desc: "Ingénierie, communauté et productivité.",
-        },
-        RecFeed {
-            title: "Real Python",
-            url: "https://realpython.com/atom.xml",
-            desc: "Tutoriels Python et bonnes pratiques.",
-        },
-        RecFeed {
-            title: "dev.to",
-            url: "https://dev.to/feed",
-            desc: "Articles communautaires sur le dev et les outils.",
-        },
-    ];
-
-    const SCIENCE: &[RecFeed] = &[
-        RecFeed {
-            title: "NASA News",
-            url: "https://www.nasa.gov/rss/dyn/breaking_news.rss",
-            desc: "Dernières nouvelles de la NASA.",
-        },
-        RecFeed {
-            title: "ScienceDaily (All)",
-            url: "https://www.sciencedaily.com/rss/all.xml",
-            desc: "Sélection d’articles de vulgarisation scientifique.",
-        },
-        RecFeed {
-            title: "Nature – Latest",
-            url: "https://www.nature.com/nature.rss",
-            desc: "Publications et actualités de la revue Nature.",
-        },
-        RecFeed {
-            title: "Quanta Magazine",
-            url: "https://api.quantamagazine.org/feed/",
-            desc: "Maths, physique, informatique et biologie théorique.",
-        },
-        RecFeed {
-            title: "MIT News",
-            url: "https://news.mit.edu/rss/topic/engineering",
-            desc: "Recherches et innovations du MIT (ingénierie).",
-        },
-    ];
-
-    const ACTU_FR: &[RecFeed] = &[
-        RecFeed {
-            title: "Le Monde – Une",
-            url: "https://www.lemonde.fr/rss/une.xml",
-            desc: "Sélection des principaux titres du Monde (FR).",
-        },
-        RecFeed {
-            title: "France 24",
-            url: "https://www.france24.com/fr/rss",
-            desc: "Info internationale en continu (FR).",
-        },
-        RecFeed {
-            title: "Le Figaro – International",
-            url: "https://www.lefigaro.fr/rss/figaro_international.xml",
-            desc: "Actualité internationale (FR).",
-        },
-        RecFeed {
-            title: "ZDNet France",
-            url: "https://www.zdnet.fr/feeds/rss/actualites/",
-            desc: "Technologies et entreprises (FR).",
-        },
-        RecFeed {
-            title: "01net",
-            url: "https://www.01net.com/feed/",
-            desc: "High-tech, tests et dossiers (FR).",
-        },
-    ];
-
-    const CATS: &[RecCategory] = &[
-        RecCategory {
-            name: "Technologie",
-            feeds: TECH,
-        },
-        RecCategory {
-            name: "Programmation",
-            feeds: DEV,
-        },
-        RecCategory {
-            name: "Science",
-            feeds: SCIENCE,
-        },
-        RecCategory {
-            name: "Actualités (FR)",
-            feeds: ACTU_FR,
-        },
-    ];
-    CATS
-}
 
 // ===
 //
@@ -199,15 +75,697 @@ fn color_for_feed(id: &str) -> Color32 {
     PALETTE[idx]
 }
 
+// ===
+//
+//
+// Libellé affiché pour un mode de tri de la liste d’articles.
+//
+//
+// ===
+// Fichier d’export/import de l’état proposé par défaut (dossier personnel).
+fn default_state_path() -> String {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join("readrss_export.json")
+        .display()
+        .to_string()
+}
+
+// ===
+// Sonde d’un flux recommandé (« Tester » dans Discover): temps de réponse, nombre d’articles
+// et titre le plus récent, ou type et message d’erreur.
+// ===
+struct FeedProbe {
+    latency: std::time::Duration,
+    item_count: usize,
+    latest_title: Option<String>,
+}
+
+type ProbeResult = Result<FeedProbe, (FetchErrorKind, String)>;
+
+// Type d’échec d’une récupération, en clair.
+fn error_kind_text(kind: FetchErrorKind) -> String {
+    tr(&format!("error_kind.{}", kind.label()))
+}
+
+// ===
+// Aperçu d’un flux demandé depuis le formulaire d’ajout (fenêtre « Aperçu du flux »).
+// ===
+enum FeedPreviewState {
+    Loading(String),
+    Ready(FeedPreview),
+    Failed(String),
+}
+
+// ===
+// Éditeur d’en-têtes d’un flux existant (fenêtre ouverte depuis le menu contextuel).
+// ===
+struct HeaderEditor {
+    feed: FeedDescriptor,
+    user_agent: String,
+    headers: Vec<(String, String)>,
+    // Identifiants Basic/Bearer du flux (feeds.json).
+    auth: AuthInput,
+    // Cookie de session du flux (secrets.json), saisi masqué; vide = aucun.
+    cookie: String,
+    error: Option<String>,
+}
+
+// ===
+// Éditeur du sélecteur CSS du contenu complet d’un flux (menu contextuel); vide = heuristique.
+// ===
+struct SelectorEditor {
+    feed: FeedDescriptor,
+    selector: String,
+    error: Option<String>,
+}
+
+// ===
+// Opération destructive annulable depuis son toast (« Annuler »).
+// ===
+enum UndoAction {
+    RemoveFeed(Box<FeedBackup>),
+    MarkRead(ReadMarks),
+}
+
+// ===
+// Ce dont dépend la liste affichée: la vue gardée est recalculée quand l’un d’eux change.
+// `today` compte pour les filtres « Aujourd’hui » et « Cette semaine »; les titres des flux,
+// pour le tri par flux seulement.
+// ===
+#[derive(PartialEq)]
+struct ArticleViewKey {
+    articles_revision: u64,
+    read_revision: usize,
+    selected_feed: Option<String>,
+    filter: ArticleFilter,
+    today: chrono::NaiveDate,
+    show_unread_only: bool,
+    sort_mode: SortMode,
+    group_similar_titles: bool,
+    feed_titles: Option<Vec<(String, String)>>,
+}
+
+// ===
+// Gestion des flux (vue dédiée): flux cochés et saisies des actions groupées.
+// ===
+#[derive(Default)]
+struct FeedManager {
+    selected: HashSet<String>,
+    category: String,
+    poll_interval: Option<u64>,
+    confirm_delete: bool,
+}
+
+// ===
+// Formulaire « Ajouter une newsletter » (boîte IMAP suivie comme un flux).
+// ===
+#[cfg(feature = "imap-feeds")]
+struct NewsletterForm {
+    title: String,
+    host: String,
+    port: String,
+    tls: bool,
+    username: String,
+    password: String,
+    folder: String,
+    from_filter: String,
+    mark_seen: bool,
+}
+
+#[cfg(feature = "imap-feeds")]
+impl Default for NewsletterForm {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            host: String::new(),
+            port: "993".to_string(),
+            tls: true,
+            username: String::new(),
+            password: String::new(),
+            folder: "INBOX".to_string(),
+            from_filter: String::new(),
+            mark_seen: false,
+        }
+    }
+}
+
+// Libellé d’un flux de dépôt GitHub (formulaire d’ajout).
+fn github_feed_label(kind: GitHubFeed) -> String {
+    match kind {
+        GitHubFeed::Releases => tr("add_feed.github_releases"),
+        GitHubFeed::Commits => tr("add_feed.github_commits"),
+        GitHubFeed::Tags => tr("add_feed.github_tags"),
+    }
+}
+
+// Nom d’un format de flux (aperçu, flux des favoris).
+fn feed_format_label(format: FeedFormat) -> &'static str {
+    match format {
+        FeedFormat::Rss => "RSS 2.0",
+        FeedFormat::Atom => "Atom",
+    }
+}
+
+// Copie éditable des en-têtes d’un flux (vides → None à l’enregistrement).
+fn headers_from_input(
+    user_agent: &str,
+    headers: &[(String, String)],
+) -> (Option<String>, Option<Vec<(String, String)>>) {
+    let user_agent = Some(user_agent.trim().to_string()).filter(|ua| !ua.is_empty());
+    let headers: Vec<(String, String)> = headers
+        .iter()
+        .filter(|(name, _)| !name.trim().is_empty())
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+    (user_agent, Some(headers).filter(|h| !h.is_empty()))
+}
+
+fn draw_headers_editor(
+    ui: &mut egui::Ui,
+    user_agent: &mut String,
+    headers: &mut Vec<(String, String)>,
+) {
+    // ===
+    // Champ User-Agent puis une ligne nom/valeur par en-tête, avec ajout et suppression.
+    // ===
+    ui.label(egui::RichText::new(tr("headers.user_agent")).meta());
+    ui.add(egui::TextEdit::singleline(user_agent).hint_text(rss_core::DEFAULT_USER_AGENT));
+    let mut remove = None;
+    for (i, (name, value)) in headers.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(name)
+                    .hint_text("X-Api-Key")
+                    .desired_width(100.0),
+            );
+            ui.add(egui::TextEdit::singleline(value).desired_width(120.0));
+            if ui
+                .small_button("✖")
+                .icon_hint(&tr("headers.remove"))
+                .clicked()
+            {
+                remove = Some(i);
+            }
+        });
+    }
+    if let Some(i) = remove {
+        headers.remove(i);
+    }
+    if ui.small_button(tr("headers.add")).clicked() {
+        headers.push((String::new(), String::new()));
+    }
+}
+
+fn draw_auth_editor(ui: &mut egui::Ui, id_source: &str, auth: &mut AuthInput, secondary: Color32) {
+    // ===
+    // Choix du type d’identification puis ses champs (secrets saisis masqués).
+    // ===
+    egui::ComboBox::from_id_source(id_source)
+        .selected_text(match auth.kind {
+            AuthKind::None => tr("add_feed.auth_none"),
+            AuthKind::Basic => "Basic".to_string(),
+            AuthKind::Bearer => "Bearer".to_string(),
+        })
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut auth.kind, AuthKind::None, tr("add_feed.auth_none"));
+            ui.selectable_value(&mut auth.kind, AuthKind::Basic, "Basic");
+            ui.selectable_value(&mut auth.kind, AuthKind::Bearer, "Bearer");
+        });
+    match auth.kind {
+        AuthKind::None => {}
+        AuthKind::Basic => {
+            let label = ui.label(egui::RichText::new(tr("add_feed.username")).meta());
+            ui.text_edit_singleline(&mut auth.username)
+                .labelled_by(label.id);
+            let label = ui.label(egui::RichText::new(tr("add_feed.password")).meta());
+            ui.add(egui::TextEdit::singleline(&mut auth.password).password(true))
+                .labelled_by(label.id);
+        }
+        AuthKind::Bearer => {
+            let label = ui.label(egui::RichText::new(tr("add_feed.token")).meta());
+            ui.add(egui::TextEdit::singleline(&mut auth.token).password(true))
+                .labelled_by(label.id);
+        }
+    }
+    if auth.kind != AuthKind::None {
+        ui.label(
+            egui::RichText::new(tr("add_feed.plaintext_notice"))
+                .color(secondary)
+                .small(),
+        );
+    }
+}
+
+fn default_export_dir() -> String {
+    dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_default()
+        .join("ReadRSS")
+        .display()
+        .to_string()
+}
+
+fn export_format_label(format: ExportFormat) -> &'static str {
+    match format {
+        ExportFormat::Markdown => "Markdown (.md)",
+        ExportFormat::Html => "HTML (.html)",
+    }
+}
+
+// Choix de conservation proposés dans le menu contextuel d’un flux.
+fn retention_choices() -> [(Option<RetentionPolicy>, String); 6] {
+    use RetentionPolicy::{KeepAll, MaxAge, MaxCount};
+    const DAY: std::time::Duration = std::time::Duration::from_secs(24 * 3600);
+    [
+        (None, tr("retention.global")),
+        (Some(MaxCount(50)), tr("retention.last_50")),
+        (Some(MaxCount(100)), tr("retention.last_100")),
+        (Some(MaxAge(DAY * 7)), tr("retention.last_7_days")),
+        (Some(MaxAge(DAY * 30)), tr("retention.last_30_days")),
+        (Some(KeepAll), tr("retention.keep_all")),
+    ]
+}
+
+// Éclaircit (delta > 0) ou assombrit (delta < 0) une couleur opaque.
+fn shade(color: Color32, delta: i16) -> Color32 {
+    let adjust = |c: u8| (c as i16 + delta).clamp(0, 255) as u8;
+    Color32::from_rgb(adjust(color.r()), adjust(color.g()), adjust(color.b()))
+}
+
+// Texte lisible sur un fond donné (accent jaune du thème contraste élevé, par exemple).
+fn contrasting_text(background: Color32) -> Color32 {
+    let background = [background.r(), background.g(), background.b()];
+    if contrast_ratio(background, [255; 3]) >= contrast_ratio(background, [0; 3]) {
+        Color32::WHITE
+    } else {
+        Color32::BLACK
+    }
+}
+
+fn theme_preset_label(preset: ThemePreset) -> String {
+    tr(match preset {
+        ThemePreset::DarkVsCode => "theme.dark",
+        ThemePreset::Light => "theme.light",
+        ThemePreset::Solarized => "theme.solarized",
+        ThemePreset::HighContrast => "theme.high_contrast",
+        ThemePreset::Custom => "theme.custom",
+    })
+}
+
+// ===
+// Ligne « libellé + sélecteur de couleur »; true si la couleur a été modifiée.
+// ===
+fn color_row(ui: &mut egui::Ui, label: &str, color: &mut [u8; 3]) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(label);
+        let mut rgb = color.map(|c| c as f32 / 255.0);
+        if ui.color_edit_button_rgb(&mut rgb).changed() {
+            *color = rgb.map(|c| (c * 255.0).round() as u8);
+            changed = true;
+        }
+    });
+    changed
+}
+
+// ===
+// Styles de texte nommés, complétant `small()` et `heading()`. Les tailles viennent de
+// `UiConfig.font_size` via apply_theme (TEXT_STYLES), si bien que le réglage s’applique partout.
+// ===
+trait RichTextStyles {
+    fn meta(self) -> Self;
+    fn body(self) -> Self;
+    fn reading(self) -> Self;
+    fn title(self) -> Self;
+    fn headline(self) -> Self;
+}
+
+impl RichTextStyles for egui::RichText {
+    fn meta(self) -> Self {
+        self.text_style(egui::TextStyle::Name("meta".into()))
+    }
+
+    fn body(self) -> Self {
+        self.text_style(egui::TextStyle::Body)
+    }
+
+    fn reading(self) -> Self {
+        self.text_style(egui::TextStyle::Name("reading".into()))
+    }
+
+    fn title(self) -> Self {
+        self.text_style(egui::TextStyle::Name("title".into()))
+    }
+
+    fn headline(self) -> Self {
+        self.text_style(egui::TextStyle::Name("headline".into()))
+    }
+}
+
+// ===
+//
+//
+// Noms accessibles des widgets, exposés aux lecteurs d’écran par AccessKit (`widget_info`
+// remplace le libellé visible). Un bouton réduit à une icône passe par `icon_hint`: la même
+// phrase sert d’infobulle et de nom lu à voix haute.
+//
+//
+// ===
+trait Accessible {
+    fn accessible(self, typ: egui::WidgetType, label: &str) -> Self;
+    fn accessible_selected(self, selected: bool, label: &str) -> Self;
+    fn icon_hint(self, label: &str) -> Self;
+}
+
+impl Accessible for egui::Response {
+    fn accessible(self, typ: egui::WidgetType, label: &str) -> Self {
+        self.widget_info(|| egui::WidgetInfo::labeled(typ, label));
+        self
+    }
+
+    fn accessible_selected(self, selected: bool, label: &str) -> Self {
+        self.widget_info(|| {
+            egui::WidgetInfo::selected(egui::WidgetType::SelectableLabel, selected, label)
+        });
+        self
+    }
+
+    fn icon_hint(self, label: &str) -> Self {
+        self.accessible(egui::WidgetType::Button, label)
+            .on_hover_text(label)
+    }
+}
+
+// Nom d’une ligne de la liste des flux pour les lecteurs d’écran (le 🔕 et le grisé sont muets).
+fn feed_row_label(feed: &FeedDescriptor, muted: bool) -> String {
+    let title: &dyn std::fmt::Display = &feed.title;
+    if feed.disabled {
+        tr_args("feed.accessible_disabled", &[("title", title)])
+    } else if muted {
+        tr_args("feed.accessible_muted", &[("title", title)])
+    } else {
+        feed.title.clone()
+    }
+}
+
+// Taille de police de référence: `font_size` à cette valeur garde les tailles ci-dessous.
+const BASE_FONT_SIZE: f32 = 14.0;
+
+// Tailles de base des styles nommés (métadonnées, corps d’article, titres, titre d’article).
+const TEXT_STYLES: [(&str, f32); 4] = [
+    ("meta", 13.0),
+    ("reading", 15.0),
+    ("title", 16.0),
+    ("headline", 22.0),
+];
+
+// Nombre maximal de lignes d’aperçu dans une carte de la liste d’articles.
+const PREVIEW_ROWS: usize = 3;
+
+// Durées proposées par le menu « Sourdine » d’un flux (clé du libellé, heures).
+const MUTE_CHOICES: [(&str, i64); 3] = [
+    ("feed.mute_1h", 1),
+    ("feed.mute_24h", 24),
+    ("feed.mute_1w", 24 * 7),
+];
+
+// Opérations annulables gardées en mémoire (les plus anciennes partent d’abord).
+const UNDO_DEPTH: usize = 5;
+
+// Intervalles proposés par la gestion des flux, en minutes (None = intervalle global).
+const POLL_INTERVAL_CHOICES: [Option<u64>; 7] = [
+    None,
+    Some(15),
+    Some(30),
+    Some(60),
+    Some(180),
+    Some(360),
+    Some(1440),
+];
+
+// Nombre de tags affichés dans la vue « Tags » (les plus fréquents).
+const MAX_TAGS_SHOWN: usize = 100;
+
+// Délai maximal des écritures de fermeture: un disque bloqué n’empêche pas de quitter.
+const SHUTDOWN_FLUSH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+// Délai sans nouveau changement avant d’enregistrer la session (défilement, vue, filtre).
+const SESSION_SAVE_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+
+// Largeurs minimales (points) des colonnes liste et lecture en mode côte à côte.
+const SPLIT_MIN_LIST_WIDTH: f32 = 280.0;
+const SPLIT_MIN_READING_WIDTH: f32 = 320.0;
+
+fn sort_mode_label(mode: SortMode) -> String {
+    tr(match mode {
+        SortMode::NewestFirst => "sort.newest",
+        SortMode::OldestFirst => "sort.oldest",
+        SortMode::ByFeed => "sort.by_feed",
+        SortMode::UnreadFirst => "sort.unread",
+    })
+}
+
+fn rule_field_label(field: RuleField) -> String {
+    tr(match field {
+        RuleField::Title => "rules.field_title",
+        RuleField::Summary => "rules.field_summary",
+        RuleField::Author => "rules.field_author",
+    })
+}
+
+fn rule_action_label(action: RuleAction) -> String {
+    tr(match action {
+        RuleAction::Hide => "rules.action_hide",
+        RuleAction::MarkRead => "rules.action_mark_read",
+        RuleAction::Highlight => "rules.action_highlight",
+    })
+}
+
+// Période de la vue Statistiques, en jours.
+const STATS_DAYS: u32 = 30;
+
+// Nombre d’articles récents parcourus par « Tester » dans l’éditeur de règles.
+const RULE_TEST_ARTICLES: usize = 200;
+
+// Durée d’abonnement WebSub proposée dans les paramètres, en jours.
+const WEBSUB_LEASE_DAYS: std::ops::RangeInclusive<u64> = 1..=30;
+
+// ===
+// Message d’une règle refusée (« règle 2: expression régulière invalide: ... »).
+// ===
+fn rule_error_message(err: &RuleError) -> String {
+    match err {
+        RuleError::EmptyPattern => tr("rules.error_empty"),
+        RuleError::InvalidRegex(reason) => tr_args("rules.error_regex", &[("reason", reason)]),
+        RuleError::InvalidRule { index, source } => tr_args(
+            "rules.error_rule",
+            &[
+                ("index", &(index + 1)),
+                ("error", &rule_error_message(source)),
+            ],
+        ),
+        RuleError::Data(e) => data_error_message(e),
+    }
+}
+
+fn allow_http_label(policy: AllowHttp) -> String {
+    tr(match policy {
+        AllowHttp::Never => "http.never",
+        AllowHttp::LoopbackOnly => "http.loopback",
+        AllowHttp::PrivateNetworks => "http.private",
+        AllowHttp::Always => "http.always",
+    })
+}
+
+// ===
+// Choix d’une heure (heures et minutes, par pas de 5 min); true si elle a changé.
+// ===
+fn time_picker(ui: &mut egui::Ui, time: &mut NaiveTime) -> bool {
+    let mut hour = time.hour();
+    let mut minute = time.minute();
+    let mut changed = ui
+        .add(
+            egui::DragValue::new(&mut hour)
+                .clamp_range(0..=23)
+                .custom_formatter(|n, _| format!("{:02}", n as u32)),
+        )
+        .changed();
+    ui.label(":");
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut minute)
+                .clamp_range(0..=55)
+                .speed(0.2)
+                .custom_formatter(|n, _| format!("{:02}", n as u32)),
+        )
+        .changed();
+    if changed {
+        let minute = minute / 5 * 5;
+        if let Some(picked) = NaiveTime::from_hms_opt(hour, minute, 0) {
+            *time = picked;
+        }
+    }
+    changed
+}
+
+// Intervalle de relevé lisible (« 30 min », « 2 h », « 1 h 30 »).
+fn interval_label(interval: std::time::Duration) -> String {
+    let minutes = interval.as_secs() / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{} min", m),
+        (h, 0) => format!("{} h", h),
+        (h, m) => format!("{} h {:02}", h, m),
+    }
+}
+
+// Transmet à l’UI les marques d’un marquage groupé fait en tâche de fond (rien si aucune).
+fn send_mark_read_undo(undo: &std::sync::mpsc::Sender<(String, UndoAction)>, marks: ReadMarks) {
+    if marks.is_empty() {
+        return;
+    }
+    let message = tr_count("toast.marked_read", marks.len() as i64, &[]);
+    let _ = undo.send((message, UndoAction::MarkRead(marks)));
+}
+
+// Libellé d’un choix de POLL_INTERVAL_CHOICES (minutes; None = intervalle global).
+fn poll_interval_choice_label(minutes: Option<u64>) -> String {
+    match minutes {
+        Some(minutes) => interval_label(std::time::Duration::from_secs(minutes * 60)),
+        None => tr("manager.interval_global"),
+    }
+}
+
+// Libellé d’un niveau de journal (liste « niveau minimal » de la vue Journal).
+fn log_level_label(level: tracing::Level) -> String {
+    tr(match level {
+        tracing::Level::ERROR => "log.errors",
+        tracing::Level::WARN => "log.warnings",
+        tracing::Level::INFO => "log.info",
+        tracing::Level::DEBUG => "log.debug",
+        tracing::Level::TRACE => "log.trace",
+    })
+}
+
+fn log_level_color(level: tracing::Level, default: egui::Color32) -> egui::Color32 {
+    match level {
+        tracing::Level::ERROR => egui::Color32::from_rgb(229, 57, 53),
+        tracing::Level::WARN => egui::Color32::from_rgb(251, 140, 0),
+        _ => default,
+    }
+}
+
+// ===
+// Message du toast affiché quand config.json n’a pas pu être lu en entier (avec la copie
+// de l’ancien fichier s’il a été réécrit).
+// ===
+fn config_issue_message(issue: &ConfigIssue) -> String {
+    let message = match issue {
+        ConfigIssue::Unreadable { error, .. } => tr_args("config.unreadable", &[("error", error)]),
+        ConfigIssue::Ignored { fields, .. } => {
+            tr_args("config.ignored", &[("fields", &fields.join(", "))])
+        }
+    };
+    match issue.backup() {
+        Some(backup) => tr_args(
+            "config.backup",
+            &[("message", &message), ("path", &backup.display())],
+        ),
+        None => message,
+    }
+}
+
+// ===
+// Message d’un échec d’enregistrement, par fichier (« Impossible d'enregistrer les flux:
+// permission refusée »).
+// ===
+fn data_error_message(err: &DataError) -> String {
+    let file = err.path().file_name().and_then(|name| name.to_str());
+    let what = tr(match file {
+        Some("feeds.json") => "data.feeds",
+        Some("read_store.json") => "data.read_state",
+        Some("articles_store.json") => "data.articles",
+        Some("feed_stats.json") => "data.feed_health",
+        Some("reading_positions.json") => "data.reading_positions",
+        Some("rules.json") => "data.rules",
+        Some("session.json") => "data.session",
+        _ => "data.other",
+    });
+    let reason = match err {
+        DataError::Io { source, .. } => match source.kind() {
+            std::io::ErrorKind::PermissionDenied => tr("data.permission_denied"),
+            std::io::ErrorKind::StorageFull => tr("data.disk_full"),
+            std::io::ErrorKind::ReadOnlyFilesystem => tr("data.read_only"),
+            std::io::ErrorKind::NotFound => tr("data.dir_not_found"),
+            _ => source.to_string(),
+        },
+        DataError::Serde { .. } => tr("data.not_serializable"),
+    };
+    tr_args("data.save_failed", &[("what", &what), ("reason", &reason)])
+}
+
 pub struct AppInit {
     pub runtime: Arc<Runtime>,
-    pub feeds: SharedFeedList,
-    pub poller: PollerHandle,
-    pub updates: mpsc::Receiver<Event>,
-    pub data_api: Arc<DataApi>,
-    pub client: Client,
-    pub poll_config: PollConfig,
-    pub seen_store: SeenStore,
+    pub service: Arc<FeedService>,
+    pub config: AppConfig,
+    // Incident au chargement de config.json, affiché en toast au démarrage.
+    pub config_issue: Option<ConfigIssue>,
+    // Tampon de la couche tracing installée par init_tracing (vue « Journal »).
+    pub logs: LogBuffer,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum AuthKind {
+    #[default]
+    None,
+    Basic,
+    Bearer,
+}
+
+// ===
+// Identifiants saisis pour un flux (formulaire d’ajout, éditeur d’en-têtes).
+// ===
+#[derive(Default)]
+struct AuthInput {
+    kind: AuthKind,
+    username: String,
+    password: String,
+    token: String,
+}
+
+impl AuthInput {
+    // Champs préremplis depuis les identifiants enregistrés d’un flux.
+    fn from_auth(auth: Option<&FeedAuth>) -> Self {
+        match auth {
+            None => Self::default(),
+            Some(FeedAuth::Basic { username, password }) => Self {
+                kind: AuthKind::Basic,
+                username: username.clone(),
+                password: password.reveal().unwrap_or_default(),
+                ..Self::default()
+            },
+            Some(FeedAuth::Bearer { token }) => Self {
+                kind: AuthKind::Bearer,
+                token: token.reveal().unwrap_or_default(),
+                ..Self::default()
+            },
+        }
+    }
+
+    // Identifiants saisis (None si non renseignés).
+    fn to_auth(&self) -> Option<FeedAuth> {
+        match self.kind {
+            AuthKind::None => None,
+            AuthKind::Basic if !self.username.trim().is_empty() => Some(FeedAuth::Basic {
+                username: self.username.trim().to_string(),
+                password: Secret::Plaintext(self.password.clone()),
+            }),
+            AuthKind::Bearer if !self.token.trim().is_empty() => Some(FeedAuth::Bearer {
+                token: Secret::Plaintext(self.token.trim().to_string()),
+            }),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -217,6 +775,11 @@ enum AppView {
     DiscoverHome,
     DiscoverCategory(String),
     Settings,
+    FeedHealth,
+    Tags,
+    Logs,
+    Stats,
+    FeedManager,
 }
 
 // ===
@@ -228,25 +791,119 @@ enum AppView {
 // ===
 pub struct RssApp {
     runtime: Arc<Runtime>,
+    // Flux, données, poller et évènements (persistés par le service avant d’arriver ici).
+    service: Arc<FeedService>,
     feeds: SharedFeedList,
-    poller: Option<PollerHandle>,
-    updates: mpsc::Receiver<Event>,
+    updates: broadcast::Receiver<Event>,
+    // Récupération de tous les flux (démarrage ou ⟳), tant qu’elle n’est pas terminée.
+    refresh_task: Option<tokio::task::JoinHandle<()>>,
     data_api: Arc<DataApi>,
     client: Client,
     poll_config: PollConfig,
-    seen_store: SeenStore,
     config: AppConfig,
     articles: Vec<FeedEntry>,
     new_feed_title: String,
     new_feed_url: String,
+    new_feed_auth: AuthInput,
+    new_feed_user_agent: String,
+    new_feed_headers: Vec<(String, String)>,
+    new_feed_full_content: bool,
+    new_feed_allow_insecure: bool,
+    // Flux choisi quand l’URL saisie est celle d’un dépôt GitHub.
+    new_feed_github_kind: GitHubFeed,
+    #[cfg(feature = "imap-feeds")]
+    newsletter_form: NewsletterForm,
+    // Recherche dans le catalogue Discover.
+    discover_query: String,
+    // Sondes « Tester » des flux recommandés, par URL, gardées pour la session.
+    discover_probes: TaskCache<String, ProbeResult>,
+    // Révision des favoris déjà écrite dans le flux des favoris (None: pas encore exporté).
+    starred_feed_revision: Option<usize>,
+    header_editor: Option<HeaderEditor>,
+    selector_editor: Option<SelectorEditor>,
+    feed_manager: FeedManager,
+    // Groupes de titres presque identiques (« Regrouper les doublons »), tenus à jour à
+    // l’arrivée des articles.
+    title_clusters: TitleClusters,
+    // Incrémenté à chaque changement de `articles` ou des groupes de titres.
+    articles_revision: u64,
+    article_view: article_list::ViewCache<ArticleViewKey, Vec<DedupedEntry>>,
+    // Position de la fenêtre vérifiée contre l’écran (première frame où il est connu).
+    window_position_checked: bool,
+    // Flux déjà suivi (id, titre) signalé sous le formulaire d’ajout, avec un lien vers lui.
+    duplicate_feed: Option<(String, String)>,
+    // Aperçu avant abonnement; le résultat arrive par canal avec l’URL demandée.
+    feed_preview: Option<FeedPreviewState>,
+    feed_preview_tx: std::sync::mpsc::Sender<(String, Result<FeedPreview, String>)>,
+    feed_preview_rx: std::sync::mpsc::Receiver<(String, Result<FeedPreview, String>)>,
     selected_feed: Option<String>,
     current_view: AppView,
     feed_search: String,
-    add_feedback: Option<(bool, String)>,
     show_unread_only: bool,
-    
-    discover_feedback: Option<(bool, String)>,
+    // Filtres rapides (barre au-dessus de la liste), non persistés.
+    article_filter: ArticleFilter,
+    article_pages: usize,
+    previews: PreviewCache,
+    sort_mode: SortMode,
+    polling_paused: bool,
+    poll_config_changed_at: Option<std::time::Instant>,
+    network_error: Option<String>,
+    websub_error: Option<String>,
+    // Bilan du dernier cycle de polling et son heure de fin (barre d’état).
+    last_cycle: Option<(CycleSummary, DateTime<Utc>)>,
+    // Aucun serveur joignable (Event::ConnectivityChanged): bandeau « hors ligne ».
+    offline: bool,
+
     focus_search_next: bool,
+    // Icônes des flux chargées à la demande (None: pas d’icône, pastille colorée à la place).
+    feed_icons: HashMap<String, Option<egui::TextureHandle>>,
+    icons_tx: std::sync::mpsc::Sender<String>,
+    icons_rx: std::sync::mpsc::Receiver<String>,
+    // Pile d’annulation (identifiant du toast, opération); les marquages faits en tâche de
+    // fond y arrivent par `undo_rx` avec le message de leur toast.
+    undo_stack: std::collections::VecDeque<(u64, UndoAction)>,
+    next_undo_id: u64,
+    undo_tx: std::sync::mpsc::Sender<(String, UndoAction)>,
+    undo_rx: std::sync::mpsc::Receiver<(String, UndoAction)>,
+    state_path: String,
+    read_later: Option<WallabagClient>,
+    viewer: Viewer,
+    toasts: Toasts,
+    export_dir: String,
+    // Dernier fichier de données exporté (bouton « Afficher l'export »).
+    last_export: Option<PathBuf>,
+    // Icône de notification (si `UiConfig.minimize_to_tray`); une seule tentative par activation.
+    tray: Option<Tray>,
+    tray_attempted: bool,
+    unread_checked_at: Option<std::time::Instant>,
+    // « Quitter » depuis l’icône: la prochaine fermeture n’est plus interceptée.
+    quitting: bool,
+    // Article affiché en détail et son défilement courant (enregistré en quittant la vue).
+    reading: Option<(FeedEntry, f32)>,
+    // Défilement à restaurer à la prochaine image de la vue détaillée.
+    pending_scroll: Option<f32>,
+    // Dernière session enregistrée et changement en attente (enregistré après SESSION_SAVE_DELAY).
+    session_saved: SessionState,
+    session_pending: Option<(SessionState, std::time::Instant)>,
+    // Position, dans la liste affichée, de l’article sélectionné (clavier, mode côte à côte).
+    selected_article_index: Option<usize>,
+    // Défilement courant de la liste d’articles et défilement à restaurer à la prochaine image.
+    list_scroll: f32,
+    pending_list_scroll: Option<f32>,
+    // Images des articles (textures par URL) et choix « Charger les images » par article
+    // (identité), prioritaire sur `UiConfig.load_images`; non persisté.
+    article_images: ArticleImages,
+    image_overrides: HashMap<String, bool>,
+    // Règles de filtrage en cours d’édition (Paramètres) et résultat du dernier « Tester »
+    // (index de la règle, message).
+    rules_draft: Vec<FilterRule>,
+    rule_test: Option<(usize, String)>,
+    // Vue « Statistiques »: calculée à l’ouverture (ou sur « Actualiser »), pas à chaque frame.
+    stats: Option<Stats>,
+    // Vue « Journal »: derniers évènements tracing, niveau minimal et recherche.
+    logs: LogBuffer,
+    log_level: tracing::Level,
+    log_search: String,
 }
 
 impl RssApp {
@@ -258,51 +915,223 @@ impl RssApp {
     //
     // ===
     pub fn new(init: AppInit) -> Self {
+        let (icons_tx, icons_rx) = std::sync::mpsc::channel();
+        let (undo_tx, undo_rx) = std::sync::mpsc::channel();
+        let (feed_preview_tx, feed_preview_rx) = std::sync::mpsc::channel();
+        let config = init.config;
+        let sort_mode = config.ui.sort_mode;
+        let viewer = Viewer::new(&config.network);
+        let service = init.service;
         let mut app = Self {
             runtime: init.runtime,
-            feeds: init.feeds,
-            poller: Some(init.poller),
-            updates: init.updates,
-            data_api: init.data_api,
-            client: init.client,
-            poll_config: init.poll_config,
-            seen_store: init.seen_store,
-            config: AppConfig::load(),
+            feeds: service.feeds().clone(),
+            updates: service.subscribe_events(),
+            refresh_task: None,
+            data_api: service.data_api().clone(),
+            client: service.client(),
+            poll_config: service.poll_config(),
+            network_error: service.network_error().map(str::to_string),
+            websub_error: service.websub_error().map(str::to_string),
+            last_cycle: None,
+            offline: !service.is_online(),
+            service,
+            config,
             articles: Vec::new(),
             new_feed_title: String::new(),
             new_feed_url: String::new(),
+            new_feed_auth: AuthInput::default(),
+            new_feed_user_agent: String::new(),
+            new_feed_headers: Vec::new(),
+            new_feed_full_content: false,
+            new_feed_allow_insecure: false,
+            new_feed_github_kind: GitHubFeed::default(),
+            #[cfg(feature = "imap-feeds")]
+            newsletter_form: NewsletterForm::default(),
+            discover_query: String::new(),
+            discover_probes: TaskCache::new(),
+            starred_feed_revision: None,
+            header_editor: None,
+            selector_editor: None,
+            feed_manager: FeedManager::default(),
+            title_clusters: TitleClusters::new(DEFAULT_TITLE_SIMILARITY),
+            articles_revision: 0,
+            article_view: Default::default(),
+            window_position_checked: false,
+            duplicate_feed: None,
+            feed_preview: None,
+            feed_preview_tx,
+            feed_preview_rx,
             selected_feed: None,
             current_view: AppView::ArticleList,
+            selected_article_index: None,
             feed_search: String::new(),
-            add_feedback: None,
             show_unread_only: false,
-            discover_feedback: None,
+            article_filter: ArticleFilter::default(),
+            article_pages: 1,
+            previews: PreviewCache::new(),
+            sort_mode,
+            polling_paused: false,
+            poll_config_changed_at: None,
             focus_search_next: false,
+            feed_icons: HashMap::new(),
+            icons_tx,
+            icons_rx,
+            undo_stack: std::collections::VecDeque::new(),
+            next_undo_id: 0,
+            undo_tx,
+            undo_rx,
+            state_path: default_state_path(),
+            read_later: None,
+            viewer,
+            toasts: Toasts::new(),
+            export_dir: default_export_dir(),
+            last_export: None,
+            tray: None,
+            tray_attempted: false,
+            unread_checked_at: None,
+            quitting: false,
+            reading: None,
+            pending_scroll: None,
+            session_saved: SessionState::default(),
+            session_pending: None,
+            list_scroll: 0.0,
+            pending_list_scroll: None,
+            article_images: ArticleImages::new(),
+            image_overrides: HashMap::new(),
+            rules_draft: Vec::new(),
+            rule_test: None,
+            stats: None,
+            logs: init.logs,
+            log_level: tracing::Level::INFO,
+            log_search: String::new(),
         };
+        app.data_api
+            .set_max_articles_per_feed(app.config.feeds.max_articles_per_feed);
+        app.data_api
+            .set_max_title_chars(app.config.feeds.max_title_chars);
         let persisted = app.runtime.block_on(app.data_api.list_all_articles());
         if !persisted.is_empty() {
             app.articles = persisted;
         }
+        app.rules_draft = app.runtime.block_on(app.data_api.rules());
+
+        if let Some(issue) = &init.config_issue {
+            app.push_toast(ToastLevel::Warning, config_issue_message(issue));
+        }
+        if let Err(e) = app.apply_sync_mode() {
+            app.push_toast(ToastLevel::Error, e);
+        }
+        if let Err(e) = app.apply_read_later_config() {
+            app.push_toast(ToastLevel::Error, e);
+        }
 
+        // La fenêtre s’affiche tout de suite avec les articles persistés; la première passe
+        // tourne en tâche de fond. En mode Fever, c’est le serveur qui récupère les flux.
         let feeds = app.runtime.block_on(list_feeds(&app.feeds));
-        if !feeds.is_empty() {
-            let events = app.runtime.block_on(async {
-                poll_once(&feeds, &app.poll_config, &app.client, &app.seen_store).await
-            });
-            for evt in events {
-                let Event::NewArticles(_, mut entries) = evt;
-                app.articles.append(&mut entries);
-            }
-            app.articles
-                .sort_by(|a, b| b.published_at.cmp(&a.published_at));
-            app.articles
-                .truncate(app.config.ui.articles_per_page.max(1));
+        app.sort_articles();
+        app.restore_session(&feeds);
+        app.refresh_icons_async(feeds.iter().map(|f| f.id.clone()).collect());
+        if !feeds.is_empty() && app.config.sync == SyncMode::Local {
+            app.refresh_task = Some(app.spawn_refresh());
         }
 
         app
     }
 
-    fn draw_discover_home(&mut self, ui: &mut egui::Ui) {
+    fn restore_session(&mut self, feeds: &[FeedDescriptor]) {
+        // ===
+        //
+        //
+        // Reprend la session enregistrée: flux (s’il est toujours suivi), filtre « Non lus »,
+        // défilement de la liste et vue ouverte. Un article qui n’est plus dans le stockage
+        // ramène à la liste.
+        //
+        //
+        // ===
+        let session = self.runtime.block_on(self.data_api.load_session());
+        self.selected_feed = session
+            .selected_feed
+            .clone()
+            .filter(|id| feeds.iter().any(|f| &f.id == id));
+        self.show_unread_only = session.unread_only;
+        if session.list_scroll > 0.0 {
+            self.pending_list_scroll = Some(session.list_scroll);
+        }
+        self.list_scroll = session.list_scroll;
+        self.current_view = match &session.view {
+            SessionView::ArticleList | SessionView::Article { .. } => AppView::ArticleList,
+            SessionView::DiscoverHome => AppView::DiscoverHome,
+            SessionView::DiscoverCategory { name } => AppView::DiscoverCategory(name.clone()),
+            SessionView::Settings => AppView::Settings,
+            SessionView::FeedHealth => AppView::FeedHealth,
+            SessionView::Tags => AppView::Tags,
+            SessionView::Logs => AppView::Logs,
+            SessionView::Stats => AppView::Stats,
+            SessionView::FeedManager => AppView::FeedManager,
+        };
+        if let Some(article) = session.article(&self.articles).cloned() {
+            self.open_article(&article);
+        }
+        self.session_saved = session;
+    }
+
+    fn session_snapshot(&self) -> SessionState {
+        // ===
+        // Session courante, telle qu’elle serait restaurée au prochain démarrage.
+        // ===
+        let view = match &self.current_view {
+            AppView::ArticleList => SessionView::ArticleList,
+            AppView::ArticleDetail(article) => SessionView::Article {
+                identity: article.identity(),
+            },
+            AppView::DiscoverHome => SessionView::DiscoverHome,
+            AppView::DiscoverCategory(name) => SessionView::DiscoverCategory { name: name.clone() },
+            AppView::Settings => SessionView::Settings,
+            AppView::FeedHealth => SessionView::FeedHealth,
+            AppView::Tags => SessionView::Tags,
+            AppView::Logs => SessionView::Logs,
+            AppView::Stats => SessionView::Stats,
+            AppView::FeedManager => SessionView::FeedManager,
+        };
+        SessionState {
+            selected_feed: self.selected_feed.clone(),
+            view,
+            unread_only: self.show_unread_only,
+            list_scroll: self.list_scroll,
+        }
+    }
+
+    fn save_session_debounced(&mut self) {
+        // ===
+        // Enregistre la session une fois stable depuis SESSION_SAVE_DELAY (pas à chaque image
+        // pendant un défilement).
+        // ===
+        let current = self.session_snapshot();
+        if current == self.session_saved {
+            self.session_pending = None;
+            return;
+        }
+        match &self.session_pending {
+            Some((pending, since)) if *pending == current => {
+                if since.elapsed() < SESSION_SAVE_DELAY {
+                    return;
+                }
+            }
+            _ => {
+                self.session_pending = Some((current, std::time::Instant::now()));
+                return;
+            }
+        }
+        self.session_pending = None;
+        if self
+            .saved(self.runtime.block_on(self.data_api.save_session(&current)))
+            .is_some()
+        {
+            self.session_saved = current;
+        }
+    }
+
+    fn draw_discover_home(&mut self, ui: &mut egui::Ui) {
         // ===
         //
         //
@@ -310,23 +1139,37 @@ impl RssApp {
         //
         //
         // ===
+        let secondary = self.config.theme.secondary_text_color32();
         ui.horizontal(|ui| {
-            ui.heading(egui::RichText::new("🔎 Discover").size(18.0));
+            ui.heading(egui::RichText::new(tr("discover.title")).heading());
         });
+        self.draw_language_selector(ui);
+        if self.draw_discover_search(ui) {
+            return;
+        }
         ui.separator();
 
-        let cats = recommended_categories();
+        // Catégories sans flux dans les langues choisies: masquées.
+        let languages = &self.config.ui.preferred_languages;
+        let cats: Vec<_> = recommended_categories()
+            .iter()
+            .map(|cat| (cat, cat.top_feeds(languages).len()))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        if cats.is_empty() {
+            ui.label(egui::RichText::new(tr("discover.no_feeds_in_languages")).color(secondary));
+        }
         let mut i = 0usize;
         while i < cats.len() {
             ui.horizontal(|ui| {
                 for j in 0..2 {
-                    if let Some(cat) = cats.get(i + j) {
+                    if let Some((cat, count)) = cats.get(i + j) {
                         ui.group(|g| {
                             g.vertical(|ui| {
                                 let btn = ui.add_sized(
                                     egui::vec2(200.0, 90.0),
                                     egui::Button::new(
-                                        egui::RichText::new(cat.name).strong().size(16.0),
+                                        egui::RichText::new(cat.name).strong().title(),
                                     ),
                                 );
                                 if btn.clicked() {
@@ -334,12 +1177,13 @@ impl RssApp {
                                         AppView::DiscoverCategory(cat.name.to_string());
                                 }
                                 ui.label(
-                                    egui::RichText::new(format!(
-                                        "Top {} flux",
-                                        cat.feeds.len().min(5)
+                                    egui::RichText::new(tr_count(
+                                        "discover.top_feeds",
+                                        *count as i64,
+                                        &[],
                                     ))
-                                    .weak()
-                                    .size(12.0),
+                                    .color(secondary)
+                                    .small(),
                                 );
                             });
                         });
@@ -353,6 +1197,217 @@ impl RssApp {
         }
     }
 
+    fn draw_recommended_feed(
+        &mut self,
+        ui: &mut egui::Ui,
+        rf: &RecFeed,
+        followed: &HashSet<String>,
+    ) {
+        // ===
+        // Carte d’un flux recommandé; « Suivi ✓ » (inactif) si le flux est déjà suivi.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        let is_followed = feed_url_key(rf.url).is_some_and(|key| followed.contains(&key));
+        ui.group(|g| {
+            g.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(rf.title).strong().title());
+                    ui.label(egui::RichText::new(rf.lang.to_uppercase()).small());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if is_followed {
+                            ui.add_enabled(
+                                false,
+                                egui::Button::new(tr("discover.followed")).small(),
+                            );
+                        } else if ui.small_button(tr("discover.follow")).clicked() {
+                            self.follow_recommended(rf.title, rf.url);
+                        }
+                        let probing = matches!(
+                            self.discover_probes.get(&rf.url.to_string()),
+                            Some(TaskSlot::Pending)
+                        );
+                        if ui
+                            .add_enabled(!probing, egui::Button::new(tr("discover.probe")).small())
+                            .on_hover_text(tr("discover.probe_hover"))
+                            .clicked()
+                        {
+                            self.probe_recommended(ui.ctx(), rf.url);
+                        }
+                    });
+                });
+                ui.label(egui::RichText::new(rf.desc).color(secondary).meta())
+                    .on_hover_text(rf.url);
+                self.draw_probe_result(ui, rf.url);
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    fn probe_recommended(&mut self, ctx: &egui::Context, url: &str) {
+        // ===
+        // « Tester »: récupère le flux une fois sur le runtime (preview_feed); un résultat déjà
+        // obtenu est réutilisé, sauf un échec, retenté.
+        // ===
+        let key = url.to_string();
+        if matches!(self.discover_probes.get(&key), Some(TaskSlot::Done(Err(_)))) {
+            self.discover_probes.forget(&key);
+        }
+        let client = self.client.clone();
+        let timeout = self.poll_config.request_timeout;
+        let allow_http = self.config.network.allow_http;
+        let task = async move {
+            let started = std::time::Instant::now();
+            preview_feed(&client, &key, timeout, allow_http)
+                .await
+                .map(|preview| FeedProbe {
+                    latency: started.elapsed(),
+                    item_count: preview.item_count,
+                    latest_title: preview.recent_titles.into_iter().next(),
+                })
+                .map_err(|e| (FetchErrorKind::from(&e), e.to_string()))
+        };
+        self.discover_probes
+            .request(&self.runtime, ctx, url.to_string(), task);
+    }
+
+    fn draw_probe_result(&self, ui: &mut egui::Ui, url: &str) {
+        // ===
+        // Résultat de « Tester » sous la description d’un flux recommandé.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        match self.discover_probes.get(&url.to_string()) {
+            None => {}
+            Some(TaskSlot::Pending) => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(
+                        egui::RichText::new(tr("discover.probing"))
+                            .color(secondary)
+                            .small(),
+                    );
+                });
+            }
+            Some(TaskSlot::Done(Ok(probe))) => {
+                let mut text = tr_args(
+                    "discover.probe_result",
+                    &[
+                        ("ms", &probe.latency.as_millis()),
+                        ("count", &probe.item_count),
+                    ],
+                );
+                if let Some(title) = &probe.latest_title {
+                    let latest = tr_args("discover.probe_latest", &[("title", title)]);
+                    text = format!("{} · {}", text, latest);
+                }
+                ui.label(egui::RichText::new(text).color(secondary).small());
+            }
+            Some(TaskSlot::Done(Err((kind, message)))) => {
+                let text = tr_args(
+                    "discover.probe_failed",
+                    &[("kind", &error_kind_text(*kind))],
+                );
+                ui.label(
+                    egui::RichText::new(text)
+                        .color(Color32::from_rgb(229, 57, 53))
+                        .small(),
+                )
+                .on_hover_text(message);
+            }
+        }
+    }
+
+    fn followed_url_keys(&self) -> HashSet<String> {
+        // ===
+        // URL normalisées (feed_url_key) des flux suivis, pour repérer les recommandations déjà
+        // suivies.
+        // ===
+        self.feeds_snapshot()
+            .iter()
+            .filter_map(|f| feed_url_key(&f.url))
+            .collect()
+    }
+
+    fn draw_discover_search(&mut self, ui: &mut egui::Ui) -> bool {
+        // ===
+        //
+        //
+        // Champ de recherche de Discover: résultats du catalogue (titre, description, URL) dans
+        // les langues choisies et, si la requête ressemble à une adresse, autodécouverte du
+        // flux via la fenêtre d’aperçu. Renvoie true tant qu’une recherche est affichée.
+        //
+        //
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        ui.add(
+            egui::TextEdit::singleline(&mut self.discover_query)
+                .hint_text(tr("discover.search_hint"))
+                .desired_width(f32::INFINITY),
+        );
+        let query = self.discover_query.trim().to_string();
+        if query.is_empty() {
+            return false;
+        }
+        ui.add_space(6.0);
+        if let Some(url) = query_as_url(&query) {
+            if ui
+                .button(tr_args("discover.find_at_url", &[("url", &url)]))
+                .on_hover_text(tr("discover.find_at_url_hover"))
+                .clicked()
+            {
+                self.new_feed_url = url;
+                self.new_feed_allow_insecure = false;
+                self.start_feed_preview(ui.ctx());
+            }
+            ui.add_space(6.0);
+        }
+        let languages = &self.config.ui.preferred_languages;
+        let results: Vec<&RecFeed> = catalog_search(&query)
+            .into_iter()
+            .filter(|feed| feed.in_languages(languages))
+            .collect();
+        if results.is_empty() {
+            ui.label(egui::RichText::new(tr("discover.no_match")).color(secondary));
+        }
+        let followed = self.followed_url_keys();
+        for rf in results {
+            self.draw_recommended_feed(ui, rf, &followed);
+        }
+        true
+    }
+
+    fn draw_language_selector(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Choix des langues de Discover (plusieurs possibles), enregistré dans la configuration.
+        // ===
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(tr("discover.languages"));
+            let languages = &mut self.config.ui.preferred_languages;
+            if ui
+                .selectable_label(languages.is_empty(), tr("discover.all_languages"))
+                .clicked()
+                && !languages.is_empty()
+            {
+                languages.clear();
+                changed = true;
+            }
+            for (code, label) in DISCOVER_LANGUAGES {
+                let selected = languages.iter().any(|l| l == code);
+                if ui.selectable_label(selected, label).clicked() {
+                    if selected {
+                        languages.retain(|l| l != code);
+                    } else {
+                        languages.push(code.to_string());
+                    }
+                    changed = true;
+                }
+            }
+        });
+        if changed {
+            let _ = self.config.save();
+        }
+    }
+
     fn draw_discover_category(&mut self, ui: &mut egui::Ui, category_name: String) {
         // ===
         //
@@ -361,13 +1416,18 @@ impl RssApp {
         //
         //
         // ===
+        let secondary = self.config.theme.secondary_text_color32();
         ui.horizontal(|ui| {
-            if ui.button("← Retour").clicked() {
+            if ui.button(tr("common.back")).clicked() {
                 self.current_view = AppView::DiscoverHome;
                 return;
             }
             ui.separator();
-            ui.heading(egui::RichText::new(format!("{} — Top 5", category_name)).size(18.0));
+            let title = tr_args(
+                "discover.category_title",
+                &[("category", &category_name), ("count", &TOP_FEEDS)],
+            );
+            ui.heading(egui::RichText::new(title).heading());
         });
         ui.separator();
 
@@ -375,101 +1435,116 @@ impl RssApp {
             .iter()
             .find(|c| c.name == category_name);
         if let Some(cat) = cat {
-            let feeds = &cat.feeds[..cat.feeds.len().min(5)];
+            let feeds = cat.top_feeds(&self.config.ui.preferred_languages);
+            if feeds.is_empty() {
+                ui.label(
+                    egui::RichText::new(tr("discover.no_feeds_in_languages")).color(secondary),
+                );
+            }
+            let followed = self.followed_url_keys();
             for rf in feeds {
-                ui.group(|g| {
-                    g.vertical(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.label(egui::RichText::new(rf.title).strong().size(16.0));
-                            ui.with_layout(
-                                egui::Layout::right_to_left(egui::Align::Center),
-                                |ui| {
-                                    if ui.small_button("Suivre").clicked() {
-                                        self.follow_recommended(rf.title, rf.url);
-                                    }
-                                },
-                            );
-                        });
-                        ui.label(egui::RichText::new(rf.desc).weak().size(13.0))
-                            .on_hover_text(rf.url);
-                    });
-                });
-                ui.add_space(6.0);
+                self.draw_recommended_feed(ui, rf, &followed);
             }
         } else {
-            ui.label(egui::RichText::new("Catégorie introuvable").weak());
+            ui.label(egui::RichText::new(tr("discover.category_not_found")).color(secondary));
         }
     }
 
-    fn setup_dark_theme(&self, ctx: &egui::Context) {
+    fn apply_theme(&self, ctx: &egui::Context) {
         // ===
         //
         //
         // Applique le thème à partir de la configuration (couleurs, arrondis, espacements).
+        // Les visuels de base egui (clair ou sombre) suivent la luminosité de l’arrière-plan.
         //
         //
         // ===
+        let theme = &self.config.theme;
+        let dark = theme.is_dark();
         let mut style = (*ctx.style()).clone();
 
-        let bg_color = Color32::from_rgb(
-            self.config.theme.background_color[0],
-            self.config.theme.background_color[1],
-            self.config.theme.background_color[2],
-        );
-        let panel_color = Color32::from_rgb(
-            self.config.theme.panel_color[0],
-            self.config.theme.panel_color[1],
-            self.config.theme.panel_color[2],
-        );
-        let border_color = Color32::from_rgb(
-            self.config.theme.border_color[0],
-            self.config.theme.border_color[1],
-            self.config.theme.border_color[2],
-        );
-        let text_color = Color32::from_rgb(
-            self.config.theme.text_color[0],
-            self.config.theme.text_color[1],
-            self.config.theme.text_color[2],
-        );
-        let accent_color = Color32::from_rgb(
-            self.config.theme.accent_color[0],
-            self.config.theme.accent_color[1],
-            self.config.theme.accent_color[2],
-        );
+        let bg_color = theme.background_color32();
+        let panel_color = theme.panel_color32();
+        let border_color = theme.border_color32();
+        let text_color = theme.text_color32();
+        let accent_color = theme.accent_color32();
         let hover_color = panel_color;
+        // Contraste élevé: contours épais, y compris autour du widget qui a le focus clavier.
+        let stroke_width = if theme.preset == ThemePreset::HighContrast {
+            2.0
+        } else {
+            1.0
+        };
+        // Nuances dérivées: plus sombres en thème sombre, plus claires en thème clair.
+        let (recessed, raised) = if dark { (-12, 12) } else { (8, -10) };
 
-        style.visuals.dark_mode = true;
+        style.visuals = if dark {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        };
         style.visuals.panel_fill = panel_color;
         style.visuals.window_fill = bg_color;
-        style.visuals.extreme_bg_color = Color32::from_rgb(25, 25, 25);
-        style.visuals.faint_bg_color = Color32::from_rgb(45, 45, 45);
+        style.visuals.window_stroke = Stroke::new(stroke_width, border_color);
+        style.visuals.extreme_bg_color = shade(bg_color, recessed);
+        style.visuals.faint_bg_color = shade(panel_color, raised / 2);
+        style.visuals.hyperlink_color = accent_color;
 
         style.visuals.override_text_color = Some(text_color);
 
         style.visuals.widgets.noninteractive.bg_fill = panel_color;
-        style.visuals.widgets.noninteractive.bg_stroke = Stroke::new(1.0, border_color);
-        style.visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, text_color);
+        style.visuals.widgets.noninteractive.weak_bg_fill = panel_color;
+        style.visuals.widgets.noninteractive.bg_stroke = Stroke::new(stroke_width, border_color);
+        style.visuals.widgets.noninteractive.fg_stroke = Stroke::new(stroke_width, text_color);
 
-        style.visuals.widgets.inactive.bg_fill = Color32::from_rgb(50, 50, 50);
-        style.visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, border_color);
-        style.visuals.widgets.inactive.fg_stroke = Stroke::new(1.0, text_color);
+        style.visuals.widgets.inactive.bg_fill = shade(panel_color, raised);
+        style.visuals.widgets.inactive.weak_bg_fill = shade(panel_color, raised);
+        style.visuals.widgets.inactive.bg_stroke = Stroke::new(stroke_width, border_color);
+        style.visuals.widgets.inactive.fg_stroke = Stroke::new(stroke_width, text_color);
 
         style.visuals.widgets.hovered.bg_fill = hover_color;
-        style.visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, accent_color);
-        style.visuals.widgets.hovered.fg_stroke = Stroke::new(1.0, text_color);
+        style.visuals.widgets.hovered.weak_bg_fill = shade(panel_color, raised * 2);
+        style.visuals.widgets.hovered.bg_stroke = Stroke::new(stroke_width, accent_color);
+        style.visuals.widgets.hovered.fg_stroke = Stroke::new(stroke_width, text_color);
 
         style.visuals.widgets.active.bg_fill = accent_color;
-        style.visuals.widgets.active.bg_stroke = Stroke::new(1.0, accent_color);
-        style.visuals.widgets.active.fg_stroke = Stroke::new(1.0, Color32::WHITE);
+        style.visuals.widgets.active.weak_bg_fill = accent_color;
+        style.visuals.widgets.active.bg_stroke = Stroke::new(stroke_width, accent_color);
+        style.visuals.widgets.active.fg_stroke =
+            Stroke::new(stroke_width, contrasting_text(accent_color));
 
-        style.visuals.selection.bg_fill = Color32::from_rgba_unmultiplied(0, 122, 204, 60);
-        style.visuals.selection.stroke = Stroke::new(1.0, accent_color);
+        style.visuals.selection.bg_fill = Color32::from_rgba_unmultiplied(
+            accent_color.r(),
+            accent_color.g(),
+            accent_color.b(),
+            60,
+        );
+        style.visuals.selection.stroke = Stroke::new(stroke_width, accent_color);
 
         style.visuals.widgets.noninteractive.rounding = Rounding::same(3.0);
         style.visuals.widgets.inactive.rounding = Rounding::same(3.0);
         style.visuals.widgets.hovered.rounding = Rounding::same(3.0);
         style.visuals.widgets.active.rounding = Rounding::same(3.0);
 
+        let scale = self.config.ui.font_size.clamp(10.0, 24.0) / BASE_FONT_SIZE;
+        let proportional = |size: f32| egui::FontId::proportional(size * scale);
+        style.text_styles = [
+            (egui::TextStyle::Small, proportional(12.0)),
+            (egui::TextStyle::Body, proportional(14.0)),
+            (egui::TextStyle::Button, proportional(14.0)),
+            (egui::TextStyle::Heading, proportional(18.0)),
+            (
+                egui::TextStyle::Monospace,
+                egui::FontId::monospace(13.0 * scale),
+            ),
+        ]
+        .into();
+        for (name, size) in TEXT_STYLES {
+            style
+                .text_styles
+                .insert(egui::TextStyle::Name(name.into()), proportional(size));
+        }
+
         style.spacing.item_spacing = egui::vec2(10.0, 8.0);
         style.spacing.button_padding = egui::vec2(10.0, 6.0);
         style.spacing.window_margin = egui::Margin::same(10.0);
@@ -483,1070 +1558,5223 @@ impl RssApp {
         // ===
         //
         //
-        // Traite les évènements entrants (nouveaux articles) et persiste.
+        // Traite les évènements du service (déjà persistés): liste d’articles et toasts. Un
+        // retard sur le canal fait relire tous les articles.
         //
         //
         // ===
-        while let Ok(evt) = self.updates.try_recv() {
+        loop {
+            let evt = match self.updates.try_recv() {
+                Ok(evt) => evt,
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "évènements manqués, articles relus");
+                    self.articles = self.runtime.block_on(self.data_api.list_all_articles());
+                    self.sort_articles();
+                    continue;
+                }
+                Err(_) => break,
+            };
             match evt {
-                Event::NewArticles(feed_id, mut entries) => {
-                    let to_persist = entries.clone();
-                    self.runtime
-                        .block_on(self.data_api.upsert_articles(&feed_id, to_persist));
-
-                    self.articles.append(&mut entries);
-                    self.articles
-                        .sort_by(|a, b| b.published_at.cmp(&a.published_at));
-                    self.articles
-                        .truncate(self.config.ui.articles_per_page.max(1));
+                // Après une relecture (retard), un lot peut déjà figurer dans la liste.
+                Event::NewArticles(_, entries) => {
+                    if self.config.ui.group_similar_titles {
+                        for entry in &entries {
+                            self.title_clusters.insert(entry);
+                        }
+                    }
+                    article_list::upsert(&mut self.articles, entries);
+                    self.articles_revision += 1;
+                    self.previews.retain(&self.articles);
+                }
+                Event::ArticlesUpdated(_, entries) => self.replace_updated_articles(entries),
+                Event::FetchCompleted(feed_id, outcome) => {
+                    self.notify_fetch_failure(&feed_id, &outcome);
+                }
+                // La barre latérale lit l’état dans la liste partagée.
+                Event::FeedDisabled(feed_id) => {
+                    tracing::info!(feed_id, "flux désactivé après des échecs répétés");
+                    let feed = self.feeds_snapshot().into_iter().find(|f| f.id == feed_id);
+                    if !feed.as_ref().is_some_and(|f| f.notifications_disabled) {
+                        let title = feed.map_or(feed_id, |f| f.title);
+                        self.push_toast(
+                            ToastLevel::Error,
+                            tr_args("toast.feed_disabled", &[("title", &title)]),
+                        );
+                    }
+                }
+                Event::FeedMoved(feed_id, _) => tracing::info!(feed_id, "flux déplacé"),
+                Event::FeedMetadata(..) | Event::FeedTitle(..) => {}
+                Event::CycleCompleted(summary) => self.last_cycle = Some((summary, Utc::now())),
+                Event::ConnectivityChanged(online) => {
+                    self.offline = !online;
+                    if online {
+                        self.push_toast(ToastLevel::Info, tr("toast.back_online"));
+                    }
                 }
             }
         }
+        self.update_tray_unread();
     }
 
-    fn feeds_snapshot(&self) -> Vec<FeedDescriptor> {
+    fn replace_updated_articles(&mut self, entries: Vec<FeedEntry>) {
         // ===
-        // Vue snapshot des flux (lecture RwLock).
+        // Articles modifiés par l’éditeur: remplace la copie affichée (liste et article ouvert).
         // ===
-        self.runtime.block_on(list_feeds(&self.feeds))
+        if let AppView::ArticleDetail(open) = &mut self.current_view {
+            let identity = open.identity();
+            if let Some(entry) = entries
+                .iter()
+                .find(|e| e.feed_id == open.feed_id && e.identity() == identity)
+            {
+                **open = entry.clone();
+            }
+        }
+        article_list::upsert(&mut self.articles, entries);
+        self.articles_revision += 1;
+        self.previews.retain(&self.articles);
     }
 
-    fn filtered_feeds(&self) -> Vec<FeedDescriptor> {
+    fn update_tray_unread(&mut self) {
         // ===
-        // Filtre de flux par recherche (titre).
+        // Infobulle de l’icône: total des non lus, recalculé au plus une fois par seconde.
         // ===
-        let feeds = self.feeds_snapshot();
-        if self.feed_search.is_empty() {
-            feeds
-        } else {
-            let needle = self.feed_search.to_lowercase();
-            feeds
-                .into_iter()
-                .filter(|feed| feed.title.to_lowercase().contains(&needle))
-                .collect()
+        let Some(tray) = self.tray.as_mut() else {
+            return;
+        };
+        if self
+            .unread_checked_at
+            .is_some_and(|at| at.elapsed() < std::time::Duration::from_secs(1))
+        {
+            return;
         }
+        self.unread_checked_at = Some(std::time::Instant::now());
+        let unread = self.runtime.block_on(self.data_api.unread_count());
+        tray.set_unread(&self.runtime, unread);
     }
 
-    fn follow_recommended(&mut self, title: &str, url: &str) {
+    fn sync_tray(&mut self, ctx: &egui::Context) {
         // ===
-        // Ajoute un flux recommandé et tente un rafraîchissement immédiat.
+        //
+        //
+        // Crée ou retire l’icône de notification selon le réglage, traite ses actions et
+        // intercepte la fermeture de la fenêtre (masquée au lieu de quitter, le poller continue).
+        //
+        //
         // ===
-        let exists = self
-            .runtime
-            .block_on(list_feeds(&self.feeds))
-            .into_iter()
-            .any(|f| f.url == url);
-        if exists {
-            self.discover_feedback = Some((false, "Déjà suivi.".to_string()));
-            return;
+        if !self.config.ui.minimize_to_tray {
+            self.tray = None;
+            self.tray_attempted = false;
+        } else if self.tray.is_none() && !self.tray_attempted {
+            self.tray_attempted = true;
+            self.tray = Tray::spawn(&self.runtime, ctx);
+            self.unread_checked_at = None;
+            if self.tray.is_none() {
+                self.push_toast(ToastLevel::Error, tr("toast.tray_unavailable"));
+            }
         }
 
-        let id = format!(
-            "discover:{}:{}",
-            title.replace(' ', "_"),
-            Utc::now().timestamp_millis()
-        );
-        let descriptor = FeedDescriptor {
-            id,
-            title: title.to_string(),
-            url: url.to_string(),
-        };
+        while let Some(command) = self.tray.as_ref().and_then(|t| t.try_recv()) {
+            match command {
+                TrayCommand::Open => {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                }
+                TrayCommand::Refresh => self.service.poll_now(),
+                TrayCommand::Quit => {
+                    self.quitting = true;
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            }
+        }
 
-        self.runtime
-            .block_on(self.data_api.add_feed(descriptor.clone()));
-        let events = self.runtime.block_on(async {
-            poll_once(
-                &[descriptor],
-                &self.poll_config,
-                &self.client,
-                &self.seen_store,
-            )
-            .await
-        });
-        for evt in events {
-            let Event::NewArticles(feed_id, mut entries) = evt;
-            let to_persist = entries.clone();
-            self.runtime
-                .block_on(self.data_api.upsert_articles(&feed_id, to_persist));
-            // Remplacer les articles existants de ce flux
-            self.articles.retain(|a| a.feed_id != feed_id);
-            self.articles.append(&mut entries);
-        }
-        self.articles
-            .sort_by(|a, b| b.published_at.cmp(&a.published_at));
-        self.articles
-            .truncate(self.config.ui.articles_per_page.max(1));
-        self.discover_feedback = Some((true, "Ajouté.".to_string()));
+        if self.tray.is_some() && !self.quitting && ctx.input(|i| i.viewport().close_requested()) {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+        }
     }
 
-    fn filtered_articles(&self) -> Vec<&FeedEntry> {
+    fn track_window_geometry(&mut self, ctx: &egui::Context) {
         // ===
-        // Retourne la vue filtrée des articles selon le flux sélectionné.
+        // Taille et position courantes de la fenêtre (hors réduction), et taille de l’écran qui
+        // l’affiche, enregistrées avec la configuration à la fermeture.
         // ===
-        if let Some(selected_feed_id) = &self.selected_feed {
-            self.articles
-                .iter()
-                .filter(|article| &article.feed_id == selected_feed_id)
-                .collect()
-        } else {
-            self.articles.iter().collect()
+        let viewport = ctx.input(|i| i.viewport().clone());
+        if viewport.minimized == Some(true) {
+            return;
+        }
+        let inner = viewport.inner_rect;
+        if let Some(rect) = inner.filter(|r| r.width() >= 1.0 && r.height() >= 1.0) {
+            self.config.ui.window_size = Some([rect.width(), rect.height()]);
+        }
+        if let Some(outer) = viewport.outer_rect {
+            self.config.ui.window_position = Some([outer.min.x, outer.min.y]);
+        }
+        if let Some(size) = viewport.monitor_size.filter(|s| s.x >= 1.0 && s.y >= 1.0) {
+            self.config.ui.monitor_size = Some([size.x, size.y]);
         }
     }
 
-    fn add_feed_from_input(&mut self) {
+    fn restore_window_position(&mut self, ctx: &egui::Context) {
         // ===
-        // Ajoute un flux saisi manuellement (HTTPS requis) et rafraîchit.
+        // Une fois, dès que l’écran est connu: ramène dans l’écran une fenêtre restaurée hors de
+        // vue (écran débranché depuis la dernière fermeture).
         // ===
-        let title_owned = self.new_feed_title.trim().to_string();
-        let url_owned = self.new_feed_url.trim().to_string();
-        if url_owned.is_empty() {
-            self.add_feedback = Some((false, "URL invalide".to_string()));
+        if self.window_position_checked {
             return;
         }
-        if let Ok(parsed) = Url::parse(&url_owned) {
-            if parsed.scheme() != "https" {
-                self.add_feedback =
-                    Some((false, "Seules les URLs HTTPS sont autorisées".to_string()));
-                return;
-            }
-        } else {
-            self.add_feedback = Some((false, "URL invalide".to_string()));
+        let (outer, monitor) = ctx.input(|i| (i.viewport().outer_rect, i.viewport().monitor_size));
+        let (Some(outer), Some(monitor)) = (outer, monitor) else {
             return;
-        }
-
-        let id = format!("{}:{}", title_owned, Utc::now().timestamp_millis());
-        let descriptor = FeedDescriptor {
-            id,
-            title: if title_owned.is_empty() {
-                url_owned.clone()
-            } else {
-                title_owned.clone()
-            },
-            url: url_owned.clone(),
         };
-
-        self.runtime
-            .block_on(self.data_api.add_feed(descriptor.clone()));
-        let events = self.runtime.block_on(async {
-            poll_once(
-                &[descriptor],
-                &self.poll_config,
-                &self.client,
-                &self.seen_store,
-            )
-            .await
-        });
-        for evt in events {
-            match evt {
-                Event::NewArticles(feed_id, mut entries) => {
-                    let to_persist = entries.clone();
-                    self.runtime
-                        .block_on(self.data_api.upsert_articles(&feed_id, to_persist));
-                    self.articles.append(&mut entries);
-                    self.articles
-                        .sort_by(|a, b| b.published_at.cmp(&a.published_at));
-                    self.articles.truncate(250);
-                }
-            }
-        }
-        self.new_feed_title.clear();
-        self.new_feed_url.clear();
-        if !title_owned.is_empty() {
-            self.add_feedback = Some((true, "Ajouté.".to_string()));
-        } else {
-            self.add_feedback = None;
+        self.window_position_checked = true;
+        let saved_monitor = self.config.ui.monitor_size.map(egui::Vec2::from);
+        if let Some(position) = window_geometry::restored_position(outer, monitor, saved_monitor) {
+            tracing::info!(?position, "fenêtre hors de l’écran, replacée");
+            ctx.send_viewport_cmd(egui::ViewportCommand::OuterPosition(position));
         }
     }
 
-    fn draw_left_panel(&mut self, ctx: &egui::Context) {
+    fn shutdown(&mut self) {
         // ===
         //
         //
-        // Panneau gauche: ajout/recherche de flux, discover, paramètres, liste des flux.
+        // Fermeture: position de lecture, taille de fenêtre et session enregistrées, puis arrêt
+        // du service (poller arrêté, toutes les données réécrites), le tout borné par
+        // SHUTDOWN_FLUSH_TIMEOUT.
         //
         //
         // ===
-        egui::SidePanel::left("feeds_panel")
-            .min_width(self.config.ui.left_panel_width.clamp(200.0, 500.0))
-            .max_width(500.0)
-            .show(ctx, |ui| {
-                ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
-                    egui::Frame::group(ui.style())
-                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
-                        .show(ui, |ui| {
-                            ui.label(
-                                egui::RichText::new("🔍 Ajouter un nouveau flux")
-                                    .strong()
-                                    .size(15.0),
-                            );
-                            ui.separator();
+        self.save_reading_position();
+        if let Err(e) = self.config.save() {
+            tracing::warn!(error = %e, "échec de l’enregistrement de la configuration");
+        }
+        let service = self.service.clone();
+        let session = self.session_snapshot();
+        let flush = async move {
+            let session = service.data_api().save_session(&session).await;
+            let data = service.shutdown().await;
+            session.and(data)
+        };
+        match self
+            .runtime
+            .block_on(tokio::time::timeout(SHUTDOWN_FLUSH_TIMEOUT, flush))
+        {
+            Ok(Ok(())) => {}
+            // Plus de fenêtre pour un toast: le journal garde la trace de l’échec.
+            Ok(Err(e)) => tracing::error!(error = %e, "données non enregistrées à la fermeture"),
+            Err(_) => tracing::warn!("écritures de fermeture interrompues (délai dépassé)"),
+        }
+    }
 
-                            ui.label(egui::RichText::new("Titre du flux :").size(13.0));
-                            let title_id = egui::Id::new("new_feed_title");
-                            let title_resp = ui.add(
-                                egui::TextEdit::singleline(&mut self.new_feed_title).id(title_id),
-                            );
+    fn refresh_all(&mut self) {
+        // ===
+        // « Tout rafraîchir »: en mode local, un tour complet dont l’avancement s’affiche au-dessus
+        // de la liste (sans en relancer un s’il est en cours); sinon un cycle du poller.
+        // ===
+        if self.config.sync != SyncMode::Local {
+            self.service.poll_now();
+        } else if self.refresh_task.is_none() {
+            self.refresh_task = Some(self.spawn_refresh());
+        }
+    }
 
-                            ui.label(egui::RichText::new("URL du flux :").size(13.0));
-                            let url_id = egui::Id::new("new_feed_url");
-                            let url_resp = ui.add(
-                                egui::TextEdit::singleline(&mut self.new_feed_url).id(url_id),
-                            );
+    fn spawn_refresh(&self) -> tokio::task::JoinHandle<()> {
+        // ===
+        // Récupère tous les flux en tâche de fond; les évènements arrivent par le service.
+        // ===
+        let service = self.service.clone();
+        self.runtime.spawn(async move {
+            service.refresh_all().await;
+        })
+    }
 
-                            // Entrée rapide: touche Entrée dans l'un des champs -> Ajouter
-                            // Gestion fiable d'Enter: on vérifie la perte de focus due à Enter
-                            let pressed_enter = ui.ctx().input(|i| i.key_pressed(egui::Key::Enter));
-                            if title_resp.lost_focus() && pressed_enter {
-                                ui.memory_mut(|m| m.request_focus(url_id));
-                            }
-                            if url_resp.lost_focus() && pressed_enter {
-                                self.add_feed_from_input();
-                            }
+    fn refresh_icons_async(&self, feed_ids: Vec<String>) {
+        // ===
+        // Met à jour les icônes des flux en tâche de fond (au plus une fois par semaine chacune);
+        // les identifiants récupérés reviennent par `icons_rx` pour recharger leur texture.
+        // ===
+        let api = self.data_api.clone();
+        let client = self.client.clone();
+        let icons = self.icons_tx.clone();
+        self.runtime.spawn(async move {
+            for feed_id in feed_ids {
+                if api.refresh_feed_icon(&client, &feed_id).await.is_some() {
+                    let _ = icons.send(feed_id);
+                }
+            }
+        });
+    }
 
-                            ui.horizontal(|ui| {
-                                if ui.button("➕ Ajouter").clicked() {
+    fn feed_icon_texture(
+        &mut self,
+        ctx: &egui::Context,
+        feed_id: &str,
+    ) -> Option<egui::TextureHandle> {
+        // ===
+        // Texture de l’icône d’un flux, décodée au premier affichage puis gardée en mémoire.
+        // ===
+        if let Some(icon) = self.feed_icons.get(feed_id) {
+            return icon.clone();
+        }
+        let texture = self
+            .runtime
+            .block_on(self.data_api.feed_icon(feed_id))
+            .and_then(|path| std::fs::read(path).ok())
+            .and_then(|bytes| image::load_from_memory(&bytes).ok())
+            .map(|decoded| {
+                let rgba = decoded.to_rgba8();
+                let size = [rgba.width() as usize, rgba.height() as usize];
+                let image = egui::ColorImage::from_rgba_unmultiplied(size, rgba.as_raw());
+                ctx.load_texture(format!("feed_icon:{}", feed_id), image, Default::default())
+            });
+        self.feed_icons.insert(feed_id.to_string(), texture.clone());
+        texture
+    }
+
+    fn notify_fetch_failure(&self, feed_id: &str, outcome: &FetchOutcome) {
+        // ===
+        // Toast d’échec au premier échec d’une série seulement (pas à chaque cycle). Hors ligne,
+        // le bandeau en tient lieu.
+        // ===
+        if outcome.success || self.offline {
+            return;
+        }
+        let feeds = self.feeds_snapshot();
+        let Some(feed) = feeds.iter().find(|f| f.id == feed_id) else {
+            return;
+        };
+        if feed.consecutive_failures > 1 || feed.notifications_disabled {
+            return;
+        }
+        let error = outcome
+            .error
+            .clone()
+            .unwrap_or_else(|| tr("toast.unknown_error"));
+        self.push_toast(
+            ToastLevel::Error,
+            tr_args(
+                "toast.fetch_failed",
+                &[("title", &feed.title), ("error", &error)],
+            ),
+        );
+    }
+
+    fn sort_articles(&mut self) {
+        // ===
+        // Trie les articles du plus récent au plus ancien (liste complète, paginée à l’affichage).
+        // ===
+        article_list::sort_newest_first(&mut self.articles);
+        self.previews.retain(&self.articles);
+        self.rebuild_title_clusters();
+    }
+
+    fn rebuild_title_clusters(&mut self) {
+        // ===
+        // Regroupe de nouveau toute la liste (rechargement, réglage modifié), du plus ancien au
+        // plus récent: le premier article d’un groupe sert de référence. Vide si désactivé.
+        // ===
+        self.articles_revision += 1;
+        self.title_clusters = TitleClusters::new(self.config.ui.title_similarity);
+        if self.config.ui.group_similar_titles {
+            for entry in self.articles.iter().rev() {
+                self.title_clusters.insert(entry);
+            }
+        }
+    }
+
+    fn reset_pagination(&mut self) {
+        // ===
+        // Revient à la première page (changement de flux ou de filtre), sans sélection.
+        // ===
+        self.article_pages = 1;
+        self.selected_article_index = None;
+    }
+
+    fn apply_pending_poll_config(&mut self) {
+        // ===
+        // Applique au poller les réglages de polling modifiés (après 500 ms sans changement).
+        // ===
+        let Some(changed_at) = self.poll_config_changed_at else {
+            return;
+        };
+        if changed_at.elapsed() < std::time::Duration::from_millis(500) {
+            return;
+        }
+        self.poll_config_changed_at = None;
+        self.poll_config = PollConfig::from_app_config(&self.config);
+        self.service.set_poll_config(self.poll_config.clone());
+        let _ = self.config.save();
+    }
+
+    fn apply_network_config(&mut self) {
+        // ===
+        // Reconstruit le client HTTP avec les réglages réseau et le transmet au poller.
+        // ===
+        for field in [
+            &mut self.config.network.proxy_url,
+            &mut self.config.network.user_agent,
+        ] {
+            if field
+                .as_deref()
+                .map(str::trim)
+                .unwrap_or_default()
+                .is_empty()
+            {
+                *field = None;
+            }
+        }
+        match rss_core::build_http_client(&self.config.network) {
+            Ok(client) => {
+                self.poll_config.allow_http = self.config.network.allow_http;
+                self.service.set_client(client.clone());
+                self.service.set_poll_config(self.poll_config.clone());
+                self.client = client;
+                self.viewer = Viewer::new(&self.config.network);
+                let _ = self.apply_read_later_config();
+                self.network_error = None;
+                let _ = self.config.save();
+                self.push_toast(ToastLevel::Success, tr("toast.applied"));
+            }
+            Err(e) => {
+                self.push_toast(ToastLevel::Error, e.to_string());
+            }
+        }
+    }
+
+    fn apply_sync_mode(&mut self) -> Result<(), String> {
+        // ===
+        // Transmet au poller le mode de synchronisation choisi (None = polling local).
+        // ===
+        let sync = match &self.config.sync {
+            SyncMode::Local => None,
+            SyncMode::Fever { endpoint, api_key } => {
+                let fever =
+                    FeverClient::new(endpoint, api_key.clone()).map_err(|e| e.to_string())?;
+                Some(FeverSync::new(fever, (*self.data_api).clone()).into())
+            }
+            SyncMode::GReader {
+                endpoint,
+                username,
+                password,
+            } => {
+                let reader = GReaderClient::new(endpoint, username, password.clone())
+                    .map_err(|e| e.to_string())?;
+                Some(GReaderSync::new(reader, (*self.data_api).clone()).into())
+            }
+        };
+        self.service.set_sync(sync);
+        Ok(())
+    }
+
+    fn open_article(&mut self, article: &FeedEntry) {
+        // ===
+        // Vue détaillée d’un article (marqué lu), reprise à la position de lecture enregistrée.
+        // ===
+        self.save_reading_position();
+        self.pending_scroll = self
+            .runtime
+            .block_on(self.data_api.reading_position(article));
+        self.current_view = AppView::ArticleDetail(Box::new(article.clone()));
+        self.saved(self.runtime.block_on(self.data_api.mark_read(article)));
+    }
+
+    fn save_reading_position(&mut self) {
+        // ===
+        // Enregistre le défilement de l’article quitté (un retour en haut efface la position).
+        // ===
+        if let Some((article, offset)) = self.reading.take() {
+            let result = self
+                .runtime
+                .block_on(self.data_api.set_reading_position(&article, offset));
+            self.saved(result);
+        }
+    }
+
+    fn open_in_viewer(&self, article: &FeedEntry, local_html: bool) {
+        // ===
+        // Lance la visionneuse sans bloquer l’UI; échec du lancement ou fin en erreur en toast.
+        // ===
+        let toasts = self.toasts.sender();
+        let on_failure = move |e: String| {
+            let _ = toasts.send((ToastLevel::Error, e));
+        };
+        let result = match article.content_html.as_deref().filter(|_| local_html) {
+            Some(html) => webview::write_article_html(&article.title, html)
+                .map_err(|e| tr_args("toast.content_write_failed", &[("error", &e)]))
+                .and_then(|path| {
+                    self.viewer
+                        .open_local_html(&path, &article.title, on_failure)
+                }),
+            None => self
+                .viewer
+                .open_url(&article.url, &article.title, on_failure),
+        };
+        match result {
+            Ok(child) => tracing::debug!(pid = child.pid(), "viewer started"),
+            Err(e) => {
+                tracing::warn!(error = %e, "viewer failed");
+                self.push_toast(ToastLevel::Error, e);
+            }
+        }
+    }
+
+    fn import_state(&mut self) {
+        // ===
+        // Fusionne un état exporté depuis une autre machine, puis recharge la liste d’articles.
+        // ===
+        match self
+            .runtime
+            .block_on(self.data_api.import_state(&self.state_path))
+        {
+            Ok(summary) => {
+                self.articles = self.runtime.block_on(self.data_api.list_all_articles());
+                self.sort_articles();
+                self.reset_pagination();
+                self.push_toast(
+                    ToastLevel::Success,
+                    tr_args(
+                        "toast.import_done",
+                        &[
+                            ("feeds", &summary.feeds_added),
+                            ("articles", &summary.articles_added),
+                            ("updated", &summary.articles_updated),
+                            ("read", &summary.read_added),
+                        ],
+                    ),
+                );
+            }
+            Err(e) => self.push_toast(ToastLevel::Error, e.to_string()),
+        }
+    }
+
+    fn cleanup_cache(&mut self) {
+        // ===
+        // Nettoyage à la demande du cache d’articles, puis rechargement de la liste.
+        // ===
+        let Some(report) = self.saved(self.runtime.block_on(self.data_api.cleanup())) else {
+            return;
+        };
+        self.articles = self.runtime.block_on(self.data_api.list_all_articles());
+        self.sort_articles();
+        self.reset_pagination();
+        self.push_toast(
+            ToastLevel::Success,
+            tr_args(
+                "toast.cache_cleaned",
+                &[
+                    ("removed", &report.removed),
+                    ("kb", &format!("{:.1}", report.bytes_saved as f64 / 1024.0)),
+                ],
+            ),
+        );
+    }
+
+    fn compact_data(&mut self) {
+        // ===
+        // Compactage à la demande (orphelins, doublons, marques anciennes), bilan en toast.
+        // ===
+        let Some(report) = self.saved(self.runtime.block_on(self.data_api.compact())) else {
+            return;
+        };
+        self.articles = self.runtime.block_on(self.data_api.list_all_articles());
+        self.sort_articles();
+        self.reset_pagination();
+        self.push_toast(
+            ToastLevel::Success,
+            tr_args(
+                "toast.data_compacted",
+                &[
+                    ("articles", &report.removed_articles),
+                    ("marks", &report.removed_read_marks),
+                    ("before", &format_bytes(report.bytes_before)),
+                    ("after", &format_bytes(report.bytes_after)),
+                ],
+            ),
+        );
+    }
+
+    fn starred_feed_file(&self) -> PathBuf {
+        // ===
+        // Fichier du flux des favoris: chemin réglé, à défaut starred.xml dans le dossier d’export.
+        // ===
+        match self.config.integrations.starred_feed.path.trim() {
+            "" => Path::new(&self.export_dir).join("starred.xml"),
+            path => PathBuf::from(path),
+        }
+    }
+
+    fn starred_feed_meta(path: &Path) -> ChannelMeta {
+        // Fichier local: son adresse file:// sert de lien et d’identifiant du canal.
+        let url = std::path::absolute(path)
+            .ok()
+            .and_then(|p| Url::from_file_path(p).ok())
+            .map_or_else(|| path.display().to_string(), String::from);
+        ChannelMeta {
+            title: tr("starred_feed.title"),
+            link: url.clone(),
+            description: Some(tr("starred_feed.description")),
+            self_url: Some(url),
+        }
+    }
+
+    fn export_starred_feed(&mut self) {
+        // ===
+        // Export demandé depuis les réglages: écrit le flux des favoris, bilan en toast.
+        // ===
+        let _ = self.config.save();
+        let path = self.starred_feed_file();
+        let format = self.config.integrations.starred_feed.format;
+        let revision = self.data_api.stars_revision();
+        let meta = Self::starred_feed_meta(&path);
+        match self
+            .runtime
+            .block_on(self.data_api.export_starred_feed(&path, meta, format))
+        {
+            Ok(count) => {
+                self.starred_feed_revision = Some(revision);
+                self.last_export = Some(path.clone());
+                self.push_toast(
+                    ToastLevel::Success,
+                    tr_args(
+                        "toast.starred_feed_exported",
+                        &[("count", &count), ("path", &path.display())],
+                    ),
+                );
+            }
+            Err(e) => self.push_toast(ToastLevel::Error, e.to_string()),
+        }
+    }
+
+    fn auto_export_starred_feed(&mut self) {
+        // ===
+        // Réexport automatique (si activé) dès que les favoris ont changé, en tâche de fond.
+        // ===
+        if !self.config.integrations.starred_feed.auto_export {
+            return;
+        }
+        let revision = self.data_api.stars_revision();
+        if self.starred_feed_revision == Some(revision) {
+            return;
+        }
+        self.starred_feed_revision = Some(revision);
+        let path = self.starred_feed_file();
+        let format = self.config.integrations.starred_feed.format;
+        let meta = Self::starred_feed_meta(&path);
+        let api = self.data_api.clone();
+        self.runtime.spawn(async move {
+            if let Err(e) = api.export_starred_feed(&path, meta, format).await {
+                tracing::warn!(error = %e, path = %path.display(), "export du flux des favoris");
+            }
+        });
+    }
+
+    fn mark_read_cutoff(&self) -> Option<chrono::DateTime<Utc>> {
+        // ===
+        // Date limite de « marquer comme lu » selon le réglage en jours (None = pas de limite).
+        // ===
+        match self.config.ui.mark_read_older_than_days {
+            0 => None,
+            days => Some(Utc::now() - chrono::Duration::days(i64::from(days))),
+        }
+    }
+
+    fn mark_feed_read_async(
+        &self,
+        ctx: &egui::Context,
+        feed_id: &str,
+        before: Option<chrono::DateTime<Utc>>,
+    ) {
+        // ===
+        // Marque un flux comme lu en tâche de fond (écriture groupée), puis redemande un rendu;
+        // les marques ajoutées reviennent par `undo_tx` pour le toast « Annuler ».
+        // ===
+        let api = self.data_api.clone();
+        let ctx = ctx.clone();
+        let feed_id = feed_id.to_string();
+        let toasts = self.toasts.sender();
+        let undo = self.undo_tx.clone();
+        self.runtime.spawn(async move {
+            match api.mark_feed_read_tracked(&feed_id, before).await {
+                Ok(marks) => send_mark_read_undo(&undo, marks),
+                Err(e) => {
+                    let _ = toasts.send((ToastLevel::Error, data_error_message(&e)));
+                }
+            }
+            ctx.request_repaint();
+        });
+    }
+
+    fn apply_read_later_config(&mut self) -> Result<(), String> {
+        // ===
+        // (Re)construit le client « lire plus tard » depuis la configuration (None = masqué).
+        // ===
+        self.read_later = match &self.config.integrations.wallabag {
+            Some(cfg) => Some(
+                WallabagClient::new(self.client.clone(), cfg.clone()).map_err(|e| e.to_string())?,
+            ),
+            None => None,
+        };
+        Ok(())
+    }
+
+    fn save_for_later(&self, ctx: &egui::Context, article: &FeedEntry) {
+        // ===
+        // Envoie l’article à Wallabag en tâche de fond; le résultat revient en toast.
+        // ===
+        let Some(service) = self.read_later.clone() else {
+            return;
+        };
+        let url = article.url.clone();
+        let title = article.title.clone();
+        let toasts = self.toasts.sender();
+        let ctx = ctx.clone();
+        self.runtime.spawn(async move {
+            let toast = match service.save(&url, &title).await {
+                Ok(()) => (
+                    ToastLevel::Success,
+                    tr_args("toast.read_later_saved", &[("title", &title)]),
+                ),
+                Err(e) => {
+                    tracing::warn!(error = %e, "read-later save failed");
+                    (
+                        ToastLevel::Error,
+                        tr_args("toast.read_later_failed", &[("error", &e)]),
+                    )
+                }
+            };
+            let _ = toasts.send(toast);
+            ctx.request_repaint();
+        });
+    }
+
+    fn export_articles_async(
+        &self,
+        ctx: &egui::Context,
+        entries: Vec<FeedEntry>,
+        format: ExportFormat,
+    ) {
+        // ===
+        // Écrit les articles dans le dossier d’export en tâche de fond; bilan affiché en toast.
+        // ===
+        let api = self.data_api.clone();
+        let dir = self.export_dir.clone();
+        let toasts = self.toasts.sender();
+        let ctx = ctx.clone();
+        self.runtime.spawn(async move {
+            let toast = match api.export_articles(&entries, &dir, format).await {
+                Ok(paths) => (
+                    ToastLevel::Success,
+                    tr_count("toast.exported", paths.len() as i64, &[("dir", &dir)]),
+                ),
+                Err(e) => (
+                    ToastLevel::Error,
+                    tr_args("toast.export_failed", &[("error", &e)]),
+                ),
+            };
+            let _ = toasts.send(toast);
+            ctx.request_repaint();
+        });
+    }
+
+    fn export_menu(&self, ui: &mut egui::Ui, entries: impl FnOnce() -> Vec<FeedEntry>) {
+        // ===
+        // Menu « Exporter… »: un choix par format, les articles ne sont collectés qu’au clic.
+        // ===
+        ui.menu_button(tr("export.menu"), |ui| {
+            let mut chosen = None;
+            for format in [ExportFormat::Markdown, ExportFormat::Html] {
+                if ui.button(export_format_label(format)).clicked() {
+                    chosen = Some(format);
+                }
+            }
+            if let Some(format) = chosen {
+                self.export_articles_async(ui.ctx(), entries(), format);
+                ui.close_menu();
+            }
+        })
+        .response
+        .on_hover_text(tr_args("export.folder", &[("dir", &self.export_dir)]));
+    }
+
+    fn share_menu(&self, ui: &mut egui::Ui, label: &str, article: &FeedEntry) {
+        // ===
+        // Menu « Partager »: copies dans le presse-papiers (lien Markdown, titre et lien,
+        // article complet en Markdown) ou e-mail pré-rempli dans le client par défaut.
+        // ===
+        ui.menu_button(label, |ui| {
+            let mut copied = None;
+            if ui.button(tr("share.copy_markdown_link")).clicked() {
+                copied = Some(markdown_link(article));
+            }
+            if ui.button(tr("share.copy_title_link")).clicked() {
+                copied = Some(title_and_link(article));
+            }
+            if ui.button(tr("share.copy_article_markdown")).clicked() {
+                let feed_title = self
+                    .feeds_snapshot()
+                    .into_iter()
+                    .find(|feed| feed.id == article.feed_id)
+                    .map(|feed| feed.title)
+                    .unwrap_or_default();
+                copied = Some(article.to_markdown(&feed_title));
+            }
+            if let Some(text) = copied {
+                ui.output_mut(|o| o.copied_text = text);
+                self.push_toast(ToastLevel::Success, tr("toast.copied"));
+                ui.close_menu();
+            }
+            if ui.button(tr("share.email")).clicked() {
+                if let Err(e) = webbrowser::open(&share_mailto(article)) {
+                    self.push_toast(
+                        ToastLevel::Error,
+                        tr_args("toast.email_failed", &[("error", &e)]),
+                    );
+                }
+                ui.close_menu();
+            }
+        })
+        .response
+        .on_hover_text(tr("share.hover"));
+    }
+
+    fn reveal_path(&self, ctx: &egui::Context, path: &Path) {
+        // ===
+        // Ouvre le chemin dans le gestionnaire de fichiers; si aucune commande ne se lance,
+        // le chemin est copié dans le presse-papiers pour être ouvert à la main.
+        // ===
+        if let Err(e) = open_path(path) {
+            tracing::warn!(path = %path.display(), error = %e, "cannot open path");
+            ctx.output_mut(|o| o.copied_text = path.display().to_string());
+            self.push_toast(
+                ToastLevel::Error,
+                tr_args("toast.open_path_failed", &[("path", &path.display())]),
+            );
+        }
+    }
+
+    fn push_toast(&self, level: ToastLevel, message: impl Into<String>) {
+        // ===
+        // Notification éphémère (voir toasts.rs), affichée à l’image suivante.
+        // ===
+        self.toasts.post(level, message);
+    }
+
+    fn saved<T>(&self, result: Result<T, DataError>) -> Option<T> {
+        // ===
+        // Résultat d’une opération DataApi: un échec d’écriture est signalé en toast (l’état en
+        // mémoire est gardé et l’écriture retentée à la prochaine opération).
+        // ===
+        result
+            .map_err(|e| self.push_toast(ToastLevel::Error, data_error_message(&e)))
+            .ok()
+    }
+
+    fn feeds_snapshot(&self) -> Vec<FeedDescriptor> {
+        // ===
+        // Vue snapshot des flux (lecture RwLock).
+        // ===
+        self.runtime.block_on(list_feeds(&self.feeds))
+    }
+
+    fn mute_feed(&self, feed_id: &str, until: Option<DateTime<Utc>>) {
+        // ===
+        // Met un flux en sourdine (ignoré par les cycles du poller) ou lève la sourdine.
+        // ===
+        let result = self
+            .runtime
+            .block_on(self.data_api.mute_feed(feed_id, until));
+        self.saved(result);
+    }
+
+    fn move_feed(&self, feed_id: &str, up: bool) {
+        // ===
+        // Déplace un flux d’un cran dans la barre latérale (ordre enregistré pour tous les flux).
+        // ===
+        let mut ids: Vec<String> = self.feeds_snapshot().into_iter().map(|f| f.id).collect();
+        let Some(index) = ids.iter().position(|id| id == feed_id) else {
+            return;
+        };
+        let target = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&i| i < ids.len())
+        };
+        if let Some(target) = target {
+            ids.swap(index, target);
+            self.saved(self.runtime.block_on(self.data_api.reorder_feeds(ids)));
+        }
+    }
+
+    fn sort_feeds_alphabetically(&self) {
+        // ===
+        // Range les flux par titre (A→Z, sans tenir compte de la casse) et enregistre cet ordre.
+        // ===
+        let mut feeds = self.feeds_snapshot();
+        feeds.sort_by_key(|f| f.title.to_lowercase());
+        let ids = feeds.into_iter().map(|f| f.id).collect();
+        self.saved(self.runtime.block_on(self.data_api.reorder_feeds(ids)));
+    }
+
+    fn filtered_feeds(&self) -> Vec<FeedDescriptor> {
+        // ===
+        // Filtre de flux par recherche (titre).
+        // ===
+        let feeds = self.feeds_snapshot();
+        if self.feed_search.is_empty() {
+            feeds
+        } else {
+            let needle = self.feed_search.to_lowercase();
+            feeds
+                .into_iter()
+                .filter(|feed| feed.title.to_lowercase().contains(&needle))
+                .collect()
+        }
+    }
+
+    fn follow_recommended(&mut self, title: &str, url: &str) {
+        // ===
+        // Ajoute un flux recommandé et tente un rafraîchissement immédiat.
+        // ===
+        let id = format!(
+            "discover:{}:{}",
+            title.replace(' ', "_"),
+            Utc::now().timestamp_millis()
+        );
+        let descriptor = FeedDescriptor::new(id, title, url);
+
+        let descriptor_id = descriptor.id.clone();
+        let added = self.runtime.block_on(self.service.add_feed(descriptor));
+        if let Err(e) = added {
+            self.report_add_feed_error(e);
+            return;
+        }
+        // Les nouveaux articles arrivent par les évènements du service.
+        self.runtime
+            .block_on(self.service.refresh_feed(&descriptor_id));
+        self.refresh_icons_async(vec![descriptor_id]);
+        self.push_toast(ToastLevel::Success, tr("toast.added"));
+    }
+
+    fn filtered_articles(&self) -> Vec<&FeedEntry> {
+        // ===
+        // Retourne la vue filtrée des articles selon le flux sélectionné, le filtre « Non lus »
+        // et les filtres rapides, triée selon le mode choisi (titre puis URL en départage).
+        // ===
+        let now = chrono::Local::now();
+        let articles: Vec<&FeedEntry> = self
+            .articles
+            .iter()
+            .filter(|article| {
+                self.selected_feed.is_none()
+                    || self.selected_feed.as_ref() == Some(&article.feed_id)
+            })
+            .filter(|article| self.article_filter.matches(article, &now))
+            .collect();
+        // État « lu » lu en une fois, pour le filtre « Non lus » et le tri « Non lus d’abord ».
+        let read = if self.show_unread_only || self.sort_mode == SortMode::UnreadFirst {
+            self.runtime.block_on(self.data_api.read_flags(&articles))
+        } else {
+            vec![false; articles.len()]
+        };
+        let mut keyed: Vec<(bool, &FeedEntry)> = read
+            .into_iter()
+            .zip(articles)
+            .filter(|(is_read, _)| !self.show_unread_only || !is_read)
+            .collect();
+
+        fn tie_break(a: &FeedEntry, b: &FeedEntry) -> std::cmp::Ordering {
+            a.title.cmp(&b.title).then_with(|| a.url.cmp(&b.url))
+        }
+        fn newest_first(a: &FeedEntry, b: &FeedEntry) -> std::cmp::Ordering {
+            b.sort_date()
+                .cmp(&a.sort_date())
+                .then_with(|| tie_break(a, b))
+        }
+
+        match self.sort_mode {
+            SortMode::NewestFirst => keyed.sort_by(|(_, a), (_, b)| newest_first(a, b)),
+            SortMode::OldestFirst => keyed.sort_by(|(_, a), (_, b)| {
+                // les articles sans date sont classés à leur première récupération
+                a.sort_date()
+                    .cmp(&b.sort_date())
+                    .then_with(|| tie_break(a, b))
+            }),
+            SortMode::ByFeed => {
+                let titles: std::collections::HashMap<String, String> = self
+                    .feeds_snapshot()
+                    .into_iter()
+                    .map(|f| (f.id, f.title.to_lowercase()))
+                    .collect();
+                keyed.sort_by(|(_, a), (_, b)| {
+                    let ta = titles.get(&a.feed_id).unwrap_or(&a.feed_id);
+                    let tb = titles.get(&b.feed_id).unwrap_or(&b.feed_id);
+                    ta.cmp(tb)
+                        .then_with(|| a.feed_id.cmp(&b.feed_id))
+                        .then_with(|| newest_first(a, b))
+                });
+            }
+            SortMode::UnreadFirst => {
+                keyed.sort_by(|(ra, a), (rb, b)| ra.cmp(rb).then_with(|| newest_first(a, b)));
+            }
+        }
+        keyed.into_iter().map(|(_, a)| a).collect()
+    }
+
+    fn new_feed_allow_http(&self) -> AllowHttp {
+        if self.new_feed_allow_insecure {
+            AllowHttp::Always
+        } else {
+            self.config.network.allow_http
+        }
+    }
+
+    fn new_feed_url_input(&self) -> Result<String, String> {
+        // ===
+        // URL saisie dans le formulaire d’ajout: HTTPS, ou HTTP si la politique réseau ou
+        // l’exception « HTTP non chiffré » du flux l’autorise.
+        // ===
+        let url = self.new_feed_url.trim();
+        match Url::parse(url) {
+            Ok(parsed) if self.new_feed_allow_http().allows(&parsed) => Ok(url.to_string()),
+            Ok(parsed) if parsed.scheme() == "http" => Err(tr("add_feed.http_refused")),
+            Ok(_) => Err(tr("add_feed.http_only")),
+            Err(_) => Err(tr("add_feed.invalid_url")),
+        }
+    }
+
+    fn draw_url_conversion(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Adresse d’un site connu (subreddit, chaîne YouTube, dépôt GitHub): flux qui sera
+        // suivi à sa place, et choix du flux pour un dépôt.
+        // ===
+        let parsed = Url::parse(self.new_feed_url.trim()).ok();
+        let Some(rewrite) = parsed.as_ref().and_then(rewrite_feed_url) else {
+            return;
+        };
+        let notice = match &rewrite {
+            UrlRewrite::Feed(url) => tr_args("add_feed.converted", &[("url", url)]),
+            UrlRewrite::YouTubeHandle(_) => tr("add_feed.converted_youtube"),
+            UrlRewrite::GitHub { owner, repo } => {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(tr("add_feed.github_feed")).meta());
+                    egui::ComboBox::from_id_source("new_feed_github_kind")
+                        .selected_text(github_feed_label(self.new_feed_github_kind))
+                        .show_ui(ui, |ui| {
+                            for kind in GitHubFeed::ALL {
+                                ui.selectable_value(
+                                    &mut self.new_feed_github_kind,
+                                    kind,
+                                    github_feed_label(kind),
+                                );
+                            }
+                        });
+                });
+                let url = github_feed_url(owner, repo, self.new_feed_github_kind);
+                tr_args("add_feed.converted", &[("url", &url)])
+            }
+        };
+        let accent = self.config.theme.accent_color32();
+        ui.label(egui::RichText::new(notice).color(accent).small());
+    }
+
+    fn known_site_feed_url(&self, url: &str) -> Result<Option<String>, String> {
+        // ===
+        // Flux d’un site connu à suivre à la place de l’URL saisie (None pour une autre URL);
+        // lit la page de la chaîne pour un identifiant YouTube.
+        // ===
+        let parsed = Url::parse(url).ok();
+        let Some(rewrite) = parsed.as_ref().and_then(rewrite_feed_url) else {
+            return Ok(None);
+        };
+        let timeout = self.poll_config.request_timeout;
+        let github = self.new_feed_github_kind;
+        self.runtime
+            .block_on(resolve_rewrite(&self.client, &rewrite, github, timeout))
+            .map(|feed| Some(feed.to_string()))
+            .map_err(|e| match e {
+                PollError::NotAFeed { .. } => tr("add_feed.youtube_unresolved"),
+                other => other.to_string(),
+            })
+    }
+
+    fn start_feed_preview(&mut self, ctx: &egui::Context) {
+        // ===
+        // Récupère le flux saisi une fois, sans l’ajouter, et ouvre la fenêtre d’aperçu.
+        // ===
+        let url = match self.new_feed_url_input() {
+            Ok(url) => url,
+            Err(message) => {
+                self.push_toast(ToastLevel::Error, message);
+                return;
+            }
+        };
+        self.feed_preview = Some(FeedPreviewState::Loading(url.clone()));
+        let client = self.client.clone();
+        let timeout = self.poll_config.request_timeout;
+        let allow_http = self.new_feed_allow_http();
+        let tx = self.feed_preview_tx.clone();
+        let ctx = ctx.clone();
+        let rewrite = Url::parse(&url).ok().as_ref().and_then(rewrite_feed_url);
+        let github = self.new_feed_github_kind;
+        self.runtime.spawn(async move {
+            // Site connu: aperçu du flux converti (l’URL saisie si la conversion échoue).
+            let feed_url = match &rewrite {
+                Some(rewrite) => resolve_rewrite(&client, rewrite, github, timeout)
+                    .await
+                    .map_or_else(|_| url.clone(), |feed| feed.to_string()),
+                None => url.clone(),
+            };
+            let result = preview_feed(&client, &feed_url, timeout, allow_http)
+                .await
+                .map_err(|e| match e {
+                    PollError::NotAFeed {
+                        looks_like_html: true,
+                        ..
+                    } => tr("add_feed.html_without_feed"),
+                    PollError::NotAFeed { .. } => tr("add_feed.not_a_feed"),
+                    other => other.to_string(),
+                });
+            let _ = tx.send((url, result));
+            ctx.request_repaint();
+        });
+    }
+
+    fn receive_feed_preview(&mut self) {
+        // ===
+        // Résultat de l’aperçu (ignoré si la fenêtre a été fermée ou relancée entre-temps);
+        // le titre du canal remplit le champ « Titre » s’il est vide.
+        // ===
+        while let Ok((url, result)) = self.feed_preview_rx.try_recv() {
+            if !matches!(&self.feed_preview, Some(FeedPreviewState::Loading(u)) if *u == url) {
+                continue;
+            }
+            self.feed_preview = Some(match result {
+                Ok(preview) => {
+                    if self.new_feed_title.trim().is_empty() && !preview.title.is_empty() {
+                        self.new_feed_title = preview.title.clone();
+                    }
+                    FeedPreviewState::Ready(preview)
+                }
+                Err(message) => FeedPreviewState::Failed(message),
+            });
+        }
+    }
+
+    fn draw_feed_preview_window(&mut self, ctx: &egui::Context) {
+        // ===
+        // Fenêtre d’aperçu: titre, format, nombre d’articles et derniers titres du flux;
+        // l’abonnement n’a lieu qu’après confirmation.
+        // ===
+        let Some(state) = &self.feed_preview else {
+            return;
+        };
+        let secondary = self.config.theme.secondary_text_color32();
+        let mut open = true;
+        let mut subscribe = false;
+        let mut close = false;
+        egui::Window::new(tr("preview.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| match state {
+                FeedPreviewState::Loading(url) => {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(tr_args("preview.loading", &[("url", url)]));
+                    });
+                }
+                FeedPreviewState::Failed(message) => {
+                    ui.label(
+                        egui::RichText::new(tr_args("preview.failed", &[("error", message)]))
+                            .color(Color32::from_rgb(229, 57, 53)),
+                    );
+                    if ui.button(tr("common.close")).clicked() {
+                        close = true;
+                    }
+                }
+                FeedPreviewState::Ready(preview) => {
+                    let title = if preview.title.is_empty() {
+                        tr("preview.untitled")
+                    } else {
+                        preview.title.clone()
+                    };
+                    ui.label(egui::RichText::new(title).title());
+                    let count = tr_count("preview.articles", preview.item_count as i64, &[]);
+                    let meta = format!("{} · {}", feed_format_label(preview.format), count);
+                    ui.label(egui::RichText::new(meta).meta().color(secondary));
+                    if let Some(description) = &preview.description {
+                        let text: String = description.chars().take(200).collect();
+                        ui.label(egui::RichText::new(text).body());
+                    }
+                    if let Some(link) = &preview.site_link {
+                        ui.hyperlink(link);
+                    }
+                    if !preview.recent_titles.is_empty() {
+                        ui.separator();
+                        ui.label(egui::RichText::new(tr("preview.recent")).strong());
+                        for recent in &preview.recent_titles {
+                            ui.label(format!("• {}", recent));
+                        }
+                    }
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("preview.subscribe")).clicked() {
+                            subscribe = true;
+                        }
+                        if ui.button(tr("common.cancel")).clicked() {
+                            close = true;
+                        }
+                    });
+                }
+            });
+        if subscribe {
+            // Adresse finale si le flux a été déplacé entre-temps.
+            if let Some(FeedPreviewState::Ready(preview)) = self.feed_preview.take() {
+                self.new_feed_url = preview.url;
+            }
+            self.add_feed_from_input();
+        } else if close || !open {
+            self.feed_preview = None;
+        }
+    }
+
+    fn report_add_feed_error(&mut self, err: AddFeedError) {
+        // ===
+        // Flux déjà suivi: toast, et rappel sous le formulaire d’ajout avec un lien vers lui.
+        // ===
+        match err {
+            AddFeedError::Duplicate { id, title } => {
+                let message = tr_args("add_feed.duplicate", &[("title", &title)]);
+                self.push_toast(ToastLevel::Info, message);
+                self.duplicate_feed = Some((id, title));
+            }
+            AddFeedError::InvalidUrl(_) => {
+                self.push_toast(ToastLevel::Error, tr("add_feed.invalid_url"))
+            }
+            AddFeedError::Data(e) => self.push_toast(ToastLevel::Error, data_error_message(&e)),
+        }
+    }
+
+    fn add_feed_from_input(&mut self) {
+        // ===
+        // Ajoute un flux saisi manuellement (HTTPS, sauf HTTP autorisé) et rafraîchit.
+        // ===
+        let title_owned = self.new_feed_title.trim().to_string();
+        let url_owned = match self.new_feed_url_input() {
+            Ok(url) => url,
+            Err(message) => {
+                self.push_toast(ToastLevel::Error, message);
+                return;
+            }
+        };
+        let url_owned = match self.known_site_feed_url(&url_owned) {
+            Ok(Some(feed_url)) => {
+                let message = tr_args("add_feed.converted", &[("url", &feed_url)]);
+                self.push_toast(ToastLevel::Info, message);
+                feed_url
+            }
+            Ok(None) => url_owned,
+            Err(message) => {
+                self.push_toast(ToastLevel::Error, message);
+                return;
+            }
+        };
+
+        let (user_agent, headers) =
+            headers_from_input(&self.new_feed_user_agent, &self.new_feed_headers);
+        let id = format!("{}:{}", title_owned, Utc::now().timestamp_millis());
+        let descriptor = FeedDescriptor {
+            id,
+            title: if title_owned.is_empty() {
+                url_owned.clone()
+            } else {
+                title_owned.clone()
+            },
+            url: url_owned.clone(),
+            auth: self.new_feed_auth.to_auth(),
+            headers,
+            user_agent,
+            full_content: self.new_feed_full_content,
+            allow_insecure: self.new_feed_allow_insecure,
+            ..Default::default()
+        };
+
+        if let Err(e) = descriptor.validate_headers() {
+            self.push_toast(ToastLevel::Error, e.to_string());
+            return;
+        }
+
+        let added_id = descriptor.id.clone();
+        let added = self.runtime.block_on(self.service.add_feed(descriptor));
+        if let Err(e) = added {
+            self.report_add_feed_error(e);
+            return;
+        }
+        self.duplicate_feed = None;
+        // Les nouveaux articles arrivent par les évènements du service.
+        let events = self
+            .runtime
+            .block_on(self.service.refresh_feed(&added_id))
+            .unwrap_or_default();
+        let not_a_feed = events.iter().find_map(|evt| match evt {
+            Event::FetchCompleted(_, outcome) => match outcome.error_kind {
+                Some(FetchErrorKind::NotAFeed { looks_like_html }) => Some(looks_like_html),
+                _ => None,
+            },
+            _ => None,
+        });
+
+        // ===
+        // L’URL ne renvoie pas un flux: on annule l’ajout et on garde la saisie pour correction.
+        // ===
+        if let Some(looks_like_html) = not_a_feed {
+            self.saved(self.runtime.block_on(self.service.remove_feed(&added_id)));
+            let msg = if looks_like_html {
+                tr("add_feed.html_page")
+            } else {
+                tr("add_feed.not_a_feed")
+            };
+            self.push_toast(ToastLevel::Error, msg);
+            return;
+        }
+
+        // Sans titre saisi, le service a repris celui du canal (Event::FeedTitle).
+        let channel_title = events.into_iter().find_map(|evt| match evt {
+            Event::FeedTitle(_, title) => Some(title),
+            _ => None,
+        });
+        self.refresh_icons_async(vec![added_id]);
+        self.new_feed_title.clear();
+        self.new_feed_url.clear();
+        self.clear_new_feed_auth();
+        let added = if title_owned.is_empty() {
+            channel_title.unwrap_or(url_owned)
+        } else {
+            title_owned
+        };
+        self.push_toast(
+            ToastLevel::Success,
+            tr_args("toast.feed_added", &[("title", &added)]),
+        );
+    }
+
+    #[cfg(feature = "imap-feeds")]
+    fn draw_newsletter_form(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Boîte IMAP suivie comme un flux: un article par message non lu.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        egui::CollapsingHeader::new(egui::RichText::new(tr("newsletter.heading")).strong())
+            .id_source("newsletter_form")
+            .default_open(false)
+            .show(ui, |ui| {
+                let form = &mut self.newsletter_form;
+                let label = ui.label(egui::RichText::new(tr("add_feed.title")).meta());
+                ui.text_edit_singleline(&mut form.title)
+                    .labelled_by(label.id);
+                let label = ui.label(egui::RichText::new(tr("newsletter.host")).meta());
+                ui.text_edit_singleline(&mut form.host)
+                    .labelled_by(label.id);
+                ui.horizontal(|ui| {
+                    let label = ui.label(egui::RichText::new(tr("newsletter.port")).meta());
+                    ui.add(egui::TextEdit::singleline(&mut form.port).desired_width(48.0))
+                        .labelled_by(label.id);
+                    if ui.checkbox(&mut form.tls, tr("newsletter.tls")).changed() {
+                        form.port = if form.tls { "993" } else { "143" }.to_string();
+                    }
+                });
+                let label = ui.label(egui::RichText::new(tr("add_feed.username")).meta());
+                ui.text_edit_singleline(&mut form.username)
+                    .labelled_by(label.id);
+                let label = ui.label(egui::RichText::new(tr("add_feed.password")).meta());
+                ui.add(egui::TextEdit::singleline(&mut form.password).password(true))
+                    .labelled_by(label.id);
+                let label = ui.label(egui::RichText::new(tr("newsletter.folder")).meta());
+                ui.text_edit_singleline(&mut form.folder)
+                    .labelled_by(label.id);
+                let label = ui.label(egui::RichText::new(tr("newsletter.from")).meta());
+                ui.text_edit_singleline(&mut form.from_filter)
+                    .labelled_by(label.id)
+                    .on_hover_text(tr("newsletter.from_hover"));
+                ui.checkbox(&mut form.mark_seen, tr("newsletter.mark_seen"))
+                    .on_hover_text(tr("newsletter.mark_seen_hover"));
+
+                let notice = if form.tls {
+                    tr("add_feed.plaintext_notice")
+                } else {
+                    tr("newsletter.insecure_notice")
+                };
+                ui.label(egui::RichText::new(notice).color(secondary).small());
+
+                if ui.button(tr("add_feed.add")).clicked() {
+                    self.add_newsletter_from_input();
+                }
+            });
+    }
+
+    #[cfg(feature = "imap-feeds")]
+    fn add_newsletter_from_input(&mut self) {
+        // ===
+        // Ajoute la boîte saisie comme flux, puis la relève une première fois.
+        // ===
+        let form = &self.newsletter_form;
+        let (host, username) = (form.host.trim(), form.username.trim());
+        if host.is_empty() || username.is_empty() || form.password.is_empty() {
+            self.push_toast(ToastLevel::Error, tr("newsletter.missing_fields"));
+            return;
+        }
+        let Ok(port) = form.port.trim().parse::<u16>() else {
+            self.push_toast(ToastLevel::Error, tr("newsletter.invalid_port"));
+            return;
+        };
+        let mut mailbox =
+            rss_core::MailboxFeed::new(host, username, Secret::Plaintext(form.password.clone()));
+        mailbox.title = form.title.trim().to_string();
+        mailbox.port = port;
+        mailbox.tls = form.tls;
+        if !form.folder.trim().is_empty() {
+            mailbox.folder = form.folder.trim().to_string();
+        }
+        mailbox.from_filter = Some(form.from_filter.trim().to_string()).filter(|f| !f.is_empty());
+        mailbox.mark_seen_on_server = form.mark_seen;
+
+        let descriptor = match mailbox.descriptor() {
+            Ok(descriptor) => descriptor,
+            Err(e) => {
+                self.push_toast(ToastLevel::Error, e.to_string());
+                return;
+            }
+        };
+        let added_id = descriptor.id.clone();
+        let title = descriptor.title.clone();
+        if let Err(e) = self.runtime.block_on(self.service.add_feed(descriptor)) {
+            self.report_add_feed_error(e);
+            return;
+        }
+        self.duplicate_feed = None;
+        self.newsletter_form = NewsletterForm::default();
+        let _ = self.runtime.block_on(self.service.refresh_feed(&added_id));
+        self.push_toast(
+            ToastLevel::Success,
+            tr_args("toast.feed_added", &[("title", &title)]),
+        );
+    }
+
+    fn clear_new_feed_auth(&mut self) {
+        self.new_feed_auth = AuthInput::default();
+        self.new_feed_user_agent.clear();
+        self.new_feed_headers.clear();
+        self.new_feed_full_content = false;
+        self.new_feed_allow_insecure = false;
+    }
+
+    fn draw_header_editor_window(&mut self, ctx: &egui::Context) {
+        // ===
+        // Fenêtre d’édition des en-têtes d’un flux; validation avant enregistrement.
+        // ===
+        let Some(editor) = &mut self.header_editor else {
+            return;
+        };
+        let mut open = true;
+        let mut save = false;
+        let secondary = self.config.theme.secondary_text_color32();
+        egui::Window::new(tr_args(
+            "headers.window_title",
+            &[("title", &editor.feed.title)],
+        ))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            draw_headers_editor(ui, &mut editor.user_agent, &mut editor.headers);
+            ui.separator();
+            ui.label(egui::RichText::new(tr("headers.auth")).meta());
+            draw_auth_editor(ui, "header_editor_auth_kind", &mut editor.auth, secondary);
+            ui.separator();
+            ui.label(egui::RichText::new(tr("headers.cookie")).meta());
+            ui.add(
+                egui::TextEdit::singleline(&mut editor.cookie)
+                    .password(true)
+                    .hint_text("session=…"),
+            )
+            .on_hover_text(tr("headers.cookie_hint"));
+            ui.separator();
+            if ui.button(tr("common.save")).clicked() {
+                save = true;
+            }
+            if let Some(err) = &editor.error {
+                ui.label(
+                    egui::RichText::new(err)
+                        .color(Color32::from_rgb(229, 57, 53))
+                        .meta(),
+                );
+            }
+        });
+        if save {
+            let (user_agent, headers) = headers_from_input(&editor.user_agent, &editor.headers);
+            let feed = FeedDescriptor {
+                user_agent,
+                headers,
+                auth: editor.auth.to_auth(),
+                ..editor.feed.clone()
+            };
+            let secret = FeedSecret::with_cookie(&editor.cookie);
+            match feed.validate_headers().and_then(|()| secret.validate()) {
+                Ok(()) => {
+                    // Seuls les champs édités sont remplacés: l’état tenu par le poller depuis
+                    // l’ouverture de la fenêtre (échecs, URL déplacée…) est gardé.
+                    let feed_ids = [feed.id];
+                    let update = FeedUpdate::Request {
+                        user_agent: feed.user_agent,
+                        headers: feed.headers,
+                        auth: feed.auth,
+                    };
+                    let result = self.data_api.update_feeds_bulk(&feed_ids, update);
+                    self.saved(self.runtime.block_on(result));
+                    let result = self.data_api.set_feed_secret(&feed_ids[0], secret);
+                    self.saved(self.runtime.block_on(result));
+                    self.header_editor = None;
+                }
+                Err(e) => editor.error = Some(e.to_string()),
+            }
+        } else if !open {
+            self.header_editor = None;
+        }
+    }
+
+    fn draw_selector_editor_window(&mut self, ctx: &egui::Context) {
+        // ===
+        // Fenêtre du sélecteur de contenu d’un flux; un sélecteur refusé n’est pas enregistré.
+        // Enregistrer un sélecteur active aussi la récupération du contenu complet.
+        // ===
+        let Some(editor) = &mut self.selector_editor else {
+            return;
+        };
+        let mut open = true;
+        let mut save = false;
+        egui::Window::new(tr_args(
+            "selector.window_title",
+            &[("title", &editor.feed.title)],
+        ))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            let label = ui.label(tr("selector.label"));
+            ui.add(
+                egui::TextEdit::singleline(&mut editor.selector)
+                    .hint_text("article .post-body")
+                    .desired_width(280.0),
+            )
+            .labelled_by(label.id);
+            ui.label(
+                egui::RichText::new(tr("selector.help"))
+                    .color(self.config.theme.secondary_text_color32())
+                    .small(),
+            );
+            ui.separator();
+            if ui.button(tr("common.save")).clicked() {
+                save = true;
+            }
+            if let Some(err) = &editor.error {
+                ui.label(
+                    egui::RichText::new(err)
+                        .color(Color32::from_rgb(229, 57, 53))
+                        .meta(),
+                );
+            }
+        });
+        if save {
+            let selector = Some(editor.selector.trim().to_string()).filter(|s| !s.is_empty());
+            let feed = FeedDescriptor {
+                full_content: editor.feed.full_content || selector.is_some(),
+                content_selector: selector,
+                ..editor.feed.clone()
+            };
+            match feed.validate_content_selector() {
+                Ok(()) => {
+                    let feed_ids = [feed.id];
+                    let update = FeedUpdate::ContentSelector(feed.content_selector);
+                    let result = self.data_api.update_feeds_bulk(&feed_ids, update);
+                    self.saved(self.runtime.block_on(result));
+                    self.selector_editor = None;
+                }
+                Err(e) => editor.error = Some(e.to_string()),
+            }
+        } else if !open {
+            self.selector_editor = None;
+        }
+    }
+
+    fn draw_offline_banner(&mut self, ctx: &egui::Context) {
+        // ===
+        // Bandeau permanent tant que le réseau est coupé: le cache reste lisible, la lecture
+        // et les favoris sont enregistrés localement.
+        // ===
+        if !self.offline {
+            return;
+        }
+        egui::TopBottomPanel::top("offline_banner").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let color = ui.visuals().warn_fg_color;
+                ui.label(
+                    egui::RichText::new(tr("offline.banner"))
+                        .color(color)
+                        .strong(),
+                )
+                .on_hover_text(tr("offline.banner_hover"));
+                if self.refresh_task.is_none() && ui.small_button(tr("offline.retry")).clicked() {
+                    self.refresh_all();
+                }
+            });
+        });
+    }
+
+    fn draw_status_bar(&mut self, ctx: &egui::Context) {
+        // ===
+        // Barre d’état: bilan du dernier cycle de polling (flux, volume, nouveautés, échecs).
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        let text = match &self.last_cycle {
+            Some((summary, at)) => tr_args(
+                "status.last_cycle",
+                &[
+                    ("when", &format_relative_date(*at, Utc::now())),
+                    ("feeds", &summary.feeds_polled),
+                    ("bytes", &format_bytes(summary.bytes_downloaded)),
+                    ("new", &summary.new_articles),
+                    ("errors", &summary.errors),
+                    ("seconds", &format!("{:.1}", summary.duration.as_secs_f64())),
+                ],
+            ),
+            None => tr("status.no_cycle"),
+        };
+        egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
+            ui.label(egui::RichText::new(text).color(secondary).meta());
+        });
+    }
+
+    fn draw_left_panel(&mut self, ctx: &egui::Context) {
+        // ===
+        //
+        //
+        // Panneau gauche: ajout/recherche de flux, discover, paramètres, liste des flux.
+        //
+        //
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        // Largeur réglée par glisser-déposer, enregistrée avec la configuration à la fermeture.
+        let panel = egui::SidePanel::left("feeds_panel")
+            .resizable(true)
+            .default_width(self.config.ui.left_panel_width.clamp(200.0, 500.0))
+            .width_range(200.0..=500.0)
+            .show(ctx, |ui| {
+                ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
+                    if let Some(err) = &self.network_error {
+                        ui.label(
+                            egui::RichText::new(tr_args(
+                                "sidebar.network_error",
+                                &[("error", err)],
+                            ))
+                            .color(Color32::from_rgb(229, 57, 53))
+                            .meta(),
+                        )
+                        .on_hover_text(tr("sidebar.network_error_hover"));
+                        ui.add_space(2.0);
+                    }
+
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
+                        .show(ui, |ui| {
+                            let w = ui.available_width();
+                            let label = if self.polling_paused {
+                                tr("sidebar.resume")
+                            } else {
+                                tr("sidebar.pause")
+                            };
+                            let toggle = egui::SelectableLabel::new(self.polling_paused, label);
+                            if ui
+                                .add_sized(egui::vec2(w, 24.0), toggle)
+                                .on_hover_text(tr("sidebar.pause_hover"))
+                                .clicked()
+                            {
+                                self.polling_paused = !self.polling_paused;
+                                if self.polling_paused {
+                                    self.service.pause();
+                                } else {
+                                    self.service.resume();
+                                }
+                            }
+                        });
+
+                    ui.add_space(2.0);
+
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
+                        .show(ui, |ui| {
+                            ui.label(
+                                egui::RichText::new(tr("add_feed.heading"))
+                                    .strong()
+                                    .reading(),
+                            );
+                            ui.separator();
+
+                            let title_label =
+                                ui.label(egui::RichText::new(tr("add_feed.title")).meta());
+                            let title_id = egui::Id::new("new_feed_title");
+                            let title_resp = ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut self.new_feed_title)
+                                        .id(title_id),
+                                )
+                                .labelled_by(title_label.id);
+
+                            let url_label =
+                                ui.label(egui::RichText::new(tr("add_feed.url")).meta());
+                            let url_id = egui::Id::new("new_feed_url");
+                            let url_resp = ui
+                                .add(egui::TextEdit::singleline(&mut self.new_feed_url).id(url_id))
+                                .labelled_by(url_label.id);
+
+                            // Entrée rapide: touche Entrée dans l'un des champs -> Ajouter
+                            // Gestion fiable d'Enter: on vérifie la perte de focus due à Enter
+                            let pressed_enter = ui.ctx().input(|i| i.key_pressed(egui::Key::Enter));
+                            if title_resp.lost_focus() && pressed_enter {
+                                ui.memory_mut(|m| m.request_focus(url_id));
+                            }
+                            if url_resp.lost_focus() && pressed_enter {
+                                self.add_feed_from_input();
+                            }
+                            self.draw_url_conversion(ui);
+
+                            egui::CollapsingHeader::new(tr("add_feed.auth"))
+                                .default_open(false)
+                                .show(ui, |ui| {
+                                    draw_auth_editor(
+                                        ui,
+                                        "new_feed_auth_kind",
+                                        &mut self.new_feed_auth,
+                                        secondary,
+                                    );
+                                });
+
+                            egui::CollapsingHeader::new(tr("add_feed.headers"))
+                                .default_open(false)
+                                .show(ui, |ui| {
+                                    draw_headers_editor(
+                                        ui,
+                                        &mut self.new_feed_user_agent,
+                                        &mut self.new_feed_headers,
+                                    );
+                                });
+
+                            ui.checkbox(
+                                &mut self.new_feed_full_content,
+                                tr("add_feed.full_content"),
+                            )
+                            .on_hover_text(tr("add_feed.full_content_hover"));
+                            if self.new_feed_url.trim_start().starts_with("http://") {
+                                ui.checkbox(
+                                    &mut self.new_feed_allow_insecure,
+                                    tr("add_feed.allow_http"),
+                                )
+                                .on_hover_text(tr("add_feed.allow_http_hover"));
+                            }
+
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .button(tr("add_feed.add"))
+                                    .accessible(
+                                        egui::WidgetType::Button,
+                                        &tr("add_feed.add_accessible"),
+                                    )
+                                    .clicked()
+                                {
                                     self.add_feed_from_input();
                                 }
-                                if ui.button("🗑 Effacer").clicked() {
+                                if ui
+                                    .button(tr("add_feed.preview"))
+                                    .on_hover_text(tr("add_feed.preview_hover"))
+                                    .clicked()
+                                {
+                                    self.start_feed_preview(ui.ctx());
+                                }
+                                if ui.button(tr("add_feed.clear")).clicked() {
                                     self.new_feed_title.clear();
                                     self.new_feed_url.clear();
-                                    self.add_feedback = None;
+                                    self.clear_new_feed_auth();
+                                    self.new_feed_github_kind = GitHubFeed::default();
+                                    self.duplicate_feed = None;
+                                }
+                            });
+
+                            if let Some((id, title)) = self.duplicate_feed.clone() {
+                                let accent = self.config.theme.accent_color32();
+                                let notice = tr_args("add_feed.duplicate", &[("title", &title)]);
+                                ui.label(egui::RichText::new(notice).color(accent));
+                                ui.horizontal(|ui| {
+                                    if ui.small_button(tr("add_feed.show_feed")).clicked() {
+                                        self.selected_feed = Some(id);
+                                        self.current_view = AppView::ArticleList;
+                                        self.reset_pagination();
+                                        self.duplicate_feed = None;
+                                    }
+                                    if ui
+                                        .small_button("✖")
+                                        .icon_hint(&tr("add_feed.dismiss"))
+                                        .clicked()
+                                    {
+                                        self.duplicate_feed = None;
+                                    }
+                                });
+                            }
+                        });
+
+                    #[cfg(feature = "imap-feeds")]
+                    {
+                        ui.add_space(2.0);
+                        egui::Frame::group(ui.style())
+                            .inner_margin(egui::Margin::symmetric(2.0, 2.0))
+                            .show(ui, |ui| self.draw_newsletter_form(ui));
+                    }
+
+                    ui.add_space(2.0);
+
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
+                        .show(ui, |ui| {
+                            let w = ui.available_width();
+                            let btn = egui::Button::new(
+                                egui::RichText::new(tr("sidebar.discover")).strong(),
+                            );
+                            if ui.add_sized(egui::vec2(w, 28.0), btn).clicked() {
+                                self.current_view = AppView::DiscoverHome;
+                                self.selected_feed = None;
+                            }
+                        });
+
+                    ui.add_space(2.0);
+
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
+                        .show(ui, |ui| {
+                            let w = ui.available_width();
+                            let btn =
+                                egui::Button::new(egui::RichText::new(tr("sidebar.tags")).strong());
+                            if ui.add_sized(egui::vec2(w, 28.0), btn).clicked() {
+                                self.current_view = AppView::Tags;
+                                self.selected_feed = None;
+                            }
+                        });
+
+                    ui.add_space(2.0);
+
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
+                        .show(ui, |ui| {
+                            let w = ui.available_width();
+                            let btn = egui::Button::new(
+                                egui::RichText::new(tr("sidebar.settings")).strong(),
+                            );
+                            if ui.add_sized(egui::vec2(w, 28.0), btn).clicked() {
+                                self.current_view = AppView::Settings;
+                                self.selected_feed = None;
+                            }
+                        });
+
+                    ui.add_space(2.0);
+
+                    // Raccourci: Ctrl+F pour mettre le focus sur la recherche
+                    if ui.input_mut(|i| i.modifiers.ctrl && i.key_pressed(egui::Key::F)) {
+                        self.focus_search_next = true;
+                    }
+
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::symmetric(6.0, 6.0))
+                        .show(ui, |ui| {
+                            ui.label(egui::RichText::new(tr("sidebar.search")).strong().reading());
+                            ui.separator();
+                            let id = egui::Id::new("feed_search_input");
+                            if self.focus_search_next {
+                                ui.memory_mut(|m| m.request_focus(id));
+                                self.focus_search_next = false;
+                            }
+                            ui.add(egui::TextEdit::singleline(&mut self.feed_search).id(id));
+                        });
+
+                    ui.add_space(10.0);
+
+                    ui.group(|group| {
+                        group.vertical(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(tr("sidebar.feeds")).strong().reading(),
+                                );
+                                // Ordre de tabulation = ordre visuel (de gauche à droite).
+                                if ui.small_button(tr("sidebar.all")).clicked() {
+                                    self.selected_feed = None;
+                                    self.current_view = AppView::ArticleList;
+                                    self.reset_pagination();
+                                    let all =
+                                        self.runtime.block_on(self.data_api.list_all_articles());
+                                    self.articles = all;
+                                    self.sort_articles();
+                                }
+                                if ui
+                                    .small_button("A→Z")
+                                    .icon_hint(&tr("sidebar.sort_az"))
+                                    .clicked()
+                                {
+                                    self.sort_feeds_alphabetically();
+                                }
+                                if ui
+                                    .small_button("⟳")
+                                    .icon_hint(&tr("sidebar.refresh_all"))
+                                    .clicked()
+                                {
+                                    self.refresh_all();
                                 }
                             });
+                            ui.separator();
+
+                            egui::ScrollArea::vertical()
+                                .auto_shrink([false, true])
+                                .show(ui, |ui| {
+                                    // Flux sans dossier d’abord, puis un intertitre par dossier.
+                                    let feeds: Vec<FeedDescriptor> =
+                                        feeds_by_category(self.filtered_feeds())
+                                            .into_iter()
+                                            .flat_map(|(_, group)| group)
+                                            .collect();
+
+                                    for (i, feed) in feeds.iter().enumerate() {
+                                        let previous = i
+                                            .checked_sub(1)
+                                            .and_then(|p| feeds[p].category.as_deref());
+                                        if let Some(category) =
+                                            feed.category.as_deref().filter(|c| {
+                                                previous.is_none_or(|p| !p.eq_ignore_ascii_case(c))
+                                            })
+                                        {
+                                            ui.add_space(4.0);
+                                            ui.label(
+                                                egui::RichText::new(format!("📁 {}", category))
+                                                    .color(secondary)
+                                                    .strong(),
+                                            );
+                                        }
+                                        let is_selected =
+                                            self.selected_feed.as_ref() == Some(&feed.id);
+
+                                        ui.horizontal(|ui| {
+                                            // Zone cliquable: toute la bande gauche (jusqu'aux boutons), texte aligné à gauche
+                                            let _spacing = ui.spacing().item_spacing.x + 8.0; // réservé si besoin
+                                            let right_controls_width = 80.0; // réserve fixe pour ⟳ et 🗑 + marges
+                                            let left_width = (ui.available_width()
+                                                - right_controls_width)
+                                                .max(120.0);
+                                            let font_id = egui::TextStyle::Body.resolve(ui.style());
+                                            let row_height = (font_id.size + 10.0).max(24.0);
+                                            let (rect, response_bg) = ui.allocate_exact_size(
+                                                egui::vec2(left_width, row_height),
+                                                egui::Sense::click(),
+                                            );
+                                            // Peindre un fond de sélection/hover plein sur toute la zone
+                                            if is_selected || response_bg.hovered() {
+                                                let visuals = ui.style().visuals.clone();
+                                                let fill = if is_selected {
+                                                    visuals.selection.bg_fill
+                                                } else {
+                                                    visuals.widgets.hovered.weak_bg_fill
+                                                };
+                                                ui.painter().rect_filled(rect, 4.0, fill);
+                                            }
+                                            // Curseur main sur hover
+                                            if response_bg.hovered() {
+                                                ui.ctx().set_cursor_icon(
+                                                    egui::CursorIcon::PointingHand,
+                                                );
+                                            }
+                                            // Icône du site, ou pastille colorée du flux à défaut
+                                            let icon_rect = egui::Rect::from_center_size(
+                                                egui::pos2(rect.left() + 16.0, rect.center().y),
+                                                egui::vec2(16.0, 16.0),
+                                            );
+                                            match self.feed_icon_texture(ui.ctx(), &feed.id) {
+                                                Some(icon) => {
+                                                    let uv = egui::Rect::from_min_max(
+                                                        egui::pos2(0.0, 0.0),
+                                                        egui::pos2(1.0, 1.0),
+                                                    );
+                                                    ui.painter().image(
+                                                        icon.id(),
+                                                        icon_rect,
+                                                        uv,
+                                                        Color32::WHITE,
+                                                    );
+                                                }
+                                                None => {
+                                                    ui.painter().circle_filled(
+                                                        icon_rect.center(),
+                                                        5.0,
+                                                        color_for_feed(&feed.id),
+                                                    );
+                                                }
+                                            }
+                                            // Dessin du libellé aligné à gauche (pas de widget au-dessus pour que tout le rect capte le clic)
+                                            let text_pos =
+                                                egui::pos2(rect.left() + 30.0, rect.center().y);
+                                            // Flux désactivé ou en sourdine (🔕): libellé grisé
+                                            let muted_until = feed
+                                                .muted_until
+                                                .filter(|_| feed.is_muted_at(Utc::now()));
+                                            let dimmed = feed.disabled || muted_until.is_some();
+                                            let text_color = if dimmed {
+                                                ui.visuals().weak_text_color()
+                                            } else {
+                                                ui.visuals().text_color()
+                                            };
+                                            let label = if muted_until.is_some() {
+                                                format!("🔕 {}", feed.title)
+                                            } else {
+                                                feed.title.clone()
+                                            };
+                                            // Titre coupé (…) à la largeur de la bande;
+                                            // complet dans l’infobulle.
+                                            let mut job = egui::text::LayoutJob::simple_singleline(
+                                                label, font_id, text_color,
+                                            );
+                                            job.wrap = egui::text::TextWrapping::truncate_at_width(
+                                                (rect.right() - text_pos.x - 4.0).max(0.0),
+                                            );
+                                            let galley = ui.painter().layout_job(job);
+                                            let galley_pos = egui::pos2(
+                                                text_pos.x,
+                                                text_pos.y - galley.size().y / 2.0,
+                                            );
+                                            ui.painter().galley(galley_pos, galley, text_color);
+                                            if let Some(until) = muted_until {
+                                                // L’indicateur disparaît de lui-même à l’échéance.
+                                                let left = until - Utc::now();
+                                                ui.ctx().request_repaint_after(
+                                                    left.to_std().unwrap_or_default(),
+                                                );
+                                            }
+                                            let response_bg = response_bg.accessible_selected(
+                                                is_selected,
+                                                &feed_row_label(feed, muted_until.is_some()),
+                                            );
+                                            let row_clicked = response_bg.clicked();
+
+                                            if row_clicked {
+                                                self.selected_feed = Some(feed.id.clone());
+                                                self.current_view = AppView::ArticleList;
+                                                self.reset_pagination();
+                                                let persisted = self.runtime.block_on(
+                                                    self.data_api.list_articles(&feed.id),
+                                                );
+                                                if !persisted.is_empty() {
+                                                    article_list::replace_feed(
+                                                        &mut self.articles,
+                                                        &feed.id,
+                                                        persisted,
+                                                    );
+                                                    self.previews.retain(&self.articles);
+                                                    self.rebuild_title_clusters();
+                                                } else {
+                                                    self.runtime.block_on(
+                                                        self.service.refresh_feed(&feed.id),
+                                                    );
+                                                }
+                                            }
+                                            response_bg.context_menu(|ui| {
+                                                if ui.button(tr("feed.mark_read")).clicked() {
+                                                    self.mark_feed_read_async(
+                                                        ui.ctx(),
+                                                        &feed.id,
+                                                        None,
+                                                    );
+                                                    ui.close_menu();
+                                                }
+                                                let days = self.config.ui.mark_read_older_than_days;
+                                                if days > 0
+                                                    && ui
+                                                        .button(tr_args(
+                                                            "feed.mark_read_older",
+                                                            &[("days", &days)],
+                                                        ))
+                                                        .clicked()
+                                                {
+                                                    let before = self.mark_read_cutoff();
+                                                    self.mark_feed_read_async(
+                                                        ui.ctx(),
+                                                        &feed.id,
+                                                        before,
+                                                    );
+                                                    ui.close_menu();
+                                                }
+                                                ui.separator();
+                                                if ui.button(tr("feed.move_up")).clicked() {
+                                                    self.move_feed(&feed.id, true);
+                                                    ui.close_menu();
+                                                }
+                                                if ui.button(tr("feed.move_down")).clicked() {
+                                                    self.move_feed(&feed.id, false);
+                                                    ui.close_menu();
+                                                }
+                                                ui.separator();
+                                                ui.menu_button(tr("feed.mute"), |ui| {
+                                                    for (label, hours) in MUTE_CHOICES {
+                                                        if ui.button(tr(label)).clicked() {
+                                                            let until = Utc::now()
+                                                                + chrono::Duration::hours(hours);
+                                                            self.mute_feed(&feed.id, Some(until));
+                                                            ui.close_menu();
+                                                        }
+                                                    }
+                                                    if muted_until.is_some()
+                                                        && ui.button(tr("feed.unmute")).clicked()
+                                                    {
+                                                        self.mute_feed(&feed.id, None);
+                                                        ui.close_menu();
+                                                    }
+                                                });
+                                                let mut full_content = feed.full_content;
+                                                if ui
+                                                    .checkbox(
+                                                        &mut full_content,
+                                                        tr("add_feed.full_content"),
+                                                    )
+                                                    .changed()
+                                                {
+                                                    let updated = FeedDescriptor {
+                                                        full_content,
+                                                        ..feed.clone()
+                                                    };
+                                                    let result = self
+                                                        .runtime
+                                                        .block_on(self.data_api.add_feed(updated));
+                                                    self.saved(result);
+                                                    ui.close_menu();
+                                                }
+                                                ui.menu_button(tr("feed.retention"), |ui| {
+                                                    for (retention, label) in retention_choices() {
+                                                        let selected = feed.retention == retention;
+                                                        if ui.radio(selected, label).clicked() {
+                                                            let updated = FeedDescriptor {
+                                                                retention,
+                                                                ..feed.clone()
+                                                            };
+                                                            let result = self.runtime.block_on(
+                                                                self.data_api.add_feed(updated),
+                                                            );
+                                                            self.saved(result);
+                                                            ui.close_menu();
+                                                        }
+                                                    }
+                                                });
+                                                if ui.button(tr("feed.headers")).clicked() {
+                                                    let cookie = self
+                                                        .data_api
+                                                        .get_feed_secret(&feed.id)
+                                                        .and_then(|s| s.cookie?.reveal())
+                                                        .unwrap_or_default();
+                                                    self.header_editor = Some(HeaderEditor {
+                                                        user_agent: feed
+                                                            .user_agent
+                                                            .clone()
+                                                            .unwrap_or_default(),
+                                                        headers: feed
+                                                            .headers
+                                                            .clone()
+                                                            .unwrap_or_default(),
+                                                        auth: AuthInput::from_auth(
+                                                            feed.auth.as_ref(),
+                                                        ),
+                                                        feed: feed.clone(),
+                                                        cookie,
+                                                        error: None,
+                                                    });
+                                                    ui.close_menu();
+                                                }
+                                                if ui.button(tr("feed.content_selector")).clicked()
+                                                {
+                                                    self.selector_editor = Some(SelectorEditor {
+                                                        selector: feed
+                                                            .content_selector
+                                                            .clone()
+                                                            .unwrap_or_default(),
+                                                        feed: feed.clone(),
+                                                        error: None,
+                                                    });
+                                                    ui.close_menu();
+                                                }
+                                            });
+                                            // Identifiants de l’URL masqués dans l’infobulle.
+                                            let url = feed.redacted_url();
+                                            let hover = match muted_until {
+                                                Some(until) => tr_args(
+                                                    "feed.muted_hover",
+                                                    &[
+                                                        ("url", &url),
+                                                        ("until", &format_date(until)),
+                                                    ],
+                                                ),
+                                                None => url,
+                                            };
+                                            response_bg.on_hover_text(format!(
+                                                "{}\n{}",
+                                                feed.title, hover
+                                            ));
+
+                                            // Actions à droite de la bande, ajoutées dans
+                                            // l’ordre visuel pour que Tab les parcoure ainsi.
+                                            if feed.disabled {
+                                                if ui
+                                                    .small_button(tr("feed.reenable"))
+                                                    .on_hover_text(tr_args(
+                                                        "feed.disabled_hover",
+                                                        &[("count", &feed.consecutive_failures)],
+                                                    ))
+                                                    .clicked()
+                                                {
+                                                    let result = self.runtime.block_on(
+                                                        self.data_api.reenable_feed(&feed.id),
+                                                    );
+                                                    self.saved(result);
+                                                }
+                                            } else if ui
+                                                .small_button("⟳")
+                                                .icon_hint(&tr_args(
+                                                    "feed.refresh",
+                                                    &[("title", &feed.title)],
+                                                ))
+                                                .clicked()
+                                            {
+                                                self.runtime
+                                                    .block_on(self.service.refresh_feed(&feed.id));
+                                            }
+                                            if ui
+                                                .small_button("🗑")
+                                                .icon_hint(&tr_args(
+                                                    "feed.remove",
+                                                    &[("title", &feed.title)],
+                                                ))
+                                                .clicked()
+                                            {
+                                                self.remove_feed_undoable(&feed.id);
+                                            }
+                                        });
+                                    }
+
+                                    if feeds.is_empty() && !self.feed_search.is_empty() {
+                                        ui.label(
+                                            egui::RichText::new(tr("sidebar.no_match"))
+                                                .color(secondary)
+                                                .meta(),
+                                        );
+                                    }
+                                });
+                        });
+                    });
+                });
+            });
+        self.config.ui.left_panel_width = panel.response.rect.width();
+    }
+
+    fn draw_main_content(&mut self, ctx: &egui::Context) {
+        // ===
+        //
+        //
+        // Contenu central: route vers la vue courante.
+        //
+        //
+        // ===
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.draw_current_view(ui);
+        });
+        if let Some(id) = self.toasts.show(ctx, &tr("common.undo")) {
+            self.undo(id);
+        }
+    }
+
+    fn push_undo(&mut self, message: String, action: UndoAction) {
+        // ===
+        // Empile une opération annulable et l’annonce par un toast « Annuler ».
+        // ===
+        let id = self.next_undo_id;
+        self.next_undo_id += 1;
+        self.undo_stack.push_back((id, action));
+        while self.undo_stack.len() > UNDO_DEPTH {
+            self.undo_stack.pop_front();
+        }
+        self.toasts.push_undo(ToastLevel::Info, message, id);
+    }
+
+    fn undo(&mut self, id: u64) {
+        // ===
+        // Annule l’opération `id` si elle est encore dans la pile (sinon, rien).
+        // ===
+        let Some(index) = self.undo_stack.iter().position(|(i, _)| *i == id) else {
+            return;
+        };
+        let Some((_, action)) = self.undo_stack.remove(index) else {
+            return;
+        };
+        match action {
+            UndoAction::RemoveFeed(backup) => {
+                let feed_id = backup.feed.id.clone();
+                let title = backup.feed.title.clone();
+                let result = self.runtime.block_on(self.data_api.restore_feed(*backup));
+                if self.saved(result).is_some() {
+                    let articles = self.runtime.block_on(self.data_api.list_articles(&feed_id));
+                    article_list::replace_feed(&mut self.articles, &feed_id, articles);
+                    self.previews.retain(&self.articles);
+                    self.rebuild_title_clusters();
+                    self.push_toast(
+                        ToastLevel::Success,
+                        tr_args("toast.feed_restored", &[("title", &title)]),
+                    );
+                }
+            }
+            UndoAction::MarkRead(marks) => {
+                let result = self.runtime.block_on(self.data_api.unmark_read(&marks));
+                if let Some(count) = self.saved(result) {
+                    self.push_toast(
+                        ToastLevel::Success,
+                        tr_count("toast.unread_restored", count as i64, &[]),
+                    );
+                }
+            }
+        }
+    }
+
+    fn remove_feed_undoable(&mut self, feed_id: &str) {
+        // ===
+        // Supprime un flux en gardant de quoi l’annuler (toast « Annuler »).
+        // ===
+        let result = self
+            .runtime
+            .block_on(self.data_api.remove_feed_with_backup(feed_id));
+        let Some(Some(backup)) = self.saved(result) else {
+            return;
+        };
+        article_list::remove_feeds(&mut self.articles, |id| id == feed_id);
+        self.articles_revision += 1;
+        if self.selected_feed.as_deref() == Some(feed_id) {
+            self.selected_feed = None;
+            self.reset_pagination();
+        }
+        let message = tr_args("toast.feed_removed", &[("title", &backup.feed.title)]);
+        self.push_undo(message, UndoAction::RemoveFeed(Box::new(backup)));
+    }
+
+    fn draw_current_view(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Route vers la vue courante.
+        // ===
+        match &self.current_view {
+            AppView::ArticleList | AppView::ArticleDetail(_)
+                if self.config.ui.layout == Layout::SplitHorizontal =>
+            {
+                self.draw_split_view(ui)
+            }
+            AppView::ArticleList => self.draw_article_list(ui),
+            AppView::ArticleDetail(article) => self.draw_article_detail(ui, (**article).clone()),
+            AppView::DiscoverHome => self.draw_discover_home(ui),
+            AppView::DiscoverCategory(name) => self.draw_discover_category(ui, name.clone()),
+            AppView::Settings => self.draw_settings(ui),
+            AppView::FeedHealth => self.draw_feed_health(ui),
+            AppView::FeedManager => self.draw_feed_manager(ui),
+            AppView::Tags => self.draw_tags(ui),
+            AppView::Logs => self.draw_logs(ui),
+            AppView::Stats => self.draw_stats(ui),
+        }
+    }
+
+    fn draw_split_view(&mut self, ui: &mut egui::Ui) {
+        // ===
+        //
+        //
+        // Mode côte à côte: liste à gauche, article sélectionné à droite. Le séparateur se
+        // déplace à la souris; sa position est gardée dans `split_list_width`, enregistrée avec
+        // la configuration.
+        //
+        //
+        // ===
+        let max_width = (ui.available_width() - SPLIT_MIN_READING_WIDTH).max(SPLIT_MIN_LIST_WIDTH);
+        let list_width = self
+            .config
+            .ui
+            .split_list_width
+            .clamp(SPLIT_MIN_LIST_WIDTH, max_width);
+        let panel = egui::SidePanel::left("article_list_pane")
+            .resizable(true)
+            .default_width(list_width)
+            .width_range(SPLIT_MIN_LIST_WIDTH..=max_width)
+            .show_inside(ui, |ui| self.draw_article_list(ui));
+        self.config.ui.split_list_width = panel.response.rect.width();
+
+        egui::CentralPanel::default().show_inside(ui, |ui| match &self.current_view {
+            AppView::ArticleDetail(article) => self.draw_article_detail(ui, (**article).clone()),
+            _ => {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(50.0);
+                    ui.label(egui::RichText::new(tr("split.select_article")).title());
+                    let hint = egui::RichText::new(tr("split.navigation_hint")).meta();
+                    ui.label(hint.color(self.config.theme.secondary_text_color32()));
+                });
+            }
+        });
+    }
+
+    fn displayed_articles(&mut self) -> Rc<Vec<DedupedEntry>> {
+        // ===
+        // Articles de la liste, dans l’ordre affiché; recalculés seulement quand les articles,
+        // l’état de lecture, les filtres ou le tri ont changé depuis la dernière image.
+        // ===
+        let feed_titles = (self.sort_mode == SortMode::ByFeed).then(|| {
+            self.feeds_snapshot()
+                .into_iter()
+                .map(|f| (f.id, f.title))
+                .collect()
+        });
+        let key = ArticleViewKey {
+            articles_revision: self.articles_revision,
+            read_revision: self.data_api.read_revision(),
+            selected_feed: self.selected_feed.clone(),
+            filter: self.article_filter.clone(),
+            today: chrono::Local::now().date_naive(),
+            show_unread_only: self.show_unread_only,
+            sort_mode: self.sort_mode,
+            group_similar_titles: self.config.ui.group_similar_titles,
+            feed_titles,
+        };
+        if let Some(view) = self.article_view.get(&key) {
+            return view;
+        }
+        let view = self.compute_displayed_articles();
+        self.article_view.store(key, view)
+    }
+
+    fn compute_displayed_articles(&self) -> Vec<DedupedEntry> {
+        // ===
+        // Articles de la liste, dans l’ordre affiché (filtrés, dédoublonnés, regroupés).
+        // ===
+        let filtered: Vec<FeedEntry> = self.filtered_articles().into_iter().cloned().collect();
+        // vue agrégée: un même article publié par plusieurs flux n’apparaît qu’une fois
+        if self.selected_feed.is_none() {
+            let deduped = dedup_entries(filtered);
+            if self.config.ui.group_similar_titles {
+                self.collapse_similar_titles(deduped)
+            } else {
+                deduped
+            }
+        } else {
+            filtered
+                .into_iter()
+                .map(|entry| DedupedEntry {
+                    feed_ids: vec![entry.feed_id.clone()],
+                    entry,
+                    similar: Vec::new(),
+                })
+                .collect()
+        }
+    }
+
+    fn collapse_similar_titles(&self, articles: Vec<DedupedEntry>) -> Vec<DedupedEntry> {
+        // ===
+        // Replie chaque article sous le premier affiché de son groupe de titres proches.
+        // ===
+        let mut first_of: HashMap<usize, usize> = HashMap::new();
+        let mut collapsed: Vec<DedupedEntry> = Vec::with_capacity(articles.len());
+        for article in articles {
+            let Some(cluster) = self.title_clusters.cluster_of(&article.entry) else {
+                collapsed.push(article);
+                continue;
+            };
+            match first_of.get(&cluster) {
+                Some(&index) => collapsed[index].similar.push(article.entry),
+                None => {
+                    first_of.insert(cluster, collapsed.len());
+                    collapsed.push(article);
+                }
+            }
+        }
+        collapsed
+    }
+
+    fn follow_open_article(&mut self, articles: &[DedupedEntry]) {
+        // ===
+        // Garde la sélection sur l’article ouvert: la liste bouge quand des articles arrivent
+        // ou quand le tri change.
+        // ===
+        let AppView::ArticleDetail(open) = &self.current_view else {
+            return;
+        };
+        let identity = open.identity();
+        let current = self.selected_article_index.and_then(|i| articles.get(i));
+        if current.is_some_and(|a| a.entry.identity() == identity) {
+            return;
+        }
+        if let Some(index) = articles.iter().position(|a| a.entry.identity() == identity) {
+            self.selected_article_index = Some(index);
+        }
+    }
+
+    fn navigate_articles(
+        &mut self,
+        ui: &egui::Ui,
+        articles: &[DedupedEntry],
+        row_height: f32,
+        per_page: usize,
+    ) {
+        // ===
+        //
+        //
+        // Navigation au clavier (hors saisie de texte): ↓/j et ↑/k déplacent la sélection,
+        // Entrée ouvre l’article. En mode côte à côte, l’article sélectionné s’ouvre aussitôt à
+        // droite. La liste défile et se pagine pour garder la sélection visible.
+        //
+        //
+        // ===
+        if articles.is_empty() || ui.ctx().wants_keyboard_input() {
+            return;
+        }
+        let (down, up, enter) = ui.input(|i| {
+            (
+                i.key_pressed(egui::Key::ArrowDown) || i.key_pressed(egui::Key::J),
+                i.key_pressed(egui::Key::ArrowUp) || i.key_pressed(egui::Key::K),
+                i.key_pressed(egui::Key::Enter),
+            )
+        });
+        let last = articles.len() - 1;
+        let current = self.selected_article_index.map(|i| i.min(last));
+        let target = match current {
+            None if down || up => Some(0),
+            Some(i) if down => Some((i + 1).min(last)),
+            Some(i) if up => Some(i.saturating_sub(1)),
+            _ => None,
+        };
+        let split = self.config.ui.layout == Layout::SplitHorizontal;
+        if let Some(index) = target.filter(|t| Some(*t) != current) {
+            self.selected_article_index = Some(index);
+            self.article_pages = self.article_pages.max(index / per_page + 1);
+            let top = index as f32 * row_height;
+            let viewport = ui.available_height().max(row_height);
+            if top < self.list_scroll {
+                self.pending_list_scroll = Some(top);
+            } else if top + row_height > self.list_scroll + viewport {
+                self.pending_list_scroll = Some(top + row_height - viewport);
+            }
+            if split {
+                self.open_article(&articles[index].entry);
+            }
+        } else if enter && !split {
+            if let Some(index) = current {
+                self.open_article(&articles[index].entry);
+            }
+        }
+    }
+
+    fn draw_article_list(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Liste/agrégat d’articles avec actions rapides, paginée par `articles_per_page`.
+        // ===
+        let articles = self.displayed_articles();
+        let total = articles.len();
+        let aggregated_view = self.selected_feed.is_none();
+        let row_height = self.article_card_height(ui, aggregated_view);
+        let per_page = self.config.ui.articles_per_page.max(1);
+        self.follow_open_article(&articles);
+        if let Some(offset) = self.pending_list_scroll {
+            // Défilement restauré: assez de pages chargées pour l’atteindre.
+            let rows = (offset / row_height).ceil() as usize + 1;
+            self.article_pages = self.article_pages.max(rows.div_ceil(per_page));
+        }
+        let visible = (self.article_pages * per_page).min(total);
+        let now = Utc::now();
+
+        ui.horizontal(|ui| {
+            ui.heading(egui::RichText::new(tr("list.heading")).heading());
+            if self.refresh_task.is_some() {
+                let color = self.config.theme.secondary_text_color32();
+                match self.service.refresh_progress() {
+                    Some((done, total)) if total > 0 => {
+                        let status = tr_args(
+                            "list.refresh_progress",
+                            &[("done", &done), ("total", &total)],
+                        );
+                        ui.add(
+                            egui::ProgressBar::new(done as f32 / total as f32).desired_width(120.0),
+                        );
+                        ui.label(egui::RichText::new(status).meta().color(color));
+                    }
+                    _ => {
+                        ui.spinner();
+                        let status = egui::RichText::new(tr("list.refreshing")).meta();
+                        ui.label(status.color(color));
+                    }
+                }
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let count = tr_args("list.count", &[("visible", &visible), ("total", &total)]);
+                ui.label(egui::RichText::new(count).meta());
+                ui.separator();
+                if ui
+                    .toggle_value(&mut self.show_unread_only, tr("list.unread"))
+                    .changed()
+                {
+                    self.reset_pagination();
+                }
+                ui.separator();
+                let previous_mode = self.sort_mode;
+                egui::ComboBox::from_id_source("article_sort_mode")
+                    .selected_text(sort_mode_label(self.sort_mode))
+                    .show_ui(ui, |ui| {
+                        for mode in [
+                            SortMode::NewestFirst,
+                            SortMode::OldestFirst,
+                            SortMode::ByFeed,
+                            SortMode::UnreadFirst,
+                        ] {
+                            ui.selectable_value(&mut self.sort_mode, mode, sort_mode_label(mode));
+                        }
+                    });
+                if self.sort_mode != previous_mode {
+                    self.config.ui.sort_mode = self.sort_mode;
+                    let _ = self.config.save();
+                    self.reset_pagination();
+                }
+                if aggregated_view
+                    && ui
+                        .toggle_value(
+                            &mut self.config.ui.group_similar_titles,
+                            tr("list.group_similar"),
+                        )
+                        .on_hover_text(tr("list.group_similar_hover"))
+                        .changed()
+                {
+                    let _ = self.config.save();
+                    self.rebuild_title_clusters();
+                    self.reset_pagination();
+                }
+                ui.separator();
+                self.export_menu(ui, || {
+                    self.filtered_articles().into_iter().cloned().collect()
+                });
+                ui.separator();
+                if ui
+                    .small_button(tr("list.mark_all_read"))
+                    .on_hover_text(tr("list.mark_all_read_hover"))
+                    .clicked()
+                {
+                    let before = self.mark_read_cutoff();
+                    let to_mark: Vec<FeedEntry> = self
+                        .filtered_articles()
+                        .into_iter()
+                        .filter(|a| match before {
+                            Some(limit) => a.sort_date() < limit,
+                            None => true,
+                        })
+                        .cloned()
+                        .collect();
+                    let api = self.data_api.clone();
+                    let ctx = ui.ctx().clone();
+                    let toasts = self.toasts.sender();
+                    let undo = self.undo_tx.clone();
+                    self.runtime.spawn(async move {
+                        match api.mark_read_batch_tracked(&to_mark).await {
+                            Ok(marks) => send_mark_read_undo(&undo, marks),
+                            Err(e) => {
+                                let _ = toasts.send((ToastLevel::Error, data_error_message(&e)));
+                            }
+                        }
+                        ctx.request_repaint();
+                    });
+                }
+                let previous_days = self.config.ui.mark_read_older_than_days;
+                ui.add(
+                    egui::DragValue::new(&mut self.config.ui.mark_read_older_than_days)
+                        .clamp_range(0..=365)
+                        .suffix(tr("unit.days_suffix")),
+                )
+                .on_hover_text(tr("list.older_than_hover"));
+                if self.config.ui.mark_read_older_than_days != previous_days {
+                    let _ = self.config.save();
+                }
+            });
+        });
+        self.draw_feed_header(ui, now);
+        self.draw_filter_bar(ui);
+        ui.separator();
+
+        let feed_titles: HashMap<String, String> = if aggregated_view {
+            self.feeds_snapshot()
+                .into_iter()
+                .map(|f| (f.id, f.title))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        if articles.is_empty() && self.article_filter.is_active() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(egui::RichText::new(tr("list.no_filter_match")).title());
+                if ui.button(tr("list.clear_filters")).clicked() {
+                    self.article_filter.clear();
+                    self.reset_pagination();
+                }
+            });
+            return;
+        }
+        if articles.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(egui::RichText::new(tr("list.empty")).title());
+                ui.label(egui::RichText::new(tr("list.empty_hint")).body());
+            });
+            return;
+        }
+
+        self.navigate_articles(ui, &articles, row_height, per_page);
+        let visible = (self.article_pages * per_page).min(total);
+
+        // Seules les cartes visibles sont construites (show_rows): hauteur fixe par carte.
+        let mut scroll = egui::ScrollArea::vertical().auto_shrink([false, true]);
+        if let Some(offset) = self.pending_list_scroll.take() {
+            scroll = scroll.vertical_scroll_offset(offset);
+        }
+        let output = scroll.show_rows(ui, row_height, visible, |ui, rows| {
+            for index in rows.clone() {
+                let (rect, _) = ui.allocate_exact_size(
+                    egui::vec2(ui.available_width(), row_height),
+                    egui::Sense::hover(),
+                );
+                let mut card = ui.child_ui_with_id_source(
+                    rect,
+                    egui::Layout::top_down(egui::Align::Min),
+                    ("article_card", index),
+                );
+                card.set_clip_rect(rect.intersect(ui.clip_rect()));
+                self.draw_article_card(&mut card, &articles[index], &feed_titles, now);
+                if self.selected_article_index == Some(index) {
+                    let accent = self.config.theme.accent_color32();
+                    let frame = rect.shrink2(egui::vec2(1.0, 3.0));
+                    ui.painter()
+                        .rect_stroke(frame, 4.0, egui::Stroke::new(2.0, accent));
+                }
+            }
+
+            if rows.end == visible && visible < total {
+                ui.vertical_centered(|ui| {
+                    let label = tr_args("list.load_more", &[("remaining", &(total - visible))]);
+                    if ui.button(label).clicked() {
+                        self.article_pages += 1;
+                    }
+                });
+                ui.add_space(5.0);
+            }
+        });
+        self.list_scroll = output.state.offset.y;
+        // Article ouvert d’un clic: il devient la sélection.
+        self.follow_open_article(&articles);
+    }
+
+    fn draw_feed_header(&self, ui: &mut egui::Ui, now: DateTime<Utc>) {
+        // ===
+        // En-tête du flux sélectionné: titre, description du canal en infobulle, lien vers le
+        // site et date de dernière mise à jour annoncée par le flux.
+        // ===
+        let Some(selected) = &self.selected_feed else {
+            return;
+        };
+        let feeds = self.feeds_snapshot();
+        let Some(feed) = feeds.iter().find(|f| &f.id == selected) else {
+            return;
+        };
+        let secondary = self.config.theme.secondary_text_color32();
+        ui.horizontal(|ui| {
+            let title = ui.label(egui::RichText::new(&feed.title).strong());
+            if let Some(description) = &feed.description {
+                title.on_hover_text(description);
+            }
+            if let Some(site_url) = &feed.site_url {
+                ui.hyperlink_to(tr("feed.site"), site_url)
+                    .on_hover_text(site_url);
+            }
+            if let Some(updated) = feed.last_updated {
+                let text = tr_args(
+                    "feed.updated",
+                    &[("when", &format_relative_date(updated, now))],
+                );
+                ui.label(egui::RichText::new(text).color(secondary).small())
+                    .on_hover_text(format_date(updated));
+            }
+        });
+    }
 
-                            if let Some((ok, msg)) = &self.add_feedback {
-                                let color = if *ok {
-                                    Color32::from_rgb(67, 160, 71)
-                                } else {
-                                    Color32::from_rgb(229, 57, 53)
-                                };
-                                ui.label(egui::RichText::new(msg.clone()).color(color).size(13.0));
+    fn draw_filter_bar(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Filtres rapides: période, image, texte sur l’auteur ou les catégories, tag. Les filtres
+        // actifs sont mis en évidence et « ✖ Effacer » les désactive tous d’un clic.
+        // ===
+        let previous = self.article_filter.clone();
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(tr("filters.label")).meta());
+            let filter = &mut self.article_filter;
+            ui.toggle_value(&mut filter.today, tr("filters.today"));
+            ui.toggle_value(&mut filter.this_week, tr("filters.this_week"));
+            ui.toggle_value(&mut filter.with_image, tr("filters.with_image"));
+            let text_active = !filter.text.trim().is_empty();
+            let mut edit = egui::TextEdit::singleline(&mut filter.text)
+                .hint_text(tr("filters.text_hint"))
+                .desired_width(160.0);
+            if text_active {
+                edit = edit.text_color(ui.visuals().selection.stroke.color);
+            }
+            ui.add(edit);
+            if let Some(tag) = &filter.tag {
+                let chip = egui::RichText::new(format!("🏷 {} ✖", tag)).strong();
+                if ui
+                    .add(egui::Button::new(chip).selected(true))
+                    .on_hover_text(tr("filters.remove_tag"))
+                    .clicked()
+                {
+                    filter.tag = None;
+                }
+            }
+            if filter.is_active() {
+                let accent = self.config.theme.accent_color32();
+                if ui
+                    .button(egui::RichText::new(tr("filters.clear")).color(accent))
+                    .on_hover_text(tr("filters.clear_hover"))
+                    .clicked()
+                {
+                    filter.clear();
+                }
+            }
+        });
+        if self.article_filter != previous {
+            self.reset_pagination();
+        }
+    }
+
+    fn article_card_height(&self, ui: &egui::Ui, aggregated_view: bool) -> f32 {
+        // ===
+        // Hauteur d’une carte d’article, dérivée des styles de texte (titre sur une ligne,
+        // aperçu sur PREVIEW_ROWS lignes au plus) pour que show_rows reste exact.
+        // ===
+        let spacing = ui.spacing().item_spacing.y;
+        let line = |name: &str| ui.text_style_height(&egui::TextStyle::Name(name.into()));
+        let mut height = line("title")
+            + ui.text_style_height(&egui::TextStyle::Small)
+            + ui.spacing().interact_size.y
+            + 13.0
+            + 3.0 * spacing;
+        if self.config.ui.show_article_preview {
+            height += PREVIEW_ROWS as f32 * line("meta") + spacing;
+        }
+        if aggregated_view {
+            height += 16.0 + spacing;
+        }
+        // Marges intérieures de Frame::group.
+        height + 12.0
+    }
+
+    fn draw_article_card(
+        &mut self,
+        ui: &mut egui::Ui,
+        deduped: &DedupedEntry,
+        feed_titles: &HashMap<String, String>,
+        now: chrono::DateTime<Utc>,
+    ) {
+        // ===
+        // Carte d’un article de la liste: titre, métadonnées, aperçu (cache), actions.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        let aggregated_view = self.selected_feed.is_none();
+        let article = &deduped.entry;
+        let also_in: Vec<String> = deduped
+            .other_feed_ids()
+            .map(|id| {
+                feed_titles
+                    .get(id)
+                    .cloned()
+                    .unwrap_or_else(|| id.to_string())
+            })
+            .collect();
+        let content_height = ui.available_height() - 12.0;
+        let mut open_similar: Option<FeedEntry> = None;
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.set_height(content_height);
+            ui.vertical(|ui| {
+                let is_read = self.runtime.block_on(self.data_api.is_read(article));
+
+                let title_text = if is_read {
+                    egui::RichText::new(&article.title)
+                        .color(secondary)
+                        .italics()
+                        .title()
+                } else {
+                    egui::RichText::new(&article.title).strong().title()
+                };
+                // Mis en évidence par une règle de filtrage: couleur d’accent.
+                let title_text = if article.highlighted {
+                    title_text.color(self.config.theme.accent_color32())
+                } else {
+                    title_text
+                };
+                let title_response = ui
+                    .add(
+                        egui::Label::new(title_text)
+                            .truncate(true)
+                            .sense(egui::Sense::click()),
+                    )
+                    .on_hover_text(&article.title);
+                // Lecteurs d’écran: titre, flux et état de lecture en une phrase.
+                let feed_name = feed_titles
+                    .get(&article.feed_id)
+                    .cloned()
+                    .unwrap_or_else(|| tr("card.unknown_feed"));
+                let state = tr(if is_read {
+                    "card.state_read"
+                } else {
+                    "card.state_unread"
+                });
+                let title_response = title_response.accessible(
+                    egui::WidgetType::Link,
+                    &tr_args(
+                        "card.accessible",
+                        &[
+                            ("title", &article.title),
+                            ("feed", &feed_name),
+                            ("state", &state),
+                        ],
+                    ),
+                );
+
+                if title_response.clicked() {
+                    self.open_article(article);
+                }
+
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
+                    if let Some(author) = &article.author {
+                        ui.label(
+                            egui::RichText::new(format!("👤 {}", author))
+                                .color(secondary)
+                                .small(),
+                        );
+                        ui.separator();
+                    }
+
+                    if let Some(category) = article.categories.first() {
+                        let more = article.categories.len() - 1;
+                        let text = if more > 0 {
+                            format!("🏷 {} +{}", category, more)
+                        } else {
+                            format!("🏷 {}", category)
+                        };
+                        let label = ui.label(egui::RichText::new(text).color(secondary).small());
+                        if more > 0 {
+                            label.on_hover_text(article.categories.join(", "));
+                        }
+                        ui.separator();
+                    }
+
+                    if let Some(date) = article.published_at {
+                        let absolute = format_date(date);
+                        let shown = match self.config.ui.date_format {
+                            DateFormat::Relative => format_relative_date(date, now),
+                            DateFormat::Absolute => absolute.clone(),
+                            DateFormat::Both => {
+                                format!("{} ({})", format_relative_date(date, now), absolute)
                             }
-                        });
+                        };
+                        ui.label(
+                            egui::RichText::new(format!("📅 {}", shown))
+                                .color(secondary)
+                                .small(),
+                        )
+                        .on_hover_text(absolute);
+                    }
 
-                    ui.add_space(2.0);
+                    let stats = self
+                        .previews
+                        .reading_stats(article, self.config.ui.reading_wpm);
+                    if stats.words > 0 {
+                        ui.separator();
+                        let minutes = stats.minutes.to_string();
+                        ui.label(
+                            egui::RichText::new(tr_args(
+                                "card.reading_time",
+                                &[("minutes", &minutes)],
+                            ))
+                            .color(secondary)
+                            .small(),
+                        )
+                        .on_hover_text(tr_count(
+                            "card.reading_words",
+                            stats.words as i64,
+                            &[],
+                        ));
+                    }
 
-                    egui::Frame::group(ui.style())
-                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
-                        .show(ui, |ui| {
-                            let w = ui.available_width();
-                            let btn =
-                                egui::Button::new(egui::RichText::new("🔎 Découvrir").strong());
-                            if ui.add_sized(egui::vec2(w, 28.0), btn).clicked() {
-                                self.current_view = AppView::DiscoverHome;
-                                self.selected_feed = None;
+                    if let Some(updated) = article.updated_at {
+                        ui.separator();
+                        let accent = self.config.theme.accent_color32();
+                        ui.label(
+                            egui::RichText::new(tr("card.updated"))
+                                .color(accent)
+                                .small(),
+                        )
+                        .on_hover_text(tr_args(
+                            "card.updated_hover",
+                            &[("date", &format_date(updated))],
+                        ));
+                    }
+                });
+
+                ui.add_space(3.0);
+
+                if self.config.ui.show_article_preview {
+                    let preview = self.previews.get(article);
+                    if !preview.is_empty() {
+                        let font_id = egui::TextStyle::Name("meta".into()).resolve(ui.style());
+                        let mut job = egui::text::LayoutJob::simple(
+                            preview.to_string(),
+                            font_id,
+                            secondary,
+                            ui.available_width(),
+                        );
+                        job.wrap.max_rows = PREVIEW_ROWS;
+                        ui.label(job);
+                    }
+                }
+
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
+                    let resumable = self
+                        .runtime
+                        .block_on(self.data_api.reading_position(article))
+                        .is_some();
+                    let read_label = if resumable {
+                        tr("card.resume")
+                    } else {
+                        tr("card.read")
+                    };
+                    if ui.small_button(read_label).clicked() {
+                        self.open_article(article);
+                    }
+
+                    if ui
+                        .small_button(tr("card.viewer"))
+                        .on_hover_text(tr("card.viewer_hover"))
+                        .clicked()
+                    {
+                        self.open_in_viewer(article, false);
+                    }
+
+                    if ui.small_button(tr("card.open")).clicked() {
+                        if let Err(e) = webbrowser::open(&article.url) {
+                            self.push_toast(
+                                ToastLevel::Error,
+                                tr_args("toast.open_link_failed", &[("error", &e)]),
+                            );
+                        }
+                    }
+                    self.share_menu(ui, "📤", article);
+                    if self.read_later.is_some()
+                        && ui
+                            .small_button("📥")
+                            .icon_hint(&tr("card.read_later"))
+                            .clicked()
+                    {
+                        self.save_for_later(ui.ctx(), article);
+                    }
+                    if is_read {
+                        ui.label(
+                            egui::RichText::new(tr("card.is_read"))
+                                .color(secondary)
+                                .small(),
+                        );
+                    } else {
+                        ui.label(
+                            egui::RichText::new(tr("card.is_unread"))
+                                .color(Color32::from_rgb(0, 122, 204))
+                                .small(),
+                        );
+                    }
+                });
+
+                if aggregated_view {
+                    let feed_name = feed_titles
+                        .get(&article.feed_id)
+                        .cloned()
+                        .unwrap_or_else(|| tr("card.unknown_feed"));
+                    let color = color_for_feed(&article.feed_id);
+                    let bar_h = 16.0;
+                    let width = ui.available_width();
+                    ui.allocate_ui_with_layout(
+                        egui::vec2(width, bar_h),
+                        egui::Layout::right_to_left(egui::Align::Center),
+                        |ui| {
+                            let max_w = 180.0;
+                            let label = egui::Label::new(
+                                egui::RichText::new(feed_name).color(color).small(),
+                            )
+                            .truncate(true);
+                            ui.add_sized(egui::vec2(max_w, 14.0), label);
+                            if !also_in.is_empty() {
+                                ui.label(
+                                    egui::RichText::new(tr_args(
+                                        "card.also_in",
+                                        &[("feeds", &also_in.join(", "))],
+                                    ))
+                                    .color(secondary)
+                                    .small(),
+                                );
                             }
-                            if let Some((ok, msg)) = &self.discover_feedback {
-                                let color = if *ok {
-                                    Color32::from_rgb(67, 160, 71)
-                                } else {
-                                    Color32::from_rgb(229, 57, 53)
-                                };
-                                ui.label(egui::RichText::new(msg.clone()).color(color).size(13.0));
+                            if !deduped.similar.is_empty() {
+                                let count = deduped.similar.len() as i64;
+                                let label =
+                                    egui::RichText::new(tr_count("card.similar", count, &[]))
+                                        .small();
+                                ui.menu_button(label, |ui| {
+                                    for other in &deduped.similar {
+                                        let source = feed_titles
+                                            .get(&other.feed_id)
+                                            .map_or(other.feed_id.as_str(), String::as_str);
+                                        let text = format!("{} — {}", source, other.title);
+                                        if ui.button(text).clicked() {
+                                            open_similar = Some(other.clone());
+                                            ui.close_menu();
+                                        }
+                                    }
+                                });
                             }
-                        });
+                        },
+                    );
+                }
+            });
+        });
+        if let Some(other) = open_similar {
+            self.open_article(&other);
+        }
+    }
 
-                    ui.add_space(2.0);
+    fn draw_article_detail(&mut self, ui: &mut egui::Ui, article: FeedEntry) {
+        // ===
+        // Détail d’un article (texte simplifié) et actions.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        let body = article
+            .content_html
+            .as_deref()
+            .or(article.summary.as_deref());
+        let base = Url::parse(&article.url).ok();
+        let blocks = body
+            .map(|html| split_inline_images(html, base.as_ref(), MAX_INLINE_IMAGES))
+            .unwrap_or_default();
+        let has_images = blocks
+            .iter()
+            .any(|block| matches!(block, ContentBlock::Image { .. }));
+        let mut load_images = has_images && self.images_allowed(&article);
+        ui.horizontal(|ui| {
+            // Côte à côte, la liste reste visible: on ferme la lecture au lieu d’y revenir.
+            let back = if self.config.ui.layout == Layout::SplitHorizontal {
+                tr("detail.close")
+            } else {
+                tr("common.back")
+            };
+            if ui.button(back).clicked() {
+                self.current_view = AppView::ArticleList;
+            }
+            ui.separator();
+            ui.heading(egui::RichText::new(tr("detail.heading")).heading());
+            if has_images
+                && ui
+                    .toggle_value(&mut load_images, tr("detail.load_images"))
+                    .on_hover_text(tr("detail.load_images_hover"))
+                    .changed()
+            {
+                self.image_overrides.insert(article.identity(), load_images);
+            }
+        });
 
-                    egui::Frame::group(ui.style())
-                        .inner_margin(egui::Margin::symmetric(2.0, 2.0))
-                        .show(ui, |ui| {
-                            let w = ui.available_width();
-                            let btn = egui::Button::new(
-                                egui::RichText::new("⚙ Paramètres").strong(),
+        ui.separator();
+
+        // Défilement piloté explicitement: état propre à chaque article, position restaurée
+        // depuis DataApi à l’ouverture.
+        let mut scroll = egui::ScrollArea::vertical()
+            .id_source(("article_detail", article.identity()))
+            .auto_shrink([false, true]);
+        if let Some(offset) = self.pending_scroll.take() {
+            scroll = scroll.vertical_scroll_offset(offset);
+        }
+        let output = scroll.show(ui, |ui| {
+            ui.group(|group| {
+                group.vertical(|ui| {
+                    // Titre de l'article
+                    ui.label(egui::RichText::new(&article.title).strong().headline());
+
+                    ui.add_space(10.0);
+
+                    // Métadonnées
+                    ui.horizontal_wrapped(|ui| {
+                        if let Some(author) = &article.author {
+                            let text = tr_args("detail.author", &[("author", author)]);
+                            ui.label(egui::RichText::new(text).body());
+                            ui.separator();
+                        }
+
+                        if !article.categories.is_empty() {
+                            ui.label(egui::RichText::new(tr("detail.categories")).body());
+                            for category in &article.categories {
+                                if ui
+                                    .small_button(category)
+                                    .on_hover_text(tr("detail.tag_hover"))
+                                    .clicked()
+                                {
+                                    self.filter_by_tag(category.clone());
+                                }
+                            }
+                            ui.separator();
+                        }
+
+                        if let Some(date) = article.published_at {
+                            ui.label(
+                                egui::RichText::new(tr_args(
+                                    "detail.published",
+                                    &[("date", &format_date(date))],
+                                ))
+                                .body(),
                             );
-                            if ui.add_sized(egui::vec2(w, 28.0), btn).clicked() {
-                                self.current_view = AppView::Settings;
-                                self.selected_feed = None;
+                        }
+
+                        let stats = self
+                            .previews
+                            .reading_stats(&article, self.config.ui.reading_wpm);
+                        if stats.words > 0 {
+                            ui.separator();
+                            let minutes = stats.minutes.to_string();
+                            let words = tr_count("card.reading_words", stats.words as i64, &[]);
+                            ui.label(
+                                egui::RichText::new(tr_args(
+                                    "detail.reading_time",
+                                    &[("minutes", &minutes), ("words", &words)],
+                                ))
+                                .body(),
+                            );
+                        }
+                    });
+
+                    ui.separator();
+
+                    if body.is_some() {
+                        // Texte et images dans l’ordre du document.
+                        for block in &blocks {
+                            match block {
+                                ContentBlock::Html(fragment) => {
+                                    let rich = richtext::parse_html(fragment, base.as_ref());
+                                    if let Some(link) = richtext::show(ui, &rich, secondary) {
+                                        if let Err(e) = webbrowser::open(&link) {
+                                            self.push_toast(
+                                                ToastLevel::Error,
+                                                tr_args("toast.open_link_failed", &[("error", &e)]),
+                                            );
+                                        }
+                                    }
+                                }
+                                ContentBlock::Image { url, alt } => {
+                                    self.draw_inline_image(ui, url, alt.as_deref(), load_images)
+                                }
                             }
-                        });
+                        }
+                    } else {
+                        ui.label(
+                            egui::RichText::new(tr("detail.no_content"))
+                                .color(secondary)
+                                .reading(),
+                        );
+                    }
 
-                    ui.add_space(2.0);
+                    // Contenu coupé à la limite par article: la suite est sur le site.
+                    if article.content_truncated && !article.url.is_empty() {
+                        ui.add_space(8.0);
+                        if ui
+                            .link(tr("detail.truncated"))
+                            .on_hover_text(tr("detail.truncated_hover"))
+                            .clicked()
+                        {
+                            if let Err(e) = webbrowser::open(&article.url) {
+                                self.push_toast(
+                                    ToastLevel::Error,
+                                    tr_args("toast.open_link_failed", &[("error", &e)]),
+                                );
+                            }
+                        }
+                    }
+
+                    ui.add_space(20.0);
+
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("card.viewer_hover")).clicked() {
+                            self.open_in_viewer(&article, false);
+                        }
+
+                        if article.content_html.is_some()
+                            && ui.button(tr("detail.read_here")).clicked()
+                        {
+                            self.open_in_viewer(&article, true);
+                        }
+
+                        if ui.button(tr("detail.open_browser")).clicked() {
+                            if let Err(e) = webbrowser::open(&article.url) {
+                                self.push_toast(
+                                    ToastLevel::Error,
+                                    tr_args("toast.open_link_failed", &[("error", &e)]),
+                                );
+                            }
+                        }
+
+                        if ui.button(tr("detail.copy_link")).clicked() {
+                            ui.output_mut(|o| o.copied_text = article.url.clone());
+                        }
+
+                        self.share_menu(ui, &tr("detail.share"), &article);
+
+                        if self.read_later.is_some() && ui.button(tr("detail.read_later")).clicked()
+                        {
+                            self.save_for_later(ui.ctx(), &article);
+                        }
+
+                        self.export_menu(ui, || vec![article.clone()]);
+                    });
+                });
+            });
+        });
+        self.reading = Some((article, output.state.offset.y));
+    }
+
+    fn images_allowed(&self, article: &FeedEntry) -> bool {
+        // ===
+        // Choix fait pour cet article, sinon le réglage global `UiConfig.load_images`.
+        // ===
+        self.image_overrides
+            .get(&article.identity())
+            .copied()
+            .unwrap_or(self.config.ui.load_images)
+    }
+
+    fn draw_inline_image(&mut self, ui: &mut egui::Ui, url: &Url, alt: Option<&str>, load: bool) {
+        // ===
+        // Image d’un article: la texture une fois chargée, sinon une pastille (images non
+        // chargées, téléchargement en cours ou image indisponible).
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        let placeholder = |ui: &mut egui::Ui, text: String| {
+            let text = egui::RichText::new(text).color(secondary).meta();
+            ui.label(text).on_hover_text(url.as_str());
+        };
+        if !load {
+            let alt = alt.map_or_else(|| tr("image.default_alt"), str::to_string);
+            placeholder(ui, format!("🖼 {}", alt));
+            return;
+        }
+        match self.article_images.slot(url) {
+            Some(ImageSlot::Ready(texture)) => {
+                let image = egui::Image::from_texture(texture)
+                    .fit_to_original_size(1.0)
+                    .max_width(ui.available_width());
+                let response = ui.add(image);
+                if let Some(alt) = alt {
+                    response.on_hover_text(alt);
+                }
+            }
+            Some(ImageSlot::Failed) => {
+                let text = match alt {
+                    Some(alt) => tr_args("image.unavailable_alt", &[("alt", &alt)]),
+                    None => tr("image.unavailable"),
+                };
+                placeholder(ui, text);
+            }
+            Some(ImageSlot::Loading) => {
+                ui.spinner();
+            }
+            None => {
+                let ctx = ui.ctx().clone();
+                self.article_images
+                    .request(&self.runtime, &self.client, &ctx, url);
+                ui.spinner();
+            }
+        }
+    }
+
+    fn draw_tags(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Tags les plus fréquents du cache d’articles; un clic filtre la liste sur le tag.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        ui.heading(egui::RichText::new(tr("tags.heading")).heading());
+        ui.separator();
+
+        let tags = self.runtime.block_on(self.data_api.tags_with_counts());
+        if tags.is_empty() {
+            let empty = egui::RichText::new(tr("tags.empty"));
+            ui.label(empty.color(secondary));
+            return;
+        }
+        if tags.len() > MAX_TAGS_SHOWN {
+            let hidden = tags.len() - MAX_TAGS_SHOWN;
+            let note = tr_args(
+                "tags.truncated",
+                &[("shown", &MAX_TAGS_SHOWN), ("hidden", &hidden)],
+            );
+            ui.label(egui::RichText::new(note).meta().color(secondary));
+        }
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for (tag, count) in tags.into_iter().take(MAX_TAGS_SHOWN) {
+                        if ui.button(format!("{} ({})", tag, count)).clicked() {
+                            self.filter_by_tag(tag);
+                        }
+                    }
+                });
+            });
+    }
+
+    fn filter_by_tag(&mut self, tag: String) {
+        // ===
+        // Affiche tous les flux filtrés sur un tag (vue « Tags » ou catégorie d’un article).
+        // ===
+        self.article_filter.tag = Some(tag);
+        self.selected_feed = None;
+        self.current_view = AppView::ArticleList;
+        self.reset_pagination();
+    }
+
+    fn draw_feed_health(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Santé des flux: dernier résultat de récupération, trié par échec le plus récent.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        ui.horizontal(|ui| {
+            if ui.button(tr("common.back")).clicked() {
+                self.current_view = AppView::Settings;
+            }
+            ui.separator();
+            ui.heading(egui::RichText::new(tr("health.heading")).heading());
+            if let Some(wait) = self.poll_config.quiet_hours_remaining() {
+                let resume =
+                    chrono::Local::now() + chrono::Duration::from_std(wait).unwrap_or_default();
+                ui.label(egui::RichText::new(tr("health.quiet_hours")).color(secondary))
+                    .on_hover_text(tr_args(
+                        "health.quiet_hours_hover",
+                        &[("time", &resume.format("%H:%M"))],
+                    ));
+            }
+        });
+        ui.separator();
+        // Compteurs du poller depuis le démarrage (ceux de /metrics pour une instance sans GUI).
+        let metrics = self.service.metrics().snapshot();
+        let duration = format!("{:.1}", metrics.last_cycle_duration_seconds);
+        let counters = ui.label(
+            egui::RichText::new(tr_args(
+                "health.metrics",
+                &[
+                    ("fetches", &metrics.fetch_total),
+                    ("errors", &metrics.fetch_errors()),
+                    ("new", &metrics.articles_new_total),
+                    ("duration", &duration),
+                ],
+            ))
+            .color(secondary),
+        );
+        if !metrics.fetch_errors_total.is_empty() {
+            let mut hover = tr("health.metrics_hover");
+            for (kind, count) in &metrics.fetch_errors_total {
+                hover.push_str(&format!("\n{}: {}", kind, count));
+            }
+            counters.on_hover_text(hover);
+        }
+        ui.separator();
+
+        let stats = self.runtime.block_on(self.data_api.feed_stats());
+        let mut rows: Vec<(FeedDescriptor, FeedStats)> = self
+            .feeds_snapshot()
+            .into_iter()
+            .map(|feed| {
+                let st = stats.get(&feed.id).cloned().unwrap_or_default();
+                (feed, st)
+            })
+            .collect();
+        rows.sort_by_key(|(_, st)| std::cmp::Reverse(st.last_failure_at));
+
+        if rows.is_empty() {
+            ui.label(egui::RichText::new(tr("health.no_feeds")).color(secondary));
+            return;
+        }
 
-                    // Raccourci: Ctrl+F pour mettre le focus sur la recherche
-                    if ui.input_mut(|i| i.modifiers.ctrl && i.key_pressed(egui::Key::F)) {
-                        self.focus_search_next = true;
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("feed_health_grid")
+                .striped(true)
+                .spacing(egui::vec2(12.0, 6.0))
+                .show(ui, |ui| {
+                    for header in [
+                        "health.col_feed",
+                        "health.col_last_fetch",
+                        "health.col_state",
+                        "health.col_http",
+                        "health.col_received",
+                        "health.col_new",
+                        "health.col_seen",
+                        "health.col_attempts",
+                        "health.col_avg_time",
+                        "health.col_last_failure",
+                        "health.col_interval",
+                    ] {
+                        ui.label(egui::RichText::new(tr(header)).strong());
                     }
+                    ui.end_row();
 
-                    egui::Frame::group(ui.style())
-                        .inner_margin(egui::Margin::symmetric(6.0, 6.0))
-                        .show(ui, |ui| {
-                            ui.label(
-                                egui::RichText::new("🔍 Rechercher dans les flux")
-                                    .strong()
-                                    .size(15.0),
-                            );
-                            ui.separator();
-                            let id = egui::Id::new("feed_search_input");
-                            if self.focus_search_next {
-                                ui.memory_mut(|m| m.request_focus(id));
-                                self.focus_search_next = false;
+                    for (feed, st) in &rows {
+                        let limit = self.poll_config.per_host_rate_limit;
+                        ui.horizontal(|ui| {
+                            ui.label(&feed.title).on_hover_text(feed.redacted_url());
+                            if let Some(wait) = throttled_for(&feed.url, limit) {
+                                ui.label(
+                                    egui::RichText::new(tr("health.throttled")).color(secondary),
+                                )
+                                .on_hover_text(tr_args(
+                                    "health.throttled_hover",
+                                    &[("seconds", &wait.as_secs().max(1))],
+                                ));
                             }
-                            ui.add(egui::TextEdit::singleline(&mut self.feed_search).id(id));
                         });
-
-                    ui.add_space(10.0);
-
-                    ui.group(|group| {
-                        group.vertical(|ui| {
-                            ui.horizontal(|ui| {
-                                ui.label(egui::RichText::new("📡 Flux RSS").strong().size(15.0));
-                                ui.with_layout(
-                                    egui::Layout::right_to_left(egui::Align::Center),
-                                    |ui| {
-                                        if ui
-                                            .small_button("⟳")
-                                            .on_hover_text("Rafraîchir tous les flux")
-                                            .clicked()
-                                        {
-                                            let feeds = self.feeds_snapshot();
-                                            if !feeds.is_empty() {
-                                                let events = self.runtime.block_on(async {
-                                                    poll_once(
-                                                        &feeds,
-                                                        &self.poll_config,
-                                                        &self.client,
-                                                        &self.seen_store,
-                                                    )
-                                                    .await
-                                                });
-                                                for evt in events {
-                                                    let Event::NewArticles(feed_id, mut entries) =
-                                                        evt;
-                                                    let to_persist = entries.clone();
-                                                    self.runtime.block_on(
-                                                        self.data_api
-                                                            .upsert_articles(&feed_id, to_persist),
-                                                    );
-                                                    self.articles.retain(|a| a.feed_id != feed_id);
-                                                    self.articles.append(&mut entries);
-                                                }
-                                                self.articles.sort_by(|a, b| {
-                                                    b.published_at.cmp(&a.published_at)
-                                                });
-                                                self.articles.truncate(
-                                                    self.config.ui.articles_per_page.max(1),
-                                                );
-                                            }
-                                        }
-
-                                        if ui.small_button("Tous").clicked() {
-                                            self.selected_feed = None;
-                                            self.current_view = AppView::ArticleList;
-                                            let all = self
-                                                .runtime
-                                                .block_on(self.data_api.list_all_articles());
-                                            self.articles = all;
-                                            self.articles.sort_by(|a, b| {
-                                                b.published_at.cmp(&a.published_at)
-                                            });
-                                            self.articles
-                                                .truncate(self.config.ui.articles_per_page.max(1));
-                                        }
-                                    },
+                        match &st.last {
+                            Some(last) => {
+                                ui.label(format_date_with(
+                                    &last.fetched_at.with_timezone(&chrono::Local),
+                                    "date.format",
+                                ));
+                                if last.success {
+                                    ui.label(
+                                        egui::RichText::new(tr("health.ok"))
+                                            .color(Color32::from_rgb(67, 160, 71)),
+                                    );
+                                } else if last.error_kind == Some(FetchErrorKind::Gone) {
+                                    ui.label(
+                                        egui::RichText::new(tr("health.gone"))
+                                            .color(Color32::from_rgb(229, 57, 53)),
+                                    )
+                                    .on_hover_text(tr("health.gone_hover"));
+                                } else {
+                                    ui.label(
+                                        egui::RichText::new(tr("health.failed"))
+                                            .color(Color32::from_rgb(229, 57, 53)),
+                                    )
+                                    .on_hover_text(last.error.clone().unwrap_or_default());
+                                }
+                                ui.label(
+                                    last.http_status
+                                        .map(|code| code.to_string())
+                                        .unwrap_or_else(|| "—".to_string()),
                                 );
-                            });
-                            ui.separator();
+                                ui.label(last.fetched_items.to_string());
+                                ui.label(last.new_items.to_string());
+                                ui.label(last.filtered_items.to_string())
+                                    .on_hover_text(tr("health.seen_hover"));
+                                ui.label(last.attempts.to_string());
+                            }
+                            None => {
+                                ui.label(egui::RichText::new(tr("health.never")).color(secondary));
+                                for _ in 0..6 {
+                                    ui.label("—");
+                                }
+                            }
+                        }
+                        ui.label(
+                            st.average_fetch_ms()
+                                .map(|ms| format!("{} ms", ms))
+                                .unwrap_or_else(|| "—".to_string()),
+                        );
+                        ui.label(
+                            st.last_failure_at
+                                .map(|at| {
+                                    format_date_with(
+                                        &at.with_timezone(&chrono::Local),
+                                        "date.format",
+                                    )
+                                })
+                                .unwrap_or_else(|| "—".to_string()),
+                        );
+                        let interval = self.poll_config.effective_interval(feed, Utc::now());
+                        if interval > feed.poll_interval.unwrap_or(self.poll_config.interval) {
+                            let since = feed
+                                .last_new_article_at
+                                .map(|at| {
+                                    format_day(
+                                        at.with_timezone(&chrono::Local).date_naive(),
+                                        "date.day",
+                                    )
+                                })
+                                .unwrap_or_default();
+                            ui.label(
+                                egui::RichText::new(interval_label(interval)).color(secondary),
+                            )
+                            .on_hover_text(tr_args("health.adaptive_hover", &[("since", &since)]));
+                        } else {
+                            ui.label(interval_label(interval));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    }
 
-                            egui::ScrollArea::vertical()
-                                .auto_shrink([false, true])
-                                .show(ui, |ui| {
-                                    let feeds = self.filtered_feeds();
+    fn draw_feed_manager(&mut self, ui: &mut egui::Ui) {
+        // ===
+        //
+        //
+        // Gestion des flux: une case par flux (filtrés par la recherche de la barre latérale) et
+        // des actions appliquées d’un coup aux flux cochés: suppression après confirmation,
+        // dossier, intervalle de relevé, notifications. Chaque action finit par un toast de bilan.
+        //
+        //
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        ui.horizontal(|ui| {
+            if ui.button(tr("common.back")).clicked() {
+                self.current_view = AppView::Settings;
+            }
+            ui.separator();
+            ui.heading(egui::RichText::new(tr("manager.heading")).heading());
+        });
+        ui.separator();
 
-                                    for feed in &feeds {
-                                        let is_selected =
-                                            self.selected_feed.as_ref() == Some(&feed.id);
+        let all = self.feeds_snapshot();
+        // Flux supprimés entre-temps: sortis de la sélection.
+        self.feed_manager
+            .selected
+            .retain(|id| all.iter().any(|f| &f.id == id));
+        let selected: Vec<String> = all
+            .iter()
+            .filter(|f| self.feed_manager.selected.contains(&f.id))
+            .map(|f| f.id.clone())
+            .collect();
+        let feeds = self.filtered_feeds();
+        if feeds.is_empty() {
+            ui.label(egui::RichText::new(tr("health.no_feeds")).color(secondary));
+            return;
+        }
 
-                                        ui.horizontal(|ui| {
-                                            // Zone cliquable: toute la bande gauche (jusqu'aux boutons), texte aligné à gauche
-                                            let _spacing = ui.spacing().item_spacing.x + 8.0; // réservé si besoin
-                                            let right_controls_width = 80.0; // réserve fixe pour ⟳ et 🗑 + marges
-                                            let left_width = (ui.available_width() - right_controls_width).max(120.0);
-                                            let (rect, response_bg) = ui.allocate_exact_size(
-                                                egui::vec2(left_width, 24.0),
-                                                egui::Sense::click(),
-                                            );
-                                            // Peindre un fond de sélection/hover plein sur toute la zone
-                                            if is_selected || response_bg.hovered() {
-                                                let visuals = ui.style().visuals.clone();
-                                                let fill = if is_selected {
-                                                    visuals.selection.bg_fill
-                                                } else {
-                                                    visuals.widgets.hovered.weak_bg_fill
-                                                };
-                                                ui.painter().rect_filled(rect, 4.0, fill);
-                                            }
-                                            // Curseur main sur hover
-                                            if response_bg.hovered() {
-                                                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-                                            }
-                                            // Dessin du libellé aligné à gauche (pas de widget au-dessus pour que tout le rect capte le clic)
-                                            let text_pos = egui::pos2(rect.left() + 8.0, rect.center().y);
-                                            let font_id = egui::FontId::proportional(14.0);
-                                            let text_color = ui.visuals().text_color();
-                                            ui.painter().text(
-                                                text_pos,
-                                                egui::Align2::LEFT_CENTER,
-                                                &feed.title,
-                                                font_id,
-                                                text_color,
-                                            );
-                                            let row_clicked = response_bg.clicked();
+        let mut update = None;
+        ui.horizontal(|ui| {
+            if ui.button(tr("manager.select_all")).clicked() {
+                self.feed_manager
+                    .selected
+                    .extend(feeds.iter().map(|f| f.id.clone()));
+            }
+            if ui.button(tr("manager.select_none")).clicked() {
+                self.feed_manager.selected.clear();
+            }
+            ui.label(
+                egui::RichText::new(tr_count("manager.selected", selected.len() as i64, &[]))
+                    .color(secondary),
+            );
+        });
+        ui.add_enabled_ui(!selected.is_empty(), |ui| {
+            ui.horizontal_wrapped(|ui| {
+                if ui.button(tr("manager.delete")).clicked() {
+                    self.feed_manager.confirm_delete = true;
+                }
+                ui.separator();
+                let label = ui.label(tr("manager.category"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.feed_manager.category)
+                        .hint_text(tr("manager.category_hint"))
+                        .desired_width(140.0),
+                )
+                .labelled_by(label.id);
+                if ui.button(tr("common.apply")).clicked() {
+                    update = Some(FeedUpdate::Category(Some(
+                        self.feed_manager.category.clone(),
+                    )));
+                }
+                ui.separator();
+                let label = ui.label(tr("manager.interval"));
+                egui::ComboBox::from_id_source("manager_interval")
+                    .selected_text(poll_interval_choice_label(self.feed_manager.poll_interval))
+                    .show_ui(ui, |ui| {
+                        for choice in POLL_INTERVAL_CHOICES {
+                            ui.selectable_value(
+                                &mut self.feed_manager.poll_interval,
+                                choice,
+                                poll_interval_choice_label(choice),
+                            );
+                        }
+                    })
+                    .response
+                    .labelled_by(label.id);
+                if ui.button(tr("common.apply")).clicked() {
+                    update = Some(FeedUpdate::PollInterval(
+                        self.feed_manager
+                            .poll_interval
+                            .map(|minutes| std::time::Duration::from_secs(minutes * 60)),
+                    ));
+                }
+                ui.separator();
+                if ui.button(tr("manager.notifications_on")).clicked() {
+                    update = Some(FeedUpdate::Notifications(true));
+                }
+                if ui.button(tr("manager.notifications_off")).clicked() {
+                    update = Some(FeedUpdate::Notifications(false));
+                }
+            });
+        });
+        ui.separator();
 
-                                            if row_clicked {
-                                                self.selected_feed = Some(feed.id.clone());
-                                                self.current_view = AppView::ArticleList;
-                                                let persisted = self.runtime.block_on(
-                                                    self.data_api.list_articles(&feed.id),
-                                                );
-                                                if !persisted.is_empty() {
-                                                    self.articles.retain(|a| a.feed_id != feed.id);
-                                                    self.articles.extend(persisted);
-                                                    self.articles.sort_by(|a, b| {
-                                                        b.published_at.cmp(&a.published_at)
-                                                    });
-                                                    self.articles.truncate(
-                                                        self.config.ui.articles_per_page.max(1),
-                                                    );
-                                                } else {
-                                                    let fd = feed.clone();
-                                                    let events = self.runtime.block_on(async {
-                                                        poll_once(
-                                                            &[fd],
-                                                            &self.poll_config,
-                                                            &self.client,
-                                                            &self.seen_store,
-                                                        )
-                                                        .await
-                                                    });
-                                                    for evt in events {
-                                                        let Event::NewArticles(
-                                                            feed_id,
-                                                            mut entries,
-                                                        ) = evt;
-                                                        let to_persist = entries.clone();
-                                                        self.runtime.block_on(
-                                                            self.data_api.upsert_articles(
-                                                                &feed_id, to_persist,
-                                                            ),
-                                                        );
-                                                        self.articles.append(&mut entries);
-                                                    }
-                                                    self.articles.sort_by(|a, b| {
-                                                        b.published_at.cmp(&a.published_at)
-                                                    });
-                                                    self.articles.truncate(
-                                                        self.config.ui.articles_per_page.max(1),
-                                                    );
-                                                }
-                                            }
-                                            response_bg.on_hover_text(&feed.url);
-
-                                            ui.with_layout(
-                                                egui::Layout::right_to_left(egui::Align::Center),
-                                                |ui| {
-                                                    if ui
-                                                        .small_button("🗑")
-                                                        .on_hover_text("Supprimer ce flux")
-                                                        .clicked()
-                                                    {
-                                                        let runtime = self.runtime.clone();
-                                                        let feed_id = feed.id.clone();
-                                                        runtime.block_on(
-                                                            self.data_api.remove_feed(&feed_id),
-                                                        );
-                                                        self.articles
-                                                            .retain(|a| a.feed_id != feed.id);
-                                                        if self.selected_feed.as_ref()
-                                                            == Some(&feed.id)
-                                                        {
-                                                            self.selected_feed = None;
-                                                        }
-                                                    }
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("feed_manager_grid")
+                .striped(true)
+                .spacing(egui::vec2(12.0, 6.0))
+                .show(ui, |ui| {
+                    for header in [
+                        "health.col_feed",
+                        "manager.col_category",
+                        "manager.col_interval",
+                        "manager.col_notifications",
+                    ] {
+                        ui.label(egui::RichText::new(tr(header)).strong());
+                    }
+                    ui.end_row();
 
-                                                    if ui
-                                                        .small_button("⟳")
-                                                        .on_hover_text("Rafraîchir ce flux")
-                                                        .clicked()
-                                                    {
-                                                        let fd = feed.clone();
-                                                        let events = self.runtime.block_on(async {
-                                                            poll_once(
-                                                                &[fd],
-                                                                &self.poll_config,
-                                                                &self.client,
-                                                                &self.seen_store,
-                                                            )
-                                                            .await
-                                                        });
-                                                        for evt in events {
-                                                            let Event::NewArticles(
-                                                                feed_id,
-                                                                mut entries,
-                                                            ) = evt;
-                                                            let to_persist = entries.clone();
-                                                            self.runtime.block_on(
-                                                                self.data_api.upsert_articles(
-                                                                    &feed_id, to_persist,
-                                                                ),
-                                                            );
-                                                            self.articles
-                                                                .retain(|a| a.feed_id != feed_id);
-                                                            self.articles.append(&mut entries);
-                                                        }
-                                                        self.articles.sort_by(|a, b| {
-                                                            b.published_at.cmp(&a.published_at)
-                                                        });
-                                                        self.articles.truncate(
-                                                            self.config.ui.articles_per_page.max(1),
-                                                        );
-                                                    }
-                                                },
-                                            );
-                                        });
-                                    }
+                    for feed in &feeds {
+                        let mut checked = self.feed_manager.selected.contains(&feed.id);
+                        if ui
+                            .checkbox(&mut checked, &feed.title)
+                            .on_hover_text(feed.redacted_url())
+                            .changed()
+                        {
+                            if checked {
+                                self.feed_manager.selected.insert(feed.id.clone());
+                            } else {
+                                self.feed_manager.selected.remove(&feed.id);
+                            }
+                        }
+                        ui.label(feed.category.as_deref().unwrap_or("—"));
+                        match feed.poll_interval {
+                            Some(interval) => ui.label(interval_label(interval)),
+                            None => ui.label(
+                                egui::RichText::new(tr("manager.interval_global")).color(secondary),
+                            ),
+                        };
+                        if feed.notifications_disabled {
+                            ui.label(egui::RichText::new(tr("manager.off")).color(secondary));
+                        } else {
+                            ui.label(tr("manager.on"));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
 
-                                    if feeds.is_empty() && !self.feed_search.is_empty() {
-                                        ui.label(
-                                            egui::RichText::new("Aucune correspondance.")
-                                                .weak()
-                                                .size(13.0),
-                                        );
-                                    }
-                                });
-                        });
-                    });
+        if let Some(update) = update {
+            let result = self
+                .runtime
+                .block_on(self.data_api.update_feeds_bulk(&selected, update));
+            if let Some(count) = self.saved(result) {
+                self.push_toast(
+                    ToastLevel::Success,
+                    tr_count("manager.updated", count as i64, &[]),
+                );
+            }
+        }
+        if self.feed_manager.confirm_delete {
+            self.draw_bulk_delete_window(ui.ctx(), &selected);
+        }
+    }
+
+    fn draw_bulk_delete_window(&mut self, ctx: &egui::Context, selected: &[String]) {
+        // ===
+        // Confirmation de la suppression groupée; rien n’est supprimé sans « Supprimer ».
+        // ===
+        let mut open = true;
+        let mut confirmed = false;
+        let mut cancelled = false;
+        egui::Window::new(tr("manager.confirm_title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(tr_count(
+                    "manager.confirm_delete",
+                    selected.len() as i64,
+                    &[],
+                ));
+                ui.horizontal(|ui| {
+                    if ui.button(tr("manager.delete")).clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button(tr("common.cancel")).clicked() {
+                        cancelled = true;
+                    }
                 });
             });
+        if confirmed {
+            let result = self.runtime.block_on(self.data_api.remove_feeds(selected));
+            if let Some(count) = self.saved(result) {
+                article_list::remove_feeds(&mut self.articles, |id| {
+                    selected.iter().any(|s| s == id)
+                });
+                self.articles_revision += 1;
+                if self
+                    .selected_feed
+                    .as_ref()
+                    .is_some_and(|id| selected.contains(id))
+                {
+                    self.selected_feed = None;
+                    self.reset_pagination();
+                }
+                self.feed_manager.selected.clear();
+                self.push_toast(
+                    ToastLevel::Success,
+                    tr_count("manager.removed", count as i64, &[]),
+                );
+            }
+        }
+        if confirmed || cancelled || !open {
+            self.feed_manager.confirm_delete = false;
+        }
     }
 
-    fn draw_main_content(&mut self, ctx: &egui::Context) {
+    fn draw_stats(&mut self, ui: &mut egui::Ui) {
         // ===
         //
         //
-        // Contenu central: route vers la vue courante.
+        // Statistiques: articles reçus par jour sur STATS_DAYS jours (jour local de
+        // publication, barre « inconnu » pour les articles sans date), flux les plus actifs,
+        // lus / non lus, moyenne de nouveaux articles par récupération et volume téléchargé.
         //
         //
         // ===
-        egui::CentralPanel::default().show(ctx, |ui| match &self.current_view {
-            AppView::ArticleList => self.draw_article_list(ui),
-            AppView::ArticleDetail(article) => self.draw_article_detail(ui, (**article).clone()),
-            AppView::DiscoverHome => self.draw_discover_home(ui),
-            AppView::DiscoverCategory(name) => self.draw_discover_category(ui, name.clone()),
-            AppView::Settings => self.draw_settings(ui),
+        let secondary = self.config.theme.secondary_text_color32();
+        let accent = self.config.theme.accent_color32();
+        ui.horizontal(|ui| {
+            if ui.button(tr("common.back")).clicked() {
+                self.current_view = AppView::Settings;
+            }
+            ui.separator();
+            ui.heading(egui::RichText::new(tr("stats.heading")).heading());
+            if ui.button(tr("stats.refresh")).clicked() {
+                self.stats = None;
+            }
+        });
+        ui.separator();
+
+        let stats = match &self.stats {
+            Some(stats) => stats.clone(),
+            None => {
+                let range = StatsRange::last_days(chrono::Local::now().date_naive(), STATS_DAYS);
+                let stats = self.runtime.block_on(self.data_api.stats(range));
+                self.stats = Some(stats.clone());
+                stats
+            }
+        };
+        let Some((first, _)) = stats.per_day.first().copied() else {
+            return;
+        };
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(tr_args("stats.per_day", &[("days", &STATS_DAYS)])).strong(),
+            );
+            let unknown_x = stats.per_day.len() as f64 + 1.0;
+            let mut bars: Vec<egui_plot::Bar> = stats
+                .per_day
+                .iter()
+                .enumerate()
+                .map(|(i, (day, count))| {
+                    egui_plot::Bar::new(i as f64, *count as f64)
+                        .name(format_day(*day, "date.day"))
+                        .fill(accent)
+                })
+                .collect();
+            if stats.unknown_date > 0 {
+                bars.push(
+                    egui_plot::Bar::new(unknown_x, stats.unknown_date as f64)
+                        .name(tr("stats.unknown"))
+                        .fill(secondary),
+                );
+            }
+            let chart = egui_plot::BarChart::new(bars)
+                .width(0.8)
+                .element_formatter(Box::new(|bar, _| {
+                    tr_args("stats.bar", &[("day", &bar.name), ("count", &bar.value)])
+                }));
+            let days = stats.per_day.len() as f64;
+            egui_plot::Plot::new("stats_per_day")
+                .height(220.0)
+                .allow_zoom(false)
+                .allow_drag(false)
+                .allow_scroll(false)
+                .allow_boxed_zoom(false)
+                .allow_double_click_reset(false)
+                .include_y(0.0)
+                .show_x(false)
+                .x_axis_formatter(move |mark, _, _| {
+                    if mark.value == unknown_x {
+                        return tr("stats.unknown");
+                    }
+                    if mark.value.fract() != 0.0 || mark.value < 0.0 || mark.value >= days {
+                        return String::new();
+                    }
+                    first
+                        .checked_add_days(chrono::Days::new(mark.value as u64))
+                        .map_or_else(String::new, |day| format_day(day, "date.short_day"))
+                })
+                .y_axis_formatter(|mark, _, _| {
+                    if mark.value.fract() == 0.0 && mark.value >= 0.0 {
+                        format!("{}", mark.value)
+                    } else {
+                        String::new()
+                    }
+                })
+                .show(ui, |plot| plot.bar_chart(chart));
+            ui.add_space(8.0);
+
+            ui.horizontal_wrapped(|ui| {
+                ui.label(egui::RichText::new(tr("stats.read_unread")).strong());
+                ui.label(format!("{} / {}", stats.read, stats.unread));
+                if stats.total() > 0 {
+                    let share = stats.read as f32 / stats.total() as f32;
+                    ui.add(
+                        egui::ProgressBar::new(share)
+                            .desired_width(160.0)
+                            .text(tr_args(
+                                "stats.read_share",
+                                &[("percent", &format!("{:.0}", share * 100.0))],
+                            )),
+                    );
+                }
+                ui.separator();
+                ui.label(egui::RichText::new(tr("stats.average")).strong());
+                match stats.average_per_poll {
+                    Some(average) => ui.label(tr_args(
+                        "stats.average_value",
+                        &[("average", &format!("{:.1}", average))],
+                    )),
+                    None => {
+                        ui.label(egui::RichText::new(tr("stats.not_measured")).color(secondary))
+                    }
+                };
+            });
+            ui.horizontal_wrapped(|ui| {
+                ui.label(egui::RichText::new(tr("stats.downloaded")).strong());
+                let total = tr_args(
+                    "stats.downloaded_value",
+                    &[
+                        ("bytes", &format_bytes(stats.bytes_downloaded)),
+                        ("fetches", &stats.fetches),
+                    ],
+                );
+                ui.label(total).on_hover_text(tr("stats.downloaded_hover"));
+            });
+            if stats.unknown_date > 0 {
+                ui.label(
+                    egui::RichText::new(tr_args(
+                        "stats.undated",
+                        &[("count", &stats.unknown_date)],
+                    ))
+                    .color(secondary)
+                    .meta(),
+                );
+            }
+            ui.add_space(8.0);
+
+            let top_title = tr_args("stats.top_feeds", &[("count", &STATS_TOP_FEEDS)]);
+            ui.label(egui::RichText::new(top_title).strong());
+            if stats.top_feeds.is_empty() {
+                ui.label(egui::RichText::new(tr("stats.no_articles")).color(secondary));
+                return;
+            }
+            let feeds = self.feeds_snapshot();
+            let max = stats.top_feeds[0].1.max(1) as f32;
+            egui::Grid::new("stats_top_feeds")
+                .striped(true)
+                .spacing(egui::vec2(12.0, 4.0))
+                .show(ui, |ui| {
+                    for (feed_id, count) in &stats.top_feeds {
+                        let title = feeds
+                            .iter()
+                            .find(|f| f.id == *feed_id)
+                            .map_or(feed_id.as_str(), |f| f.title.as_str());
+                        ui.label(title);
+                        ui.add(
+                            egui::ProgressBar::new(*count as f32 / max)
+                                .desired_width(200.0)
+                                .text(count.to_string()),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    fn draw_logs(&mut self, ui: &mut egui::Ui) {
+        // ===
+        // Journal: derniers évènements tracing (niveau minimal, recherche), copiables pour un
+        // rapport de bug. Le tampon n’est lu que tant que la vue est ouverte.
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        ui.horizontal(|ui| {
+            if ui.button(tr("common.back")).clicked() {
+                self.current_view = AppView::Settings;
+            }
+            ui.separator();
+            ui.heading(egui::RichText::new(tr("logs.heading")).heading());
+        });
+        ui.separator();
+
+        let lines = self.logs.snapshot(self.log_level, &self.log_search);
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_source("log_level")
+                .selected_text(log_level_label(self.log_level))
+                .show_ui(ui, |ui| {
+                    for level in [
+                        tracing::Level::ERROR,
+                        tracing::Level::WARN,
+                        tracing::Level::INFO,
+                        tracing::Level::DEBUG,
+                        tracing::Level::TRACE,
+                    ] {
+                        ui.selectable_value(&mut self.log_level, level, log_level_label(level));
+                    }
+                });
+            ui.add(
+                egui::TextEdit::singleline(&mut self.log_search)
+                    .hint_text(tr("logs.search_hint"))
+                    .desired_width(240.0),
+            );
+            if ui
+                .button(tr("logs.copy"))
+                .on_hover_text(tr("logs.copy_hover"))
+                .clicked()
+            {
+                let text: Vec<String> = lines.iter().map(LogLine::to_text).collect();
+                ui.output_mut(|o| o.copied_text = text.join("\n"));
+                self.push_toast(
+                    ToastLevel::Info,
+                    tr_count("logs.copied", lines.len() as i64, &[]),
+                );
+            }
+            if ui.button(tr("logs.clear")).clicked() {
+                self.logs.clear();
+            }
         });
+        let dropped = self.logs.dropped();
+        if dropped > 0 {
+            ui.label(
+                egui::RichText::new(tr_count("logs.dropped", dropped as i64, &[]))
+                    .small()
+                    .color(secondary),
+            );
+        }
+        ui.separator();
+
+        if lines.is_empty() {
+            ui.label(egui::RichText::new(tr("logs.empty")).color(secondary));
+            return;
+        }
+        egui::ScrollArea::both()
+            .stick_to_bottom(true)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for line in &lines {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(line.time.format("%H:%M:%S").to_string())
+                                .monospace()
+                                .color(secondary),
+                        );
+                        ui.label(
+                            egui::RichText::new(format!("{:<5}", line.level))
+                                .monospace()
+                                .color(log_level_color(line.level, secondary)),
+                        );
+                        ui.label(
+                            egui::RichText::new(&line.target)
+                                .monospace()
+                                .color(secondary),
+                        );
+                        if let Some(feed) = &line.feed {
+                            ui.label(egui::RichText::new(feed).monospace());
+                        }
+                        ui.add(
+                            egui::Label::new(egui::RichText::new(&line.message).monospace())
+                                .selectable(true),
+                        );
+                    });
+                }
+            });
     }
 
-    fn draw_article_list(&mut self, ui: &mut egui::Ui) {
+    fn draw_settings(&mut self, ui: &mut egui::Ui) {
         // ===
-        // Liste/agrégat d’articles avec actions rapides.
+        // Page Paramètres: thème, interface, flux.
         // ===
+        let secondary = self.config.theme.secondary_text_color32();
         ui.horizontal(|ui| {
-            ui.heading(egui::RichText::new("📰 Articles RSS").size(18.0));
+            ui.heading(egui::RichText::new(tr("settings.heading")).heading());
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                ui.label(
-                    egui::RichText::new(format!("{} articles", self.articles.len())).size(13.0),
-                );
-                ui.separator();
-                ui.toggle_value(&mut self.show_unread_only, "Non lus");
-                ui.separator();
-                if ui
-                    .small_button("Tout marquer comme lu")
-                    .on_hover_text("Marquer tous les articles visibles comme lus")
-                    .clicked()
-                {
-                    let to_mark: Vec<FeedEntry> =
-                        self.filtered_articles().into_iter().cloned().collect();
-                    for entry in to_mark {
-                        self.runtime.block_on(self.data_api.mark_read(&entry));
-                    }
+                if ui.button(tr("health.heading")).clicked() {
+                    self.current_view = AppView::FeedHealth;
+                }
+                if ui.button(tr("manager.heading")).clicked() {
+                    self.current_view = AppView::FeedManager;
+                }
+                if ui.button(tr("logs.heading")).clicked() {
+                    self.current_view = AppView::Logs;
+                }
+                if ui.button(tr("stats.heading")).clicked() {
+                    self.stats = None;
+                    self.current_view = AppView::Stats;
                 }
             });
         });
         ui.separator();
 
-        egui::ScrollArea::vertical()
-            .auto_shrink([false, true])
-            .show(ui, |ui| {
-                let articles: Vec<FeedEntry> =
-                    self.filtered_articles().into_iter().cloned().collect();
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Frame::group(ui.style())
+                .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("settings.theme")).strong().title());
+                        ui.separator();
 
-                let aggregated_view = self.selected_feed.is_none();
-                use std::collections::HashMap;
-                let mut feed_title_map: HashMap<String, String> = HashMap::new();
-                if aggregated_view {
-                    for f in self.feeds_snapshot() {
-                        feed_title_map.insert(f.id.clone(), f.title.clone());
-                    }
-                }
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.preset"));
+                            let current = self.config.theme.preset;
+                            egui::ComboBox::from_id_source("theme_preset")
+                                .selected_text(theme_preset_label(current))
+                                .show_ui(ui, |ui| {
+                                    for preset in ThemePreset::ALL {
+                                        if ui
+                                            .selectable_label(
+                                                preset == current,
+                                                theme_preset_label(preset),
+                                            )
+                                            .clicked()
+                                            && preset != current
+                                        {
+                                            self.config.theme.apply_preset(preset);
+                                            let _ = self.config.save();
+                                        }
+                                    }
+                                });
+                        });
 
-                if articles.is_empty() {
-                    ui.vertical_centered(|ui| {
-                        ui.add_space(50.0);
-                        ui.label(egui::RichText::new("📭 Aucun article disponible").size(16.0));
-                        ui.label(
-                            egui::RichText::new("Ajoutez des flux RSS pour voir des articles")
-                                .size(14.0),
+                        let theme = &mut self.config.theme;
+                        let mut edited = false;
+                        edited |= color_row(
+                            ui,
+                            &tr("settings.color_background"),
+                            &mut theme.background_color,
                         );
+                        edited |=
+                            color_row(ui, &tr("settings.color_panel"), &mut theme.panel_color);
+                        edited |=
+                            color_row(ui, &tr("settings.color_accent"), &mut theme.accent_color);
+                        edited |= color_row(ui, &tr("settings.color_text"), &mut theme.text_color);
+                        edited |= color_row(
+                            ui,
+                            &tr("settings.color_secondary"),
+                            &mut theme.secondary_text_color,
+                        );
+                        edited |=
+                            color_row(ui, &tr("settings.color_border"), &mut theme.border_color);
+                        if edited {
+                            theme.preset = ThemePreset::Custom;
+                            let _ = self.config.save();
+                        }
+
+                        // Couleurs Custom: signale un texte sous le seuil WCAG AA.
+                        let contrast = self.config.theme.min_text_contrast();
+                        let custom = self.config.theme.preset == ThemePreset::Custom;
+                        if custom && contrast < WCAG_AA_CONTRAST {
+                            ui.label(
+                                egui::RichText::new(tr_args(
+                                    "settings.low_contrast",
+                                    &[("ratio", &format!("{:.1}", contrast))],
+                                ))
+                                .color(ui.visuals().warn_fg_color)
+                                .meta(),
+                            );
+                        }
+
+                        if ui.button(tr("settings.reset_theme")).clicked() {
+                            self.config.theme = rss_core::ThemeConfig::default();
+                            let _ = self.config.save();
+                        }
                     });
-                    return;
-                }
+                });
 
-                ui.add_space(4.0);
+            ui.add_space(2.0);
 
-                for article in articles {
-                    if self.show_unread_only
-                        && self.runtime.block_on(self.data_api.is_read(&article))
-                    {
-                        continue;
-                    }
-                    egui::Frame::group(ui.style()).show(ui, |ui| {
-                        ui.set_width(ui.available_width());
-                        ui.set_min_height(128.0);
-                        ui.vertical(|ui| {
-                            let is_read = self.runtime.block_on(self.data_api.is_read(&article));
+            egui::Frame::group(ui.style())
+                .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(
+                            egui::RichText::new(tr("settings.interface"))
+                                .strong()
+                                .title(),
+                        );
+                        ui.separator();
 
-                            let title_text = if is_read {
-                                egui::RichText::new(&article.title)
-                                    .weak()
-                                    .italics()
-                                    .size(16.0)
-                            } else {
-                                egui::RichText::new(&article.title).strong().size(17.0)
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.language"));
+                            let previous = self.config.ui.language.clone();
+                            let selected = match &previous {
+                                Some(code) => i18n::language_name(code),
+                                None => tr("settings.language_system"),
                             };
-                            let title_response = ui.add(
-                                egui::Label::new(title_text)
-                                    .wrap(true)
-                                    .sense(egui::Sense::click()),
-                            );
+                            egui::ComboBox::from_id_source("ui_language")
+                                .selected_text(selected)
+                                .show_ui(ui, |ui| {
+                                    let language = &mut self.config.ui.language;
+                                    ui.selectable_value(
+                                        language,
+                                        None,
+                                        tr("settings.language_system"),
+                                    );
+                                    for code in i18n::available_languages() {
+                                        let name = i18n::language_name(code);
+                                        ui.selectable_value(language, Some(code.to_string()), name);
+                                    }
+                                });
+                            if self.config.ui.language != previous {
+                                i18n::set_language(self.config.ui.language.as_deref());
+                                let _ = self.config.save();
+                            }
+                        });
 
-                            if title_response.clicked() {
-                                self.current_view =
-                                    AppView::ArticleDetail(Box::new(article.clone()));
-                                self.runtime.block_on(self.data_api.mark_read(&article));
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.font_size"));
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut self.config.ui.font_size, 10.0..=24.0)
+                                        .suffix(" px"),
+                                )
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                let _ = self.config.save();
                             }
+                        });
 
-                            ui.add_space(5.0);
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.dates"));
+                            let previous = self.config.ui.date_format;
+                            for (format, label) in [
+                                (DateFormat::Relative, tr("settings.dates_relative")),
+                                (DateFormat::Absolute, tr("settings.dates_absolute")),
+                                (DateFormat::Both, tr("settings.dates_both")),
+                            ] {
+                                ui.radio_value(&mut self.config.ui.date_format, format, label);
+                            }
+                            if self.config.ui.date_format != previous {
+                                let _ = self.config.save();
+                            }
+                        });
 
-                            ui.horizontal_wrapped(|ui| {
-                                if let Some(author) = &article.author {
-                                    ui.label(
-                                        egui::RichText::new(format!("👤 {}", author))
-                                            .weak()
-                                            .size(12.0),
-                                    );
-                                    ui.separator();
-                                }
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.layout"));
+                            let previous = self.config.ui.layout;
+                            for (layout, label) in [
+                                (Layout::SinglePane, tr("settings.layout_single")),
+                                (Layout::SplitHorizontal, tr("settings.layout_split")),
+                            ] {
+                                ui.radio_value(&mut self.config.ui.layout, layout, label);
+                            }
+                            if self.config.ui.layout != previous {
+                                let _ = self.config.save();
+                            }
+                        });
 
-                                if let Some(category) = &article.category {
-                                    ui.label(
-                                        egui::RichText::new(format!("🏷 {}", category))
-                                            .weak()
-                                            .size(12.0),
-                                    );
-                                    ui.separator();
-                                }
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.articles_per_page"));
+                            if ui
+                                .add(egui::Slider::new(
+                                    &mut self.config.ui.articles_per_page,
+                                    10..=500,
+                                ))
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                let _ = self.config.save();
+                            }
+                        });
 
-                                if let Some(date) = article.published_at {
-                                    ui.label(
-                                        egui::RichText::new(format!(
-                                            "📅 {}",
-                                            date.format("%d/%m/%Y %H:%M")
-                                        ))
-                                        .weak()
-                                        .size(12.0),
-                                    );
-                                }
-                            });
+                        if ui
+                            .checkbox(
+                                &mut self.config.ui.show_article_preview,
+                                tr("settings.show_article_preview"),
+                            )
+                            .changed()
+                        {
+                            let _ = self.config.save();
+                        }
 
-                            ui.add_space(3.0);
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.title_similarity"));
+                            let response = ui
+                                .add(egui::Slider::new(
+                                    &mut self.config.ui.title_similarity,
+                                    0.5..=1.0,
+                                ))
+                                .on_hover_text(tr("settings.title_similarity_hover"));
+                            // Regroupement recalculé une fois le curseur relâché.
+                            if response.drag_stopped()
+                                || (response.changed() && !response.dragged())
+                            {
+                                let _ = self.config.save();
+                                self.rebuild_title_clusters();
+                            }
+                        });
 
-                            if self.config.ui.show_article_preview {
-                                let preview_text = if let Some(html) = &article.content_html {
-                                    html2text::from_read(html.as_bytes(), 100)
-                                } else if let Some(summary) = &article.summary {
-                                    html2text::from_read(summary.as_bytes(), 100)
-                                } else {
-                                    String::new()
-                                };
-                                let preview_trunc = {
-                                    let max_chars = 300usize;
-                                    if preview_text.chars().count() > max_chars {
-                                        let mut s: String = preview_text
-                                            .chars()
-                                            .take(max_chars.saturating_sub(3))
-                                            .collect();
-                                        s.push_str("...");
-                                        s
-                                    } else {
-                                        preview_text
-                                    }
-                                };
-                                if !preview_trunc.is_empty() {
-                                    ui.label(egui::RichText::new(preview_trunc).weak().size(13.0));
-                                }
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.reading_wpm"));
+                            let response = ui
+                                .add(
+                                    egui::DragValue::new(&mut self.config.ui.reading_wpm)
+                                        .clamp_range(60..=1000)
+                                        .suffix(tr("unit.wpm_suffix")),
+                                )
+                                .on_hover_text(tr("settings.reading_wpm_hover"));
+                            if response.changed() {
+                                let _ = self.config.save();
                             }
+                        });
 
-                            ui.add_space(5.0);
+                        if ui
+                            .checkbox(&mut self.config.ui.load_images, tr("settings.load_images"))
+                            .on_hover_text(tr("settings.load_images_hover"))
+                            .changed()
+                        {
+                            let _ = self.config.save();
+                        }
 
-                            ui.horizontal(|ui| {
-                                if ui.small_button("📖 Lire").clicked() {
-                                    self.current_view =
-                                        AppView::ArticleDetail(Box::new(article.clone()));
-                                    self.runtime.block_on(self.data_api.mark_read(&article));
-                                }
+                        if ui
+                            .checkbox(
+                                &mut self.config.ui.minimize_to_tray,
+                                tr("settings.minimize_to_tray"),
+                            )
+                            .on_hover_text(tr("settings.minimize_to_tray_hover"))
+                            .changed()
+                        {
+                            let _ = self.config.save();
+                        }
+                    });
+                });
 
-                                if ui.small_button("🔗 Ouvrir").clicked() {
-                                    if let Err(e) = webbrowser::open(&article.url) {
-                                        eprintln!("Erreur lors de l'ouverture du lien: {}", e);
-                                    }
-                                }
-                                if is_read {
-                                    ui.label(egui::RichText::new("Lu").weak().size(12.0));
-                                } else {
-                                    ui.label(
-                                        egui::RichText::new("• Non lu")
-                                            .color(Color32::from_rgb(0, 122, 204))
-                                            .size(12.0),
-                                    );
-                                }
-                            });
+            ui.add_space(2.0);
 
-                            if aggregated_view {
-                                let feed_name = feed_title_map
-                                    .get(&article.feed_id)
-                                    .cloned()
-                                    .unwrap_or_else(|| "Flux inconnu".to_string());
-                                let color = color_for_feed(&article.feed_id);
-                                let bar_h = 16.0;
-                                let width = ui.available_width();
-                                ui.allocate_ui_with_layout(
-                                    egui::vec2(width, bar_h),
-                                    egui::Layout::right_to_left(egui::Align::Center),
-                                    |ui| {
-                                        let max_w = 180.0;
-                                        let label = egui::Label::new(
-                                            egui::RichText::new(feed_name).color(color).size(12.0),
-                                        )
-                                        .truncate(true);
-                                        ui.add_sized(egui::vec2(max_w, 14.0), label);
-                                    },
-                                );
+            egui::Frame::group(ui.style())
+                .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("sidebar.feeds")).strong().title());
+                        ui.separator();
+
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.update_interval"));
+                            if ui
+                                .add(
+                                    egui::Slider::new(
+                                        &mut self.config.feeds.update_interval_minutes,
+                                        1..=120,
+                                    )
+                                    .suffix(" min"),
+                                )
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
                             }
                         });
-                    });
 
-                    ui.add_space(5.0);
-                }
-            });
-    }
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.max_articles"));
+                            if ui
+                                .add(egui::Slider::new(
+                                    &mut self.config.feeds.max_articles_per_feed,
+                                    10..=500,
+                                ))
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                self.data_api.set_max_articles_per_feed(
+                                    self.config.feeds.max_articles_per_feed,
+                                );
+                                let _ = self.config.save();
+                            }
+                        });
 
-    fn draw_article_detail(&mut self, ui: &mut egui::Ui, article: FeedEntry) {
-        // ===
-        // Détail d’un article (texte simplifié) et actions.
-        // ===
-        ui.horizontal(|ui| {
-            if ui.button("← Retour").clicked() {
-                self.current_view = AppView::ArticleList;
-            }
-            ui.separator();
-            ui.heading(egui::RichText::new("📖 Lecture d'article").size(18.0));
-        });
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.request_timeout"));
+                            if ui
+                                .add(
+                                    egui::Slider::new(
+                                        &mut self.config.feeds.request_timeout_seconds,
+                                        5..=60,
+                                    )
+                                    .suffix(tr("unit.seconds_suffix")),
+                                )
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
+                            }
+                        });
 
-        ui.separator();
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.retry_attempts"));
+                            if ui
+                                .add(egui::Slider::new(
+                                    &mut self.config.feeds.retry_attempts,
+                                    1..=10,
+                                ))
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
+                            }
+                        });
 
-        egui::ScrollArea::vertical()
-            .auto_shrink([false, true])
-            .show(ui, |ui| {
-                ui.group(|group| {
-                    group.vertical(|ui| {
-                        // Titre de l'article
-                        ui.label(egui::RichText::new(&article.title).strong().size(22.0));
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.disable_after"));
+                            if ui
+                                .add(egui::Slider::new(
+                                    &mut self.config.feeds.disable_after_failures,
+                                    0..=50,
+                                ))
+                                .labelled_by(label.id)
+                                .on_hover_text(tr("settings.disable_after_hover"))
+                                .changed()
+                            {
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
+                            }
+                        });
 
-                        ui.add_space(10.0);
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.max_content"));
+                            let mut kib = self.config.feeds.max_content_bytes / 1024;
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut kib, 16..=2048)
+                                        .suffix(tr("unit.kib_suffix")),
+                                )
+                                .labelled_by(label.id)
+                                .changed()
+                            {
+                                self.config.feeds.max_content_bytes = kib * 1024;
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
+                            }
+                        });
 
-                        // Métadonnées
-                        ui.horizontal_wrapped(|ui| {
-                            if let Some(author) = &article.author {
-                                ui.label(
-                                    egui::RichText::new(format!("👤 Auteur: {}", author))
-                                        .size(14.0),
-                                );
-                                ui.separator();
+                        ui.horizontal(|ui| {
+                            let label = ui.label(tr("settings.max_feed"));
+                            let mut mib = self.config.feeds.max_feed_bytes / (1024 * 1024);
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut mib, 1..=50)
+                                        .suffix(tr("unit.mib_suffix")),
+                                )
+                                .labelled_by(label.id)
+                                .on_hover_text(tr("settings.max_feed_hover"))
+                                .changed()
+                            {
+                                self.config.feeds.max_feed_bytes = mib * 1024 * 1024;
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
                             }
+                        });
 
-                            if let Some(category) = &article.category {
-                                ui.label(
-                                    egui::RichText::new(format!("🏷 Catégorie: {}", category))
-                                        .size(14.0),
-                                );
-                                ui.separator();
+                        ui.horizontal(|ui| {
+                            let mut enabled = self.config.feeds.quiet_hours.is_some();
+                            let mut changed = ui
+                                .checkbox(&mut enabled, tr("settings.quiet_hours"))
+                                .on_hover_text(tr("settings.quiet_hours_hover"))
+                                .changed();
+                            let default_window = (
+                                NaiveTime::from_hms_opt(23, 0, 0).unwrap_or_default(),
+                                NaiveTime::from_hms_opt(7, 0, 0).unwrap_or_default(),
+                            );
+                            let (mut start, mut end) =
+                                self.config.feeds.quiet_hours.unwrap_or(default_window);
+                            ui.add_enabled_ui(enabled, |ui| {
+                                ui.label(tr("settings.quiet_from"));
+                                changed |= time_picker(ui, &mut start);
+                                ui.label(tr("settings.quiet_to"));
+                                changed |= time_picker(ui, &mut end);
+                                changed |= ui
+                                    .checkbox(
+                                        &mut self.config.feeds.quiet_hours_catch_up,
+                                        tr("settings.quiet_catch_up"),
+                                    )
+                                    .changed();
+                            });
+                            if changed {
+                                self.config.feeds.quiet_hours = enabled.then_some((start, end));
+                                self.poll_config_changed_at = Some(std::time::Instant::now());
                             }
+                        });
 
-                            if let Some(date) = article.published_at {
-                                ui.label(
-                                    egui::RichText::new(format!(
-                                        "📅 Publié le: {}",
-                                        date.format("%d/%m/%Y à %H:%M")
-                                    ))
-                                    .size(14.0),
-                                );
+                        if ui
+                            .checkbox(
+                                &mut self.config.feeds.adaptive_polling,
+                                tr("settings.adaptive"),
+                            )
+                            .on_hover_text(tr("settings.adaptive_hover"))
+                            .changed()
+                        {
+                            self.poll_config_changed_at = Some(std::time::Instant::now());
+                        }
+
+                        if ui
+                            .checkbox(
+                                &mut self.config.feeds.auto_update_moved_feeds,
+                                tr("settings.update_moved"),
+                            )
+                            .on_hover_text(tr("settings.update_moved_hover"))
+                            .changed()
+                        {
+                            self.service.set_auto_update_moved_feeds(
+                                self.config.feeds.auto_update_moved_feeds,
+                            );
+                            let _ = self.config.save();
+                        }
+                    });
+                });
+
+            ui.add_space(2.0);
+
+            egui::Frame::group(ui.style())
+                .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("settings.network")).strong().title());
+                        ui.separator();
+
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.proxy"));
+                            let proxy = self
+                                .config
+                                .network
+                                .proxy_url
+                                .get_or_insert_with(String::new);
+                            ui.add(
+                                egui::TextEdit::singleline(proxy)
+                                    .hint_text("http://proxy.local:3128"),
+                            );
+                        });
+
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.user_agent"));
+                            let ua = self
+                                .config
+                                .network
+                                .user_agent
+                                .get_or_insert_with(String::new);
+                            ui.add(
+                                egui::TextEdit::singleline(ua)
+                                    .hint_text(rss_core::DEFAULT_USER_AGENT),
+                            );
+                        });
+
+                        ui.checkbox(
+                            &mut self.config.network.accept_invalid_certs,
+                            tr("settings.accept_invalid_certs"),
+                        );
+
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.allow_http"));
+                            let policy = &mut self.config.network.allow_http;
+                            egui::ComboBox::from_id_source("network_allow_http")
+                                .selected_text(allow_http_label(*policy))
+                                .show_ui(ui, |ui| {
+                                    for option in [
+                                        AllowHttp::Never,
+                                        AllowHttp::LoopbackOnly,
+                                        AllowHttp::PrivateNetworks,
+                                        AllowHttp::Always,
+                                    ] {
+                                        ui.selectable_value(
+                                            policy,
+                                            option,
+                                            allow_http_label(option),
+                                        );
+                                    }
+                                });
+                        });
+
+                        ui.horizontal(|ui| {
+                            if ui.button(tr("common.apply")).clicked() {
+                                self.apply_network_config();
                             }
                         });
+                    });
+                });
+
+            ui.add_space(2.0);
+
+            egui::Frame::group(ui.style())
+                .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("settings.websub")).strong().title());
+                        ui.separator();
+                        self.draw_websub_settings(ui);
+                    });
+                });
 
+            ui.add_space(2.0);
+
+            egui::Frame::group(ui.style())
+                .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("settings.sync")).strong().title());
                         ui.separator();
 
-                        if let Some(html) = &article.content_html {
-                            let text = html2text::from_read(html.as_bytes(), 100);
-                            ui.label(egui::RichText::new(text).size(15.0));
-                        } else if let Some(summary) = &article.summary {
-                            let text = html2text::from_read(summary.as_bytes(), 100);
-                            ui.label(egui::RichText::new(text).size(15.0));
-                        } else {
-                            ui.label(
-                                egui::RichText::new("Aucun contenu disponible")
-                                    .weak()
-                                    .size(15.0),
-                            );
+                        // 0 = locale, 1 = Fever, 2 = Google Reader
+                        let current = match self.config.sync {
+                            SyncMode::Local => 0,
+                            SyncMode::Fever { .. } => 1,
+                            SyncMode::GReader { .. } => 2,
+                        };
+                        let mut choice = current;
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut choice, 0, tr("settings.sync_local"));
+                            ui.radio_value(&mut choice, 1, tr("settings.sync_fever"));
+                            ui.radio_value(&mut choice, 2, tr("settings.sync_greader"));
+                        });
+                        if choice != current {
+                            self.config.sync = match choice {
+                                1 => SyncMode::Fever {
+                                    endpoint: String::new(),
+                                    api_key: Secret::Plaintext(String::new()),
+                                },
+                                2 => SyncMode::GReader {
+                                    endpoint: String::new(),
+                                    username: String::new(),
+                                    password: Secret::Plaintext(String::new()),
+                                },
+                                _ => SyncMode::Local,
+                            };
                         }
 
-                        ui.add_space(20.0);
+                        match &mut self.config.sync {
+                            SyncMode::Local => {}
+                            SyncMode::Fever {
+                                endpoint,
+                                api_key: Secret::Plaintext(api_key),
+                            } => {
+                                ui.horizontal(|ui| {
+                                    ui.label(tr("settings.api_url"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(endpoint)
+                                            .hint_text("https://rss.example.org/api/fever.php"),
+                                    );
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(tr("settings.api_key"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(api_key)
+                                            .password(true)
+                                            .hint_text(tr("settings.api_key_hint")),
+                                    );
+                                });
+                            }
+                            SyncMode::GReader {
+                                endpoint,
+                                username,
+                                password: Secret::Plaintext(password),
+                            } => {
+                                ui.horizontal(|ui| {
+                                    ui.label(tr("settings.api_url"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(endpoint)
+                                            .hint_text("https://rss.example.org/api/greader.php"),
+                                    );
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(tr("settings.username"));
+                                    ui.text_edit_singleline(username);
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(tr("settings.api_password"));
+                                    ui.add(egui::TextEdit::singleline(password).password(true));
+                                });
+                            }
+                        }
 
                         ui.horizontal(|ui| {
-                            if ui.button("Ouvrir dans le navigateur").clicked() {
-                                if let Err(e) = webbrowser::open(&article.url) {
-                                    eprintln!("Erreur lors de l'ouverture du lien: {}", e);
+                            if ui.button(tr("common.apply")).clicked() {
+                                match self.apply_sync_mode() {
+                                    Ok(()) => {
+                                        let _ = self.config.save();
+                                        self.service.poll_now();
+                                        self.push_toast(ToastLevel::Success, tr("toast.applied"));
+                                    }
+                                    Err(e) => self.push_toast(ToastLevel::Error, e),
                                 }
                             }
-
-                            if ui.button("Copier le lien").clicked() {
-                                ui.output_mut(|o| o.copied_text = article.url.clone());
-                            }
-
                         });
-                        
                     });
                 });
-            });
-    }
 
-    fn draw_settings(&mut self, ui: &mut egui::Ui) {
-        // ===
-        // Page Paramètres: thème, interface, flux.
-        // ===
-        ui.heading(egui::RichText::new("⚙ Paramètres").size(18.0));
-        ui.separator();
+            ui.add_space(2.0);
 
-        egui::ScrollArea::vertical().show(ui, |ui| {
             egui::Frame::group(ui.style())
                 .inner_margin(egui::Margin::symmetric(4.0, 4.0))
                 .show(ui, |ui| {
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("🎨 Thème").strong().size(16.0));
-                    ui.separator();
+                    ui.vertical(|ui| {
+                        ui.label(
+                            egui::RichText::new(tr("settings.read_later"))
+                                .strong()
+                                .title(),
+                        );
+                        ui.separator();
 
-                    ui.horizontal(|ui| {
-                        ui.label("Couleur d'arrière-plan:");
-                        let mut bg = [
-                            self.config.theme.background_color[0] as f32 / 255.0,
-                            self.config.theme.background_color[1] as f32 / 255.0,
-                            self.config.theme.background_color[2] as f32 / 255.0,
-                        ];
-                        if ui.color_edit_button_rgb(&mut bg).changed() {
-                            self.config.theme.background_color = [
-                                (bg[0] * 255.0) as u8,
-                                (bg[1] * 255.0) as u8,
-                                (bg[2] * 255.0) as u8,
-                            ];
-                            let _ = self.config.save();
+                        let mut enabled = self.config.integrations.wallabag.is_some();
+                        if ui.checkbox(&mut enabled, tr("settings.enable")).changed() {
+                            self.config.integrations.wallabag = enabled.then(|| WallabagConfig {
+                                url: String::new(),
+                                client_id: String::new(),
+                                client_secret: Secret::Plaintext(String::new()),
+                                username: String::new(),
+                                password: Secret::Plaintext(String::new()),
+                            });
                         }
-                    });
 
-                    ui.horizontal(|ui| {
-                        ui.label("Couleur du panneau:");
-                        let mut panel = [
-                            self.config.theme.panel_color[0] as f32 / 255.0,
-                            self.config.theme.panel_color[1] as f32 / 255.0,
-                            self.config.theme.panel_color[2] as f32 / 255.0,
-                        ];
-                        if ui.color_edit_button_rgb(&mut panel).changed() {
-                            self.config.theme.panel_color = [
-                                (panel[0] * 255.0) as u8,
-                                (panel[1] * 255.0) as u8,
-                                (panel[2] * 255.0) as u8,
-                            ];
-                            let _ = self.config.save();
+                        if let Some(WallabagConfig {
+                            url,
+                            client_id,
+                            client_secret: Secret::Plaintext(client_secret),
+                            username,
+                            password: Secret::Plaintext(password),
+                        }) = &mut self.config.integrations.wallabag
+                        {
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings.url"));
+                                ui.add(
+                                    egui::TextEdit::singleline(url)
+                                        .hint_text("https://wallabag.example.org"),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings.client_id"));
+                                ui.text_edit_singleline(client_id);
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings.client_secret"));
+                                ui.add(egui::TextEdit::singleline(client_secret).password(true));
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings.username"));
+                                ui.text_edit_singleline(username);
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings.password"));
+                                ui.add(egui::TextEdit::singleline(password).password(true));
+                            });
                         }
-                    });
 
-                    ui.horizontal(|ui| {
-                        ui.label("Couleur d'accent:");
-                        let mut accent = [
-                            self.config.theme.accent_color[0] as f32 / 255.0,
-                            self.config.theme.accent_color[1] as f32 / 255.0,
-                            self.config.theme.accent_color[2] as f32 / 255.0,
-                        ];
-                        if ui.color_edit_button_rgb(&mut accent).changed() {
-                            self.config.theme.accent_color = [
-                                (accent[0] * 255.0) as u8,
-                                (accent[1] * 255.0) as u8,
-                                (accent[2] * 255.0) as u8,
-                            ];
-                            let _ = self.config.save();
-                        }
+                        ui.horizontal(|ui| {
+                            if ui.button(tr("common.apply")).clicked() {
+                                match self.apply_read_later_config() {
+                                    Ok(()) => {
+                                        let _ = self.config.save();
+                                        self.push_toast(ToastLevel::Success, tr("toast.applied"));
+                                    }
+                                    Err(e) => self.push_toast(ToastLevel::Error, e),
+                                }
+                            }
+                        });
                     });
-
-                    if ui
-                        .button("🔄 Réinitialiser aux valeurs par défaut")
-                        .clicked()
-                    {
-                        self.config.theme = rss_core::ThemeConfig::default();
-                        let _ = self.config.save();
-                    }
                 });
-            });
 
             ui.add_space(2.0);
 
             egui::Frame::group(ui.style())
                 .inner_margin(egui::Margin::symmetric(4.0, 4.0))
                 .show(ui, |ui| {
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("🖥 Interface").strong().size(16.0));
-                    ui.separator();
-
-                    ui.horizontal(|ui| {
-                        ui.label("Taille de police:");
-                        if ui
-                            .add(
-                                egui::Slider::new(&mut self.config.ui.font_size, 10.0..=24.0)
-                                    .suffix(" px"),
-                            )
-                            .changed()
-                        {
-                            let _ = self.config.save();
-                        }
-                    });
-
-                    // Paramètre supprimé de l'UI: Largeur du panneau de gauche
-
-                    ui.horizontal(|ui| {
-                        ui.label("Articles par page:");
-                        if ui
-                            .add(egui::Slider::new(
-                                &mut self.config.ui.articles_per_page,
-                                10..=500,
-                            ))
-                            .changed()
-                        {
-                            let _ = self.config.save();
-                        }
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("settings.rules")).strong().title());
+                        ui.separator();
+                        self.draw_rules_editor(ui);
                     });
-
-                    if ui
-                        .checkbox(
-                            &mut self.config.ui.show_article_preview,
-                            "Afficher les aperçus d'articles",
-                        )
-                        .changed()
-                    {
-                        let _ = self.config.save();
-                    }
                 });
-            });
 
             ui.add_space(2.0);
 
             egui::Frame::group(ui.style())
                 .inner_margin(egui::Margin::symmetric(4.0, 4.0))
                 .show(ui, |ui| {
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("📡 Flux RSS").strong().size(16.0));
-                    ui.separator();
-
-                    ui.horizontal(|ui| {
-                        ui.label("Intervalle de mise à jour:");
-                        ui.add(
-                            egui::Slider::new(
-                                &mut self.config.feeds.update_interval_minutes,
-                                1..=120,
-                            )
-                            .suffix(" min"),
-                        );
-                    });
-
-                    ui.horizontal(|ui| {
-                        ui.label("Articles max par flux:");
-                        ui.add(egui::Slider::new(
-                            &mut self.config.feeds.max_articles_per_feed,
-                            10..=500,
-                        ));
-                    });
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr("settings.data")).strong().title());
+                        ui.separator();
 
-                    ui.horizontal(|ui| {
-                        ui.label("Timeout des requêtes:");
-                        ui.add(
-                            egui::Slider::new(
-                                &mut self.config.feeds.request_timeout_seconds,
-                                5..=60,
-                            )
-                            .suffix(" sec"),
-                        );
-                    });
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.state_file"));
+                            ui.add(egui::TextEdit::singleline(&mut self.state_path));
+                        });
 
-                    ui.horizontal(|ui| {
-                        ui.label("Tentatives de réessai:");
-                        ui.add(egui::Slider::new(
-                            &mut self.config.feeds.retry_attempts,
-                            1..=10,
-                        ));
-                    });
-                });
-            });
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.export_dir"));
+                            ui.add(egui::TextEdit::singleline(&mut self.export_dir));
+                            if ui
+                                .button("📂")
+                                .icon_hint(&tr("settings.open_export_dir"))
+                                .clicked()
+                            {
+                                let dir = PathBuf::from(&self.export_dir);
+                                self.reveal_path(ui.ctx(), &dir);
+                            }
+                        });
 
-            ui.add_space(2.0);
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings.starred_feed"));
+                            let hint = self.starred_feed_file().display().to_string();
+                            let starred = &mut self.config.integrations.starred_feed;
+                            ui.add(egui::TextEdit::singleline(&mut starred.path).hint_text(hint));
+                            egui::ComboBox::from_id_source("starred_feed_format")
+                                .selected_text(feed_format_label(starred.format))
+                                .show_ui(ui, |ui| {
+                                    for format in [FeedFormat::Rss, FeedFormat::Atom] {
+                                        ui.selectable_value(
+                                            &mut starred.format,
+                                            format,
+                                            feed_format_label(format),
+                                        );
+                                    }
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(tr("settings.export_starred_feed"))
+                                .on_hover_text(tr("settings.export_starred_feed_hover"))
+                                .clicked()
+                            {
+                                self.export_starred_feed();
+                            }
+                            if ui
+                                .checkbox(
+                                    &mut self.config.integrations.starred_feed.auto_export,
+                                    tr("settings.starred_feed_auto"),
+                                )
+                                .changed()
+                            {
+                                let _ = self.config.save();
+                            }
+                        });
 
-            ui.horizontal(|ui| {
-                if ui.button("🗂 Ouvrir le dossier de configuration").clicked() {
-                    if let Ok(config_path) = rss_core::AppConfig::config_file_path() {
-                        if let Some(parent) = config_path.parent() {
-                            #[cfg(target_os = "windows")]
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(tr("settings.export_data"))
+                                .on_hover_text(tr("settings.export_data_hover"))
+                                .clicked()
+                            {
+                                match self
+                                    .runtime
+                                    .block_on(self.data_api.export_state(&self.state_path))
+                                {
+                                    Ok(()) => {
+                                        self.last_export = Some(PathBuf::from(&self.state_path));
+                                        self.push_toast(
+                                            ToastLevel::Success,
+                                            tr("toast.data_exported"),
+                                        )
+                                    }
+                                    Err(e) => self.push_toast(ToastLevel::Error, e.to_string()),
+                                }
+                            }
+                            if let Some(path) = self.last_export.clone() {
+                                if ui
+                                    .button(tr("settings.show_export"))
+                                    .on_hover_text(path.display().to_string())
+                                    .clicked()
+                                {
+                                    self.reveal_path(ui.ctx(), &path);
+                                }
+                            }
+                            if ui
+                                .button(tr("settings.import_data"))
+                                .on_hover_text(tr("settings.import_data_hover"))
+                                .clicked()
                             {
-                                let mut cmd = std::process::Command::new("explorer");
-                                let _ = cmd.arg(parent).spawn();
+                                self.import_state();
                             }
-                            #[cfg(target_os = "macos")]
+                            if ui
+                                .button(tr("settings.clean_cache"))
+                                .on_hover_text(tr("settings.clean_cache_hover"))
+                                .clicked()
                             {
-                                let mut cmd = std::process::Command::new("open");
-                                let _ = cmd.arg(parent).spawn();
+                                self.cleanup_cache();
                             }
-                            #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+                            if ui
+                                .button(tr("settings.compact_data"))
+                                .on_hover_text(tr("settings.compact_data_hover"))
+                                .clicked()
                             {
-                                let mut cmd = std::process::Command::new("xdg-open");
-                                let _ = cmd.arg(parent).spawn();
+                                self.compact_data();
                             }
+                        });
+                    });
+                });
+
+            ui.add_space(2.0);
+
+            ui.horizontal(|ui| {
+                if let Ok(config_path) = rss_core::AppConfig::config_file_path() {
+                    if let Some(dir) = config_path.parent() {
+                        if ui.button(tr("settings.open_config_dir")).clicked() {
+                            self.reveal_path(ui.ctx(), dir);
                         }
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(dir.display().to_string()).monospace(),
+                            )
+                            .selectable(true),
+                        );
                     }
                 }
 
                 ui.label(
-                    egui::RichText::new("💡 Les modifications sont sauvegardées automatiquement")
-                        .size(12.0)
-                        .weak(),
+                    egui::RichText::new(tr("settings.autosave"))
+                        .small()
+                        .color(secondary),
                 );
             });
         });
     }
 }
 
+impl RssApp {
+    fn draw_rules_editor(&mut self, ui: &mut egui::Ui) {
+        // ===
+        //
+        //
+        // Tableau des règles de filtrage (flux, champ, motif, expression régulière, action),
+        // appliquées aux nouveaux articles. Un motif refusé est signalé pendant la saisie;
+        // « Tester » compte les articles récents concernés; « Enregistrer » remplace les
+        // règles de DataApi.
+        //
+        //
+        // ===
+        let secondary = self.config.theme.secondary_text_color32();
+        let error_color = ui.visuals().error_fg_color;
+        let feeds = self.feeds_snapshot();
+        let mut remove = None;
+        let mut test = None;
+        if self.rules_draft.is_empty() {
+            ui.label(egui::RichText::new(tr("rules.empty")).color(secondary));
+        } else {
+            egui::Grid::new("filter_rules_grid")
+                .num_columns(7)
+                .striped(true)
+                .show(ui, |ui| {
+                    for header in [
+                        "rules.column_feed",
+                        "rules.column_field",
+                        "rules.column_pattern",
+                        "rules.column_regex",
+                        "rules.column_action",
+                    ] {
+                        ui.label(egui::RichText::new(tr(header)).strong());
+                    }
+                    for _ in 0..2 {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                    for (index, rule) in self.rules_draft.iter_mut().enumerate() {
+                        let scope = match &rule.scope {
+                            None => tr("rules.all_feeds"),
+                            Some(id) => feeds
+                                .iter()
+                                .find(|f| f.id == *id)
+                                .map_or_else(|| id.clone(), |f| f.title.clone()),
+                        };
+                        egui::ComboBox::from_id_source(("rule_scope", index))
+                            .selected_text(scope)
+                            .width(160.0)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut rule.scope, None, tr("rules.all_feeds"));
+                                for feed in &feeds {
+                                    ui.selectable_value(
+                                        &mut rule.scope,
+                                        Some(feed.id.clone()),
+                                        &feed.title,
+                                    );
+                                }
+                            });
+                        egui::ComboBox::from_id_source(("rule_field", index))
+                            .selected_text(rule_field_label(rule.field))
+                            .show_ui(ui, |ui| {
+                                for field in RuleField::ALL {
+                                    ui.selectable_value(
+                                        &mut rule.field,
+                                        field,
+                                        rule_field_label(field),
+                                    );
+                                }
+                            });
+                        ui.vertical(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut rule.pattern)
+                                    .hint_text(tr("rules.pattern_hint"))
+                                    .desired_width(180.0),
+                            );
+                            if let Err(e) = rule.compile() {
+                                let message = egui::RichText::new(rule_error_message(&e))
+                                    .small()
+                                    .color(error_color);
+                                ui.label(message);
+                            }
+                        });
+                        ui.checkbox(&mut rule.regex, "")
+                            .on_hover_text(tr("rules.regex_hover"));
+                        egui::ComboBox::from_id_source(("rule_action", index))
+                            .selected_text(rule_action_label(rule.action))
+                            .show_ui(ui, |ui| {
+                                for action in RuleAction::ALL {
+                                    ui.selectable_value(
+                                        &mut rule.action,
+                                        action,
+                                        rule_action_label(action),
+                                    );
+                                }
+                            });
+                        if ui
+                            .button(tr("rules.test"))
+                            .on_hover_text(tr("rules.test_hover"))
+                            .clicked()
+                        {
+                            test = Some(index);
+                        }
+                        if ui
+                            .small_button("🗑")
+                            .on_hover_text(tr("rules.remove"))
+                            .clicked()
+                        {
+                            remove = Some(index);
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+
+        if let Some(index) = test {
+            let message = match self.rules_draft[index].compile() {
+                Ok(rule) => {
+                    let mut recent: Vec<&FeedEntry> = self.articles.iter().collect();
+                    recent.sort_by_key(|a| std::cmp::Reverse(a.sort_date()));
+                    recent.truncate(RULE_TEST_ARTICLES);
+                    let matched: Vec<&str> = recent
+                        .iter()
+                        .filter(|a| rule.matches(a))
+                        .map(|a| a.title.as_str())
+                        .collect();
+                    match matched.as_slice() {
+                        [] => tr_args("rules.test_none", &[("recent", &recent.len())]),
+                        titles => tr_count(
+                            "rules.test_matched",
+                            titles.len() as i64,
+                            &[
+                                ("recent", &recent.len()),
+                                ("titles", &titles[..titles.len().min(3)].join(" · ")),
+                            ],
+                        ),
+                    }
+                }
+                Err(e) => rule_error_message(&e),
+            };
+            self.rule_test = Some((index, message));
+        }
+        if let Some(index) = remove {
+            self.rules_draft.remove(index);
+            self.rule_test = None;
+        }
+        if let Some((index, message)) = &self.rule_test {
+            ui.label(
+                egui::RichText::new(tr_args(
+                    "rules.test_result",
+                    &[("index", &(index + 1)), ("message", message)],
+                ))
+                .color(secondary)
+                .meta(),
+            );
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button(tr("rules.add")).clicked() {
+                self.rules_draft.push(FilterRule::default());
+                self.rule_test = None;
+            }
+            if ui
+                .button(tr("rules.save"))
+                .on_hover_text(tr("rules.save_hover"))
+                .clicked()
+            {
+                let result = self
+                    .runtime
+                    .block_on(self.data_api.set_rules(self.rules_draft.clone()));
+                match result {
+                    Ok(()) => self.push_toast(ToastLevel::Success, tr("toast.rules_saved")),
+                    Err(e) => self.push_toast(ToastLevel::Error, rule_error_message(&e)),
+                }
+            }
+        });
+    }
+}
+
+impl RssApp {
+    // ===
+    //
+    //
+    // Réglages WebSub: adresse d’écoute, URL publique de rappel et durée des abonnements.
+    // « Appliquer » (re)démarre le serveur tout de suite; la désactivation prend effet au
+    // prochain démarrage.
+    //
+    //
+    // ===
+    fn draw_websub_settings(&mut self, ui: &mut egui::Ui) {
+        let secondary = self.config.theme.secondary_text_color32();
+        let websub = &mut self.config.websub;
+        ui.checkbox(&mut websub.enabled, tr("websub.enabled"))
+            .on_hover_text(tr("websub.enabled_hover"));
+        ui.horizontal(|ui| {
+            ui.label(tr("websub.bind_address"));
+            ui.add(
+                egui::TextEdit::singleline(&mut websub.bind_address).hint_text("127.0.0.1:8765"),
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label(tr("websub.callback_url"));
+            let callback = websub.callback_base_url.get_or_insert_with(String::new);
+            ui.add(egui::TextEdit::singleline(callback).hint_text("https://reader.example.org"))
+                .on_hover_text(tr("websub.callback_url_hover"));
+        });
+        ui.horizontal(|ui| {
+            ui.label(tr("websub.lease"));
+            let mut days = (websub.lease_seconds / 86_400)
+                .clamp(*WEBSUB_LEASE_DAYS.start(), *WEBSUB_LEASE_DAYS.end());
+            if ui
+                .add(
+                    egui::DragValue::new(&mut days)
+                        .clamp_range(WEBSUB_LEASE_DAYS)
+                        .suffix(tr("unit.days_suffix")),
+                )
+                .changed()
+            {
+                websub.lease_seconds = days * 86_400;
+            }
+        });
+
+        let status = match (self.service.websub(), &self.websub_error) {
+            (Some(websub), _) => {
+                let subscriptions = websub.subscriptions();
+                let active = subscriptions
+                    .iter()
+                    .filter(|s| s.state == SubscriptionState::Active)
+                    .count();
+                egui::RichText::new(tr_count(
+                    "websub.status",
+                    active as i64,
+                    &[
+                        ("addr", &websub.local_addr()),
+                        ("total", &subscriptions.len()),
+                    ],
+                ))
+                .color(secondary)
+            }
+            (None, Some(err)) => {
+                egui::RichText::new(format!("⚠ {}", err)).color(Color32::from_rgb(229, 57, 53))
+            }
+            (None, None) => egui::RichText::new(tr("websub.inactive")).color(secondary),
+        };
+        ui.label(status.meta());
+
+        ui.horizontal(|ui| {
+            if ui.button(tr("common.apply")).clicked() {
+                self.apply_websub_config();
+            }
+        });
+    }
+
+    fn apply_websub_config(&mut self) {
+        let websub = &mut self.config.websub;
+        if websub
+            .callback_base_url
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or_default()
+            .is_empty()
+        {
+            websub.callback_base_url = None;
+        }
+        let _ = self.config.save();
+        if !self.config.websub.enabled {
+            let message = if self.service.websub().is_some() {
+                tr("websub.saved_stop_on_restart")
+            } else {
+                tr("websub.saved")
+            };
+            self.push_toast(ToastLevel::Info, message);
+            return;
+        }
+        match self
+            .runtime
+            .block_on(self.service.start_websub(&self.config.websub))
+        {
+            Ok(addr) => {
+                self.websub_error = None;
+                self.service.poll_now();
+                self.push_toast(
+                    ToastLevel::Success,
+                    tr_args("websub.started", &[("addr", &addr)]),
+                );
+            }
+            Err(e) => {
+                self.websub_error = Some(e.to_string());
+                self.push_toast(ToastLevel::Error, e.to_string());
+            }
+        }
+    }
+}
+
 impl Drop for RssApp {
     // ===
-    // Arrêt du poller à la fermeture de l’appli.
+    // Filet de sécurité si on_exit n’a pas été appelé: arrêt du service après la position de
+    // lecture en cours (sans effet si shutdown est déjà passé).
     // ===
     fn drop(&mut self) {
-        if let Some(handle) = self.poller.take() {
-            let _ = self.runtime.block_on(handle.stop());
-        }
+        self.save_reading_position();
+        let _ = self.runtime.block_on(tokio::time::timeout(
+            SHUTDOWN_FLUSH_TIMEOUT,
+            self.service.shutdown(),
+        ));
     }
 }
 
@@ -1555,10 +6783,51 @@ impl eframe::App for RssApp {
     // Boucle UI: apply thème, consommer les updates, dessiner panneaux et contenu.
     // ===
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        self.setup_dark_theme(ctx);
+        self.apply_theme(ctx);
+        self.sync_tray(ctx);
+        self.restore_window_position(ctx);
+        self.track_window_geometry(ctx);
         self.refresh_updates();
+        self.apply_pending_poll_config();
+        while let Ok(feed_id) = self.icons_rx.try_recv() {
+            self.feed_icons.remove(&feed_id);
+        }
+        while let Ok((message, action)) = self.undo_rx.try_recv() {
+            self.push_undo(message, action);
+        }
+        self.receive_feed_preview();
+        self.discover_probes.receive();
+        self.auto_export_starred_feed();
+        self.article_images.receive(ctx);
+        if self.refresh_task.as_ref().is_some_and(|h| h.is_finished()) {
+            self.refresh_task = None;
+        }
+        // Les évènements du poller arrivent hors interaction: repeindre régulièrement
+        // (plus souvent pendant un rafraîchissement complet, pour suivre son avancement).
+        let repaint_ms = if self.refresh_task.is_some() {
+            100
+        } else {
+            1000
+        };
+        ctx.request_repaint_after(std::time::Duration::from_millis(repaint_ms));
 
+        self.draw_offline_banner(ctx);
+        self.draw_status_bar(ctx);
         self.draw_left_panel(ctx);
         self.draw_main_content(ctx);
+        self.draw_header_editor_window(ctx);
+        self.draw_selector_editor_window(ctx);
+        self.draw_feed_preview_window(ctx);
+        if !matches!(self.current_view, AppView::ArticleDetail(_)) {
+            self.save_reading_position();
+        }
+        self.save_session_debounced();
+    }
+
+    // ===
+    // Appelé par eframe avant la destruction de l’application, runtime encore disponible.
+    // ===
+    fn on_exit(&mut self) {
+        self.shutdown();
     }
 }
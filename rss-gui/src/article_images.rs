@@ -0,0 +1,139 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use eframe::egui;
+use reqwest::Client;
+use rss_core::{fetch_inline_image, redact_url};
+use tokio::runtime::Runtime;
+use url::Url;
+
+// ===
+//
+//
+// Images affichées dans la vue détaillée: téléchargées sur le runtime
+// (`rss_core::fetch_inline_image`), décodées hors du thread de l’interface (premier cadre
+// seulement pour un GIF animé), réduites à MAX_IMAGE_SIDE puis gardées en texture par URL.
+//
+//
+// ===
+
+// Côté maximal (pixels) d’une image gardée en texture; les plus grandes sont réduites.
+pub const MAX_IMAGE_SIDE: u32 = 1600;
+
+// Nombre d’images gardées en mémoire (les plus anciennes demandées partent d’abord).
+pub const MAX_CACHED_IMAGES: usize = 64;
+
+pub enum ImageSlot {
+    Loading,
+    Ready(egui::TextureHandle),
+    Failed,
+}
+
+type Loaded = (Url, Result<egui::ColorImage, String>);
+
+pub struct ArticleImages {
+    slots: HashMap<Url, ImageSlot>,
+    order: VecDeque<Url>,
+    tx: Sender<Loaded>,
+    rx: Receiver<Loaded>,
+}
+
+impl ArticleImages {
+    pub fn new() -> Self {
+        let (tx, rx) = channel();
+        Self {
+            slots: HashMap::new(),
+            order: VecDeque::new(),
+            tx,
+            rx,
+        }
+    }
+
+    // État d’une image; None si elle n’a jamais été demandée (ou a quitté le cache).
+    pub fn slot(&self, url: &Url) -> Option<&ImageSlot> {
+        self.slots.get(url)
+    }
+
+    // ===
+    //
+    //
+    // Lance le téléchargement d’une image (une seule fois par URL); le résultat arrive par
+    // canal et `receive` en fait une texture.
+    //
+    //
+    // ===
+    pub fn request(&mut self, runtime: &Runtime, client: &Client, ctx: &egui::Context, url: &Url) {
+        if self.slots.contains_key(url) {
+            return;
+        }
+        self.insert(url.clone(), ImageSlot::Loading);
+        let tx = self.tx.clone();
+        let client = client.clone();
+        let ctx = ctx.clone();
+        let url = url.clone();
+        runtime.spawn(async move {
+            let result = match fetch_inline_image(&client, &url).await {
+                Ok(bytes) => tokio::task::spawn_blocking(move || decode_image(&bytes))
+                    .await
+                    .unwrap_or_else(|e| Err(e.to_string())),
+                Err(e) => Err(e.to_string()),
+            };
+            if let Err(e) = &result {
+                tracing::debug!(url = %redact_url(&url), error = %e, "image d’article indisponible");
+            }
+            let _ = tx.send((url, result));
+            ctx.request_repaint();
+        });
+    }
+
+    // Textures des images arrivées depuis la dernière image (appelé à chaque frame).
+    pub fn receive(&mut self, ctx: &egui::Context) {
+        while let Ok((url, result)) = self.rx.try_recv() {
+            // Sortie du cache pendant le téléchargement: résultat ignoré.
+            let Some(slot) = self.slots.get_mut(&url) else {
+                continue;
+            };
+            *slot = match result {
+                Ok(image) => ImageSlot::Ready(ctx.load_texture(
+                    format!("article_image:{}", url),
+                    image,
+                    Default::default(),
+                )),
+                Err(_) => ImageSlot::Failed,
+            };
+        }
+    }
+
+    fn insert(&mut self, url: Url, slot: ImageSlot) {
+        while self.order.len() >= MAX_CACHED_IMAGES {
+            if let Some(oldest) = self.order.pop_front() {
+                self.slots.remove(&oldest);
+            }
+        }
+        self.order.push_back(url.clone());
+        self.slots.insert(url, slot);
+    }
+}
+
+// ===
+//
+//
+// Décode une image (PNG, JPEG, GIF, WebP, BMP, ICO; premier cadre d’une animation) et la
+// réduit à MAX_IMAGE_SIDE en gardant ses proportions.
+//
+//
+// ===
+pub fn decode_image(bytes: &[u8]) -> Result<egui::ColorImage, String> {
+    let decoded = image::load_from_memory(bytes).map_err(|e| e.to_string())?;
+    let decoded = if decoded.width() > MAX_IMAGE_SIDE || decoded.height() > MAX_IMAGE_SIDE {
+        decoded.thumbnail(MAX_IMAGE_SIDE, MAX_IMAGE_SIDE)
+    } else {
+        decoded
+    };
+    let rgba = decoded.to_rgba8();
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    Ok(egui::ColorImage::from_rgba_unmultiplied(
+        size,
+        rgba.as_raw(),
+    ))
+}
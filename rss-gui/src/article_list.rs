@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use rss_core::FeedEntry;
+
+// ===
+//
+//
+// Fusion des articles reçus dans la liste affichée. La récupération, la persistance et la
+// limite par flux relèvent du service (FeedService, DataApi); ici, seulement la copie en
+// mémoire: un article est repéré par son flux et son identité, et la liste reste triée du
+// plus récent au plus ancien (tri stable: à date égale, l’ordre d’arrivée est conservé).
+//
+//
+// ===
+
+// Remplace ou ajoute chaque article reçu (nouveaux articles, articles modifiés).
+pub fn upsert(articles: &mut Vec<FeedEntry>, entries: Vec<FeedEntry>) {
+    if entries.is_empty() {
+        return;
+    }
+    let mut index: HashMap<(String, String), usize> = articles
+        .iter()
+        .enumerate()
+        .map(|(i, a)| ((a.feed_id.clone(), a.identity()), i))
+        .collect();
+    for entry in entries {
+        let key = (entry.feed_id.clone(), entry.identity());
+        match index.get(&key) {
+            Some(&i) => articles[i] = entry,
+            None => {
+                index.insert(key, articles.len());
+                articles.push(entry);
+            }
+        }
+    }
+    sort_newest_first(articles);
+}
+
+// Remplace tous les articles d’un flux par ceux relus du stockage.
+pub fn replace_feed(articles: &mut Vec<FeedEntry>, feed_id: &str, entries: Vec<FeedEntry>) {
+    articles.retain(|a| a.feed_id != feed_id);
+    articles.extend(entries);
+    sort_newest_first(articles);
+}
+
+// Retire les articles des flux supprimés.
+pub fn remove_feeds(articles: &mut Vec<FeedEntry>, removed: impl Fn(&str) -> bool) {
+    articles.retain(|a| !removed(&a.feed_id));
+}
+
+pub fn sort_newest_first(articles: &mut [FeedEntry]) {
+    articles.sort_by_key(|a| std::cmp::Reverse(a.sort_date()));
+}
+
+// ===
+//
+//
+// Vue calculée de la liste (articles filtrés, dédoublonnés, triés), gardée d’une image à
+// l’autre: la GUI ne la recalcule que lorsque la clé (révisions des articles et de l’état de
+// lecture, flux sélectionné, filtres, tri) change.
+//
+//
+// ===
+pub struct ViewCache<K, V> {
+    cached: Option<(K, Rc<V>)>,
+}
+
+impl<K, V> Default for ViewCache<K, V> {
+    fn default() -> Self {
+        Self { cached: None }
+    }
+}
+
+impl<K: PartialEq, V> ViewCache<K, V> {
+    // Vue gardée, si elle a été calculée pour cette clé.
+    pub fn get(&self, key: &K) -> Option<Rc<V>> {
+        match &self.cached {
+            Some((cached, view)) if cached == key => Some(Rc::clone(view)),
+            _ => None,
+        }
+    }
+
+    // Remplace la vue gardée par celle calculée pour `key`.
+    pub fn store(&mut self, key: K, view: V) -> Rc<V> {
+        let view = Rc::new(view);
+        self.cached = Some((key, Rc::clone(&view)));
+        view
+    }
+}
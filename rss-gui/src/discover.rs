@@ -0,0 +1,283 @@
+// ===
+//
+//
+// Catalogue Discover: flux recommandés, classés par catégorie, avec leur langue pour le
+// filtre de la vue d’accueil.
+//
+//
+// ===
+
+pub struct RecFeed {
+    pub title: &'static str,
+    pub url: &'static str,
+    // Langue des articles (code ISO 639-1).
+    pub lang: &'static str,
+    pub desc: &'static str,
+}
+
+pub struct RecCategory {
+    pub name: &'static str,
+    pub feeds: &'static [RecFeed],
+}
+
+// Langues proposées par le sélecteur de Discover (code ISO 639-1, libellé).
+pub const DISCOVER_LANGUAGES: [(&str, &str); 2] = [("fr", "Français"), ("en", "English")];
+
+// Nombre de flux montrés par catégorie.
+pub const TOP_FEEDS: usize = 5;
+
+impl RecFeed {
+    // Flux dans l’une des `languages` (toujours vrai si la liste est vide).
+    pub fn in_languages(&self, languages: &[String]) -> bool {
+        languages.is_empty() || languages.iter().any(|l| l == self.lang)
+    }
+}
+
+impl RecCategory {
+    // ===
+    // Flux de la catégorie dans l’une des `languages` (toutes si la liste est vide), au plus
+    // TOP_FEEDS.
+    // ===
+    pub fn top_feeds(&self, languages: &[String]) -> Vec<&'static RecFeed> {
+        self.feeds
+            .iter()
+            .filter(|feed| feed.in_languages(languages))
+            .take(TOP_FEEDS)
+            .collect()
+    }
+}
+
+// ===
+//
+//
+// Recherche dans tout le catalogue: flux dont le titre, la description ou l’URL contient
+// `query`, sans tenir compte de la casse ni des accents. Un flux présent dans plusieurs
+// catégories n’apparaît qu’une fois; requête vide: aucun résultat.
+//
+//
+// ===
+pub fn catalog_search(query: &str) -> Vec<&'static RecFeed> {
+    let needle = fold(query.trim());
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let mut found: Vec<&'static RecFeed> = Vec::new();
+    for feed in recommended_categories().iter().flat_map(|cat| cat.feeds) {
+        let matches = [feed.title, feed.desc, feed.url]
+            .iter()
+            .any(|field| fold(field).contains(&needle));
+        if matches && !found.iter().any(|f| f.url == feed.url) {
+            found.push(feed);
+        }
+    }
+    found
+}
+
+// ===
+//
+//
+// Requête qui ressemble à une adresse (« https://… » ou « exemple.org/blog », sans espace):
+// l’URL à passer à l’autodécouverte, en HTTPS si aucun schéma n’est donné.
+//
+//
+// ===
+pub fn query_as_url(query: &str) -> Option<String> {
+    let query = query.trim();
+    if query.is_empty() || query.contains(char::is_whitespace) {
+        return None;
+    }
+    let candidate = if query.contains("://") {
+        query.to_string()
+    } else {
+        format!("https://{}", query)
+    };
+    let url = url::Url::parse(&candidate).ok()?;
+    let host = url.host_str()?;
+    let looks_like_host = host.contains('.') && !host.starts_with('.') && !host.ends_with('.');
+    (matches!(url.scheme(), "http" | "https") && looks_like_host).then_some(candidate)
+}
+
+// Minuscules sans accents (lettres latines courantes), pour comparer des textes saisis.
+fn fold(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => out.push('a'),
+            'ç' => out.push('c'),
+            'è' | 'é' | 'ê' | 'ë' => out.push('e'),
+            'ì' | 'í' | 'î' | 'ï' => out.push('i'),
+            'ñ' => out.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => out.push('o'),
+            'ù' | 'ú' | 'û' | 'ü' => out.push('u'),
+            'ý' | 'ÿ' => out.push('y'),
+            'æ' => out.push_str("ae"),
+            'œ' => out.push_str("oe"),
+            // Tirets et espaces typographiques (« high‑tech », « Nature – Latest »).
+            '\u{2010}' | '\u{2011}' | '\u{2013}' | '\u{2014}' => out.push('-'),
+            '\u{a0}' | '\u{202f}' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// ===
+//
+//
+// Catégories/flux recommandés (affichés dans Discover).
+//
+//
+// ===
+pub fn recommended_categories() -> &'static [RecCategory] {
+    const TECH: &[RecFeed] = &[
+        RecFeed {
+            title: "Ars Technica",
+            url: "https://arstechnica.com/feed/",
+            lang: "en",
+            desc: "Actualités et analyses high‑tech, science et société.",
+        },
+        RecFeed {
+            title: "TechCrunch",
+            url: "https://techcrunch.com/feed/",
+            lang: "en",
+            desc: "Startups, produits et innovations du monde de la tech.",
+        },
+        RecFeed {
+            title: "The Register",
+            url: "https://www.theregister.com/headlines.atom",
+            lang: "en",
+            desc: "IT, logiciels, matériel et industrie (ton décalé).",
+        },
+        RecFeed {
+            title: "Numerama",
+            url: "https://www.numerama.com/feed/",
+            lang: "fr",
+            desc: "Culture numérique, société, environnement et science (FR).",
+        },
+        RecFeed {
+            title: "Korben",
+            url: "https://korben.info/feed",
+            lang: "fr",
+            desc: "Veille tech, tips et découvertes (FR).",
+        },
+    ];
+
+    const DEV: &[RecFeed] = &[
+        RecFeed {
+            title: "Rust Blog",
+            url: "https://blog.rust-lang.org/feed.xml",
+            lang: "en",
+            desc: "Annonces officielles du langage Rust.",
+        },
+        RecFeed {
+            title: "GitHub Blog",
+            url: "https://github.blog/feed/",
+            lang: "en",
+            desc: "Actualités GitHub, produits et écosystème open‑source.",
+        },
+        RecFeed {
+            title: "Stack Overflow Blog",
+            url: "https://stackoverflow.blog/feed/",
+            lang: "en",
+            desc: "Ingénierie, communauté et productivité.",
+        },
+        RecFeed {
+            title: "Real Python",
+            url: "https://realpython.com/atom.xml",
+            lang: "en",
+            desc: "Tutoriels Python et bonnes pratiques.",
+        },
+        RecFeed {
+            title: "dev.to",
+            url: "https://dev.to/feed",
+            lang: "en",
+            desc: "Articles communautaires sur le dev et les outils.",
+        },
+    ];
+
+    const SCIENCE: &[RecFeed] = &[
+        RecFeed {
+            title: "NASA News",
+            url: "https://www.nasa.gov/rss/dyn/breaking_news.rss",
+            lang: "en",
+            desc: "Dernières nouvelles de la NASA.",
+        },
+        RecFeed {
+            title: "ScienceDaily (All)",
+            url: "https://www.sciencedaily.com/rss/all.xml",
+            lang: "en",
+            desc: "Sélection d’articles de vulgarisation scientifique.",
+        },
+        RecFeed {
+            title: "Nature – Latest",
+            url: "https://www.nature.com/nature.rss",
+            lang: "en",
+            desc: "Publications et actualités de la revue Nature.",
+        },
+        RecFeed {
+            title: "Quanta Magazine",
+            url: "https://api.quantamagazine.org/feed/",
+            lang: "en",
+            desc: "Maths, physique, informatique et biologie théorique.",
+        },
+        RecFeed {
+            title: "MIT News",
+            url: "https://news.mit.edu/rss/topic/engineering",
+            lang: "en",
+            desc: "Recherches et innovations du MIT (ingénierie).",
+        },
+    ];
+
+    const ACTU_FR: &[RecFeed] = &[
+        RecFeed {
+            title: "Le Monde – Une",
+            url: "https://www.lemonde.fr/rss/une.xml",
+            lang: "fr",
+            desc: "Sélection des principaux titres du Monde (FR).",
+        },
+        RecFeed {
+            title: "France 24",
+            url: "https://www.france24.com/fr/rss",
+            lang: "fr",
+            desc: "Info internationale en continu (FR).",
+        },
+        RecFeed {
+            title: "Le Figaro – International",
+            url: "https://www.lefigaro.fr/rss/figaro_international.xml",
+            lang: "fr",
+            desc: "Actualité internationale (FR).",
+        },
+        RecFeed {
+            title: "ZDNet France",
+            url: "https://www.zdnet.fr/feeds/rss/actualites/",
+            lang: "fr",
+            desc: "Technologies et entreprises (FR).",
+        },
+        RecFeed {
+            title: "01net",
+            url: "https://www.01net.com/feed/",
+            lang: "fr",
+            desc: "High-tech, tests et dossiers (FR).",
+        },
+    ];
+
+    const CATS: &[RecCategory] = &[
+        RecCategory {
+            name: "Technologie",
+            feeds: TECH,
+        },
+        RecCategory {
+            name: "Programmation",
+            feeds: DEV,
+        },
+        RecCategory {
+            name: "Science",
+            feeds: SCIENCE,
+        },
+        RecCategory {
+            name: "Actualités (FR)",
+            feeds: ACTU_FR,
+        },
+    ];
+    CATS
+}
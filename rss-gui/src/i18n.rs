@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::sync::{OnceLock, RwLock};
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use rss_core::{relative_time, RelativeTime};
+
+// ===
+//
+//
+// Traduction de l’interface: une table JSON plate (clé → texte) par langue, déposée dans
+// rss-gui/locales/<code>.json et embarquée à la compilation (build.rs). Les textes peuvent
+// contenir des paramètres nommés `{nom}`; une clé absente de la langue courante retombe sur
+// le français (langue source), puis sur la clé elle-même.
+//
+//
+// ===
+
+include!(concat!(env!("OUT_DIR"), "/locales.rs"));
+
+// Langue dans laquelle les textes sont écrits en premier (table complète de référence).
+pub const SOURCE_LANGUAGE: &str = "fr";
+
+// Langue retenue quand ni le réglage ni le système ne correspondent à une table.
+const FALLBACK_LANGUAGE: &str = "en";
+
+type Table = HashMap<String, String>;
+
+static CURRENT: RwLock<&str> = RwLock::new(SOURCE_LANGUAGE);
+
+fn tables() -> &'static HashMap<&'static str, Table> {
+    static TABLES: OnceLock<HashMap<&'static str, Table>> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        LOCALES
+            .iter()
+            .filter_map(|(code, json)| match serde_json::from_str(json) {
+                Ok(table) => Some((*code, table)),
+                Err(e) => {
+                    tracing::warn!(language = code, error = %e, "unreadable translation table");
+                    None
+                }
+            })
+            .collect()
+    })
+}
+
+// Codes des langues disponibles, triés.
+pub fn available_languages() -> Vec<&'static str> {
+    let mut codes: Vec<&'static str> = tables().keys().copied().collect();
+    codes.sort_unstable();
+    codes
+}
+
+// Nom d’une langue dans cette langue (« Français », « English »), pour le sélecteur.
+pub fn language_name(code: &str) -> String {
+    tables()
+        .get(code)
+        .and_then(|table| table.get("language.name"))
+        .cloned()
+        .unwrap_or_else(|| code.to_string())
+}
+
+// ===
+//
+//
+// Choisit la table à utiliser: le réglage s’il existe, sinon la langue du système (« fr-CA »
+// et « fr_FR » donnent « fr »), sinon l’anglais.
+//
+//
+// ===
+pub fn resolve_language(preferred: Option<&str>, system: Option<&str>) -> &'static str {
+    let tables = tables();
+    let find = |tag: &str| {
+        let tag = tag.trim().to_ascii_lowercase().replace('_', "-");
+        let primary = tag.split('-').next().unwrap_or_default().to_string();
+        [tag, primary]
+            .into_iter()
+            .find_map(|code| tables.get_key_value(code.as_str()).map(|(code, _)| *code))
+    };
+    preferred
+        .and_then(find)
+        .or_else(|| system.and_then(find))
+        .or_else(|| find(FALLBACK_LANGUAGE))
+        .unwrap_or(SOURCE_LANGUAGE)
+}
+
+// Applique la langue réglée (UiConfig::language) ou celle du système; retourne le code retenu.
+pub fn set_language(preferred: Option<&str>) -> &'static str {
+    let code = resolve_language(preferred, sys_locale::get_locale().as_deref());
+    *CURRENT.write().unwrap() = code;
+    code
+}
+
+pub fn current_language() -> &'static str {
+    *CURRENT.read().unwrap()
+}
+
+fn lookup(key: &str) -> Option<&'static String> {
+    let tables = tables();
+    tables
+        .get(current_language())
+        .and_then(|table| table.get(key))
+        .or_else(|| tables.get(SOURCE_LANGUAGE).and_then(|table| table.get(key)))
+}
+
+// Texte de `key` dans la langue courante.
+pub fn tr(key: &str) -> String {
+    lookup(key).cloned().unwrap_or_else(|| key.to_string())
+}
+
+// Texte de `key` avec ses paramètres `{nom}` remplacés.
+pub fn tr_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = tr(key);
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    text
+}
+
+// ===
+//
+//
+// Variante selon un nombre: `key_zero` pour 0 et `key_one` pour 1 si la table les définit,
+// `key` sinon. Le nombre est passé en paramètre `{count}`.
+//
+//
+// ===
+pub fn tr_count(key: &str, count: i64, args: &[(&str, &dyn Display)]) -> String {
+    let variant = match count {
+        0 => Some(format!("{}_zero", key)),
+        1 => Some(format!("{}_one", key)),
+        _ => None,
+    };
+    let key = variant
+        .filter(|variant| lookup(variant).is_some())
+        .unwrap_or_else(|| key.to_string());
+    let mut all: Vec<(&str, &dyn Display)> = vec![("count", &count)];
+    all.extend_from_slice(args);
+    tr_args(&key, &all)
+}
+
+// ===
+//
+//
+// Dates au format de la langue courante: `key` désigne un motif strftime de la table
+// (`date.format` pour une date complète, `date.day` pour un jour, `date.short_day` pour un
+// axe de graphique). Un motif invalide retombe sur le format ISO.
+//
+//
+// ===
+pub fn format_date(date: DateTime<Utc>) -> String {
+    format_date_with(&date, "date.format")
+}
+
+pub fn format_date_with<Tz: TimeZone>(date: &DateTime<Tz>, key: &str) -> String
+where
+    Tz::Offset: Display,
+{
+    render(date.format(&tr(key))).unwrap_or_else(|| date.format("%Y-%m-%d %H:%M").to_string())
+}
+
+pub fn format_day(day: NaiveDate, key: &str) -> String {
+    render(day.format(&tr(key))).unwrap_or_else(|| day.format("%Y-%m-%d").to_string())
+}
+
+fn render(value: impl Display) -> Option<String> {
+    let mut text = String::new();
+    write!(text, "{}", value).ok()?;
+    Some(text)
+}
+
+// Date relative à `now` (« il y a 3 h », « 3 h ago »...).
+pub fn format_relative_date(date: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    match relative_time(date, now) {
+        RelativeTime::JustNow => tr("date.just_now"),
+        RelativeTime::Seconds(n) => tr_count("date.seconds_ago", n, &[]),
+        RelativeTime::Minutes(n) => tr_count("date.minutes_ago", n, &[]),
+        RelativeTime::Hours(n) => tr_count("date.hours_ago", n, &[]),
+        RelativeTime::Yesterday => tr("date.yesterday"),
+        RelativeTime::Days(n) => tr_count("date.days_ago", n, &[]),
+        RelativeTime::LastWeek => tr("date.last_week"),
+        RelativeTime::Weeks(n) => tr_count("date.weeks_ago", n, &[]),
+        RelativeTime::Months(n) => tr_count("date.months_ago", n, &[]),
+        RelativeTime::Years(n) => tr_count("date.years_ago", n, &[]),
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Local};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+// ===
+//
+//
+// Journal affiché dans l’application (vue « Journal »): une couche tracing garde les derniers
+// évènements, déjà mis en forme, dans un tampon circulaire partagé avec l’interface.
+// L’écriture ne bloque jamais: si l’interface lit le tampon à cet instant, la ligne est
+// comptée comme perdue plutôt que d’attendre (le poller ne ralentit pas pour l’affichage).
+//
+//
+// ===
+
+// Nombre de lignes gardées (les plus anciennes partent d’abord).
+pub const LOG_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    // Champ `feed` de l’évènement (URL ou id du flux), s’il est présent.
+    pub feed: Option<String>,
+    // Message suivi des autres champs (`clé=valeur`).
+    pub message: String,
+}
+
+impl LogLine {
+    // ===
+    //
+    //
+    // Ligne en texte brut, pour « Copier » (rapport de bug).
+    //
+    //
+    // ===
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{} {:<5} {}",
+            self.time.format("%Y-%m-%d %H:%M:%S"),
+            self.level,
+            self.target
+        );
+        if let Some(feed) = &self.feed {
+            let _ = write!(text, " feed={}", feed);
+        }
+        let _ = write!(text, ": {}", self.message);
+        text
+    }
+
+    // Recherche plein texte, insensible à la casse (`needle` déjà en minuscules).
+    pub fn matches(&self, needle: &str) -> bool {
+        needle.is_empty()
+            || self.message.to_lowercase().contains(needle)
+            || self.target.to_lowercase().contains(needle)
+            || self
+                .feed
+                .as_ref()
+                .is_some_and(|f| f.to_lowercase().contains(needle))
+    }
+}
+
+// ===
+//
+//
+// Tampon partagé entre la couche (écriture) et la vue (lecture).
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    lines: Arc<RwLock<VecDeque<LogLine>>>,
+    capacity: usize,
+    dropped: Arc<AtomicU64>,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: Arc::new(RwLock::new(VecDeque::with_capacity(capacity))),
+            capacity,
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    // Couche à installer dans le subscriber (init_tracing).
+    pub fn layer(&self) -> LogLayer {
+        LogLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    // ===
+    //
+    //
+    // Lignes de niveau `max_level` ou plus grave, filtrées par `search` (dans l’ordre
+    // d’arrivée). Seule la vue ouverte appelle cette copie.
+    //
+    //
+    // ===
+    pub fn snapshot(&self, max_level: Level, search: &str) -> Vec<LogLine> {
+        let needle = search.trim().to_lowercase();
+        self.with_lines(|lines| {
+            lines
+                .iter()
+                .filter(|line| line.level <= max_level && line.matches(&needle))
+                .cloned()
+                .collect()
+        })
+    }
+
+    // Accès en lecture au tampon; les évènements émis pendant `f` sont perdus, pas attendus.
+    pub fn with_lines<R>(&self, f: impl FnOnce(&VecDeque<LogLine>) -> R) -> R {
+        match self.lines.read() {
+            Ok(lines) => f(&lines),
+            Err(poisoned) => f(&poisoned.into_inner()),
+        }
+    }
+
+    // Lignes perdues parce que le tampon était occupé au moment de l’évènement.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut lines) = self.lines.write() {
+            lines.clear();
+        }
+    }
+
+    fn push(&self, line: LogLine) {
+        match self.lines.try_write() {
+            Ok(mut lines) => {
+                if self.capacity == 0 {
+                    return;
+                }
+                while lines.len() >= self.capacity {
+                    lines.pop_front();
+                }
+                lines.push_back(line);
+            }
+            Err(_) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+pub struct LogLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = FieldText::default();
+        event.record(&mut fields);
+        let metadata = event.metadata();
+        self.buffer.push(LogLine {
+            time: Local::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            feed: fields.feed,
+            message: fields.text,
+        });
+    }
+}
+
+// Met en forme les champs d’un évènement: `message` d’abord, `feed` à part, puis le reste.
+#[derive(Default)]
+struct FieldText {
+    text: String,
+    feed: Option<String>,
+}
+
+impl FieldText {
+    fn append(&mut self, field: &Field, value: &dyn std::fmt::Display) {
+        match field.name() {
+            "message" => {
+                let rest = std::mem::take(&mut self.text);
+                self.text = value.to_string();
+                if !rest.is_empty() {
+                    let _ = write!(self.text, " {}", rest);
+                }
+            }
+            "feed" | "feed_id" | "feed_url" => self.feed = Some(value.to_string()),
+            name => {
+                if !self.text.is_empty() {
+                    self.text.push(' ');
+                }
+                let _ = write!(self.text, "{}={}", name, value);
+            }
+        }
+    }
+}
+
+impl Visit for FieldText {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.append(field, &value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.append(field, &format_args!("{:?}", value));
+    }
+}
@@ -1,15 +1,28 @@
 mod app;
+mod article_images;
+mod article_list;
+mod discover;
+mod i18n;
+mod log_capture;
+mod open_path;
+mod richtext;
+mod task_cache;
+mod toasts;
+mod tray;
+mod webview;
+mod window_geometry;
 
 use std::sync::Arc;
 
 use eframe::{egui, NativeOptions};
-use reqwest::{redirect, ClientBuilder};
-use rss_core::{shared_feed_list, spawn_poller, AppConfig, DataApi, PollConfig, SeenStore};
+use rss_core::{AppConfig, FeedService};
 use tokio::runtime::Runtime;
-use tokio::sync::mpsc;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
 use crate::app::{AppInit, RssApp};
+use crate::log_capture::{LogBuffer, LOG_CAPACITY};
 
 // ===
 //
@@ -22,57 +35,48 @@ use crate::app::{AppInit, RssApp};
 // ===
 //
 //
-// Initialise le runtime, les services (data/poller) et lance la fenêtre principale.
+// Initialise le runtime, le service de flux (données, poller, évènements) et lance la fenêtre
+// principale.
 //
 //
 // ===
 fn main() -> eframe::Result<()> {
-    init_tracing();
+    if let Some(code) = webview_child_exit_code() {
+        std::process::exit(code);
+    }
+    let logs = init_tracing();
 
     let runtime = Arc::new(Runtime::new().expect("failed to initialise Tokio runtime"));
-    let feed_store = shared_feed_list(Vec::new());
-    let (update_tx, update_rx) = mpsc::channel(64);
-    let client = ClientBuilder::new()
-        .redirect(redirect::Policy::limited(5))
-        .user_agent("ReadRSS/0.1 (+https://github.com/xAMA0x/ReadRSS)")
-        .build()
-        .expect("failed to build HTTP client");
-    let client_for_app = client.clone();
-    let poll_config = load_poll_config();
-    let seen_store = load_seen_store(&runtime);
-    let seen_for_app = seen_store.clone();
-    let data_api = load_data_api(&runtime, feed_store.clone());
-
-    let poller = {
-        let guard = runtime.enter();
-        let handle = spawn_poller(
-            feed_store.clone(),
-            poll_config.clone(),
-            client,
-            update_tx,
-            seen_store,
-        );
-        drop(guard);
-        handle
-    };
-
+    // Client HTTP en repli sur les réglages par défaut si la section réseau est invalide;
+    // l’erreur est affichée dans l’UI (FeedService::network_error).
+    let (config, config_issue) = AppConfig::load_checked();
+    // Langue de l’interface: réglage explicite, sinon celle du système.
+    i18n::set_language(config.ui.language.as_deref());
+    let service = runtime.block_on(FeedService::open_with(config_dir(), &config));
+
+    // Taille et position enregistrées à la dernière fermeture, taille bornée par le minimum;
+    // la position est vérifiée à la première frame (RssApp::restore_window_position).
+    let window_size = config
+        .ui
+        .window_size
+        .map_or([800.0, 800.0], |[w, h]| [w.max(600.0), h.max(500.0)]);
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(window_size)
+        .with_min_inner_size([600.0, 500.0]);
+    if let Some(position) = config.ui.window_position {
+        viewport = viewport.with_position(position);
+    }
     let init = AppInit {
         runtime: runtime.clone(),
-        feeds: feed_store,
-        poller,
-        updates: update_rx,
-        data_api,
-        client: client_for_app,
-        poll_config,
-        seen_store: seen_for_app,
+        service: Arc::new(service),
+        config,
+        config_issue,
+        logs,
     };
-
     eframe::run_native(
         "ReadRSS",
         NativeOptions {
-            viewport: egui::ViewportBuilder::default()
-                .with_inner_size([800.0, 800.0])
-                .with_min_inner_size([600.0, 500.0]),
+            viewport,
             ..Default::default()
         },
         Box::new(move |cc| {
@@ -85,73 +89,49 @@ fn main() -> eframe::Result<()> {
 // ===
 //
 //
-// Initialise le logging via tracing (filtrable par RUST_LOG).
-//
-//
-// ===
-fn init_tracing() {
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-    let _ = tracing_subscriber::fmt().with_env_filter(filter).try_init();
-}
-
-// ===
-//
-//
-// Dossier de configuration de l’application.
+// Mode de test `--webview-child [code] <cible>`: se comporte comme une visionneuse qui se
+// termine aussitôt avec `code` (0 par défaut), sans ouvrir de fenêtre.
 //
 //
 // ===
-fn config_dir() -> std::path::PathBuf {
-    let mut dir = dirs::config_dir().unwrap_or_else(|| std::env::current_dir().unwrap());
-    dir.push("readrss");
-    dir
-}
-
-fn load_poll_config() -> PollConfig {
-    // ===
-    //
-    //
-    // Construit PollConfig depuis AppConfig (section feeds) pour aligner l’UI et le runtime.
-    //
-    //
-    // ===
-    let app_cfg = AppConfig::load();
-    PollConfig {
-        interval: std::time::Duration::from_secs(
-            app_cfg.feeds.update_interval_minutes.max(1) * 60,
-        ),
-        request_timeout: std::time::Duration::from_secs(
-            app_cfg.feeds.request_timeout_seconds.max(1),
-        ),
-        max_retries: app_cfg.feeds.retry_attempts.max(1) as usize,
-        ..PollConfig::default()
+fn webview_child_exit_code() -> Option<i32> {
+    let mut args = std::env::args().skip(1);
+    if args.next()? != "--webview-child" {
+        return None;
     }
+    Some(args.next().and_then(|a| a.parse().ok()).unwrap_or(0))
 }
 
 // ===
 //
 //
-// Charge/initialise le magasin de “vus” (SeenStore) depuis le disque.
+// Initialise le logging via tracing (filtrable par RUST_LOG): sortie terminal et tampon du
+// journal affiché dans l’application (vue « Journal »).
 //
 //
 // ===
-fn load_seen_store(runtime: &Arc<Runtime>) -> SeenStore {
-    let mut path = config_dir();
-    path.push("seen_store.json");
-    runtime.block_on(SeenStore::load_from(&path))
+fn init_tracing() -> LogBuffer {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let logs = LogBuffer::new(LOG_CAPACITY);
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(logs.layer())
+        .try_init();
+    logs
 }
 
 // ===
 //
 //
-// Charge l’API de données (feeds, read-state, cache d’articles) depuis le dossier config.
+// Dossier de configuration de l’application.
 //
 //
 // ===
-fn load_data_api(runtime: &Arc<Runtime>, feeds: rss_core::SharedFeedList) -> Arc<DataApi> {
-    let dir = config_dir();
-    let api = runtime.block_on(DataApi::load_from_dir(feeds, dir));
-    Arc::new(api)
+fn config_dir() -> std::path::PathBuf {
+    let mut dir = dirs::config_dir().unwrap_or_else(|| std::env::current_dir().unwrap());
+    dir.push("readrss");
+    dir
 }
 
 // ===
@@ -0,0 +1,85 @@
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+// ===
+//
+//
+// Ouverture d’un dossier ou d’un fichier dans le gestionnaire de fichiers du système
+// (bouton « Ouvrir le dossier de configuration », « Afficher l’export »). Le choix de la
+// commande est séparé du lancement (`Spawner`) pour être testé sans créer de processus.
+//
+//
+// ===
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    Windows,
+    MacOs,
+    Other,
+}
+
+impl Platform {
+    pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Platform::Windows
+        } else if cfg!(target_os = "macos") {
+            Platform::MacOs
+        } else {
+            Platform::Other
+        }
+    }
+
+    // Programme qui ouvre un chemin dans le gestionnaire de fichiers de la plateforme.
+    pub fn open_program(self) -> &'static str {
+        match self {
+            Platform::Windows => "explorer",
+            Platform::MacOs => "open",
+            Platform::Other => "xdg-open",
+        }
+    }
+
+    // Code de sortie significatif (explorer rend 1 même quand l’ouverture a réussi).
+    pub fn exit_status_is_meaningful(self) -> bool {
+        self != Platform::Windows
+    }
+}
+
+// Lance `program path` sans attendre sa fin.
+pub trait Spawner {
+    fn spawn(&self, program: &str, path: &Path) -> io::Result<()>;
+}
+
+// Lanceur réel: processus enfant, attendu par un thread pour ne pas laisser de zombie.
+pub struct SystemSpawner;
+
+impl Spawner for SystemSpawner {
+    fn spawn(&self, program: &str, path: &Path) -> io::Result<()> {
+        let mut child = Command::new(program).arg(path).spawn()?;
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+        Ok(())
+    }
+}
+
+// ===
+//
+//
+// Ouvre `path` avec la commande de `platform`. Un fichier est montré via son dossier
+// (l’ouvrir lancerait l’application associée, pas le gestionnaire de fichiers). L’erreur de
+// lancement est rendue pour que l’appelant propose une alternative (copie du chemin).
+//
+//
+// ===
+pub fn open_path_with(spawner: &impl Spawner, platform: Platform, path: &Path) -> io::Result<()> {
+    let target = match path.parent() {
+        Some(parent) if path.is_file() => parent,
+        _ => path,
+    };
+    spawner.spawn(platform.open_program(), target)
+}
+
+pub fn open_path(path: &Path) -> io::Result<()> {
+    open_path_with(&SystemSpawner, Platform::current(), path)
+}
@@ -0,0 +1,511 @@
+use eframe::egui::{self, Color32};
+use rss_core::{find_tag_end, tag_name};
+use url::Url;
+
+// ===
+//
+//
+// Rendu HTML minimal de la vue détaillée: le HTML (déjà nettoyé) devient une suite de blocs
+// (paragraphes, titres h1–h4, éléments de liste, code, filets) faits de morceaux de texte
+// stylés (gras, italique, code, lien). Les balises inconnues sont ignorées et leur texte
+// gardé; les balises mal fermées ne font jamais échouer le rendu.
+//
+//
+// ===
+
+// Retrait (points) par niveau de liste et par niveau de citation.
+const LIST_INDENT: f32 = 18.0;
+const QUOTE_INDENT: f32 = 12.0;
+
+// Morceau de texte d’un bloc, avec son style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    pub link: Option<String>,
+}
+
+impl Span {
+    fn same_style(&self, other: &Span) -> bool {
+        self.bold == other.bold
+            && self.italic == other.italic
+            && self.code == other.code
+            && self.link == other.link
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockKind {
+    Paragraph,
+    // Niveau 1 à 4 (h5 et h6 sont rendus comme h4).
+    Heading(u8),
+    // Élément de liste et sa marque (« • » ou « 3. »).
+    ListItem(String),
+    Code,
+    Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub kind: BlockKind,
+    pub spans: Vec<Span>,
+    // Niveau de liste (retrait) et de citation (<blockquote>) du bloc.
+    pub depth: usize,
+    pub quote: usize,
+}
+
+impl Block {
+    // Texte brut du bloc, sans style.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+// ===
+//
+//
+// Découpe `html` en blocs. Les liens relatifs sont résolus sur `base` (lien de l’article);
+// seuls les liens HTTP(S) et mailto restent cliquables.
+//
+//
+// ===
+pub fn parse_html(html: &str, base: Option<&Url>) -> Vec<Block> {
+    let mut parser = Parser::new(base);
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        parser.text(&rest[..lt]);
+        rest = &rest[lt..];
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+        let opens_tag = rest[1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        let end = if opens_tag { find_tag_end(rest) } else { None };
+        let Some(end) = end else {
+            // « < » isolé ou balise jamais fermée: gardé comme texte.
+            parser.text("<");
+            rest = &rest[1..];
+            continue;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        let closing = tag.starts_with('/');
+        let name = tag_name(tag);
+        match name.as_str() {
+            "pre" if !closing => {
+                let (raw, after) = split_at_closing(rest, "pre");
+                parser.code_block(raw);
+                rest = after;
+            }
+            "script" | "style" if !closing => rest = split_at_closing(rest, &name).1,
+            _ => parser.tag(&name, tag, closing),
+        }
+    }
+    parser.text(rest);
+    parser.finish()
+}
+
+struct Parser<'a> {
+    base: Option<&'a Url>,
+    blocks: Vec<Block>,
+    current: Option<Block>,
+    bold: usize,
+    italic: usize,
+    code: usize,
+    links: Vec<Option<String>>,
+    // Listes ouvertes: None pour des puces, Some(n) pour le prochain numéro.
+    lists: Vec<Option<usize>>,
+    quote: usize,
+    heading: Option<u8>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(base: Option<&'a Url>) -> Self {
+        Self {
+            base,
+            blocks: Vec::new(),
+            current: None,
+            bold: 0,
+            italic: 0,
+            code: 0,
+            links: Vec::new(),
+            lists: Vec::new(),
+            quote: 0,
+            heading: None,
+        }
+    }
+
+    fn tag(&mut self, name: &str, tag: &str, closing: bool) {
+        match name {
+            "p" | "div" | "section" | "article" | "header" | "footer" | "figure" | "figcaption"
+            | "table" | "tr" | "dl" | "dt" | "dd" => self.flush(),
+            "br" => self.push("\n"),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading = if closing {
+                    None
+                } else {
+                    Some(name[1..].parse::<u8>().unwrap_or(4).min(4))
+                };
+            }
+            "strong" | "b" => self.bold = counter(self.bold, closing),
+            "em" | "i" => self.italic = counter(self.italic, closing),
+            "code" | "kbd" | "samp" | "tt" => self.code = counter(self.code, closing),
+            "a" if closing => {
+                self.links.pop();
+            }
+            "a" => {
+                let href = attr_value(tag, "href").and_then(|href| self.resolve_link(&href));
+                self.links.push(href);
+            }
+            "ul" | "ol" => {
+                self.flush();
+                if closing {
+                    self.lists.pop();
+                } else if name == "ol" {
+                    let start = attr_value(tag, "start").and_then(|s| s.trim().parse().ok());
+                    self.lists.push(Some(start.unwrap_or(1)));
+                } else {
+                    self.lists.push(None);
+                }
+            }
+            "li" => {
+                self.flush();
+                if !closing {
+                    let marker = match self.lists.last_mut() {
+                        Some(Some(next)) => {
+                            *next += 1;
+                            format!("{}.", *next - 1)
+                        }
+                        _ => "•".to_string(),
+                    };
+                    self.current = Some(Block {
+                        kind: BlockKind::ListItem(marker),
+                        spans: Vec::new(),
+                        depth: self.lists.len().max(1),
+                        quote: self.quote,
+                    });
+                }
+            }
+            "blockquote" => {
+                self.flush();
+                self.quote = counter(self.quote, closing);
+            }
+            "hr" => {
+                self.flush();
+                self.blocks.push(Block {
+                    kind: BlockKind::Rule,
+                    spans: Vec::new(),
+                    depth: self.lists.len(),
+                    quote: self.quote,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // Texte courant: entités décodées, blancs fusionnés.
+    fn text(&mut self, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        let decoded = decode_entities(raw);
+        let mut collapsed = String::with_capacity(decoded.len());
+        for c in decoded.chars() {
+            if c.is_whitespace() {
+                if !collapsed.ends_with(' ') {
+                    collapsed.push(' ');
+                }
+            } else {
+                collapsed.push(c);
+            }
+        }
+        self.push(&collapsed);
+    }
+
+    fn push(&mut self, text: &str) {
+        let at_line_start = self.current.as_ref().is_none_or(|block| {
+            block
+                .spans
+                .last()
+                .is_none_or(|s| s.text.ends_with([' ', '\n']))
+        });
+        let text = if at_line_start {
+            text.trim_start_matches(' ')
+        } else {
+            text
+        };
+        if text.is_empty() {
+            return;
+        }
+        let span = Span {
+            text: text.to_string(),
+            bold: self.bold > 0 || self.heading.is_some(),
+            italic: self.italic > 0,
+            code: self.code > 0,
+            link: self.links.iter().rev().find_map(Clone::clone),
+        };
+        let (heading, depth, quote) = (self.heading, self.lists.len(), self.quote);
+        let block = self.current.get_or_insert_with(|| Block {
+            kind: heading.map_or(BlockKind::Paragraph, BlockKind::Heading),
+            spans: Vec::new(),
+            depth,
+            quote,
+        });
+        match block.spans.last_mut() {
+            Some(last) if last.same_style(&span) => last.text.push_str(&span.text),
+            _ => block.spans.push(span),
+        }
+    }
+
+    fn code_block(&mut self, raw: &str) {
+        self.flush();
+        let text = decode_entities(&strip_tags(raw));
+        let text = text.trim_matches('\n').trim_end();
+        if text.is_empty() {
+            return;
+        }
+        self.blocks.push(Block {
+            kind: BlockKind::Code,
+            spans: vec![Span {
+                text: text.to_string(),
+                code: true,
+                ..Span::default()
+            }],
+            depth: self.lists.len(),
+            quote: self.quote,
+        });
+    }
+
+    // Termine le bloc en cours (ignoré s’il ne contient que des blancs).
+    fn flush(&mut self) {
+        let Some(mut block) = self.current.take() else {
+            return;
+        };
+        while let Some(last) = block.spans.last_mut() {
+            let trimmed = last.text.trim_end().len();
+            last.text.truncate(trimmed);
+            if !last.text.is_empty() {
+                break;
+            }
+            block.spans.pop();
+        }
+        if !block.spans.is_empty() {
+            self.blocks.push(block);
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush();
+        self.blocks
+    }
+
+    fn resolve_link(&self, href: &str) -> Option<String> {
+        let href = href.trim();
+        let url = match self.base {
+            Some(base) => base.join(href).ok()?,
+            None => Url::parse(href).ok()?,
+        };
+        matches!(url.scheme(), "http" | "https" | "mailto").then(|| url.to_string())
+    }
+}
+
+fn counter(value: usize, closing: bool) -> usize {
+    if closing {
+        value.saturating_sub(1)
+    } else {
+        value + 1
+    }
+}
+
+// ===
+//
+//
+// Affiche les blocs; renvoie le lien cliqué (ouvert par l’appelant dans le navigateur).
+// `secondary` colore les marques de liste et le filet des citations.
+//
+//
+// ===
+pub fn show(ui: &mut egui::Ui, blocks: &[Block], secondary: Color32) -> Option<String> {
+    let mut clicked = None;
+    for block in blocks {
+        let indent = block.depth as f32 * LIST_INDENT + block.quote as f32 * QUOTE_INDENT;
+        let response = ui.horizontal(|ui| {
+            ui.add_space(indent);
+            if let BlockKind::ListItem(marker) = &block.kind {
+                let marker = egui::RichText::new(format!("{} ", marker))
+                    .text_style(reading())
+                    .color(secondary);
+                ui.label(marker);
+            }
+            ui.vertical(|ui| match &block.kind {
+                BlockKind::Rule => {
+                    ui.separator();
+                }
+                BlockKind::Code => {
+                    egui::Frame::none()
+                        .fill(ui.visuals().extreme_bg_color)
+                        .rounding(4.0)
+                        .inner_margin(6.0)
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.label(egui::RichText::new(block.text()).monospace());
+                        });
+                }
+                _ => {
+                    let style = match block.kind {
+                        BlockKind::Heading(1) => egui::TextStyle::Name("headline".into()),
+                        BlockKind::Heading(2) => egui::TextStyle::Name("title".into()),
+                        _ => reading(),
+                    };
+                    ui.horizontal_wrapped(|ui| {
+                        ui.spacing_mut().item_spacing.x = 0.0;
+                        for span in &block.spans {
+                            if let Some(url) = show_span(ui, span, &style) {
+                                clicked = Some(url);
+                            }
+                        }
+                    });
+                }
+            });
+        });
+        if block.quote > 0 {
+            let rect = response.response.rect;
+            let x = rect.left() + (block.quote - 1) as f32 * QUOTE_INDENT + 2.0;
+            ui.painter()
+                .vline(x, rect.y_range(), egui::Stroke::new(2.0, secondary));
+        }
+        ui.add_space(4.0);
+    }
+    clicked
+}
+
+fn show_span(ui: &mut egui::Ui, span: &Span, style: &egui::TextStyle) -> Option<String> {
+    let mut text = egui::RichText::new(&span.text).text_style(style.clone());
+    if span.bold {
+        text = text.strong();
+    }
+    if span.italic {
+        text = text.italics();
+    }
+    if span.code {
+        text = text.code();
+    }
+    match &span.link {
+        Some(url) => {
+            let response = ui.add(egui::Link::new(text)).on_hover_text(url);
+            response.clicked().then(|| url.clone())
+        }
+        None => {
+            ui.label(text);
+            None
+        }
+    }
+}
+
+fn reading() -> egui::TextStyle {
+    egui::TextStyle::Name("reading".into())
+}
+
+// Valeur d’un attribut, entités décodées.
+fn attr_value(tag: &str, name: &str) -> Option<String> {
+    rss_core::attr_value(tag, name).map(|value| decode_entities(&value))
+}
+
+// Sépare `s` à la balise fermante `</name>`: contenu avant, reste après.
+fn split_at_closing<'s>(s: &'s str, name: &str) -> (&'s str, &'s str) {
+    let needle = format!("</{}", name);
+    let found = s
+        .as_bytes()
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle.as_bytes()));
+    match found {
+        Some(start) => {
+            let after = s[start..].find('>').map_or("", |end| &s[start + end + 1..]);
+            (&s[..start], after)
+        }
+        None => (s, ""),
+    }
+}
+
+// Texte d’un fragment sans ses balises (contenu de <pre>).
+fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        out.push_str(&rest[..lt]);
+        rest = &rest[lt..];
+        match find_tag_end(rest) {
+            Some(end) => rest = &rest[end + 1..],
+            None => break,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// ===
+//
+//
+// Décode les entités HTML courantes (nommées usuelles et numériques); une entité inconnue
+// reste telle quelle.
+//
+//
+// ===
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let decoded = entity.and_then(|name| match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            "hellip" => Some('…'),
+            "mdash" => Some('—'),
+            "ndash" => Some('–'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            "laquo" => Some('«'),
+            "raquo" => Some('»'),
+            _ => {
+                let number = name.strip_prefix('#')?;
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (entity, decoded) {
+            (Some(name), Some(c)) => {
+                out.push(c);
+                rest = &rest[name.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
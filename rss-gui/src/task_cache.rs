@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use eframe::egui;
+use tokio::runtime::Runtime;
+
+// ===
+//
+//
+// Résultats de tâches lancées sur le runtime pour une vue, gardés par clé pour la session:
+// une seule tâche par clé, résultat reçu par canal (`receive` à chaque frame) et repeint
+// demandé à son arrivée. L’interface ne bloque jamais en attendant.
+//
+//
+// ===
+
+pub enum TaskSlot<V> {
+    Pending,
+    Done(V),
+}
+
+pub struct TaskCache<K, V> {
+    slots: HashMap<K, TaskSlot<V>>,
+    tx: Sender<(K, V)>,
+    rx: Receiver<(K, V)>,
+}
+
+impl<K, V> TaskCache<K, V>
+where
+    K: Eq + Hash + Clone + Send + 'static,
+    V: Send + 'static,
+{
+    pub fn new() -> Self {
+        let (tx, rx) = channel();
+        Self {
+            slots: HashMap::new(),
+            tx,
+            rx,
+        }
+    }
+
+    // État de la tâche d’une clé; None si elle n’a jamais été demandée (ou a été oubliée).
+    pub fn get(&self, key: &K) -> Option<&TaskSlot<V>> {
+        self.slots.get(key)
+    }
+
+    // ===
+    //
+    //
+    // Lance `task` sur le runtime, sauf si la clé a déjà une tâche en cours ou un résultat.
+    //
+    //
+    // ===
+    pub fn request<F>(&mut self, runtime: &Runtime, ctx: &egui::Context, key: K, task: F)
+    where
+        F: Future<Output = V> + Send + 'static,
+    {
+        if self.slots.contains_key(&key) {
+            return;
+        }
+        self.slots.insert(key.clone(), TaskSlot::Pending);
+        let tx = self.tx.clone();
+        let ctx = ctx.clone();
+        runtime.spawn(async move {
+            let value = task.await;
+            let _ = tx.send((key, value));
+            ctx.request_repaint();
+        });
+    }
+
+    // Oublie le résultat d’une clé pour une nouvelle tentative (sans effet pendant la tâche).
+    pub fn forget(&mut self, key: &K) {
+        if matches!(self.slots.get(key), Some(TaskSlot::Done(_))) {
+            self.slots.remove(key);
+        }
+    }
+
+    // Résultats arrivés depuis la dernière frame.
+    pub fn receive(&mut self) {
+        while let Ok((key, value)) = self.rx.try_recv() {
+            if let Some(slot) = self.slots.get_mut(&key) {
+                *slot = TaskSlot::Done(value);
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use eframe::egui::{self, Color32};
+
+// ===
+//
+//
+// Notifications éphémères (toasts) empilées en bas à droite: chaque message disparaît seul
+// après TOAST_LIFETIME ou à la fermeture. Les tâches de fond publient par un `ToastSender`
+// cloné, l’UI vide le canal à chaque image. Un toast d’annulation (push_undo) reste
+// UNDO_TOAST_LIFETIME et porte un bouton « Annuler ».
+//
+//
+// ===
+
+// Durée d’affichage d’une notification.
+pub const TOAST_LIFETIME: Duration = Duration::from_secs(5);
+
+// Durée d’affichage d’un toast d’annulation, le temps de cliquer « Annuler ».
+pub const UNDO_TOAST_LIFETIME: Duration = Duration::from_secs(10);
+
+// Nombre maximal de notifications à l’écran (les plus anciennes partent d’abord).
+const MAX_VISIBLE: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    fn color(self) -> Color32 {
+        match self {
+            Self::Info => Color32::from_rgb(30, 136, 229),
+            Self::Success => Color32::from_rgb(67, 160, 71),
+            Self::Warning => Color32::from_rgb(251, 140, 0),
+            Self::Error => Color32::from_rgb(229, 57, 53),
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Info => "ℹ",
+            Self::Success => "✔",
+            Self::Warning => "⚠",
+            Self::Error => "⚠",
+        }
+    }
+}
+
+// Émetteur utilisable depuis n’importe quel thread ou tâche.
+pub type ToastSender = mpsc::Sender<(ToastLevel, String)>;
+
+struct Toast {
+    level: ToastLevel,
+    message: String,
+    created_at: Instant,
+    // Opération annulable associée (identifiant choisi par l’appelant).
+    undo: Option<u64>,
+}
+
+impl Toast {
+    fn expired(&self) -> bool {
+        let lifetime = match self.undo {
+            Some(_) => UNDO_TOAST_LIFETIME,
+            None => TOAST_LIFETIME,
+        };
+        self.created_at.elapsed() >= lifetime
+    }
+}
+
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+    tx: ToastSender,
+    rx: mpsc::Receiver<(ToastLevel, String)>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel();
+        Self {
+            queue: VecDeque::new(),
+            tx,
+            rx,
+        }
+    }
+
+    pub fn sender(&self) -> ToastSender {
+        self.tx.clone()
+    }
+
+    // Publie un message via le canal (utilisable sans accès mutable, affiché à l’image suivante).
+    pub fn post(&self, level: ToastLevel, message: impl Into<String>) {
+        let _ = self.tx.send((level, message.into()));
+    }
+
+    // ===
+    //
+    //
+    // Ajoute un message; au-delà de MAX_VISIBLE, le plus ancien est retiré.
+    //
+    //
+    // ===
+    pub fn push(&mut self, level: ToastLevel, message: impl Into<String>) {
+        self.enqueue(level, message.into(), None);
+    }
+
+    // Ajoute un message avec un bouton « Annuler »; show renvoie `undo_id` s’il est cliqué.
+    pub fn push_undo(&mut self, level: ToastLevel, message: impl Into<String>, undo_id: u64) {
+        self.enqueue(level, message.into(), Some(undo_id));
+    }
+
+    fn enqueue(&mut self, level: ToastLevel, message: String, undo: Option<u64>) {
+        self.queue.push_back(Toast {
+            level,
+            message,
+            created_at: Instant::now(),
+            undo,
+        });
+        while self.queue.len() > MAX_VISIBLE {
+            self.queue.pop_front();
+        }
+    }
+
+    // ===
+    //
+    //
+    // Récupère les messages des tâches de fond, retire les expirés et dessine la pile
+    // (le plus récent en bas), avec un bouton de fermeture par message. Renvoie l’identifiant
+    // d’annulation dont le bouton « Annuler » vient d’être cliqué (le toast est alors retiré).
+    //
+    //
+    // ===
+    pub fn show(&mut self, ctx: &egui::Context, undo_label: &str) -> Option<u64> {
+        while let Ok((level, message)) = self.rx.try_recv() {
+            self.push(level, message);
+        }
+        self.queue.retain(|toast| !toast.expired());
+        if self.queue.is_empty() {
+            return None;
+        }
+
+        let mut closed = None;
+        let mut undone = None;
+        egui::Area::new(egui::Id::new("toasts"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -12.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.set_max_width(360.0);
+                for (index, toast) in self.queue.iter().enumerate() {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            let color = toast.level.color();
+                            ui.label(egui::RichText::new(toast.level.icon()).color(color));
+                            let message = egui::RichText::new(&toast.message)
+                                .text_style(egui::TextStyle::Name("meta".into()));
+                            ui.add(egui::Label::new(message).wrap(true));
+                            if let Some(undo) = toast.undo {
+                                if ui.small_button(undo_label).clicked() {
+                                    closed = Some(index);
+                                    undone = Some(undo);
+                                }
+                            }
+                            if ui.small_button("✖").clicked() {
+                                closed = Some(index);
+                            }
+                        });
+                    });
+                    ui.add_space(4.0);
+                }
+            });
+        if let Some(index) = closed {
+            self.queue.remove(index);
+        }
+        ctx.request_repaint_after(Duration::from_millis(250));
+        undone
+    }
+}
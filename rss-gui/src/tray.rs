@@ -0,0 +1,321 @@
+use std::sync::mpsc;
+
+use eframe::egui;
+use tokio::runtime::Runtime;
+
+use crate::i18n::tr_count;
+
+// ===
+//
+//
+// Icône de la zone de notification: menu « Ouvrir / Actualiser / Quitter » et infobulle avec
+// le nombre d’articles non lus. Linux passe par StatusNotifierItem (ksni, D-Bus, compatible
+// AppIndicator), Windows et macOS par tray-icon. Les actions reviennent à l’UI par canal.
+//
+//
+// ===
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayCommand {
+    Open,
+    Refresh,
+    Quit,
+}
+
+pub struct Tray {
+    backend: platform::Backend,
+    commands: mpsc::Receiver<TrayCommand>,
+    unread: Option<usize>,
+}
+
+impl Tray {
+    // ===
+    //
+    //
+    // Crée l’icône; None (avec un avertissement) si le bureau n’offre pas de zone de notification.
+    //
+    //
+    // ===
+    pub fn spawn(runtime: &Runtime, ctx: &egui::Context) -> Option<Self> {
+        let (tx, commands) = mpsc::channel();
+        match platform::Backend::spawn(runtime, ctx.clone(), tx) {
+            Ok(backend) => Some(Self {
+                backend,
+                commands,
+                unread: None,
+            }),
+            Err(e) => {
+                tracing::warn!(error = %e, "system tray unavailable");
+                None
+            }
+        }
+    }
+
+    // Prochaine action demandée depuis l’icône, s’il y en a une.
+    pub fn try_recv(&self) -> Option<TrayCommand> {
+        self.backend.pump();
+        self.commands.try_recv().ok()
+    }
+
+    // Met à jour l’infobulle (seulement si le nombre de non lus a changé).
+    pub fn set_unread(&mut self, runtime: &Runtime, count: usize) {
+        if self.unread == Some(count) {
+            return;
+        }
+        self.unread = Some(count);
+        self.backend.set_tooltip(runtime, tooltip(count));
+    }
+}
+
+fn tooltip(unread: usize) -> String {
+    tr_count("tray.tooltip", unread as i64, &[])
+}
+
+const ICON_SIZE: u32 = 32;
+
+// ===
+//
+//
+// Icône RSS dessinée à la volée (carré orange, point et deux arcs blancs), en RGBA.
+//
+//
+// ===
+fn icon_rgba() -> Vec<u8> {
+    let (orange, white) = ([242u8, 101, 34, 255], [255u8, 255, 255, 255]);
+    let mut pixels = Vec::with_capacity((ICON_SIZE * ICON_SIZE * 4) as usize);
+    for y in 0..ICON_SIZE {
+        for x in 0..ICON_SIZE {
+            let (dx, dy) = (x as f32 - 7.0, 25.0 - y as f32);
+            let d = (dx * dx + dy * dy).sqrt();
+            let in_quadrant = dx >= -1.0 && dy >= -1.0;
+            let mark =
+                d < 3.5 || (in_quadrant && ((9.0..13.0).contains(&d) || (16.0..20.0).contains(&d)));
+            pixels.extend_from_slice(if mark { &white } else { &orange });
+        }
+    }
+    pixels
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::sync::mpsc;
+
+    use eframe::egui;
+    use ksni::TrayMethods;
+    use tokio::runtime::Runtime;
+
+    use super::{icon_rgba, tooltip, TrayCommand, ICON_SIZE};
+    use crate::i18n::tr;
+
+    struct ReadRssTray {
+        tooltip: String,
+        commands: mpsc::Sender<TrayCommand>,
+        ctx: egui::Context,
+    }
+
+    impl ReadRssTray {
+        fn send(&self, command: TrayCommand) {
+            let _ = self.commands.send(command);
+            if command == TrayCommand::Open {
+                // La fenêtre masquée ne redessine plus: on la réaffiche directement.
+                self.ctx
+                    .send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                self.ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+            }
+            self.ctx.request_repaint();
+        }
+    }
+
+    impl ksni::Tray for ReadRssTray {
+        fn id(&self) -> String {
+            "readrss".into()
+        }
+
+        fn title(&self) -> String {
+            "ReadRSS".into()
+        }
+
+        fn icon_pixmap(&self) -> Vec<ksni::Icon> {
+            // ksni attend de l’ARGB32 (ordre réseau).
+            let mut data = icon_rgba();
+            for pixel in data.chunks_exact_mut(4) {
+                pixel.rotate_right(1);
+            }
+            vec![ksni::Icon {
+                width: ICON_SIZE as i32,
+                height: ICON_SIZE as i32,
+                data,
+            }]
+        }
+
+        fn tool_tip(&self) -> ksni::ToolTip {
+            ksni::ToolTip {
+                title: self.tooltip.clone(),
+                ..Default::default()
+            }
+        }
+
+        fn activate(&mut self, _x: i32, _y: i32) {
+            self.send(TrayCommand::Open);
+        }
+
+        fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
+            use ksni::menu::StandardItem;
+            let item = |label: &str, command: TrayCommand| {
+                StandardItem {
+                    label: label.into(),
+                    activate: Box::new(move |tray: &mut Self| tray.send(command)),
+                    ..Default::default()
+                }
+                .into()
+            };
+            vec![
+                item(&tr("tray.open"), TrayCommand::Open),
+                item(&tr("tray.refresh"), TrayCommand::Refresh),
+                ksni::MenuItem::Separator,
+                item(&tr("tray.quit"), TrayCommand::Quit),
+            ]
+        }
+    }
+
+    pub struct Backend {
+        handle: ksni::Handle<ReadRssTray>,
+    }
+
+    impl Backend {
+        pub fn spawn(
+            runtime: &Runtime,
+            ctx: egui::Context,
+            commands: mpsc::Sender<TrayCommand>,
+        ) -> Result<Self, String> {
+            let tray = ReadRssTray {
+                tooltip: tooltip(0),
+                commands,
+                ctx,
+            };
+            let handle = runtime.block_on(tray.spawn()).map_err(|e| e.to_string())?;
+            Ok(Self { handle })
+        }
+
+        // Les actions arrivent déjà par le canal (thread D-Bus).
+        pub fn pump(&self) {}
+
+        pub fn set_tooltip(&self, runtime: &Runtime, text: String) {
+            let handle = self.handle.clone();
+            runtime.spawn(async move {
+                handle.update(|tray| tray.tooltip = text).await;
+            });
+        }
+    }
+
+    impl Drop for Backend {
+        fn drop(&mut self) {
+            // La demande d’arrêt part immédiatement; inutile d’attendre sa fin.
+            drop(self.handle.shutdown());
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use std::sync::mpsc;
+
+    use eframe::egui;
+    use tokio::runtime::Runtime;
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+    use tray_icon::{
+        Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+    };
+
+    use super::{icon_rgba, tooltip, TrayCommand, ICON_SIZE};
+    use crate::i18n::tr;
+
+    // ===
+    //
+    //
+    // tray-icon doit vivre sur le thread de la boucle d’évènements (celui de l’UI); les clics
+    // sont relevés par `pump()` à chaque image.
+    //
+    //
+    // ===
+    pub struct Backend {
+        icon: TrayIcon,
+        items: Vec<(MenuItem, TrayCommand)>,
+        commands: mpsc::Sender<TrayCommand>,
+        ctx: egui::Context,
+    }
+
+    impl Backend {
+        pub fn spawn(
+            _runtime: &Runtime,
+            ctx: egui::Context,
+            commands: mpsc::Sender<TrayCommand>,
+        ) -> Result<Self, String> {
+            let items = vec![
+                (
+                    MenuItem::new(tr("tray.open"), true, None),
+                    TrayCommand::Open,
+                ),
+                (
+                    MenuItem::new(tr("tray.refresh"), true, None),
+                    TrayCommand::Refresh,
+                ),
+                (
+                    MenuItem::new(tr("tray.quit"), true, None),
+                    TrayCommand::Quit,
+                ),
+            ];
+            let menu = Menu::new();
+            let separator = PredefinedMenuItem::separator();
+            menu.append_items(&[&items[0].0, &items[1].0, &separator, &items[2].0])
+                .map_err(|e| e.to_string())?;
+            let icon =
+                Icon::from_rgba(icon_rgba(), ICON_SIZE, ICON_SIZE).map_err(|e| e.to_string())?;
+            let icon = TrayIconBuilder::new()
+                .with_menu(Box::new(menu))
+                .with_tooltip(tooltip(0))
+                .with_icon(icon)
+                .with_menu_on_left_click(false)
+                .build()
+                .map_err(|e| e.to_string())?;
+            Ok(Self {
+                icon,
+                items,
+                commands,
+                ctx,
+            })
+        }
+
+        pub fn pump(&self) {
+            while let Ok(event) = MenuEvent::receiver().try_recv() {
+                let chosen = self.items.iter().find(|(item, _)| item.id() == event.id());
+                if let Some((_, command)) = chosen {
+                    self.send(*command);
+                }
+            }
+            while let Ok(event) = TrayIconEvent::receiver().try_recv() {
+                let open = match event {
+                    TrayIconEvent::Click {
+                        button: MouseButton::Left,
+                        button_state: MouseButtonState::Up,
+                        ..
+                    } => true,
+                    TrayIconEvent::DoubleClick { .. } => true,
+                    _ => false,
+                };
+                if open {
+                    self.send(TrayCommand::Open);
+                }
+            }
+        }
+
+        fn send(&self, command: TrayCommand) {
+            let _ = self.commands.send(command);
+            self.ctx.request_repaint();
+        }
+
+        pub fn set_tooltip(&self, _runtime: &Runtime, text: String) {
+            let _ = self.icon.set_tooltip(Some(text));
+        }
+    }
+}
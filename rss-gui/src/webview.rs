@@ -0,0 +1,292 @@
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rss_core::NetworkConfig;
+
+use crate::i18n::tr_args;
+use crate::open_path::Platform;
+
+// ===
+//
+//
+// Visionneuse d’articles: ouverture d’une URL ou d’un HTML local dans un processus enfant
+// (programme d’ouverture de la plateforme, `Platform::open_program`, la cible passée en argument
+// sans shell). Le lancement ne bloque pas: un thread surveille chaque enfant et signale une fin
+// anormale; le nombre d’enfants ouverts est plafonné.
+//
+//
+// ===
+
+// Nombre maximal de visionneuses ouvertes en même temps.
+pub const MAX_OPEN_VIEWERS: usize = 4;
+
+// Variables du proxy transmises à l’enfant (les outils lisent l’une ou l’autre casse).
+const PROXY_ENV: [&str; 6] = [
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "ALL_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "all_proxy",
+];
+
+// User-Agent configuré, à l’usage d’une visionneuse qui sait le reprendre.
+pub const USER_AGENT_ENV: &str = "READRSS_USER_AGENT";
+
+// Processus de visionneuse lancé (surveillé en arrière-plan jusqu’à sa fin).
+#[derive(Debug)]
+pub struct WebviewChild {
+    pid: u32,
+}
+
+impl WebviewChild {
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+}
+
+// ===
+//
+//
+// Lanceur de visionneuses: programme de la plateforme (ou remplacé, pour les tests),
+// environnement réseau de l’appli et compteur des enfants encore ouverts.
+//
+//
+// ===
+#[derive(Debug, Clone)]
+pub struct Viewer {
+    program: Option<(OsString, Vec<OsString>)>,
+    env: Vec<(String, String)>,
+    max_open: usize,
+    open: Arc<AtomicUsize>,
+}
+
+impl Viewer {
+    pub fn new(network: &NetworkConfig) -> Self {
+        let mut env = Vec::new();
+        let configured = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        if let Some(proxy) = configured(&network.proxy_url) {
+            env.extend(PROXY_ENV.iter().map(|k| (k.to_string(), proxy.clone())));
+        }
+        if let Some(user_agent) = configured(&network.user_agent) {
+            env.push((USER_AGENT_ENV.to_string(), user_agent));
+        }
+        Self {
+            program: None,
+            env,
+            max_open: MAX_OPEN_VIEWERS,
+            open: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    // ===
+    //
+    //
+    // Ouvre une URL d’article (http/https uniquement) dans la visionneuse; `on_failure` reçoit
+    // le message si l’enfant se termine en erreur.
+    //
+    //
+    // ===
+    pub fn open_url(
+        &self,
+        url: &str,
+        title: &str,
+        on_failure: impl FnOnce(String) + Send + 'static,
+    ) -> Result<WebviewChild, String> {
+        let parsed =
+            url::Url::parse(url).map_err(|e| tr_args("viewer.invalid_url", &[("error", &e)]))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(tr_args(
+                "viewer.unsupported_scheme",
+                &[("scheme", &parsed.scheme())],
+            ));
+        }
+        tracing::info!(title, "ouverture de l’article dans la visionneuse");
+        self.spawn(parsed.as_str(), on_failure)
+    }
+
+    // ===
+    //
+    //
+    // Ouvre un fichier HTML local (contenu d’article déjà écrit sur disque) dans la visionneuse.
+    //
+    //
+    // ===
+    pub fn open_local_html(
+        &self,
+        path: &Path,
+        title: &str,
+        on_failure: impl FnOnce(String) + Send + 'static,
+    ) -> Result<WebviewChild, String> {
+        let url = url::Url::from_file_path(path)
+            .map_err(|_| tr_args("viewer.invalid_path", &[("path", &path.display())]))?;
+        tracing::info!(
+            title,
+            path = %path.display(),
+            "ouverture du contenu local dans la visionneuse"
+        );
+        self.spawn(url.as_str(), on_failure)
+    }
+
+    // Commande complète lancée pour une cible (programme, arguments et environnement).
+    pub fn command(&self, target: &str) -> Command {
+        let mut cmd = match &self.program {
+            Some((program, args)) => {
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+            None => Command::new(Platform::current().open_program()),
+        };
+        cmd.arg(target);
+        cmd.envs(self.env.iter().map(|(k, v)| (k, v)));
+        cmd
+    }
+
+    fn spawn(
+        &self,
+        target: &str,
+        on_failure: impl FnOnce(String) + Send + 'static,
+    ) -> Result<WebviewChild, String> {
+        let max_open = self.max_open;
+        self.open
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                (n < max_open).then_some(n + 1)
+            })
+            .map_err(|n| tr_args("viewer.too_many", &[("open", &n), ("max", &max_open)]))?;
+
+        let mut child = match self.command(target).spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                self.open.fetch_sub(1, Ordering::SeqCst);
+                return Err(tr_args("viewer.launch_failed", &[("error", &e)]));
+            }
+        };
+        let pid = child.id();
+        let open = self.open.clone();
+        let check_status =
+            self.program.is_some() || Platform::current().exit_status_is_meaningful();
+        std::thread::spawn(move || {
+            let result = child.wait();
+            open.fetch_sub(1, Ordering::SeqCst);
+            match result {
+                Ok(status) if status.success() || !check_status => {}
+                Ok(status) => {
+                    tracing::warn!(pid, %status, "viewer exited with an error");
+                    on_failure(tr_args("viewer.exited", &[("status", &status)]));
+                }
+                Err(e) => on_failure(tr_args("viewer.lost", &[("error", &e)])),
+            }
+        });
+        Ok(WebviewChild { pid })
+    }
+}
+
+// Réglages sans usage dans l’appli elle-même: tests (tests/webview_tests.rs) et diagnostic.
+#[allow(dead_code)]
+impl Viewer {
+    // Remplace la visionneuse de la plateforme (la cible est ajoutée après `args`).
+    pub fn with_program(
+        mut self,
+        program: impl Into<OsString>,
+        args: impl IntoIterator<Item = impl Into<OsString>>,
+    ) -> Self {
+        self.program = Some((program.into(), args.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    pub fn with_max_open(mut self, max_open: usize) -> Self {
+        self.max_open = max_open;
+        self
+    }
+
+    // Nombre de visionneuses lancées et pas encore terminées.
+    pub fn open_count(&self) -> usize {
+        self.open.load(Ordering::SeqCst)
+    }
+}
+
+// Préfixe des fichiers d’article écrits pour la visionneuse.
+const ARTICLE_FILE_PREFIX: &str = "readrss_article_";
+
+// Âge au-delà duquel un fichier d’article est supprimé à l’écriture suivante.
+const ARTICLE_FILE_TTL: Duration = Duration::from_secs(24 * 3600);
+
+// Dossier des fichiers d’article, propre à l’utilisateur (cache), à défaut le dossier temporaire.
+fn article_dir() -> PathBuf {
+    dirs::cache_dir()
+        .map(|dir| dir.join("ReadRSS").join("articles"))
+        .unwrap_or_else(std::env::temp_dir)
+}
+
+pub fn write_article_html(title: &str, html: &str) -> std::io::Result<PathBuf> {
+    write_article_html_in(&article_dir(), title, html)
+}
+
+// ===
+//
+//
+// Écrit le HTML (nettoyé) d’un article dans un nouveau fichier de `dir`, au nom aléatoire et
+// créé en exclusivité (ni lien symbolique suivi, ni fichier d’un autre réutilisé; 0600 sous
+// Unix). Les scripts y sont interdits par une politique CSP en plus du nettoyage. Les fichiers
+// de plus d’un jour laissés par les ouvertures précédentes sont supprimés au passage.
+//
+//
+// ===
+pub fn write_article_html_in(dir: &Path, title: &str, html: &str) -> std::io::Result<PathBuf> {
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(dir)?;
+    remove_old_article_files(dir);
+
+    let title = rss_core::sanitize::escape_html(title);
+    let body = rss_core::sanitize::sanitize_html(html);
+    let document = format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">\
+         <meta http-equiv=\"Content-Security-Policy\" content=\"script-src 'none'; object-src 'none'\">\
+         <title>{title}</title>\
+         <style>body{{max-width:46em;margin:2em auto;padding:0 1em;font-family:sans-serif;line-height:1.5}}img{{max-width:100%;height:auto}}</style>\
+         </head><body><h1>{title}</h1>\n{body}\n</body></html>\n"
+    );
+    let mut file = tempfile::Builder::new()
+        .prefix(ARTICLE_FILE_PREFIX)
+        .suffix(".html")
+        .tempfile_in(dir)?;
+    file.write_all(document.as_bytes())?;
+    let (_, path) = file.keep().map_err(|e| e.error)?;
+    Ok(path)
+}
+
+fn remove_old_article_files(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let is_article = entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(ARTICLE_FILE_PREFIX);
+        let expired = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > ARTICLE_FILE_TTL);
+        if is_article && expired {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use eframe::egui::{Pos2, Rect, Vec2};
+
+// ===
+//
+//
+// Position de fenêtre restaurée au démarrage. Les écrans ne sont connus qu’une fois la fenêtre
+// ouverte, et seulement celui qui l’affiche (à défaut l’écran principal, placé à l’origine):
+// une position enregistrée qui n’y est pas visible alors que cet écran n’a plus la taille
+// notée à la fermeture (écran débranché) est ramenée dans l’écran.
+//
+//
+// ===
+
+// Portion de la barre de titre qui doit rester visible pour pouvoir saisir la fenêtre.
+pub const MIN_VISIBLE: f32 = 48.0;
+
+// La barre de titre (haut de la fenêtre) est-elle assez visible dans l’écran?
+pub fn visible_on(window: Rect, monitor: Rect) -> bool {
+    let visible_width = window.max.x.min(monitor.max.x) - window.min.x.max(monitor.min.x);
+    visible_width >= MIN_VISIBLE
+        && window.min.y >= monitor.min.y
+        && window.min.y <= monitor.max.y - MIN_VISIBLE
+}
+
+// Position qui fait tenir la fenêtre dans l’écran (coin haut gauche si elle est plus grande).
+pub fn clamp_to_monitor(window: Rect, monitor: Rect) -> Pos2 {
+    let max = (monitor.max - window.size()).max(monitor.min);
+    window.min.clamp(monitor.min, max)
+}
+
+// ===
+//
+//
+// Nouvelle position de la fenêtre (None: la garder). `saved_monitor` est la taille de l’écran
+// notée à la fermeture: s’il a toujours cette taille, la position enregistrée est respectée,
+// même hors de l’origine (second écran).
+//
+//
+// ===
+pub fn restored_position(
+    window: Rect,
+    monitor_size: Vec2,
+    saved_monitor: Option<Vec2>,
+) -> Option<Pos2> {
+    let monitor = Rect::from_min_size(Pos2::ZERO, monitor_size);
+    if visible_on(window, monitor) || saved_monitor == Some(monitor_size) {
+        return None;
+    }
+    Some(clamp_to_monitor(window, monitor))
+}
@@ -0,0 +1,63 @@
+// The GUI is a binary crate: the image module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/article_images.rs"]
+mod article_images;
+
+use std::io::Cursor;
+
+use article_images::{decode_image, MAX_IMAGE_SIDE};
+use image::codecs::gif::GifEncoder;
+use image::{Delay, Frame, ImageFormat, Rgba, RgbaImage};
+
+fn encode(image: &RgbaImage, format: ImageFormat) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+    image.write_to(&mut bytes, format).unwrap();
+    bytes.into_inner()
+}
+
+#[test]
+fn png_is_decoded_to_its_size() {
+    let png = encode(
+        &RgbaImage::from_pixel(4, 2, Rgba([255, 0, 0, 255])),
+        ImageFormat::Png,
+    );
+    let decoded = decode_image(&png).unwrap();
+    assert_eq!(decoded.size, [4, 2]);
+    assert_eq!(
+        decoded.pixels[0],
+        eframe::egui::Color32::from_rgb(255, 0, 0)
+    );
+}
+
+#[test]
+fn large_images_are_scaled_down_keeping_proportions() {
+    let wide = RgbaImage::new(MAX_IMAGE_SIDE * 2, 100);
+    let decoded = decode_image(&encode(&wide, ImageFormat::Png)).unwrap();
+    assert_eq!(decoded.size, [MAX_IMAGE_SIDE as usize, 50]);
+}
+
+#[test]
+fn animated_gif_shows_its_first_frame() {
+    let mut gif = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut gif);
+        let delay = Delay::from_numer_denom_ms(100, 1);
+        for color in [[0, 0, 255, 255], [0, 255, 0, 255]] {
+            let frame = RgbaImage::from_pixel(3, 3, Rgba(color));
+            encoder
+                .encode_frame(Frame::from_parts(frame, 0, 0, delay))
+                .unwrap();
+        }
+    }
+    let decoded = decode_image(&gif).unwrap();
+    assert_eq!(decoded.size, [3, 3]);
+    assert_eq!(
+        decoded.pixels[4],
+        eframe::egui::Color32::from_rgb(0, 0, 255)
+    );
+}
+
+#[test]
+fn garbage_is_an_error() {
+    assert!(decode_image(b"<html>not an image</html>").is_err());
+}
@@ -0,0 +1,94 @@
+// The GUI is a binary crate: the article list module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/article_list.rs"]
+mod article_list;
+
+use chrono::{DateTime, TimeZone, Utc};
+use rss_core::FeedEntry;
+
+fn at(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 1, hour, 0, 0).unwrap()
+}
+
+fn entry(feed_id: &str, guid: &str, title: &str, published: DateTime<Utc>) -> FeedEntry {
+    FeedEntry {
+        feed_id: feed_id.into(),
+        title: title.into(),
+        url: format!("https://example.org/{}", guid),
+        published_at: Some(published),
+        guid: Some(guid.into()),
+        first_seen_at: published,
+        ..Default::default()
+    }
+}
+
+fn titles(articles: &[FeedEntry]) -> Vec<&str> {
+    articles.iter().map(|a| a.title.as_str()).collect()
+}
+
+#[test]
+fn upsert_replaces_known_articles_and_keeps_newest_first() {
+    let mut articles = vec![entry("a", "1", "a1", at(8)), entry("b", "1", "b1", at(9))];
+    article_list::upsert(
+        &mut articles,
+        vec![
+            entry("a", "1", "a1 edited", at(8)),
+            entry("a", "2", "a2", at(10)),
+            // Same identity in another feed is a different article.
+            entry("c", "1", "c1", at(7)),
+        ],
+    );
+    assert_eq!(titles(&articles), ["a2", "b1", "a1 edited", "c1"]);
+
+    // A batch already merged after a reload does not duplicate anything.
+    article_list::upsert(&mut articles, vec![entry("a", "2", "a2", at(10))]);
+    assert_eq!(articles.len(), 4);
+}
+
+#[test]
+fn replace_feed_swaps_only_that_feed() {
+    let mut articles = vec![
+        entry("a", "1", "a1", at(8)),
+        entry("a", "2", "a2", at(9)),
+        entry("b", "1", "b1", at(10)),
+    ];
+    article_list::replace_feed(&mut articles, "a", vec![entry("a", "3", "a3", at(11))]);
+    assert_eq!(titles(&articles), ["a3", "b1"]);
+
+    article_list::remove_feeds(&mut articles, |id| id == "b");
+    assert_eq!(titles(&articles), ["a3"]);
+}
+
+#[test]
+fn sort_is_stable_for_equal_dates() {
+    let mut articles = vec![
+        entry("a", "1", "first", at(8)),
+        entry("b", "1", "newer", at(9)),
+        entry("c", "1", "second", at(8)),
+        entry("d", "1", "third", at(8)),
+    ];
+    article_list::sort_newest_first(&mut articles);
+    assert_eq!(titles(&articles), ["newer", "first", "second", "third"]);
+    article_list::upsert(&mut articles, vec![entry("e", "1", "fourth", at(8))]);
+    assert_eq!(
+        titles(&articles),
+        ["newer", "first", "second", "third", "fourth"]
+    );
+}
+
+#[test]
+fn view_cache_is_reused_until_its_key_changes() {
+    let mut cache: article_list::ViewCache<(u64, bool), Vec<&str>> = Default::default();
+    assert!(cache.get(&(1, false)).is_none());
+
+    let view = cache.store((1, false), vec!["a1", "b1"]);
+    let again = cache.get(&(1, false)).unwrap();
+    assert!(std::rc::Rc::ptr_eq(&view, &again));
+
+    // Another revision or filter misses, and the new view replaces the old one.
+    assert!(cache.get(&(2, false)).is_none());
+    assert!(cache.get(&(1, true)).is_none());
+    cache.store((1, true), vec!["b1"]);
+    assert!(cache.get(&(1, false)).is_none());
+    assert_eq!(*cache.get(&(1, true)).unwrap(), ["b1"]);
+}
@@ -0,0 +1,107 @@
+// The GUI is a binary crate: the catalog module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/discover.rs"]
+mod discover;
+
+use discover::{
+    catalog_search, query_as_url, recommended_categories, DISCOVER_LANGUAGES, TOP_FEEDS,
+};
+
+fn langs(codes: &[&str]) -> Vec<String> {
+    codes.iter().map(|c| c.to_string()).collect()
+}
+
+fn non_empty_categories(languages: &[String]) -> Vec<&'static str> {
+    recommended_categories()
+        .iter()
+        .filter(|cat| !cat.top_feeds(languages).is_empty())
+        .map(|cat| cat.name)
+        .collect()
+}
+
+#[test]
+fn every_feed_has_a_selectable_language() {
+    for cat in recommended_categories() {
+        for feed in cat.feeds {
+            assert!(
+                DISCOVER_LANGUAGES
+                    .iter()
+                    .any(|(code, _)| *code == feed.lang),
+                "{} has no selectable language",
+                feed.title
+            );
+        }
+    }
+}
+
+#[test]
+fn no_language_selected_shows_everything() {
+    for cat in recommended_categories() {
+        assert_eq!(cat.top_feeds(&[]).len(), cat.feeds.len().min(TOP_FEEDS));
+    }
+    assert_eq!(
+        non_empty_categories(&langs(&["fr", "en"])),
+        non_empty_categories(&[])
+    );
+}
+
+#[test]
+fn categories_without_matching_feeds_are_hidden() {
+    let french = langs(&["fr"]);
+    assert_eq!(
+        non_empty_categories(&french),
+        ["Technologie", "Actualités (FR)"]
+    );
+    let tech = &recommended_categories()[0];
+    let titles: Vec<_> = tech.top_feeds(&french).iter().map(|f| f.title).collect();
+    assert_eq!(titles, ["Numerama", "Korben"]);
+
+    assert!(!non_empty_categories(&langs(&["en"])).contains(&"Actualités (FR)"));
+    assert!(non_empty_categories(&langs(&["de"])).is_empty());
+}
+
+fn search_titles(query: &str) -> Vec<&'static str> {
+    catalog_search(query).iter().map(|f| f.title).collect()
+}
+
+#[test]
+fn catalog_search_ignores_case_and_accents() {
+    let accented = search_titles("actualités");
+    assert!(!accented.is_empty());
+    assert_eq!(search_titles("ACTUALITES"), accented);
+    assert_eq!(search_titles("Actualites"), accented);
+
+    assert_eq!(search_titles("numerama"), ["Numerama"]);
+    assert_eq!(search_titles("  le monde "), ["Le Monde – Une"]);
+    // Typographic dashes match a plain hyphen.
+    assert_eq!(search_titles("nature - latest"), ["Nature – Latest"]);
+    assert!(search_titles("high-tech").contains(&"Ars Technica"));
+}
+
+#[test]
+fn catalog_search_matches_urls_once_and_needs_a_query() {
+    assert_eq!(search_titles("blog.rust-lang.org"), ["Rust Blog"]);
+    let rss = catalog_search("rss");
+    for (i, feed) in rss.iter().enumerate() {
+        assert!(rss[..i].iter().all(|other| other.url != feed.url));
+    }
+    assert!(catalog_search("").is_empty());
+    assert!(catalog_search("   ").is_empty());
+    assert!(catalog_search("zzz introuvable").is_empty());
+}
+
+#[test]
+fn url_like_queries_are_offered_to_autodiscovery() {
+    assert_eq!(
+        query_as_url("https://blog.example.org/"),
+        Some("https://blog.example.org/".to_string())
+    );
+    assert_eq!(
+        query_as_url(" example.org/blog "),
+        Some("https://example.org/blog".to_string())
+    );
+    assert_eq!(query_as_url("rust"), None);
+    assert_eq!(query_as_url("le monde"), None);
+    assert_eq!(query_as_url("ftp://example.org/"), None);
+    assert_eq!(query_as_url("example."), None);
+}
@@ -0,0 +1,144 @@
+// The GUI is a binary crate: the translation module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/i18n.rs"]
+mod i18n;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration, TimeZone, Utc};
+use i18n::{
+    format_date, format_relative_date, resolve_language, tr, tr_args, tr_count, SOURCE_LANGUAGE,
+};
+
+fn locales_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("locales")
+}
+
+fn read_table(path: &Path) -> HashMap<String, String> {
+    let json = std::fs::read_to_string(path).unwrap();
+    serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+fn source_table() -> HashMap<String, String> {
+    read_table(&locales_dir().join(format!("{}.json", SOURCE_LANGUAGE)))
+}
+
+#[test]
+fn every_source_key_is_translated_in_every_table() {
+    let source = source_table();
+    let mut checked = 0;
+    for entry in std::fs::read_dir(locales_dir()).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let table = read_table(&path);
+        let mut missing: Vec<&String> = source.keys().filter(|k| !table.contains_key(*k)).collect();
+        missing.sort();
+        assert!(missing.is_empty(), "{} lacks {:?}", path.display(), missing);
+        checked += 1;
+    }
+    // fr and en at least.
+    assert!(checked >= 2);
+}
+
+#[test]
+fn every_key_used_by_the_app_exists() {
+    let source = source_table();
+    let app =
+        std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/app.rs")).unwrap();
+    let mut missing = Vec::new();
+    for call in ["tr(\"", "tr_args(\"", "tr_count(\""] {
+        for (start, _) in app.match_indices(call) {
+            let rest = &app[start + call.len()..];
+            let key = &rest[..rest.find('"').unwrap()];
+            if !source.contains_key(key) {
+                missing.push(key.to_string());
+            }
+        }
+    }
+    assert!(
+        missing.is_empty(),
+        "keys missing from fr.json: {:?}",
+        missing
+    );
+}
+
+#[test]
+fn language_is_resolved_from_setting_then_system() {
+    assert_eq!(resolve_language(Some("en"), Some("fr-FR")), "en");
+    assert_eq!(resolve_language(None, Some("fr-CA")), "fr");
+    assert_eq!(resolve_language(None, Some("fr_FR")), "fr");
+    assert_eq!(resolve_language(Some("xx"), Some("EN-us")), "en");
+    // No table for either: English.
+    assert_eq!(resolve_language(Some("xx"), Some("de-DE")), "en");
+    assert_eq!(resolve_language(None, None), "en");
+}
+
+#[test]
+fn unknown_key_falls_back_to_the_key() {
+    assert_eq!(tr("no.such.key"), "no.such.key");
+}
+
+#[test]
+fn named_arguments_are_substituted() {
+    let text = tr_args(
+        "toast.fetch_failed",
+        &[("title", &"Blog"), ("error", &"timeout")],
+    );
+    assert!(text.contains("Blog"), "{}", text);
+    assert!(text.contains("timeout"), "{}", text);
+    assert!(!text.contains('{'), "{}", text);
+}
+
+#[test]
+fn count_picks_zero_and_one_variants() {
+    let dir: &dyn std::fmt::Display = &"/tmp";
+    assert_eq!(
+        tr_count("toast.exported", 0, &[("dir", dir)]),
+        tr_args("toast.exported_zero", &[("dir", dir)])
+    );
+    assert_eq!(
+        tr_count("toast.exported", 1, &[("dir", dir)]),
+        tr_args("toast.exported_one", &[("dir", dir)])
+    );
+    let many = tr_count("toast.exported", 12, &[("dir", dir)]);
+    assert!(many.contains("12") && many.contains("/tmp"), "{}", many);
+}
+
+#[test]
+fn relative_dates_use_the_table() {
+    let now = Utc.with_ymd_and_hms(2024, 5, 10, 12, 0, 0).unwrap();
+    assert_eq!(format_relative_date(now, now), tr("date.just_now"));
+    let three_hours = format_relative_date(now - Duration::hours(3), now);
+    assert_eq!(three_hours, tr_count("date.hours_ago", 3, &[]));
+    assert!(three_hours.contains('3'));
+}
+
+#[test]
+fn french_table_matches_core_formatting() {
+    // The source language is the default: the table reproduces rss-core's French output.
+    let now = Utc.with_ymd_and_hms(2024, 5, 10, 12, 0, 0).unwrap();
+    for offset in [
+        Duration::seconds(5),
+        Duration::seconds(40),
+        Duration::minutes(7),
+        Duration::hours(5),
+        Duration::days(1),
+        Duration::days(4),
+        Duration::days(9),
+        Duration::days(20),
+        Duration::days(45),
+        Duration::days(100),
+        Duration::days(400),
+        Duration::days(900),
+    ] {
+        let date = now - offset;
+        assert_eq!(
+            format_relative_date(date, now),
+            rss_core::format_relative(date, now)
+        );
+    }
+    assert_eq!(format_date(now), rss_core::format_absolute(now));
+}
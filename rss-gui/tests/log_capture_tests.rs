@@ -0,0 +1,79 @@
+// The GUI is a binary crate: the capture module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/log_capture.rs"]
+mod log_capture;
+
+use log_capture::LogBuffer;
+use tracing::Level;
+use tracing_subscriber::layer::SubscriberExt;
+
+fn capture(capacity: usize, emit: impl FnOnce()) -> LogBuffer {
+    let logs = LogBuffer::new(capacity);
+    let subscriber = tracing_subscriber::registry().with(logs.layer());
+    tracing::subscriber::with_default(subscriber, emit);
+    logs
+}
+
+#[test]
+fn events_are_captured_with_their_fields() {
+    let logs = capture(10, || {
+        tracing::warn!(
+            feed = "https://example.org/feed",
+            status = 503,
+            "failed to fetch feed"
+        );
+        tracing::info!("poller started");
+    });
+
+    let lines = logs.snapshot(Level::TRACE, "");
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].level, Level::WARN);
+    assert_eq!(lines[0].feed.as_deref(), Some("https://example.org/feed"));
+    assert_eq!(lines[0].message, "failed to fetch feed status=503");
+    assert!(lines[0].to_text().contains("feed=https://example.org/feed"));
+    assert_eq!(lines[1].message, "poller started");
+}
+
+#[test]
+fn level_and_search_filter_the_snapshot() {
+    let logs = capture(10, || {
+        tracing::error!("disk full");
+        tracing::warn!(feed = "https://slow.example/rss", "timeout");
+        tracing::debug!("cycle done");
+    });
+
+    let warnings = logs.snapshot(Level::WARN, "");
+    assert_eq!(warnings.len(), 2);
+    let search = logs.snapshot(Level::TRACE, "SLOW.example");
+    assert_eq!(search.len(), 1);
+    assert_eq!(search[0].message, "timeout");
+}
+
+#[test]
+fn oldest_lines_are_dropped_past_capacity() {
+    let logs = capture(3, || {
+        for n in 0..5 {
+            tracing::info!("line {}", n);
+        }
+    });
+
+    let messages: Vec<String> = logs
+        .snapshot(Level::TRACE, "")
+        .into_iter()
+        .map(|line| line.message)
+        .collect();
+    assert_eq!(messages, ["line 2", "line 3", "line 4"]);
+}
+
+#[test]
+fn a_busy_buffer_does_not_block_the_caller() {
+    let logs = LogBuffer::new(10);
+    let subscriber = tracing_subscriber::registry().with(logs.layer());
+    // The view holds the buffer while the event is emitted.
+    let reading = logs.clone();
+    tracing::subscriber::with_default(subscriber, || {
+        reading.with_lines(|_| tracing::info!("while reading"));
+    });
+    assert_eq!(logs.dropped(), 1);
+    assert!(logs.snapshot(Level::TRACE, "").is_empty());
+}
@@ -0,0 +1,75 @@
+// The GUI is a binary crate: the helper module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/open_path.rs"]
+mod open_path;
+
+use std::cell::RefCell;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use open_path::{open_path_with, Platform, Spawner};
+
+// Records the commands it is asked to run instead of starting them.
+#[derive(Default)]
+struct Recorder {
+    calls: RefCell<Vec<(String, PathBuf)>>,
+    fail: bool,
+}
+
+impl Spawner for Recorder {
+    fn spawn(&self, program: &str, path: &Path) -> io::Result<()> {
+        self.calls
+            .borrow_mut()
+            .push((program.to_string(), path.to_path_buf()));
+        if self.fail {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "no such program"));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn each_platform_uses_its_file_manager() {
+    let dir = std::env::temp_dir();
+    for (platform, program) in [
+        (Platform::Windows, "explorer"),
+        (Platform::MacOs, "open"),
+        (Platform::Other, "xdg-open"),
+    ] {
+        let recorder = Recorder::default();
+        open_path_with(&recorder, platform, &dir).unwrap();
+        assert_eq!(
+            recorder.calls.into_inner(),
+            vec![(program.to_string(), dir.clone())]
+        );
+    }
+}
+
+#[test]
+fn a_file_is_shown_through_its_folder() {
+    let dir = std::env::temp_dir().join(format!("readrss_open_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("readrss_export.json");
+    std::fs::write(&file, b"{}").unwrap();
+
+    let recorder = Recorder::default();
+    open_path_with(&recorder, Platform::Other, &file).unwrap();
+    assert_eq!(recorder.calls.borrow()[0].1, dir);
+
+    // A path that does not exist yet is passed as is.
+    let missing = dir.join("missing");
+    open_path_with(&recorder, Platform::Other, &missing).unwrap();
+    assert_eq!(recorder.calls.borrow()[1].1, missing);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn spawn_failure_is_returned() {
+    let recorder = Recorder {
+        fail: true,
+        ..Recorder::default()
+    };
+    let err = open_path_with(&recorder, Platform::MacOs, Path::new("/tmp")).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
@@ -0,0 +1,138 @@
+// The GUI is a binary crate: the renderer module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/richtext.rs"]
+mod richtext;
+
+use eframe::egui;
+use richtext::{parse_html, show, BlockKind};
+use url::Url;
+
+fn kinds_and_text(html: &str) -> Vec<(BlockKind, usize, String)> {
+    parse_html(html, None)
+        .into_iter()
+        .map(|block| {
+            let text = block.text();
+            (block.kind, block.depth, text)
+        })
+        .collect()
+}
+
+#[test]
+fn nested_lists_keep_their_markers_and_depth() {
+    let html = "<ol><li>One<ul><li>Bullet</li><li>Other</li></ul></li><li>Two</li></ol>";
+    assert_eq!(
+        kinds_and_text(html),
+        [
+            (BlockKind::ListItem("1.".into()), 1, "One".into()),
+            (BlockKind::ListItem("•".into()), 2, "Bullet".into()),
+            (BlockKind::ListItem("•".into()), 2, "Other".into()),
+            (BlockKind::ListItem("2.".into()), 1, "Two".into()),
+        ]
+    );
+    let started = kinds_and_text("<ol start=\"4\"><li>Four<li>Five</ol>");
+    assert_eq!(started[1].0, BlockKind::ListItem("5.".into()));
+}
+
+#[test]
+fn links_keep_their_query_string_and_resolve_on_the_base() {
+    let base = Url::parse("https://example.org/blog/post.html").unwrap();
+    let html = "<p>See <a href=\"/search?q=rust&amp;page=2\">the <b>results</b></a> and \
+                <a href='https://other.example/?a=1&b=%20x'>this</a>.</p>\
+                <p><a href=\"javascript:alert(1)\">bad</a></p>";
+    let blocks = parse_html(html, Some(&base));
+    assert_eq!(blocks[0].text(), "See the results and this.");
+    let links: Vec<_> = blocks[0]
+        .spans
+        .iter()
+        .map(|span| (span.text.as_str(), span.link.as_deref(), span.bold))
+        .collect();
+    assert_eq!(
+        links,
+        [
+            ("See ", None, false),
+            (
+                "the ",
+                Some("https://example.org/search?q=rust&page=2"),
+                false
+            ),
+            (
+                "results",
+                Some("https://example.org/search?q=rust&page=2"),
+                true
+            ),
+            (" and ", None, false),
+            ("this", Some("https://other.example/?a=1&b=%20x"), false),
+            (".", None, false),
+        ]
+    );
+    // Scripts are not clickable; their text stays.
+    assert_eq!(blocks[1].spans[0].link, None);
+    assert_eq!(blocks[1].text(), "bad");
+}
+
+#[test]
+fn malformed_html_degrades_to_text() {
+    let html = "<p>Unclosed <strong>bold <em>both</p></em></strong></i><p>a < b &unknown; \
+                &#233;&#x2014;</p><div><span>kept</span> <blink>too";
+    let blocks = kinds_and_text(html);
+    assert_eq!(
+        blocks,
+        [
+            (BlockKind::Paragraph, 0, "Unclosed bold both".into()),
+            (BlockKind::Paragraph, 0, "a < b &unknown; é—".into()),
+            (BlockKind::Paragraph, 0, "kept too".into()),
+        ]
+    );
+    // Dangling tags and stray closers never panic.
+    for html in [
+        "<",
+        "<a href=\"x",
+        "</ul></li></blockquote>text",
+        "<pre>code",
+        "<!-- x",
+    ] {
+        parse_html(html, None);
+    }
+    assert_eq!(kinds_and_text("</ul></li>text")[0].2, "text");
+}
+
+#[test]
+fn headings_code_quotes_and_rules() {
+    let html = "<h1>Title</h1><h5>Small</h5><blockquote><p>Quoted   text</p></blockquote>\
+                <hr><pre>  fn main() {\n      <b>x</b> &lt; 1;\n  }</pre><p>Line<br>break</p>";
+    let blocks = parse_html(html, None);
+    assert_eq!(blocks[0].kind, BlockKind::Heading(1));
+    assert!(blocks[0].spans[0].bold);
+    assert_eq!(blocks[1].kind, BlockKind::Heading(4));
+    assert_eq!(
+        (blocks[2].quote, blocks[2].text()),
+        (1, "Quoted text".into())
+    );
+    assert_eq!(blocks[3].kind, BlockKind::Rule);
+    assert_eq!(blocks[4].kind, BlockKind::Code);
+    assert_eq!(blocks[4].text(), "  fn main() {\n      x < 1;\n  }");
+    assert_eq!(blocks[5].text(), "Line\nbreak");
+}
+
+#[test]
+fn rendering_runs_headless() {
+    let ctx = egui::Context::default();
+    ctx.style_mut(|style| {
+        for (name, size) in [("reading", 15.0), ("title", 16.0), ("headline", 22.0)] {
+            style.text_styles.insert(
+                egui::TextStyle::Name(name.into()),
+                egui::FontId::proportional(size),
+            );
+        }
+    });
+    let blocks = parse_html(
+        "<h2>T</h2><ul><li><a href=\"https://a.example/\">x</a></li></ul>\
+         <blockquote>q</blockquote><pre>c</pre><hr>",
+        None,
+    );
+    let _ = ctx.run(Default::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            assert_eq!(show(ui, &blocks, egui::Color32::GRAY), None);
+        });
+    });
+}
@@ -0,0 +1,56 @@
+// The GUI is a binary crate: the task cache module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/task_cache.rs"]
+mod task_cache;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+use task_cache::{TaskCache, TaskSlot};
+
+fn wait_done(cache: &mut TaskCache<String, usize>, key: &str) -> usize {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        cache.receive();
+        if let Some(TaskSlot::Done(value)) = cache.get(&key.to_string()) {
+            return *value;
+        }
+        assert!(Instant::now() < deadline, "task never completed");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn results_are_cached_per_key_until_forgotten() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let ctx = egui::Context::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+    let mut cache: TaskCache<String, usize> = TaskCache::new();
+    let task = |runs: &Arc<AtomicUsize>| {
+        let runs = runs.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            runs.fetch_add(1, Ordering::SeqCst) + 1
+        }
+    };
+
+    assert!(cache.get(&"a".to_string()).is_none());
+    cache.request(&runtime, &ctx, "a".into(), task(&runs));
+    assert!(matches!(cache.get(&"a".into()), Some(TaskSlot::Pending)));
+    // Forgetting a pending task and requesting again does not start a second one.
+    cache.forget(&"a".into());
+    cache.request(&runtime, &ctx, "a".into(), task(&runs));
+    assert_eq!(wait_done(&mut cache, "a"), 1);
+
+    cache.request(&runtime, &ctx, "a".into(), task(&runs));
+    assert!(matches!(cache.get(&"a".into()), Some(TaskSlot::Done(1))));
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+    cache.forget(&"a".into());
+    cache.request(&runtime, &ctx, "a".into(), task(&runs));
+    assert_eq!(wait_done(&mut cache, "a"), 2);
+    cache.request(&runtime, &ctx, "b".into(), task(&runs));
+    assert_eq!(wait_done(&mut cache, "b"), 3);
+}
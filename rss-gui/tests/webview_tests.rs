@@ -0,0 +1,162 @@
+// The GUI is a binary crate: the viewer module (and the translations and platform launcher it
+// uses) is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/i18n.rs"]
+mod i18n;
+#[allow(dead_code)]
+#[path = "../src/open_path.rs"]
+mod open_path;
+#[allow(dead_code)]
+#[path = "../src/webview.rs"]
+mod webview;
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use rss_core::NetworkConfig;
+use webview::{Viewer, USER_AGENT_ENV};
+
+const URL: &str = "https://example.org/article";
+
+// Fake viewer: the GUI binary itself, exiting at once with `code`.
+fn fake_viewer(code: i32) -> Viewer {
+    Viewer::new(&NetworkConfig::default()).with_program(
+        env!("CARGO_BIN_EXE_rss-gui"),
+        ["--webview-child".to_string(), code.to_string()],
+    )
+}
+
+fn failure_channel() -> (impl FnOnce(String) + Send + 'static, mpsc::Receiver<String>) {
+    let (tx, rx) = mpsc::channel();
+    (
+        move |message| {
+            let _ = tx.send(message);
+        },
+        rx,
+    )
+}
+
+#[test]
+fn clean_exit_reports_nothing_and_frees_the_slot() {
+    let viewer = fake_viewer(0);
+    let (on_failure, failures) = failure_channel();
+    let child = viewer.open_url(URL, "Article", on_failure).unwrap();
+    assert!(child.pid() > 0);
+
+    // The callback is dropped without being called once the child has exited.
+    assert_eq!(
+        failures.recv_timeout(Duration::from_secs(10)),
+        Err(mpsc::RecvTimeoutError::Disconnected)
+    );
+    assert_eq!(viewer.open_count(), 0);
+}
+
+#[test]
+fn abnormal_exit_is_reported() {
+    let viewer = fake_viewer(3);
+    let (on_failure, failures) = failure_channel();
+    viewer.open_url(URL, "Article", on_failure).unwrap();
+    let message = failures.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert!(message.contains("terminée en erreur"), "{}", message);
+}
+
+#[test]
+fn spawn_failure_is_returned_and_releases_the_slot() {
+    let viewer = Viewer::new(&NetworkConfig::default())
+        .with_program("/nonexistent/readrss-viewer", Vec::<String>::new())
+        .with_max_open(1);
+    let err = viewer.open_url(URL, "Article", |_| {}).unwrap_err();
+    assert!(err.contains("Impossible de lancer"), "{}", err);
+    assert_eq!(viewer.open_count(), 0);
+
+    let err = viewer
+        .open_url("file:///etc/passwd", "Article", |_| {})
+        .unwrap_err();
+    assert!(err.contains("Schéma non pris en charge"), "{}", err);
+}
+
+#[cfg(unix)]
+#[test]
+fn open_children_are_capped() {
+    // `sh -c 'sleep 2' <url>`: the target becomes $0 and the child stays open.
+    let viewer = Viewer::new(&NetworkConfig::default())
+        .with_program("sh", ["-c", "sleep 2"])
+        .with_max_open(1);
+    viewer.open_url(URL, "Article", |_| {}).unwrap();
+    assert_eq!(viewer.open_count(), 1);
+    let err = viewer.open_url(URL, "Article", |_| {}).unwrap_err();
+    assert!(err.contains("Trop de visionneuses"), "{}", err);
+}
+
+#[test]
+fn child_inherits_proxy_and_user_agent() {
+    let network = NetworkConfig {
+        proxy_url: Some("http://proxy.local:3128".into()),
+        user_agent: Some("ReadRSS-Test/1.0".into()),
+        ..NetworkConfig::default()
+    };
+    let command = Viewer::new(&network).command(URL);
+    let env: Vec<_> = command
+        .get_envs()
+        .map(|(k, v)| (k.to_owned(), v.map(|v| v.to_owned())))
+        .collect();
+    let value = |key: &str| {
+        env.iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, v)| v.clone())
+    };
+    assert_eq!(value("HTTPS_PROXY").unwrap(), "http://proxy.local:3128");
+    assert_eq!(value("http_proxy").unwrap(), "http://proxy.local:3128");
+    assert_eq!(value(USER_AGENT_ENV).unwrap(), "ReadRSS-Test/1.0");
+
+    let blank = NetworkConfig {
+        proxy_url: Some("  ".into()),
+        ..NetworkConfig::default()
+    };
+    assert_eq!(Viewer::new(&blank).command(URL).get_envs().count(), 0);
+}
+
+#[test]
+fn default_viewer_gets_the_url_as_one_argument_without_a_shell() {
+    let url = "https://example.org/article?a=1&calc|x^y";
+    let command = Viewer::new(&NetworkConfig::default()).command(url);
+    assert_eq!(
+        command.get_program(),
+        open_path::Platform::current().open_program()
+    );
+    let args: Vec<_> = command.get_args().collect();
+    assert_eq!(args, [url]);
+}
+
+#[test]
+fn article_files_are_new_private_files_in_the_given_directory() {
+    let dir = std::env::temp_dir().join(format!("readrss_viewer_{}", std::process::id()));
+    let first =
+        webview::write_article_html_in(&dir, "A <b>", "<p>x</p><script>bad()</script>").unwrap();
+    let second = webview::write_article_html_in(&dir, "A <b>", "<p>x</p>").unwrap();
+
+    // Same article twice: two distinct files, never a shared predictable name.
+    assert_ne!(first, second);
+    assert!(first.starts_with(&dir) && second.starts_with(&dir));
+    let html = std::fs::read_to_string(&first).unwrap();
+    assert!(html.contains("<title>A &lt;b&gt;</title>"), "{}", html);
+    assert!(!html.contains("bad()"), "{}", html);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let mode = std::fs::metadata(&dir).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+    }
+
+    // Files older than a day are cleaned up by the next write.
+    let old = std::fs::File::options().write(true).open(&first).unwrap();
+    old.set_modified(std::time::SystemTime::now() - Duration::from_secs(2 * 24 * 3600))
+        .unwrap();
+    drop(old);
+    webview::write_article_html_in(&dir, "B", "<p>y</p>").unwrap();
+    assert!(!first.exists());
+    assert!(second.exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
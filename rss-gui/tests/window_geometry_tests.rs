@@ -0,0 +1,71 @@
+// The GUI is a binary crate: the window geometry module is compiled here directly.
+#[allow(dead_code)]
+#[path = "../src/window_geometry.rs"]
+mod window_geometry;
+
+use eframe::egui::{pos2, vec2, Pos2, Rect};
+use window_geometry::{clamp_to_monitor, restored_position, visible_on};
+
+const MONITOR: [f32; 2] = [1920.0, 1080.0];
+
+fn window(x: f32, y: f32) -> Rect {
+    Rect::from_min_size(pos2(x, y), vec2(800.0, 600.0))
+}
+
+fn monitor() -> Rect {
+    Rect::from_min_size(Pos2::ZERO, MONITOR.into())
+}
+
+#[test]
+fn title_bar_must_be_reachable() {
+    assert!(visible_on(window(100.0, 100.0), monitor()));
+    // Mostly off the right edge, but enough of the title bar remains.
+    assert!(visible_on(window(1860.0, 100.0), monitor()));
+    assert!(!visible_on(window(1900.0, 100.0), monitor()));
+    assert!(!visible_on(window(-780.0, 100.0), monitor()));
+    // Title bar above the screen or at its very bottom.
+    assert!(!visible_on(window(100.0, -20.0), monitor()));
+    assert!(!visible_on(window(100.0, 1050.0), monitor()));
+}
+
+#[test]
+fn clamping_keeps_the_window_inside() {
+    assert_eq!(
+        clamp_to_monitor(window(2500.0, 1200.0), monitor()),
+        pos2(1120.0, 480.0)
+    );
+    assert_eq!(
+        clamp_to_monitor(window(-300.0, -50.0), monitor()),
+        pos2(0.0, 0.0)
+    );
+    assert_eq!(
+        clamp_to_monitor(window(300.0, 200.0), monitor()),
+        pos2(300.0, 200.0)
+    );
+    // Larger than the screen: top left corner.
+    let huge = Rect::from_min_size(pos2(500.0, 500.0), vec2(2500.0, 1400.0));
+    assert_eq!(clamp_to_monitor(huge, monitor()), Pos2::ZERO);
+}
+
+#[test]
+fn off_screen_windows_return_only_when_the_monitor_changed() {
+    let size = MONITOR.into();
+    // Visible: kept as is.
+    assert_eq!(restored_position(window(100.0, 100.0), size, None), None);
+    // On a second screen that is still connected (same monitor size as at exit).
+    assert_eq!(
+        restored_position(window(2200.0, 100.0), size, Some(size)),
+        None
+    );
+    // That screen was unplugged: the primary one is smaller.
+    let laptop = vec2(1366.0, 768.0);
+    assert_eq!(
+        restored_position(window(2200.0, 100.0), laptop, Some(size)),
+        Some(pos2(566.0, 100.0))
+    );
+    // First run with a saved position but no monitor recorded.
+    assert_eq!(
+        restored_position(window(-2000.0, 100.0), size, None),
+        Some(pos2(0.0, 100.0))
+    );
+}